@@ -0,0 +1,50 @@
+use runner::lang::{Catalog, Lang, Statements};
+
+pub const CATALOG: Catalog = Catalog {
+    lang: Lang::Fr,
+    statements: Statements::Table(&[
+        ("Q1", "écrire `quadratic_twist`, qui renvoie le plus petit non-carré c >= 2 de Fq et b' = 7c^3, puis\n`lift_to_twist`, qui envoie un x hors de secp256k1 sur le point (cx, c^2 y) de y^2 = x^3 + b', et None pour un x sur la courbe"),
+        ("Q2", "écrire `verify_twist_factors`, qui vérifie la factorisation de Sage contre l'ordre du tordu :\nle produit des facteurs vaut cet ordre, et chacun est 2, 3, 5, 7 ou passe le test de Fermat pour ces quatre bases"),
+        ("Q3", "écrire `twist_point_of_order`, l'abscisse d'un point d'ordre r sur le tordu, pour les\npaires (premier, ordre) de `TWIST_SMALL_ORDERS`"),
+        ("Q4", "écrire `twist_attack`, qui envoie à l'oracle un point de chaque ordre de `TWIST_SMALL_ORDERS` et\nrenvoie les paires (résidu, ordre) avec k = +-résidu mod ordre"),
+        ("Q5", "écrire `checked_x_only_ecdh`, la correction : elle rejette les x qui ne sont pas sur secp256k1"),
+        ("Q6", "combien de bits de k l'attaque a-t-elle révélés, et que coûte le reste avec les kangourous de\nnumber-theory ? SafeCurves appelle cela la sécurité du tordu d'une courbe : pourquoi Curve25519 est-elle choisie avec un tordu\nd'ordre 4 fois un premier, alors que secp256k1 compte sur les implémentations pour vérifier x ? Quelle vérification fait\nle `lift_x` de BIP340 sur les clés publiques en x seul ?"),
+    ]),
+    hints: &[
+        (
+            "Q1",
+            &[
+                "`Field::legendre` distingue les carrés des non-carrés : `is_qnr` pour un non-carré",
+                "x est hors de secp256k1 si et seulement si x^3 + 7 est un non-carré ; alors (x^3 + 7) / c est un carré, dont la racine y vérifie c y^2 = x^3 + 7",
+                "Multiplier c y^2 = x^3 + 7 par c^3 : (c^2 y)^2 = (cx)^3 + 7c^3",
+            ],
+        ),
+        (
+            "Q2",
+            &[
+                "Multiplier d'abord les facteurs avec `Iterator::product` : Sage peut avoir raison sur la primalité et tort sur la courbe",
+                "Fermat : a^(q - 1) = 1 mod q pour un premier q et une base a qu'il ne divise pas, `BigUint::modpow` le calcule",
+            ],
+        ),
+        (
+            "Q3",
+            &[
+                "Multiplier un point aléatoire du tordu par #E' / r ne marche que si la r-partie du tordu est cyclique",
+                "Multiplier par #E' divisé par la puissance de q dans #E' : le résultat est dans la q-partie, dont les points sont d'ordre 1, q, q^2...",
+                "Tirer x avec x^3 + 7 non-carré, le multiplier par ce cofacteur avec `x_only_mul`, et garder le point si r l'annule mais pas r / q",
+            ],
+        ),
+        (
+            "Q4",
+            &[
+                "La réponse à un point P' d'ordre r est x(kP') = x((k mod r) P')",
+                "Essayer x(jP') pour j = 0, ..., r / 2 : j et r - j donnent le même x, et j = 0 le point à l'infini, None",
+                "Une requête par entrée de `TWIST_SMALL_ORDERS`, et le résidu est le j dont x(jP') correspond à la réponse",
+            ],
+        ),
+        (
+            "Q5",
+            &["Le cofacteur de secp256k1 vaut 1 : vérifier que x^3 + 7 est un carré est toute la validation nécessaire"],
+        ),
+    ],
+};
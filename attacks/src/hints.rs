@@ -0,0 +1,45 @@
+use runner::hints::Hints;
+use runner::lang::Catalog;
+
+pub const CATALOGS: &[Catalog] = &[
+    Catalog::english(HINTS, include_str!("main.rs")),
+    crate::fr::CATALOG,
+];
+
+pub const HINTS: Hints = &[
+    (
+        "Q1",
+        &[
+            "`Field::legendre` tells the squares from the non-squares: `is_qnr` for a non-square",
+            "x is off secp256k1 iff x^3 + 7 is a non-square; then (x^3 + 7) / c is a square, whose root y satisfies c y^2 = x^3 + 7",
+            "Multiply c y^2 = x^3 + 7 by c^3: (c^2 y)^2 = (cx)^3 + 7c^3",
+        ],
+    ),
+    (
+        "Q2",
+        &[
+            "Multiply the factors with `Iterator::product` first: Sage may be right about primality and wrong about the curve",
+            "Fermat: a^(q - 1) = 1 mod q for a prime q and a base a it does not divide, `BigUint::modpow` computes it",
+        ],
+    ),
+    (
+        "Q3",
+        &[
+            "Multiplying a random point of the twist by #E' / r only works when the r-part of the twist is cyclic",
+            "Multiply by #E' divided by the full power of q in #E': the result lies in the q-part, whose points have order 1, q, q^2...",
+            "Draw x with x^3 + 7 a non-square, multiply by that cofactor with `x_only_mul`, and keep the point if r kills it but r / q does not",
+        ],
+    ),
+    (
+        "Q4",
+        &[
+            "The answer to a point P' of order r is x(kP') = x((k mod r) P')",
+            "Try x(jP') for j = 0, ..., r / 2: j and r - j give the same x, and j = 0 the point at infinity, None",
+            "One query per entry of `TWIST_SMALL_ORDERS`, and the residue is the j whose x(jP') matches the answer",
+        ],
+    ),
+    (
+        "Q5",
+        &["The cofactor of secp256k1 is 1: checking that x^3 + 7 is a square is all the validation there is"],
+    ),
+];
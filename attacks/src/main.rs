@@ -0,0 +1,235 @@
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{Field, PrimeField};
+use ark_secp256k1::{Affine, Fq, Fr};
+use ark_std::{One, UniformRand, Zero};
+use num_bigint::BigUint;
+use rand::Rng;
+use runner::Runner;
+use sage_fixtures::Fixtures;
+use twist::*;
+
+mod fr;
+mod hints;
+mod twist;
+
+fn main() {
+    let runner = Runner::start(env!("CARGO_PKG_NAME"), hints::CATALOGS);
+    let mut rng = seed::rng();
+
+    // Attacks on implementations which skip a check, on the curves of ff-ec: what an attacker learns from the
+    // answers to the inputs the implementation should have rejected
+    let g = Affine::generator();
+
+    // Q8 of ff-ec asked what an x-only scalar multiplication which forgets to check x leaks: the answer on
+    // secp256k1, take a look at module `twist`
+    let k = Fr::rand(&mut rng);
+    assert_eq!(
+        x_only_mul(k.into_bigint().as_ref(), g.x),
+        Some((g * k).into_affine().x)
+    );
+    assert_eq!(x_only_mul(Fr::MODULUS.as_ref(), g.x), None);
+    // Q1: write `quadratic_twist`, which returns the smallest non-square c >= 2 of Fq and b' = 7c^3, then
+    // `lift_to_twist`, which maps an x off secp256k1 to the point (cx, c^2 y) of y^2 = x^3 + b', and None for an x on it
+    runner.question("Q1");
+    let (c, twist_b) = quadratic_twist();
+    assert!(c.legendre().is_qnr());
+    assert!((2..c.into_bigint().0[0]).all(|i| Fq::from(i).legendre().is_qr()));
+    assert_eq!(twist_b, Fq::from(7) * c * c * c);
+    assert_eq!(lift_to_twist(g.x, c), None);
+    let mut lifted = 0;
+    while lifted < 20 {
+        let x = Fq::rand(&mut rng);
+        match lift_to_twist(x, c) {
+            Some((tx, ty)) => {
+                assert!(Affine::get_point_from_x_unchecked(x, false).is_none());
+                assert_eq!(tx, c * x);
+                assert_eq!(ty.square(), tx * tx * tx + twist_b);
+                lifted += 1;
+            }
+            None => assert!(Affine::get_point_from_x_unchecked(x, false).is_some()),
+        }
+    }
+
+    // Q2: write `verify_twist_factors`, which checks the factorization of Sage against the order of the twist:
+    // the factors multiply to it, and each one is 2, 3, 5, 7 or passes the Fermat test to these four bases
+    runner.question("Q2");
+    // the factorization of the order of the twist, computed with SageMath: see the `fixtures` directory
+    let fixtures: Fixtures = include_str!("../fixtures/twist.fixtures").parse().unwrap();
+    let twist: BigUint = fixtures.get("secp256k1", "twist_order");
+    let twist_factors: Vec<BigUint> = fixtures.get_list("secp256k1", "twist_order_factors");
+    assert!(verify_twist_factors(&twist, &twist_factors));
+    assert!(!verify_twist_factors(&(&twist + 2u32), &twist_factors));
+    let mut merged = twist_factors[1..].to_vec();
+    merged[0] *= 3u32; // 9
+    assert!(!verify_twist_factors(&twist, &merged));
+    let mut composite = twist_factors.clone();
+    let large = composite.pop().unwrap();
+    composite[0] *= &large;
+    assert!(!verify_twist_factors(&twist, &composite));
+    assert!(!verify_twist_factors(
+        &twist,
+        &[twist.clone(), BigUint::from(1u32)]
+    ));
+    // the order kills every point of the twist, with the bare ladder of module `twist`
+    for _ in 0..5 {
+        let x = loop {
+            let x = Fq::rand(&mut rng);
+            if !x.is_zero() && lift_to_twist(x, c).is_some() {
+                break x;
+            }
+        };
+        assert_eq!(x_only_mul(&twist.to_u64_digits(), x), None);
+        assert!(x_only_mul(&(&twist / &large).to_u64_digits(), x).is_some());
+    }
+
+    // Q3: write `twist_point_of_order`, the x-coordinate of a point of order r on the twist, for the
+    // (prime, order) pairs of `TWIST_SMALL_ORDERS`
+    runner.question("Q3");
+    for (q, r) in TWIST_SMALL_ORDERS {
+        let x = twist_point_of_order(&twist, (q, r), &mut rng);
+        assert!(Affine::get_point_from_x_unchecked(x, false).is_none());
+        assert_eq!(x_only_mul(&[r], x), None);
+        assert!(x_only_mul(&[r / q], x).is_some());
+    }
+
+    // Q4: write `twist_attack`, which sends one point of each order of `TWIST_SMALL_ORDERS` to the oracle and
+    // returns the pairs (residue, order) with k = +-residue mod order
+    runner.question("Q4");
+    let mut oracle = XOnlyEcdhOracle::new(false, &mut rng);
+    let alice = Fr::rand(&mut rng);
+    assert_eq!(
+        oracle.respond((g * alice).into_affine().x),
+        x_only_mul(alice.into_bigint().as_ref(), oracle.public_key)
+    );
+    let residues = twist_attack(&mut oracle, &twist, &mut rng);
+    assert_eq!(residues.len(), TWIST_SMALL_ORDERS.len());
+    for ((residue, order), (_, r)) in residues.iter().zip(TWIST_SMALL_ORDERS) {
+        assert_eq!(*order, r);
+        assert!(oracle.is_secret_mod(*residue, *order));
+    }
+    assert_eq!(oracle.queries, 1 + TWIST_SMALL_ORDERS.len());
+    let modulus: u64 = TWIST_SMALL_ORDERS.iter().map(|(_, r)| r).product();
+    println!(
+        "k mod {} up to the signs ({:.1} bits) from {} points of the twist",
+        modulus,
+        (modulus as f64).log2(),
+        TWIST_SMALL_ORDERS.len()
+    );
+
+    // Q5: write `checked_x_only_ecdh`, the fix: it rejects the x which are not on secp256k1
+    runner.question("Q5");
+    let mut oracle = XOnlyEcdhOracle::new(true, &mut rng);
+    assert_eq!(
+        oracle.respond((g * alice).into_affine().x),
+        x_only_mul(alice.into_bigint().as_ref(), oracle.public_key)
+    );
+    for (q, r) in TWIST_SMALL_ORDERS {
+        assert_eq!(
+            oracle.respond(twist_point_of_order(&twist, (q, r), &mut rng)),
+            None
+        );
+    }
+    let residues = twist_attack(&mut oracle, &twist, &mut rng);
+    assert!(!residues
+        .iter()
+        .all(|(residue, order)| oracle.is_secret_mod(*residue, *order)));
+
+    // Q6: how many bits of k did the attack reveal, and what does the rest cost with the kangaroos of
+    // number-theory? SafeCurves calls this the twist security of a curve: why is Curve25519 chosen with a twist
+    // of order 4 times a prime, while secp256k1 relies on the implementations checking x? Which check does the
+    // `lift_x` of BIP340 do on x-only public keys?
+    runner.question("Q6");
+
+    println!("Good job! 🏴‍☠️");
+    runner.finish();
+}
+
+fn quadratic_twist() -> (Fq, Fq) {
+    // SOLUTION-BEGIN
+    let c = (2u64..)
+        .map(Fq::from)
+        .find(|c| c.legendre().is_qnr())
+        .unwrap();
+    (c, Fq::from(7) * c * c * c)
+    // SOLUTION-END
+}
+
+fn lift_to_twist(x: Fq, c: Fq) -> Option<(Fq, Fq)> {
+    // SOLUTION-BEGIN
+    let rhs = x * x * x + Fq::from(7);
+    if !rhs.legendre().is_qnr() {
+        return None;
+    }
+    // c y^2 = x^3 + 7: the quotient of two non-squares is a square
+    let y = (rhs / c).sqrt()?;
+    Some((c * x, c * c * y))
+    // SOLUTION-END
+}
+
+fn verify_twist_factors(order: &BigUint, factors: &[BigUint]) -> bool {
+    // SOLUTION-BEGIN
+    let bases = [2u32, 3, 5, 7].map(BigUint::from);
+    let is_probable_prime = |q: &BigUint| {
+        bases.contains(q)
+            || (*q > BigUint::from(7u32)
+                && bases
+                    .iter()
+                    .all(|a| a.modpow(&(q - 1u32), q) == BigUint::from(1u32)))
+    };
+    factors.iter().product::<BigUint>() == *order && factors.iter().all(is_probable_prime)
+    // SOLUTION-END
+}
+
+fn twist_point_of_order<R: Rng>(twist: &BigUint, (q, r): (u64, u64), rng: &mut R) -> Fq {
+    // SOLUTION-BEGIN
+    // the full power of q in #E', then a point whose order divides it
+    let mut q_part = BigUint::from(1u32);
+    while (twist / &q_part) % q == BigUint::from(0u32) {
+        q_part *= q;
+    }
+    let cofactor = (twist / q_part).to_u64_digits();
+    loop {
+        let x = Fq::rand(rng);
+        if !(x * x * x + Fq::from(7)).legendre().is_qnr() {
+            continue;
+        }
+        // its order divides the exponent of the q-part: keep it if it is r exactly
+        if let Some(point) = x_only_mul(&cofactor, x) {
+            if x_only_mul(&[r], point).is_none() && x_only_mul(&[r / q], point).is_some() {
+                return point;
+            }
+        }
+    }
+    // SOLUTION-END
+}
+
+fn twist_attack<R: Rng>(
+    oracle: &mut XOnlyEcdhOracle,
+    twist: &BigUint,
+    rng: &mut R,
+) -> Vec<(u64, u64)> {
+    // SOLUTION-BEGIN
+    TWIST_SMALL_ORDERS
+        .iter()
+        .map(|(q, r)| {
+            let x = twist_point_of_order(twist, (*q, *r), rng);
+            let answer = oracle.respond(x);
+            // x(jP) = x(-jP): j and r - j give the same answer
+            let residue = (0..=r / 2)
+                .find(|j| x_only_mul(&[*j], x) == answer)
+                .unwrap_or(0);
+            (residue, *r)
+        })
+        .collect()
+    // SOLUTION-END
+}
+
+fn checked_x_only_ecdh(k: &Fr, x: Fq) -> Option<Fq> {
+    // SOLUTION-BEGIN
+    // secp256k1 has a prime order: every point of the curve but the point at infinity has order n
+    if (x * x * x + Fq::from(7)).legendre().is_qnr() {
+        return None;
+    }
+    x_only_mul(k.into_bigint().as_ref(), x)
+    // SOLUTION-END
+}
@@ -0,0 +1,87 @@
+use crate::*;
+
+// Invalid-curve attacks on x-only ECDH over secp256k1 itself, after the toy Montgomery curve of curve25519
+// The x-only formulas of Brier and Joye only use x and b = 7: for an x which is not on secp256k1, x^3 + 7 is
+// not a square, and x is the x-coordinate of a point of the twist c y^2 = x^3 + 7 (c a non-square), isomorphic
+// to y^2 = x^3 + 7c^3 by (x, y) -> (cx, c^2 y), on which the very same formulas compute
+// #E is prime, but #E' = 3^2 * 13^2 * 3319 * 22639 * q with q of 220 bits (see the `fixtures` directory)
+// Careful: the 3-part of the twist is Z/3 x Z/3, not Z/9, so no point has order 9, when the 13-part is Z/169
+
+// the orders of the points of small order the attack uses, each a power of the prime before it
+pub const TWIST_SMALL_ORDERS: [(u64, u64); 4] = [(3, 3), (13, 169), (3319, 3319), (22639, 22639)];
+
+// x(kP) for the point P of x-coordinate x on secp256k1 or its twist, None for the point at infinity
+// k is a list of 64-bit limbs, least significant first. It checks nothing about x: this is the bug
+pub fn x_only_mul(k: &[u64], x: Fq) -> Option<Fq> {
+    // (0, y) has order 3 on the curve or its twist, where the differential addition divides by x
+    if x.is_zero() {
+        let k_mod_3 = k.iter().rev().fold(0, |r, limb| (r + limb % 3) % 3);
+        return (k_mod_3 != 0).then_some(Fq::zero());
+    }
+    let b = Fq::from(7);
+    let b4 = b.double().double();
+    let double = |(x, z): (Fq, Fq)| {
+        let (xx, zz) = (x.square(), z.square());
+        (
+            xx.square() - b4.double() * x * zz * z,
+            (x * xx + b * zz * z) * z.double().double(),
+        )
+    };
+    // R + S from R, S and R - S = P
+    let add = |(x1, z1): (Fq, Fq), (x2, z2): (Fq, Fq)| {
+        let (z1z2, cross) = (z1 * z2, x1 * z2 + x2 * z1);
+        (
+            (x1 * x2).square() - b4 * z1z2 * cross,
+            x * (x1 * z2 - x2 * z1).square(),
+        )
+    };
+    // R1 - R0 = P all along the ladder
+    let (mut r0, mut r1) = ((Fq::one(), Fq::zero()), (x, Fq::one()));
+    for i in (0..64 * k.len()).rev() {
+        if (k[i / 64] >> (i % 64)) & 1 == 1 {
+            (r0, r1) = (add(r0, r1), double(r1));
+        } else {
+            (r0, r1) = (double(r0), add(r0, r1));
+        }
+    }
+    let (x, z) = r0;
+    z.inverse().map(|inverse| x * inverse)
+}
+
+// A server of x-only ECDH: it answers every x with x(kP), its shared secret with the sender of x
+// (in a real protocol, the attacker would rather see a MAC keyed with it, and test the candidates one by one)
+pub struct XOnlyEcdhOracle {
+    secret: Fr,
+    pub public_key: Fq,
+    pub validate: bool, // whether to run `checked_x_only_ecdh` instead of the bare ladder
+    pub queries: usize,
+}
+
+impl XOnlyEcdhOracle {
+    pub fn new<R: Rng>(validate: bool, rng: &mut R) -> XOnlyEcdhOracle {
+        let secret = Fr::rand(rng);
+        let public_key = (Affine::generator() * secret).into_affine().x;
+        XOnlyEcdhOracle {
+            secret,
+            public_key,
+            validate,
+            queries: 0,
+        }
+    }
+
+    pub fn respond(&mut self, x: Fq) -> Option<Fq> {
+        self.queries += 1;
+        if self.validate {
+            checked_x_only_ecdh(&self.secret, x)
+        } else {
+            x_only_mul(self.secret.into_bigint().as_ref(), x)
+        }
+    }
+
+    // whether k = residue or k = -residue mod modulus: x(kP) = x(-kP) only tells k up to its sign
+    pub fn is_secret_mod(&self, residue: u64, modulus: u64) -> bool {
+        let k = BigUint::from(self.secret) % modulus;
+        k == BigUint::from(residue % modulus)
+            || k == BigUint::from((modulus - residue % modulus) % modulus)
+    }
+}
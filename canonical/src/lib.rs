@@ -0,0 +1,401 @@
+//! Canonical encoding of answers, for the checkers which compare digests instead of values
+//!
+//! A checker which ships `SHA-256(answer)` rather than the answer itself only works if every student
+//! machine turns the same answer into the same bytes. `Debug` output, `usize`, native endianness, arkworks'
+//! Montgomery limbs or the iteration order of a `HashSet` (randomized in each process) all vary between
+//! runs, compilers or architectures. `Canonical` fixes one encoding per value:
+//!
+//! - integers are big-endian on their width, `usize` and `isize` on 64 bits
+//! - `bool` is one byte 0 or 1, strings are their UTF-8 bytes
+//! - a prime field element is its canonical integer (not its Montgomery form), big-endian on the size of
+//!   the modulus
+//! - a point is compressed: a flag byte, 0 for the point at infinity, then x with 2 or 3 for the parity of y
+//!   (short Weierstrass), or y with 2 or 3 for the parity of x (twisted Edwards)
+//! - a sequence is its length on 8 bytes followed by its items; sets are sorted by the encodings of their
+//!   items and maps are sets of (key, value) pairs, so that `HashSet` and `BTreeSet` agree
+//! - tuples are their fields one after the other, `Option` a byte 0 or 1 then the value
+//!
+//! Floats have no encoding: answers are exact.
+//!
+//! `digest` hashes an encoding under a label naming the question, so that equal answers to different
+//! questions get different digests:
+//!
+//! ```text
+//! let expected = "a3f1...";
+//! assert!(canonical::check("ff-ec/Q1", &generators, expected));
+//! ```
+//!
+//! The checks of the exercises use `assert_digest`, or `assert_same` when the expected answer depends on the
+//! random instance and is computed at runtime: unlike `assert_eq!`, their failure messages never print the
+//! expected value.
+//!
+//! Without its default `std` feature the crate is `no_std` with `alloc`: everything but the encodings of
+//! `HashSet` and `HashMap`, which live in `std`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use ark_ec::short_weierstrass::{Affine as SWAffine, SWCurveConfig};
+use ark_ec::twisted_edwards::{Affine as TEAffine, TECurveConfig};
+use ark_ff::{BigInteger, Field, Fp, FpConfig, PrimeField};
+use num_bigint::{BigInt, BigUint, Sign};
+use sha2::{Digest, Sha256};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+pub trait Canonical {
+    fn encode(&self, out: &mut Vec<u8>);
+
+    fn to_canonical_bytes(&self) -> Vec<u8> {
+        let mut out = vec![];
+        self.encode(&mut out);
+        out
+    }
+}
+
+/// SHA-256 of the label and the encoding of the answer
+pub fn digest<T: Canonical + ?Sized>(label: &str, answer: &T) -> [u8; 32] {
+    let mut bytes = vec![];
+    label.encode(&mut bytes);
+    answer.encode(&mut bytes);
+    Sha256::digest(bytes).into()
+}
+
+pub fn digest_hex<T: Canonical + ?Sized>(label: &str, answer: &T) -> String {
+    digest(label, answer)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Whether the answer has the expected digest, in lowercase hexadecimal
+pub fn check<T: Canonical + ?Sized>(label: &str, answer: &T, expected: &str) -> bool {
+    digest_hex(label, answer) == expected.to_ascii_lowercase()
+}
+
+/// Panics with a message naming the question, but not the answer, unless the answer has the expected digest
+pub fn assert_digest<T: Canonical + ?Sized>(label: &str, answer: &T, expected: &str) {
+    if !check(label, answer, expected) {
+        panic!("wrong answer to {}", label);
+    }
+}
+
+/// Panics unless the answer has the same encoding as the expected value, whose type may differ
+pub fn assert_same<A: Canonical + ?Sized, E: Canonical + ?Sized>(
+    label: &str,
+    answer: &A,
+    expected: &E,
+) {
+    if digest(label, answer) != digest(label, expected) {
+        panic!("wrong answer to {}", label);
+    }
+}
+
+macro_rules! big_endian {
+    ($($t:ty),*) => {
+        $(impl Canonical for $t {
+            fn encode(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_be_bytes());
+            }
+        })*
+    };
+}
+
+big_endian!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl Canonical for usize {
+    fn encode(&self, out: &mut Vec<u8>) {
+        (*self as u64).encode(out);
+    }
+}
+
+impl Canonical for isize {
+    fn encode(&self, out: &mut Vec<u8>) {
+        (*self as i64).encode(out);
+    }
+}
+
+impl Canonical for bool {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+}
+
+impl Canonical for str {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.as_bytes().encode(out);
+    }
+}
+
+impl Canonical for String {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.as_str().encode(out);
+    }
+}
+
+// the magnitude, big-endian without leading zeros, as a sequence of bytes
+impl Canonical for BigUint {
+    fn encode(&self, out: &mut Vec<u8>) {
+        let bytes = if *self == BigUint::default() {
+            vec![]
+        } else {
+            self.to_bytes_be()
+        };
+        bytes.encode(out);
+    }
+}
+
+// a sign byte (0 for zero or positive, 1 for negative), then the magnitude
+impl Canonical for BigInt {
+    fn encode(&self, out: &mut Vec<u8>) {
+        (self.sign() == Sign::Minus).encode(out);
+        self.magnitude().encode(out);
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Canonical for Fp<P, N> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        let bytes = self.into_bigint().to_bytes_be();
+        let size = (Self::MODULUS_BIT_SIZE as usize).div_ceil(8);
+        out.extend_from_slice(&bytes[bytes.len() - size..]);
+    }
+}
+
+fn is_odd<F: Field>(x: &F) -> bool {
+    // the parity of an element of a prime field; extension fields take the parity of their first coordinate
+    let mut bytes = vec![];
+    x.serialize_uncompressed(&mut bytes).unwrap();
+    bytes[0] & 1 == 1
+}
+
+impl<P: SWCurveConfig> Canonical for SWAffine<P>
+where
+    P::BaseField: Canonical,
+{
+    fn encode(&self, out: &mut Vec<u8>) {
+        match self.infinity {
+            true => out.push(0),
+            false => {
+                out.push(if is_odd(&self.y) { 3 } else { 2 });
+                self.x.encode(out);
+            }
+        }
+    }
+}
+
+impl<P: TECurveConfig> Canonical for TEAffine<P>
+where
+    P::BaseField: Canonical,
+{
+    fn encode(&self, out: &mut Vec<u8>) {
+        // the identity (0, 1) is a point like any other in Edwards form
+        out.push(if is_odd(&self.x) { 3 } else { 2 });
+        self.y.encode(out);
+    }
+}
+
+impl<T: Canonical + ?Sized> Canonical for &T {
+    fn encode(&self, out: &mut Vec<u8>) {
+        (**self).encode(out);
+    }
+}
+
+impl<T: Canonical> Canonical for [T] {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.len().encode(out);
+        for item in self {
+            item.encode(out);
+        }
+    }
+}
+
+impl<T: Canonical, const N: usize> Canonical for [T; N] {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.as_slice().encode(out);
+    }
+}
+
+impl<T: Canonical> Canonical for Vec<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.as_slice().encode(out);
+    }
+}
+
+impl<T: Canonical> Canonical for Option<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.is_some().encode(out);
+        if let Some(value) = self {
+            value.encode(out);
+        }
+    }
+}
+
+// a set whatever its iteration order: the items sorted by their encodings
+fn encode_set<'a, T: Canonical + 'a>(items: impl Iterator<Item = &'a T>, out: &mut Vec<u8>) {
+    let mut encodings: Vec<Vec<u8>> = items.map(|item| item.to_canonical_bytes()).collect();
+    encodings.sort();
+    encodings.len().encode(out);
+    out.extend(encodings.concat());
+}
+
+#[cfg(feature = "std")]
+impl<T: Canonical, S> Canonical for HashSet<T, S> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        encode_set(self.iter(), out);
+    }
+}
+
+impl<T: Canonical> Canonical for BTreeSet<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        encode_set(self.iter(), out);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Canonical, V: Canonical, S> Canonical for HashMap<K, V, S> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        encode_set(self.iter().collect::<Vec<_>>().iter(), out);
+    }
+}
+
+impl<K: Canonical, V: Canonical> Canonical for BTreeMap<K, V> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        encode_set(self.iter().collect::<Vec<_>>().iter(), out);
+    }
+}
+
+macro_rules! tuple {
+    ($($t:ident $i:tt),*) => {
+        impl<$($t: Canonical),*> Canonical for ($($t,)*) {
+            fn encode(&self, out: &mut Vec<u8>) {
+                $(self.$i.encode(out);)*
+            }
+        }
+    };
+}
+
+tuple!(A 0);
+tuple!(A 0, B 1);
+tuple!(A 0, B 1, C 2);
+tuple!(A 0, B 1, C 2, D 3);
+
+// Known answers pinning the encodings: a change here changes the digests shipped in the checkers
+#[cfg(test)]
+mod tests {
+    #![allow(non_local_definitions)]
+    use super::*;
+    use ark_ec::AffineRepr;
+    use ark_ff::{Fp64, MontBackend, MontConfig};
+    use ark_secp256k1::{Affine, Fq, Fr};
+    use std::collections::{HashMap, HashSet};
+
+    #[derive(MontConfig)]
+    #[modulus = "89"]
+    #[generator = "3"]
+    struct F89Config;
+    type F89 = Fp64<MontBackend<F89Config, 1>>;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[test]
+    fn integers_are_big_endian_on_their_width() {
+        assert_eq!(7u32.to_canonical_bytes(), [0, 0, 0, 7]);
+        assert_eq!((-2i16).to_canonical_bytes(), [0xff, 0xfe]);
+        assert_eq!(
+            0x0102030405060708u64.to_canonical_bytes(),
+            [1, 2, 3, 4, 5, 6, 7, 8]
+        );
+    }
+
+    #[test]
+    fn usize_is_written_as_a_u64() {
+        assert_eq!(1usize.to_canonical_bytes(), 1u64.to_canonical_bytes());
+        assert_eq!(1usize.to_canonical_bytes(), [0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!((-1isize).to_canonical_bytes(), [0xff; 8]);
+    }
+
+    #[test]
+    fn sequences_strings_and_options() {
+        assert_eq!(
+            vec![1u8, 2].to_canonical_bytes(),
+            [0, 0, 0, 0, 0, 0, 0, 2, 1, 2]
+        );
+        assert_eq!(
+            "ab".to_canonical_bytes(),
+            [0, 0, 0, 0, 0, 0, 0, 2, b'a', b'b']
+        );
+        assert_eq!(Some(true).to_canonical_bytes(), [1, 1]);
+        assert_eq!(None::<u8>.to_canonical_bytes(), [0]);
+        assert_eq!((1u8, 2u16).to_canonical_bytes(), [1, 0, 2]);
+    }
+
+    #[test]
+    fn big_integers() {
+        assert_eq!(BigUint::default().to_canonical_bytes(), [0; 8]);
+        assert_eq!(
+            BigInt::from(-5).to_canonical_bytes(),
+            [1, 0, 0, 0, 0, 0, 0, 0, 1, 5]
+        );
+    }
+
+    #[test]
+    fn hash_and_btree_collections_agree() {
+        let hashed: HashSet<u16> = [3, 1, 2].into_iter().collect();
+        let sorted: BTreeSet<u16> = [2, 3, 1].into_iter().collect();
+        let expected = [0, 0, 0, 0, 0, 0, 0, 3, 0, 1, 0, 2, 0, 3];
+        assert_eq!(hashed.to_canonical_bytes(), expected);
+        assert_eq!(sorted.to_canonical_bytes(), expected);
+        let hashed: HashMap<u8, bool> = [(2, true), (1, false)].into_iter().collect();
+        let sorted: BTreeMap<u8, bool> = [(1, false), (2, true)].into_iter().collect();
+        assert_eq!(
+            hashed.to_canonical_bytes(),
+            [0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 2, 1]
+        );
+        assert_eq!(sorted.to_canonical_bytes(), hashed.to_canonical_bytes());
+    }
+
+    #[test]
+    fn field_elements_are_canonical_integers() {
+        // not the Montgomery form, on the byte size of the modulus
+        assert_eq!(F89::from(5u8).to_canonical_bytes(), [5]);
+        assert_eq!((-F89::from(1u8)).to_canonical_bytes(), [88]);
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        assert_eq!(Fr::from(1u8).to_canonical_bytes(), one);
+        assert_eq!(
+            hex(&(-Fq::from(1u8)).to_canonical_bytes()),
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e"
+        );
+    }
+
+    #[test]
+    fn points_are_compressed() {
+        let g = Affine::generator();
+        let x = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        assert_eq!(hex(&g.to_canonical_bytes()), format!("02{}", x));
+        assert_eq!(hex(&(-g).to_canonical_bytes()), format!("03{}", x));
+        assert_eq!(Affine::zero().to_canonical_bytes(), [0]);
+    }
+
+    #[test]
+    fn digests() {
+        assert_eq!(
+            digest_hex("canonical/Q1", &42u64),
+            "cd623a205b1f7670322a2e51180c31a873ce7c6b07204a45ef51253e5c0d2001"
+        );
+        let set: HashSet<u16> = [1, 2, 3].into_iter().collect();
+        assert!(check(
+            "canonical/set",
+            &set,
+            "728ED74125808690932BB0B0D118B18C31B4416D86F9DEEC1034E9ACA887F9D8"
+        ));
+        assert_ne!(digest("a", &1u8), digest("b", &1u8));
+    }
+}
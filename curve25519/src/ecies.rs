@@ -0,0 +1,175 @@
+use crate::*;
+use sha2::{Digest, Sha256};
+
+// ECIES: hybrid encryption to a public key R = rB, where every message gets a fresh key
+// - the sender draws an ephemeral secret e, sends E = eB, and both sides compute the shared secret
+//   X25519(e, R) = X25519(r, E)
+// - the shared secret is not a uniform key: HKDF-SHA256 turns it into the key of an AEAD, here
+//   key = HKDF(salt = none, ikm = shared secret, info = ECIES_INFO || E || R), 32 bytes
+// - the message is sealed with ChaCha20-Poly1305 under this key, with a zero nonce (the key is used once)
+//   and no associated data
+// The ciphertext is E || sealed message || tag, 48 bytes longer than the message
+//
+// ChaCha20-Poly1305 (RFC 8439) is written below, as no crate is available here: ChaCha20 encrypts, the first
+// 32 bytes of its keystream for block 0 key a Poly1305 MAC over aad || ciphertext and their lengths,
+// Poly1305 being the evaluation of a polynomial at r in F_(2^130 - 5)
+
+pub const ECIES_INFO: &[u8] = b"ECIES-X25519-ChaCha20Poly1305";
+pub const TAG_SIZE: usize = 16;
+
+// A ciphertext to Alice of RFC 7748 section 6.1, with the key pair of Bob as the ephemeral key, computed by an
+// independent implementation in Python
+pub const RFC7748_ALICE_SECRET_KEY: &str =
+    "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a";
+pub const ECIES_MESSAGE: &[u8] = b"Meet me at the fountain at noon";
+pub const ECIES_CIPHERTEXT: &str = "\
+    de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f46f9ebeec268297aacbec385bcf879e7ddc4f4b5205768\
+    a1535e5903029fb80304d00767212dcafd5af40937c725ed";
+
+pub mod poly1305_field {
+    #![allow(non_local_definitions, unexpected_cfgs)] // lints triggered by the code of the derive macro
+    use ark_ff::{Fp192, MontBackend, MontConfig};
+
+    #[derive(MontConfig)]
+    #[modulus = "1361129467683753853853498429727072845819"]
+    #[generator = "2"]
+    pub struct Poly1305Config;
+    // integers modulo 2^130 - 5
+    pub type F130 = Fp192<MontBackend<Poly1305Config, 3>>;
+}
+pub use poly1305_field::F130;
+
+fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(12);
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(7);
+}
+
+// RFC 8439 section 2.3: "expand 32-byte k", the key, the counter and the nonce, 20 rounds, plus the input
+pub fn chacha20_block(key: &[u8; 32], counter: u32, nonce: &[u8; 12]) -> [u8; 64] {
+    let words = |bytes: &[u8]| -> Vec<u32> {
+        bytes
+            .chunks(4)
+            .map(|word| u32::from_le_bytes(word.try_into().unwrap()))
+            .collect()
+    };
+    let mut input = [0u32; 16];
+    input[..4].copy_from_slice(&[0x61707865, 0x3320646e, 0x79622d32, 0x6b206574]);
+    input[4..12].copy_from_slice(&words(key));
+    input[12] = counter;
+    input[13..].copy_from_slice(&words(nonce));
+    let mut state = input;
+    for _ in 0..10 {
+        quarter_round(&mut state, 0, 4, 8, 12);
+        quarter_round(&mut state, 1, 5, 9, 13);
+        quarter_round(&mut state, 2, 6, 10, 14);
+        quarter_round(&mut state, 3, 7, 11, 15);
+        quarter_round(&mut state, 0, 5, 10, 15);
+        quarter_round(&mut state, 1, 6, 11, 12);
+        quarter_round(&mut state, 2, 7, 8, 13);
+        quarter_round(&mut state, 3, 4, 9, 14);
+    }
+    let mut block = [0u8; 64];
+    for (i, chunk) in block.chunks_mut(4).enumerate() {
+        chunk.copy_from_slice(&state[i].wrapping_add(input[i]).to_le_bytes());
+    }
+    block
+}
+
+// encrypts or decrypts, from block `counter` on
+pub fn chacha20(key: &[u8; 32], counter: u32, nonce: &[u8; 12], data: &[u8]) -> Vec<u8> {
+    data.chunks(64)
+        .zip(counter..)
+        .flat_map(|(chunk, counter)| {
+            let keystream = chacha20_block(key, counter, nonce);
+            chunk
+                .iter()
+                .zip(keystream)
+                .map(|(b, k)| b ^ k)
+                .collect::<Vec<u8>>()
+        })
+        .collect()
+}
+
+// RFC 8439 section 2.5: r (clamped) and s are the two halves of the key, every 16-byte block with a 0x01 byte
+// appended is a coefficient, and the tag is (the polynomial at r) + s mod 2^128
+pub fn poly1305(key: &[u8; 32], message: &[u8]) -> [u8; 16] {
+    let mut r = u128::from_le_bytes(key[..16].try_into().unwrap());
+    r &= 0x0ffffffc0ffffffc0ffffffc0fffffff;
+    let s = u128::from_le_bytes(key[16..].try_into().unwrap());
+    let r = F130::from(r);
+    let mut accumulator = F130::zero();
+    for block in message.chunks(16) {
+        let mut bytes = block.to_vec();
+        bytes.push(1);
+        accumulator = (accumulator + F130::from_le_bytes_mod_order(&bytes)) * r;
+    }
+    let limbs = accumulator.into_bigint().0;
+    let low = limbs[0] as u128 | (limbs[1] as u128) << 64;
+    low.wrapping_add(s).to_le_bytes()
+}
+
+fn pad16(bytes: &[u8]) -> Vec<u8> {
+    let mut padded = bytes.to_vec();
+    padded.resize(bytes.len().div_ceil(16) * 16, 0);
+    padded
+}
+
+fn aead_tag(key: &[u8; 32], nonce: &[u8; 12], aad: &[u8], ciphertext: &[u8]) -> [u8; 16] {
+    let one_time_key: [u8; 32] = chacha20_block(key, 0, nonce)[..32].try_into().unwrap();
+    let mac_data = [
+        pad16(aad),
+        pad16(ciphertext),
+        (aad.len() as u64).to_le_bytes().to_vec(),
+        (ciphertext.len() as u64).to_le_bytes().to_vec(),
+    ]
+    .concat();
+    poly1305(&one_time_key, &mac_data)
+}
+
+// RFC 8439 section 2.8: the ciphertext followed by the tag
+pub fn aead_seal(key: &[u8; 32], nonce: &[u8; 12], aad: &[u8], plaintext: &[u8]) -> Vec<u8> {
+    let ciphertext = chacha20(key, 1, nonce, plaintext);
+    let tag = aead_tag(key, nonce, aad, &ciphertext);
+    [ciphertext, tag.to_vec()].concat()
+}
+
+// None if the tag does not match
+// (the comparison of the tags should be constant-time, see the HMAC questions of the hashes chapter)
+pub fn aead_open(key: &[u8; 32], nonce: &[u8; 12], aad: &[u8], sealed: &[u8]) -> Option<Vec<u8>> {
+    let (ciphertext, tag) = sealed.split_at_checked(sealed.len().checked_sub(TAG_SIZE)?)?;
+    (aead_tag(key, nonce, aad, ciphertext) == tag).then(|| chacha20(key, 1, nonce, ciphertext))
+}
+
+pub fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    const BLOCK_SIZE: usize = 64;
+    let mut padded_key = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        padded_key[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        padded_key[..key.len()].copy_from_slice(key);
+    }
+    let inner_pad: Vec<u8> = padded_key.iter().map(|b| b ^ 0x36).collect();
+    let outer_pad: Vec<u8> = padded_key.iter().map(|b| b ^ 0x5c).collect();
+    let inner = Sha256::new()
+        .chain_update(inner_pad)
+        .chain_update(data)
+        .finalize();
+    Sha256::new()
+        .chain_update(outer_pad)
+        .chain_update(inner)
+        .finalize()
+        .into()
+}
+
+// RFC 5869 with a single block of output: extract (an empty salt is 32 zero bytes), then expand T(1)
+pub fn hkdf_sha256(salt: &[u8], ikm: &[u8], info: &[u8]) -> [u8; 32] {
+    let salt = if salt.is_empty() { &[0; 32][..] } else { salt };
+    let prk = hmac_sha256(salt, ikm);
+    hmac_sha256(&prk, &[info, &[1]].concat())
+}
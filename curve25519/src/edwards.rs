@@ -0,0 +1,187 @@
+use crate::*;
+use secret::Scalar;
+use sha2::{Digest, Sha512};
+use std::ops::Neg;
+
+// Edwards25519 is the twisted Edwards curve -x^2 + y^2 = 1 + d x^2 y^2 over the same field as Curve25519,
+// with d = -121665 / 121666. It is birationally equivalent to Curve25519:
+//   (u, v) = ((1 + y) / (1 - y), sqrt(-486664) u / x)
+//   (x, y) = (sqrt(-486664) u / v, (u - 1) / (u + 1))
+// The addition law of the Edwards form is complete: the same formula adds any two points, doubling
+// and the neutral element (0, 1) included, which makes constant-time implementations easier
+// Ed25519 signatures (RFC 8032) live on this curve, in the subgroup of prime order l generated by B
+
+pub mod scalar {
+    #![allow(non_local_definitions, unexpected_cfgs)] // lints triggered by the code of the derive macro
+    use ark_ff::{Fp256, MontBackend, MontConfig};
+
+    #[derive(MontConfig)]
+    #[modulus = "7237005577332262213973186563042994240857116359379907606001950938285454250989"]
+    #[generator = "2"]
+    pub struct ScalarConfig;
+    // integers modulo l = 2^252 + 27742317777372353535851937790883648493
+    pub type Fr = Fp256<MontBackend<ScalarConfig, 4>>;
+}
+pub use scalar::Fr;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EdwardsPoint {
+    pub x: Fp,
+    pub y: Fp,
+}
+
+pub fn edwards_d() -> Fp {
+    -Fp::from(121665) / Fp::from(121666)
+}
+
+// the constant of the birational map, sqrt(-(A + 2)), with the sign chosen by RFC 7748
+pub fn sqrt_minus_a_plus_2() -> Fp {
+    let root = (-Fp::from(A + 2)).sqrt().unwrap();
+    if root.into_bigint().is_odd() {
+        -root
+    } else {
+        root
+    }
+}
+
+impl EdwardsPoint {
+    pub fn identity() -> EdwardsPoint {
+        EdwardsPoint {
+            x: Fp::ZERO,
+            y: Fp::ONE,
+        }
+    }
+
+    pub fn is_on_curve(&self) -> bool {
+        let (x2, y2) = (self.x.square(), self.y.square());
+        -x2 + y2 == Fp::ONE + edwards_d() * x2 * y2
+    }
+
+    // the point with y-coordinate `y` and the given parity of x, if any
+    pub fn from_y(y: Fp, x_is_odd: bool) -> Option<EdwardsPoint> {
+        let y2 = y.square();
+        let x = ((y2 - Fp::ONE) / (edwards_d() * y2 + Fp::ONE)).sqrt()?;
+        if x.is_zero() && x_is_odd {
+            return None;
+        }
+        let x = if x.into_bigint().is_odd() == x_is_odd {
+            x
+        } else {
+            -x
+        };
+        Some(EdwardsPoint { x, y })
+    }
+
+    // the base point B has y = 4/5 and an even x
+    pub fn base_point() -> EdwardsPoint {
+        EdwardsPoint::from_y(Fp::from(4) / Fp::from(5), false).unwrap()
+    }
+
+    // y in little-endian, the parity of x in the most significant bit
+    pub fn encode(&self) -> [u8; 32] {
+        let mut bytes = encode_u_coordinate(&self.y);
+        bytes[31] |= (self.x.into_bigint().is_odd() as u8) << 7;
+        bytes
+    }
+
+    pub fn decode(bytes: &[u8; 32]) -> Option<EdwardsPoint> {
+        let x_is_odd = bytes[31] >> 7 == 1;
+        let mut y_bytes = *bytes;
+        y_bytes[31] &= 0x7f;
+        let y = Fp::from_le_bytes_mod_order(&y_bytes);
+        if encode_u_coordinate(&y) != y_bytes {
+            return None; // y >= p
+        }
+        EdwardsPoint::from_y(y, x_is_odd)
+    }
+
+    // double-and-add, using your addition law
+    pub fn mul(&self, scalar: &Fr) -> EdwardsPoint {
+        self.mul_bits(&scalar.into_bigint().to_bits_be())
+    }
+
+    // lP = O; l itself cannot be a scalar of `mul` (it is 0 mod l), hence the multiplication by its bits
+    pub fn is_in_prime_order_subgroup(&self) -> bool {
+        self.is_on_curve() && self.mul_bits(&Fr::MODULUS.to_bits_be()) == EdwardsPoint::identity()
+    }
+
+    fn mul_bits(&self, bits: &[bool]) -> EdwardsPoint {
+        bits.iter().fold(EdwardsPoint::identity(), |acc, bit| {
+            let double = acc + acc;
+            if *bit {
+                double + *self
+            } else {
+                double
+            }
+        })
+    }
+}
+
+impl Neg for EdwardsPoint {
+    type Output = EdwardsPoint;
+
+    fn neg(self) -> EdwardsPoint {
+        EdwardsPoint {
+            x: -self.x,
+            y: self.y,
+        }
+    }
+}
+
+// Curve25519 with both coordinates: v^2 = u^3 + A u^2 + u
+// Its addition law is not complete: the formulas depend on the inputs
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MontgomeryPoint {
+    Infinity,
+    Affine(Fp, Fp),
+}
+
+impl MontgomeryPoint {
+    pub fn is_on_curve(&self) -> bool {
+        match *self {
+            MontgomeryPoint::Infinity => true,
+            MontgomeryPoint::Affine(u, v) => {
+                v.square() == u * (u.square() + Fp::from(A) * u + Fp::ONE)
+            }
+        }
+    }
+
+    pub fn add(&self, other: &MontgomeryPoint) -> MontgomeryPoint {
+        let (u1, v1, u2, v2) = match (*self, *other) {
+            (MontgomeryPoint::Infinity, _) => return *other,
+            (_, MontgomeryPoint::Infinity) => return *self,
+            (MontgomeryPoint::Affine(u1, v1), MontgomeryPoint::Affine(u2, v2)) => (u1, v1, u2, v2),
+        };
+        let slope = if u1 != u2 {
+            (v2 - v1) / (u2 - u1)
+        } else if v1 == v2 && !v1.is_zero() {
+            (Fp::from(3) * u1.square() + Fp::from(2 * A) * u1 + Fp::ONE) / v1.double()
+        } else {
+            return MontgomeryPoint::Infinity;
+        };
+        let u3 = slope.square() - Fp::from(A) - u1 - u2;
+        MontgomeryPoint::Affine(u3, slope * (u1 - u3) - v1)
+    }
+}
+
+// SHA-512 of the concatenated inputs, as a little-endian integer reduced mod l
+pub fn hash_to_scalar(data: &[&[u8]]) -> Fr {
+    let digest = data
+        .iter()
+        .fold(Sha512::new(), |hasher, chunk| hasher.chain_update(chunk))
+        .finalize();
+    Fr::from_le_bytes_mod_order(&digest)
+}
+
+// RFC 8032: the secret scalar s (the clamped first half of SHA-512(secret key)) and the nonce prefix (the second half)
+pub fn expand_secret_key(secret_key: &[u8; 32]) -> (Scalar<Fr>, [u8; 32]) {
+    let digest = Sha512::digest(secret_key);
+    let mut scalar: [u8; 32] = digest[..32].try_into().unwrap();
+    scalar[0] &= 0xf8;
+    scalar[31] &= 0x7f;
+    scalar[31] |= 0x40;
+    (
+        Scalar::new(Fr::from_le_bytes_mod_order(&scalar)),
+        digest[32..].try_into().unwrap(),
+    )
+}
@@ -0,0 +1,84 @@
+use runner::lang::{Catalog, Lang, Statements};
+
+pub const CATALOG: Catalog = Catalog {
+    lang: Lang::Fr,
+    statements: Statements::Table(&[
+        ("Q1", "écrire `clamp`, qui transforme 32 octets aléatoires en scalaire X25519 (RFC 7748 section 5) :\nmettre à zéro les 3 bits de poids faible et le bit de poids fort, et mettre à un le bit suivant"),
+        ("Q2", "écrire `ladder`, l'échelle de Montgomery qui calcule u(kP) à partir de u(P) (RFC 7748 section 5)\nLe scalaire est traité bit par bit du bit 254 au bit 0, avec les mêmes opérations quel que soit le bit"),
+        ("Q3", "écrire `x25519`, qui « clampe » le scalaire, décode u, exécute l'échelle et encode le résultat"),
+        ("Q4", "la courbe est d'ordre 8 * l pour un premier l : pourquoi la mise à zéro des 3 bits de poids faible protège-t-elle\ndes attaques par petit sous-groupe ? Pourquoi le bit 254 à un aide-t-il les implémentations en temps constant ?"),
+        ("Q5", "x25519 accepte n'importe quel u, même la coordonnée u d'un point du twist quadratique :\npourquoi est-ce sans danger pour Curve25519 et pas pour toute courbe ?"),
+        ("Q6", "comparer avec les coordonnées jacobiennes de ff-ec : pourquoi les coordonnées (X : Z) suffisent-elles ici ?"),
+        ("Q7", "implémenter la loi d'addition complète de la courbe d'Edwards tordue -x^2 + y^2 = 1 + d x^2 y^2 :\nx3 = (x1 y2 + y1 x2) / (1 + d x1 x2 y1 y2), y3 = (y1 y2 + x1 x2) / (1 - d x1 x2 y1 y2)"),
+        ("Q8", "écrire `to_montgomery` et `to_edwards`, les applications birationnelles entre les deux formes\nLes points exceptionnels sont le neutre (0, 1) -> l'infini et (0, -1) -> (0, 0)"),
+        ("Q9", "écrire `ed25519_sign` et `ed25519_verify` (RFC 8032 section 5.1), vérifiées sur les vecteurs de test de la RFC\n- la clé publique est A = sB, avec (s, prefix) donnés par `expand_secret_key`\n- r = H(prefix || M), R = rB, k = H(R || A || M) et S = r + k s mod l, la signature est R || S\n- le vérifieur contrôle que S < l et SB = R + kA"),
+        ("Q10", "la courbe est d'ordre 8l : qu'est-ce qui peut mal tourner avec des points de petit ordre, et pourquoi certains vérifieurs\ncontrôlent-ils 8SB = 8R + 8kA à la place ? (voir « Taming the many EdDSAs »)\n`EdwardsPoint::is_in_prime_order_subgroup` rejette les points qui ont une composante de petit ordre :"),
+        ("Q11", "que coûte-t-elle par rapport à une vérification de signature ? Voir number-theory pour un test moins cher avec les couplages"),
+        ("Q12", "écrire `twist_point_of_order`, qui renvoie la coordonnée u d'un point d'ordre premier r sur le twist"),
+        ("Q13", "écrire `twist_attack`, qui retrouve la clé secrète de la victime avec une requête par facteur de #E'\nChaque réponse donne k mod r au signe près : essayer les combinaisons contre la clé publique"),
+        ("Q14", "écrire `validated_ladder`, la correction : elle rejette les u qui ne sont pas sur la courbe, et ceux des\npoints de petit ordre de la courbe elle-même (dont les multiples par le cofacteur 4 sont le point à l'infini)"),
+        ("Q15", "l'ordre du twist de Curve25519 est 4 fois un premier : quels bits de k l'attaque y révèle-t-elle ?"),
+        ("Q16", "écrire `ecies_encrypt`, qui tire une clé éphémère et renvoie E || message scellé || tag"),
+        ("Q17", "écrire `ecies_decrypt`, qui renvoie None pour un chiffré trop court, un tag qui ne correspond pas,\nou une clé éphémère de petit ordre, dont le secret partagé est nul"),
+        ("Q18", "écrire `maul`, qui transforme un chiffré en un autre chiffré du même message, sans aucune\nclé : l'AEAD protège le message scellé, qu'en est-il de la clé éphémère ?"),
+        ("Q19", "un message a maintenant plusieurs chiffrés : qu'est-ce que cela casse (un cache anti-rejeu, une signature ou un haché du\nchiffré, la sécurité à chiffrés choisis) ? Pourquoi hacher les octets de E tels que reçus corrige-t-il le problème, plutôt\nque la coordonnée u que décode l'échelle, et pourquoi hacher R aussi ?"),
+    ]),
+    hints: &[
+        (
+            "Q2",
+            &[
+                "L'échelle garde deux points dont la différence vaut toujours P",
+                "Pour chaque bit, échanger la paire selon le bit, faire une addition différentielle et un doublement, puis échanger à nouveau",
+                "Suivre le pseudo-code de la RFC 7748 section 5 avec a24 = 121665, et renvoyer x_2 / z_2",
+            ],
+        ),
+        (
+            "Q5",
+            &[
+                "Un u qui n'est pas sur la courbe est sur son twist quadratique, où l'échelle calcule aussi",
+                "Qu'un attaquant peut-il apprendre de k P' avec P' de petit ordre sur le twist ?",
+                "Le twist de Curve25519 est d'ordre 4 fois un grand premier : il n'a pas de petit sous-groupe utile",
+            ],
+        ),
+        (
+            "Q12",
+            &[
+                "u est sur le twist si et seulement si u^3 + A u^2 + u n'est pas un carré",
+                "L'échelle calcule aussi sur le twist : multiplier par le cofacteur #E' / r élimine les autres facteurs",
+                "Pour u = 2, 3, ... sur le twist, renvoyer ladder(#E' / r, u) dès qu'il n'est pas le point à l'infini",
+            ],
+        ),
+        (
+            "Q13",
+            &[
+                "La réponse de la victime à un point d'ordre r est u(k P'), qui ne dépend que de k mod r",
+                "Trouver i dans [0, r) tel que ladder(i, u) soit la réponse : k = i ou -i mod r",
+                "Recoller les résidus avec `crt_primes`, en essayant chaque combinaison de signes contre la clé publique",
+            ],
+        ),
+        (
+            "Q16",
+            &[
+                "Tirer un secret éphémère e avec le rng : E = X25519(e, 9) est envoyé, X25519(e, R) est le secret partagé",
+                "La clé est `hkdf_sha256` du secret partagé, avec un sel vide et ECIES_INFO || E || R comme info",
+                "Sceller le message avec `aead_seal` sous cette clé, un nonce nul et sans données associées, puis préfixer E",
+            ],
+        ),
+        (
+            "Q17",
+            &[
+                "Séparer E du message scellé, qui fait au moins `TAG_SIZE` octets",
+                "Le secret partagé est X25519(r, E), et la clé publique R = X25519(r, 9) entre aussi dans l'info",
+                "Rejeter un secret partagé nul avant de dériver la clé, puis laisser `aead_open` vérifier le tag",
+            ],
+        ),
+        (
+            "Q18",
+            &[
+                "Sans E dans la dérivation de clé, tout E' donnant le même secret partagé déchiffre le même message scellé",
+                "Comment `decode_u_coordinate` lit-elle les 32 octets de E ?",
+                "Le bit de poids fort de u est ignoré : l'inverser",
+            ],
+        ),
+    ],
+};
@@ -0,0 +1,66 @@
+use runner::hints::Hints;
+use runner::lang::Catalog;
+
+pub const CATALOGS: &[Catalog] = &[
+    Catalog::english(HINTS, include_str!("main.rs")),
+    crate::fr::CATALOG,
+];
+
+pub const HINTS: Hints = &[
+    (
+        "Q2",
+        &[
+            "The ladder keeps two points whose difference is always P",
+            "For each bit, swap the pair according to the bit, do one differential addition and one doubling, swap back",
+            "Follow the pseudo-code of RFC 7748 section 5 with a24 = 121665, and return x_2 / z_2",
+        ],
+    ),
+    (
+        "Q5",
+        &[
+            "A u which is not on the curve lies on its quadratic twist, where the ladder computes too",
+            "What can an attacker learn from k P' with P' of small order on the twist?",
+            "The twist of Curve25519 has order 4 times a large prime: it has no useful small subgroup",
+        ],
+    ),
+    (
+        "Q12",
+        &[
+            "u is on the twist iff u^3 + A u^2 + u is not a square",
+            "The ladder computes on the twist too: multiplying by the cofactor #E' / r kills the other factors",
+            "For u = 2, 3, ... on the twist, return ladder(#E' / r, u) as soon as it is not the point at infinity",
+        ],
+    ),
+    (
+        "Q13",
+        &[
+            "The victim's answer to a point of order r is u(k P'), which only depends on k mod r",
+            "Find i in [0, r) with ladder(i, u) equal to the answer: k = i or -i mod r",
+            "Glue the residues with `crt_primes`, trying every combination of signs against the public key",
+        ],
+    ),
+    (
+        "Q16",
+        &[
+            "Draw an ephemeral secret e with the rng: E = X25519(e, 9) is sent, X25519(e, R) is the shared secret",
+            "The key is `hkdf_sha256` of the shared secret, with an empty salt and ECIES_INFO || E || R as info",
+            "Seal the message with `aead_seal` under this key, a zero nonce and no associated data, and prepend E",
+        ],
+    ),
+    (
+        "Q17",
+        &[
+            "Split E from the sealed message, which is at least `TAG_SIZE` bytes long",
+            "The shared secret is X25519(r, E), and the public key R = X25519(r, 9) goes into the info too",
+            "Reject an all-zero shared secret before deriving the key, then let `aead_open` check the tag",
+        ],
+    ),
+    (
+        "Q18",
+        &[
+            "Without E in the key derivation, any E' with the same shared secret decrypts the same sealed message",
+            "How does `decode_u_coordinate` read the 32 bytes of E?",
+            "The most significant bit of u is ignored: flip it",
+        ],
+    ),
+];
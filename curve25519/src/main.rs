@@ -0,0 +1,602 @@
+use ark_ff::{BigInteger, Field, PrimeField, Zero};
+use ecies::*;
+use edwards::*;
+use montgomery::*;
+use rand::Rng;
+use runner::Runner;
+use std::ops::Add;
+use training_common::protocol_sim::{duplex, Channel};
+use twist::*;
+
+mod ecies;
+mod edwards;
+mod fr;
+mod hints;
+mod montgomery;
+mod twist;
+
+fn main() {
+    let runner = Runner::start(env!("CARGO_PKG_NAME"), hints::CATALOGS);
+    let mut rng = seed::rng();
+
+    // Take a look at module `montgomery`
+    // Q1: write `clamp`, which turns 32 random bytes into an X25519 scalar (RFC 7748 section 5):
+    // clear the 3 lowest bits, clear the highest bit and set the second highest one
+    runner.question("Q1");
+    let clamped = clamp(&[0xff; 32]);
+    assert_eq!(clamped[0], 0xf8);
+    assert_eq!(clamped[31], 0x7f);
+    assert_eq!(clamp(&[0; 32])[31], 0x40);
+
+    // Q2: write `ladder`, the Montgomery ladder computing u(kP) from u(P) (RFC 7748 section 5)
+    // The scalar is processed bit by bit from bit 254 down to 0, with the same operations whatever the bit
+    // Q3: write `x25519`, which clamps the scalar, decodes u, runs the ladder and encodes the result
+    runner.questions(&["Q2", "Q3"]);
+    let vectors = [
+        (
+            "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+            "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+            "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552",
+        ),
+        (
+            "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+            "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493",
+            "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957",
+        ),
+    ];
+    for (scalar, u, expected) in vectors {
+        assert_eq!(hex(&x25519(&from_hex(scalar), &from_hex(u))), expected);
+    }
+
+    // The iterated test: k, u := X25519(k, u), k
+    let mut k = BASE_POINT;
+    let mut u = BASE_POINT;
+    for i in 1..=1000 {
+        let output = x25519(&k, &u);
+        u = k;
+        k = output;
+        if i == 1 {
+            assert_eq!(
+                hex(&k),
+                "422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079"
+            );
+        }
+    }
+    assert_eq!(
+        hex(&k),
+        "684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51"
+    );
+
+    // The Diffie-Hellman test of section 6.1, Alice and Bob exchanging their public keys over a link of the
+    // training-common crate (module `protocol_sim`)
+    let alice_sk = from_hex("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+    let bob_sk = from_hex("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+    let (mut alice_end, mut bob_end) = duplex(0, 1);
+    alice_end.send(x25519(&alice_sk, &BASE_POINT));
+    bob_end.send(x25519(&bob_sk, &BASE_POINT));
+    let (Some(bob_pk), Some(alice_pk)) = (alice_end.receive(), bob_end.receive()) else {
+        panic!("the link is reliable")
+    };
+    assert_eq!(
+        hex(&alice_pk),
+        "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"
+    );
+    assert_eq!(
+        hex(&bob_pk),
+        "de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f"
+    );
+    let shared = x25519(&alice_sk, &bob_pk);
+    assert_eq!(shared, x25519(&bob_sk, &alice_pk));
+    assert_eq!(
+        hex(&shared),
+        "4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742"
+    );
+    // an eavesdropper on the link saw both public keys, and not the shared secret
+    let transcript = alice_end.transcript();
+    assert_eq!(transcript.len(), 2);
+    assert!(transcript.messages.iter().all(|(_, _, key)| *key != shared));
+
+    // Any random 32 bytes make a valid secret key
+    for _ in 0..10 {
+        let (a, b): ([u8; 32], [u8; 32]) = (rng.gen(), rng.gen());
+        let (a_pk, b_pk) = (x25519(&a, &BASE_POINT), x25519(&b, &BASE_POINT));
+        assert_eq!(x25519(&a, &b_pk), x25519(&b, &a_pk));
+    }
+
+    // Q4: the curve has order 8 * l for a prime l: why does clearing the 3 lowest bits protect
+    // against small-subgroup attacks? Why does setting bit 254 help constant-time implementations?
+    // Q5: x25519 accepts any u, even if it is the u-coordinate of a point of the quadratic twist:
+    // why is it fine for Curve25519 and not for every curve?
+    // Q6: compare with the Jacobian coordinates of ff-ec: why are (X : Z) coordinates enough here?
+    runner.questions(&["Q4", "Q5", "Q6"]);
+
+    // Now take a look at module `edwards`
+    // Q7: implement the complete addition law of the twisted Edwards curve -x^2 + y^2 = 1 + d x^2 y^2:
+    // x3 = (x1 y2 + y1 x2) / (1 + d x1 x2 y1 y2), y3 = (y1 y2 + x1 x2) / (1 - d x1 x2 y1 y2)
+    runner.question("Q7");
+    let base = EdwardsPoint::base_point();
+    assert!(base.is_on_curve());
+    assert_eq!(base + EdwardsPoint::identity(), base);
+    assert_eq!(base + -base, EdwardsPoint::identity());
+    // B has prime order l
+    assert_eq!(base.mul(&-Fr::from(1)) + base, EdwardsPoint::identity());
+    let points: Vec<EdwardsPoint> = (0..20)
+        .map(|_| base.mul(&Fr::from_le_bytes_mod_order(&rng.gen::<[u8; 32]>())))
+        .collect();
+    for pair in points.windows(3) {
+        let (p, q, r) = (pair[0], pair[1], pair[2]);
+        assert!((p + q).is_on_curve());
+        assert_eq!(p + q, q + p);
+        assert_eq!((p + q) + r, p + (q + r));
+        assert_eq!(p + p, p.mul(&Fr::from(2))); // no special case for doubling
+    }
+
+    // Q8: write `to_montgomery` and `to_edwards`, the birational maps between both forms
+    // The exceptional points are the neutral element (0, 1) -> infinity and (0, -1) -> (0, 0)
+    runner.question("Q8");
+    assert!(matches!(to_montgomery(&base), MontgomeryPoint::Affine(u, _) if u == Fp::from(9)));
+    for pair in points.windows(2) {
+        let (p, q) = (pair[0], pair[1]);
+        let (mp, mq) = (to_montgomery(&p), to_montgomery(&q));
+        assert!(mp.is_on_curve());
+        assert_eq!(to_edwards(&mp), p);
+        // the map is a group homomorphism
+        assert_eq!(to_montgomery(&(p + q)), mp.add(&mq));
+        assert_eq!(to_montgomery(&(p + p)), mp.add(&mp));
+        assert_eq!(to_montgomery(&(p + -p)), MontgomeryPoint::Infinity);
+    }
+    let two_torsion = EdwardsPoint {
+        x: Fp::ZERO,
+        y: -Fp::ONE,
+    };
+    assert_eq!(
+        to_montgomery(&two_torsion),
+        MontgomeryPoint::Affine(Fp::ZERO, Fp::ZERO)
+    );
+    assert_eq!(
+        to_edwards(&MontgomeryPoint::Infinity),
+        EdwardsPoint::identity()
+    );
+    // X25519 and the Edwards scalar multiplication agree on u-coordinates
+    let k = clamp(&rng.gen());
+    let MontgomeryPoint::Affine(u, _) = to_montgomery(&base.mul(&Fr::from_le_bytes_mod_order(&k)))
+    else {
+        panic!("kB is not the neutral element");
+    };
+    assert_eq!(x25519(&k, &BASE_POINT), encode_u_coordinate(&u));
+
+    // Q9: write `ed25519_sign` and `ed25519_verify` (RFC 8032 section 5.1), checked against the RFC test vectors
+    // - the public key is A = sB, with (s, prefix) given by `expand_secret_key`
+    // - r = H(prefix || M), R = rB, k = H(R || A || M) and S = r + k s mod l, the signature is R || S
+    // - the verifier checks that S < l and SB = R + kA
+    runner.question("Q9");
+    let vectors = [
+        (
+            "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            "",
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+        ),
+        (
+            "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+            "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+            "72",
+            "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+        ),
+    ];
+    for (secret_key, public_key, message, signature) in vectors {
+        let message: Vec<u8> = (0..message.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&message[i..i + 2], 16).unwrap())
+            .collect();
+        let (computed_pk, computed_signature) = ed25519_sign(&from_hex(secret_key), &message);
+        assert_eq!(hex(&computed_pk), public_key);
+        assert_eq!(hex(&computed_signature), signature);
+        assert!(ed25519_verify(&computed_pk, &message, &computed_signature));
+        assert!(!ed25519_verify(
+            &computed_pk,
+            b"another message",
+            &computed_signature
+        ));
+        // S + l is a different encoding of the same scalar, it must be rejected
+        let mut malleated = computed_signature;
+        let mut carry = 0;
+        for (byte, l_byte) in malleated[32..].iter_mut().zip(Fr::MODULUS.to_bytes_le()) {
+            let sum = *byte as u16 + l_byte as u16 + carry;
+            *byte = sum as u8;
+            carry = sum >> 8;
+        }
+        assert!(!ed25519_verify(&computed_pk, &message, &malleated));
+    }
+    // Q10: the curve has order 8l: what could go wrong with points of small order, and why do some verifiers
+    // check 8SB = 8R + 8kA instead? (see "Taming the many EdDSAs")
+    // `EdwardsPoint::is_in_prime_order_subgroup` rejects the points with a small order component:
+    runner.question("Q10");
+    let torsion = EdwardsPoint::from_y(-Fp::ONE, false).unwrap(); // (0, -1) has order 2
+    assert!(base.is_in_prime_order_subgroup());
+    assert!(EdwardsPoint::identity().is_in_prime_order_subgroup());
+    assert!(!torsion.is_in_prime_order_subgroup());
+    assert!(!(base + torsion).is_in_prime_order_subgroup());
+    // Q11: what does it cost compared to a signature verification? See number-theory for a cheaper check with pairings
+    runner.question("Q11");
+
+    // Back to Q5, with a curve whose twist is weak: take a look at module `twist`
+    let curve = toy_curve();
+    let base = curve.base_point();
+    assert!(curve.is_on_curve(base));
+    assert_eq!(curve.ladder(TOY_ORDER / 4, base), None);
+    let mut victim = Victim::new(curve, false, &mut rng);
+    let alice = rng.gen_range(1..TOY_ORDER / 4);
+    assert_eq!(
+        victim.respond(curve.ladder(alice, base).unwrap()),
+        curve.ladder(alice, victim.public_key)
+    );
+    // Q12: write `twist_point_of_order`, which returns the u-coordinate of a point of prime order r on the twist
+    runner.question("Q12");
+    for r in TOY_TWIST_FACTORS {
+        let u = twist_point_of_order(&curve, r, &mut rng);
+        assert!(!curve.is_on_curve(u));
+        assert!(curve.ladder(1, u).is_some());
+        assert_eq!(curve.ladder(r, u), None);
+    }
+    // Q13: write `twist_attack`, which recovers the secret key of the victim with one query per factor of #E'
+    // Each answer gives k mod r up to the sign: try the combinations against the public key
+    runner.question("Q13");
+    let mut victim = Victim::new(curve, false, &mut rng);
+    let k = twist_attack(&mut victim, &mut rng).unwrap();
+    assert!(victim.is_secret(k));
+    assert_eq!(victim.queries, TOY_TWIST_FACTORS.len());
+    println!(
+        "Secret key recovered from {} points of the twist",
+        victim.queries
+    );
+    // Q14: write `validated_ladder`, the fix: it rejects the u which are not on the curve, and those of the
+    // points of small order of the curve itself (whose multiples by the cofactor 4 are the point at infinity)
+    runner.question("Q14");
+    let mut victim = Victim::new(curve, true, &mut rng);
+    assert_eq!(
+        victim.respond(curve.ladder(alice, base).unwrap()),
+        curve.ladder(alice, victim.public_key)
+    );
+    assert_eq!(victim.respond(0), None); // (0, 0) has order 2
+    for r in TOY_TWIST_FACTORS {
+        assert_eq!(
+            victim.respond(twist_point_of_order(&curve, r, &mut rng)),
+            None
+        );
+    }
+    assert_eq!(twist_attack(&mut victim, &mut rng), None);
+    // Q15: the order of the twist of Curve25519 is 4 times a prime: which bits of k does the attack reveal there?
+    runner.question("Q15");
+
+    // Now take a look at module `ecies`, whose ChaCha20-Poly1305 is checked against RFC 8439 section 2.8.2
+    let key: [u8; 32] = std::array::from_fn(|i| 0x80 + i as u8);
+    let nonce = [7, 0, 0, 0, 0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47];
+    let aad = [
+        0x50, 0x51, 0x52, 0x53, 0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7,
+    ];
+    let plaintext = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, \
+        sunscreen would be it.";
+    let sealed = aead_seal(&key, &nonce, &aad, plaintext);
+    assert_eq!(hex(&sealed[..16]), "d31a8d34648e60db7b86afbc53ef7ec2");
+    assert_eq!(
+        hex(&sealed[plaintext.len()..]),
+        "1ae10b594f09e26a7e902ecbd0600691"
+    );
+    assert_eq!(aead_open(&key, &nonce, &aad, &sealed).unwrap(), plaintext);
+    assert_eq!(aead_open(&key, &nonce, &[], &sealed), None);
+
+    // Q16: write `ecies_encrypt`, which draws an ephemeral key and returns E || sealed message || tag
+    // Q17: write `ecies_decrypt`, which returns None for a ciphertext too short, a tag which does not match,
+    // or an ephemeral key of small order, whose shared secret is all zeros
+    runner.questions(&["Q16", "Q17"]);
+    let alice_sk = from_hex(RFC7748_ALICE_SECRET_KEY);
+    let alice_pk = x25519(&alice_sk, &BASE_POINT);
+    let ciphertext = bytes_from_hex(ECIES_CIPHERTEXT);
+    assert_eq!(
+        ecies_decrypt(&alice_sk, &ciphertext).unwrap(),
+        ECIES_MESSAGE
+    );
+    for length in [0, 1, 63, 64, 65, 200] {
+        let secret_key: [u8; 32] = rng.gen();
+        let public_key = x25519(&secret_key, &BASE_POINT);
+        let message: Vec<u8> = (0..length).map(|_| rng.gen()).collect();
+        let ciphertext = ecies_encrypt(&public_key, &message, &mut rng);
+        assert_eq!(ciphertext.len(), 32 + length + TAG_SIZE);
+        assert_ne!(ciphertext, ecies_encrypt(&public_key, &message, &mut rng));
+        assert_eq!(ecies_decrypt(&secret_key, &ciphertext).unwrap(), message);
+        assert_eq!(ecies_decrypt(&rng.gen(), &ciphertext), None);
+        // flipping any bit, those of the ephemeral key included, gets the ciphertext rejected
+        for _ in 0..20 {
+            let mut tampered = ciphertext.clone();
+            let i = rng.gen_range(0..tampered.len() * 8);
+            tampered[i / 8] ^= 1 << (i % 8);
+            assert_eq!(ecies_decrypt(&secret_key, &tampered), None);
+        }
+        assert_eq!(
+            ecies_decrypt(&secret_key, &ciphertext[..ciphertext.len() - 1]),
+            None
+        );
+    }
+    assert_eq!(ecies_decrypt(&alice_sk, &[0; 32 + TAG_SIZE - 1]), None);
+    // u = 0 and u = 1 have order 2 and 4: the shared secret is zero whatever the secret key, and so is the key
+    for u in [0, 1] {
+        let mut ephemeral = [0u8; 32];
+        ephemeral[0] = u;
+        let info = [ECIES_INFO, &ephemeral, &alice_pk].concat();
+        let key = hkdf_sha256(&[], &[0; 32], &info);
+        let forged = [ephemeral.to_vec(), aead_seal(&key, &[0; 12], &[], b"")].concat();
+        assert_eq!(ecies_decrypt(&alice_sk, &forged), None);
+    }
+
+    // A careless implementation derives the key from the shared secret alone:
+    let encrypt_unbound = |recipient: &[u8; 32], message: &[u8], ephemeral_secret: &[u8; 32]| {
+        let shared = x25519(ephemeral_secret, recipient);
+        let key = hkdf_sha256(&[], &shared, ECIES_INFO);
+        let ephemeral = x25519(ephemeral_secret, &BASE_POINT);
+        [ephemeral.to_vec(), aead_seal(&key, &[0; 12], &[], message)].concat()
+    };
+    let decrypt_unbound = |secret_key: &[u8; 32], ciphertext: &[u8]| {
+        let (ephemeral, sealed) = ciphertext.split_at(32);
+        let shared = x25519(secret_key, ephemeral.try_into().unwrap());
+        aead_open(
+            &hkdf_sha256(&[], &shared, ECIES_INFO),
+            &[0; 12],
+            &[],
+            sealed,
+        )
+    };
+    // Q18: write `maul`, which turns a ciphertext into a different ciphertext of the same message, without any
+    // key: the AEAD protects the sealed message, what about the ephemeral key?
+    runner.question("Q18");
+    for _ in 0..10 {
+        let secret_key: [u8; 32] = rng.gen();
+        let public_key = x25519(&secret_key, &BASE_POINT);
+        let ciphertext = encrypt_unbound(&public_key, ECIES_MESSAGE, &rng.gen());
+        let mauled = maul(&ciphertext);
+        assert_ne!(mauled, ciphertext);
+        assert_eq!(
+            decrypt_unbound(&secret_key, &mauled).unwrap(),
+            ECIES_MESSAGE
+        );
+        // binding E into the key derivation gets the mauled ciphertext rejected
+        let ciphertext = ecies_encrypt(&public_key, ECIES_MESSAGE, &mut rng);
+        assert_eq!(ecies_decrypt(&secret_key, &maul(&ciphertext)), None);
+    }
+    // Q19: a message now has several ciphertexts: what does it break (a replay cache, a signature or a hash of
+    // the ciphertext, chosen-ciphertext security)? Why does hashing the bytes of E as received fix it, rather
+    // than the u-coordinate the ladder decodes, and why hash R too?
+    runner.question("Q19");
+
+    println!("Good job!");
+    runner.finish();
+}
+
+fn clamp(scalar: &[u8; 32]) -> [u8; 32] {
+    // SOLUTION-BEGIN
+    let mut clamped = *scalar;
+    clamped[0] &= 0xf8;
+    clamped[31] &= 0x7f;
+    clamped[31] |= 0x40;
+    clamped
+    // SOLUTION-END
+}
+
+fn ladder(k: &[u8; 32], u: Fp) -> Fp {
+    // SOLUTION-BEGIN
+    let a24 = Fp::from(A24);
+    let x1 = u;
+    let (mut x2, mut z2) = (Fp::ONE, Fp::ZERO);
+    let (mut x3, mut z3) = (u, Fp::ONE);
+    let mut swap = 0;
+    for t in (0..255).rev() {
+        let k_t = (k[t / 8] >> (t % 8)) & 1;
+        swap ^= k_t;
+        conditional_swap(swap, &mut x2, &mut x3);
+        conditional_swap(swap, &mut z2, &mut z3);
+        swap = k_t;
+        let a = x2 + z2;
+        let aa = a.square();
+        let b = x2 - z2;
+        let bb = b.square();
+        let e = aa - bb;
+        let c = x3 + z3;
+        let d = x3 - z3;
+        let da = d * a;
+        let cb = c * b;
+        x3 = (da + cb).square();
+        z3 = x1 * (da - cb).square();
+        x2 = aa * bb;
+        z2 = e * (aa + a24 * e);
+    }
+    conditional_swap(swap, &mut x2, &mut x3);
+    conditional_swap(swap, &mut z2, &mut z3);
+    // z2^(p - 2) = 1 / z2, and 0 for the point at infinity
+    let mut p_minus_two = Fp::MODULUS;
+    p_minus_two.sub_with_borrow(&2u64.into());
+    x2 * z2.pow(p_minus_two)
+    // SOLUTION-END
+}
+
+fn x25519(k: &[u8; 32], u: &[u8; 32]) -> [u8; 32] {
+    // SOLUTION-BEGIN
+    encode_u_coordinate(&ladder(&clamp(k), decode_u_coordinate(u)))
+    // SOLUTION-END
+}
+
+impl Add for EdwardsPoint {
+    type Output = EdwardsPoint;
+
+    fn add(self, other: EdwardsPoint) -> EdwardsPoint {
+        // SOLUTION-BEGIN
+        let t = edwards_d() * self.x * other.x * self.y * other.y;
+        EdwardsPoint {
+            x: (self.x * other.y + self.y * other.x) / (Fp::ONE + t),
+            y: (self.y * other.y + self.x * other.x) / (Fp::ONE - t),
+        }
+        // SOLUTION-END
+    }
+}
+
+fn to_montgomery(point: &EdwardsPoint) -> MontgomeryPoint {
+    // SOLUTION-BEGIN
+    if *point == EdwardsPoint::identity() {
+        return MontgomeryPoint::Infinity;
+    }
+    if point.x.is_zero() {
+        return MontgomeryPoint::Affine(Fp::ZERO, Fp::ZERO); // (0, -1)
+    }
+    let u = (Fp::ONE + point.y) / (Fp::ONE - point.y);
+    MontgomeryPoint::Affine(u, sqrt_minus_a_plus_2() * u / point.x)
+    // SOLUTION-END
+}
+
+fn to_edwards(point: &MontgomeryPoint) -> EdwardsPoint {
+    // SOLUTION-BEGIN
+    match *point {
+        MontgomeryPoint::Infinity => EdwardsPoint::identity(),
+        MontgomeryPoint::Affine(u, v) if v.is_zero() => {
+            assert!(
+                u.is_zero(),
+                "the other points of order 2 have no Edwards counterpart"
+            );
+            EdwardsPoint {
+                x: Fp::ZERO,
+                y: -Fp::ONE,
+            }
+        }
+        MontgomeryPoint::Affine(u, v) => EdwardsPoint {
+            x: sqrt_minus_a_plus_2() * u / v,
+            y: (u - Fp::ONE) / (u + Fp::ONE),
+        },
+    }
+    // SOLUTION-END
+}
+
+// returns the public key and the signature
+fn ed25519_sign(secret_key: &[u8; 32], message: &[u8]) -> ([u8; 32], [u8; 64]) {
+    // SOLUTION-BEGIN
+    let (s, prefix) = expand_secret_key(secret_key);
+    let base = EdwardsPoint::base_point();
+    let public_key = base.mul(s.expose()).encode();
+    let r = hash_to_scalar(&[&prefix, message]);
+    let big_r = base.mul(&r).encode();
+    let k = hash_to_scalar(&[&big_r, &public_key, message]);
+    let mut signature = [0u8; 64];
+    signature[..32].copy_from_slice(&big_r);
+    signature[32..].copy_from_slice(&(r + k * s.expose()).into_bigint().to_bytes_le());
+    (public_key, signature)
+    // SOLUTION-END
+}
+
+fn ed25519_verify(public_key: &[u8; 32], message: &[u8], signature: &[u8; 64]) -> bool {
+    // SOLUTION-BEGIN
+    let s = Fr::from_le_bytes_mod_order(&signature[32..]);
+    if s.into_bigint().to_bytes_le() != signature[32..] {
+        return false; // S >= l
+    }
+    let big_r_bytes: [u8; 32] = signature[..32].try_into().unwrap();
+    let (Some(big_r), Some(a)) = (
+        EdwardsPoint::decode(&big_r_bytes),
+        EdwardsPoint::decode(public_key),
+    ) else {
+        return false;
+    };
+    let k = hash_to_scalar(&[&big_r_bytes, public_key, message]);
+    EdwardsPoint::base_point().mul(&s) == big_r + a.mul(&k)
+    // SOLUTION-END
+}
+
+fn twist_point_of_order<R: Rng>(curve: &ToyMontgomeryCurve, r: u64, rng: &mut R) -> u64 {
+    // SOLUTION-BEGIN
+    loop {
+        let u = rng.gen_range(0..curve.p);
+        if curve.is_on_curve(u) {
+            continue;
+        }
+        if let Some(point) = curve.ladder(TOY_TWIST_ORDER / r, u) {
+            return point;
+        }
+    }
+    // SOLUTION-END
+}
+
+fn twist_attack<R: Rng>(victim: &mut Victim, rng: &mut R) -> Option<u64> {
+    // SOLUTION-BEGIN
+    let curve = victim.curve;
+    // k P' for P' of order r is i P' for i = k mod r, or i = -k mod r
+    let residues = TOY_TWIST_FACTORS
+        .iter()
+        .map(|r| {
+            let u = twist_point_of_order(&curve, *r, rng);
+            let answer = victim.respond(u);
+            (0..*r).find(|i| curve.ladder(*i, u) == answer)
+        })
+        .collect::<Option<Vec<u64>>>()?;
+    let modulus: u64 = TOY_TWIST_FACTORS.iter().product();
+    let base = curve.base_point();
+    (0..1u32 << residues.len()).find_map(|signs| {
+        let signed: Vec<u64> = residues
+            .iter()
+            .zip(TOY_TWIST_FACTORS)
+            .enumerate()
+            .map(|(j, (i, r))| {
+                if (signs >> j) & 1 == 1 {
+                    (r - i) % r
+                } else {
+                    *i
+                }
+            })
+            .collect();
+        // the product of the factors is a bit smaller than the order of the base point
+        let x = crt_primes(&signed, &TOY_TWIST_FACTORS);
+        (x..TOY_ORDER / 4)
+            .step_by(modulus as usize)
+            .find(|k| curve.ladder(*k, base) == Some(victim.public_key))
+    })
+    // SOLUTION-END
+}
+
+fn validated_ladder(curve: &ToyMontgomeryCurve, k: u64, u: u64) -> Option<u64> {
+    // SOLUTION-BEGIN
+    if !curve.is_on_curve(u) || curve.ladder(4, u).is_none() {
+        return None;
+    }
+    curve.ladder(k, u)
+    // SOLUTION-END
+}
+
+fn ecies_encrypt<R: Rng>(recipient: &[u8; 32], message: &[u8], rng: &mut R) -> Vec<u8> {
+    // SOLUTION-BEGIN
+    let ephemeral_secret: [u8; 32] = rng.gen();
+    let ephemeral = x25519(&ephemeral_secret, &BASE_POINT);
+    let shared = x25519(&ephemeral_secret, recipient);
+    let key = hkdf_sha256(&[], &shared, &[ECIES_INFO, &ephemeral, recipient].concat());
+    [ephemeral.to_vec(), aead_seal(&key, &[0; 12], &[], message)].concat()
+    // SOLUTION-END
+}
+
+fn ecies_decrypt(secret_key: &[u8; 32], ciphertext: &[u8]) -> Option<Vec<u8>> {
+    // SOLUTION-BEGIN
+    if ciphertext.len() < 32 + TAG_SIZE {
+        return None;
+    }
+    let (ephemeral, sealed) = ciphertext.split_at(32);
+    let shared = x25519(secret_key, ephemeral.try_into().unwrap());
+    if shared == [0; 32] {
+        return None;
+    }
+    let recipient = x25519(secret_key, &BASE_POINT);
+    let key = hkdf_sha256(&[], &shared, &[ECIES_INFO, ephemeral, &recipient].concat());
+    aead_open(&key, &[0; 12], &[], sealed)
+    // SOLUTION-END
+}
+
+fn maul(ciphertext: &[u8]) -> Vec<u8> {
+    // SOLUTION-BEGIN
+    // the ladder ignores the most significant bit of u
+    let mut mauled = ciphertext.to_vec();
+    mauled[31] ^= 0x80;
+    mauled
+    // SOLUTION-END
+}
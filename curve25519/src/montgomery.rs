@@ -0,0 +1,62 @@
+use crate::*;
+
+// Curve25519 is the Montgomery curve B v^2 = u^3 + A u^2 + u with A = 486662, B = 1 over F_p, p = 2^255 - 19
+// Only the u-coordinate is used by X25519: the Montgomery ladder computes u(kP) from u(P) alone,
+// in projective coordinates (X : Z) with u = X / Z, so that there is a single inversion at the end
+
+pub mod field {
+    #![allow(non_local_definitions)] // the derive macro writes its impl blocks inside a function
+    use ark_ff::{Fp256, MontBackend, MontConfig};
+
+    #[derive(MontConfig)]
+    #[modulus = "57896044618658097711785492504343953926634992332820282019728792003956564819949"]
+    #[generator = "2"]
+    pub struct Field25519Config;
+    pub type Fp = Fp256<MontBackend<Field25519Config, 4>>;
+}
+pub use field::Fp;
+
+pub const A: u64 = 486662;
+// the constant of the doubling formula
+pub const A24: u64 = (A - 2) / 4;
+// u-coordinate of the base point
+pub const BASE_POINT: [u8; 32] = {
+    let mut u = [0u8; 32];
+    u[0] = 9;
+    u
+};
+
+// RFC 7748 section 5: little-endian, the most significant bit is ignored, and non-canonical values are accepted
+pub fn decode_u_coordinate(bytes: &[u8; 32]) -> Fp {
+    let mut bytes = *bytes;
+    bytes[31] &= 0x7f;
+    Fp::from_le_bytes_mod_order(&bytes)
+}
+
+pub fn encode_u_coordinate(u: &Fp) -> [u8; 32] {
+    u.into_bigint().to_bytes_le().try_into().unwrap()
+}
+
+// swaps a and b if `swap` is 1, without branching on it
+// (arkworks field elements are not constant-time anyway, this is for the sake of the exercise)
+pub fn conditional_swap(swap: u8, a: &mut Fp, b: &mut Fp) {
+    let mask = Fp::from(swap);
+    let dummy = mask * (*a - *b);
+    *a -= dummy;
+    *b += dummy;
+}
+
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub fn bytes_from_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+pub fn from_hex(hex: &str) -> [u8; 32] {
+    bytes_from_hex(hex).try_into().unwrap()
+}
@@ -0,0 +1,153 @@
+use crate::*;
+
+// Invalid-curve attacks on x-only key exchange, on a weak toy Montgomery curve v^2 = u^3 + A u^2 + u over F_p
+// The ladder never uses v: for a u which is not on the curve, u^3 + A u^2 + u is not a square, and u is the
+// u-coordinate of a point of the quadratic twist c v^2 = u^3 + A u^2 + u (c a non-square) instead,
+// on which the very same formulas compute
+// #E + #E' = 2p + 2: here #E = 4q with q prime, as it should, but #E' = 4 * 7 * 359 * 1669 is smooth
+// A victim which runs the ladder on any u it receives, and uses the result as a shared secret, computes k P'
+// for a P' of the twist chosen by the attacker: with P' of small order r, k P' reveals k mod r (up to the sign,
+// u(-P) = u(P)), and the Chinese remainder theorem glues them back together as in Pohlig-Hellman
+
+pub const TOY_P: u64 = 16777447;
+pub const TOY_A: u64 = 10;
+pub const TOY_ORDER: u64 = 4 * 4194527;
+pub const TOY_TWIST_ORDER: u64 = 4 * 7 * 359 * 1669;
+pub const TOY_TWIST_FACTORS: [u64; 3] = [7, 359, 1669];
+
+#[derive(Clone, Copy, Debug)]
+pub struct ToyMontgomeryCurve {
+    pub p: u64,
+    pub a: u64,
+}
+
+pub fn toy_curve() -> ToyMontgomeryCurve {
+    ToyMontgomeryCurve { p: TOY_P, a: TOY_A }
+}
+
+pub fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+pub fn pow_mod(mut base: u64, mut exponent: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exponent >>= 1;
+    }
+    result
+}
+
+impl ToyMontgomeryCurve {
+    pub fn mul(&self, a: u64, b: u64) -> u64 {
+        mul_mod(a, b, self.p)
+    }
+
+    pub fn pow(&self, base: u64, exponent: u64) -> u64 {
+        pow_mod(base, exponent, self.p)
+    }
+
+    // u^3 + A u^2 + u
+    pub fn rhs(&self, u: u64) -> u64 {
+        let u2 = self.mul(u, u);
+        (self.mul(u2, u) + self.mul(self.a, u2) + u) % self.p
+    }
+
+    // Euler's criterion: u is on the curve iff u^3 + A u^2 + u is a square (or 0), otherwise it is on the twist
+    pub fn is_on_curve(&self, u: u64) -> bool {
+        let rhs = self.rhs(u);
+        rhs == 0 || self.pow(rhs, (self.p - 1) / 2) == 1
+    }
+
+    // the Montgomery ladder of RFC 7748 on (X : Z) coordinates, None for the point at infinity (Z = 0)
+    // It checks nothing about u: this is the bug
+    pub fn ladder(&self, k: u64, u: u64) -> Option<u64> {
+        let p = self.p;
+        let a24 = (self.a - 2) / 4;
+        let (sub, add) = (
+            |a: u64, b: u64| (a + p - b) % p,
+            |a: u64, b: u64| (a + b) % p,
+        );
+        let (mut x2, mut z2, mut x3, mut z3) = (1, 0, u % p, 1);
+        for t in (0..u64::BITS - k.leading_zeros()).rev() {
+            if (k >> t) & 1 == 1 {
+                (x2, z2, x3, z3) = (x3, z3, x2, z2);
+            }
+            let (a, b) = (add(x2, z2), sub(x2, z2));
+            let (aa, bb) = (self.mul(a, a), self.mul(b, b));
+            let e = sub(aa, bb);
+            let (da, cb) = (self.mul(sub(x3, z3), a), self.mul(add(x3, z3), b));
+            let (plus, minus) = (add(da, cb), sub(da, cb));
+            x3 = self.mul(plus, plus);
+            z3 = self.mul(u % p, self.mul(minus, minus));
+            x2 = self.mul(aa, bb);
+            z2 = self.mul(e, add(aa, self.mul(a24, e)));
+            if (k >> t) & 1 == 1 {
+                (x2, z2, x3, z3) = (x3, z3, x2, z2);
+            }
+        }
+        (z2 != 0).then(|| self.mul(x2, self.pow(z2, p - 2)))
+    }
+
+    // the u-coordinate of a point of order q: 4 times the first point of the curve with a nonzero multiple by 4
+    pub fn base_point(&self) -> u64 {
+        (2..self.p)
+            .filter(|u| self.is_on_curve(*u))
+            .find_map(|u| self.ladder(4, u))
+            .unwrap()
+    }
+}
+
+// The victim of the lab: it answers every u with u(kP), its shared secret with the sender of u
+// (in a real protocol, the attacker would rather see a MAC keyed with it, and test the candidates one by one)
+pub struct Victim {
+    pub curve: ToyMontgomeryCurve,
+    secret: u64,
+    pub public_key: u64,
+    pub validate: bool, // whether to run `validated_ladder` instead of the buggy ladder
+    pub queries: usize,
+}
+
+impl Victim {
+    pub fn new<R: Rng>(curve: ToyMontgomeryCurve, validate: bool, rng: &mut R) -> Victim {
+        let secret = rng.gen_range(1..TOY_ORDER / 4);
+        Victim {
+            curve,
+            secret,
+            public_key: curve.ladder(secret, curve.base_point()).unwrap(),
+            validate,
+            queries: 0,
+        }
+    }
+
+    pub fn respond(&mut self, u: u64) -> Option<u64> {
+        self.queries += 1;
+        if self.validate {
+            validated_ladder(&self.curve, self.secret, u)
+        } else {
+            self.curve.ladder(self.secret, u)
+        }
+    }
+
+    pub fn is_secret(&self, k: u64) -> bool {
+        k == self.secret
+    }
+}
+
+// the x in [0, m_1 ... m_k) such that x = r_i mod m_i, for distinct primes m_i (see number-theory for the general case)
+pub fn crt_primes(residues: &[u64], moduli: &[u64]) -> u64 {
+    let m: u64 = moduli.iter().product();
+    residues
+        .iter()
+        .zip(moduli)
+        .map(|(r, m_i)| {
+            let n_i = m / m_i;
+            // n_i^-1 mod m_i, by Fermat's little theorem
+            let inverse = pow_mod(n_i % m_i, m_i - 2, *m_i);
+            mul_mod(mul_mod(*r, n_i, m), inverse, m)
+        })
+        .fold(0, |acc, x| (acc + x) % m)
+}
@@ -0,0 +1,47 @@
+use runner::lang::{Catalog, Lang, Statements};
+
+pub const CATALOG: Catalog = Catalog {
+    lang: Lang::Fr,
+    statements: Statements::Table(&[
+        ("Q1", "écrire `complete_add` pour les courbes avec a = 0 : l'article calcule les sommes de produits croisés avec\nune multiplication chacune, (X1 + Y1)(X2 + Y2) - t0 - t1, soit 12 multiplications et 2 par b3 en tout"),
+        ("Q2", "les formules échouent sur la courbe jouet pour P - Q = T, d'ordre 2, et nulle part sur secp256k1 : l'article\nles prouve complètes quand le groupe est d'ordre impair. Pourquoi un point d'ordre 2 compte-t-il, et pourquoi\nCurve25519 (h = 8) est-elle écrite sous une autre forme pour ses formules complètes ?\nElles coûtent 12 multiplications là où les coordonnées jacobiennes additionnent en 11M + 5S : pourquoi les bibliothèques\nen temps constant et les circuits zk paient-ils ce prix ?"),
+        ("Q3", "écrire `double_and_add` et `montgomery_ladder`, à partir du bit de poids fort de k"),
+        ("Q4", "écrire `wnaf`, le NAF de largeur w de k à partir du chiffre de poids faible, et `wnaf_mul` qui l'utilise"),
+        ("Q5", "écrire `glv_decompose`, qui décompose k en k1 + k2 lambda mod n avec k1 et k2 d'environ 128 bits,\net `glv_mul` qui calcule k1 P + k2 phi(P) avec une seule chaîne de doublements"),
+        ("Q6", "lancer `cargo run --release -- bench-scalarmul` : classer les quatre algorithmes et expliquer les écarts par\nleurs nombres de doublements et d'additions. Lequel choisiriez-vous pour un k secret, et pourquoi n'est-ce pas le\nplus rapide ? Quelle largeur de fenêtre rend wNAF le plus rapide, et que gagnerait GLV avec wNAF sur les deux moitiés ?"),
+    ]),
+    hints: &[
+        (
+            "Q1",
+            &[
+                "Aucune branche : le point à l'infini est (0 : 1 : 0), et les mêmes lignes l'additionnent comme n'importe quel autre point",
+                "(X1 + Y1)(X2 + Y2) = X1 X2 + Y1 Y2 + (X1 Y2 + X2 Y1) : soustraire t0 et t1 pour obtenir les produits croisés",
+                "Calculer Z1 Z2 une fois, puis t2 = b3 Z1 Z2, et 3 t0 = t0 + t0 + t0 : les formules tiennent alors en trois lignes",
+            ],
+        ),
+        (
+            "Q3",
+            &[
+                "`k.into_bigint().to_bits_be()` donne les bits de k à partir du poids fort, zéros de tête compris",
+                "Double-and-add : doubler le résultat pour chaque bit, puis ajouter P quand le bit est à un",
+                "Échelle : garder R1 = R0 + P. Pour un bit 0, R1 = R0 + R1 et R0 = 2 R0, pour un bit 1 l'inverse",
+            ],
+        ),
+        (
+            "Q4",
+            &[
+                "Tant que k > 0 : si k est impair, prendre le chiffre d = k mod 2^w, moins 2^w s'il n'est pas inférieur à 2^(w - 1), sinon 0",
+                "Soustraire le chiffre, puis diviser k par deux : k - d est un multiple de 2^w, ce qui force les w - 1 zéros qui suivent",
+                "Précalculer P, 3P, ..., (2^(w - 1) - 1)P, puis partir du dernier chiffre : le chiffre d ajoute ou soustrait le multiple |d|P d'indice |d| / 2",
+            ],
+        ),
+        (
+            "Q5",
+            &[
+                "Écrire (k, 0) dans la base du réseau : c1 = round(b2 k / n) et c2 = round(-b1 k / n), puis soustraire c1 (a1, b1) + c2 (a2, b2)",
+                "k1 = k - c1 a1 - c2 a2 et k2 = -c1 b1 - c2 b2, l'un ou l'autre peut être négatif : multiplier alors -P",
+                "L'astuce de Shamir : précalculer P1 + P2, puis doubler une fois par bit et ajouter P1, P2 ou P1 + P2 selon les bits de |k1| et |k2|",
+            ],
+        ),
+    ],
+};
@@ -0,0 +1,42 @@
+use runner::hints::Hints;
+use runner::lang::Catalog;
+
+pub const CATALOGS: &[Catalog] = &[
+    Catalog::english(HINTS, include_str!("main.rs")),
+    crate::fr::CATALOG,
+];
+
+pub const HINTS: Hints = &[
+    (
+        "Q1",
+        &[
+            "No branch at all: the point at infinity is (0 : 1 : 0), and the same lines add it like any other point",
+            "(X1 + Y1)(X2 + Y2) = X1 X2 + Y1 Y2 + (X1 Y2 + X2 Y1): subtract t0 and t1 to get the cross products",
+            "Compute Z1 Z2 once, then t2 = b3 Z1 Z2, and 3 t0 = t0 + t0 + t0: the formulas are then three lines",
+        ],
+    ),
+    (
+        "Q3",
+        &[
+            "`k.into_bigint().to_bits_be()` gives the bits of k from the most significant one, leading zeros included",
+            "Double-and-add: double the result for each bit, then add P when the bit is set",
+            "Ladder: keep R1 = R0 + P. For a 0 bit, R1 = R0 + R1 and R0 = 2 R0, for a 1 bit the other way round",
+        ],
+    ),
+    (
+        "Q4",
+        &[
+            "While k > 0: if k is odd, take the digit d = k mod 2^w, minus 2^w if it is not below 2^(w - 1), else 0",
+            "Subtract the digit, then halve k: k - d is a multiple of 2^w, which forces the w - 1 zeros that follow",
+            "Precompute P, 3P, ..., (2^(w - 1) - 1)P, then go from the last digit down: the digit d adds or subtracts the multiple |d|P at index |d| / 2",
+        ],
+    ),
+    (
+        "Q5",
+        &[
+            "Write (k, 0) in the basis of the lattice: c1 = round(b2 k / n) and c2 = round(-b1 k / n), then subtract c1 (a1, b1) + c2 (a2, b2)",
+            "k1 = k - c1 a1 - c2 a2 and k2 = -c1 b1 - c2 b2, either may be negative: multiply -P then",
+            "Shamir's trick: precompute P1 + P2, then double once per bit and add P1, P2 or P1 + P2 according to the bits of |k1| and |k2|",
+        ],
+    ),
+];
@@ -0,0 +1,31 @@
+use ark_ec::short_weierstrass::{Affine, SWCurveConfig};
+use ark_ec::CurveConfig;
+use ark_ff::{Field, Zero};
+
+// Homogeneous projective coordinates (X : Y : Z) for the point (X / Z, Y / Z), not the Jacobian ones of
+// arkworks (X / Z^2, Y / Z^3): the curve becomes Y^2 Z = X^3 + a X Z^2 + b Z^3, and the point at infinity
+// is (0 : 1 : 0), the only point with Z = 0
+// (0 : 0 : 0) is no point at all: it is what the complete formulas of Q1 return where they fail
+
+pub type Homogeneous<P> = (
+    <P as CurveConfig>::BaseField,
+    <P as CurveConfig>::BaseField,
+    <P as CurveConfig>::BaseField,
+);
+
+pub fn to_homogeneous<P: SWCurveConfig>(point: &Affine<P>) -> Homogeneous<P> {
+    match point.infinity {
+        true => (P::BaseField::ZERO, P::BaseField::ONE, P::BaseField::ZERO),
+        false => (point.x, point.y, P::BaseField::ONE),
+    }
+}
+
+// `None` for (0 : 0 : 0) and for the triples off the curve
+pub fn from_homogeneous<P: SWCurveConfig>((x, y, z): Homogeneous<P>) -> Option<Affine<P>> {
+    let point = match z.inverse() {
+        Some(z_inverse) => Affine::new_unchecked(x * z_inverse, y * z_inverse),
+        None if x.is_zero() && !y.is_zero() => Affine::identity(),
+        None => return None,
+    };
+    point.is_on_curve().then_some(point)
+}
@@ -0,0 +1,364 @@
+use ark_ec::short_weierstrass::SWCurveConfig;
+use ark_ec::{AffineRepr, CurveGroup, Group};
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_secp256k1::{Affine, Fq, Fr, Projective};
+use ark_std::{ops::Mul, One, UniformRand, Zero};
+use homogeneous::*;
+use num_bigint::{BigInt as Integer, BigUint};
+use rand::Rng;
+use runner::Runner;
+use scalar_mul::*;
+use std::time::Instant;
+use training_common::plot::SmallCurve;
+
+mod fr;
+mod hints;
+mod homogeneous;
+mod scalar_mul;
+
+// The toy curve y^2 = x^3 + 7 over F_89 of ff-ec: see module `toy` of the training-common crate
+use training_common::toy::{ToyAffine, ToyConfig, F};
+
+fn main() {
+    // instructors: `cargo run --release -- bench-scalarmul` times the algorithms of Q3 to Q5, see module
+    // `scalar_mul`
+    let args = runner::args();
+    if let [command, rest @ ..] = args.as_slice() {
+        if command == "bench-scalarmul" {
+            return bench_scalar_mul(rest);
+        }
+    }
+
+    let runner = Runner::start(env!("CARGO_PKG_NAME"), hints::CATALOGS);
+    let mut rng = seed::rng();
+
+    // The curves of ff-ec again, the toy curve and secp256k1, from the side of their implementation: the
+    // formulas of the group law and the algorithms of scalar multiplication
+
+    // Q24 of ff-ec needed a case for every pair of points: O, P = Q, P = -Q, the others. A branch on secret points
+    // leaks through timing, and a circuit or a GPU kernel has to evaluate every branch anyway
+    // Renes, Costello and Batina (2015) give a single formula in homogeneous coordinates (see module
+    // `homogeneous`), with no case at all: for a = 0, b3 = 3b and the products t0 = X1 X2, t1 = Y1 Y2,
+    // t2 = b3 Z1 Z2,
+    //   X3 = (X1 Y2 + X2 Y1)(t1 - t2) - b3 (Y1 Z2 + Y2 Z1)(X1 Z2 + X2 Z1)
+    //   Y3 = (t1 + t2)(t1 - t2) + 3 b3 t0 (X1 Z2 + X2 Z1)
+    //   Z3 = (Y1 Z2 + Y2 Z1)(t1 + t2) + 3 t0 (X1 Y2 + X2 Y1)
+    // Q1: write `complete_add` for the curves with a = 0: the paper computes the sums of cross products with
+    // one multiplication each, (X1 + Y1)(X2 + Y2) - t0 - t1, for 12 multiplications and 2 by b3 in all
+    runner.question("Q1");
+    // every pair of points of the toy curve, O included
+    let mut toy = vec![ToyAffine::zero()];
+    toy.extend(
+        SmallCurve::new(89, 0, 7)
+            .points()
+            .into_iter()
+            .map(|(x, y)| ToyAffine::new_unchecked(F::from(x), F::from(y))),
+    );
+    let two_torsion = *toy[1..].iter().find(|point| point.y.is_zero()).unwrap();
+    let mut exceptions = 0;
+    for p in &toy {
+        for q in &toy {
+            let sum = complete_add::<ToyConfig>(&to_homogeneous(p), &to_homogeneous(q));
+            if (*p - q).into_affine() == two_torsion {
+                assert_eq!(sum, (F::zero(), F::zero(), F::zero()));
+                exceptions += 1;
+            } else {
+                assert_eq!(from_homogeneous(sum), Some((*p + q).into_affine()));
+            }
+        }
+    }
+    // each point P has one partner P + T, T the point of order 2
+    assert_eq!(exceptions, toy.len());
+    println!(
+        "complete_add on the {} pairs of points of y^2 = x^3 + 7 over F_89: {} exceptions, P - Q = ({}, 0)",
+        toy.len() * toy.len(),
+        exceptions,
+        two_torsion.x
+    );
+    let secp256k1_add = |p: &Affine, q: &Affine| {
+        let sum = complete_add::<ark_secp256k1::Config>(&to_homogeneous(p), &to_homogeneous(q));
+        assert_eq!(from_homogeneous(sum), Some((*p + q).into_affine()));
+    };
+    let o = Affine::zero();
+    secp256k1_add(&o, &o);
+    for _ in 0..1000 {
+        let p = Projective::rand(&mut rng).into_affine();
+        let q = Projective::rand(&mut rng).into_affine();
+        secp256k1_add(&p, &q);
+        secp256k1_add(&p, &p);
+        secp256k1_add(&p, &-p);
+        secp256k1_add(&p, &o);
+        secp256k1_add(&o, &q);
+    }
+    // any representative of the inputs: (X : Y : Z) is (lX : lY : lZ)
+    for _ in 0..100 {
+        let (p, q) = (Projective::rand(&mut rng), Projective::rand(&mut rng));
+        let scale = |(x, y, z): (Fq, Fq, Fq), l: Fq| (l * x, l * y, l * z);
+        let p_scaled = scale(to_homogeneous(&p.into_affine()), Fq::rand(&mut rng));
+        let q_scaled = scale(to_homogeneous(&q.into_affine()), Fq::rand(&mut rng));
+        let sum = complete_add::<ark_secp256k1::Config>(&p_scaled, &q_scaled);
+        assert_eq!(from_homogeneous(sum), Some((p + q).into_affine()));
+    }
+    // Q2: the formulas fail on the toy curve for P - Q = T, of order 2, and nowhere on secp256k1: the paper
+    // proves them complete when the group has odd order. Why does a point of order 2 matter, and why is
+    // Curve25519 (h = 8) written in another form for its complete formulas?
+    // They cost 12 multiplications where Jacobian coordinates add with 11M + 5S: why do constant-time
+    // libraries and zk circuits pay the price?
+    runner.question("Q2");
+
+    // Scalar multiplication, four ways: take a look at module `scalar_mul`
+    // Q3: write `double_and_add` and `montgomery_ladder`, from the most significant bit of k
+    runner.question("Q3");
+    for implementation in [&DoubleAndAdd as &dyn ScalarMul, &Ladder] {
+        if let Some(error) = cross_check(implementation, &mut rng) {
+            panic!("{}", error);
+        }
+    }
+
+    // Q4: write `wnaf`, the width-w NAF of k from the least significant digit, and `wnaf_mul` which uses it
+    runner.question("Q4");
+    for width in 2..=6 {
+        for k in [Fr::zero(), Fr::one(), -Fr::one(), Fr::rand(&mut rng)] {
+            let digits = wnaf(&k, width);
+            let recomposed = digits
+                .iter()
+                .rev()
+                .fold(Integer::from(0), |acc, d| acc * 2 + *d);
+            assert_eq!(recomposed, Integer::from(BigUint::from(k)), "k = {}", k);
+            for (i, d) in digits.iter().enumerate() {
+                if *d != 0 {
+                    assert!(d % 2 != 0 && d.abs() < 1 << (width - 1), "digit {}", d);
+                    assert!(digits[i + 1..].iter().take(width - 1).all(|d| *d == 0));
+                }
+            }
+            assert!(digits.len() <= 257 && digits.last() != Some(&0));
+        }
+        if let Some(error) = cross_check(&Wnaf(width), &mut rng) {
+            panic!("{}", error);
+        }
+    }
+
+    // Q5: write `glv_decompose`, which splits k into k1 + k2 lambda mod n with k1 and k2 of about 128 bits,
+    // and `glv_mul` which computes k1 P + k2 phi(P) with a single chain of doublings
+    runner.question("Q5");
+    let n = Integer::from(BigUint::from(Fr::MODULUS));
+    let lambda = Integer::from(BigUint::from(glv_lambda()));
+    for k in [Fr::zero(), Fr::one(), -Fr::one(), glv_lambda()]
+        .into_iter()
+        .chain((0..1000).map(|_| Fr::rand(&mut rng)))
+    {
+        let (k1, k2) = glv_decompose(&k);
+        assert!(
+            k1.bits() <= 129 && k2.bits() <= 129,
+            "k1 = {}, k2 = {}",
+            k1,
+            k2
+        );
+        let sum: Integer = (k1 + k2 * &lambda) % &n;
+        assert_eq!((sum + &n) % &n, Integer::from(BigUint::from(k)));
+    }
+    if let Some(error) = cross_check(&Glv, &mut rng) {
+        panic!("{}", error);
+    }
+    // Q6: run `cargo run --release -- bench-scalarmul`: rank the four algorithms and explain the gaps with
+    // their counts of doublings and additions. Which one would you pick for a secret k, and why is it not the
+    // fastest? Which window width makes wNAF fastest, and what would GLV with wNAF on both halves save?
+    runner.question("Q6");
+
+    println!("Good job! 🏴‍☠️");
+    runner.finish();
+}
+
+fn complete_add<P: SWCurveConfig>(p: &Homogeneous<P>, q: &Homogeneous<P>) -> Homogeneous<P> {
+    // SOLUTION-BEGIN
+    // Algorithm 7 of the paper
+    let ((x1, y1, z1), (x2, y2, z2)) = (*p, *q);
+    let b3 = P::COEFF_B.double() + P::COEFF_B;
+    let t0 = x1 * x2;
+    let t1 = y1 * y2;
+    let t2 = b3 * (z1 * z2);
+    let xy = (x1 + y1) * (x2 + y2) - t0 - t1; // X1 Y2 + X2 Y1
+    let yz = (y1 + z1) * (y2 + z2) - t1 - z1 * z2; // Y1 Z2 + Y2 Z1
+    let xz = (x1 + z1) * (x2 + z2) - t0 - z1 * z2; // X1 Z2 + X2 Z1
+    let (sum, difference) = (t1 + t2, t1 - t2);
+    let t0 = t0.double() + t0;
+    let b3_xz = b3 * xz;
+    (
+        xy * difference - yz * b3_xz,
+        sum * difference + t0 * b3_xz,
+        yz * sum + t0 * xy,
+    )
+    // SOLUTION-END
+}
+
+struct DoubleAndAdd;
+
+impl ScalarMul for DoubleAndAdd {
+    fn name(&self) -> String {
+        "double-and-add".to_string()
+    }
+
+    fn mul(&self, point: &Projective, k: &Fr) -> Projective {
+        double_and_add(point, k)
+    }
+}
+
+struct Ladder;
+
+impl ScalarMul for Ladder {
+    fn name(&self) -> String {
+        "ladder".to_string()
+    }
+
+    fn mul(&self, point: &Projective, k: &Fr) -> Projective {
+        montgomery_ladder(point, k)
+    }
+}
+
+// the width of the window
+struct Wnaf(usize);
+
+impl ScalarMul for Wnaf {
+    fn name(&self) -> String {
+        format!("wNAF, w = {}", self.0)
+    }
+
+    fn mul(&self, point: &Projective, k: &Fr) -> Projective {
+        wnaf_mul(point, k, self.0)
+    }
+}
+
+struct Glv;
+
+impl ScalarMul for Glv {
+    fn name(&self) -> String {
+        "GLV".to_string()
+    }
+
+    fn mul(&self, point: &Projective, k: &Fr) -> Projective {
+        glv_mul(point, k)
+    }
+}
+
+fn double_and_add(point: &Projective, k: &Fr) -> Projective {
+    // SOLUTION-BEGIN
+    let bits = k.into_bigint().to_bits_be();
+    let mut result = Projective::zero();
+    for bit in bits {
+        result.double_in_place();
+        if bit {
+            result += point;
+        }
+    }
+    result
+    // SOLUTION-END
+}
+
+fn montgomery_ladder(point: &Projective, k: &Fr) -> Projective {
+    // SOLUTION-BEGIN
+    // (R0, R1) = (mP, (m + 1)P) for the prefix m of k
+    let (mut r0, mut r1) = (Projective::zero(), *point);
+    for bit in k.into_bigint().to_bits_be() {
+        match bit {
+            false => {
+                r1 += r0;
+                r0.double_in_place();
+            }
+            true => {
+                r0 += r1;
+                r1.double_in_place();
+            }
+        }
+    }
+    r0
+    // SOLUTION-END
+}
+
+fn wnaf(k: &Fr, width: usize) -> Vec<i64> {
+    // SOLUTION-BEGIN
+    let mut k = Integer::from(BigUint::from(*k));
+    let modulus = Integer::from(1u64 << width);
+    let half = 1i64 << (width - 1);
+    let mut digits = vec![];
+    while k > Integer::from(0) {
+        let digit = match k.bit(0) {
+            // the signed residue of k mod 2^w, in (-2^(w - 1), 2^(w - 1))
+            true => {
+                let residue = i64::try_from(&k % &modulus).unwrap();
+                if residue >= half {
+                    residue - (1 << width)
+                } else {
+                    residue
+                }
+            }
+            false => 0,
+        };
+        k -= digit;
+        k >>= 1;
+        digits.push(digit);
+    }
+    digits
+    // SOLUTION-END
+}
+
+fn wnaf_mul(point: &Projective, k: &Fr, width: usize) -> Projective {
+    // SOLUTION-BEGIN
+    // P, 3P, 5P, ..., (2^(w - 1) - 1)P
+    let double = point.double();
+    let mut odd_multiples = vec![*point];
+    for i in 1..1 << (width - 2) {
+        odd_multiples.push(odd_multiples[i - 1] + double);
+    }
+    let mut result = Projective::zero();
+    for digit in wnaf(k, width).iter().rev() {
+        result.double_in_place();
+        match digit.cmp(&0) {
+            std::cmp::Ordering::Greater => result += odd_multiples[(*digit / 2) as usize],
+            std::cmp::Ordering::Less => result -= odd_multiples[(-*digit / 2) as usize],
+            std::cmp::Ordering::Equal => {}
+        }
+    }
+    result
+    // SOLUTION-END
+}
+
+fn glv_decompose(k: &Fr) -> (Integer, Integer) {
+    // SOLUTION-BEGIN
+    let n = Integer::from(BigUint::from(Fr::MODULUS));
+    let k = Integer::from(BigUint::from(*k));
+    let (a1, b1) = (hex_integer(GLV_A1), hex_integer(GLV_B1));
+    let (a2, b2) = (hex_integer(GLV_A2), hex_integer(GLV_B2));
+    // (k, 0) in the basis, rounded to the closest lattice vector: c1 = round(b2 k / n), c2 = round(-b1 k / n)
+    let round = |x: Integer| -> Integer { (x * 2 + &n) / (&n * 2) };
+    let c1 = round(&b2 * &k);
+    let c2 = round(-&b1 * &k);
+    let k1 = k - &c1 * a1 - &c2 * a2;
+    let k2 = -c1 * b1 - c2 * b2;
+    (k1, k2)
+    // SOLUTION-END
+}
+
+fn glv_mul(point: &Projective, k: &Fr) -> Projective {
+    // SOLUTION-BEGIN
+    let (k1, k2) = glv_decompose(k);
+    let signed = |k: &Integer, point: Projective| match k.sign() {
+        num_bigint::Sign::Minus => (k.magnitude().clone(), -point),
+        _ => (k.magnitude().clone(), point),
+    };
+    let (k1, p1) = signed(&k1, *point);
+    let (k2, p2) = signed(&k2, endomorphism(&point.into_affine()).into_group());
+    let p1_plus_p2 = p1 + p2;
+    let mut result = Projective::zero();
+    // Shamir's trick: one chain of doublings for both scalars
+    for i in (0..k1.bits().max(k2.bits())).rev() {
+        result.double_in_place();
+        match (k1.bit(i), k2.bit(i)) {
+            (true, true) => result += p1_plus_p2,
+            (true, false) => result += p1,
+            (false, true) => result += p2,
+            (false, false) => {}
+        }
+    }
+    result
+    // SOLUTION-END
+}
@@ -0,0 +1,145 @@
+use crate::*;
+use std::hint::black_box;
+
+// The scalar multiplications of the exercise on secp256k1, behind a single trait so that one harness checks
+// them all against arkworks and times them side by side: `cargo run --release -- bench-scalarmul [count]`
+// - double-and-add: a doubling per bit and an addition per bit set, about 256 D + 128 A
+// - Montgomery ladder: a doubling and an addition per bit whatever the bit, 256 D + 256 A, no branch on k
+// - wNAF: k in signed digits, odd and below 2^(w - 1) in absolute value, with at least w - 1 zeros after
+//   each non-zero digit: 2^(w - 2) precomputed odd multiples, then about 256 D + 256 / (w + 1) A
+// - GLV: phi(x, y) = (beta x, y) is lambda P for every point, for free. With k = k1 + k2 lambda mod n and
+//   k1, k2 of about 128 bits, kP = k1 P + k2 phi(P) shares its 128 doublings between both halves
+// A new algorithm only needs an implementation of `ScalarMul` and a line in `implementations`
+
+// the cube roots of unity of secp256k1: beta in Fq, lambda in Fr, with lambda (x, y) = (beta x, y)
+pub const GLV_BETA: &str = "7ae96a2b657c07106e64479eac3434e99cf0497512f58995c1396c28719501ee";
+pub const GLV_LAMBDA: &str = "5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72";
+// a short basis (a1, b1), (a2, b2) of the lattice of the (x, y) with x + y lambda = 0 mod n, from the
+// extended Euclidean algorithm on (n, lambda), with a1 b2 - a2 b1 = n
+pub const GLV_A1: &str = "3086d221a7d46bcde86c90e49284eb15";
+pub const GLV_B1: &str = "-e4437ed6010e88286f547fa90abfe4c3";
+pub const GLV_A2: &str = "114ca50f7a8e2f3f657c1108d9d44cfd8";
+pub const GLV_B2: &str = GLV_A1;
+
+pub const WNAF_WIDTH: usize = 5;
+pub const DEFAULT_BENCH_COUNT: usize = 200;
+
+pub trait ScalarMul {
+    fn name(&self) -> String;
+
+    fn mul(&self, point: &Projective, k: &Fr) -> Projective;
+}
+
+// the reference
+pub struct Arkworks;
+
+impl ScalarMul for Arkworks {
+    fn name(&self) -> String {
+        "arkworks".to_string()
+    }
+
+    fn mul(&self, point: &Projective, k: &Fr) -> Projective {
+        point.mul(k)
+    }
+}
+
+pub fn implementations() -> Vec<Box<dyn ScalarMul>> {
+    vec![
+        Box::new(Arkworks),
+        Box::new(DoubleAndAdd),
+        Box::new(Ladder),
+        Box::new(Wnaf(WNAF_WIDTH)),
+        Box::new(Glv),
+    ]
+}
+
+pub fn hex_integer(hex: &str) -> Integer {
+    Integer::parse_bytes(hex.as_bytes(), 16).unwrap()
+}
+
+pub fn glv_beta() -> Fq {
+    Fq::from(hex_integer(GLV_BETA).to_biguint().unwrap())
+}
+
+pub fn glv_lambda() -> Fr {
+    Fr::from(hex_integer(GLV_LAMBDA).to_biguint().unwrap())
+}
+
+// phi(P) = lambda P
+pub fn endomorphism(point: &Affine) -> Affine {
+    match point.xy() {
+        None => *point,
+        Some((x, y)) => Affine::new_unchecked(glv_beta() * x, *y),
+    }
+}
+
+// None when `implementation` agrees with arkworks on the edge cases and random points and scalars
+pub fn cross_check<R: Rng>(implementation: &dyn ScalarMul, rng: &mut R) -> Option<String> {
+    let g = Projective::generator();
+    let mut cases: Vec<(Projective, Fr)> = [Fr::zero(), Fr::one(), Fr::from(2u64), -Fr::one()]
+        .iter()
+        .map(|k| (g, *k))
+        .collect();
+    cases.push((Projective::zero(), Fr::rand(rng)));
+    cases.push((g, glv_lambda()));
+    cases.extend((0..50).map(|_| (Projective::rand(rng), Fr::rand(rng))));
+    cases.into_iter().find_map(|(point, k)| {
+        let expected = point.mul(k);
+        let actual = implementation.mul(&point, &k);
+        (actual != expected).then(|| {
+            format!(
+                "{}: wrong k P for k = {} and P = {}",
+                implementation.name(),
+                k,
+                point.into_affine()
+            )
+        })
+    })
+}
+
+// the time per multiplication of each implementation, on the same points and scalars
+pub fn timing_table<R: Rng>(implementations: &[Box<dyn ScalarMul>], count: usize, rng: &mut R) {
+    let inputs: Vec<(Projective, Fr)> = (0..count)
+        .map(|_| (Projective::rand(rng), Fr::rand(rng)))
+        .collect();
+    let mut reference = None;
+    println!("{:<16} {:>12} {:>10}", "algorithm", "per mul", "/ arkworks");
+    for implementation in implementations {
+        let start = Instant::now();
+        black_box(
+            inputs
+                .iter()
+                .map(|(point, k)| implementation.mul(point, k))
+                .collect::<Vec<_>>(),
+        );
+        let per_mul = start.elapsed() / count as u32;
+        let reference = *reference.get_or_insert(per_mul);
+        println!(
+            "{:<16} {:>12.2?} {:>10.2}",
+            implementation.name(),
+            per_mul,
+            per_mul.as_secs_f64() / reference.as_secs_f64()
+        );
+    }
+}
+
+pub fn bench_scalar_mul(args: &[String]) {
+    let count = match args {
+        [] => DEFAULT_BENCH_COUNT,
+        [count] => count.parse().expect("count"),
+        _ => panic!("usage: bench-scalarmul [count]"),
+    };
+    let mut rng = seed::rng();
+    let implementations = implementations();
+    for implementation in &implementations {
+        if let Some(error) = cross_check(implementation.as_ref(), &mut rng) {
+            panic!("{}", error);
+        }
+    }
+    println!(
+        "{} implementations agree with arkworks, {} multiplications each on secp256k1:",
+        implementations.len(),
+        count
+    );
+    timing_table(&implementations, count, &mut rng);
+}
@@ -0,0 +1,135 @@
+use runner::lang::{Catalog, Lang, Statements};
+
+pub const CATALOG: Catalog = Catalog {
+    lang: Lang::Fr,
+    statements: Statements::Table(&[
+        ("Q1", "implémenter l'arithmétique de l'extension quadratique `Quadratic` à la fin de ce fichier :\n`Add`, `Sub`, `Neg`, `Mul` (réduire avec u^2 = beta), puis `conjugate`, `norm` et `inverse`\nLe conjugué de a = c0 + c1 u est c0 - c1 u, et la norme a * conj(a) = c0^2 - beta c1^2 est dans le corps de base"),
+        ("Q2", "3 engendre le groupe multiplicatif de F_89, pourquoi n'est-il pas un carré ?\nPourquoi ne pouvait-on pas prendre u^2 = -1 comme pour les nombres complexes ?\nVoici ce qui se passe avec u^2 = 4 : le polynôme u^2 - 4 = (u - 2)(u + 2) est réductible"),
+        ("Q3", "combien y a-t-il de polynômes unitaires irréductibles de degré 2 sur F_89 ?"),
+        ("Q4", "pourquoi 1 + u n'est-il ni un carré ni un cube dans F_p2 ? Pourquoi v n'est-il pas un carré dans F_p6, de sorte que w^2 = v\ndéfinisse F_p12 ? Que coûterait une multiplication dans F_p12 construit directement comme F_p[X] / (un irréductible de degré 12) ?"),
+        ("Q5", "écrire `power` qui calcule x^e par square-and-multiply"),
+        ("Q6", "écrire `Quadratic::frobenius` *sans* exponentiation"),
+        ("Q7", "écrire `trace_of_frobenius` à partir de p et #E(F_p)"),
+        ("Q8", "écrire `count_points_fp2` qui compte les points de y^2 = x^3 + 7 sur F_89^2 avec le critère d'Euler :\nun a non nul est un carré dans F_q si et seulement si a^((q - 1) / 2) = 1"),
+        ("Q9", "écrire `frobenius_point` et vérifier l'équation caractéristique sur des points de E(F_89^2)"),
+        ("Q10", "ici t = 0, la courbe est supersingulière : que vaut pi^2 ? Pourquoi p = 2 mod 3 en est-il la raison ?"),
+        ("Q11", "écrire `WeierstrassCurve::add`, la loi de la corde et de la tangente avec un a quelconque"),
+        ("Q12", "écrire `count_points_weierstrass`, comme `count_points_fp2` pour des coefficients quelconques"),
+        ("Q13", "écrire `weierstrass_points` qui liste les points affines de la courbe"),
+        ("Q14", "E' a des points à coordonnées dans F_89 ou non, mais peut-elle être définie sur F_89 après un changement de\nvariables ?\nCombien E' a-t-elle de points sur F_89^4 ?"),
+        ("Q15", "implémenter la multiplication de `Binary` à la fin de ce fichier, un produit de polynômes sur F_2\nréduit modulo f, puis son `inverse`"),
+        ("Q16", "écrire `KoblitzCurve::add` : la corde et la tangente recoupent toujours la courbe en un troisième point, mais\nl'opposé de (x, y) est maintenant (x, x + y)"),
+        ("Q17", "écrire `KoblitzCurve::frobenius`, puis `koblitz_order` qui donne #E_a(F_2^m) à partir de\nl'équation caractéristique de tau, comme #E(F_89^2) en Q8"),
+        ("Q18", "écrire `tnaf` qui développe k dans l'anneau Z[tau] (tau^2 = mu tau - 2), et `KoblitzCurve::tau_mul`"),
+        ("Q19", "le développement de k a environ 2 log2(k) chiffres : comment le ramener à environ m chiffres ?\nPourquoi les carrés sont-ils presque gratuits dans une base normale ?\nPourquoi le Frobenius accélère-t-il aussi le rho de Pollard sur une courbe de Koblitz, et pourquoi m doit-il être premier ?"),
+        ("Q20", "écrire `singular_point`, le point singulier de y^2 = x^3 + ax + b sur F_2^17"),
+        ("Q21", "écrire `GeneralCurve::add`, la loi de groupe de l'équation de Weierstrass générale\n- l'opposé de (x, y) est (x, -y - a1 x - a3), voir `GeneralCurve::neg`\n- la corde ou la tangente y = slope x + intercept recoupe la courbe en un troisième point"),
+        ("Q22", "pourquoi les courbes avec a2 = 0 sont-elles supersingulières en caractéristique 3, et celles avec a1 = 0 en\ncaractéristique 2 ? Pourquoi les standards évitent-ils les petites caractéristiques pour les courbes sans couplage ?"),
+    ]),
+    hints: &[
+        (
+            "Q1",
+            &[
+                "Les éléments sont c0 + c1 u avec u^2 = beta, multipliés comme des polynômes en u",
+                "(a0 + a1 u)(b0 + b1 u) = (a0 b0 + beta a1 b1) + (a0 b1 + a1 b0) u",
+                "L'inverse de a est conj(a) / norm(a), la norme étant dans le corps de base",
+            ],
+        ),
+        (
+            "Q2",
+            &[
+                "Un générateur est un carré si et seulement si son ordre divise (p - 1) / 2",
+                "-1 est un carré modulo p si et seulement si p = 1 mod 4",
+                "89 mod 4 = 1 : -1 est un carré, donc u^2 + 1 est réductible sur F_89",
+            ],
+        ),
+        (
+            "Q3",
+            &[
+                "Un polynôme unitaire de degré 2 est réductible si et seulement s'il a une racine dans F_89",
+                "Compter les réductibles : (X - a)(X - b) avec a = b ou a != b",
+                "Il y a 89^2 polynômes unitaires de degré 2, dont 89 + 89 * 88 / 2 sont réductibles",
+            ],
+        ),
+        (
+            "Q6",
+            &[
+                "Le Frobenius x -> x^p est un automorphisme de corps qui fixe F_p",
+                "u^p = u u^(p - 1) = (u^2)^((p - 1) / 2) u = beta^((p - 1) / 2) u",
+                "frobenius(c0 + c1 u) = c0 + c1 beta^((p - 1) / 2) u, et beta^((p - 1) / 2) = -1 pour un beta non carré",
+            ],
+        ),
+        (
+            "Q14",
+            &[
+                "Des courbes isomorphes sur la clôture algébrique ont le même j-invariant",
+                "j = 1728 4a^3 / (4a^3 + 27b^2) : le calculer pour E', est-il dans F_89 ?",
+                "Avec q = 89^2 et t = q + 1 - #E'(F_q), les racines de X^2 - t X + q donnent #E'(F_q^2) = q^2 + 1 - (t^2 - 2q)",
+            ],
+        ),
+        (
+            "Q15",
+            &[
+                "Multiplier comme des entiers en binaire, avec des XOR à la place des additions avec retenue",
+                "Décaler et additionner : pour chaque bit de b, ajouter a si le bit est à un, puis multiplier a par X, un décalage à gauche",
+                "Après le décalage, un bit en position m est X^m = f - X^m : faire un XOR de a avec le module. L'inverse est x^(2^m - 2)",
+            ],
+        ),
+        (
+            "Q16",
+            &[
+                "La droite passant par P et Q recoupe la courbe en un troisième point R, et P + Q = -R = (x_R, x_R + y_R)",
+                "Substituer y = slope x + c dans y^2 + xy = x^3 + a x^2 + 1 : la somme des racines vaut slope^2 + slope + a",
+                "Corde : slope = (y1 + y2) / (x1 + x2), x3 = slope^2 + slope + x1 + x2 + a, y3 = slope (x1 + x3) + x3 + y1. Tangente : slope = x1 + y1 / x1, x3 = slope^2 + slope + a, y3 = x1^2 + slope x3 + x3",
+            ],
+        ),
+        (
+            "Q17",
+            &[
+                "L'élévation au carré est un automorphisme de corps qui fixe F_2, où vivent a et 1",
+                "tau a pour trace mu et pour degré 2 en tant qu'endomorphisme : ses valeurs propres sont les racines de X^2 - mu X + 2",
+                "Avec alpha et beta ces racines, #E(F_2^m) = 2^m + 1 - (alpha^m + beta^m), et V_k = alpha^k + beta^k vérifie V_k = mu V_(k-1) - 2 V_(k-2)",
+            ],
+        ),
+        (
+            "Q18",
+            &[
+                "Comme un NAF en base 2 : prendre un chiffre qui rend le reste divisible par tau^2, puis diviser par tau",
+                "r0 + r1 tau est divisible par tau si et seulement si r0 est pair, et tau^2 le divise si et seulement si r0 - 2 r1 = 0 mod 4",
+                "Pour r0 impair, le chiffre est 2 - ((r0 - 2 r1) mod 4), puis (r0, r1) devient (r1 + mu r0 / 2, -r0 / 2). Évaluer avec Horner, à partir du chiffre de plus haut rang",
+            ],
+        ),
+        (
+            "Q19",
+            &[
+                "tau^m = 1 sur les points de E(F_2^m) : k et k mod (tau^m - 1) donnent le même multiple",
+                "Dans une base normale un élément est un vecteur de coefficients des conjugués beta^(2^i) : que lui fait l'élévation au carré ?",
+                "Les m images d'un point par le Frobenius sont gratuites, donc une marche aléatoire peut travailler sur des classes de m points ; pour un m composé, les sous-corps ouvrent la porte à la descente de Weil",
+            ],
+        ),
+        (
+            "Q20",
+            &[
+                "Un point singulier est sur la courbe et annule les deux dérivées partielles de y^2 - x^3 - ax - b",
+                "En caractéristique 2, 3x^2 + a = x^2 + a et chaque élément a exactement une racine carrée",
+                "x = sqrt(a), puis y^2 = x(x^2 + a) + b = b : y = sqrt(b), avec sqrt(z) = z^(2^(m - 1))",
+            ],
+        ),
+        (
+            "Q21",
+            &[
+                "D'abord P + (-P) = O, avec l'opposé de `GeneralCurve::neg` ; puis trouver la droite passant par P et Q",
+                "Substituer y = slope x + intercept : le coefficient de x^2 de la cubique donne x1 + x2 + x3 = slope^2 + a1 slope - a2",
+                "Tangente : slope = (3x^2 + 2a2 x + a4 - a1 y) / (2y + a1 x + a3), intercept = (-x^3 + a4 x + 2a6 - a3 y) / (2y + a1 x + a3) ; puis y3 = -(slope + a1) x3 - intercept - a3",
+            ],
+        ),
+        (
+            "Q22",
+            &[
+                "Une courbe est supersingulière quand elle n'a pas de point d'ordre p sur la clôture algébrique",
+                "En caractéristique 2, le j-invariant vaut a1^12 / discriminant : que vaut-il pour a1 = 0 ? En caractéristique 3, il vaut a2^6 / discriminant avec a1 = a3 = 0",
+                "Les courbes supersingulières ont un petit degré de plongement, et le Frobenius des petites caractéristiques accélère les logarithmes discrets dans les extensions (Joux, 2013)",
+            ],
+        ),
+    ],
+};
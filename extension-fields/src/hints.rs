@@ -0,0 +1,114 @@
+use runner::hints::Hints;
+use runner::lang::Catalog;
+
+pub const CATALOGS: &[Catalog] = &[
+    Catalog::english(HINTS, include_str!("main.rs")),
+    crate::fr::CATALOG,
+];
+
+pub const HINTS: Hints = &[
+    (
+        "Q1",
+        &[
+            "Elements are c0 + c1 u with u^2 = beta, multiplied as polynomials in u",
+            "(a0 + a1 u)(b0 + b1 u) = (a0 b0 + beta a1 b1) + (a0 b1 + a1 b0) u",
+            "The inverse of a is conj(a) / norm(a), the norm being in the base field",
+        ],
+    ),
+    (
+        "Q2",
+        &[
+            "A generator is a square iff its order divides (p - 1) / 2",
+            "-1 is a square modulo p iff p = 1 mod 4",
+            "89 mod 4 = 1: -1 is a square, so u^2 + 1 is reducible over F_89",
+        ],
+    ),
+    (
+        "Q3",
+        &[
+            "A monic polynomial of degree 2 is reducible iff it has a root in F_89",
+            "Count the reducible ones: (X - a)(X - b) with a = b or a != b",
+            "There are 89^2 monic polynomials of degree 2, of which 89 + 89 * 88 / 2 are reducible",
+        ],
+    ),
+    (
+        "Q6",
+        &[
+            "The Frobenius x -> x^p is a field automorphism which fixes F_p",
+            "u^p = u u^(p - 1) = (u^2)^((p - 1) / 2) u = beta^((p - 1) / 2) u",
+            "frobenius(c0 + c1 u) = c0 + c1 beta^((p - 1) / 2) u, and beta^((p - 1) / 2) = -1 for a non-square beta",
+        ],
+    ),
+    (
+        "Q14",
+        &[
+            "Isomorphic curves over the algebraic closure have the same j-invariant",
+            "j = 1728 4a^3 / (4a^3 + 27b^2): compute it for E', is it in F_89?",
+            "With q = 89^2 and t = q + 1 - #E'(F_q), the roots of X^2 - t X + q give #E'(F_q^2) = q^2 + 1 - (t^2 - 2q)",
+        ],
+    ),
+    (
+        "Q15",
+        &[
+            "Multiply like integers in binary, with XOR instead of the additions with carries",
+            "Shift-and-add: for each bit of b, add a if the bit is set, then multiply a by X, a left shift",
+            "After the shift, a bit at position m is X^m = f - X^m: XOR a with the modulus. The inverse is x^(2^m - 2)",
+        ],
+    ),
+    (
+        "Q16",
+        &[
+            "The line through P and Q meets the curve in a third point R, and P + Q = -R = (x_R, x_R + y_R)",
+            "Substitute y = slope x + c in y^2 + xy = x^3 + a x^2 + 1: the sum of the roots is slope^2 + slope + a",
+            "Chord: slope = (y1 + y2) / (x1 + x2), x3 = slope^2 + slope + x1 + x2 + a, y3 = slope (x1 + x3) + x3 + y1. Tangent: slope = x1 + y1 / x1, x3 = slope^2 + slope + a, y3 = x1^2 + slope x3 + x3",
+        ],
+    ),
+    (
+        "Q17",
+        &[
+            "The squaring is a field automorphism which fixes F_2, where a and 1 live",
+            "tau has trace mu and degree 2 as an endomorphism: its eigenvalues are the roots of X^2 - mu X + 2",
+            "With alpha and beta these roots, #E(F_2^m) = 2^m + 1 - (alpha^m + beta^m), and V_k = alpha^k + beta^k satisfies V_k = mu V_(k-1) - 2 V_(k-2)",
+        ],
+    ),
+    (
+        "Q18",
+        &[
+            "Like a NAF in base 2: take a digit which makes the remainder divisible by tau^2, then divide by tau",
+            "r0 + r1 tau is divisible by tau iff r0 is even, and tau^2 divides it iff r0 - 2 r1 = 0 mod 4",
+            "For an odd r0, the digit is 2 - ((r0 - 2 r1) mod 4), then (r0, r1) becomes (r1 + mu r0 / 2, -r0 / 2). Evaluate with Horner, from the highest digit",
+        ],
+    ),
+    (
+        "Q19",
+        &[
+            "tau^m = 1 on the points of E(F_2^m): k and k mod (tau^m - 1) give the same multiple",
+            "In a normal basis an element is a vector of coefficients of the conjugates beta^(2^i): what does squaring do to it?",
+            "The m images of a point by the Frobenius are computed for free, so a random walk may work on classes of m points; for a composite m, the subfields open the door to Weil descent",
+        ],
+    ),
+    (
+        "Q20",
+        &[
+            "A singular point is on the curve and cancels both partial derivatives of y^2 - x^3 - ax - b",
+            "In characteristic 2, 3x^2 + a = x^2 + a and every element has exactly one square root",
+            "x = sqrt(a), then y^2 = x(x^2 + a) + b = b: y = sqrt(b), with sqrt(z) = z^(2^(m - 1))",
+        ],
+    ),
+    (
+        "Q21",
+        &[
+            "P + (-P) = O first, with the opposite of `GeneralCurve::neg`; then find the line through P and Q",
+            "Substitute y = slope x + intercept: the x^2 coefficient of the cubic gives x1 + x2 + x3 = slope^2 + a1 slope - a2",
+            "Tangent: slope = (3x^2 + 2a2 x + a4 - a1 y) / (2y + a1 x + a3), intercept = (-x^3 + a4 x + 2a6 - a3 y) / (2y + a1 x + a3); then y3 = -(slope + a1) x3 - intercept - a3",
+        ],
+    ),
+    (
+        "Q22",
+        &[
+            "A curve is supersingular when it has no point of order p over the algebraic closure",
+            "In characteristic 2, the j-invariant is a1^12 / discriminant: what is it for a1 = 0? In characteristic 3, it is a2^6 / discriminant with a1 = a3 = 0",
+            "Supersingular curves have a small embedding degree, and the Frobenius of small characteristics speeds up discrete logs in the extension fields (Joux, 2013)",
+        ],
+    ),
+];
@@ -0,0 +1,176 @@
+use crate::*;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+// Binary fields F_2^m = F_2[X] / (f), f irreducible of degree m, in polynomial basis: an element is a
+// polynomial of degree < m over F_2, stored as the bits of a u64, bit i being the coefficient of X^i
+// - the addition is a XOR, and every element is its own opposite: the characteristic is 2
+// - the multiplication is a product of polynomials without carries, reduced modulo f
+// - the squaring x -> x^2 is the Frobenius, it is linear: (x + y)^2 = x^2 + y^2
+// sect163k1 lives in F_2^163 with f = X^163 + X^7 + X^6 + X^3 + 1, the toy curve below in F_2^17
+
+pub trait BinaryConfig: Copy + Debug + PartialEq {
+    const DEGREE: u32;
+    // the irreducible polynomial f, X^DEGREE included
+    const MODULUS: u64;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Binary<C: BinaryConfig> {
+    pub bits: u64,
+    _config: PhantomData<C>,
+}
+
+impl<C: BinaryConfig> Binary<C> {
+    pub fn new(bits: u64) -> Binary<C> {
+        assert!(bits >> C::DEGREE == 0, "degree {} or more", C::DEGREE);
+        Binary {
+            bits,
+            _config: PhantomData,
+        }
+    }
+
+    pub fn rand<R: rand::Rng>(rng: &mut R) -> Binary<C> {
+        Binary::new(rng.gen::<u64>() & ((1 << C::DEGREE) - 1))
+    }
+
+    pub fn elements() -> impl Iterator<Item = Binary<C>> {
+        (0..1 << C::DEGREE).map(Binary::new)
+    }
+
+    pub fn square(&self) -> Binary<C> {
+        *self * *self
+    }
+
+    // Tr(x) = x + x^2 + x^4 + ... + x^(2^(m - 1)), 0 or 1
+    pub fn trace(&self) -> Binary<C> {
+        let mut term = *self;
+        let mut trace = *self;
+        for _ in 1..C::DEGREE {
+            term = term.square();
+            trace = trace + term;
+        }
+        trace
+    }
+
+    // for an odd m, H(c) = sum of the c^(4^i), i <= (m - 1) / 2, solves z^2 + z = c when Tr(c) = 0
+    pub fn half_trace(&self) -> Binary<C> {
+        assert!(C::DEGREE % 2 == 1);
+        let mut term = *self;
+        let mut half_trace = *self;
+        for _ in 0..C::DEGREE / 2 {
+            term = term.square().square();
+            half_trace = half_trace + term;
+        }
+        half_trace
+    }
+}
+
+impl<C: BinaryConfig> Add for Binary<C> {
+    type Output = Binary<C>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)] // the coefficients are added modulo 2
+    fn add(self, other: Binary<C>) -> Binary<C> {
+        Binary::new(self.bits ^ other.bits)
+    }
+}
+
+impl<C: BinaryConfig> Sub for Binary<C> {
+    type Output = Binary<C>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)] // the coefficients are added modulo 2
+    fn sub(self, other: Binary<C>) -> Binary<C> {
+        Binary::new(self.bits ^ other.bits)
+    }
+}
+
+impl<C: BinaryConfig> Neg for Binary<C> {
+    type Output = Binary<C>;
+
+    fn neg(self) -> Binary<C> {
+        self
+    }
+}
+
+// Koblitz curves E_a: y^2 + xy = x^3 + a x^2 + 1 with a = 0 or 1, over F_2^m
+// The short Weierstrass form needs to divide by 2 and 3: in characteristic 2 the curve keeps its xy term,
+// so the opposite of (x, y) is (x, x + y) and the formulas of the group law change
+// The coefficients are in F_2: the Frobenius tau(x, y) = (x^2, y^2) maps the curve to itself, and
+// satisfies tau^2 - mu tau + 2 = 0 with mu = (-1)^(1 - a), the trace of E_a over F_2
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KoblitzCurve<C: BinaryConfig> {
+    pub a: Binary<C>,
+}
+
+impl<C: BinaryConfig> KoblitzCurve<C> {
+    pub fn new(a: u64) -> KoblitzCurve<C> {
+        assert!(a <= 1, "a Koblitz curve has a = 0 or 1");
+        KoblitzCurve { a: Binary::new(a) }
+    }
+
+    pub fn mu(&self) -> i64 {
+        if self.a == Binary::one() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    pub fn contains(&self, point: &Point<Binary<C>>) -> bool {
+        match *point {
+            Point::Infinity => true,
+            Point::Affine(x, y) => y * y + x * y == x * x * x + self.a * x * x + Binary::one(),
+        }
+    }
+
+    pub fn neg(&self, point: &Point<Binary<C>>) -> Point<Binary<C>> {
+        match *point {
+            Point::Infinity => Point::Infinity,
+            Point::Affine(x, y) => Point::Affine(x, x + y),
+        }
+    }
+
+    // y = xz turns the equation into z^2 + z = x + a + 1 / x^2, which has a solution iff its trace is 0
+    pub fn lift_x(&self, x: Binary<C>) -> Option<Point<Binary<C>>> {
+        let Some(x_inverse) = x.inverse() else {
+            return Some(Point::Affine(x, Binary::one())); // y^2 = 1
+        };
+        let c = x + self.a + x_inverse.square();
+        if c.trace() != Binary::zero() {
+            return None;
+        }
+        Some(Point::Affine(x, x * c.half_trace()))
+    }
+
+    pub fn random_point<R: rand::Rng>(&self, rng: &mut R) -> Point<Binary<C>> {
+        loop {
+            if let Some(point) = self.lift_x(Binary::rand(rng)) {
+                return point;
+            }
+        }
+    }
+
+    // the point at infinity, (0, 1), and two points for each x of trace 0
+    pub fn count_points(&self) -> u64 {
+        Binary::<C>::elements()
+            .map(|x| match self.lift_x(x) {
+                None => 0,
+                Some(_) if x == Binary::zero() => 1,
+                Some(_) => 2,
+            })
+            .sum::<u64>()
+            + 1
+    }
+
+    // double-and-add, with your `add`
+    pub fn mul(&self, point: &Point<Binary<C>>, scalar: u64) -> Point<Binary<C>> {
+        let mut result = Point::Infinity;
+        for bit in (0..64).rev() {
+            result = self.add(&result, &result);
+            if (scalar >> bit) & 1 == 1 {
+                result = self.add(&result, point);
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,999 @@
+use ark_bls12_381::{Fq, Fq12, Fq2, Fq6};
+use ark_ff::{Field, MontFp};
+use ark_std::{ops::Mul, ops::Neg, ops::Sub, UniformRand};
+use koblitz::*;
+use rand::Rng;
+use runner::Runner;
+use sage_fixtures::Fixtures;
+use std::ops::Add;
+use tower::*;
+use toy_curve::*;
+
+mod fr;
+mod hints;
+mod koblitz;
+mod scoreboard;
+mod tower;
+mod toy_curve;
+
+mod f89 {
+    #![allow(non_local_definitions)] // the derive macro writes its impl blocks inside a function
+    use ark_ff::{Fp64, MontBackend, MontConfig};
+
+    #[derive(MontConfig)]
+    #[modulus = "89"]
+    #[generator = "3"]
+    pub struct F89Config;
+    pub type F89 = Fp64<MontBackend<F89Config, 1>>;
+}
+use f89::F89;
+
+mod f3 {
+    #![allow(non_local_definitions)]
+    use ark_ff::{Fp64, MontBackend, MontConfig};
+
+    #[derive(MontConfig)]
+    #[modulus = "3"]
+    #[generator = "2"]
+    pub struct F3Config;
+    pub type F3 = Fp64<MontBackend<F3Config, 1>>;
+}
+use f3::F3;
+
+// F_9 = F_3[u] / (u^2 + 1) and F_81 = F_9[w] / (w^2 - (1 + u)), a field of characteristic 3
+// F_3[v] / (v^3 - xi) is no field here: v^3 - xi = (v - xi)^3, the cube is the Frobenius
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct F9Config;
+impl QuadraticConfig for F9Config {
+    type Base = F3;
+    fn non_residue() -> F3 {
+        MontFp!("-1")
+    }
+}
+pub type F9 = Quadratic<F9Config>;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct F81Config;
+impl QuadraticConfig for F81Config {
+    type Base = F9;
+    // its norm 2 is not a square of F_3
+    fn non_residue() -> F9 {
+        F9::new(MontFp!("1"), MontFp!("1"))
+    }
+}
+pub type F81 = Quadratic<F81Config>;
+
+// F_89^2 = F_89[u] / (u^2 - 3)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Toy2Config;
+impl QuadraticConfig for Toy2Config {
+    type Base = F89;
+    fn non_residue() -> F89 {
+        MontFp!("3")
+    }
+}
+pub type ToyFp2 = Quadratic<Toy2Config>;
+
+// The tower of BLS12-381, the same as `ark_bls12_381`'s one
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bls2Config;
+impl QuadraticConfig for Bls2Config {
+    type Base = Fq;
+    // p = 3 mod 4 so -1 is not a square: u = sqrt(-1) like the complex numbers
+    fn non_residue() -> Fq {
+        MontFp!("-1")
+    }
+}
+pub type BlsFp2 = Quadratic<Bls2Config>;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bls6Config;
+impl CubicConfig for Bls6Config {
+    type Base = BlsFp2;
+    fn non_residue() -> BlsFp2 {
+        BlsFp2::new(MontFp!("1"), MontFp!("1")) // xi = 1 + u
+    }
+}
+pub type BlsFp6 = Cubic<Bls6Config>;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bls12Config;
+impl QuadraticConfig for Bls12Config {
+    type Base = BlsFp6;
+    fn non_residue() -> BlsFp6 {
+        BlsFp6::new(BlsFp2::zero(), BlsFp2::one(), BlsFp2::zero()) // v
+    }
+}
+pub type BlsFp12 = Quadratic<Bls12Config>;
+
+// F_2^17 = F_2[X] / (X^17 + X^3 + 1)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Toy2mConfig;
+impl BinaryConfig for Toy2mConfig {
+    const DEGREE: u32 = 17;
+    const MODULUS: u64 = 1 << 17 | 1 << 3 | 1;
+}
+pub type ToyF2m = Binary<Toy2mConfig>;
+
+fn main() {
+    let args = runner::args();
+    if let [command] = args.as_slice() {
+        if command == "scoreboard" {
+            return scoreboard::scoreboard();
+        }
+    }
+
+    let runner = Runner::start(env!("CARGO_PKG_NAME"), hints::CATALOGS);
+    let mut rng = seed::rng();
+
+    // Take a look at module `tower`
+    // Q1: implement the arithmetic of the quadratic extension `Quadratic` at the end of this file:
+    // `Add`, `Sub`, `Neg`, `Mul` (reduce with u^2 = beta), then `conjugate`, `norm` and `inverse`
+    // The conjugate of a = c0 + c1 u is c0 - c1 u, and the norm a * conj(a) = c0^2 - beta c1^2 lies in the base field
+    runner.question("Q1");
+    let u = ToyFp2::new(F89::from(0), F89::from(1));
+    assert_eq!(u * u, ToyFp2::new(F89::from(3), F89::from(0)));
+    let a = ToyFp2::new(F89::from(5), F89::from(27));
+    let b = ToyFp2::new(F89::from(42), F89::from(8));
+    assert_eq!(a + b, ToyFp2::new(F89::from(47), F89::from(35)));
+    assert_eq!(a - b, ToyFp2::new(F89::from(52), F89::from(19)));
+    // (5 + 27u)(42 + 8u) = 210 + 648 + (40 + 1134)u
+    assert_eq!(a * b, ToyFp2::new(F89::from(858), F89::from(1174)));
+    assert_eq!(a.norm(), F89::from(25) - F89::from(3 * 27 * 27));
+
+    // F_89^2 is small enough to check everything exhaustively
+    let elements: Vec<ToyFp2> = (0..89u64)
+        .flat_map(|c0| (0..89u64).map(move |c1| ToyFp2::new(F89::from(c0), F89::from(c1))))
+        .collect();
+    for x in &elements {
+        if *x == ToyFp2::zero() {
+            assert_eq!(x.inverse(), None);
+            continue;
+        }
+        assert_eq!(*x * x.inverse().unwrap(), ToyFp2::one());
+        assert_ne!(x.norm(), F89::from(0));
+        assert_eq!(x.norm(), (*x * x.conjugate()).c0);
+        assert_eq!(x.conjugate().conjugate(), *x);
+    }
+    // the norm is multiplicative
+    for _ in 0..1000 {
+        let (x, y) = (random_toy(&mut rng), random_toy(&mut rng));
+        assert_eq!((x * y).norm(), x.norm() * y.norm());
+        assert_eq!((x * y).conjugate(), x.conjugate() * y.conjugate());
+    }
+
+    // Q2: 3 generates the multiplicative group of F_89, why is it a non-square?
+    // Why couldn't we take u^2 = -1 as for the complex numbers?
+    // Here is what happens with u^2 = 4: the polynomial u^2 - 4 = (u - 2)(u + 2) is reducible
+    runner.question("Q2");
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Reducible;
+    impl QuadraticConfig for Reducible {
+        type Base = F89;
+        fn non_residue() -> F89 {
+            MontFp!("4")
+        }
+    }
+    let x = Quadratic::<Reducible>::new(F89::from(-2), F89::from(1));
+    let y = Quadratic::<Reducible>::new(F89::from(2), F89::from(1));
+    assert_eq!(x * y, Quadratic::zero()); // zero divisors: this is a ring, not a field
+    assert_eq!(x.inverse(), None);
+    // Q3: how many irreducible monic polynomials of degree 2 are there over F_89?
+    runner.question("Q3");
+
+    // Now let's compare with the extension fields of arkworks on BLS12-381, with a 381-bit base field
+    for _ in 0..100 {
+        let (x, y) = (Fq2::rand(&mut rng), Fq2::rand(&mut rng));
+        let (mx, my) = (from_ark2(&x), from_ark2(&y));
+        assert_eq!(mx + my, from_ark2(&(x + y)));
+        assert_eq!(mx - my, from_ark2(&(x - y)));
+        assert_eq!(mx * my, from_ark2(&(x * y)));
+        assert_eq!(
+            mx.inverse().unwrap(),
+            from_ark2(&Field::inverse(&x).unwrap())
+        );
+        assert_eq!(mx.norm(), x.norm());
+        let mut conjugate = x;
+        conjugate.conjugate_in_place();
+        assert_eq!(mx.conjugate(), from_ark2(&conjugate));
+    }
+
+    // The same code, stacked on `Cubic`, gives F_p12
+    for _ in 0..100 {
+        let (x, y) = (Fq12::rand(&mut rng), Fq12::rand(&mut rng));
+        let (mx, my) = (from_ark12(&x), from_ark12(&y));
+        assert_eq!(mx * my, from_ark12(&(x * y)));
+        assert_eq!(
+            mx.inverse().unwrap(),
+            from_ark12(&Field::inverse(&x).unwrap())
+        );
+    }
+    // Q4: why is 1 + u neither a square nor a cube in F_p2? Why is v a non-square in F_p6, so that w^2 = v
+    // defines F_p12? What would be the cost of a multiplication in F_p12 built directly as F_p[X] / (an irreducible of degree 12)?
+    runner.question("Q4");
+
+    // The Frobenius endomorphism x -> x^p
+    // It is a field automorphism of F_p^k: (x + y)^p = x^p + y^p since the binomial coefficients vanish mod p
+    // and it fixes exactly the base field F_p (Fermat's little theorem, and x^p - x has at most p roots)
+    // Q5: write `power` which computes x^e by square-and-multiply
+    // Q6: write `Quadratic::frobenius` *without* exponentiation
+    runner.questions(&["Q5", "Q6"]);
+    let p: u64 = 89;
+    assert_eq!(power(F89::from(5), 7), F89::from(72));
+    let mut fixed = 0;
+    for x in &elements {
+        assert_eq!(x.frobenius(), power(*x, p));
+        assert_eq!(x.frobenius().frobenius(), *x);
+        if x.frobenius() == *x {
+            assert_eq!(x.c1, F89::from(0));
+            fixed += 1;
+        }
+    }
+    assert_eq!(fixed, p);
+    for _ in 0..1000 {
+        let (x, y) = (random_toy(&mut rng), random_toy(&mut rng));
+        assert_eq!((x + y).frobenius(), x.frobenius() + y.frobenius());
+        assert_eq!((x * y).frobenius(), x.frobenius() * y.frobenius());
+    }
+    // the same on BLS12-381, compared with arkworks
+    for _ in 0..100 {
+        let x = Fq2::rand(&mut rng);
+        let mut frobenius = x;
+        frobenius.frobenius_map_in_place(1);
+        assert_eq!(from_ark2(&x).frobenius(), from_ark2(&frobenius));
+    }
+
+    // Frobenius and point counting: #E(F_p) = p + 1 - t where t is the trace of Frobenius
+    // pi(x, y) = (x^p, y^p) maps the points of E over F_p2 to points of E, and fixes exactly E(F_p)
+    // it satisfies pi^2 - [t] pi + [p] = 0, so #E(F_p^2) = p^2 + 1 - (t^2 - 2p)
+    let fixtures: Fixtures = include_str!("../fixtures/toy.fixtures").parse().unwrap();
+    let order: u64 = fixtures.get("toy", "order");
+    // Q7: write `trace_of_frobenius` from p and #E(F_p)
+    runner.question("Q7");
+    let t = trace_of_frobenius(p, order);
+    assert_eq!(t, fixtures.get::<i64>("toy", "trace"));
+    // Q8: write `count_points_fp2` which counts the points of y^2 = x^3 + 7 over F_89^2 with Euler's criterion:
+    // a non-zero a is a square in F_q iff a^((q - 1) / 2) = 1
+    runner.question("Q8");
+    let order_fp2 = count_points_fp2(&elements);
+    assert_eq!(order_fp2, fixtures.get::<u64>("toy", "order_fp2"));
+    assert_eq!(
+        order_fp2 as i64,
+        (p * p + 1) as i64 - (t * t - 2 * p as i64)
+    );
+
+    // Q9: write `frobenius_point` and check the characteristic equation on points of E(F_89^2)
+    runner.question("Q9");
+    let b = ToyFp2::new(F89::from(7), F89::from(0));
+    // x-coordinates in F_89 (every 89th element) and some more in F_89^2
+    let points: Vec<Point<ToyFp2>> = elements
+        .iter()
+        .step_by(89)
+        .take(20)
+        .chain(elements.iter().step_by(89 * 3 + 1))
+        .flat_map(|x| {
+            let rhs = *x * *x * *x + b;
+            elements
+                .iter()
+                .filter(move |y| **y * **y == rhs)
+                .map(move |y| Point::Affine(*x, *y))
+        })
+        .collect();
+    assert!(points.len() > 10);
+    let mut rational = 0;
+    for point in &points {
+        assert!(on_curve(point, b));
+        let image = frobenius_point(point);
+        assert!(on_curve(&image, b));
+        let t_pi = if t >= 0 {
+            image * t as u64
+        } else {
+            -(image * (-t) as u64)
+        };
+        assert_eq!(
+            frobenius_point(&image) + -t_pi + *point * p,
+            Point::Infinity
+        );
+        if image == *point {
+            rational += 1;
+        }
+    }
+    println!(
+        "{} of the {} sampled points of E(F_89^2) are in E(F_89)",
+        rational,
+        points.len()
+    );
+    // Q10: here t = 0, the curve is supersingular: what is pi^2? Why is p = 2 mod 3 the reason?
+    runner.question("Q10");
+
+    // Curves live over any field, not only prime ones: take a look at `WeierstrassCurve` in module `toy_curve`
+    // E': y^2 = x^3 + (1 + u) x + 5u has its coefficients in F_89^2, and no sense over F_89
+    let coefficient = |key| {
+        let c: Vec<u64> = fixtures.get_list("weierstrass", key);
+        ToyFp2::new(F89::from(c[0]), F89::from(c[1]))
+    };
+    let curve = WeierstrassCurve::new(coefficient("a"), coefficient("b"));
+    // Q11: write `WeierstrassCurve::add`, the chord-and-tangent law with a generic a
+    runner.question("Q11");
+    let first = elements
+        .iter()
+        .find_map(|x| {
+            let rhs = *x * *x * *x + curve.a * *x + curve.b;
+            elements
+                .iter()
+                .find(|y| **y != ToyFp2::zero() && **y * **y == rhs)
+                .map(|y| Point::Affine(*x, *y))
+        })
+        .unwrap();
+    assert_eq!(curve.add(&first, &Point::Infinity), first);
+    assert_eq!(curve.add(&first, &-first), Point::Infinity);
+    let double = curve.add(&first, &first);
+    assert!(curve.contains(&double));
+    let triple = curve.add(&double, &first);
+    assert!(curve.contains(&triple));
+    assert_eq!(triple, curve.add(&first, &double));
+    assert_eq!(
+        curve.add(&triple, &double),
+        curve.add(&curve.add(&double, &double), &first)
+    );
+    // with a = 0, the law is the one of module `toy_curve`
+    let e7 = WeierstrassCurve::new(ToyFp2::zero(), b);
+    for point in &points {
+        assert_eq!(e7.add(point, point), *point + *point);
+        assert_eq!(e7.mul(point, 10), *point * 10);
+    }
+
+    // Q12: write `count_points_weierstrass`, as `count_points_fp2` for any coefficients
+    runner.question("Q12");
+    let order = count_points_weierstrass(&curve, &elements);
+    assert_eq!(order, fixtures.get::<u64>("weierstrass", "order"));
+    // Hasse bound: |#E - (q + 1)| <= 2 sqrt(q) with q = 89^2
+    assert!(order.abs_diff(p * p + 1) <= 2 * p);
+
+    // Q13: write `weierstrass_points` which lists the affine points of the curve
+    runner.question("Q13");
+    let curve_points = weierstrass_points(&curve, &elements);
+    assert_eq!(curve_points.len() as u64 + 1, order);
+    let in_prime_field = |z: &ToyFp2| z.c1 == F89::from(0);
+    let outside = curve_points
+        .iter()
+        .filter(|point| match point {
+            Point::Affine(x, y) => !in_prime_field(x) && !in_prime_field(y),
+            Point::Infinity => false,
+        })
+        .count();
+    println!(
+        "{} of the {} points of E'(F_89^2) have both coordinates outside F_89",
+        outside, order
+    );
+    // the points form a group of order #E': closure, inverses, and Lagrange's theorem
+    for (i, point) in curve_points.iter().enumerate().step_by(97) {
+        assert!(curve.contains(point));
+        let other = curve_points[(i * 31 + 7) % curve_points.len()];
+        assert!(curve.contains(&curve.add(point, &other)));
+        assert_eq!(curve.add(point, &other), curve.add(&other, point));
+        assert_eq!(curve.add(point, &-*point), Point::Infinity);
+        assert_eq!(curve.mul(point, order), Point::Infinity);
+    }
+
+    // Q14: E' has points with coordinates in F_89 or not, but can it be defined over F_89 after a change of
+    // variables?
+    // How many points does E' have over F_89^4?
+    runner.question("Q14");
+
+    // Characteristic 2: take a look at module `koblitz`
+    // Q15: implement the multiplication of `Binary` at the end of this file, a product of polynomials over F_2
+    // reduced modulo f, then its `inverse`
+    runner.question("Q15");
+    let fixtures: Fixtures = include_str!("../fixtures/koblitz.fixtures")
+        .parse()
+        .unwrap();
+    assert_eq!(ToyF2m::elements().count(), 1 << Toy2mConfig::DEGREE);
+    assert_eq!(Toy2mConfig::DEGREE, fixtures.get::<u32>("binary", "degree"));
+    assert_eq!(
+        Toy2mConfig::MODULUS,
+        fixtures.get::<u64>("binary", "modulus")
+    );
+    let element = |key| ToyF2m::new(fixtures.get("binary", key));
+    let (a, b) = (element("a"), element("b"));
+    assert_eq!(a * b, element("product"));
+    assert_eq!(a.inverse(), Some(element("inverse")));
+    let x = ToyF2m::new(1 << 16);
+    assert_eq!(x * ToyF2m::new(2), ToyF2m::new(0b1001)); // X^17 = X^3 + 1
+    assert_eq!(ToyF2m::zero().inverse(), None);
+    for x in ToyF2m::elements().skip(1) {
+        assert_eq!(x * x.inverse().unwrap(), ToyF2m::one());
+    }
+    for _ in 0..1000 {
+        let (x, y, z) = (
+            ToyF2m::rand(&mut rng),
+            ToyF2m::rand(&mut rng),
+            ToyF2m::rand(&mut rng),
+        );
+        assert_eq!(x * y, y * x);
+        assert_eq!(x * (y + z), x * y + x * z);
+        assert_eq!((x * y) * z, x * (y * z));
+        assert_eq!((x + y).square(), x.square() + y.square());
+        assert_eq!(power(x, 1 << Toy2mConfig::DEGREE), x);
+    }
+
+    // The toy Koblitz curve y^2 + xy = x^3 + x^2 + 1 over F_2^17, the equation of sect163k1
+    let koblitz = KoblitzCurve::<Toy2mConfig>::new(1);
+    let order_2m: u64 = fixtures.get("koblitz", "order");
+    let cofactor: u64 = fixtures.get("koblitz", "cofactor");
+    let generator = {
+        let g: Vec<u64> = fixtures.get_list("koblitz", "generator");
+        Point::Affine(ToyF2m::new(g[0]), ToyF2m::new(g[1]))
+    };
+    // Q16: write `KoblitzCurve::add`: the chord and the tangent still meet the curve in a third point, but
+    // the opposite of (x, y) is now (x, x + y)
+    runner.question("Q16");
+    assert!(koblitz.contains(&generator));
+    assert_eq!(koblitz.add(&generator, &Point::Infinity), generator);
+    assert_eq!(koblitz.add(&Point::Infinity, &generator), generator);
+    assert_eq!(
+        koblitz.add(&generator, &koblitz.neg(&generator)),
+        Point::Infinity
+    );
+    // (0, 1) is its own opposite, the point of order 2
+    let two_torsion = Point::Affine(ToyF2m::zero(), ToyF2m::one());
+    assert!(koblitz.contains(&two_torsion));
+    assert_eq!(koblitz.add(&two_torsion, &two_torsion), Point::Infinity);
+    let double = koblitz.add(&generator, &generator);
+    assert!(koblitz.contains(&double));
+    assert_eq!(
+        koblitz.mul(&generator, order_2m / cofactor),
+        Point::Infinity
+    );
+    assert_ne!(koblitz.mul(&generator, 1000), Point::Infinity);
+    for _ in 0..100 {
+        let (p, q, r) = (
+            koblitz.random_point(&mut rng),
+            koblitz.random_point(&mut rng),
+            koblitz.random_point(&mut rng),
+        );
+        let sum = koblitz.add(&p, &q);
+        assert!(koblitz.contains(&sum));
+        assert_eq!(sum, koblitz.add(&q, &p));
+        assert_eq!(koblitz.add(&sum, &r), koblitz.add(&p, &koblitz.add(&q, &r)));
+        assert_eq!(koblitz.mul(&p, order_2m), Point::Infinity);
+    }
+
+    // Q17: write `KoblitzCurve::frobenius`, then `koblitz_order` which gives #E_a(F_2^m) from the
+    // characteristic equation of tau, as #E(F_89^2) in Q8
+    runner.question("Q17");
+    let mu = koblitz.mu();
+    for _ in 0..100 {
+        let point = koblitz.random_point(&mut rng);
+        let image = koblitz.frobenius(&point);
+        assert!(koblitz.contains(&image));
+        let mu_tau = match mu {
+            1 => image,
+            _ => koblitz.neg(&image),
+        };
+        // tau^2 + 2 = mu tau
+        assert_eq!(
+            koblitz.add(&koblitz.frobenius(&image), &koblitz.mul(&point, 2)),
+            mu_tau
+        );
+        let cycle = (0..Toy2mConfig::DEGREE).fold(point, |q, _| koblitz.frobenius(&q));
+        assert_eq!(cycle, point);
+    }
+    assert_eq!(koblitz.count_points(), order_2m);
+    let orders: Vec<u64> = fixtures.get_list("koblitz", "orders");
+    for (m, order) in (1..).zip(orders) {
+        assert_eq!(koblitz_order(1, m), order, "m = {}", m);
+    }
+    // E_0 has (0, 1), (1, 0), (1, 1) and the point at infinity over F_2
+    assert_eq!(koblitz_order(0, 1), 4);
+    assert_eq!(koblitz_order(0, 5), 44);
+
+    // A scalar multiplication without doublings: write k as a sum of powers of tau, then
+    // kP = u_0 P + tau(u_1 P + tau(u_2 P + ...)), the tau-adic NAF has digits 0, 1 and -1 and no two
+    // consecutive non-zero digits
+    // Q18: write `tnaf` which expands k in the ring Z[tau] (tau^2 = mu tau - 2), and `KoblitzCurve::tau_mul`
+    runner.question("Q18");
+    for k in [9, 100, 65587] {
+        let expected: Vec<i8> = fixtures.get_list("koblitz", &format!("tnaf_{}", k));
+        assert_eq!(tnaf(k, mu), expected, "k = {}", k);
+    }
+    for _ in 0..100 {
+        let k = rng.gen_range(0..order_2m);
+        let digits = tnaf(k, mu);
+        assert!(digits.windows(2).all(|pair| pair[0] * pair[1] == 0));
+        let point = koblitz.random_point(&mut rng);
+        assert_eq!(koblitz.tau_mul(&point, k), koblitz.mul(&point, k));
+    }
+    let digits = tnaf(order_2m / cofactor - 1, mu);
+    println!(
+        "[n - 1]G: {} digits, {} additions and no doubling for tau_mul, against 16 doublings for double-and-add",
+        digits.len(),
+        digits.iter().filter(|digit| **digit != 0).count()
+    );
+
+    // Q19: the expansion of k has about 2 log2(k) digits: how would you shorten it to about m digits?
+    // Why are the squarings almost free with a normal basis?
+    // Why does the Frobenius also speed up Pollard's rho on a Koblitz curve, and why must m be prime?
+    runner.question("Q19");
+
+    // The short form y^2 = x^3 + ax + b needs 2 and 3 to be invertible: take a look at `GeneralCurve` in
+    // module `toy_curve`
+    // In characteristic 2, the derivative of y^2 in y is 2y = 0, and the one of x^3 + ax + b in x is
+    // x^2 + a, which always vanishes: every curve of the short form has a singular point
+    // Q20: write `singular_point`, the singular point of y^2 = x^3 + ax + b over F_2^17
+    runner.question("Q20");
+    for _ in 0..100 {
+        let (a, b) = (ToyF2m::rand(&mut rng), ToyF2m::rand(&mut rng));
+        let short = GeneralCurve {
+            a1: ToyF2m::zero(),
+            a2: ToyF2m::zero(),
+            a3: ToyF2m::zero(),
+            a4: a,
+            a6: b,
+        };
+        assert_eq!(short.discriminant(), ToyF2m::zero());
+        let singular = singular_point(a, b);
+        assert!(short.contains(&singular));
+        let Point::Affine(x, _) = singular else {
+            panic!("the singular point is affine");
+        };
+        assert_eq!(small::<ToyF2m>(3) * x * x + a, ToyF2m::zero());
+    }
+    // in characteristic 3, 4a^3 + 27b^2 = a^3: y^2 = x^3 + b has a cusp, since x^3 + b = (x + b^(1/3))^3
+    let cusp = GeneralCurve {
+        a1: F81::zero(),
+        a2: F81::zero(),
+        a3: F81::zero(),
+        a4: F81::zero(),
+        a6: -F81::one(),
+    };
+    assert_eq!(cusp.discriminant(), F81::zero());
+    assert!(cusp.contains(&Point::Affine(F81::one(), F81::zero())));
+
+    // Q21: write `GeneralCurve::add`, the group law of the general Weierstrass equation
+    // - the opposite of (x, y) is (x, -y - a1 x - a3), see `GeneralCurve::neg`
+    // - the chord or the tangent y = slope x + intercept meets the curve in a third point
+    runner.question("Q21");
+    let f9: Vec<F9> = (0..3u64)
+        .flat_map(|c0| (0..3u64).map(move |c1| F9::new(F3::from(c0), F3::from(c1))))
+        .collect();
+    let f81: Vec<F81> = f9
+        .iter()
+        .flat_map(|c0| f9.iter().map(move |c1| F81::new(*c0, *c1)))
+        .collect();
+    // y^2 = x^3 + x^2 - 1 over F_81: the x^2 term cannot be removed by x -> x - a2 / 3
+    let char3 = GeneralCurve::new(
+        F81::zero(),
+        F81::one(),
+        F81::zero(),
+        F81::zero(),
+        -F81::one(),
+    );
+    let mut group = char3.points(&f81);
+    group.push(Point::Infinity);
+    let order = group.len() as u64;
+    assert!(order.abs_diff(81 + 1) <= 2 * 9);
+    println!("y^2 = x^3 + x^2 - 1 has {} points over F_81", order);
+    // the axioms of a commutative group, on every point
+    for p in &group {
+        assert_eq!(char3.add(p, &Point::Infinity), *p);
+        assert_eq!(char3.add(p, &char3.neg(p)), Point::Infinity);
+        assert_eq!(char3.mul(p, order), Point::Infinity);
+        for q in &group {
+            let sum = char3.add(p, q);
+            assert!(char3.contains(&sum));
+            assert_eq!(sum, char3.add(q, p));
+            for r in &group {
+                assert_eq!(char3.add(&sum, r), char3.add(p, &char3.add(q, r)));
+            }
+        }
+    }
+    // the same law as the Koblitz curve y^2 + xy = x^3 + x^2 + 1 in characteristic 2...
+    let (zero, one) = (ToyF2m::zero(), ToyF2m::one());
+    let char2 = GeneralCurve::new(one, one, zero, zero, one);
+    for _ in 0..100 {
+        let (p, q) = (
+            koblitz.random_point(&mut rng),
+            koblitz.random_point(&mut rng),
+        );
+        assert_eq!(char2.add(&p, &q), koblitz.add(&p, &q));
+        assert_eq!(char2.add(&p, &p), koblitz.add(&p, &p));
+    }
+    // ...and as E' of Q11 over F_89^2
+    let general = GeneralCurve::new(
+        ToyFp2::zero(),
+        ToyFp2::zero(),
+        ToyFp2::zero(),
+        curve.a,
+        curve.b,
+    );
+    for (p, q) in curve_points
+        .iter()
+        .zip(curve_points.iter().rev())
+        .step_by(50)
+    {
+        assert_eq!(general.add(p, q), curve.add(p, q));
+        assert_eq!(general.add(p, p), curve.add(p, p));
+    }
+
+    // Q22: why are the curves with a2 = 0 supersingular in characteristic 3, and those with a1 = 0 in
+    // characteristic 2? Why do the standards avoid small characteristics for pairing-free curves?
+    runner.question("Q22");
+
+    println!("Good job!");
+    runner.finish();
+}
+
+fn random_toy<R: rand::Rng>(rng: &mut R) -> ToyFp2 {
+    ToyFp2::new(F89::rand(rng), F89::rand(rng))
+}
+
+fn from_ark2(x: &Fq2) -> BlsFp2 {
+    BlsFp2::new(x.c0, x.c1)
+}
+
+fn from_ark6(x: &Fq6) -> BlsFp6 {
+    BlsFp6::new(from_ark2(&x.c0), from_ark2(&x.c1), from_ark2(&x.c2))
+}
+
+fn from_ark12(x: &Fq12) -> BlsFp12 {
+    BlsFp12::new(from_ark6(&x.c0), from_ark6(&x.c1))
+}
+
+impl<C: QuadraticConfig> Add for Quadratic<C> {
+    type Output = Quadratic<C>;
+
+    fn add(self, other: Quadratic<C>) -> Quadratic<C> {
+        // SOLUTION-BEGIN
+        Quadratic::new(self.c0 + other.c0, self.c1 + other.c1)
+        // SOLUTION-END
+    }
+}
+
+impl<C: QuadraticConfig> Sub for Quadratic<C> {
+    type Output = Quadratic<C>;
+
+    fn sub(self, other: Quadratic<C>) -> Quadratic<C> {
+        // SOLUTION-BEGIN
+        Quadratic::new(self.c0 - other.c0, self.c1 - other.c1)
+        // SOLUTION-END
+    }
+}
+
+impl<C: QuadraticConfig> Neg for Quadratic<C> {
+    type Output = Quadratic<C>;
+
+    fn neg(self) -> Quadratic<C> {
+        // SOLUTION-BEGIN
+        Quadratic::new(-self.c0, -self.c1)
+        // SOLUTION-END
+    }
+}
+
+impl<C: QuadraticConfig> Mul for Quadratic<C> {
+    type Output = Quadratic<C>;
+
+    fn mul(self, other: Quadratic<C>) -> Quadratic<C> {
+        // SOLUTION-BEGIN
+        // Karatsuba: 3 multiplications in the base field instead of 4
+        let v0 = self.c0 * other.c0;
+        let v1 = self.c1 * other.c1;
+        let cross = (self.c0 + self.c1) * (other.c0 + other.c1) - v0 - v1;
+        Quadratic::new(v0 + C::non_residue() * v1, cross)
+        // SOLUTION-END
+    }
+}
+
+impl<C: QuadraticConfig> Quadratic<C> {
+    pub fn conjugate(&self) -> Quadratic<C> {
+        // SOLUTION-BEGIN
+        Quadratic::new(self.c0, -self.c1)
+        // SOLUTION-END
+    }
+
+    pub fn norm(&self) -> C::Base {
+        // SOLUTION-BEGIN
+        self.c0 * self.c0 - C::non_residue() * (self.c1 * self.c1)
+        // SOLUTION-END
+    }
+}
+
+impl<C: QuadraticConfig> TowerField for Quadratic<C> {
+    fn zero() -> Quadratic<C> {
+        Quadratic::new(C::Base::zero(), C::Base::zero())
+    }
+
+    fn one() -> Quadratic<C> {
+        Quadratic::new(C::Base::one(), C::Base::zero())
+    }
+
+    fn inverse(&self) -> Option<Quadratic<C>> {
+        // SOLUTION-BEGIN
+        // a^-1 = conj(a) / norm(a)
+        let norm_inverse = self.norm().inverse()?;
+        let conjugate = self.conjugate();
+        Some(Quadratic::new(
+            conjugate.c0 * norm_inverse,
+            conjugate.c1 * norm_inverse,
+        ))
+        // SOLUTION-END
+    }
+}
+
+fn power<F: TowerField>(x: F, exponent: u64) -> F {
+    // SOLUTION-BEGIN
+    let mut result = F::one();
+    for bit in (0..64).rev() {
+        result = result * result;
+        if (exponent >> bit) & 1 == 1 {
+            result = result * x;
+        }
+    }
+    result
+    // SOLUTION-END
+}
+
+impl<C: QuadraticConfig> Quadratic<C>
+where
+    C::Base: Field,
+{
+    pub fn frobenius(&self) -> Quadratic<C> {
+        // SOLUTION-BEGIN
+        // beta is not a square so beta^((p - 1) / 2) = -1: the Frobenius is the conjugation
+        self.conjugate()
+        // SOLUTION-END
+    }
+}
+
+fn trace_of_frobenius(p: u64, order: u64) -> i64 {
+    // SOLUTION-BEGIN
+    (p + 1) as i64 - order as i64
+    // SOLUTION-END
+}
+
+fn count_points_fp2(elements: &[ToyFp2]) -> u64 {
+    // SOLUTION-BEGIN
+    let q = elements.len() as u64;
+    let b = ToyFp2::new(F89::from(7), F89::from(0));
+    let mut count = 1; // the point at infinity
+    for x in elements {
+        let rhs = *x * *x * *x + b;
+        if rhs == ToyFp2::zero() {
+            count += 1;
+        } else if power(rhs, (q - 1) / 2) == ToyFp2::one() {
+            count += 2;
+        }
+    }
+    count
+    // SOLUTION-END
+}
+
+fn frobenius_point(point: &Point<ToyFp2>) -> Point<ToyFp2> {
+    // SOLUTION-BEGIN
+    match *point {
+        Point::Infinity => Point::Infinity,
+        Point::Affine(x, y) => Point::Affine(x.frobenius(), y.frobenius()),
+    }
+    // SOLUTION-END
+}
+
+impl<F: TowerField> WeierstrassCurve<F> {
+    pub fn add(&self, p: &Point<F>, q: &Point<F>) -> Point<F> {
+        // SOLUTION-BEGIN
+        let (x1, y1, x2, y2) = match (*p, *q) {
+            (Point::Infinity, _) => return *q,
+            (_, Point::Infinity) => return *p,
+            (Point::Affine(x1, y1), Point::Affine(x2, y2)) => (x1, y1, x2, y2),
+        };
+        let slope = if x1 != x2 {
+            (y2 - y1) * (x2 - x1).inverse().unwrap()
+        } else if y1 == y2 && y1 != F::zero() {
+            let x_squared = x1 * x1;
+            (x_squared + x_squared + x_squared + self.a) * (y1 + y1).inverse().unwrap()
+        } else {
+            return Point::Infinity;
+        };
+        let x3 = slope * slope - x1 - x2;
+        Point::Affine(x3, slope * (x1 - x3) - y1)
+        // SOLUTION-END
+    }
+}
+
+fn count_points_weierstrass(curve: &WeierstrassCurve<ToyFp2>, elements: &[ToyFp2]) -> u64 {
+    // SOLUTION-BEGIN
+    let q = elements.len() as u64;
+    let mut count = 1;
+    for x in elements {
+        let rhs = *x * *x * *x + curve.a * *x + curve.b;
+        if rhs == ToyFp2::zero() {
+            count += 1;
+        } else if power(rhs, (q - 1) / 2) == ToyFp2::one() {
+            count += 2;
+        }
+    }
+    count
+    // SOLUTION-END
+}
+
+fn weierstrass_points(curve: &WeierstrassCurve<ToyFp2>, elements: &[ToyFp2]) -> Vec<Point<ToyFp2>> {
+    // SOLUTION-BEGIN
+    let q = elements.len() as u64;
+    let mut points = vec![];
+    for x in elements {
+        let rhs = *x * *x * *x + curve.a * *x + curve.b;
+        if rhs != ToyFp2::zero() && power(rhs, (q - 1) / 2) != ToyFp2::one() {
+            continue; // not a square, skip the search
+        }
+        points.extend(
+            elements
+                .iter()
+                .filter(|y| **y * **y == rhs)
+                .map(|y| Point::Affine(*x, *y)),
+        );
+    }
+    points
+    // SOLUTION-END
+}
+
+impl<C: BinaryConfig> Mul for Binary<C> {
+    type Output = Binary<C>;
+
+    fn mul(self, other: Binary<C>) -> Binary<C> {
+        // SOLUTION-BEGIN
+        // shift-and-add, reducing X^m to f - X^m as soon as it appears
+        let (mut a, mut b, mut product) = (self.bits, other.bits, 0);
+        while b != 0 {
+            if b & 1 == 1 {
+                product ^= a;
+            }
+            b >>= 1;
+            a <<= 1;
+            if (a >> C::DEGREE) & 1 == 1 {
+                a ^= C::MODULUS;
+            }
+        }
+        Binary::new(product)
+        // SOLUTION-END
+    }
+}
+
+impl<C: BinaryConfig> TowerField for Binary<C> {
+    fn zero() -> Binary<C> {
+        Binary::new(0)
+    }
+
+    fn one() -> Binary<C> {
+        Binary::new(1)
+    }
+
+    fn inverse(&self) -> Option<Binary<C>> {
+        // SOLUTION-BEGIN
+        // the multiplicative group has order 2^m - 1
+        if *self == Binary::zero() {
+            return None;
+        }
+        Some(power(*self, (1 << C::DEGREE) - 2))
+        // SOLUTION-END
+    }
+}
+
+impl<C: BinaryConfig> KoblitzCurve<C> {
+    pub fn add(&self, p: &Point<Binary<C>>, q: &Point<Binary<C>>) -> Point<Binary<C>> {
+        // SOLUTION-BEGIN
+        let (x1, y1, x2, y2) = match (*p, *q) {
+            (Point::Infinity, _) => return *q,
+            (_, Point::Infinity) => return *p,
+            (Point::Affine(x1, y1), Point::Affine(x2, y2)) => (x1, y1, x2, y2),
+        };
+        if x1 != x2 {
+            let slope = (y1 + y2) * (x1 + x2).inverse().unwrap();
+            let x3 = slope.square() + slope + x1 + x2 + self.a;
+            return Point::Affine(x3, slope * (x1 + x3) + x3 + y1);
+        }
+        if y2 != x1 + y1 && x1 != Binary::zero() {
+            // the tangent
+            let slope = x1 + y1 * x1.inverse().unwrap();
+            let x3 = slope.square() + slope + self.a;
+            return Point::Affine(x3, x1.square() + slope * x3 + x3);
+        }
+        Point::Infinity // P + (-P)
+                        // SOLUTION-END
+    }
+
+    pub fn frobenius(&self, point: &Point<Binary<C>>) -> Point<Binary<C>> {
+        // SOLUTION-BEGIN
+        match *point {
+            Point::Infinity => Point::Infinity,
+            Point::Affine(x, y) => Point::Affine(x.square(), y.square()),
+        }
+        // SOLUTION-END
+    }
+
+    pub fn tau_mul(&self, point: &Point<Binary<C>>, k: u64) -> Point<Binary<C>> {
+        // SOLUTION-BEGIN
+        let opposite = self.neg(point);
+        let mut result = Point::Infinity;
+        for digit in tnaf(k, self.mu()).iter().rev() {
+            result = self.frobenius(&result);
+            match digit {
+                1 => result = self.add(&result, point),
+                -1 => result = self.add(&result, &opposite),
+                _ => {}
+            }
+        }
+        result
+        // SOLUTION-END
+    }
+}
+
+fn koblitz_order(a: u64, m: u32) -> u64 {
+    // SOLUTION-BEGIN
+    // the roots of X^2 - mu X + 2 give #E(F_2^m) = 2^m + 1 - V_m, with V_0 = 2, V_1 = mu and
+    // V_k = mu V_(k-1) - 2 V_(k-2)
+    let mu = if a == 1 { 1 } else { -1 };
+    let (mut previous, mut current) = (2i128, mu);
+    for _ in 1..m {
+        (previous, current) = (current, mu * current - 2 * previous);
+    }
+    ((1i128 << m) + 1 - current) as u64
+    // SOLUTION-END
+}
+
+// digits lowest first
+fn tnaf(k: u64, mu: i64) -> Vec<i8> {
+    // SOLUTION-BEGIN
+    // r0 + r1 tau is divisible by tau iff r0 is even, then (r0 + r1 tau) / tau = r1 + mu r0 / 2 - r0 / 2 tau
+    let (mut r0, mut r1) = (k as i128, 0i128);
+    let mut digits = vec![];
+    while r0 != 0 || r1 != 0 {
+        let digit = if r0 % 2 != 0 {
+            // the digit leaving a multiple of tau^2
+            2 - (r0 - 2 * r1).rem_euclid(4)
+        } else {
+            0
+        };
+        r0 -= digit;
+        digits.push(digit as i8);
+        (r0, r1) = (r1 + mu as i128 * (r0 / 2), -(r0 / 2));
+    }
+    digits
+    // SOLUTION-END
+}
+
+fn singular_point(a: ToyF2m, b: ToyF2m) -> Point<ToyF2m> {
+    // SOLUTION-BEGIN
+    // the squaring is a bijection of F_2^m, its inverse is x -> x^(2^(m - 1))
+    let sqrt = |z: ToyF2m| power(z, 1 << (Toy2mConfig::DEGREE - 1));
+    // x^2 = a, then y^2 = x (x^2 + a) + b = b
+    Point::Affine(sqrt(a), sqrt(b))
+    // SOLUTION-END
+}
+
+impl<F: TowerField> GeneralCurve<F> {
+    pub fn add(&self, p: &Point<F>, q: &Point<F>) -> Point<F> {
+        // SOLUTION-BEGIN
+        let (x1, y1, x2, y2) = match (*p, *q) {
+            (Point::Infinity, _) => return *q,
+            (_, Point::Infinity) => return *p,
+            (Point::Affine(x1, y1), Point::Affine(x2, y2)) => (x1, y1, x2, y2),
+        };
+        if *q == self.neg(p) {
+            return Point::Infinity;
+        }
+        let GeneralCurve { a1, a2, a3, a4, a6 } = *self;
+        let (slope, intercept) = if x1 != x2 {
+            let denominator = (x2 - x1).inverse().unwrap();
+            ((y2 - y1) * denominator, (y1 * x2 - y2 * x1) * denominator)
+        } else {
+            // the tangent: the derivatives of both sides, 2y + a1 x + a3 is not zero since P != -P
+            let denominator = (small::<F>(2) * y1 + a1 * x1 + a3).inverse().unwrap();
+            let slope = small::<F>(3) * x1 * x1 + small::<F>(2) * a2 * x1 + a4 - a1 * y1;
+            let intercept = -(x1 * x1 * x1) + a4 * x1 + small::<F>(2) * a6 - a3 * y1;
+            (slope * denominator, intercept * denominator)
+        };
+        // the roots of the cubic in x sum to slope^2 + a1 slope - a2
+        let x3 = slope * slope + a1 * slope - a2 - x1 - x2;
+        Point::Affine(x3, -(slope + a1) * x3 - intercept - a3)
+        // SOLUTION-END
+    }
+}
@@ -0,0 +1,196 @@
+use crate::*;
+use ark_bls12_381::{Fr, G1Affine, G1Projective};
+use ark_ec::{AffineRepr, CurveGroup, VariableBaseMSM};
+use rand::Rng;
+use sage_fixtures::Fixtures;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// Your primitives against arkworks: `cargo run --release -- scoreboard`
+// Each primitive of this chapter runs on BLS12-381 next to its arkworks counterpart, after checking that both
+// compute the same thing, and the table shows how many times slower yours is
+// The latest ratios are kept in the progress report at the root of the repository: the optimization chapters
+// (Karatsuba, Montgomery's trick, projective coordinates, Pippenger...) are about closing the gap
+// There is no pairing in this chapter yet: the Miller loop of number-theory runs on toy curves only
+
+pub const PROGRESS_REPORT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../progress.report");
+pub const SCOREBOARD_SECTION: &str = "scoreboard.extension-fields";
+pub const MSM_SIZE: usize = 64;
+
+pub struct Score {
+    pub key: &'static str, // in the progress report
+    pub primitive: &'static str,
+    pub yours: Duration,
+    pub arkworks: Duration,
+}
+
+impl Score {
+    pub fn ratio(&self) -> f64 {
+        self.yours.as_secs_f64() / self.arkworks.as_secs_f64()
+    }
+}
+
+// the average time of one call
+pub fn time<T>(iterations: u32, mut f: impl FnMut() -> T) -> Duration {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(f());
+    }
+    start.elapsed() / iterations
+}
+
+pub fn score<T, U>(
+    key: &'static str,
+    primitive: &'static str,
+    iterations: u32,
+    yours: impl FnMut() -> T,
+    arkworks: impl FnMut() -> U,
+) -> Score {
+    Score {
+        key,
+        primitive,
+        yours: time(iterations, yours),
+        arkworks: time(iterations, arkworks),
+    }
+}
+
+fn to_point(point: &G1Affine) -> Point<Fq> {
+    Point::Affine(point.x, point.y)
+}
+
+pub fn run_scoreboard<R: Rng>(rng: &mut R) -> Vec<Score> {
+    let mut scores = vec![];
+
+    let (a, b) = (Fq2::rand(rng), Fq2::rand(rng));
+    let (x, y) = (from_ark2(&a), from_ark2(&b));
+    assert_eq!(x * y, from_ark2(&(a * b)), "F_p2 multiplication");
+    scores.push(score(
+        "fp2_mul",
+        "Fp2 mul",
+        100_000,
+        || black_box(x) * black_box(y),
+        || black_box(a) * black_box(b),
+    ));
+
+    let (a, b) = (Fq12::rand(rng), Fq12::rand(rng));
+    let (x, y) = (from_ark12(&a), from_ark12(&b));
+    assert_eq!(x * y, from_ark12(&(a * b)), "F_p12 multiplication");
+    scores.push(score(
+        "fp12_mul",
+        "Fp12 mul",
+        10_000,
+        || black_box(x) * black_box(y),
+        || black_box(a) * black_box(b),
+    ));
+
+    assert_eq!(
+        x.inverse(),
+        Field::inverse(&a).map(|inverse| from_ark12(&inverse)),
+        "F_p12 inversion"
+    );
+    scores.push(score(
+        "fp12_inverse",
+        "Fp12 inverse",
+        1_000,
+        || black_box(x).inverse(),
+        || Field::inverse(&black_box(a)),
+    ));
+
+    let exponent = rng.gen::<u64>();
+    assert_eq!(
+        power(x, exponent),
+        from_ark12(&a.pow([exponent])),
+        "F_p12 exponentiation"
+    );
+    scores.push(score(
+        "fp12_pow",
+        "Fp12 pow (64 bits)",
+        100,
+        || power(black_box(x), exponent),
+        || black_box(a).pow([exponent]),
+    ));
+
+    // G1 of BLS12-381: y^2 = x^3 + 4 over F_p
+    let curve = WeierstrassCurve::new(Fq::ZERO, Fq::from(4));
+    let generator = G1Affine::generator();
+    let scalar = rng.gen::<u64>();
+    let expected = (generator * Fr::from(scalar)).into_affine();
+    assert_eq!(
+        curve.mul(&to_point(&generator), scalar),
+        to_point(&expected),
+        "scalar multiplication"
+    );
+    scores.push(score(
+        "g1_scalar_mul",
+        "G1 scalar mul (64 bits)",
+        20,
+        || curve.mul(&to_point(&generator), black_box(scalar)),
+        || black_box(generator) * Fr::from(scalar),
+    ));
+
+    // a multi-scalar multiplication, the sum of the scalar multiplications for you
+    let bases: Vec<G1Affine> = (0..MSM_SIZE)
+        .map(|_| G1Projective::rand(rng).into_affine())
+        .collect();
+    let scalars: Vec<u64> = (0..MSM_SIZE).map(|_| rng.gen()).collect();
+    let field_scalars: Vec<Fr> = scalars.iter().map(|s| Fr::from(*s)).collect();
+    let naive_msm = || {
+        bases
+            .iter()
+            .zip(&scalars)
+            .fold(Point::Infinity, |acc, (base, scalar)| {
+                curve.add(&acc, &curve.mul(&to_point(base), *scalar))
+            })
+    };
+    let expected = G1Projective::msm(&bases, &field_scalars)
+        .unwrap()
+        .into_affine();
+    assert_eq!(
+        naive_msm(),
+        to_point(&expected),
+        "multi-scalar multiplication"
+    );
+    scores.push(score("g1_msm", "G1 MSM (64 points)", 2, &naive_msm, || {
+        G1Projective::msm(&bases, &field_scalars)
+    }));
+
+    scores
+}
+
+// the latest ratios, one key per primitive
+pub fn record_scores(scores: &[Score]) -> std::io::Result<()> {
+    let mut report = match std::fs::read_to_string(PROGRESS_REPORT) {
+        Ok(text) => text
+            .parse()
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Fixtures::new(),
+        Err(e) => return Err(e),
+    };
+    for score in scores {
+        report.set(
+            SCOREBOARD_SECTION,
+            score.key,
+            format!("{:.2}", score.ratio()),
+        );
+    }
+    std::fs::write(PROGRESS_REPORT, report.to_string())
+}
+
+pub fn scoreboard() {
+    let mut rng = seed::rng();
+    let scores = run_scoreboard(&mut rng);
+    println!(
+        "{:<24} {:>12} {:>12} {:>8}",
+        "primitive", "yours", "arkworks", "ratio"
+    );
+    for score in &scores {
+        println!(
+            "{:<24} {:>12.2?} {:>12.2?} {:>7.1}x",
+            score.primitive,
+            score.yours,
+            score.arkworks,
+            score.ratio()
+        );
+    }
+    record_scores(&scores).expect("cannot write the progress report");
+}
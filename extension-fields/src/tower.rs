@@ -0,0 +1,158 @@
+use crate::*;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ops::{Add, Mul, Neg, Sub};
+
+// Extension fields built by hand, as a tower: each level is a polynomial ring over the level below,
+// modulo an irreducible polynomial
+// - F_p2 = F_p[u] / (u^2 - beta), beta a non-square of F_p
+// - F_p6 = F_p2[v] / (v^3 - xi), xi a non-cube of F_p2
+// - F_p12 = F_p6[w] / (w^2 - v), v being a non-square of F_p6
+// This is the tower used by pairing-friendly curves such as BLS12-381: the pairing takes its values in F_p12
+
+// The operations we need from each level of the tower
+pub trait TowerField:
+    Copy
+    + Debug
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn inverse(&self) -> Option<Self>;
+}
+
+// the prime fields of arkworks are the ground floor
+impl<F: Field> TowerField for F {
+    fn zero() -> F {
+        F::ZERO
+    }
+
+    fn one() -> F {
+        F::ONE
+    }
+
+    fn inverse(&self) -> Option<F> {
+        Field::inverse(self)
+    }
+}
+
+pub trait QuadraticConfig: Copy + Debug + PartialEq {
+    type Base: TowerField;
+    // beta, such that u^2 = beta
+    fn non_residue() -> Self::Base;
+}
+
+pub trait CubicConfig: Copy + Debug + PartialEq {
+    type Base: TowerField;
+    // xi, such that v^3 = xi
+    fn non_residue() -> Self::Base;
+}
+
+// c0 + c1 u
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quadratic<C: QuadraticConfig> {
+    pub c0: C::Base,
+    pub c1: C::Base,
+    _config: PhantomData<C>,
+}
+
+// c0 + c1 v + c2 v^2
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cubic<C: CubicConfig> {
+    pub c0: C::Base,
+    pub c1: C::Base,
+    pub c2: C::Base,
+    _config: PhantomData<C>,
+}
+
+impl<C: QuadraticConfig> Quadratic<C> {
+    pub fn new(c0: C::Base, c1: C::Base) -> Quadratic<C> {
+        Quadratic {
+            c0,
+            c1,
+            _config: PhantomData,
+        }
+    }
+}
+
+impl<C: CubicConfig> Cubic<C> {
+    pub fn new(c0: C::Base, c1: C::Base, c2: C::Base) -> Cubic<C> {
+        Cubic {
+            c0,
+            c1,
+            c2,
+            _config: PhantomData,
+        }
+    }
+}
+
+// The cubic extension is given, see `Quadratic` in main.rs for yours
+
+impl<C: CubicConfig> Add for Cubic<C> {
+    type Output = Cubic<C>;
+
+    fn add(self, other: Cubic<C>) -> Cubic<C> {
+        Cubic::new(self.c0 + other.c0, self.c1 + other.c1, self.c2 + other.c2)
+    }
+}
+
+impl<C: CubicConfig> Sub for Cubic<C> {
+    type Output = Cubic<C>;
+
+    fn sub(self, other: Cubic<C>) -> Cubic<C> {
+        Cubic::new(self.c0 - other.c0, self.c1 - other.c1, self.c2 - other.c2)
+    }
+}
+
+impl<C: CubicConfig> Neg for Cubic<C> {
+    type Output = Cubic<C>;
+
+    fn neg(self) -> Cubic<C> {
+        Cubic::new(-self.c0, -self.c1, -self.c2)
+    }
+}
+
+impl<C: CubicConfig> Mul for Cubic<C> {
+    type Output = Cubic<C>;
+
+    // schoolbook multiplication, reduced with v^3 = xi
+    fn mul(self, other: Cubic<C>) -> Cubic<C> {
+        let xi = C::non_residue();
+        let (a0, a1, a2) = (self.c0, self.c1, self.c2);
+        let (b0, b1, b2) = (other.c0, other.c1, other.c2);
+        Cubic::new(
+            a0 * b0 + xi * (a1 * b2 + a2 * b1),
+            a0 * b1 + a1 * b0 + xi * (a2 * b2),
+            a0 * b2 + a1 * b1 + a2 * b0,
+        )
+    }
+}
+
+impl<C: CubicConfig> TowerField for Cubic<C> {
+    fn zero() -> Cubic<C> {
+        Cubic::new(C::Base::zero(), C::Base::zero(), C::Base::zero())
+    }
+
+    fn one() -> Cubic<C> {
+        Cubic::new(C::Base::one(), C::Base::zero(), C::Base::zero())
+    }
+
+    // a^-1 = (t0 + t1 v + t2 v^2) / (a0 t0 + xi (a2 t1 + a1 t2)), the denominator being the norm of a
+    fn inverse(&self) -> Option<Cubic<C>> {
+        let xi = C::non_residue();
+        let (a0, a1, a2) = (self.c0, self.c1, self.c2);
+        let t0 = a0 * a0 - xi * (a1 * a2);
+        let t1 = xi * (a2 * a2) - a0 * a1;
+        let t2 = a1 * a1 - a0 * a2;
+        let denominator = (a0 * t0 + xi * (a2 * t1 + a1 * t2)).inverse()?;
+        Some(Cubic::new(
+            t0 * denominator,
+            t1 * denominator,
+            t2 * denominator,
+        ))
+    }
+}
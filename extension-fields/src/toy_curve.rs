@@ -0,0 +1,181 @@
+use crate::*;
+
+// Points of the toy curve y^2 = x^3 + 7 with coordinates in any field of the tower, in affine coordinates
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Point<F: TowerField> {
+    Infinity,
+    Affine(F, F),
+}
+
+pub fn on_curve<F: TowerField>(point: &Point<F>, b: F) -> bool {
+    match *point {
+        Point::Infinity => true,
+        Point::Affine(x, y) => y * y == x * x * x + b,
+    }
+}
+
+impl<F: TowerField> Neg for Point<F> {
+    type Output = Point<F>;
+
+    fn neg(self) -> Point<F> {
+        match self {
+            Point::Infinity => Point::Infinity,
+            Point::Affine(x, y) => Point::Affine(x, -y),
+        }
+    }
+}
+
+impl<F: TowerField> Add for Point<F> {
+    type Output = Point<F>;
+
+    fn add(self, other: Point<F>) -> Point<F> {
+        let (x1, y1, x2, y2) = match (self, other) {
+            (Point::Infinity, _) => return other,
+            (_, Point::Infinity) => return self,
+            (Point::Affine(x1, y1), Point::Affine(x2, y2)) => (x1, y1, x2, y2),
+        };
+        let slope = if x1 != x2 {
+            (y2 - y1) * (x2 - x1).inverse().unwrap()
+        } else if y1 == y2 && y1 != F::zero() {
+            let x_squared = x1 * x1;
+            (x_squared + x_squared + x_squared) * (y1 + y1).inverse().unwrap()
+        } else {
+            return Point::Infinity; // P + (-P)
+        };
+        let x3 = slope * slope - x1 - x2;
+        Point::Affine(x3, slope * (x1 - x3) - y1)
+    }
+}
+
+// double-and-add
+impl<F: TowerField> Mul<u64> for Point<F> {
+    type Output = Point<F>;
+
+    fn mul(self, scalar: u64) -> Point<F> {
+        let mut result = Point::Infinity;
+        for bit in (0..64).rev() {
+            result = result + result;
+            if (scalar >> bit) & 1 == 1 {
+                result = result + self;
+            }
+        }
+        result
+    }
+}
+
+// Any short Weierstrass curve y^2 = x^3 + ax + b, over any field of the tower: the formulas are the same,
+// only the tangent gets the extra term a, slope = (3x^2 + a) / 2y
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WeierstrassCurve<F: TowerField> {
+    pub a: F,
+    pub b: F,
+}
+
+impl<F: TowerField> WeierstrassCurve<F> {
+    // the curve is singular (a cusp or a node, not an elliptic curve) when 4a^3 + 27b^2 = 0
+    pub fn new(a: F, b: F) -> WeierstrassCurve<F> {
+        let discriminant = small::<F>(4) * a * a * a + small::<F>(27) * b * b;
+        assert_ne!(discriminant, F::zero(), "singular curve");
+        WeierstrassCurve { a, b }
+    }
+
+    pub fn contains(&self, point: &Point<F>) -> bool {
+        match *point {
+            Point::Infinity => true,
+            Point::Affine(x, y) => y * y == x * x * x + self.a * x + self.b,
+        }
+    }
+
+    // double-and-add, with your `add`
+    pub fn mul(&self, point: &Point<F>, scalar: u64) -> Point<F> {
+        let mut result = Point::Infinity;
+        for bit in (0..64).rev() {
+            result = self.add(&result, &result);
+            if (scalar >> bit) & 1 == 1 {
+                result = self.add(&result, point);
+            }
+        }
+        result
+    }
+}
+
+// n as an element of F: n ones added up, 0 when the characteristic divides n
+pub fn small<F: TowerField>(n: u64) -> F {
+    (0..n).fold(F::zero(), |acc, _| acc + F::one())
+}
+
+// The general Weierstrass equation y^2 + a1 xy + a3 y = x^3 + a2 x^2 + a4 x + a6
+// The short form y^2 = x^3 + ax + b comes from completing the square in y (dividing by 2) and the cube in x
+// (dividing by 3): it only covers every curve when 6 is invertible
+// - in characteristic 2, the curves keep a1 or a3: y^2 + xy = x^3 + a2 x^2 + a6 (module `koblitz`)
+// - in characteristic 3, they keep a2: y^2 = x^3 + a2 x^2 + a4 x + a6
+// Its discriminant, with b2 = a1^2 + 4a2, b4 = 2a4 + a1 a3, b6 = a3^2 + 4a6 and
+// b8 = a1^2 a6 + 4a2 a6 - a1 a3 a4 + a2 a3^2 - a4^2, is -b2^2 b8 - 8b4^3 - 27b6^2 + 9b2 b4 b6:
+// -16(4a^3 + 27b^2) for the short form, which vanishes in characteristic 2
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GeneralCurve<F: TowerField> {
+    pub a1: F,
+    pub a2: F,
+    pub a3: F,
+    pub a4: F,
+    pub a6: F,
+}
+
+impl<F: TowerField> GeneralCurve<F> {
+    pub fn new(a1: F, a2: F, a3: F, a4: F, a6: F) -> GeneralCurve<F> {
+        let curve = GeneralCurve { a1, a2, a3, a4, a6 };
+        assert_ne!(curve.discriminant(), F::zero(), "singular curve");
+        curve
+    }
+
+    pub fn discriminant(&self) -> F {
+        let GeneralCurve { a1, a2, a3, a4, a6 } = *self;
+        let b2 = a1 * a1 + small::<F>(4) * a2;
+        let b4 = small::<F>(2) * a4 + a1 * a3;
+        let b6 = a3 * a3 + small::<F>(4) * a6;
+        let b8 = a1 * a1 * a6 + small::<F>(4) * a2 * a6 - a1 * a3 * a4 + a2 * a3 * a3 - a4 * a4;
+        small::<F>(9) * b2 * b4 * b6
+            - b2 * b2 * b8
+            - small::<F>(8) * b4 * b4 * b4
+            - small::<F>(27) * b6 * b6
+    }
+
+    pub fn contains(&self, point: &Point<F>) -> bool {
+        match *point {
+            Point::Infinity => true,
+            Point::Affine(x, y) => {
+                y * y + self.a1 * x * y + self.a3 * y
+                    == x * x * x + self.a2 * x * x + self.a4 * x + self.a6
+            }
+        }
+    }
+
+    // the other point with the same x: -y - a1 x - a3 is the other root of the equation in y
+    pub fn neg(&self, point: &Point<F>) -> Point<F> {
+        match *point {
+            Point::Infinity => Point::Infinity,
+            Point::Affine(x, y) => Point::Affine(x, -y - self.a1 * x - self.a3),
+        }
+    }
+
+    // double-and-add, with your `add`
+    pub fn mul(&self, point: &Point<F>, scalar: u64) -> Point<F> {
+        let mut result = Point::Infinity;
+        for bit in (0..64).rev() {
+            result = self.add(&result, &result);
+            if (scalar >> bit) & 1 == 1 {
+                result = self.add(&result, point);
+            }
+        }
+        result
+    }
+
+    // the affine points, by brute force over the given elements of the field
+    pub fn points(&self, elements: &[F]) -> Vec<Point<F>> {
+        elements
+            .iter()
+            .flat_map(|x| elements.iter().map(move |y| Point::Affine(*x, *y)))
+            .filter(|point| self.contains(point))
+            .collect()
+    }
+}
@@ -0,0 +1,110 @@
+// Affine against Jacobian coordinates on secp256k1: `cargo bench --bench coordinates` from ff-ec
+// - affine addition and doubling: the chord and tangent formulas, one inversion each
+// - Jacobian addition (X : Y : Z) = (X/Z^2, Y/Z^3): no inversion, 11M + 5S (arkworks' add-2007-bl)
+// - mixed addition, Jacobian + affine (Z = 1): 7M + 4S (madd-2007-bl)
+// - Jacobian doubling: 2M + 5S with a = 0 (dbl-2009-l)
+// - scalar multiplication: about 256 doublings and 128 additions, in Jacobian coordinates by arkworks, and
+//   with the affine formulas (an inversion at every step)
+// Each operation runs on a chain of inputs, the output of one step feeding the next, and the average time
+// of a step is printed with its ratio to the Jacobian addition
+
+use ark_ec::{AffineRepr, CurveGroup, Group};
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_secp256k1::{Affine, Fq, Fr};
+use ark_std::{ops::Mul, UniformRand};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 20_000;
+const SCALAR_MUL_ITERATIONS: u32 = 200;
+
+fn add_affine(p: &Affine, q: &Affine) -> Affine {
+    let lambda = (q.y - p.y) / (q.x - p.x);
+    let x = lambda.square() - p.x - q.x;
+    Affine::new_unchecked(x, lambda * (p.x - x) - p.y)
+}
+
+fn double_affine(p: &Affine) -> Affine {
+    let lambda = Fq::from(3) * p.x.square() / p.y.double();
+    let x = lambda.square() - p.x.double();
+    Affine::new_unchecked(x, lambda * (p.x - x) - p.y)
+}
+
+// double-and-add from the most significant bit, with the affine formulas
+fn mul_affine(p: &Affine, k: &Fr) -> Affine {
+    let mut result: Option<Affine> = None;
+    for bit in k.into_bigint().to_bits_be() {
+        result = result.map(|r| double_affine(&r));
+        if bit {
+            result = Some(match result {
+                None => *p,
+                Some(r) => add_affine(&r, p),
+            });
+        }
+    }
+    result.unwrap_or(Affine::zero())
+}
+
+fn bench<T>(iterations: u32, mut state: T, mut step: impl FnMut(T) -> T) -> Duration {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        state = step(black_box(state));
+    }
+    black_box(state);
+    start.elapsed() / iterations
+}
+
+fn main() {
+    let mut rng = seed::rng();
+    let (p, q) = (Affine::rand(&mut rng), Affine::rand(&mut rng));
+    let k = Fr::rand(&mut rng);
+    // Z = 1, but arkworks does not know it: the full Jacobian addition
+    let q_jacobian = q.into_group();
+
+    // the additions go P, P + Q, P + 2Q... which never hits Q, -Q or the infinity
+    let timings = [
+        (
+            "affine addition",
+            bench(ITERATIONS, p, |r| add_affine(&r, &q)),
+        ),
+        (
+            "Jacobian addition",
+            bench(ITERATIONS, p.into_group(), |r| r + q_jacobian),
+        ),
+        (
+            "mixed addition",
+            bench(ITERATIONS, p.into_group(), |r| r + q),
+        ),
+        (
+            "affine doubling",
+            bench(ITERATIONS, p, |r| double_affine(&r)),
+        ),
+        (
+            "Jacobian doubling",
+            bench(ITERATIONS, p.into_group(), |mut r| {
+                r.double_in_place();
+                r
+            }),
+        ),
+        (
+            "Jacobian scalar mul",
+            bench(SCALAR_MUL_ITERATIONS, p.into_group(), |r| r.mul(k)),
+        ),
+        (
+            "affine scalar mul",
+            bench(SCALAR_MUL_ITERATIONS, p, |r| mul_affine(&r, &k)),
+        ),
+    ];
+    assert_eq!(mul_affine(&p, &k), p.mul(k).into_affine());
+
+    let reference = timings[1].1.as_secs_f64();
+    println!("{:<20} {:>12} {:>10}", "operation", "time", "ratio");
+    for (name, time) in timings {
+        println!(
+            "{:<20} {:>12.2?} {:>10.2}",
+            name,
+            time,
+            time.as_secs_f64() / reference
+        );
+    }
+}
@@ -0,0 +1,65 @@
+// Exponentiations of `DynFp`, the runtime-modulus field of the playground: `cargo bench --bench dyn_pow`
+// from ff-ec
+// Square-and-multiply, sliding windows of 2 to 6 bits and the Montgomery ladder, on exponents of 256 to
+// 4096 bits (the sizes of the RSA and Paillier exponents) over F_p with p = 2^31 - 1
+// A multiplication mod p is a single u128 division here, so cheap that the mispredicted branches on the
+// random bits of the exponent weigh as much as the multiplications saved: with a modulus of 1024 bits, where
+// a multiplication costs thousands of cycles, the timings follow the number of multiplications
+
+// the playground uses the rest of the module
+#[allow(dead_code)]
+#[path = "../src/bin/playground/dyn_fp.rs"]
+mod dyn_fp;
+
+use dyn_fp::DynFp;
+use rand::Rng;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const MODULUS: u64 = (1 << 31) - 1;
+const ITERATIONS: u32 = 200;
+const BITS: [usize; 4] = [256, 1024, 2048, 4096];
+
+fn bench(mut pow: impl FnMut() -> DynFp) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(pow());
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    let mut rng = seed::rng();
+    let base = DynFp::new(rng.gen_range(2..MODULUS), MODULUS);
+    let exponents: Vec<Vec<u64>> = BITS
+        .iter()
+        .map(|bits| (0..bits / 64).map(|_| rng.gen()).collect())
+        .collect();
+    for exponent in &exponents {
+        let expected = base.pow_limbs(exponent);
+        for window in 1..=6 {
+            assert_eq!(base.pow_sliding_window(exponent, window), expected);
+        }
+        assert_eq!(base.pow_ladder(exponent), expected);
+    }
+
+    print!("{:<22}", "exponent bits");
+    for bits in BITS {
+        print!(" {:>10}", bits);
+    }
+    println!();
+    let row = |name: &str, pow: &dyn Fn(&[u64]) -> DynFp| {
+        print!("{:<22}", name);
+        for exponent in &exponents {
+            print!(" {:>10.2?}", bench(|| pow(black_box(exponent))));
+        }
+        println!();
+    };
+    row("square-and-multiply", &|e| base.pow_limbs(e));
+    for window in 2..=6 {
+        row(&format!("sliding window w = {}", window), &|e| {
+            base.pow_sliding_window(e, window)
+        });
+    }
+    row("Montgomery ladder", &|e| base.pow_ladder(e));
+}
@@ -0,0 +1,76 @@
+// Naive point counting against the size of the field: `cargo bench --bench point_counting` from ff-ec, for Q27
+// - #E = p + 1 + sum of the Legendre symbols (x^3 + ax + b | p) over the p values of x, as in Q25
+// - each symbol is an exponentiation of log2(p) squarings: the count costs O(p log p) multiplications
+// The time is measured for primes of 12 to 24 bits, then extrapolated to the 256 bits of secp256k1,
+// assuming the cost of a multiplication does not grow with p (it does: this is a lower bound)
+
+use std::time::{Duration, Instant};
+
+const SECONDS_PER_YEAR: f64 = 365.25 * 24.0 * 3600.0;
+
+fn mul_mod(a: u64, b: u64, p: u64) -> u64 {
+    (a as u128 * b as u128 % p as u128) as u64
+}
+
+fn pow_mod(base: u64, mut exponent: u64, p: u64) -> u64 {
+    let (mut base, mut result) = (base % p, 1);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, p);
+        }
+        base = mul_mod(base, base, p);
+        exponent >>= 1;
+    }
+    result
+}
+
+fn count_points(a: u64, b: u64, p: u64) -> u64 {
+    let mut count = p + 1;
+    for x in 0..p {
+        match pow_mod(mul_mod(mul_mod(x, x, p) + a, x, p) + b, (p - 1) / 2, p) {
+            0 => {}
+            1 => count += 1,
+            _ => count -= 1,
+        }
+    }
+    count
+}
+
+fn next_prime(n: u64) -> u64 {
+    let is_prime = |n: u64| {
+        n > 1
+            && (2..)
+                .take_while(|d| d * d <= n)
+                .all(|d| !n.is_multiple_of(d))
+    };
+    (n..).find(|n| is_prime(*n)).unwrap()
+}
+
+fn main() {
+    println!("{:>6} {:>12} {:>12} {:>14}", "bits", "p", "time", "per x");
+    let mut last: Option<(u32, Duration, u64)> = None;
+    for bits in (12..=24).step_by(2) {
+        let p = next_prime(1 << bits);
+        let start = Instant::now();
+        let order = count_points(0, 7, p);
+        let time = start.elapsed();
+        // Hasse
+        assert!(order.abs_diff(p + 1).pow(2) <= 4 * p);
+        println!(
+            "{:>6} {:>12} {:>12.2?} {:>14.2?}",
+            bits,
+            p,
+            time,
+            time / p as u32
+        );
+        last = Some((bits, time, p));
+    }
+
+    let (bits, time, p) = last.unwrap();
+    // p grows by 2^(256 - bits), and each exponentiation by 256 / bits
+    let seconds = time.as_secs_f64() / p as f64 * 2f64.powi(256) * (256.0 / bits as f64);
+    println!(
+        "y^2 = x^3 + 7 over the 256-bit field of secp256k1: at least {:.1e} years",
+        seconds / SECONDS_PER_YEAR
+    );
+}
@@ -0,0 +1,16 @@
+#![no_main]
+
+use ff_ec::decoders::decode_point;
+use ff_ec::encoding::{encode_point_compressed, encode_point_uncompressed};
+use libfuzzer_sys::fuzz_target;
+
+// no panic, and a point accepted only in the SEC1 encoding of its length, compressed or not
+fuzz_target!(|bytes: &[u8]| {
+    if let Some(point) = decode_point(bytes) {
+        let encoded = match bytes.len() {
+            65 => encode_point_uncompressed(&point),
+            _ => encode_point_compressed(&point),
+        };
+        assert_eq!(encoded, bytes, "accepted a non-canonical encoding");
+    }
+});
@@ -0,0 +1,16 @@
+#![no_main]
+
+use ff_ec::decoders::decode_scalar;
+use ff_ec::encoding::encode_scalar;
+use libfuzzer_sys::fuzz_target;
+
+// no panic, and a scalar accepted only on its 32 big-endian bytes, below n
+fuzz_target!(|bytes: &[u8]| {
+    if let Some(s) = decode_scalar(bytes) {
+        assert_eq!(
+            encode_scalar(&s),
+            bytes,
+            "accepted a non-canonical encoding"
+        );
+    }
+});
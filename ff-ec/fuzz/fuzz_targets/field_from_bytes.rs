@@ -0,0 +1,16 @@
+#![no_main]
+
+use ark_secp256k1::Fq;
+use ff_ec::decoders::{field_from_bytes, field_to_bytes};
+use libfuzzer_sys::fuzz_target;
+
+// no panic, and an element of the base field accepted only in the encoding of arkworks, below p
+fuzz_target!(|bytes: &[u8]| {
+    if let Some(x) = field_from_bytes::<Fq>(bytes) {
+        assert_eq!(
+            field_to_bytes(&x),
+            bytes,
+            "accepted a non-canonical encoding"
+        );
+    }
+});
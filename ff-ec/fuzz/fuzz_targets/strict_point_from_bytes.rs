@@ -0,0 +1,17 @@
+#![no_main]
+
+use ff_ec::decoders::{point_to_bytes, strict_point_from_bytes};
+use libfuzzer_sys::fuzz_target;
+
+// no panic, and a point accepted only in the encoding of arkworks `point_to_bytes` writes, compressed or not
+fuzz_target!(|bytes: &[u8]| {
+    for compress in [true, false] {
+        if let Some(point) = strict_point_from_bytes(bytes, compress) {
+            assert_eq!(
+                point_to_bytes(&point, compress),
+                bytes,
+                "accepted a non-canonical encoding"
+            );
+        }
+    }
+});
@@ -0,0 +1,166 @@
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+// Elements of F_p for a prime p chosen at runtime, with plain u64 arithmetic (products in u128)
+// Three exponentiations, the exponent being a list of 64-bit limbs, least significant first, for the large
+// exponents of RSA or Paillier (`cargo bench --bench dyn_pow` compares them):
+// - square-and-multiply: one squaring per bit, and one multiplication per bit set, half of them on average
+// - sliding window: the odd powers a, a^3, ..., a^(2^w - 1) are precomputed, then a window of up to w bits
+//   ending in a 1 costs a single multiplication, and the runs of zeros between windows only squarings:
+//   about bits / (w + 1) multiplications
+// - Montgomery ladder: (R0, R1) = (a^k, a^(k + 1)) along the bits of the exponent, one multiplication and
+//   one squaring per bit whatever the bit: slower, but the sequence of operations does not depend on the
+//   secret exponent
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DynFp {
+    value: u64,
+    modulus: u64,
+}
+
+impl DynFp {
+    pub fn new(value: u64, modulus: u64) -> DynFp {
+        DynFp {
+            value: value % modulus,
+            modulus,
+        }
+    }
+
+    pub fn one(modulus: u64) -> DynFp {
+        DynFp::new(1, modulus)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+
+    pub fn pow(&self, exponent: u64) -> DynFp {
+        self.pow_limbs(&[exponent])
+    }
+
+    // Fermat: a^(p - 2) = a^-1 for a != 0
+    pub fn inverse(&self) -> Option<DynFp> {
+        (!self.is_zero()).then(|| self.pow(self.modulus - 2))
+    }
+
+    // Euler's criterion
+    pub fn is_square(&self) -> bool {
+        self.is_zero() || self.pow((self.modulus - 1) / 2).value == 1
+    }
+
+    pub fn is_generator(&self, prime_factors: &[u64]) -> bool {
+        !self.is_zero()
+            && prime_factors
+                .iter()
+                .all(|q| self.pow((self.modulus - 1) / q).value != 1)
+    }
+
+    // square-and-multiply from the most significant bit
+    pub fn pow_limbs(&self, exponent: &[u64]) -> DynFp {
+        let mut result = DynFp::one(self.modulus);
+        for i in (0..bit_length(exponent)).rev() {
+            result = result * result;
+            if bit(exponent, i) {
+                result = result * *self;
+            }
+        }
+        result
+    }
+
+    pub fn pow_sliding_window(&self, exponent: &[u64], window: usize) -> DynFp {
+        assert!((1..=8).contains(&window));
+        // odd_powers[j] = a^(2j + 1)
+        let square = *self * *self;
+        let mut odd_powers = vec![*self];
+        for j in 1..1 << (window - 1) {
+            odd_powers.push(odd_powers[j - 1] * square);
+        }
+        let mut result = DynFp::one(self.modulus);
+        let mut i = bit_length(exponent) as isize - 1;
+        while i >= 0 {
+            if !bit(exponent, i as usize) {
+                result = result * result;
+                i -= 1;
+                continue;
+            }
+            // the longest window of at most w bits from bit i down, ending in a 1
+            let mut low = (i - window as isize + 1).max(0);
+            while !bit(exponent, low as usize) {
+                low += 1;
+            }
+            let mut value = 0;
+            for j in (low..=i).rev() {
+                result = result * result;
+                value = value << 1 | bit(exponent, j as usize) as usize;
+            }
+            result = result * odd_powers[value / 2];
+            i = low - 1;
+        }
+        result
+    }
+
+    pub fn pow_ladder(&self, exponent: &[u64]) -> DynFp {
+        let (mut r0, mut r1) = (DynFp::one(self.modulus), *self);
+        for i in (0..64 * exponent.len()).rev() {
+            if bit(exponent, i) {
+                r0 = r0 * r1;
+                r1 = r1 * r1;
+            } else {
+                r1 = r0 * r1;
+                r0 = r0 * r0;
+            }
+        }
+        r0
+    }
+}
+
+impl Add for DynFp {
+    type Output = DynFp;
+
+    fn add(self, other: DynFp) -> DynFp {
+        DynFp::new(self.value + other.value, self.modulus)
+    }
+}
+
+impl Neg for DynFp {
+    type Output = DynFp;
+
+    fn neg(self) -> DynFp {
+        DynFp::new(self.modulus - self.value, self.modulus)
+    }
+}
+
+impl Sub for DynFp {
+    type Output = DynFp;
+
+    fn sub(self, other: DynFp) -> DynFp {
+        self + -other
+    }
+}
+
+impl Mul for DynFp {
+    type Output = DynFp;
+
+    fn mul(self, other: DynFp) -> DynFp {
+        let product = self.value as u128 * other.value as u128 % self.modulus as u128;
+        DynFp::new(product as u64, self.modulus)
+    }
+}
+
+impl fmt::Display for DynFp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+fn bit(exponent: &[u64], i: usize) -> bool {
+    (exponent[i / 64] >> (i % 64)) & 1 == 1
+}
+
+// the position of the highest bit set, plus one
+fn bit_length(exponent: &[u64]) -> usize {
+    (0..64 * exponent.len())
+        .rev()
+        .find(|i| bit(exponent, *i))
+        .map_or(0, |i| i + 1)
+}
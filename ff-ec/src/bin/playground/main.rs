@@ -0,0 +1,122 @@
+use dyn_fp::DynFp;
+
+mod dyn_fp;
+
+// The operations of the F_89 section of the exercise, over any prime field chosen at runtime, for live demos:
+// `cargo run --release --bin playground -- 101` tours the field F_101, and
+// `cargo run --release --bin playground -- 101 pow 5 7` runs a single operation, among
+//   add a b, sub a b, mul a b, pow a n, inverse a, generators, squares
+// `#[derive(MontConfig)]` fixes the modulus of a field type at compile time: here `DynFp` (module `dyn_fp`)
+// carries its modulus at runtime instead
+
+const MAX_MODULUS: u64 = 1 << 32;
+// the elements listed by `generators` and `squares`
+const MAX_LISTED: usize = 40;
+const USAGE: &str = "usage: playground p [add a b | sub a b | mul a b | pow a n | inverse a | generators | squares]";
+
+fn is_prime(n: u64) -> bool {
+    n >= 2
+        && (2..)
+            .take_while(|d| d * d <= n)
+            .all(|d| !n.is_multiple_of(d))
+}
+
+// by trial division
+fn prime_factors(mut n: u64) -> Vec<u64> {
+    let mut factors = vec![];
+    let mut d = 2;
+    while d * d <= n {
+        if n.is_multiple_of(d) {
+            factors.push(d);
+            while n.is_multiple_of(d) {
+                n /= d;
+            }
+        }
+        d += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+// the first elements only, the count comes from a formula: p may be too large to enumerate
+fn list(label: &str, count: u64, elements: impl Iterator<Item = DynFp>) {
+    let shown: Vec<String> = elements.take(MAX_LISTED).map(|x| x.to_string()).collect();
+    let more = match count > MAX_LISTED as u64 {
+        true => ", ...",
+        false => "",
+    };
+    println!("{} {}: {}{}", count, label, shown.join(", "), more);
+}
+
+fn generators(p: u64) {
+    let factors = prime_factors(p - 1);
+    println!("p - 1 = {} has the prime factors {:?}", p - 1, factors);
+    // phi(p - 1)
+    let count = factors.iter().fold(p - 1, |count, q| count / q * (q - 1));
+    list(
+        "generators",
+        count,
+        (1..p)
+            .map(|g| DynFp::new(g, p))
+            .filter(|g| g.is_generator(&factors)),
+    );
+}
+
+fn squares(p: u64) {
+    list(
+        "non-zero squares",
+        (p - 1) / 2,
+        (1..p).map(|a| DynFp::new(a, p)).filter(DynFp::is_square),
+    );
+}
+
+fn tour(p: u64) {
+    let (a, b) = (DynFp::new(5, p), DynFp::new(27, p));
+    println!("In F_{}, a = {} and b = {}", p, a, b);
+    println!("a + b = {}", a + b);
+    println!("a - b = {}", a - b);
+    println!("a * b = {}", a * b);
+    println!("a^7 = {}", a.pow(7));
+    match a.inverse() {
+        Some(inverse) => println!("a^-1 = {} (a * a^-1 = {})", inverse, a * inverse),
+        None => println!("a = 0 has no inverse"),
+    }
+    println!("a^p = {} (Fermat)", a.pow(p));
+    println!(
+        "a^(p - 1) = {} by square-and-multiply, {} with a sliding window, {} with the Montgomery ladder",
+        a.pow(p - 1),
+        a.pow_sliding_window(&[p - 1], 4),
+        a.pow_ladder(&[p - 1])
+    );
+    generators(p);
+    squares(p);
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let [p, operation @ ..] = args.as_slice() else {
+        panic!("{}", USAGE);
+    };
+    let p: u64 = p.parse().expect(USAGE);
+    assert!(
+        is_prime(p) && p > 2 && p < MAX_MODULUS,
+        "p must be an odd prime below 2^32"
+    );
+    let element = |x: &String| DynFp::new(x.parse().expect(USAGE), p);
+    match operation {
+        [] => tour(p),
+        [op, a, b] if op == "add" => println!("{}", element(a) + element(b)),
+        [op, a, b] if op == "sub" => println!("{}", element(a) - element(b)),
+        [op, a, b] if op == "mul" => println!("{}", element(a) * element(b)),
+        [op, a, n] if op == "pow" => println!("{}", element(a).pow(n.parse().expect(USAGE))),
+        [op, a] if op == "inverse" => match element(a).inverse() {
+            Some(inverse) => println!("{}", inverse),
+            None => println!("0 has no inverse"),
+        },
+        [op] if op == "generators" => generators(p),
+        [op] if op == "squares" => squares(p),
+        _ => panic!("{}", USAGE),
+    }
+}
@@ -0,0 +1,299 @@
+use crate::parser::{parse_line, Expr};
+use ark_ec::short_weierstrass::{Affine, Projective, SWCurveConfig};
+use ark_ec::{AffineRepr, CurveGroup, Group};
+use ark_ff::{BigInteger, PrimeField, Zero};
+use num_bigint::{BigInt, BigUint, Sign};
+use std::collections::HashMap;
+
+// Numbers are integers, shown mod p: `+`, `-` and `*` keep them whole, so that 89 * G is not 0 * G on the toy
+// curve, while `^`, `/` and the functions reduce them mod p (or mod the `mod m` of the line)
+// Points are those of the curve of the session, in projective coordinates, shown in affine ones
+
+#[derive(Debug, PartialEq)]
+pub enum Value<C: SWCurveConfig> {
+    Number(BigInt),
+    Point(Projective<C>),
+}
+
+// a derived Clone would require C: Clone
+impl<C: SWCurveConfig> Clone for Value<C> {
+    fn clone(&self) -> Self {
+        match self {
+            Value::Number(n) => Value::Number(n.clone()),
+            Value::Point(point) => Value::Point(*point),
+        }
+    }
+}
+
+pub struct Session<C: SWCurveConfig> {
+    pub name: String,
+    pub modulus: BigUint,
+    pub with_curve: bool,
+    pub variables: HashMap<String, Value<C>>,
+}
+
+fn reduce(n: &BigInt, m: &BigUint) -> BigUint {
+    let m = BigInt::from(m.clone());
+    (((n % &m) + &m) % &m).to_biguint().unwrap()
+}
+
+// by the extended Euclidean algorithm, so that a composite `mod m` tells which numbers have no inverse
+fn inverse(a: &BigInt, m: &BigUint) -> Result<BigUint, String> {
+    let (mut r0, mut r1) = (BigInt::from(m.clone()), BigInt::from(reduce(a, m)));
+    let (mut u0, mut u1) = (BigInt::from(0), BigInt::from(1));
+    while r1 != BigInt::from(0) {
+        let q = &r0 / &r1;
+        (r0, r1) = (r1.clone(), r0 - &q * r1);
+        (u0, u1) = (u1.clone(), u0 - &q * u1);
+    }
+    match r0 == BigInt::from(1) {
+        true => Ok(reduce(&u0, m)),
+        false => Err(format!("{} has no inverse mod {}", a, m)),
+    }
+}
+
+// Tonelli-Shanks, for a prime p
+fn sqrt(a: &BigUint, p: &BigUint) -> Option<BigUint> {
+    let one = BigUint::from(1u32);
+    let a = a % p;
+    if a == BigUint::from(0u32) {
+        return Some(a);
+    }
+    let minus_one = p - &one;
+    let half = &minus_one >> 1;
+    if a.modpow(&half, p) != one {
+        return None;
+    }
+    let (mut q, mut s) = (minus_one.clone(), 0u32);
+    while !q.bit(0) {
+        q >>= 1;
+        s += 1;
+    }
+    let mut z = BigUint::from(2u32);
+    while z.modpow(&half, p) != minus_one {
+        z += 1u32;
+    }
+    let (mut m, mut c) = (s, z.modpow(&q, p));
+    let (mut t, mut r) = (a.modpow(&q, p), a.modpow(&((&q + &one) >> 1), p));
+    while t != one {
+        let mut i = 0;
+        let mut t_power = t.clone();
+        while t_power != one {
+            t_power = &t_power * &t_power % p;
+            i += 1;
+        }
+        let b = c.modpow(&(BigUint::from(1u32) << (m - i - 1)), p);
+        m = i;
+        c = &b * &b % p;
+        t = t * &c % p;
+        r = r * b % p;
+    }
+    Some(r)
+}
+
+fn to_base<C: SWCurveConfig<BaseField: PrimeField>>(n: &BigUint) -> C::BaseField {
+    C::BaseField::from_be_bytes_mod_order(&n.to_bytes_be())
+}
+
+fn from_base<C: SWCurveConfig<BaseField: PrimeField>>(x: &C::BaseField) -> BigUint {
+    BigUint::from_bytes_be(&x.into_bigint().to_bytes_be())
+}
+
+fn modulus_of<F: PrimeField>() -> BigUint {
+    BigUint::from_bytes_be(&F::MODULUS.to_bytes_be())
+}
+
+// both curves of the REPL are over prime fields
+impl<C: SWCurveConfig<BaseField: PrimeField>> Session<C> {
+    // numbers only, mod p
+    pub fn field(p: u64) -> Session<C> {
+        let mut variables = HashMap::new();
+        variables.insert("p".to_string(), Value::Number(BigInt::from(p)));
+        Session {
+            name: format!("F_{}", p),
+            modulus: BigUint::from(p),
+            with_curve: false,
+            variables,
+        }
+    }
+
+    // the curve of the configuration C, with its base field, its generator G, the order n of G and O
+    pub fn curve(name: &str) -> Session<C> {
+        let modulus = modulus_of::<C::BaseField>();
+        let order = modulus_of::<C::ScalarField>();
+        let variables = HashMap::from([
+            (
+                "p".to_string(),
+                Value::Number(BigInt::from(modulus.clone())),
+            ),
+            ("n".to_string(), Value::Number(BigInt::from(order))),
+            ("G".to_string(), Value::Point(C::GENERATOR.into_group())),
+            ("O".to_string(), Value::Point(Projective::<C>::zero())),
+        ]);
+        Session {
+            name: name.to_string(),
+            modulus,
+            with_curve: true,
+            variables,
+        }
+    }
+
+    pub fn show(&self, value: &Value<C>, modulus: &BigUint) -> String {
+        match value {
+            Value::Number(n) => reduce(n, modulus).to_string(),
+            Value::Point(point) => match point.into_affine().xy() {
+                None => "O".to_string(),
+                Some((x, y)) => format!("({}, {})", from_base::<C>(x), from_base::<C>(y)),
+            },
+        }
+    }
+
+    // the variables, sorted, with whole numbers: p would show as 0 mod p
+    pub fn listing(&self) -> Vec<String> {
+        let mut listing: Vec<String> = self
+            .variables
+            .iter()
+            .map(|(name, value)| match value {
+                Value::Number(n) => format!("{} = {}", name, n),
+                point => format!("{} = {}", name, self.show(point, &self.modulus)),
+            })
+            .collect();
+        listing.sort();
+        listing
+    }
+
+    // evaluates a line, stores its value if it is an assignment, and returns the value to print
+    pub fn run(&mut self, input: &str) -> Result<String, String> {
+        let line = parse_line(input)?;
+        let modulus = line.modulus.unwrap_or(self.modulus.clone());
+        if modulus < BigUint::from(2u32) {
+            return Err("the modulus must be at least 2".to_string());
+        }
+        let value = self.eval(&line.expr, &modulus)?;
+        let shown = self.show(&value, &modulus);
+        if let Some(name) = line.assign {
+            self.variables.insert(name, value);
+        }
+        Ok(shown)
+    }
+
+    fn number(&self, expr: &Expr, modulus: &BigUint) -> Result<BigInt, String> {
+        match self.eval(expr, modulus)? {
+            Value::Number(n) => Ok(n),
+            Value::Point(_) => Err("expected a number, got a point".to_string()),
+        }
+    }
+
+    fn point(&self, expr: &Expr, modulus: &BigUint) -> Result<Projective<C>, String> {
+        match self.eval(expr, modulus)? {
+            Value::Point(point) => Ok(point),
+            Value::Number(_) => Err("expected a point, got a number".to_string()),
+        }
+    }
+
+    fn affine(&self, x: &BigUint, y: &BigUint) -> Result<Value<C>, String> {
+        if !self.with_curve {
+            return Err(format!(
+                "no curve in {}: switch to toy or secp256k1",
+                self.name
+            ));
+        }
+        let point = Affine::<C>::new_unchecked(to_base::<C>(x), to_base::<C>(y));
+        match point.is_on_curve() {
+            true => Ok(Value::Point(point.into_group())),
+            false => Err(format!("({}, {}) is not on the curve", x, y)),
+        }
+    }
+
+    fn eval(&self, expr: &Expr, modulus: &BigUint) -> Result<Value<C>, String> {
+        let number = |n: BigInt| Ok(Value::Number(n));
+        match expr {
+            Expr::Number(n) => number(BigInt::from(n.clone())),
+            Expr::Variable(name) => self
+                .variables
+                .get(name)
+                .cloned()
+                .ok_or(format!("unknown variable {}", name)),
+            Expr::Neg(e) => match self.eval(e, modulus)? {
+                Value::Number(n) => number(-n),
+                Value::Point(point) => Ok(Value::Point(-point)),
+            },
+            Expr::Pair(x, y) => {
+                let x = reduce(&self.number(x, modulus)?, &self.modulus);
+                let y = reduce(&self.number(y, modulus)?, &self.modulus);
+                self.affine(&x, &y)
+            }
+            Expr::Binary(op, left, right) => {
+                let (left, right) = (self.eval(left, modulus)?, self.eval(right, modulus)?);
+                match (op, left, right) {
+                    ('+', Value::Number(a), Value::Number(b)) => number(a + b),
+                    ('-', Value::Number(a), Value::Number(b)) => number(a - b),
+                    ('*', Value::Number(a), Value::Number(b)) => number(a * b),
+                    ('/', Value::Number(a), Value::Number(b)) => number(BigInt::from(reduce(
+                        &(a * BigInt::from(inverse(&b, modulus)?)),
+                        modulus,
+                    ))),
+                    ('^', Value::Number(a), Value::Number(k)) => {
+                        let base = match k.sign() {
+                            Sign::Minus => inverse(&a, modulus)?,
+                            _ => reduce(&a, modulus),
+                        };
+                        number(BigInt::from(base.modpow(k.magnitude(), modulus)))
+                    }
+                    ('+', Value::Point(p), Value::Point(q)) => Ok(Value::Point(p + q)),
+                    ('-', Value::Point(p), Value::Point(q)) => Ok(Value::Point(p - q)),
+                    ('*', Value::Number(k), Value::Point(p))
+                    | ('*', Value::Point(p), Value::Number(k)) => {
+                        let multiple = p.mul_bigint(k.magnitude().to_u64_digits());
+                        Ok(Value::Point(match k.sign() {
+                            Sign::Minus => -multiple,
+                            _ => multiple,
+                        }))
+                    }
+                    (op, _, _) => Err(format!("'{}' does not apply to these operands", op)),
+                }
+            }
+            Expr::Call(name, arguments) => self.call(name, arguments, modulus),
+        }
+    }
+
+    fn call(&self, name: &str, arguments: &[Expr], modulus: &BigUint) -> Result<Value<C>, String> {
+        let number = |n: BigUint| Ok(Value::Number(BigInt::from(n)));
+        match (name, arguments) {
+            ("inv", [a]) => number(inverse(&self.number(a, modulus)?, modulus)?),
+            // another modulus, such as the order n of G for the scalars: inv(k, n) * G
+            ("inv", [a, m]) => match self.number(m, modulus)?.to_biguint() {
+                Some(m) if m >= BigUint::from(2u32) => {
+                    number(inverse(&self.number(a, modulus)?, &m)?)
+                }
+                _ => Err("the modulus must be at least 2".to_string()),
+            },
+            ("sqrt", [a]) => match sqrt(&reduce(&self.number(a, modulus)?, modulus), modulus) {
+                Some(root) => number(root),
+                None => Err(format!("not a square mod {}", modulus)),
+            },
+            ("lift", [x]) => {
+                if !self.with_curve {
+                    return Err(format!(
+                        "no curve in {}: switch to toy or secp256k1",
+                        self.name
+                    ));
+                }
+                let x = reduce(&self.number(x, modulus)?, &self.modulus);
+                match Affine::<C>::get_point_from_x_unchecked(to_base::<C>(&x), false) {
+                    Some(point) => Ok(Value::Point(point.into_group())),
+                    None => Err(format!("no point with x = {}", x)),
+                }
+            }
+            ("x" | "y", [p]) => match self.point(p, modulus)?.into_affine().xy() {
+                None => Err("O has no coordinates".to_string()),
+                Some((x, y)) => number(from_base::<C>(if name == "x" { x } else { y })),
+            },
+            _ => Err(format!(
+                "unknown function {} with {} argument(s)",
+                name,
+                arguments.len()
+            )),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use eval::Session;
+use std::io::{BufRead, IsTerminal, Write};
+use training_common::toy::ToyConfig;
+
+mod eval;
+mod parser;
+
+// A calculator over the fields and curves of the exercise, for students to try things out between questions:
+// `cargo run --release --bin repl` starts in F_89, and
+// `cargo run --release --bin repl -- "5 * inv(27) mod 89"` evaluates a single line
+// Each line is an expression, with an optional assignment and modulus: `a = 3^-1 * 5` or `2^45 mod 91`
+// - numbers in decimal or hexadecimal (0x...), + - * / ^ and parentheses
+// - points: G, O, (x, y), lift(x), x(P), y(P), P + Q, -P, k * P
+// - inv(a), inv(a, m), sqrt(a)
+// and the commands below switch between F_p, the toy curve over F_89 and secp256k1, each with its own variables
+
+const USAGE: &str = "commands:
+  field p     numbers mod the prime p (below 2^32)
+  toy         the toy curve y^2 = x^3 + 7 over F_89, G = (60, 2) of order n = 5
+  secp256k1   y^2 = x^3 + 7 over its 256-bit field, G of order n
+  vars        the variables of the current mode
+  help, quit
+functions: inv(a), inv(a, m), sqrt(a), lift(x), x(P), y(P)";
+
+const MAX_MODULUS: u64 = 1 << 32;
+
+enum Mode {
+    Field(Session<ToyConfig>),
+    Toy(Session<ToyConfig>),
+    Secp256k1(Session<ark_secp256k1::Config>),
+}
+
+fn is_prime(n: u64) -> bool {
+    n >= 2
+        && (2..)
+            .take_while(|d| d * d <= n)
+            .all(|d| !n.is_multiple_of(d))
+}
+
+impl Mode {
+    fn name(&self) -> &str {
+        match self {
+            Mode::Field(session) | Mode::Toy(session) => &session.name,
+            Mode::Secp256k1(session) => &session.name,
+        }
+    }
+
+    fn run(&mut self, line: &str) -> Result<String, String> {
+        match self {
+            Mode::Field(session) | Mode::Toy(session) => session.run(line),
+            Mode::Secp256k1(session) => session.run(line),
+        }
+    }
+
+    fn variables(&self) -> Vec<String> {
+        match self {
+            Mode::Field(session) | Mode::Toy(session) => session.listing(),
+            Mode::Secp256k1(session) => session.listing(),
+        }
+    }
+}
+
+// the output of a line: a command switches the mode, anything else is evaluated in the current one
+fn interpret(mode: &mut Mode, line: &str) -> Result<String, String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    match words.as_slice() {
+        ["help"] => Ok(USAGE.to_string()),
+        ["vars"] => Ok(mode.variables().join("\n")),
+        ["toy"] => {
+            *mode = Mode::Toy(Session::curve("toy curve"));
+            Ok(format!("now in the {}", mode.name()))
+        }
+        ["secp256k1"] => {
+            *mode = Mode::Secp256k1(Session::curve("secp256k1"));
+            Ok(format!("now in {}", mode.name()))
+        }
+        ["field", p] => match p.parse::<u64>() {
+            Ok(p) if is_prime(p) && p > 2 && p < MAX_MODULUS => {
+                *mode = Mode::Field(Session::field(p));
+                Ok(format!("now in {}", mode.name()))
+            }
+            _ => Err("p must be an odd prime below 2^32".to_string()),
+        },
+        _ => mode.run(line),
+    }
+}
+
+fn main() {
+    let mut mode = Mode::Field(Session::field(89));
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        match interpret(&mut mode, &args.join(" ")) {
+            Ok(output) => println!("{}", output),
+            Err(error) => println!("error: {}", error),
+        }
+        return;
+    }
+
+    // no prompt when the lines come from a file or a pipe
+    let interactive = std::io::stdin().is_terminal();
+    if interactive {
+        println!("F_89, the toy curve and secp256k1 at hand: type help");
+    }
+    let prompt = |mode: &Mode| {
+        if interactive {
+            print!("{}> ", mode.name());
+            std::io::stdout().flush().unwrap();
+        }
+    };
+    prompt(&mode);
+    for line in std::io::stdin().lock().lines() {
+        let line = line.unwrap();
+        let line = line.trim();
+        if line == "quit" || line == "exit" {
+            break;
+        }
+        if !line.is_empty() {
+            match interpret(&mut mode, line) {
+                Ok(output) => println!("{}", output),
+                Err(error) => println!("error: {}", error),
+            }
+        }
+        prompt(&mode);
+    }
+}
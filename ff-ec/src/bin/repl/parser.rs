@@ -0,0 +1,191 @@
+use num_bigint::BigUint;
+
+// The grammar of a line, the usual precedences, `^` binding tighter than a unary minus on its right only:
+//   line    := [name '='] expr ['mod' number]
+//   expr    := term (('+' | '-') term)*
+//   term    := unary (('*' | '/') unary)*
+//   unary   := '-' unary | power
+//   power   := atom ['^' unary]
+//   atom    := number | name | name '(' expr (',' expr)* ')' | '(' expr ')' | '(' expr ',' expr ')'
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    Number(BigUint),
+    Variable(String),
+    Neg(Box<Expr>),
+    Binary(char, Box<Expr>, Box<Expr>),
+    Call(String, Vec<Expr>),
+    // a point given by its affine coordinates
+    Pair(Box<Expr>, Box<Expr>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Line {
+    pub assign: Option<String>,
+    pub expr: Expr,
+    pub modulus: Option<BigUint>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Number(BigUint),
+    Name(String),
+    Symbol(char),
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = vec![];
+    let mut chars = input.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_digit() {
+            let mut digits = String::new();
+            while let Some(&d) = chars.peek().filter(|d| d.is_ascii_alphanumeric()) {
+                digits.push(d);
+                chars.next();
+            }
+            let number = match digits.strip_prefix("0x") {
+                Some(hex) => BigUint::parse_bytes(hex.as_bytes(), 16),
+                None => BigUint::parse_bytes(digits.as_bytes(), 10),
+            };
+            tokens.push(Token::Number(
+                number.ok_or(format!("invalid number {}", digits))?,
+            ));
+        } else if c.is_alphabetic() || c == '_' {
+            let mut name = String::new();
+            while let Some(&d) = chars.peek().filter(|d| d.is_alphanumeric() || **d == '_') {
+                name.push(d);
+                chars.next();
+            }
+            tokens.push(Token::Name(name));
+        } else if "+-*/^(),=".contains(c) {
+            tokens.push(Token::Symbol(c));
+            chars.next();
+        } else {
+            return Err(format!("unexpected character '{}'", c));
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn eat(&mut self, symbol: char) -> bool {
+        let found = self.peek() == Some(&Token::Symbol(symbol));
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, symbol: char) -> Result<(), String> {
+        match self.eat(symbol) {
+            true => Ok(()),
+            false => Err(format!("expected '{}'", symbol)),
+        }
+    }
+
+    fn expr(&mut self) -> Result<Expr, String> {
+        let mut left = self.term()?;
+        while let Some(Token::Symbol(op @ ('+' | '-'))) = self.peek().cloned() {
+            self.position += 1;
+            left = Expr::Binary(op, Box::new(left), Box::new(self.term()?));
+        }
+        Ok(left)
+    }
+
+    fn term(&mut self) -> Result<Expr, String> {
+        let mut left = self.unary()?;
+        while let Some(Token::Symbol(op @ ('*' | '/'))) = self.peek().cloned() {
+            self.position += 1;
+            left = Expr::Binary(op, Box::new(left), Box::new(self.unary()?));
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Expr, String> {
+        match self.eat('-') {
+            true => Ok(Expr::Neg(Box::new(self.unary()?))),
+            false => self.power(),
+        }
+    }
+
+    fn power(&mut self) -> Result<Expr, String> {
+        let base = self.atom()?;
+        match self.eat('^') {
+            true => Ok(Expr::Binary('^', Box::new(base), Box::new(self.unary()?))),
+            false => Ok(base),
+        }
+    }
+
+    fn atom(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Number(n)) => Ok(Expr::Number(n)),
+            Some(Token::Name(name)) if self.eat('(') => {
+                let mut arguments = vec![self.expr()?];
+                while self.eat(',') {
+                    arguments.push(self.expr()?);
+                }
+                self.expect(')')?;
+                Ok(Expr::Call(name, arguments))
+            }
+            Some(Token::Name(name)) => Ok(Expr::Variable(name)),
+            Some(Token::Symbol('(')) => {
+                let first = self.expr()?;
+                let expr = match self.eat(',') {
+                    true => Expr::Pair(Box::new(first), Box::new(self.expr()?)),
+                    false => first,
+                };
+                self.expect(')')?;
+                Ok(expr)
+            }
+            Some(token) => Err(format!("unexpected {:?}", token)),
+            None => Err("unexpected end of line".to_string()),
+        }
+    }
+}
+
+pub fn parse_line(input: &str) -> Result<Line, String> {
+    let mut tokens = tokenize(input)?;
+    // a trailing `mod p`
+    let mut modulus = None;
+    if let [.., Token::Name(keyword), Token::Number(p)] = tokens.as_slice() {
+        if keyword == "mod" {
+            modulus = Some(p.clone());
+            tokens.truncate(tokens.len() - 2);
+        }
+    }
+    let mut assign = None;
+    if let [Token::Name(name), Token::Symbol('='), ..] = tokens.as_slice() {
+        assign = Some(name.clone());
+        tokens.drain(..2);
+    }
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+    };
+    let expr = parser.expr()?;
+    match parser.peek() {
+        None => Ok(Line {
+            assign,
+            expr,
+            modulus,
+        }),
+        Some(token) => Err(format!("unexpected {:?}", token)),
+    }
+}
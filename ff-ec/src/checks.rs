@@ -0,0 +1,30 @@
+use crate::*;
+
+// The expected answers of the first questions, checked by their digests (see crate `canonical`)
+// The answers of Q1, Q4 and Q5 do not depend on the random instance: only their digests are here
+// The points of Q2 and Q3 are random, their expected answers are computed at runtime:
+// reading the functions below gives these two questions away
+
+// the set of the generators of F_89*
+pub const Q1_DIGEST: &str = "ca7c6df69afd9cd9b29b1b83cdc254e6c34ae004541f2fb1055f5c7d7a480f06";
+// whether 0, 1 and 5 are x-coordinates of points of secp256k1
+pub const Q4_DIGEST: &str = "dedb0b2beb623d95a383d2652f105708b537bca38dd08627407a24da306f7621";
+// the affine coordinates (x, y) of 2G
+pub const Q5_DIGEST: &str = "a40501cf98bec54ede59e7eac347181d1b486ebd0877abd45e34bc1f86184ed1";
+
+// (lhs, rhs) of the affine equation of the curve
+pub fn q2(point: &Affine) -> (Fq, Fq) {
+    (point.y.square(), point.x.square() * point.x + Fq::from(7))
+}
+
+// (lhs, rhs) of the Jacobian equation of the curve
+pub fn q3(point: &Projective) -> (Fq, Fq) {
+    let z2 = point.z.square();
+    let rhs = point.x.square() * point.x + Fq::from(7) * z2.square() * z2;
+    (point.y.square(), rhs)
+}
+
+// (x, y), or None for the point at infinity
+pub fn coordinates(point: &Affine) -> Option<(Fq, Fq)> {
+    point.xy().map(|(x, y)| (*x, *y))
+}
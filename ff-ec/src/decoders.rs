@@ -0,0 +1,96 @@
+use crate::encoding::*;
+use crate::serialization::*;
+use ark_ec::AffineRepr;
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_secp256k1::{Affine, Fq, Fr};
+use ark_serialize::{CanonicalDeserialize, Validate};
+
+// The encoders and decoders of Q12, Q28, Q29 and Q31, between the SEC1 encoding of module `encoding` and the one
+// of arkworks of module `serialization`
+// The decoders parse bytes an attacker chooses: whatever the input, they return None rather than panic, and
+// accept only the bytes the encoder writes back. Two fuzzers check it: `cargo run --release -- fuzz` (see
+// module `fuzzing`), and the cargo-fuzz targets of `fuzz/`, `cargo +nightly fuzz run decode_point` for one,
+// which link this module through the library of the chapter
+
+pub fn field_to_bytes<F: PrimeField>(x: &F) -> Vec<u8> {
+    // SOLUTION-BEGIN
+    let mut bytes = x.into_bigint().to_bytes_le();
+    bytes.truncate(F::MODULUS_BIT_SIZE.div_ceil(8) as usize);
+    bytes
+    // SOLUTION-END
+}
+
+pub fn field_from_bytes<F: PrimeField>(bytes: &[u8]) -> Option<F> {
+    // SOLUTION-BEGIN
+    // the reduction maps a non-canonical integer to another one, which re-encodes differently
+    let x = F::from_le_bytes_mod_order(bytes);
+    (field_to_bytes(&x) == bytes).then_some(x)
+    // SOLUTION-END
+}
+
+pub fn point_to_bytes(point: &Affine, compress: bool) -> Vec<u8> {
+    // SOLUTION-BEGIN
+    let (x, y, flags) = match point.xy() {
+        None => (Fq::zero(), Fq::zero(), 1 << 6),
+        Some((x, y)) => (*x, *y, if *y > -*y { 1 << 7 } else { 0 }),
+    };
+    let with_flags = |z: &Fq| {
+        let mut bytes = field_to_bytes(z);
+        if bytes.len() * 8 < Fq::MODULUS_BIT_SIZE as usize + 2 {
+            bytes.push(0);
+        }
+        *bytes.last_mut().unwrap() |= flags;
+        bytes
+    };
+    match compress {
+        true => with_flags(&x),
+        false => [field_to_bytes(&x), with_flags(&y)].concat(),
+    }
+    // SOLUTION-END
+}
+
+pub fn decode_point(bytes: &[u8]) -> Option<Affine> {
+    // SOLUTION-BEGIN
+    let field_element = |bytes: &[u8]| {
+        let x = Fq::from_be_bytes_mod_order(bytes);
+        (be_bytes(&x) == bytes).then_some(x) // x < p
+    };
+    match (bytes.first()?, bytes.len()) {
+        (0, 1) => Some(Affine::zero()),
+        (2 | 3, 33) => {
+            let x = field_element(&bytes[1..])?;
+            let point = Affine::get_point_from_x_unchecked(x, false)?;
+            let y_is_odd = point.y.into_bigint().is_odd();
+            Some(if y_is_odd == (bytes[0] == 3) {
+                point
+            } else {
+                -point
+            })
+        }
+        (4, 65) => {
+            let point =
+                Affine::new_unchecked(field_element(&bytes[1..33])?, field_element(&bytes[33..])?);
+            point.is_on_curve().then_some(point)
+        }
+        _ => None,
+    }
+    // SOLUTION-END
+}
+
+pub fn decode_scalar(bytes: &[u8]) -> Option<Fr> {
+    // SOLUTION-BEGIN
+    if bytes.len() != 32 {
+        return None;
+    }
+    let s = Fr::from_be_bytes_mod_order(bytes);
+    (encode_scalar(&s) == bytes).then_some(s) // s < n
+                                              // SOLUTION-END
+}
+
+pub fn strict_point_from_bytes(bytes: &[u8], compress: bool) -> Option<Affine> {
+    // SOLUTION-BEGIN
+    let point =
+        Affine::deserialize_with_mode(bytes, compress_mode(compress), Validate::Yes).ok()?;
+    (point_to_bytes(&point, compress) == bytes).then_some(point)
+    // SOLUTION-END
+}
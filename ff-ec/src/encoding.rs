@@ -0,0 +1,42 @@
+use ark_ec::AffineRepr;
+use ark_ff::{BigInteger, PrimeField};
+use ark_secp256k1::{Affine, Fq, Fr};
+
+// SEC1 encodings of secp256k1 points and scalars
+// - the point at infinity is the single byte 0x00
+// - compressed: 0x02 (even y) or 0x03 (odd y), then x on 32 big-endian bytes
+// - uncompressed: 0x04, then x and y on 32 big-endian bytes each
+// - a scalar is 32 big-endian bytes
+// A decoder must accept exactly one encoding per value: x >= p, s >= n, a point off the curve,
+// a wrong prefix or length must all be rejected. Otherwise two different byte strings mean the same thing
+// (signature malleability, hash or cache mismatches...), or an invalid point gets into a scalar multiplication
+
+pub fn be_bytes(x: &Fq) -> Vec<u8> {
+    x.into_bigint().to_bytes_be()
+}
+
+pub fn encode_point_compressed(point: &Affine) -> Vec<u8> {
+    if point.is_zero() {
+        return vec![0];
+    }
+    let prefix = if point.y.into_bigint().is_odd() { 3 } else { 2 };
+    [vec![prefix], be_bytes(&point.x)].concat()
+}
+
+pub fn encode_point_uncompressed(point: &Affine) -> Vec<u8> {
+    if point.is_zero() {
+        return vec![0];
+    }
+    [vec![4], be_bytes(&point.x), be_bytes(&point.y)].concat()
+}
+
+pub fn encode_scalar(s: &Fr) -> Vec<u8> {
+    s.into_bigint().to_bytes_be()
+}
+
+pub fn from_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
@@ -0,0 +1,46 @@
+use crate::*;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// Fixed-window exponentiation: write the exponent in base 2^W, precompute a^0, a^1, ..., a^(2^W - 1),
+// then for each digit d from the most significant one: square W times and multiply by a^d
+// For a b-bit exponent it takes about b squarings, 2^W multiplications for the table and b / W for the digits:
+// a larger window saves multiplications in the loop but costs them back in the table
+// The window is a const generic: `pow_window::<4, _>(a, exponent)`, each W is its own function
+
+pub const WINDOWS: [usize; 5] = [2, 3, 4, 5, 6];
+pub const POW_WINDOW_SECTION: &str = "pow_window.ff-ec";
+
+pub type Pow = fn(Fq, &[u64]) -> Fq;
+
+// about how many multiplications (squarings included) a fixed window of w takes on a b-bit exponent
+pub fn window_cost(w: usize, bits: usize) -> usize {
+    bits + (1 << w) + bits.div_ceil(w)
+}
+
+// the average time of one exponentiation of a base by an exponent of the list
+pub fn time_pow(pow: Pow, bases: &[Fq], exponents: &[[u64; 4]]) -> Duration {
+    let start = Instant::now();
+    for (base, exponent) in bases.iter().zip(exponents) {
+        black_box(pow(black_box(*base), black_box(exponent)));
+    }
+    start.elapsed() / bases.len() as u32
+}
+
+// the latest timings in nanoseconds, one key per window, and the fastest window
+pub fn record_pow_windows(timings: &[(usize, Duration)]) -> std::io::Result<()> {
+    let mut report = match std::fs::read_to_string(runner::PROGRESS_REPORT) {
+        Ok(text) => text
+            .parse()
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Fixtures::new(),
+        Err(e) => return Err(e),
+    };
+    for (w, time) in timings {
+        report.set(POW_WINDOW_SECTION, &format!("w{}", w), time.as_nanos());
+    }
+    if let Some((w, _)) = timings.iter().min_by_key(|(_, time)| *time) {
+        report.set(POW_WINDOW_SECTION, "optimum", w);
+    }
+    std::fs::write(runner::PROGRESS_REPORT, report.to_string())
+}
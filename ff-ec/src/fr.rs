@@ -0,0 +1,199 @@
+use runner::lang::{Catalog, Lang, Statements};
+
+pub const CATALOG: Catalog = Catalog {
+    lang: Lang::Fr,
+    statements: Statements::Table(&[
+        ("Q1", "trouver tous les générateurs du groupe multiplicatif de F_89\nles mettre dans le vecteur `gen`"),
+        ("Q2", "vérifier que les coordonnées du point `g_aff` satisfont l'équation de la courbe y^2 = x^3 + 7\nÉcrire `affine_equation` qui calcule le membre de gauche `lhs` et le membre de droite `rhs` de cette équation"),
+        ("Q3", "vérifier que les coordonnées du point `g_proj` satisfont l'équation de la courbe en coordonnées projectives jacobiennes Y^2 = X^3 + 7*Z^6\nÉcrire `jacobian_equation` qui calcule le membre de gauche `lhs` et le membre de droite `rhs` de cette équation"),
+        ("Q4", "Y a-t-il un point de secp256k1 d'abscisse 0 ? 1 ? et 5 ?\nÉcrire `is_x_coordinate` qui dit si x est l'abscisse d'un point de la courbe"),
+        ("Q5", "écrire `double_affine` qui calcule les coordonnées affines de 2P avec les formules de doublement du cours\n(2P est le point à l'infini quand P l'est)"),
+        ("Q6", "écrire `count_points` qui compte les points de y^2 = x^3 + 7 sur un petit corps premier par force brute\n(ne pas oublier le point à l'infini)"),
+        ("Q7", "écrire `twist_order` qui calcule son nombre de points à partir de p et #E"),
+        ("Q8", "une implémentation calcule des multiplications scalaires sur les seules abscisses et oublie de vérifier que x est sur la courbe :\nqu'apprend un attaquant en envoyant les abscisses de points de petit ordre du twist ?"),
+        ("Q9", "écrire `extended_gcd` qui renvoie (g, u, v) tels que ua + vb = g = pgcd(a, b)"),
+        ("Q10", "en déduire `inverse_mod` : si ua + vp = 1 alors u est l'inverse de a mod p"),
+        ("Q11", "l'inverse est aussi a^(p-2) par le petit théorème de Fermat : combien de multiplications cela prend-il ?\nCombien d'étapes de division l'algorithme d'Euclide étendu fait-il au plus (voir le théorème de Lamé) ?"),
+        ("Q12", "écrire `decode_point` et `decode_scalar` dans le module `decoders`, qui doivent rejeter tout encodage non canonique\nLes fixtures contiennent des encodages valides et leurs mutants, le rapport liste ceux que vos décodeurs traitent mal"),
+        ("Q13", "arkworks a son propre encodage compressé, avec des drapeaux pour l'infini et le signe de y :\ndonner les mutants de `Affine::serialize_compressed(&g_aff)` à `Affine::deserialize_compressed` et comparer"),
+        ("Q14", "écrire `pow_window`, l'exponentiation à fenêtre fixe de W bits\nL'exposant est une liste de mots de 64 bits, poids faible en premier, comme dans `Field::pow`"),
+        ("Q15", "rien à écrire, `pow_window` sur des exposants de 256 bits pour W = 2..6\nQuel W minimise `window_cost` ? Est-ce le plus rapide ? Les temps vont dans le rapport de progression"),
+        ("Q16", "avant de mesurer, prédire le coût d'une addition affine, d'une addition jacobienne, d'une addition mixte\n(jacobien + affine), d'un doublement affine et jacobien, en multiplications M, carrés S et inversions I\nEn supposant I = 100M et S = 0.8M, quels rapports à l'addition jacobienne attendez-vous ?"),
+        ("Q17", "lancer `cargo bench --bench coordinates` et comparer avec vos prédictions\nPourquoi l'addition mixte est-elle moins chère ? Que gagne une multiplication scalaire aux coordonnées jacobiennes,\net pourquoi arkworks renvoie-t-il quand même des points affines avec `into_affine` ?"),
+        ("Q18", "écrire `generators_parallel`, qui renvoie les générateurs de F_p* parmi les candidats, pour p = 2^31 - 1,\ncomme `generators_sequential`, mais répartit les candidats en blocs cherchés par `threads` threads\n(`std::thread::scope`), puis concatène leurs résultats dans l'ordre"),
+        ("Q19", "écrire `multiplicative_order`, qui calcule l'ordre de g dans F_p* à partir de la factorisation de p - 1\n(voir le module `generators`), puis résoudre Q1 à nouveau : les générateurs de F_89* sont les éléments d'ordre 88"),
+        ("Q20", "écrire `toy_points` qui liste tous les points affines de y^2 = x^3 + 7 sur F_89, par force brute\nAvec le point à l'infini, ils forment le groupe E(F_89) : combien a-t-il d'éléments ?"),
+        ("Q21", "écrire `point_order` qui renvoie l'ordre d'un point : le plus petit k > 0 tel que kP = 0\nLagrange : l'ordre de chaque point divise l'ordre du groupe"),
+        ("Q22", "écrire `cofactor` qui décompose l'ordre du groupe en r h, r son plus grand facteur premier\nLe sous-groupe d'ordre r est celui où travaille arkworks, h P y tombe pour tout point P"),
+        ("Q23", "secp256k1 a un cofacteur de 1. Qu'est-ce qui pourrait mal tourner avec h > 1 si un protocole acceptait n'importe quel point de la\ncourbe venant d'un attaquant ? Comment Curve25519 (h = 8) et ses protocoles s'en accommodent-ils ?"),
+        ("Q24", "écrire `affine_add` et `affine_double` à partir des formules de la corde et de la tangente du cours\n- O + Q = Q, P + O = P, P + (-P) = O\n- P + P est un doublement : la tangente a pour pente 3x^2 / 2y (il n'y a pas de point avec y = 0 sur secp256k1)\n- sinon la corde passant par P et Q a pour pente (y_Q - y_P) / (x_Q - x_P)"),
+        ("Q25", "écrire `legendre_symbol` qui renvoie 1, 0 ou -1 selon que a est un carré non nul, zéro ou un non-carré\nmod p, puis `count_points_legendre` qui compte les points de y^2 = x^3 + ax + b avec p symboles de Legendre"),
+        ("Q26", "écrire `trace_of_frobenius`, t = p + 1 - #E, et vérifier la borne de Hasse |t| <= 2 sqrt(p) sur des courbes aléatoires"),
+        ("Q27", "lancer `cargo bench --bench point_counting` : combien de temps prendrait `count_points_legendre` sur\nsecp256k1 ? Deux des petites courbes ont t = 0, pourquoi ?\nL'algorithme de Schoof trouve t mod l à partir de la l-torsion pour de petits premiers l : combien de premiers l faut-il\npour un p de 256 bits ?"),
+        ("Q28", "écrire `field_to_bytes` et `field_from_bytes` dans le module `decoders`, l'encodage arkworks d'un élément de corps premier\n`field_from_bytes` rejette une mauvaise longueur et tout entier >= p, comme `deserialize_compressed`"),
+        ("Q29", "écrire `point_to_bytes` dans le module `decoders`, l'encodage arkworks d'un point de secp256k1, compressé ou non"),
+        ("Q30", "BLS12-381 a un p de 381 bits : combien d'octets arkworks prend-il pour un point compressé, et où\nvont les drapeaux ? Pourquoi « y > -y » et pas la parité de y comme en SEC1 ?\nQue ferait un décodeur SEC1 des octets d'arkworks, et que se passe-t-il si un désérialiseur accepte\nles deux drapeaux à la fois ?"),
+        ("Q31", "écrire `strict_point_from_bytes` dans le module `decoders`, qui accepte exactement les octets qu'écrit\n`point_to_bytes`, puis lancer `cargo run --release -- fuzz` : chaque décodeur du chapitre doit s'en sortir sans découverte"),
+    ]),
+    hints: &[
+        (
+            "Q1",
+            &[
+                "g engendre F_89* si et seulement si ses puissances g^1, ..., g^88 prennent les 88 valeurs non nulles",
+                "De façon équivalente, g^(88 / q) != 1 pour chaque facteur premier q de 88 = 2^3 * 11",
+                "Pour chaque i dans 2..89, rassembler g^1, ..., g^88 dans un `HashSet` et garder i si l'ensemble a 88 éléments",
+            ],
+        ),
+        (
+            "Q5",
+            &[
+                "La tangente en G recoupe la courbe en un troisième point, dont le symétrique est 2G",
+                "La pente de la tangente est lambda = 3 x^2 / (2 y) (a = 0 pour secp256k1)",
+                "x_2 = lambda^2 - 2 x et y_2 = lambda (x - x_2) - y",
+            ],
+        ),
+        (
+            "Q7",
+            &[
+                "#E = p + 1 - t, où t est la trace du Frobenius",
+                "Le twist a pour trace -t",
+                "#E' = p + 1 + t = 2 (p + 1) - #E",
+            ],
+        ),
+        (
+            "Q9",
+            &[
+                "pgcd(a, b) = pgcd(b, a mod b) : garder les coefficients de Bézout de chaque reste en chemin",
+                "Garder deux lignes (r, u, v) avec u a + v b = r, en partant de (a, 1, 0) et (b, 0, 1)",
+                "Avec q = r_0 / r_1, la ligne suivante est (r_0 - q r_1, u_0 - q u_1, v_0 - q v_1) : s'arrêter quand r_1 = 0",
+            ],
+        ),
+        (
+            "Q12",
+            &[
+                "Chaque valeur a exactement un encodage : toute autre suite d'octets doit être rejetée",
+                "Vérifier le préfixe et la longueur ensemble, puis que x < p (et y < p, s < n), puis que le point est sur la courbe",
+                "Décoder un entier avec `from_be_bytes_mod_order`, et le rejeter si son réencodage diffère de l'entrée",
+            ],
+        ),
+        (
+            "Q16",
+            &[
+                "Compter les opérations des formules de la corde et de la tangente : la pente est une division",
+                "Affine : 1I + 2M + 1S pour une addition, 1I + 2M + 2S pour un doublement ; addition mixte : 7M + 4S",
+                "Addition jacobienne 11M + 5S = 15M, doublement 2M + 5S = 6M (a = 0) : rapports d'environ 6.9, 1, 0.7, 6.9 et 0.4",
+            ],
+        ),
+        (
+            "Q17",
+            &[
+                "Une inversion coûte autant que des dizaines de multiplications, et une formule affine en a une",
+                "Avec Z = 1 les termes Z^2 et Z^3 d'un opérande disparaissent",
+                "Une multiplication scalaire enchaîne des centaines d'opérations et n'inverse qu'une fois à la fin ; l'égalité, le hachage et l'encodage ont besoin de la forme affine unique",
+            ],
+        ),
+        (
+            "Q18",
+            &[
+                "Chaque candidat est testé indépendamment : découper l'intervalle en `threads` blocs consécutifs",
+                "`std::thread::scope` permet aux threads d'emprunter à l'appelant, et `spawn` renvoie un handle dont `join` donne le résultat",
+                "Lancer un `generators_sequential(start..end)` par bloc, puis joindre les handles dans l'ordre et concaténer",
+            ],
+        ),
+        (
+            "Q19",
+            &[
+                "L'ordre de g divise p - 1, et g^k = 1 exactement quand l'ordre divise k",
+                "Partir de p - 1 : pour chaque facteur premier q, l'ordre reste un multiple de q tant que g^(order / q) != 1",
+                "Pour chaque (q, e), diviser l'ordre par q au plus e fois, en s'arrêtant au premier g^(order / q) != 1",
+            ],
+        ),
+        (
+            "Q20",
+            &[
+                "Un point est une paire (x, y) d'éléments de F_89 qui satisfait l'équation : il n'y a que 89^2 paires à essayer",
+                "Comparer `y.square()` avec `x.square() * x + F::from(7)`",
+                "Boucler sur x et y dans 0..89, ajouter `ToyAffine::new_unchecked(x, y)` pour les paires sur la courbe (`new` rejette les points hors du sous-groupe d'ordre r) ; le groupe a un élément de plus, le point à l'infini",
+            ],
+        ),
+        (
+            "Q21",
+            &[
+                "Ajouter P à lui-même jusqu'à atteindre le point à l'infini",
+                "Travailler en coordonnées projectives : `point.into_group()`, puis `+=` le point affine",
+                "Partir de P avec k = 1 et incrémenter k jusqu'à ce que le multiple soit `is_zero()`",
+            ],
+        ),
+        (
+            "Q22",
+            &[
+                "Factoriser l'ordre du groupe par divisions successives",
+                "r est le dernier facteur premier trouvé, le cofacteur est l'ordre divisé par r",
+                "Diviser par chaque d à partir de 2 tant que d^2 <= n, retenir le plus grand d ; ce qui reste de n au-dessus de 1 est premier et plus grand",
+            ],
+        ),
+        (
+            "Q24",
+            &[
+                "Traiter d'abord le point à l'infini : c'est l'élément neutre",
+                "Même x : soit Q = -P et la somme est O, soit Q = P et c'est un doublement",
+                "Corde : lambda = (y2 - y1) / (x2 - x1), x3 = lambda^2 - x1 - x2, y3 = lambda (x1 - x3) - y1 ; tangente : lambda = 3x^2 / 2y et x3 = lambda^2 - 2x",
+            ],
+        ),
+        (
+            "Q25",
+            &[
+                "F_p* est cyclique d'ordre pair p - 1 : a^(p - 1) = 1, donc a^((p - 1) / 2) est une racine carrée de 1, soit 1 ou -1",
+                "Pour un x donné, y^2 = x^3 + ax + b a 2 solutions si le membre de droite est un carré non nul, 1 s'il est nul, 0 sinon",
+                "Cela fait 1 + (rhs | p) points pour chaque x : #E = 1 + p + la somme des symboles de Legendre",
+            ],
+        ),
+        (
+            "Q26",
+            &[
+                "Le comptage de Q25 donne t directement",
+                "t = -(la somme des symboles de Legendre) : une marche aléatoire de p pas atteindrait environ sqrt(p), Hasse la borne par 2 sqrt(p)",
+                "La borne est un théorème sur les courbes elliptiques : les points lisses d'une cubique singulière forment à la place le groupe F_p* ou F_p",
+            ],
+        ),
+        (
+            "Q27",
+            &[
+                "Le temps par x bouge à peine : le comptage croît comme p, c'est-à-dire 2^bits",
+                "Pour p = 2 mod 3, x -> x^3 est une bijection de F_p ; pour p = 3 mod 4, -1 n'est pas un carré : que devient la somme des symboles pour b = 0 ?",
+                "|t| <= 2 sqrt(p), donc t est connu dès qu'il l'est modulo des premiers l dont le produit dépasse 4 sqrt(p) : environ 130 bits de produit pour secp256k1",
+            ],
+        ),
+        (
+            "Q28",
+            &[
+                "`into_bigint` donne l'entier canonique, pas la forme de Montgomery stockée dans l'élément",
+                "`BigInteger::to_bytes_le` écrit tous les mots : garder ceil(MODULUS_BIT_SIZE / 8) octets",
+                "Décoder avec `from_le_bytes_mod_order`, puis réencoder : les octets sont canoniques si et seulement s'ils reviennent inchangés",
+            ],
+        ),
+        (
+            "Q29",
+            &[
+                "Partir de `field_to_bytes` de x, et de y si non compressé",
+                "Les drapeaux ont besoin de 2 bits au-dessus des 256 bits de p : ajouter un octet nul, puis faire un OR des drapeaux dans le dernier octet",
+                "Infini : x = y = 0 avec le bit 6 à un ; sinon le bit 7 est à un si et seulement si y > -y",
+            ],
+        ),
+        (
+            "Q30",
+            &[
+                "381 bits dans 48 octets laissent 3 bits libres : pas d'octet en plus, et un troisième drapeau tient",
+                "La comparaison y > -y fonctionne dans tout corps, même un corps dont les éléments ne sont pas des entiers comme F_p2",
+                "Un décodeur SEC1 lit le premier octet comme un préfixe. Les deux drapeaux à la fois donnent deux sens à une même chaîne, comme en Q12",
+            ],
+        ),
+        (
+            "Q31",
+            &[
+                "`deserialize_with_mode` avec le mode de `compress_mode` et `Validate::Yes` vérifie le point, pas les octets qui l'entourent",
+                "Réencoder le point décodé avec `point_to_bytes` et ne l'accepter que s'il redonne exactement l'entrée",
+            ],
+        ),
+    ],
+};
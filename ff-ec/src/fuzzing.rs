@@ -0,0 +1,87 @@
+use crate::*;
+use training_common::fuzz::{fuzz, print_report, DEFAULT_FUZZ_RUNS};
+
+// `cargo run --release -- fuzz [runs]` feeds mutants of valid encodings to the decoders of Q12, Q28 and Q31
+// A decoder may reject anything, but must never panic, and must only accept the bytes it would encode:
+// every accepted input is encoded again and compared (see module `fuzz` of the training-common crate)
+// Fed to `Affine::deserialize_compressed` itself, the fuzzer finds two inputs for the point at infinity within
+// seconds: its flag with a non-zero x, and a valid encoding followed by a stray byte. Hence the wrapper of Q31
+
+// Ok(true) for an accepted canonical encoding, Ok(false) for a rejected input
+fn round_trip<T>(
+    bytes: &[u8],
+    decoded: Option<T>,
+    encode: impl Fn(&T) -> Vec<u8>,
+) -> Result<bool, String> {
+    match decoded {
+        None => Ok(false),
+        Some(value) if encode(&value) == bytes => Ok(true),
+        Some(value) => Err(format!(
+            "accepted a non-canonical encoding of {}",
+            to_hex(&encode(&value))
+        )),
+    }
+}
+
+pub fn fuzz_decoders(args: &[String]) {
+    let runs = match args {
+        [] => DEFAULT_FUZZ_RUNS,
+        [runs] => runs.parse().expect("runs"),
+        _ => panic!("usage: fuzz [runs]"),
+    };
+    let mut rng = seed::rng();
+    let g = Affine::generator();
+    let points = [g, -g, Affine::zero()];
+    let scalars = [Fr::one(), -Fr::one()];
+    let elements = [Fq::one(), -Fq::one()];
+
+    let seeds: Vec<Vec<u8>> = points
+        .iter()
+        .flat_map(|point| {
+            [
+                encode_point_compressed(point),
+                encode_point_uncompressed(point),
+            ]
+        })
+        .collect();
+    let sec1_points = fuzz(&seeds, runs, &mut rng, |bytes| {
+        let encode = |point: &Affine| match bytes.len() {
+            65 => encode_point_uncompressed(point),
+            _ => encode_point_compressed(point),
+        };
+        round_trip(bytes, decode_point(bytes), encode)
+    });
+
+    let seeds: Vec<Vec<u8>> = scalars.iter().map(encode_scalar).collect();
+    let sec1_scalars = fuzz(&seeds, runs, &mut rng, |bytes| {
+        round_trip(bytes, decode_scalar(bytes), encode_scalar)
+    });
+
+    let seeds: Vec<Vec<u8>> = elements.iter().map(field_to_bytes).collect();
+    let field_elements = fuzz(&seeds, runs, &mut rng, |bytes| {
+        round_trip(bytes, field_from_bytes::<Fq>(bytes), field_to_bytes)
+    });
+
+    let mut ark_points = vec![];
+    for compress in [true, false] {
+        let seeds: Vec<Vec<u8>> = points
+            .iter()
+            .map(|point| ark_bytes(point, compress))
+            .collect();
+        ark_points.push(fuzz(&seeds, runs, &mut rng, |bytes| {
+            let decoded = strict_point_from_bytes(bytes, compress);
+            round_trip(bytes, decoded, |point| point_to_bytes(point, compress))
+        }));
+    }
+
+    let passed = [
+        print_report("decode_point", &sec1_points),
+        print_report("decode_scalar", &sec1_scalars),
+        print_report("field_from_bytes::<Fq>", &field_elements),
+        print_report("strict_point_from_bytes, compressed", &ark_points[0]),
+        print_report("strict_point_from_bytes, uncompressed", &ark_points[1]),
+    ];
+    if passed.contains(&false) {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,81 @@
+use std::ops::Range;
+use std::time::Duration;
+
+// Q1 again, over the 31-bit field F_p with p = 2^31 - 1: far too many elements for the powers of each candidate
+// - g generates F_p* if and only if g^((p - 1) / q) != 1 for each prime q dividing p - 1
+// - the candidates of a range are independent: the search splits into chunks, one per thread
+// The sequential search below is the skeleton of Q18; the parallel search must return the same generators,
+// in the same order, within a time budget derived from the sequential one and the number of cores
+
+pub const LARGE_PRIME: u64 = (1 << 31) - 1;
+// p - 1 = 2 * 3^2 * 7 * 11 * 31 * 151 * 331
+pub const PRIME_FACTORS: [u64; 7] = [2, 3, 7, 11, 31, 151, 331];
+// the candidates searched in Q18
+pub const SEARCH: Range<u64> = 2..1 << 20;
+
+// multiplications in u128: p may have up to 64 bits
+pub fn mul_mod(a: u64, b: u64, p: u64) -> u64 {
+    (a as u128 * b as u128 % p as u128) as u64
+}
+
+pub fn pow_mod(base: u64, mut exponent: u64, p: u64) -> u64 {
+    let (mut base, mut result) = (base % p, 1);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, p);
+        }
+        base = mul_mod(base, base, p);
+        exponent >>= 1;
+    }
+    result
+}
+
+pub fn is_generator(g: u64) -> bool {
+    !g.is_multiple_of(LARGE_PRIME)
+        && PRIME_FACTORS
+            .iter()
+            .all(|q| pow_mod(g, (LARGE_PRIME - 1) / q, LARGE_PRIME) != 1)
+}
+
+pub fn generators_sequential(candidates: Range<u64>) -> Vec<u64> {
+    candidates.filter(|g| is_generator(*g)).collect()
+}
+
+// The order of g in F_p* divides p - 1 = prod q_i^e_i (Lagrange): start from p - 1 and divide by each q_i
+// as long as g^(order / q_i) is still 1, a few exponentiations per prime factor instead of up to p - 1 multiplications
+// g is a generator exactly when its order is p - 1, which Q19 checks against the brute force of Q1
+pub const FACTORS_89: [(u64, u32); 2] = [(2, 3), (11, 1)];
+pub const FACTORS_65537: [(u64, u32); 1] = [(2, 16)];
+// the Mersenne prime 2^61 - 1: brute force would take up to 2^61 multiplications per element
+pub const MERSENNE_61: u64 = (1 << 61) - 1;
+pub const FACTORS_MERSENNE_61: [(u64, u32); 12] = [
+    (2, 1),
+    (3, 2),
+    (5, 2),
+    (7, 1),
+    (11, 1),
+    (13, 1),
+    (31, 1),
+    (41, 1),
+    (61, 1),
+    (151, 1),
+    (331, 1),
+    (1321, 1),
+];
+
+// the smallest k > 0 such that g^k = 1, one multiplication at a time
+pub fn order_brute_force(g: u64, p: u64) -> u64 {
+    let g = g % p;
+    let (mut power, mut k) = (g, 1);
+    while power != 1 {
+        power = mul_mod(power, g, p);
+        k += 1;
+    }
+    k
+}
+
+// a quarter slower than a perfect split over the cores, plus the cost of starting the threads
+pub fn time_budget(sequential: Duration, threads: usize) -> Duration {
+    let cores = std::thread::available_parallelism().map_or(1, |n| n.get());
+    sequential * 5 / 4 / threads.min(cores).max(1) as u32 + Duration::from_millis(20)
+}
@@ -0,0 +1,169 @@
+use runner::hints::Hints;
+use runner::lang::Catalog;
+
+pub const CATALOGS: &[Catalog] = &[
+    Catalog::english(HINTS, include_str!("main.rs")),
+    crate::fr::CATALOG,
+];
+
+pub const HINTS: Hints = &[
+    (
+        "Q1",
+        &[
+            "g generates F_89* iff its powers g^1, ..., g^88 take all the 88 non-zero values",
+            "Equivalently, g^(88 / q) != 1 for every prime factor q of 88 = 2^3 * 11",
+            "For each i in 2..89, collect g^1, ..., g^88 in a `HashSet` and keep i if the set has 88 elements",
+        ],
+    ),
+    (
+        "Q5",
+        &[
+            "The tangent at G cuts the curve at a third point, whose reflection is 2G",
+            "The slope of the tangent is lambda = 3 x^2 / (2 y) (a = 0 for secp256k1)",
+            "x_2 = lambda^2 - 2 x and y_2 = lambda (x - x_2) - y",
+        ],
+    ),
+    (
+        "Q7",
+        &[
+            "#E = p + 1 - t, where t is the trace of the Frobenius",
+            "The twist has trace -t",
+            "#E' = p + 1 + t = 2 (p + 1) - #E",
+        ],
+    ),
+    (
+        "Q9",
+        &[
+            "gcd(a, b) = gcd(b, a mod b): keep the Bezout coefficients of each remainder along the way",
+            "Keep two rows (r, u, v) with u a + v b = r, starting from (a, 1, 0) and (b, 0, 1)",
+            "With q = r_0 / r_1, the next row is (r_0 - q r_1, u_0 - q u_1, v_0 - q v_1): stop when r_1 = 0",
+        ],
+    ),
+    (
+        "Q12",
+        &[
+            "Each value has exactly one encoding: any other byte string must be rejected",
+            "Check the prefix and the length together, then that x < p (and y < p, s < n), then that the point is on the curve",
+            "Decode an integer with `from_be_bytes_mod_order`, and reject it if its re-encoding differs from the input",
+        ],
+    ),
+    (
+        "Q16",
+        &[
+            "Count the operations of the chord-and-tangent formulas: the slope is a division",
+            "Affine: 1I + 2M + 1S for an addition, 1I + 2M + 2S for a doubling; mixed addition: 7M + 4S",
+            "Jacobian addition 11M + 5S = 15M, doubling 2M + 5S = 6M (a = 0): ratios of about 6.9, 1, 0.7, 6.9 and 0.4",
+        ],
+    ),
+    (
+        "Q17",
+        &[
+            "An inversion costs as much as dozens of multiplications, and an affine formula has one",
+            "With Z = 1 the terms Z^2 and Z^3 of one operand vanish",
+            "A scalar multiplication chains hundreds of operations and inverts once at the end; equality, hashing and encoding need the unique affine form",
+        ],
+    ),
+    (
+        "Q18",
+        &[
+            "Each candidate is tested on its own: split the range into `threads` consecutive chunks",
+            "`std::thread::scope` lets the threads borrow from the caller, and `spawn` returns a handle whose `join` gives the result",
+            "Spawn one `generators_sequential(start..end)` per chunk, then join the handles in order and concatenate",
+        ],
+    ),
+    (
+        "Q19",
+        &[
+            "The order of g divides p - 1, and g^k = 1 exactly when the order divides k",
+            "Start from p - 1: for each prime factor q, the order is still a multiple of q while g^(order / q) != 1",
+            "For each (q, e), divide the order by q at most e times, stopping at the first g^(order / q) != 1",
+        ],
+    ),
+    (
+        "Q20",
+        &[
+            "A point is a pair (x, y) of elements of F_89 satisfying the equation: there are only 89^2 pairs to try",
+            "Compare `y.square()` with `x.square() * x + F::from(7)`",
+            "Loop over x and y in 0..89, push `ToyAffine::new_unchecked(x, y)` for the pairs on the curve (`new` rejects the points outside the subgroup of order r); the group has one more element, the point at infinity",
+        ],
+    ),
+    (
+        "Q21",
+        &[
+            "Add P to itself until you reach the point at infinity",
+            "Work in projective coordinates: `point.into_group()`, then `+=` the affine point",
+            "Start from P with k = 1 and increment k until the multiple `is_zero()`",
+        ],
+    ),
+    (
+        "Q22",
+        &[
+            "Factor the order of the group by trial division",
+            "r is the last prime factor found, the cofactor is the order divided by r",
+            "Divide out each d from 2 while d^2 <= n, remember the largest d; what remains of n above 1 is prime and larger",
+        ],
+    ),
+    (
+        "Q24",
+        &[
+            "Handle the point at infinity first: it is the neutral element",
+            "Same x: either Q = -P and the sum is O, or Q = P and it is a doubling",
+            "Chord: lambda = (y2 - y1) / (x2 - x1), x3 = lambda^2 - x1 - x2, y3 = lambda (x1 - x3) - y1; tangent: lambda = 3x^2 / 2y and x3 = lambda^2 - 2x",
+        ],
+    ),
+    (
+        "Q25",
+        &[
+            "F_p* is cyclic of even order p - 1: a^(p - 1) = 1, so a^((p - 1) / 2) is a square root of 1, that is 1 or -1",
+            "For a given x, y^2 = x^3 + ax + b has 2 solutions if the right-hand side is a non-zero square, 1 if it is zero, 0 otherwise",
+            "That is 1 + (rhs | p) points for each x: #E = 1 + p + the sum of the Legendre symbols",
+        ],
+    ),
+    (
+        "Q26",
+        &[
+            "The count of Q25 gives t directly",
+            "t = -(the sum of the Legendre symbols): a random walk of p steps would reach about sqrt(p), Hasse bounds it by 2 sqrt(p)",
+            "The bound is a theorem on elliptic curves: the smooth points of a singular cubic form the group F_p* or F_p instead",
+        ],
+    ),
+    (
+        "Q27",
+        &[
+            "The time per x barely moves: the count grows like p, that is 2^bits",
+            "For p = 2 mod 3, x -> x^3 is a bijection of F_p; for p = 3 mod 4, -1 is not a square: what happens to the sum of the symbols for b = 0?",
+            "|t| <= 2 sqrt(p), so t is known once it is known modulo primes l whose product exceeds 4 sqrt(p): about 130 bits of product for secp256k1",
+        ],
+    ),
+    (
+        "Q28",
+        &[
+            "`into_bigint` gives the canonical integer, not the Montgomery form stored in the element",
+            "`BigInteger::to_bytes_le` writes all the limbs: keep ceil(MODULUS_BIT_SIZE / 8) bytes",
+            "Decode with `from_le_bytes_mod_order`, then re-encode: the bytes are canonical iff they come back unchanged",
+        ],
+    ),
+    (
+        "Q29",
+        &[
+            "Start from `field_to_bytes` of x, and of y when uncompressed",
+            "The flags need 2 bits above the 256 bits of p: add a byte of zeros, then OR the flags into the last byte",
+            "Infinity: x = y = 0 with bit 6 set; otherwise bit 7 is set iff y > -y",
+        ],
+    ),
+    (
+        "Q30",
+        &[
+            "381 bits in 48 bytes leave 3 spare bits: no extra byte, and a third flag fits",
+            "The comparison y > -y works in any field, even one whose elements are not integers such as F_p2",
+            "A SEC1 decoder reads the first byte as a prefix. Both flags at once are two meanings for one string, as in Q12",
+        ],
+    ),
+    (
+        "Q31",
+        &[
+            "`deserialize_with_mode` with the mode of `compress_mode` and `Validate::Yes` checks the point, not the bytes around it",
+            "Encode the decoded point again with `point_to_bytes` and accept it only if it gives back exactly the input",
+        ],
+    ),
+];
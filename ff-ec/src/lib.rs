@@ -0,0 +1,5 @@
+// The library of the chapter: the encoders and decoders of Q12, Q28, Q29 and Q31 with the encodings they
+// follow, for `main.rs` and for the cargo-fuzz targets of `fuzz/`, which cannot link a binary
+pub mod decoders;
+pub mod encoding;
+pub mod serialization;
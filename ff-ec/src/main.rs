@@ -1,23 +1,69 @@
 use ark_ec::{AffineRepr, CurveGroup};
-use ark_ff::{BigInt, Field, Fp64, MontBackend, MontConfig, PrimeField};
+use ark_ff::{BigInt, BigInteger, Field, PrimeField};
 use ark_secp256k1::{Affine, Fq, Fr, Projective};
+use ark_serialize::{CanonicalDeserialize, Validate};
 use ark_std::{ops::Mul, One, UniformRand, Zero};
-use rand::SeedableRng;
-use rand_chacha::ChaChaRng;
+use checks::*;
+use exponentiation::*;
+use ff_ec::{decoders::*, encoding::*, serialization::*};
+use fuzzing::*;
+use generators::*;
+use num_bigint::{BigInt as Integer, BigUint};
+use rand::Rng;
+use runner::Runner;
+use sage_fixtures::Fixtures;
 use std::collections::HashSet;
+use std::ops::Range;
+use std::time::Instant;
+use toy_curve::*;
+
+mod checks;
+mod exponentiation;
+mod fr;
+mod fuzzing;
+mod generators;
+mod hints;
+mod properties;
+mod toy_curve;
+#[cfg(feature = "solutions")]
+mod walkthrough;
+
+// The small field of the first questions, F_89, also the base field of the toy curve of module `toy_curve`
+// Both live in the training-common crate, for the other chapters: take a look at its module `toy`
+pub use training_common::toy::F;
 
 fn main() {
+    // instructors: `cargo run --release -- plot 60 2` draws the toy curve and the orbit of (60, 2), see
+    // `plot_curve` in module `toy_curve`
+    let args = runner::args();
+    if let [command, rest @ ..] = args.as_slice() {
+        if command == "plot" {
+            return plot_curve(rest);
+        }
+        if command == "fuzz" {
+            return fuzz_decoders(rest);
+        }
+    }
+
+    // instructors: `cargo run --release --features solutions -- walkthrough q5` replays a reference solution
+    #[cfg(feature = "solutions")]
+    {
+        let args = runner::args();
+        if let [command, question] = args.as_slice() {
+            if command == "walkthrough" {
+                return walkthrough::walkthrough(question);
+            }
+        }
+    }
+
+    let runner = Runner::start(env!("CARGO_PKG_NAME"), hints::CATALOGS);
+
     // We initialize a random number generator to sample random field and group elements
-    let mut rng = ChaChaRng::from_seed(*b"Advanced cryptography training 1");
+    let mut rng = seed::rng();
     // Now we can sample an element of type `T` by calling `T::rand(&mut rng)`
 
-    // Let us experiment a bit with a small field with 89 elements
-    #[derive(MontConfig)]
-    #[modulus = "89"]
-    #[generator = "3"] // we need to provide a generator of F*, the multiplicative group of the field
-    pub struct FqConfig;
-    pub type F = Fp64<MontBackend<FqConfig, 1>>;
-    // `F` is now the type of an element in the field F_89
+    // Let us experiment a bit with a small field with 89 elements, `F`, defined at the top of this file
+    // `F` is the type of an element in the field F_89
     // We can define field elements from integers with `F::from(i)`
     let a = F::from(5);
     let b = F::from(27);
@@ -32,30 +78,31 @@ fn main() {
     assert_eq!(F::from(0xff), F::from(77)); // 255 = 77 mod 89
 
     // One can also compute a^n in the field; n must be converted into a "big integer" over a 64-bit limb
-    assert_eq!(a.pow(BigInt::<1>::from(7 as u32)), F::from(72)); // 5^7 = 72 mod 89
-    
+    assert_eq!(a.pow(BigInt::<1>::from(7u32)), F::from(72)); // 5^7 = 72 mod 89
+
     // The multiplicative identity of `F` can be obtained with `F::one()`
     assert_eq!(F::one(), F::from(1));
-    
+
     // We can inverse field elements; NB: we must unwrap as it may return an Error if called on zero
     let c = F::rand(&mut rng);
     let d = c.inverse().unwrap();
     assert_eq!(c * d, F::one());
-    
+
     // The size of `F` can be obtained with `F::MODULUS`
     let p = F::MODULUS;
-    
+
     // one can check Fermat's little theorem: for a in F, one has a^p = a mod p
     let a = F::rand(&mut rng);
     assert_eq!(a.pow(p), a);
 
     // Q1: find all generators of the multiplicative group of F_89
     // put them in vector `gen`
+    runner.question("Q1");
     let mut gen_list: Vec<i32> = Vec::new();
     for i in 2..89 {
-        // 1 ne peut pas être un générateur donc on commence à 2
+        // 1 cannot be a generator, so we start at 2
         let g = F::from(i);
-        let f = |x: i32| g.pow(BigInt::<1>::from(x as u32)); 
+        let f = |x: i32| g.pow(BigInt::<1>::from(x as u32));
         let all_generated: HashSet<_> = (1..89).map(f).collect();
         if all_generated.len() == 88 {
             gen_list.push(i)
@@ -66,8 +113,9 @@ fn main() {
         gen_list.len(),
         gen_list
     );
-    // uncomment the following line to check your solution (it shouldn't panic for the correct solution)
-    assert_eq!(gen_list.iter().sum::<i32>(), 1780);
+    // the answers are checked against their digests (see crate `canonical`), which do not give them away
+    let generators: HashSet<i32> = gen_list.iter().copied().collect();
+    canonical::assert_digest("ff-ec/Q1", &generators, Q1_DIGEST);
 
     // The crate ark-secp256k1 implements the secp256k1 elliptic curve used in Bitcoin
     // We bring four types from this crate into scope: `Fq`, `Fr`, `Affine` and `Projective`
@@ -78,10 +126,10 @@ fn main() {
     // The point at infinity (i.e., the zero of the group law) can be obtained with `Affine::zero()` or `Projective::zero()`
     // An affine point `g` is encoded as a struct with three fields, its coordinates `x` and `y` and a boolean `infinity`
     let zero_aff = Affine::zero();
-    
+
     // Obviously, field `infinity` is set to `true` for the point at infinity
     assert!(zero_aff.infinity);
-    
+
     // Let's take a look at a random point
     let g_aff = Affine::rand(&mut rng);
     println!("g_aff.x = {}", g_aff.x);
@@ -89,34 +137,41 @@ fn main() {
     println!("g_aff.infinity = {}\n", g_aff.infinity);
 
     // Q2: check that the coordinates of point `g_aff` satisfy the curve equation y^2 = x^3 + 7
-    // Compute the left-hand side `lhs` and the right-hand side `rhs` of this equation and check that they are equal
-    let lhs = 0;
-    let rhs = 0;
+    // Write `affine_equation` which computes the left-hand side `lhs` and the right-hand side `rhs` of this equation
+    runner.question("Q2");
+    let (lhs, rhs) = affine_equation(&g_aff);
     assert_eq!(lhs, rhs);
+    canonical::assert_same("ff-ec/Q2", &(lhs, rhs), &q2(&g_aff));
 
     // We can convert from affine to projective representations using `into_group()`
     // and vice-versa with `into_affine()`
     let zero_proj = zero_aff.into_group();
-    
+
     // The point at infinity in jacobian projective coordinates is [1 : 1 : 0]
     assert!(zero_proj.x.is_one());
     assert!(zero_proj.y.is_one());
     assert!(zero_proj.z.is_zero());
 
     let g_proj = Projective::rand(&mut rng);
-    
+
     // Q3: check that the coordinates of point `g_proj` satisfy the curve equation in Jacobian projective coordinates Y^2 = X^3 + 7*Z^6
-    // Compute the left-hand side `lhs` and the right-hand side `rhs` of this equation and check that they are equal
-    let lhs = 0;
-    let rhs = 0;
+    // Write `jacobian_equation` which computes the left-hand side `lhs` and the right-hand side `rhs` of this equation
+    runner.question("Q3");
+    let (lhs, rhs) = jacobian_equation(&g_proj);
     assert_eq!(lhs, rhs);
+    canonical::assert_same("ff-ec/Q3", &(lhs, rhs), &q3(&g_proj));
 
     // One can check that a field element x is a square with a.legendre().is_qr()
     // Q4: Is there a point on secp256k1 with x-coordinate 0? 1? and 5?
+    // Write `is_x_coordinate` which tells whether x is the x-coordinate of a point of the curve
+    runner.question("Q4");
+    let on_curve = [0, 1, 5].map(|x| is_x_coordinate(Fq::from(x)));
+    canonical::assert_digest("ff-ec/Q4", &on_curve, Q4_DIGEST);
+    properties::square_roots();
 
     // The "standard" generator G (that everyone uses in cryptographic schemes) of the curve can be obtained with Affine::generator() or Projective::generator()
     let gen = Affine::generator();
-    
+
     // We can add points with +
     // We can also compute scalar multiplication with method `mul` which takes an element from the scalar field as argument
     // The result is in projective form even if applied to an affine point, if we want the affine form we must convert back to affine explicitly
@@ -124,12 +179,719 @@ fn main() {
     let d = (gen.mul(Fr::from(2)) + gen.mul(Fr::from(2))).into_affine();
     assert_eq!(c, d);
 
-    // Q5: compute the affine coordinates of 2G using the doubling formulas in the slides
-    let x = 0;
-    let y = 0;
-    // Check they are the same as the one you get by computing `gen.mul(Fr::from(2)` by uncommenting the following two lines
-    // assert_eq!(x, gen.mul(Fr::from(2)).into_affine().x);
-    // assert_eq!(y, gen.mul(Fr::from(2)).into_affine().y);
+    // Q5: write `double_affine` which computes the affine coordinates of 2P using the doubling formulas in the slides
+    // (2P is the point at infinity when P is)
+    runner.question("Q5");
+    let double = double_affine(&gen);
+    canonical::assert_digest("ff-ec/Q5", &(double.x, double.y), Q5_DIGEST);
+    properties::doubling();
+
+    // Some answers are too expensive to recompute in Rust: they were computed with SageMath
+    // and saved as fixtures (see the `fixtures` directory and the script that generated them)
+    let fixtures: Fixtures = include_str!("../fixtures/curves.fixtures").parse().unwrap();
+
+    // Q6: write `count_points` which counts the points of y^2 = x^3 + 7 over a small prime field by brute force
+    // (don't forget the point at infinity)
+    runner.question("Q6");
+    let toy_p: u64 = fixtures.get("toy", "p");
+    assert_eq!(count_points(toy_p), fixtures.get::<u64>("toy", "order"));
+
+    // Brute force is hopeless for secp256k1: Sage counts its points with the Schoof-Elkies-Atkin algorithm
+    // The number of points is #E = p + 1 - t, where |t| <= 2 sqrt(p) is the trace of Frobenius (Hasse bound)
+    let p: BigUint = fixtures.get("secp256k1", "p");
+    let order: BigUint = fixtures.get("secp256k1", "order");
+    assert_eq!(p, BigUint::from(Fq::MODULUS));
+    assert_eq!(order, BigUint::from(Fr::MODULUS));
+    assert_eq!(&p + 1u32 - &order, fixtures.get("secp256k1", "trace"));
+
+    // The quadratic twist y^2 = x^3 + 7c^3 (c a non-square) holds the x-coordinates which are not on secp256k1
+    // Q7: write `twist_order` which computes its number of points from p and #E
+    runner.question("Q7");
+    let twist = twist_order(&p, &order);
+    assert_eq!(twist, fixtures.get("secp256k1", "twist_order"));
+    let factors: Vec<BigUint> = fixtures.get_list("secp256k1", "twist_order_factors");
+    assert_eq!(factors.iter().product::<BigUint>(), twist);
+    println!(
+        "The twist of secp256k1 has order {}, whose largest prime factor has {} bits",
+        twist,
+        factors.iter().max().unwrap().bits()
+    );
+    // Q8: an implementation computes x-only scalar multiplications and forgets to check that x is on the curve:
+    // what does an attacker learn by sending x-coordinates of points of small order on the twist?
+    runner.question("Q8");
+
+    // So far `.inverse()` was a black box: let us open it
+    // Q9: write `extended_gcd` which returns (g, u, v) such that ua + vb = g = gcd(a, b)
+    runner.question("Q9");
+    let (g, u, v) = extended_gcd(&Integer::from(240), &Integer::from(46));
+    assert_eq!(g, Integer::from(2));
+    assert_eq!(u * 240 + v * 46, g);
+    let (g, u, _) = extended_gcd(&Integer::from(89), &Integer::from(0));
+    assert_eq!(g, Integer::from(89));
+    assert_eq!(u, Integer::one());
+
+    // Q10: derive `inverse_mod` from it: if ua + vp = 1 then u is the inverse of a mod p
+    runner.question("Q10");
+    for _ in 0..5000 {
+        let a = F::rand(&mut rng);
+        let expected = a.inverse().map(|inv| BigUint::from(inv.into_bigint()));
+        assert_eq!(inverse_mod(&a.into(), &F::MODULUS.into()), expected);
+        let a = Fq::rand(&mut rng);
+        let expected = a.inverse().map(|inv| BigUint::from(inv.into_bigint()));
+        assert_eq!(inverse_mod(&a.into(), &Fq::MODULUS.into()), expected);
+    }
+    assert_eq!(inverse_mod(&BigUint::from(0u32), &Fq::MODULUS.into()), None);
+    assert_eq!(
+        inverse_mod(&BigUint::from(6u32), &BigUint::from(9u32)),
+        None
+    );
+    properties::inversion();
+    // Q11: the inverse is also a^(p-2) by Fermat's little theorem: how many multiplications does it take?
+    // How many division steps does the extended Euclidean algorithm take at most (see Lamé's theorem)?
+    runner.question("Q11");
+
+    // Take a look at module `encoding`
+    // Q12: write `decode_point` and `decode_scalar` in module `decoders`, which must reject every non-canonical encoding
+    // The fixtures hold valid encodings and mutants of them, the report lists those your decoders get wrong
+    runner.question("Q12");
+    let encodings: Fixtures = include_str!("../fixtures/encodings.fixtures")
+        .parse()
+        .unwrap();
+    let mut wrong = vec![];
+    for name in encodings.sections() {
+        let bytes = from_hex(encodings.raw(name, "hex"));
+        let valid: bool = encodings.get(name, "valid");
+        let reencoded = match encodings.raw(name, "kind") {
+            "point" => decode_point(&bytes).map(|point| match bytes.len() {
+                65 => encode_point_uncompressed(&point),
+                _ => encode_point_compressed(&point),
+            }),
+            _ => decode_scalar(&bytes).map(|s| encode_scalar(&s)),
+        };
+        let accepted = reencoded.is_some();
+        if valid && accepted {
+            assert_eq!(reencoded.unwrap(), bytes, "{} does not round-trip", name);
+        }
+        if accepted != valid {
+            println!(
+                "{}: {}",
+                name,
+                if valid {
+                    "valid but rejected"
+                } else {
+                    "mutant slipped through"
+                }
+            );
+            wrong.push(name);
+        }
+    }
+    assert!(
+        wrong.is_empty(),
+        "{} encodings out of {} are wrongly decoded",
+        wrong.len(),
+        encodings.sections().count()
+    );
+    properties::decoding();
+    // Q13: arkworks has its own compressed encoding, with flags for the infinity and the sign of y:
+    // feed the mutants of `Affine::serialize_compressed(&g_aff)` to `Affine::deserialize_compressed` and compare
+    runner.question("Q13");
+
+    // Take a look at module `exponentiation`
+    // Q14: write `pow_window`, the fixed-window exponentiation with a window of W bits
+    // The exponent is a list of 64-bit limbs, least significant first, as in `Field::pow`
+    runner.question("Q14");
+    let exponent = Fq::rand(&mut rng).into_bigint();
+    let a = Fq::rand(&mut rng);
+    let expected = a.pow(exponent);
+    assert_eq!(pow_window::<1, _>(a, exponent.as_ref()), expected);
+    assert_eq!(pow_window::<2, _>(a, exponent.as_ref()), expected);
+    assert_eq!(pow_window::<3, _>(a, exponent.as_ref()), expected);
+    assert_eq!(pow_window::<4, _>(a, exponent.as_ref()), expected);
+    assert_eq!(pow_window::<5, _>(a, exponent.as_ref()), expected);
+    assert_eq!(pow_window::<6, _>(a, exponent.as_ref()), expected);
+    assert_eq!(pow_window::<7, _>(a, exponent.as_ref()), expected);
+    assert_eq!(pow_window::<4, _>(a, &[]), Fq::one());
+    assert_eq!(pow_window::<4, _>(a, &[0, 0]), Fq::one());
+    assert_eq!(pow_window::<5, _>(Fq::zero(), &[3]), Fq::zero());
+    let b = F::rand(&mut rng);
+    assert_eq!(pow_window::<3, _>(b, &[87]), b.pow([87]));
+    assert_eq!(pow_window::<3, _>(b, &[u64::MAX, 1]), b.pow([u64::MAX, 1]));
+    properties::exponentiation();
+
+    // Q15: nothing to write, `pow_window` on 256-bit exponents for W = 2..6
+    // Which W minimizes `window_cost`? Is it the fastest one? The timings go to the progress report
+    runner.question("Q15");
+    let bases: Vec<Fq> = (0..2000).map(|_| Fq::rand(&mut rng)).collect();
+    let exponents: Vec<[u64; 4]> = (0..2000)
+        .map(|_| Fq::rand(&mut rng).into_bigint().0)
+        .collect();
+    let windows: [Pow; 5] = [
+        pow_window::<2, Fq>,
+        pow_window::<3, Fq>,
+        pow_window::<4, Fq>,
+        pow_window::<5, Fq>,
+        pow_window::<6, Fq>,
+    ];
+    let timings: Vec<(usize, std::time::Duration)> = WINDOWS
+        .iter()
+        .zip(windows)
+        .map(|(w, pow)| (*w, time_pow(pow, &bases, &exponents)))
+        .collect();
+    let square_and_multiply = time_pow(|a, exponent| a.pow(exponent), &bases, &exponents);
+    println!("W  multiplications  time");
+    for (w, time) in &timings {
+        println!("{}  {:>15}  {:.2?}", w, window_cost(*w, 256), time);
+    }
+    println!(
+        "square-and-multiply of arkworks: {:.2?}",
+        square_and_multiply
+    );
+    record_pow_windows(&timings).expect("cannot write the progress report");
+
+    // The slides use affine (x, y) and Jacobian (X : Y : Z) coordinates: how much faster are the latter?
+    // Q16: before measuring, predict the cost of an affine addition, a Jacobian addition, a mixed addition
+    // (Jacobian + affine), an affine and a Jacobian doubling, in multiplications M, squarings S and inversions I
+    // Assuming I = 100M and S = 0.8M, which ratios to the Jacobian addition do you expect?
+    runner.question("Q16");
+    // Q17: run `cargo bench --bench coordinates` and compare with your predictions
+    // Why is the mixed addition cheaper? What does a scalar multiplication gain from Jacobian coordinates,
+    // and why does arkworks still return affine points from `into_affine`?
+    runner.question("Q17");
+
+    // Take a look at module `generators`
+    // Q18: write `generators_parallel`, which returns the generators of F_p* among the candidates, for p = 2^31 - 1,
+    // like `generators_sequential`, but splits the candidates into chunks searched by `threads` threads
+    // (`std::thread::scope`), then concatenates their results in order
+    runner.question("Q18");
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    for candidates in [0..0, 0..3, 2..1000, LARGE_PRIME - 100..LARGE_PRIME + 100] {
+        for threads in [1, 3, 8] {
+            assert_eq!(
+                generators_parallel(candidates.clone(), threads),
+                generators_sequential(candidates.clone())
+            );
+        }
+    }
+    let start = Instant::now();
+    let expected = generators_sequential(SEARCH);
+    let sequential = start.elapsed();
+    let start = Instant::now();
+    let found = generators_parallel(SEARCH, threads);
+    let parallel = start.elapsed();
+    assert_eq!(found, expected);
+    println!(
+        "{} generators of F_p* below 2^20: {:.2?} sequential, {:.2?} on {} threads",
+        found.len(),
+        sequential,
+        parallel,
+        threads
+    );
+    assert!(
+        parallel <= time_budget(sequential, threads),
+        "the parallel search exceeds its budget of {:.2?}",
+        time_budget(sequential, threads)
+    );
+
+    // Q19: write `multiplicative_order`, which computes the order of g in F_p* from the factorization of p - 1
+    // (see module `generators`), then solve Q1 again: the generators of F_89* are the elements of order 88
+    runner.question("Q19");
+    let generators_89: HashSet<i32> = (1..89)
+        .filter(|g| multiplicative_order(*g as u64, 89, &FACTORS_89) == 88)
+        .collect();
+    assert_eq!(generators_89, generators);
+    for (p, factors) in [(89, &FACTORS_89[..]), (65537, &FACTORS_65537[..])] {
+        for g in 1..p {
+            assert_eq!(multiplicative_order(g, p, factors), order_brute_force(g, p));
+        }
+    }
+    let start = Instant::now();
+    for _ in 0..1000 {
+        let g = rng.gen_range(1..MERSENNE_61);
+        let order = multiplicative_order(g, MERSENNE_61, &FACTORS_MERSENNE_61);
+        assert!((MERSENNE_61 - 1).is_multiple_of(order));
+        assert_eq!(pow_mod(g, order, MERSENNE_61), 1);
+        for (q, _) in FACTORS_MERSENNE_61 {
+            assert!(!order.is_multiple_of(q) || pow_mod(g, order / q, MERSENNE_61) != 1);
+        }
+    }
+    assert_eq!(
+        multiplicative_order(1, MERSENNE_61, &FACTORS_MERSENNE_61),
+        1
+    );
+    assert_eq!(
+        multiplicative_order(MERSENNE_61 - 1, MERSENNE_61, &FACTORS_MERSENNE_61),
+        2
+    );
+    println!(
+        "Orders of 1000 random elements of F_p*, p = 2^61 - 1: {:.2?}",
+        start.elapsed()
+    );
+
+    // Back to F_89, with the equation of secp256k1: take a look at module `toy_curve`
+    // Q20: write `toy_points` which lists all the affine points of y^2 = x^3 + 7 over F_89, by brute force
+    // With the point at infinity, they make the group E(F_89): how many elements does it have?
+    runner.question("Q20");
+    let points = toy_points();
+    assert!(points.iter().all(|point| point.is_on_curve()));
+    let distinct: HashSet<(F, F)> = points.iter().map(|point| (point.x, point.y)).collect();
+    assert_eq!(distinct.len(), points.len());
+    assert!(points.contains(&ToyAffine::generator()));
+    let group_order = points.len() as u64 + 1;
+    assert_eq!(group_order, configured_order());
+    println!("y^2 = x^3 + 7 over F_89 has {} points", group_order);
+
+    // Q21: write `point_order` which returns the order of a point: the smallest k > 0 with kP = 0
+    // Lagrange: the order of every point divides the order of the group
+    runner.question("Q21");
+    assert_eq!(point_order(&ToyAffine::zero()), 1);
+    let mut orders: Vec<(u64, usize)> = vec![];
+    for point in &points {
+        let order = point_order(point);
+        assert!(group_order.is_multiple_of(order));
+        assert!(point.mul_bigint([order]).is_zero());
+        match orders.iter_mut().find(|(o, _)| *o == order) {
+            Some((_, count)) => *count += 1,
+            None => orders.push((order, 1)),
+        }
+    }
+    orders.sort();
+    println!("(order, number of points): {:?}", orders);
+    // the group is cyclic: some point generates it
+    assert!(orders.iter().any(|(order, _)| *order == group_order));
+
+    // Q22: write `cofactor` which splits the order of the group into r h, r its largest prime factor
+    // The subgroup of order r is where arkworks works, h P lands in it for every point P
+    runner.question("Q22");
+    let (r, h) = cofactor(group_order);
+    assert_eq!(r * h, group_order);
+    assert_eq!(r, ToyFr::MODULUS.0[0]);
+    assert_eq!(h, configured_cofactor());
+    for point in &points {
+        let cleared = point.clear_cofactor();
+        assert_eq!(cleared, point.mul_bigint([h]).into_affine());
+        assert!(r.is_multiple_of(point_order(&cleared)));
+    }
+    assert_eq!(point_order(&ToyAffine::generator()), r);
+    assert_eq!(cofactor(101), (101, 1));
+    assert_eq!(cofactor(96), (3, 32));
+
+    // Q23: secp256k1 has a cofactor of 1. What could go wrong with h > 1 if a protocol took any point of the
+    // curve from an attacker? How do Curve25519 (h = 8) and its protocols cope with it?
+    runner.question("Q23");
+
+    // Q5 doubled one point; the group law has more cases, with the point at infinity O written `None`
+    // Q24: write `affine_add` and `affine_double` from the chord-and-tangent formulas of the slides
+    // - O + Q = Q, P + O = P, P + (-P) = O
+    // - P + P is a doubling: the tangent has slope 3x^2 / 2y (there is no point with y = 0 on secp256k1)
+    // - otherwise the chord through P and Q has slope (y_Q - y_P) / (x_Q - x_P)
+    runner.question("Q24");
+    let g = Affine::generator();
+    assert_eq!(affine_double(None), None);
+    assert_eq!(affine_add(None, None), None);
+    assert_eq!(affine_add(coordinates(&g), None), coordinates(&g));
+    assert_eq!(affine_add(coordinates(&g), coordinates(&-g)), None);
+    assert_eq!(
+        affine_double(coordinates(&g)),
+        coordinates(&(g + g).into_affine())
+    );
+    properties::group_law();
+
+    // Q6 counted the points of y^2 = x^3 + 7 over F_89 with p^2 tries: a square root has at most two
+    // values, and Euler's criterion tells how many without looking for them
+    // Q25: write `legendre_symbol` which returns 1, 0 or -1 as a is a non-zero square, zero or not a square
+    // mod p, then `count_points_legendre` which counts the points of y^2 = x^3 + ax + b with p Legendre symbols
+    runner.question("Q25");
+    assert_eq!(legendre_symbol(0, 89), 0);
+    assert_eq!(legendre_symbol(3, 89), -1); // a generator is not a square
+    assert_eq!(legendre_symbol(9, 89), 1);
+    assert_eq!(
+        count_points_legendre(0, 7, toy_p),
+        fixtures.get::<u64>("toy", "order")
+    );
+    let curves: Vec<[u64; 4]> = {
+        let column = |key| fixtures.get_list::<u64>("small_curves", key);
+        let (ps, a, b, orders) = (column("p"), column("a"), column("b"), column("order"));
+        (0..ps.len())
+            .map(|i| [ps[i], a[i], b[i], orders[i]])
+            .collect()
+    };
+    for &[p, a, b, order] in &curves {
+        assert_eq!(
+            count_points_legendre(a, b, p),
+            order,
+            "y^2 = x^3 + {}x + {} over F_{}",
+            a,
+            b,
+            p
+        );
+    }
+
+    // Q26: write `trace_of_frobenius`, t = p + 1 - #E, and check the Hasse bound |t| <= 2 sqrt(p) on random curves
+    runner.question("Q26");
+    let mut worst: f64 = 0.0;
+    for &[p, a, b, order] in &curves {
+        let t = trace_of_frobenius(a, b, p);
+        assert_eq!(t, (p + 1) as i64 - order as i64);
+        assert!(t * t <= 4 * p as i64);
+    }
+    for _ in 0..200 {
+        let p = curves[rng.gen_range(0..4)][0];
+        let (a, b) = (rng.gen_range(0..p), rng.gen_range(0..p));
+        // 4a^3 + 27b^2 = 0 is a singular curve, for which the bound does not hold
+        if (4 * pow_mod(a, 3, p) + 27 * pow_mod(b, 2, p)).is_multiple_of(p) {
+            continue;
+        }
+        let t = trace_of_frobenius(a, b, p);
+        assert!(
+            t * t <= 4 * p as i64,
+            "y^2 = x^3 + {}x + {} over F_{}",
+            a,
+            b,
+            p
+        );
+        worst = worst.max(t.unsigned_abs() as f64 / (2.0 * (p as f64).sqrt()));
+    }
+    println!(
+        "largest |t| / 2 sqrt(p) over 200 random curves: {:.3}",
+        worst
+    );
+
+    // Q27: run `cargo bench --bench point_counting`: how long would `count_points_legendre` take on
+    // secp256k1? Two of the small curves have t = 0, why?
+    // Schoof's algorithm finds t mod l from the l-torsion for small primes l: how many primes l are needed
+    // for a 256-bit p?
+    runner.question("Q27");
+
+    // Q12 and Q13 met two encodings of the same points: take a look at module `serialization` for the one of
+    // arkworks, which the proofs and keys of the later chapters are written in
+    // Q28: write `field_to_bytes` and `field_from_bytes` in module `decoders`, the arkworks encoding of a prime field element
+    // `field_from_bytes` rejects a wrong length and any integer >= p, like `deserialize_compressed`
+    runner.question("Q28");
+    for _ in 0..1000 {
+        let (x, s) = (Fq::rand(&mut rng), Fr::rand(&mut rng));
+        canonical::assert_same("ff-ec/Q28", &field_to_bytes(&x), &ark_bytes(&x, true));
+        canonical::assert_same("ff-ec/Q28", &field_to_bytes(&s), &ark_bytes(&s, false));
+        assert_eq!(field_from_bytes::<Fq>(&field_to_bytes(&x)), Some(x));
+        assert_eq!(field_from_bytes::<Fr>(&field_to_bytes(&s)), Some(s));
+    }
+    let modulus = Fq::MODULUS.to_bytes_le();
+    assert_eq!(field_from_bytes::<Fq>(&modulus), None);
+    assert!(Fq::deserialize_compressed(&modulus[..]).is_err());
+    assert_eq!(field_from_bytes::<Fq>(&modulus[..31]), None);
+    let padded = [field_to_bytes(&Fq::one()), vec![0]].concat();
+    assert_eq!(field_from_bytes::<Fq>(&padded), None);
+    let vectors = (
+        field_to_bytes(&Fq::one()),
+        field_to_bytes(&-Fr::one()),
+        field_to_bytes(&Fq::from(2).pow([255])),
+    );
+    canonical::assert_digest("ff-ec/Q28", &vectors, Q28_DIGEST);
+
+    // Q29: write `point_to_bytes` in module `decoders`, the arkworks encoding of a point of secp256k1, compressed or not
+    runner.question("Q29");
+    let g = Affine::generator();
+    let special = [g, -g, Affine::zero()];
+    let points: Vec<Affine> = special
+        .into_iter()
+        .chain((0..1000).map(|_| Affine::rand(&mut rng)))
+        .collect();
+    for point in &points {
+        for compress in [true, false] {
+            let bytes = point_to_bytes(point, compress);
+            canonical::assert_same("ff-ec/Q29", &bytes, &ark_bytes(point, compress));
+            let decoded = match compress {
+                true => Affine::deserialize_compressed(&bytes[..]),
+                false => Affine::deserialize_uncompressed(&bytes[..]),
+            };
+            assert_eq!(decoded.ok(), Some(*point));
+        }
+    }
+    let vectors: Vec<Vec<u8>> = [true, false]
+        .iter()
+        .flat_map(|compress| special.iter().map(|point| point_to_bytes(point, *compress)))
+        .collect();
+    canonical::assert_digest("ff-ec/Q29", &vectors, Q29_DIGEST);
+    println!("G in SEC1:     {}", to_hex(&encode_point_compressed(&g)));
+    println!("G in arkworks: {}", to_hex(&point_to_bytes(&g, true)));
+
+    // Q30: BLS12-381 has a 381-bit p: how many bytes does arkworks take for a compressed point, and where
+    // do the flags go? Why "y > -y" and not the parity of y as in SEC1?
+    // What would a SEC1 decoder make of the bytes of arkworks, and what goes wrong if a deserializer accepts
+    // both flags at once?
+    runner.question("Q30");
+
+    // Back to the arkworks encoding of Q29: `deserialize_compressed` reads what it needs from a reader and
+    // leaves the rest, and trusts the flag of the point at infinity whatever the coordinates
+    // Q31: write `strict_point_from_bytes` in module `decoders`, which accepts exactly the bytes `point_to_bytes`
+    // writes, then run `cargo run --release -- fuzz`: every decoder of the chapter must come out without a finding
+    runner.question("Q31");
+    for point in &points {
+        for compress in [true, false] {
+            let bytes = ark_bytes(point, compress);
+            assert_eq!(strict_point_from_bytes(&bytes, compress), Some(*point));
+            let trailing = [&bytes[..], &[0]].concat();
+            assert!(Affine::deserialize_with_mode(
+                &trailing[..],
+                compress_mode(compress),
+                Validate::Yes
+            )
+            .is_ok());
+            assert_eq!(strict_point_from_bytes(&trailing, compress), None);
+            assert_eq!(
+                strict_point_from_bytes(&bytes[..bytes.len() - 1], compress),
+                None
+            );
+        }
+    }
+    // the flag of the point at infinity on a non-zero x
+    let mut infinity = ark_bytes(&Affine::zero(), true);
+    infinity[0] = 1;
+    assert_eq!(
+        Affine::deserialize_compressed(&infinity[..]).ok(),
+        Some(Affine::zero())
+    );
+    assert_eq!(strict_point_from_bytes(&infinity, true), None);
+    let mut both_flags = ark_bytes(&g, true);
+    *both_flags.last_mut().unwrap() |= 0xc0;
+    assert_eq!(strict_point_from_bytes(&both_flags, true), None);
 
     println!("Good job! 🏴‍☠️");
+    runner.finish();
+}
+
+fn legendre_symbol(a: u64, p: u64) -> i64 {
+    // SOLUTION-BEGIN
+    // Euler's criterion: a^((p - 1) / 2) is 1 for the non-zero squares, -1 = p - 1 for the others
+    match pow_mod(a, (p - 1) / 2, p) {
+        0 => 0,
+        1 => 1,
+        _ => -1,
+    }
+    // SOLUTION-END
+}
+
+fn count_points_legendre(a: u64, b: u64, p: u64) -> u64 {
+    // SOLUTION-BEGIN
+    // each x gives 1 + (x^3 + ax + b | p) points, plus the point at infinity
+    let sum: i64 = (0..p)
+        .map(|x| legendre_symbol(mul_mod(mul_mod(x, x, p) + a, x, p) + b, p))
+        .sum();
+    (p as i64 + 1 + sum) as u64
+    // SOLUTION-END
+}
+
+fn trace_of_frobenius(a: u64, b: u64, p: u64) -> i64 {
+    // SOLUTION-BEGIN
+    (p + 1) as i64 - count_points_legendre(a, b, p) as i64
+    // SOLUTION-END
+}
+
+fn affine_add(p: Option<(Fq, Fq)>, q: Option<(Fq, Fq)>) -> Option<(Fq, Fq)> {
+    // SOLUTION-BEGIN
+    let ((x1, y1), (x2, y2)) = match (p, q) {
+        (None, _) => return q,
+        (_, None) => return p,
+        (Some(p), Some(q)) => (p, q),
+    };
+    if x1 == x2 {
+        return match y1 == y2 {
+            true => affine_double(p),
+            false => None, // Q = -P
+        };
+    }
+    let lambda = (y2 - y1) / (x2 - x1);
+    let x3 = lambda.square() - x1 - x2;
+    Some((x3, lambda * (x1 - x3) - y1))
+    // SOLUTION-END
+}
+
+fn affine_double(p: Option<(Fq, Fq)>) -> Option<(Fq, Fq)> {
+    // SOLUTION-BEGIN
+    let (x, y) = p?;
+    let lambda = Fq::from(3) * x.square() / y.double();
+    let x3 = lambda.square() - x.double();
+    Some((x3, lambda * (x - x3) - y))
+    // SOLUTION-END
+}
+
+fn toy_points() -> Vec<ToyAffine> {
+    // SOLUTION-BEGIN
+    let mut points = vec![];
+    for x in 0..89 {
+        for y in 0..89 {
+            let (x, y) = (F::from(x), F::from(y));
+            if y.square() == x.square() * x + F::from(7) {
+                // `new` would also check that the point is in the subgroup of order r
+                points.push(ToyAffine::new_unchecked(x, y));
+            }
+        }
+    }
+    points
+    // SOLUTION-END
+}
+
+fn point_order(point: &ToyAffine) -> u64 {
+    // SOLUTION-BEGIN
+    let mut multiple = point.into_group();
+    let mut order = 1;
+    while !multiple.is_zero() {
+        multiple += point;
+        order += 1;
+    }
+    order
+    // SOLUTION-END
+}
+
+fn cofactor(order: u64) -> (u64, u64) {
+    // SOLUTION-BEGIN
+    let (mut n, mut largest, mut d) = (order, 1, 2);
+    while d * d <= n {
+        while n.is_multiple_of(d) {
+            largest = d;
+            n /= d;
+        }
+        d += 1;
+    }
+    if n > 1 {
+        largest = largest.max(n);
+    }
+    (largest, order / largest)
+    // SOLUTION-END
+}
+
+fn generators_parallel(candidates: Range<u64>, threads: usize) -> Vec<u64> {
+    // SOLUTION-BEGIN
+    let length = candidates.end.saturating_sub(candidates.start);
+    let chunk = length.div_ceil(threads.max(1) as u64).max(1);
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = (candidates.start..candidates.end)
+            .step_by(chunk as usize)
+            .map(|start| {
+                let end = (start + chunk).min(candidates.end);
+                scope.spawn(move || generators_sequential(start..end))
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect()
+    })
+    // SOLUTION-END
+}
+
+fn multiplicative_order(g: u64, p: u64, factors: &[(u64, u32)]) -> u64 {
+    // SOLUTION-BEGIN
+    let mut order = p - 1;
+    for (q, exponent) in factors {
+        for _ in 0..*exponent {
+            if pow_mod(g, order / q, p) != 1 {
+                break;
+            }
+            order /= q;
+        }
+    }
+    order
+    // SOLUTION-END
+}
+
+fn count_points(p: u64) -> u64 {
+    // SOLUTION-BEGIN
+    let mut count = 1;
+    for x in 0..p {
+        let rhs = (x * x % p * x + 7) % p;
+        count += (0..p).filter(|y| y * y % p == rhs).count() as u64;
+    }
+    count
+    // SOLUTION-END
+}
+
+fn twist_order(p: &BigUint, order: &BigUint) -> BigUint {
+    // SOLUTION-BEGIN
+    // #E' = p + 1 + t = 2(p + 1) - #E
+    (p + 1u32) * 2u32 - order
+    // SOLUTION-END
+}
+
+fn extended_gcd(a: &Integer, b: &Integer) -> (Integer, Integer, Integer) {
+    // SOLUTION-BEGIN
+    // invariants: u0 a + v0 b = r0 and u1 a + v1 b = r1
+    let (mut r0, mut u0, mut v0) = (a.clone(), Integer::one(), Integer::zero());
+    let (mut r1, mut u1, mut v1) = (b.clone(), Integer::zero(), Integer::one());
+    while !r1.is_zero() {
+        let q = &r0 / &r1;
+        let r2 = &r0 - &q * &r1;
+        let u2 = &u0 - &q * &u1;
+        let v2 = &v0 - &q * &v1;
+        (r0, u0, v0) = (r1, u1, v1);
+        (r1, u1, v1) = (r2, u2, v2);
+    }
+    (r0, u0, v0)
+    // SOLUTION-END
+}
+
+fn inverse_mod(a: &BigUint, p: &BigUint) -> Option<BigUint> {
+    // SOLUTION-BEGIN
+    let p = Integer::from(p.clone());
+    let (g, u, _) = extended_gcd(&Integer::from(a.clone()), &p);
+    if !g.is_one() {
+        return None;
+    }
+    // u may be negative
+    (((u % &p) + &p) % &p).to_biguint()
+    // SOLUTION-END
+}
+
+fn affine_equation(point: &Affine) -> (Fq, Fq) {
+    // SOLUTION-BEGIN
+    (point.y.square(), point.x.square() * point.x + Fq::from(7))
+    // SOLUTION-END
+}
+
+fn jacobian_equation(point: &Projective) -> (Fq, Fq) {
+    // SOLUTION-BEGIN
+    let z6 = point.z.square() * point.z.square() * point.z.square();
+    (
+        point.y.square(),
+        point.x.square() * point.x + Fq::from(7) * z6,
+    )
+    // SOLUTION-END
+}
+
+fn is_x_coordinate(x: Fq) -> bool {
+    // SOLUTION-BEGIN
+    (x.square() * x + Fq::from(7)).legendre().is_qr()
+    // SOLUTION-END
+}
+
+fn double_affine(point: &Affine) -> Affine {
+    // SOLUTION-BEGIN
+    // no point of secp256k1 has y = 0: only the point at infinity doubles to infinity
+    if point.is_zero() {
+        return *point;
+    }
+    let lambda = Fq::from(3) * point.x.square() / point.y.double();
+    let x = lambda.square() - point.x.double();
+    let y = lambda * (point.x - x) - point.y;
+    Affine::new(x, y)
+    // SOLUTION-END
+}
+
+fn pow_window<const W: usize, F: Field>(base: F, exponent: &[u64]) -> F {
+    // SOLUTION-BEGIN
+    let mut table = vec![F::one(); 1 << W];
+    for i in 1..table.len() {
+        table[i] = table[i - 1] * base;
+    }
+    let bits = 64 * exponent.len();
+    let bit = |i: usize| (i < bits && (exponent[i / 64] >> (i % 64)) & 1 == 1) as usize;
+    let mut result = F::one();
+    for window in (0..bits.div_ceil(W)).rev() {
+        for _ in 0..W {
+            result.square_in_place();
+        }
+        let digit = (0..W).fold(0, |digit, j| digit | bit(window * W + j) << j);
+        result *= table[digit];
+    }
+    result
+    // SOLUTION-END
 }
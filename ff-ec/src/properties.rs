@@ -0,0 +1,191 @@
+use crate::*;
+use rand::Rng;
+use runner::property::for_all;
+
+// Hidden property checks of the functions you write, against arkworks on edge cases and hundreds of random
+// inputs: zero, the point at infinity, x-coordinates whose x^3 + 7 is not a square, scalars just above n...
+// Each check draws from its own generator, so that the instances of the next questions do not depend on it
+
+// is_x_coordinate(x) iff arkworks finds a square root of x^3 + 7
+pub fn square_roots() {
+    let mut rng = seed::rng();
+    let edge_cases = [0, 1, 2, 5, 7]
+        .map(Fq::from)
+        .into_iter()
+        .chain([-Fq::from(7)]);
+    for_all(
+        "is_x_coordinate agrees with Affine::get_point_from_x_unchecked",
+        edge_cases,
+        || Fq::rand(&mut rng),
+        |x| is_x_coordinate(*x) == Affine::get_point_from_x_unchecked(*x, false).is_some(),
+    );
+}
+
+pub fn doubling() {
+    let mut rng = seed::rng();
+    let edge_cases = [Affine::zero(), Affine::generator(), -Affine::generator()];
+    for_all(
+        "double_affine(P) = P + P",
+        edge_cases,
+        || Affine::rand(&mut rng),
+        |point| double_affine(point) == (*point + *point).into_affine(),
+    );
+}
+
+pub fn inversion() {
+    let mut rng = seed::rng();
+    let p: BigUint = Fq::MODULUS.into();
+    let edge_cases = [Fq::zero(), Fq::one(), -Fq::one(), Fq::from(2)];
+    for_all(
+        "inverse_mod agrees with Fq::inverse",
+        edge_cases,
+        || Fq::rand(&mut rng),
+        |a| inverse_mod(&(*a).into(), &p) == a.inverse().map(BigUint::from),
+    );
+    // composite moduli: an inverse exists iff gcd(a, m) = 1
+    let gcd = |a: u64, m: u64| {
+        (0..).try_fold((a, m), |(a, m), _| match m {
+            0 => Err(a),
+            _ => Ok((m, a % m)),
+        })
+    };
+    let edge_cases = [(0, 1), (1, 1), (6, 9), (9, 6), (5, 9), (10, 10)];
+    for_all(
+        "inverse_mod modulo any m",
+        edge_cases,
+        || (rng.gen_range(0..1 << 20), rng.gen_range(1..1 << 20)),
+        |(a, m)| {
+            let coprime = gcd(*a, *m).unwrap_err() == 1;
+            match inverse_mod(&BigUint::from(*a), &BigUint::from(*m)) {
+                Some(inverse) => {
+                    coprime
+                        && inverse < BigUint::from(*m)
+                        && (inverse * *a) % *m == BigUint::from(1 % *m)
+                }
+                None => !coprime,
+            }
+        },
+    );
+}
+
+pub fn decoding() {
+    let mut rng = seed::rng();
+    let edge_cases = [Affine::zero(), Affine::generator(), -Affine::generator()];
+    for_all(
+        "decode_point(encode_point(P)) = P",
+        edge_cases,
+        || Affine::rand(&mut rng),
+        |point| {
+            decode_point(&encode_point_compressed(point)) == Some(*point)
+                && decode_point(&encode_point_uncompressed(point)) == Some(*point)
+        },
+    );
+    // the bytes of x are taken as they are: x >= p, or x^3 + 7 not a square, is rejected
+    let p: BigUint = Fq::MODULUS.into();
+    let edge_cases = [
+        [0u8; 32],
+        [0xff; 32],
+        pad32(&be_bytes(&Fq::from(5))),
+        pad32(&p.to_bytes_be()),
+    ];
+    for_all(
+        "decode_point(02 || x) succeeds iff x < p is an x-coordinate",
+        edge_cases,
+        || rng.gen::<[u8; 32]>(),
+        |x| {
+            let bytes = [&[2u8][..], x].concat();
+            let expected = Fq::from_bigint(BigInt::new(bytes_to_limbs(x)))
+                .and_then(|x| Affine::get_point_from_x_unchecked(x, false));
+            decode_point(&bytes).is_some() == expected.is_some()
+        },
+    );
+    let n: BigUint = Fr::MODULUS.into();
+    let edge_cases = [
+        [0u8; 32],
+        [0xff; 32],
+        pad32(&encode_scalar(&-Fr::one())),
+        pad32(&n.to_bytes_be()),
+        pad32(&(&n + 1u32).to_bytes_be()),
+    ];
+    for_all(
+        "decode_scalar(s) succeeds iff s < n",
+        edge_cases,
+        || rng.gen::<[u8; 32]>(),
+        |bytes| {
+            let expected =
+                (BigUint::from_bytes_be(bytes) < n).then(|| Fr::from_be_bytes_mod_order(bytes));
+            decode_scalar(bytes) == expected
+        },
+    );
+}
+
+pub fn exponentiation() {
+    let mut rng = seed::rng();
+    let edge_cases = [
+        (Fq::zero(), [0; 4]),
+        (Fq::zero(), [1, 0, 0, 0]),
+        (Fq::one(), [u64::MAX; 4]),
+        (-Fq::one(), [u64::MAX; 4]),
+        (Fq::from(2), [0, 0, 0, 1 << 63]),
+    ];
+    for_all(
+        "pow_window agrees with Field::pow",
+        edge_cases,
+        || (Fq::rand(&mut rng), rng.gen::<[u64; 4]>()),
+        |(base, exponent)| {
+            let expected = base.pow(exponent);
+            pow_window::<1, _>(*base, exponent) == expected
+                && pow_window::<4, _>(*base, exponent) == expected
+                && pow_window::<7, _>(*base, exponent) == expected
+        },
+    );
+}
+
+// big-endian bytes into the little-endian limbs of a `BigInt<4>`
+fn bytes_to_limbs(bytes: &[u8; 32]) -> [u64; 4] {
+    std::array::from_fn(|i| u64::from_be_bytes(bytes[24 - 8 * i..32 - 8 * i].try_into().unwrap()))
+}
+
+fn pad32(bytes: &[u8]) -> [u8; 32] {
+    let mut padded = [0; 32];
+    padded[32 - bytes.len()..].copy_from_slice(bytes);
+    padded
+}
+
+pub fn group_law() {
+    let mut rng = seed::rng();
+    let (g, o) = (Affine::generator(), Affine::zero());
+    let g2 = (g + g).into_affine();
+    let edge_cases = [
+        (o, o),
+        (o, g),
+        (g, o),
+        (g, -g),
+        (-g, g),
+        (g, g),
+        (g, g2),
+        (g2, -g),
+    ];
+    let mut random = || {
+        let p = Affine::rand(&mut rng);
+        match rng.gen_range(0..4) {
+            0 => (p, p),
+            1 => (p, -p),
+            _ => (p, Affine::rand(&mut rng)),
+        }
+    };
+    for_all(
+        "affine_add(P, Q) = P + Q",
+        edge_cases,
+        &mut random,
+        |(p, q)| {
+            affine_add(coordinates(p), coordinates(q)) == coordinates(&(*p + *q).into_affine())
+        },
+    );
+    for_all(
+        "affine_double(P) = P + P",
+        edge_cases.map(|(p, _)| p),
+        || random().0,
+        |p| affine_double(coordinates(p)) == coordinates(&(*p + *p).into_affine()),
+    );
+}
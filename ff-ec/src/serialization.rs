@@ -0,0 +1,38 @@
+use ark_serialize::{CanonicalSerialize, Compress};
+
+// The wire format of arkworks, `CanonicalSerialize` and `CanonicalDeserialize`, which differs from SEC1
+// (see module `encoding`) in every detail:
+// - a prime field element is its canonical integer (not its Montgomery form) in *little-endian*, on
+//   ceil(MODULUS_BIT_SIZE / 8) bytes; compressed or not, it is the same
+// - a short Weierstrass point carries two flags in the two most significant bits of its last byte:
+//   bit 7 when y is "negative", that is y > -y as integers in [0, p), and bit 6 for the point at infinity,
+//   whose coordinates are written as zeros
+// - the flags go into spare bits above the modulus: when there are fewer than 2 (secp256k1, whose p has
+//   256 bits), the field element gets one more byte for them
+// - compressed: x with the flags; uncompressed: x, then y with the flags
+// Q28 to Q30 are checked against arkworks, and against fixed vectors through their digests
+
+pub fn compress_mode(compress: bool) -> Compress {
+    if compress {
+        Compress::Yes
+    } else {
+        Compress::No
+    }
+}
+
+// what arkworks writes, for comparison
+pub fn ark_bytes<T: CanonicalSerialize>(value: &T, compress: bool) -> Vec<u8> {
+    let compress = compress_mode(compress);
+    let mut bytes = Vec::with_capacity(value.serialized_size(compress));
+    value.serialize_with_mode(&mut bytes, compress).unwrap();
+    bytes
+}
+
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+// (1 in Fq, -1 in Fr, 2^255 mod p in Fq)
+pub const Q28_DIGEST: &str = "19f84f357f2f48260d067226f2e312fee9b89f4e1e9847ee31ef38eebc4a2068";
+// G, -G and the point at infinity, compressed then uncompressed
+pub const Q29_DIGEST: &str = "731135195f2a96557bd26316d8052f73ad453999045a4b6c81446314181678f0";
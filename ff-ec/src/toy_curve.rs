@@ -0,0 +1,31 @@
+// y^2 = x^3 + 7, the equation of secp256k1, over the field F_89 of the first questions
+// Small enough to list every point, and not of prime order, unlike secp256k1:
+// arkworks works in a subgroup of prime order r, and wants to know it along with the cofactor h = #E / r
+// - `ScalarField` is F_r, the field of the scalars of the subgroup
+// - `GENERATOR` is a point of order r, and `COFACTOR_INV` is h^-1 mod r
+// Q20 to Q22 check your answers against this configuration, shared with the other chapters: see module `toy`
+// of the training-common crate
+
+use training_common::plot;
+
+pub use training_common::toy::{configured_cofactor, configured_order, ToyAffine, ToyFr};
+
+// `cargo run --release -- plot [p] [x y] [--numbered]`: the points of y^2 = x^3 + 7 over F_p (F_89 by default),
+// and the orbit of (x, y) if given, numbered with the last digit of each multiple with `--numbered`
+pub fn plot_curve(args: &[String]) {
+    let numbered = args.iter().any(|arg| arg == "--numbered");
+    let numbers: Vec<u64> = args
+        .iter()
+        .filter(|arg| *arg != "--numbered")
+        .map(|arg| arg.parse().expect("usage: plot [p] [x y] [--numbered]"))
+        .collect();
+    let (p, highlight) = match numbers.as_slice() {
+        [] => (89, None),
+        [p] => (*p, None),
+        [x, y] => (89, Some((*x, *y))),
+        [p, x, y] => (*p, Some((*x, *y))),
+        _ => panic!("usage: plot [p] [x y] [--numbered]"),
+    };
+    let curve = plot::SmallCurve::new(p, 0, 7);
+    print!("{}", plot::plot(&curve, highlight, numbered));
+}
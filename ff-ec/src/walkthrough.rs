@@ -0,0 +1,81 @@
+use crate::*;
+use std::fmt::Display;
+use std::io::{self, BufRead, Write};
+
+// Step-by-step replay of reference solutions, for instructors to live-demo once the students are done
+// `cargo run --release --features solutions -- walkthrough q5`, then Enter to reveal each step
+// The solution records its intermediate values in a `Trace` as it computes, and the walkthrough plays it back
+
+pub const QUESTIONS: [&str; 1] = ["q5"];
+
+pub struct Step {
+    pub formula: &'static str,
+    pub value: String,
+}
+
+#[derive(Default)]
+pub struct Trace {
+    pub steps: Vec<Step>,
+}
+
+impl Trace {
+    pub fn record<T: Display>(&mut self, formula: &'static str, value: &T) {
+        self.steps.push(Step {
+            formula,
+            value: value.to_string(),
+        });
+    }
+}
+
+// Q5: the tangent at P = (x, y) has slope lambda = 3x^2 / 2y (the curve is y^2 = x^3 + 7),
+// and 2P = (lambda^2 - 2x, lambda (x - x') - y)
+pub fn double_traced(point: &Affine, trace: &mut Trace) -> Affine {
+    let (x, y) = (point.x, point.y);
+    trace.record("x", &x);
+    trace.record("y", &y);
+    let numerator = Fq::from(3) * x.square();
+    trace.record("3 x^2", &numerator);
+    let denominator = y.double();
+    trace.record("2 y", &denominator);
+    let inverse = denominator.inverse().unwrap();
+    trace.record("(2 y)^-1", &inverse);
+    let lambda = numerator * inverse;
+    trace.record("lambda = 3 x^2 / (2 y)", &lambda);
+    let x2 = lambda.square() - x.double();
+    trace.record("x' = lambda^2 - 2 x", &x2);
+    let y2 = lambda * (x - x2) - y;
+    trace.record("y' = lambda (x - x') - y", &y2);
+    Affine::new(x2, y2)
+}
+
+pub fn walkthrough(question: &str) {
+    let mut trace = Trace::default();
+    match question {
+        "q5" => {
+            println!("Q5: doubling the generator G of secp256k1 in affine coordinates");
+            let double = double_traced(&Affine::generator(), &mut trace);
+            play(&trace);
+            assert_eq!(double, Affine::generator().mul(Fr::from(2)).into_affine());
+            println!("same as gen.mul(Fr::from(2))");
+        }
+        _ => eprintln!(
+            "no walkthrough for {}, available: {}",
+            question,
+            QUESTIONS.join(", ")
+        ),
+    }
+}
+
+// one step per line of the standard input, the remaining steps all at once when it is closed
+fn play(trace: &Trace) {
+    let mut lines = io::stdin().lock().lines();
+    let mut paused = true;
+    for step in &trace.steps {
+        if paused {
+            print!("[Enter] ");
+            io::stdout().flush().unwrap();
+            paused = matches!(lines.next(), Some(Ok(_)));
+        }
+        println!("{:<26} = {}", step.formula, step.value);
+    }
+}
@@ -0,0 +1,96 @@
+// Montgomery's trick against one inversion per element, in the base field of secp256k1:
+// `cargo bench --bench batch_inversion` from fields, for Q2
+// - one by one: an inversion each (an extended Euclidean algorithm or an exponentiation, ~100 multiplications)
+// - in a batch: the prefix products, one inversion, then 2 multiplications per element on the way back,
+//   about 3 multiplications per element and a single inversion, however many elements
+// - arkworks' `batch_inversion`, the same trick
+// The batch sizes go from 1 to 10000; then 10000 Jacobian points are converted to affine coordinates one by one
+// and with `normalize_batch`, which is what an MSM or a batch of affine additions does with the trick
+
+use ark_ec::{CurveGroup, Group};
+use ark_ff::{batch_inversion, Field, Zero};
+use ark_secp256k1::{Fq, Projective};
+use ark_std::UniformRand;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const SIZES: [usize; 5] = [1, 10, 100, 1000, 10_000];
+// the elements inverted per measurement, whatever the batch size
+const TOTAL: usize = 100_000;
+
+fn batch_inverse(values: &[Fq]) -> Vec<Fq> {
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut product = Fq::ONE;
+    for x in values {
+        prefix.push(product);
+        if !x.is_zero() {
+            product *= x;
+        }
+    }
+    let mut inverse = product.inverse().unwrap();
+    let mut inverses = vec![Fq::zero(); values.len()];
+    for i in (0..values.len()).rev() {
+        if !values[i].is_zero() {
+            inverses[i] = inverse * prefix[i];
+            inverse *= values[i];
+        }
+    }
+    inverses
+}
+
+// the time per element
+fn bench(values: &[Fq], size: usize, invert: impl Fn(&[Fq])) -> Duration {
+    let start = Instant::now();
+    for batch in values.chunks(size) {
+        invert(black_box(batch));
+    }
+    start.elapsed() / values.len() as u32
+}
+
+fn main() {
+    let mut rng = seed::rng();
+    let values: Vec<Fq> = (0..TOTAL).map(|_| Fq::rand(&mut rng)).collect();
+
+    println!(
+        "{:>10} {:>12} {:>12} {:>12} {:>8}",
+        "batch size", "one by one", "batch", "arkworks", "speedup"
+    );
+    let one_by_one = bench(&values, 1, |batch| {
+        black_box(batch.iter().map(|x| x.inverse()).collect::<Vec<_>>());
+    });
+    for size in SIZES {
+        let batch = bench(&values, size, |batch| {
+            black_box(batch_inverse(batch));
+        });
+        let arkworks = bench(&values, size, |batch| {
+            let mut batch = batch.to_vec();
+            batch_inversion(&mut batch);
+            black_box(batch);
+        });
+        println!(
+            "{:>10} {:>12.2?} {:>12.2?} {:>12.2?} {:>7.1}x",
+            size,
+            one_by_one,
+            batch,
+            arkworks,
+            one_by_one.as_secs_f64() / batch.as_secs_f64()
+        );
+    }
+
+    let points: Vec<Projective> = (0..SIZES[SIZES.len() - 1])
+        .map(|_| Projective::rand(&mut rng).double()) // Z != 1, which `into_affine` would skip
+        .collect();
+    let start = Instant::now();
+    black_box(points.iter().map(|p| p.into_affine()).collect::<Vec<_>>());
+    let one_by_one = start.elapsed();
+    let start = Instant::now();
+    black_box(Projective::normalize_batch(&points));
+    let batch = start.elapsed();
+    println!(
+        "{} Jacobian points to affine: {:.2?} one by one, {:.2?} with `normalize_batch` ({:.1}x)",
+        points.len(),
+        one_by_one,
+        batch,
+        one_by_one.as_secs_f64() / batch.as_secs_f64()
+    );
+}
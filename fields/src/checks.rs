@@ -0,0 +1,7 @@
+// The expected answers of Q12 and Q13, which do not depend on the random instance: only their digests are
+// here (see crate `canonical`)
+
+// the non-zero squares of F_89
+pub const Q12_DIGEST: &str = "ffed7f4e50e501b524a55ed0d157af5f57b1aca30546908fa776063121e97554";
+// the number of points of secp256k1 with x-coordinate x, for x in X_CANDIDATES
+pub const Q13_DIGEST: &str = "8309b38e6613bc93be6710dd433f6ab4d2d242d6b86b470638387eeea724aa6e";
@@ -0,0 +1,33 @@
+use ark_ff::Field;
+
+// Square-and-multiply, the textbook exponentiation, is the `pow_window` of ff-ec with a window of one bit:
+// from the most significant set bit down, square, then multiply by the base if the bit is set. A b-bit
+// exponent with h set bits takes b - 1 squarings and h - 1 multiplications, where `pow_naive` multiplies by
+// the base e - 1 times: at least 2^255 - 1 multiplications for a 256-bit exponent
+
+// base^exponent by repeated multiplication
+pub fn pow_naive<F: Field>(base: F, exponent: u64) -> F {
+    if exponent == 0 {
+        return F::one();
+    }
+    let mut result = base;
+    for _ in 1..exponent {
+        result *= base;
+    }
+    result
+}
+
+// the multiplications of `pow_naive`
+pub fn naive_count(exponent: u64) -> u64 {
+    exponent.saturating_sub(1)
+}
+
+// the limbs of the largest exponent of `bits` bits, 2^bits - 1
+pub fn all_ones(bits: usize) -> Vec<u64> {
+    (0..bits.div_ceil(64))
+        .map(|i| match bits - 64 * i {
+            b if b >= 64 => u64::MAX,
+            b => (1 << b) - 1,
+        })
+        .collect()
+}
@@ -0,0 +1,99 @@
+use runner::lang::{Catalog, Lang, Statements};
+
+pub const CATALOG: Catalog = Catalog {
+    lang: Lang::Fr,
+    statements: Statements::Table(&[
+        ("Q1", "écrire `batch_inverse`, l'astuce de Montgomery : les inverses de tous les éléments avec un seul appel à\n`inverse()`, à partir des produits préfixes a_1, a_1 a_2, ..., a_1 ... a_n, puis en remontant depuis l'inverse\ndu dernier. Les zéros n'ont pas d'inverse : les laisser à zéro, et inverser les autres"),
+        ("Q2", "lancer `cargo bench --bench batch_inversion` : comment le gain croît-il avec la taille du lot,\net où cesse-t-il de croître ? L'astuce multiplie les éléments entre eux : que fait un seul zéro\nà une implémentation négligente, et que dit-il à un attaquant qui chronomètre une implémentation en temps constant ?"),
+        ("Q3", "écrire `pow_square_and_multiply`, qui lit l'exposant à partir de son bit à un de poids le plus fort :\npartir de la base, puis pour chaque bit suivant élever au carré, et multiplier par la base si le bit est à un\nL'exposant est une liste de mots de 64 bits, poids faible en premier, comme dans `Field::pow`"),
+        ("Q4", "écrire `square_and_multiply_count`, le nombre d'élévations au carré et de multiplications que fait `pow_square_and_multiply`\nsur un exposant, à comparer aux e - 1 multiplications de `pow_naive`\nAu temps par multiplication mesuré ci-dessous, combien de temps prendrait `pow_naive` sur un exposant de 256 bits ?\nPourquoi le nombre de bits à un d'un exposant aléatoire est-il environ la moitié de sa taille, et que gagne dessus le `pow_window` de ff-ec ?"),
+        ("Q5", "écrire `sample_mod_reduction`, qui réduit `bytes` octets aléatoires modulo p"),
+        ("Q6", "écrire `sample_rejection`, qui tire les bits de p jusqu'à ce que le candidat soit inférieur à p"),
+        ("Q7", "quelle est la taille du biais de 32 octets aléatoires réduits modulo l'ordre n de secp256k1, et combien\nd'échantillons faudrait-il au test du khi-deux pour le voir ? Quelle attaque de number-theory transforme quelques bits biaisés\ndes nonces ECDSA en clé privée ? Pourquoi le hash_to_field de la RFC 9380 réduit-il 16 octets de plus que p n'en demande\nplutôt que de rejeter, et pourquoi une boucle de rejet peut-elle poser problème dans du code en temps constant ?"),
+        ("Q8", "écrire `barrett_mu` et `barrett_reduce`, qui réduit modulo p le produit de deux résidus"),
+        ("Q9", "écrire `montgomery_inverse`, -p^-1 mod 2^64, `to_montgomery`, `redc` et `montgomery_mul`"),
+        ("Q10", "entrer dans la forme de Montgomery et en sortir coûte une multiplication chaque fois : pourquoi arkworks garde-t-il\ntous les éléments sous cette forme, et quand Barrett est-il le meilleur choix ? En quoi `%` se compile-t-il quand p est une\nconstante ? La dernière soustraction des deux est un branchement sur des données secrètes : comment la supprimer ?"),
+        ("Q11", "écrire `euler_legendre`, le symbole de Legendre de n'importe quel corps premier par le critère d'Euler"),
+        ("Q12", "écrire `quadratic_residues`, les carrés non nuls d'un petit corps par valeur entière croissante"),
+        ("Q13", "écrire `points_with_x`, le nombre de points de secp256k1 d'abscisse x"),
+    ]),
+    hints: &[
+        (
+            "Q1",
+            &[
+                "Si P = a_1 a_2 ... a_n, alors 1 / a_n = (1 / P) * (a_1 ... a_(n-1)) : une inversion donne le dernier inverse",
+                "Garder les produits préfixes dans un vecteur ; multiplier 1 / P par a_n donne 1 / (a_1 ... a_(n-1)), et ainsi de suite à rebours",
+                "Sauter les zéros dans les deux passes : ils restent hors des produits, et leur inverse reste nul",
+            ],
+        ),
+        (
+            "Q3",
+            &[
+                "Le bit i de l'exposant est `(exponent[i / 64] >> (i % 64)) & 1` : trouver d'abord le plus haut bit à un",
+                "Descendre d'un bit double l'exposant lu jusque-là, d'où une élévation au carré, et un bit à un ajoute un, d'où une multiplication par la base",
+                "Aucun bit à un signifie que l'exposant est 0 et le résultat un, y compris pour une base nulle",
+            ],
+        ),
+        (
+            "Q4",
+            &[
+                "Compter la boucle de Q3 plutôt que la chronométrer : une élévation au carré par bit sous le plus haut",
+                "Chaque bit à un sauf le plus haut coûte aussi une multiplication",
+                "Avec b la position du plus haut bit à un plus un et h le nombre de bits à un (`leading_zeros`, `count_ones`), c'est b - 1 + h - 1, et 0 pour les exposants 0 et 1",
+            ],
+        ),
+        (
+            "Q5",
+            &[
+                "Remplir un tampon de `bytes` octets avec `RngCore::fill_bytes`",
+                "`PrimeField::from_le_bytes_mod_order` les lit comme un entier petit-boutiste et le réduit modulo p",
+            ],
+        ),
+        (
+            "Q6",
+            &[
+                "Tirer `MODULUS_BIT_SIZE` bits, la taille de p : un candidat est inférieur à 2p, et à p plus d'une fois sur deux",
+                "Remplir ceil(bits / 8) octets et garder leurs `MODULUS_BIT_SIZE` bits de poids faible, le moins significatif d'abord",
+                "`F::BigInt::from_bits_le` construit le candidat, et `F::from_bigint` renvoie None quand il vaut p ou plus : tirer à nouveau",
+            ],
+        ),
+        (
+            "Q8",
+            &[
+                "k est la taille en bits de p, `u64::BITS - p.leading_zeros()`, et mu = floor(2^(2k) / p) tient sur 64 bits",
+                "q = ((x >> (k - 1)) mu) >> (k + 1) sous-estime floor(x / p) d'au plus 2",
+                "x - qp est inférieur à 3p : le calculer sur les 64 bits de poids faible avec des opérations modulaires, puis soustraire p tant qu'il vaut p ou plus",
+            ],
+        ),
+        (
+            "Q9",
+            &[
+                "p^-1 mod 2^64 par l'itération de Newton : x -> x (2 - px) double le nombre de bits de poids faible corrects, et x = p est correct mod 8",
+                "La forme de Montgomery de a est a 2^64 mod p : décaler a dans la moitié haute d'un u128 et le réduire avec `%`, une fois",
+                "REDC : m = T (-p^-1) mod 2^64 fait de T + mp un multiple de 2^64 ; renvoyer (T + mp) >> 64, moins p s'il vaut p ou plus",
+            ],
+        ),
+        (
+            "Q11",
+            &[
+                "Critère d'Euler : a^((p - 1) / 2) vaut 1 pour les carrés non nuls et -1 pour les autres a non nuls",
+                "`F::MODULUS_MINUS_ONE_DIV_TWO` est l'exposant (p - 1) / 2, que `Field::pow` prend tel quel",
+                "Comparer la puissance à zéro et à un : tout le reste vaut -1",
+            ],
+        ),
+        (
+            "Q12",
+            &[
+                "Dans un corps d'un seul mot, p est `F::MODULUS.as_ref()[0]`",
+                "Garder les a de 1..p dont l'`euler_legendre` vaut 1, convertis avec `F::from`, par ordre croissant",
+            ],
+        ),
+        (
+            "Q13",
+            &[
+                "Les points d'abscisse x sont les (x, y) pour les y tels que y^2 = x^3 + 7",
+                "Un carré non nul a deux racines carrées, zéro une seule, un non-carré aucune : 1 + (x^3 + 7 | p) points",
+            ],
+        ),
+    ],
+};
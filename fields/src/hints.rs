@@ -0,0 +1,87 @@
+use runner::hints::Hints;
+use runner::lang::Catalog;
+
+pub const CATALOGS: &[Catalog] = &[
+    Catalog::english(HINTS, include_str!("main.rs")),
+    crate::fr::CATALOG,
+];
+
+pub const HINTS: Hints = &[
+    (
+        "Q1",
+        &[
+            "If P = a_1 a_2 ... a_n, then 1 / a_n = (1 / P) * (a_1 ... a_(n-1)): one inversion gives the last inverse",
+            "Keep the prefix products in a vector; multiplying 1 / P by a_n gives 1 / (a_1 ... a_(n-1)), and so on backwards",
+            "Skip the zeros in both passes: they stay out of the products, and their inverse stays zero",
+        ],
+    ),
+    (
+        "Q3",
+        &[
+            "Bit i of the exponent is `(exponent[i / 64] >> (i % 64)) & 1`: find the highest set one first",
+            "Going down one bit doubles the exponent read so far, hence a squaring, and a set bit adds one, hence a multiplication by the base",
+            "No set bit at all means the exponent is 0 and the result is one, including for a base of zero",
+        ],
+    ),
+    (
+        "Q4",
+        &[
+            "Count the loop of Q3 rather than timing it: one squaring per bit below the top one",
+            "Every set bit but the top one costs a multiplication too",
+            "With b the position of the top set bit plus one and h the number of set bits (`leading_zeros`, `count_ones`), it is b - 1 + h - 1, and 0 for the exponents 0 and 1",
+        ],
+    ),
+    (
+        "Q5",
+        &[
+            "Fill a buffer of `bytes` bytes with `RngCore::fill_bytes`",
+            "`PrimeField::from_le_bytes_mod_order` reads them as a little-endian integer and reduces it modulo p",
+        ],
+    ),
+    (
+        "Q6",
+        &[
+            "Draw `MODULUS_BIT_SIZE` bits, the size of p: a candidate is below 2p, and below p more than half of the time",
+            "Fill ceil(bits / 8) bytes and keep their low `MODULUS_BIT_SIZE` bits, least significant first",
+            "`F::BigInt::from_bits_le` builds the candidate, and `F::from_bigint` returns None when it is p or more: draw again",
+        ],
+    ),
+    (
+        "Q8",
+        &[
+            "k is the bit length of p, `u64::BITS - p.leading_zeros()`, and mu = floor(2^(2k) / p) fits in 64 bits",
+            "q = ((x >> (k - 1)) mu) >> (k + 1) underestimates floor(x / p) by at most 2",
+            "x - qp is below 3p: compute it on the low 64 bits with wrapping operations, then subtract p while it is p or more",
+        ],
+    ),
+    (
+        "Q9",
+        &[
+            "p^-1 mod 2^64 by Newton's iteration: x -> x (2 - px) doubles the number of correct low bits, and x = p is correct mod 8",
+            "The Montgomery form of a is a 2^64 mod p: shift a into the high half of a u128 and reduce it with `%`, once",
+            "REDC: m = T (-p^-1) mod 2^64 makes T + mp a multiple of 2^64; return (T + mp) >> 64, minus p if it is p or more",
+        ],
+    ),
+    (
+        "Q11",
+        &[
+            "Euler's criterion: a^((p - 1) / 2) is 1 for the non-zero squares and -1 for the other non-zero a",
+            "`F::MODULUS_MINUS_ONE_DIV_TWO` is the exponent (p - 1) / 2, which `Field::pow` takes as is",
+            "Compare the power with zero and one: anything else is -1",
+        ],
+    ),
+    (
+        "Q12",
+        &[
+            "In a field of a single limb, p is `F::MODULUS.as_ref()[0]`",
+            "Keep the a in 1..p whose `euler_legendre` is 1, converted with `F::from`, in increasing order",
+        ],
+    ),
+    (
+        "Q13",
+        &[
+            "The points with x-coordinate x are (x, y) for the y with y^2 = x^3 + 7",
+            "A non-zero square has two square roots, zero one, a non-square none: 1 + (x^3 + 7 | p) points",
+        ],
+    ),
+];
@@ -0,0 +1,458 @@
+use ark_ff::{BigInt, BigInteger, Field, MontConfig, PrimeField};
+use ark_secp256k1::{Affine, Fq, Fr};
+use ark_std::{One, UniformRand, Zero};
+use checks::*;
+use exponentiation::*;
+use quadratic_residues::*;
+use rand::{Rng, RngCore};
+use reduction::*;
+use runner::Runner;
+use sampling::*;
+use std::collections::HashSet;
+use std::hint::black_box;
+use std::ops::Range;
+use std::time::Instant;
+
+mod checks;
+mod exponentiation;
+mod fr;
+mod hints;
+mod properties;
+mod quadratic_residues;
+mod reduction;
+mod sampling;
+
+// The small field of the first questions of ff-ec, F_89, and the scalar field of its toy curve: see module
+// `toy` of the training-common crate
+use training_common::toy::{ToyFr, F};
+
+fn main() {
+    let runner = Runner::start(env!("CARGO_PKG_NAME"), hints::CATALOGS);
+    let mut rng = seed::rng();
+
+    // The fields of ff-ec again, F_89 and the base field Fq of secp256k1, from the side of their implementation:
+    // what an operation costs, and how to do fewer of the expensive ones
+
+    // An inversion costs about as much as a hundred multiplications: an MSM which converts thousands of
+    // Jacobian points to affine ones, or adds them with the affine formulas, inverts them all at once
+    // Q1: write `batch_inverse`, Montgomery's trick: the inverses of all the elements with a single call to
+    // `inverse()`, from the prefix products a_1, a_1 a_2, ..., a_1 ... a_n, then walking back from the inverse
+    // of the last one. Zeros have no inverse: leave them at zero, and invert the others
+    runner.question("Q1");
+    let element_wise = |values: &[Fq]| -> Vec<Fq> {
+        values
+            .iter()
+            .map(|x| x.inverse().unwrap_or(Fq::zero()))
+            .collect()
+    };
+    assert_eq!(batch_inverse::<Fq>(&[]), vec![]);
+    assert_eq!(batch_inverse(&[Fq::from(2)]), element_wise(&[Fq::from(2)]));
+    assert_eq!(
+        batch_inverse(&[Fq::zero(), Fq::zero()]),
+        vec![Fq::zero(); 2]
+    );
+    for length in [1, 2, 3, 10, 100] {
+        let mut values: Vec<Fq> = (0..length).map(|_| Fq::rand(&mut rng)).collect();
+        assert_eq!(batch_inverse(&values), element_wise(&values));
+        values[rng.gen_range(0..length)] = Fq::zero();
+        values[0] = Fq::zero();
+        assert_eq!(batch_inverse(&values), element_wise(&values));
+    }
+    let small: Vec<F> = (0..89u64).map(F::from).collect();
+    let small_inverses: Vec<F> = small
+        .iter()
+        .map(|x| x.inverse().unwrap_or(F::zero()))
+        .collect();
+    assert_eq!(batch_inverse(&small), small_inverses);
+    let values: Vec<Fq> = (0..10_000).map(|_| Fq::rand(&mut rng)).collect();
+    let start = Instant::now();
+    let expected = element_wise(&values);
+    let element_wise_time = start.elapsed();
+    let start = Instant::now();
+    let inverses = batch_inverse(&values);
+    let batch_time = start.elapsed();
+    assert_eq!(inverses, expected);
+    println!(
+        "10000 inverses in F_p of secp256k1: {:.2?} one by one, {:.2?} in a batch ({:.1}x)",
+        element_wise_time,
+        batch_time,
+        element_wise_time.as_secs_f64() / batch_time.as_secs_f64()
+    );
+    // a single inversion and 3 multiplications per element: anything close to the element-wise time
+    // inverts more than once
+    assert!(
+        batch_time * 4 < element_wise_time,
+        "batch_inverse is not faster than one inversion per element"
+    );
+    // Q2: run `cargo bench --bench batch_inversion`: how does the speedup grow with the size of the batch,
+    // and where does it stop growing? The trick multiplies the elements together: what does a single zero do
+    // to a careless implementation, and what does it tell an attacker timing a constant-time one?
+    runner.question("Q2");
+
+    // Exponentiation: `pow_window` of Q14 of ff-ec improves on the textbook algorithm, which module
+    // `exponentiation` describes
+    // Q3: write `pow_square_and_multiply`, which reads the exponent from its most significant set bit:
+    // start from the base, then for each following bit square, and multiply by the base if the bit is set
+    // The exponent is a list of 64-bit limbs, least significant first, as in `Field::pow`
+    runner.question("Q3");
+    let exponent = Fq::rand(&mut rng).into_bigint();
+    let a = Fq::rand(&mut rng);
+    assert_eq!(
+        pow_square_and_multiply(a, exponent.as_ref()),
+        a.pow(exponent)
+    );
+    assert_eq!(pow_square_and_multiply(a, &[]), Fq::one());
+    assert_eq!(pow_square_and_multiply(a, &[0, 0]), Fq::one());
+    assert_eq!(pow_square_and_multiply(a, &[1]), a);
+    assert_eq!(pow_square_and_multiply(Fq::zero(), &[3]), Fq::zero());
+    let b = F::rand(&mut rng);
+    assert_eq!(pow_square_and_multiply(b, &[87]), b.pow([87]));
+    assert_eq!(pow_square_and_multiply(b, &[88]), F::one());
+    assert_eq!(
+        pow_square_and_multiply(b, &[u64::MAX, 1]),
+        b.pow([u64::MAX, 1])
+    );
+    properties::square_and_multiply();
+
+    // Q4: write `square_and_multiply_count`, the number of squarings and multiplications `pow_square_and_multiply`
+    // does on an exponent, to compare with the e - 1 multiplications of `pow_naive`
+    // At the time per multiplication measured below, how long would `pow_naive` take on a 256-bit exponent?
+    // Why is the number of set bits of a random exponent about half its size, and what does `pow_window` of ff-ec save
+    // on it?
+    runner.question("Q4");
+    assert_eq!(square_and_multiply_count(&[]), 0);
+    assert_eq!(square_and_multiply_count(&[0, 0]), 0);
+    assert_eq!(square_and_multiply_count(&[1]), 0);
+    assert_eq!(square_and_multiply_count(&[2]), 1);
+    assert_eq!(square_and_multiply_count(&[3]), 2);
+    assert_eq!(square_and_multiply_count(&[0b1011]), 5);
+    assert_eq!(square_and_multiply_count(&[0, 1]), 64);
+    assert_eq!(square_and_multiply_count(&[0, 1, 0]), 64);
+    assert_eq!(square_and_multiply_count(&[u64::MAX; 4]), 510);
+    for e in 0..100 {
+        assert!(square_and_multiply_count(&[e]) <= naive_count(e));
+    }
+    println!("bits  naive     square-and-multiply");
+    for bits in [8, 16, 32, 64, 128, 256] {
+        println!(
+            "{:>4}  ~2^{:<6} {}",
+            bits,
+            bits,
+            square_and_multiply_count(&all_ones(bits))
+        );
+    }
+    let e = (1 << 20) - 1;
+    let start = Instant::now();
+    let naive = pow_naive(a, e);
+    let naive_time = start.elapsed();
+    let start = Instant::now();
+    assert_eq!(pow_square_and_multiply(a, &[e]), naive);
+    let fast_time = start.elapsed();
+    println!(
+        "2^20 - 1: {} multiplications in {:.2?} ({:.2?} each), {} in {:.2?}",
+        naive_count(e),
+        naive_time,
+        naive_time / naive_count(e) as u32,
+        square_and_multiply_count(&[e]),
+        fast_time
+    );
+
+    // Uniform field elements from random bytes: take a look at module `sampling`
+    // Q5: write `sample_mod_reduction`, which reduces `bytes` random bytes modulo p
+    runner.question("Q5");
+    properties::sampling();
+    let threshold = chi_square_threshold(88);
+    for bytes in [1, 2, 16] {
+        let chi2 = chi_square(89_000, || sample_mod_reduction::<F, _>(&mut rng, bytes));
+        println!(
+            "{} random byte(s) mod 89: chi-square {:.1}, threshold {:.1}",
+            bytes, chi2, threshold
+        );
+        // 256 = 2 * 89 + 78: the residues below 78 have 3 preimages, the others 2
+        assert_eq!(bytes == 1, chi2 > threshold);
+    }
+    // Q6: write `sample_rejection`, which draws the bits of p until the candidate is below p
+    runner.question("Q6");
+    let chi2 = chi_square(89_000, || sample_rejection::<F, _>(&mut rng));
+    println!(
+        "rejection sampling over F_89: chi-square {:.1}, threshold {:.1}",
+        chi2, threshold
+    );
+    assert!(chi2 <= threshold);
+    let chi2 = chi_square(10_000, || sample_rejection::<ToyFr, _>(&mut rng));
+    assert!(chi2 <= chi_square_threshold(4));
+    // 7 bits for 89: a byte accepted with probability 89 / 128
+    let mut counting = CountingRng::new(&mut rng);
+    for _ in 0..10_000 {
+        sample_rejection::<F, _>(&mut counting);
+    }
+    let bytes_per_sample = counting.bytes as f64 / 10_000.0;
+    assert!((1.3..1.6).contains(&bytes_per_sample));
+    let mut counting = CountingRng::new(&mut rng);
+    let samples: HashSet<Fr> = (0..1000)
+        .map(|_| sample_rejection::<Fr, _>(&mut counting))
+        .collect();
+    assert_eq!(samples.len(), 1000);
+    assert_eq!(counting.bytes, 32_000);
+    println!(
+        "rejection sampling: {:.2} bytes per element of F_89, 32 per element of Fr",
+        bytes_per_sample
+    );
+    // Q7: how large is the bias of 32 random bytes reduced modulo the order n of secp256k1, and how many
+    // samples would the chi-square test need to see it? Which attack of number-theory turns a few biased bits
+    // of ECDSA nonces into the private key? Why does hash_to_field of RFC 9380 reduce 16 bytes more than p needs
+    // instead of rejecting, and why can a rejection loop be a problem in constant-time code?
+    runner.question("Q7");
+
+    // Modular reduction without division: take a look at module `reduction`
+    // Q8: write `barrett_mu` and `barrett_reduce`, which reduces a product of two residues modulo p
+    runner.question("Q8");
+    let mu = barrett_mu(P62);
+    assert_eq!(mu as u128, (1u128 << 124) / P62 as u128);
+    properties::barrett();
+    // Q9: write `montgomery_inverse`, -p^-1 mod 2^64, `to_montgomery`, `redc` and `montgomery_mul`
+    runner.question("Q9");
+    let p_inv = montgomery_inverse(P62);
+    assert_eq!(p_inv, <F62Config as MontConfig<1>>::INV);
+    assert_eq!(
+        to_montgomery(1, P62),
+        montgomery_form(F62::one()),
+        "R mod p is the Montgomery form of 1"
+    );
+    assert_eq!(
+        redc(<F62Config as MontConfig<1>>::R2.0[0] as u128, P62, p_inv),
+        montgomery_form(F62::one())
+    );
+    properties::montgomery();
+    // a chain of multiplications, each waiting for the previous one, modulo a p the compiler cannot see
+    let p = black_box(P62);
+    let factors: Vec<u64> = (0..1_000_000).map(|_| rng.gen_range(0..p)).collect();
+    let time = |name: &str, multiply: &dyn Fn(u64, u64) -> u64, one: u64| {
+        let start = Instant::now();
+        let product = factors
+            .iter()
+            .fold(one, |product, x| multiply(product, black_box(*x)));
+        println!(
+            "{:>12}: {:.2?} for 10^6 multiplications",
+            name,
+            start.elapsed()
+        );
+        product
+    };
+    let naive = time("%", &|a, b| (a as u128 * b as u128 % p as u128) as u64, 1);
+    let barrett = time(
+        "Barrett",
+        &|a, b| barrett_reduce(a as u128 * b as u128, p, mu),
+        1,
+    );
+    assert_eq!(barrett, naive);
+    // the factors are taken as Montgomery forms: the product is theirs times R^-(n - 1)
+    let montgomery = time(
+        "Montgomery",
+        &|a, b| montgomery_mul(a, b, p, p_inv),
+        to_montgomery(1, p),
+    );
+    let arkworks = time(
+        "arkworks",
+        &|a, b| montgomery_form(F62::new_unchecked(BigInt([a])) * F62::new_unchecked(BigInt([b]))),
+        montgomery_form(F62::one()),
+    );
+    assert_eq!(montgomery, arkworks);
+    assert_eq!(
+        redc(montgomery as u128, p, p_inv),
+        F62::new_unchecked(BigInt([montgomery])).into_bigint().0[0]
+    );
+    // Q10: entering and leaving the Montgomery form costs a multiplication each: why does arkworks keep every
+    // element in the form, and when is Barrett the better choice? What does `%` compile to when p is a constant?
+    // The last subtraction of both is a branch on secret data: how would you remove it?
+    runner.question("Q10");
+
+    // Q4 of ff-ec again, with your own Legendre symbol: take a look at module `quadratic_residues`
+    // Q11: write `euler_legendre`, the Legendre symbol of any prime field by Euler's criterion
+    runner.question("Q11");
+    assert_eq!(euler_legendre(F::zero()), 0);
+    assert_eq!(euler_legendre(F::from(9)), 1);
+    assert_eq!(euler_legendre(F::from(3)), -1); // a generator is not a square
+    properties::legendre_symbols();
+    // Q12: write `quadratic_residues`, the non-zero squares of a small field by increasing integer value
+    runner.question("Q12");
+    let residues = quadratic_residues::<F>();
+    assert_eq!(residues.len(), 44);
+    training_common::assert_digest("fields/Q12", &residues, Q12_DIGEST);
+    assert_eq!(quadratic_residues::<ToyFr>(), [1, 4].map(ToyFr::from));
+    // Q13: write `points_with_x`, the number of points of secp256k1 with x-coordinate x
+    runner.question("Q13");
+    let counts: Vec<usize> = X_CANDIDATES.map(|x| points_with_x(Fq::from(x))).collect();
+    training_common::assert_digest("fields/Q13", &counts, Q13_DIGEST);
+    for (x, count) in X_CANDIDATES.zip(&counts) {
+        let on_curve = Affine::get_point_from_x_unchecked(Fq::from(x), false).is_some();
+        assert_eq!(*count > 0, on_curve);
+    }
+    // x^3 = -7 has no solution in Fq: no point has y = 0, and secp256k1 has no point of order 2
+    assert!(counts.iter().all(|count| *count != 1));
+
+    println!("Good job! 🏴‍☠️");
+    runner.finish();
+}
+
+fn batch_inverse<F: Field>(values: &[F]) -> Vec<F> {
+    // SOLUTION-BEGIN
+    // prefix[i] is the product of the non-zero elements before i
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut product = F::one();
+    for x in values {
+        prefix.push(product);
+        if !x.is_zero() {
+            product *= x;
+        }
+    }
+    // the inverse of the product of all the elements, then the inverse of the product of those before i
+    let mut inverse = product.inverse().unwrap();
+    let mut inverses = vec![F::zero(); values.len()];
+    for i in (0..values.len()).rev() {
+        if !values[i].is_zero() {
+            inverses[i] = inverse * prefix[i];
+            inverse *= values[i];
+        }
+    }
+    inverses
+    // SOLUTION-END
+}
+
+fn pow_square_and_multiply<F: Field>(base: F, exponent: &[u64]) -> F {
+    // SOLUTION-BEGIN
+    let bit = |i: usize| (exponent[i / 64] >> (i % 64)) & 1 == 1;
+    let Some(top) = (0..64 * exponent.len()).rev().find(|i| bit(*i)) else {
+        return F::one();
+    };
+    let mut result = base;
+    for i in (0..top).rev() {
+        result.square_in_place();
+        if bit(i) {
+            result *= base;
+        }
+    }
+    result
+    // SOLUTION-END
+}
+
+fn square_and_multiply_count(exponent: &[u64]) -> u64 {
+    // SOLUTION-BEGIN
+    let bits = exponent.iter().rposition(|limb| *limb != 0).map_or(0, |i| {
+        64 * (i as u64 + 1) - exponent[i].leading_zeros() as u64
+    });
+    let ones: u64 = exponent.iter().map(|limb| limb.count_ones() as u64).sum();
+    // bits - 1 squarings and ones - 1 multiplications
+    (bits + ones).saturating_sub(2)
+    // SOLUTION-END
+}
+
+fn sample_mod_reduction<F: PrimeField, R: RngCore>(rng: &mut R, bytes: usize) -> F {
+    // SOLUTION-BEGIN
+    let mut buffer = vec![0; bytes];
+    rng.fill_bytes(&mut buffer);
+    F::from_le_bytes_mod_order(&buffer)
+    // SOLUTION-END
+}
+
+fn sample_rejection<F: PrimeField, R: RngCore>(rng: &mut R) -> F {
+    // SOLUTION-BEGIN
+    let bits = F::MODULUS_BIT_SIZE as usize;
+    let mut buffer = vec![0u8; bits.div_ceil(8)];
+    loop {
+        rng.fill_bytes(&mut buffer);
+        // the low `bits` bits: the candidate is below 2^bits < 2p
+        let candidate: Vec<bool> = (0..bits)
+            .map(|i| (buffer[i / 8] >> (i % 8)) & 1 == 1)
+            .collect();
+        if let Some(x) = F::from_bigint(F::BigInt::from_bits_le(&candidate)) {
+            return x;
+        }
+    }
+    // SOLUTION-END
+}
+
+fn barrett_mu(p: u64) -> u64 {
+    // SOLUTION-BEGIN
+    let k = u64::BITS - p.leading_zeros();
+    ((1u128 << (2 * k)) / p as u128) as u64
+    // SOLUTION-END
+}
+
+fn barrett_reduce(x: u128, p: u64, mu: u64) -> u64 {
+    // SOLUTION-BEGIN
+    let k = u64::BITS - p.leading_zeros();
+    // x >> (k - 1) and mu have k + 1 bits: one 64-bit multiplication, and q < 2^(k + 1)
+    let q = (((x >> (k - 1)) as u64 as u128 * mu as u128) >> (k + 1)) as u64;
+    // x - qp < 3p fits in 64 bits, whatever the bits above
+    let mut r = (x as u64).wrapping_sub(q.wrapping_mul(p));
+    while r >= p {
+        r -= p;
+    }
+    r
+    // SOLUTION-END
+}
+
+fn montgomery_inverse(p: u64) -> u64 {
+    // SOLUTION-BEGIN
+    // Newton's iteration doubles the correct low bits of p^-1 mod 2^64, and p p = 1 mod 8 for an odd p
+    let mut inverse = p;
+    for _ in 0..5 {
+        inverse = inverse.wrapping_mul(2u64.wrapping_sub(p.wrapping_mul(inverse)));
+    }
+    inverse.wrapping_neg()
+    // SOLUTION-END
+}
+
+fn to_montgomery(a: u64, p: u64) -> u64 {
+    // SOLUTION-BEGIN
+    (((a as u128) << 64) % p as u128) as u64
+    // SOLUTION-END
+}
+
+fn redc(t: u128, p: u64, p_inv: u64) -> u64 {
+    // SOLUTION-BEGIN
+    let m = (t as u64).wrapping_mul(p_inv);
+    let r = ((t + m as u128 * p as u128) >> 64) as u64;
+    if r >= p {
+        r - p
+    } else {
+        r
+    }
+    // SOLUTION-END
+}
+
+fn montgomery_mul(a: u64, b: u64, p: u64, p_inv: u64) -> u64 {
+    // SOLUTION-BEGIN
+    redc(a as u128 * b as u128, p, p_inv)
+    // SOLUTION-END
+}
+
+fn euler_legendre<F: PrimeField>(a: F) -> i8 {
+    // SOLUTION-BEGIN
+    let power = a.pow(F::MODULUS_MINUS_ONE_DIV_TWO);
+    if power.is_zero() {
+        0
+    } else if power.is_one() {
+        1
+    } else {
+        -1
+    }
+    // SOLUTION-END
+}
+
+fn quadratic_residues<F: PrimeField>() -> Vec<F> {
+    // SOLUTION-BEGIN
+    let p = F::MODULUS.as_ref()[0];
+    (1..p)
+        .map(F::from)
+        .filter(|a| euler_legendre(*a) == 1)
+        .collect()
+    // SOLUTION-END
+}
+
+fn points_with_x(x: Fq) -> usize {
+    // SOLUTION-BEGIN
+    (1 + euler_legendre(x.square() * x + Fq::from(7))) as usize
+    // SOLUTION-END
+}
@@ -0,0 +1,137 @@
+use crate::*;
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
+use runner::property::for_all;
+
+// Hidden property checks of the functions you write, against arkworks on edge cases and hundreds of random
+// inputs. Each check draws from its own generator, so that the instances of the next questions do not depend on it
+
+// pow_square_and_multiply agrees with `Field::pow` over F_89 and Fq, with the exponents on one limb or more
+pub fn square_and_multiply() {
+    let mut rng = seed::rng();
+    let edge_cases = [
+        (F::zero(), vec![]),
+        (F::zero(), vec![0]),
+        (F::from(3), vec![88]),
+        (-F::one(), vec![u64::MAX, u64::MAX]),
+        (F::from(2), vec![0, 1]),
+    ];
+    for_all(
+        "pow_square_and_multiply agrees with Field::pow over F_89",
+        edge_cases,
+        || {
+            let limbs = rng.gen_range(0..3);
+            (F::rand(&mut rng), (0..limbs).map(|_| rng.gen()).collect())
+        },
+        |(base, exponent)| pow_square_and_multiply(*base, exponent) == base.pow(exponent),
+    );
+    let edge_cases = [
+        (Fq::zero(), [0; 4]),
+        (Fq::one(), [u64::MAX; 4]),
+        (-Fq::one(), [1, 0, 0, 0]),
+        (Fq::from(2), [0, 0, 0, 1 << 63]),
+    ];
+    for_all(
+        "pow_square_and_multiply agrees with Field::pow over Fq",
+        edge_cases,
+        || (Fq::rand(&mut rng), rng.gen::<[u64; 4]>()),
+        |(base, exponent)| pow_square_and_multiply(*base, exponent) == base.pow(exponent),
+    );
+}
+
+// sample_mod_reduction reduces the next `bytes` bytes of the generator, read as a little-endian integer
+pub fn sampling() {
+    let mut rng = seed::rng();
+    let edge_cases = [([0; 32], 0), ([1; 32], 1), ([2; 32], 32), ([3; 32], 64)];
+    for_all(
+        "sample_mod_reduction agrees with Fr::from_le_bytes_mod_order",
+        edge_cases,
+        || (rng.gen(), rng.gen_range(0..100)),
+        |(seed, bytes)| {
+            let mut buffer = vec![0; *bytes];
+            ChaChaRng::from_seed(*seed).fill_bytes(&mut buffer);
+            let sample: Fr = sample_mod_reduction(&mut ChaChaRng::from_seed(*seed), *bytes);
+            sample == Fr::from_le_bytes_mod_order(&buffer)
+        },
+    );
+}
+
+// barrett_reduce agrees with `%` on the products of two residues modulo p = 2^62 - 57
+pub fn barrett() {
+    let mut rng = seed::rng();
+    let mu = barrett_mu(P62);
+    let edge_cases = [
+        (0, 0),
+        (1, 1),
+        (P62 - 1, P62 - 1),
+        (P62 - 1, 1),
+        (1 << 31, 1 << 31),
+    ];
+    for_all(
+        "barrett_reduce(ab) = ab mod p",
+        edge_cases,
+        || (rng.gen_range(0..P62), rng.gen_range(0..P62)),
+        |(a, b)| {
+            let x = *a as u128 * *b as u128;
+            barrett_reduce(x, P62, mu) as u128 == x % P62 as u128
+        },
+    );
+}
+
+// the Montgomery forms agree with the limbs of `F62`, and `redc` leaves the form
+pub fn montgomery() {
+    let mut rng = seed::rng();
+    let p_inv = montgomery_inverse(P62);
+    let edge_cases = [(0, 0), (1, 1), (P62 - 1, P62 - 1), (P62 - 1, 2)];
+    for_all(
+        "to_montgomery and montgomery_mul agree with F62",
+        edge_cases,
+        || (rng.gen_range(0..P62), rng.gen_range(0..P62)),
+        |(a, b)| {
+            let (fa, fb) = (F62::from(*a), F62::from(*b));
+            let (ma, mb) = (to_montgomery(*a, P62), to_montgomery(*b, P62));
+            ma == montgomery_form(fa)
+                && montgomery_mul(ma, mb, P62, p_inv) == montgomery_form(fa * fb)
+                && redc(ma as u128, P62, p_inv) == *a
+        },
+    );
+}
+
+// euler_legendre agrees with `Field::legendre` and is multiplicative, over F_89 and Fq
+pub fn legendre_symbols() {
+    let mut rng = seed::rng();
+    let symbol = |character: ark_ff::LegendreSymbol| match character {
+        ark_ff::LegendreSymbol::Zero => 0,
+        ark_ff::LegendreSymbol::QuadraticResidue => 1,
+        ark_ff::LegendreSymbol::QuadraticNonResidue => -1,
+    };
+    for_all(
+        "euler_legendre agrees with Field::legendre over F_89",
+        (0..89).map(F::from),
+        || F::rand(&mut rng),
+        |a| euler_legendre(*a) == symbol(a.legendre()),
+    );
+    let edge_cases = [Fq::zero(), Fq::one(), -Fq::one(), Fq::from(7), -Fq::from(7)];
+    for_all(
+        "euler_legendre agrees with Field::legendre over Fq",
+        edge_cases,
+        || Fq::rand(&mut rng),
+        |a| euler_legendre(*a) == symbol(a.legendre()),
+    );
+    for_all(
+        "(ab | p) = (a | p)(b | p) over F_89",
+        [
+            (F::zero(), F::from(3)),
+            (F::from(3), F::from(3)),
+            (F::from(3), F::from(9)),
+        ],
+        || (F::rand(&mut rng), F::rand(&mut rng)),
+        |(a, b)| euler_legendre(*a * b) == euler_legendre(*a) * euler_legendre(*b),
+    );
+    for_all(
+        "(ab | p) = (a | p)(b | p) over Fq",
+        [(-Fq::one(), -Fq::one()), (Fq::zero(), -Fq::one())],
+        || (Fq::rand(&mut rng), Fq::rand(&mut rng)),
+        |(a, b)| euler_legendre(*a * b) == euler_legendre(*a) * euler_legendre(*b),
+    );
+}
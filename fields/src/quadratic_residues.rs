@@ -0,0 +1,13 @@
+use crate::*;
+
+// The Legendre symbol (a | p) is 0 for a = 0, 1 for the non-zero squares of F_p and -1 for the others
+// - Euler's criterion: F_p* is cyclic of even order p - 1, and a = g^k is a square iff k is even, iff
+//   a^((p - 1) / 2) = 1. Otherwise a^((p - 1) / 2) = -1, the only other square root of 1
+// - the non-zero squares are the even powers of a generator: (p - 1) / 2 of them, 44 in F_89
+// - the symbol is multiplicative: (ab | p) = (a | p)(b | p), since g^k g^l is a square iff k + l is even
+// - x is the x-coordinate of a point of y^2 = x^3 + 7 iff x^3 + 7 is a square: (x^3 + 7 | p) = 1 gives the two
+//   points (x, y) and (x, -y), 0 the single point (x, 0), -1 none
+// `PrimeField::MODULUS_MINUS_ONE_DIV_TWO` is (p - 1) / 2, as the big integer `Field::pow` takes
+
+// the x-coordinates of Q13, after the 0, 1 and 5 of Q4 of ff-ec
+pub const X_CANDIDATES: Range<u64> = 0..32;
@@ -0,0 +1,28 @@
+// Modular reduction with integer operations only, for a prime p of 62 bits: the product of two residues
+// is a u128 below p^2, and `%` on a u128 is a slow division
+// - Barrett: precompute mu = floor(2^(2k) / p), k the bit length of p. Then q = ((x >> (k - 1)) mu) >> (k + 1)
+//   is floor(x / p) or at most 2 less: x - qp is the residue after at most two subtractions of p
+// - Montgomery: work on aR mod p with R = 2^64, the Montgomery form of a. REDC(T) = T R^-1 mod p adds to T the
+//   multiple mp of p which clears its low 64 bits, m = T (-p^-1) mod 2^64, then shifts: (T + mp) / R < 2p
+//   The product of aR and bR is abR^2, and its REDC abR: the form is stable under multiplication
+// `Fp64<MontBackend<_, 1>>` of arkworks is exactly that: its `.0 .0[0]` is the Montgomery form, and its
+// `MontConfig` carries INV = -p^-1 mod 2^64 and R2 = R^2 mod p, to enter the form with one REDC
+
+pub const P62: u64 = 4611686018427387847; // 2^62 - 57
+
+mod f62 {
+    #![allow(non_local_definitions)]
+    use ark_ff::{Fp64, MontBackend, MontConfig};
+
+    #[derive(MontConfig)]
+    #[modulus = "4611686018427387847"]
+    #[generator = "6"]
+    pub struct F62Config;
+    pub type F62 = Fp64<MontBackend<F62Config, 1>>;
+}
+pub use f62::{F62Config, F62};
+
+// the Montgomery form arkworks stores for x
+pub fn montgomery_form(x: F62) -> u64 {
+    x.0 .0[0]
+}
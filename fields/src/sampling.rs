@@ -0,0 +1,69 @@
+use crate::*;
+use rand::RngCore;
+
+// Sampling a uniform field element from the bytes of a random generator
+// - reducing n random bytes modulo p: the 2^(8n) values do not split evenly into p classes, the
+//   2^(8n) mod p smallest residues get one preimage more than the others. The bias is about p / 2^(8n):
+//   blatant with one byte for F_89, invisible with 16 more bytes than p needs
+// - rejection sampling: draw as many bits as p has, and start over when the candidate is p or more.
+//   Every accepted value is equally likely, and since p has exactly that many bits, a draw is accepted
+//   with probability p / 2^bits > 1/2: less than 2 draws on average
+// A chi-square test counts the samples of each value of a small field: when the samples are uniform, it follows
+// a chi-square law with p - 1 degrees of freedom, whose mean is p - 1
+
+// the chi-square statistic of `samples` elements of a small prime field against the uniform distribution
+pub fn chi_square<F: PrimeField>(samples: usize, mut sample: impl FnMut() -> F) -> f64 {
+    assert!(F::MODULUS_BIT_SIZE <= 20, "one counter per field element");
+    let p = F::MODULUS.as_ref()[0] as usize;
+    let mut counts = vec![0u64; p];
+    for _ in 0..samples {
+        counts[sample().into_bigint().as_ref()[0] as usize] += 1;
+    }
+    let expected = samples as f64 / p as f64;
+    counts
+        .iter()
+        .map(|&count| (count as f64 - expected).powi(2) / expected)
+        .sum()
+}
+
+// the value a chi-square law with `degrees` degrees of freedom exceeds with probability 10^-6, by the
+// Wilson-Hilferty approximation: a uniform sampler fails the test once in a million runs
+pub fn chi_square_threshold(degrees: u64) -> f64 {
+    let k = degrees as f64;
+    let z = 4.75;
+    k * (1.0 - 2.0 / (9.0 * k) + z * (2.0 / (9.0 * k)).sqrt()).powi(3)
+}
+
+// a generator which counts the bytes it hands out, to measure the cost of a sampler
+pub struct CountingRng<R: RngCore> {
+    pub inner: R,
+    pub bytes: u64,
+}
+
+impl<R: RngCore> CountingRng<R> {
+    pub fn new(inner: R) -> CountingRng<R> {
+        CountingRng { inner, bytes: 0 }
+    }
+}
+
+impl<R: RngCore> RngCore for CountingRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.bytes += 4;
+        self.inner.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.bytes += 8;
+        self.inner.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.bytes += dest.len() as u64;
+        self.inner.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.bytes += dest.len() as u64;
+        self.inner.try_fill_bytes(dest)
+    }
+}
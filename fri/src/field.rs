@@ -0,0 +1,80 @@
+use crate::*;
+
+// The Goldilocks field, p = 2^64 - 2^32 + 1, used by Plonky2 and other STARKs
+// p - 1 = 2^32 (2^32 - 1): the multiplicative group has subgroups of order 2^k for every k <= 32,
+// so polynomials can be evaluated on them (and interpolated) with radix-2 FFTs, a.k.a. NTTs
+// Elements fit in a u64, which makes the arithmetic fast on 64-bit CPUs
+
+pub mod goldilocks {
+    #![allow(non_local_definitions, unexpected_cfgs)] // lints triggered by the code of the derive macro
+    use ark_ff::{Fp64, MontBackend, MontConfig};
+
+    #[derive(MontConfig)]
+    #[modulus = "18446744069414584321"]
+    #[generator = "7"]
+    pub struct GoldilocksConfig;
+    pub type F = Fp64<MontBackend<GoldilocksConfig, 1>>;
+}
+pub use goldilocks::F;
+
+// a generator of the subgroup of order n, a power of 2
+pub fn root_of_unity(n: usize) -> F {
+    assert!(n.is_power_of_two());
+    F::get_root_of_unity(n as u64).unwrap()
+}
+
+// the subgroup of order n: 1, w, w^2, ..., w^(n-1)
+pub fn domain(n: usize) -> Vec<F> {
+    let omega = root_of_unity(n);
+    let mut points = Vec::with_capacity(n);
+    let mut x = F::one();
+    for _ in 0..n {
+        points.push(x);
+        x *= omega;
+    }
+    points
+}
+
+// Horner's rule, quadratic when used on a whole domain: the reference the NTT is checked against
+pub fn evaluate(coefficients: &[F], x: F) -> F {
+    coefficients
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, c| acc * x + c)
+}
+
+// the degree of the polynomial, None for the zero polynomial
+pub fn degree(coefficients: &[F]) -> Option<usize> {
+    coefficients.iter().rposition(|c| !c.is_zero())
+}
+
+// Goldilocks by hand, on canonical u64 in [0, p)
+// Montgomery multiplication works for any modulus, but the shape of p allows a cheaper reduction of the
+// 128-bit product x = x_lo + 2^64 x_hi, with x_hi = x_hi_lo + 2^32 x_hi_hi:
+//   2^64 = 2^32 - 1 (mod p) and 2^96 = -1 (mod p)
+// so x = x_lo - x_hi_hi + (2^32 - 1) x_hi_lo (mod p): one subtraction, one 32x32-bit product and one addition,
+// no division and no conversion to or from Montgomery form
+
+pub const GOLDILOCKS: u64 = 0xffff_ffff_0000_0001;
+// 2^64 mod p, the correction for a carry out of 64 bits
+pub const EPSILON: u64 = 0xffff_ffff;
+
+pub fn to_canonical(x: &F) -> u64 {
+    x.into_bigint().0[0]
+}
+
+pub fn goldilocks_mul(a: u64, b: u64) -> u64 {
+    crate::reduce128(a as u128 * b as u128)
+}
+
+// iterates x <- x * y + c, the workload of the benchmark, with the arithmetic of ark-ff
+pub fn mul_add_chain_ark(x: F, y: F, c: F, iterations: usize) -> F {
+    (0..iterations).fold(x, |x, _| std::hint::black_box(x * y + c))
+}
+
+// the same with the hand-written arithmetic
+pub fn mul_add_chain_goldilocks(x: u64, y: u64, c: u64, iterations: usize) -> u64 {
+    (0..iterations).fold(x, |x, _| {
+        std::hint::black_box(crate::goldilocks_add(goldilocks_mul(x, y), c))
+    })
+}
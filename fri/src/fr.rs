@@ -0,0 +1,49 @@
+use runner::lang::{Catalog, Lang, Statements};
+
+pub const CATALOG: Catalog = Catalog {
+    lang: Lang::Fr,
+    statements: Statements::Table(&[
+        ("Q1", "écrire `ntt`, qui évalue un polynôme sur le sous-groupe engendré par omega, d'ordre le nombre\nde coefficients, et `intt`, qui l'interpole en retour\nUtiliser la FFT récursive de Cooley-Tukey : p(X) = p_e(X^2) + X p_o(X^2), et p_e, p_o sont évalués sur le\nsous-groupe engendré par omega^2, deux fois plus petit"),
+        ("Q2", "écrire `rs_encode`, le mot de code de Reed-Solomon d'un polynôme de degré < k : ses évaluations sur le\nsous-groupe d'ordre N = k * blowup\nDeux mots de code différents coïncident sur au plus k - 1 entrées (leur différence a au plus k - 1 racines), donc\nils diffèrent sur plus de 1 - 1/blowup d'entre elles"),
+        ("Q3", "écrire `fold`, la couche repliée f'(x^2) = (f(x) + f(-x)) / 2 + beta (f(x) - f(-x)) / (2 x),\npour une couche évaluée sur le sous-groupe engendré par omega\nReplier le mot de code de p donne le mot de code de p_e + beta p_o, avec le même blowup"),
+        ("Q4", "écrire `fri_verify`, qui rejoue la transcription et vérifie :\n- la forme de la preuve : une racine par repli sauf le dernier, `blowup` entrées dans la couche finale, et\nchaque requête ouvrant chaque couche\n- que la couche finale est une constante, le mot de code d'un polynôme de degré 0\n- pour chaque requête, les chemins de Merkle des deux ouvertures de chaque couche, et que replier les deux valeurs donne\nla valeur ouverte dans la couche suivante (la couche finale après le dernier repli)"),
+        ("Q5", "un prouveur tricheur détient un mot dont une fraction delta des entrées diffère d'un mot de code : jeter un\nœil à `fri_prove_cheating`\nUne requête l'attrape quand l'une des deux entrées qu'elle ouvre dans la première couche a été modifiée : il passe une\nrequête avec probabilité (1 - delta)^2\nÉcrire `queries_for_security`, le nombre de requêtes qu'il passe avec probabilité au plus 2^-bits"),
+        ("Q6", "écrire `reduce128`, qui réduit un entier de 128 bits modulo le premier de Goldilocks avec l'astuce du\nmodule `field`, et `goldilocks_add`, renvoyant toutes deux des valeurs canoniques dans [0, p)\nAttention aux retenues : `overflowing_add` et `overflowing_sub` disent quand elles se produisent"),
+        ("Q7", "rien à écrire, l'arithmétique écrite à la main face au backend de Montgomery générique"),
+        ("Q8", "la `ntt` récursive alloue au moins un vecteur dans chacun de ses 2n - 1 appels : écrire `ntt_in_place`,\nla FFT itérative qui écrase son entrée, avec au moins ALLOCATION_FACTOR fois moins d'allocations\nPermuter les coefficients dans l'ordre à bits inversés, puis faire log n tours de papillons\n(a, b) <- (a + w b, a - w b) sur des blocs de taille 2, 4, ..., n\nLes allocations sont comptées avec la feature `memprofile` de ce chapitre, active par défaut"),
+    ]),
+    hints: &[
+        (
+            "Q1",
+            &[
+                "Décomposer p(X) = p_e(X^2) + X p_o(X^2) selon ses coefficients pairs et impairs",
+                "Les carrés du sous-groupe engendré par omega forment le sous-groupe engendré par omega^2, deux fois plus petit",
+                "Avec E et O les NTT de p_e et p_o sur omega^2 : p(omega^i) = E_i + omega^i O_i et p(omega^(i + n/2)) = E_i - omega^i O_i",
+            ],
+        ),
+        (
+            "Q3",
+            &[
+                "x et -x sont tous deux dans le domaine, et ont le même carré",
+                "Dans un sous-groupe d'ordre n engendré par omega, -omega^i = omega^(i + n/2)",
+                "Pour i < n / 2, avec x = omega^i : f'(x^2) = (f[i] + f[i + n/2]) / 2 + beta (f[i] - f[i + n/2]) / (2 x)",
+            ],
+        ),
+        (
+            "Q6",
+            &[
+                "p = 2^64 - 2^32 + 1 : 2^64 = 2^32 - 1 mod p et 2^96 = -1 mod p",
+                "Décomposer x = x_lo + 2^64 (x_hi_lo + 2^32 x_hi_hi) avec x_hi_lo et x_hi_hi sur 32 bits",
+                "x = x_lo - x_hi_hi + x_hi_lo (2^32 - 1) mod p : gérer l'emprunt et la retenue, puis soustraire p une fois si besoin",
+            ],
+        ),
+        (
+            "Q8",
+            &[
+                "La FFT itérative exécute la récursion de bas en haut, dans la même tranche",
+                "Après avoir permuté les entrées dans l'ordre à bits inversés de leurs indices, les papillons de chaque niveau travaillent sur des blocs contigus",
+                "Pour size = 2, 4, ..., n, avec w une racine de l'unité d'ordre size : (a, b) <- (a + w^j b, a - w^j b) sur les moitiés de chaque bloc",
+            ],
+        ),
+    ],
+};
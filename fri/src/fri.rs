@@ -0,0 +1,188 @@
+use crate::*;
+use sha2::{Digest, Sha256};
+
+// FRI (Fast Reed-Solomon Interactive oracle proof of proximity, Ben-Sasson, Bentov, Horesh, Riabzev), the
+// low-degree test at the heart of STARKs
+// The prover commits to a word f of length N, its evaluations on the subgroup of order N, and convinces the
+// verifier that f is (close to) a polynomial of degree < k, with k = N / blowup, after reading only a few
+// entries of f
+//
+// Folding: split f(X) = f_e(X^2) + X f_o(X^2) into its even and odd parts, then for a random beta
+//   f'(Y) = f_e(Y) + beta f_o(Y)
+// has half the degree of f, and its evaluations on the subgroup of order N / 2 (the squares) come from
+// two evaluations of f each:
+//   f'(x^2) = (f(x) + f(-x)) / 2 + beta (f(x) - f(-x)) / (2 x)
+// With w of order N, -w^i = w^(i + N/2): entry i of the folded layer depends on entries i and i + N/2 of f
+//
+// Commit phase: the prover commits to each layer with a Merkle tree, and gets beta from the transcript,
+// until the layer has `blowup` entries: it should then be a constant, sent in the clear
+// Query phase: for random indices i, the prover opens every layer at i and i + N/2 (reduced modulo the
+// size of the layer), and the verifier checks that folding the two values gives the next layer
+// Both phases are non-interactive: beta and the indices are drawn from the transcript, which the verifier
+// replays from the roots and the final layer
+//
+// A word far from every polynomial of degree < k cannot fold into a constant: the prover has to lie in
+// some layer, and each query catches the lie with a probability that grows with the distance
+
+pub const TRANSCRIPT_LABEL: &[u8] = b"FRI low-degree test";
+
+pub struct FriParams {
+    pub degree_bound: usize, // k, a power of 2
+    pub blowup: usize,       // N / k, the inverse of the rate of the code
+    pub queries: usize,
+}
+
+impl FriParams {
+    pub fn codeword_length(&self) -> usize {
+        self.degree_bound * self.blowup
+    }
+}
+
+// the two values the next layer is folded from, at `index` and `index + N/2` for a layer of size N
+pub struct LayerOpening {
+    pub low: F,
+    pub high: F,
+    pub low_path: Vec<Hash>,
+    pub high_path: Vec<Hash>,
+}
+
+pub struct FriProof {
+    pub layer_roots: Vec<Hash>, // the first root is the commitment to the codeword
+    pub final_layer: Vec<F>,
+    pub queries: Vec<Vec<LayerOpening>>, // for each query, the openings of every committed layer
+}
+
+impl FriProof {
+    pub fn size(&self) -> usize {
+        let field = 8;
+        let hash = 32;
+        self.layer_roots.len() * hash
+            + self.final_layer.len() * field
+            + self
+                .queries
+                .iter()
+                .flatten()
+                .map(|opening| {
+                    2 * field + (opening.low_path.len() + opening.high_path.len()) * hash
+                })
+                .sum::<usize>()
+    }
+}
+
+// Fiat-Shamir: a SHA-256 chain over everything the prover sent so far
+pub struct Transcript {
+    state: Hash,
+}
+
+impl Transcript {
+    pub fn new(label: &[u8]) -> Transcript {
+        Transcript {
+            state: Sha256::digest(label).into(),
+        }
+    }
+
+    pub fn append_root(&mut self, root: &Hash) {
+        self.state = Sha256::new()
+            .chain_update(self.state)
+            .chain_update(root)
+            .finalize()
+            .into();
+    }
+
+    pub fn append_field_elements(&mut self, elements: &[F]) {
+        let mut bytes = Vec::new();
+        elements.serialize_compressed(&mut bytes).unwrap();
+        self.state = Sha256::new()
+            .chain_update(self.state)
+            .chain_update(bytes)
+            .finalize()
+            .into();
+    }
+
+    fn squeeze(&mut self) -> Hash {
+        self.state = Sha256::new()
+            .chain_update(self.state)
+            .chain_update(b"challenge")
+            .finalize()
+            .into();
+        self.state
+    }
+
+    pub fn challenge(&mut self) -> F {
+        F::from_le_bytes_mod_order(&self.squeeze())
+    }
+
+    // in [0, n), n a power of 2
+    pub fn index(&mut self, n: usize) -> usize {
+        let bytes = self.squeeze();
+        usize::from_le_bytes(bytes[..8].try_into().unwrap()) % n
+    }
+}
+
+// the layer of size N, and the Merkle tree committing to it
+pub struct CommittedLayer {
+    pub values: Vec<F>,
+    pub tree: MerkleTree,
+}
+
+impl CommittedLayer {
+    pub fn new(values: Vec<F>) -> CommittedLayer {
+        let tree = MerkleTree::new(&values);
+        CommittedLayer { values, tree }
+    }
+
+    // `index` is reduced modulo N/2
+    pub fn open(&self, index: usize) -> LayerOpening {
+        let half = self.values.len() / 2;
+        let index = index % half;
+        LayerOpening {
+            low: self.values[index],
+            high: self.values[index + half],
+            low_path: self.tree.open(index),
+            high_path: self.tree.open(index + half),
+        }
+    }
+}
+
+// The prover: `folded_from` is the word whose folds are committed after the first layer, `word` itself for
+// an honest prover
+fn prove(word: &[F], folded_from: &[F], params: &FriParams) -> FriProof {
+    assert_eq!(word.len(), params.codeword_length());
+    let mut transcript = Transcript::new(TRANSCRIPT_LABEL);
+    let mut layers = vec![CommittedLayer::new(word.to_vec())];
+    let mut folded = folded_from.to_vec();
+    let mut omega = root_of_unity(word.len());
+    while folded.len() > params.blowup {
+        transcript.append_root(&layers.last().unwrap().tree.root());
+        let beta = transcript.challenge();
+        folded = fold(&folded, beta, omega);
+        omega.square_in_place();
+        if folded.len() > params.blowup {
+            layers.push(CommittedLayer::new(folded.clone()));
+        }
+    }
+    transcript.append_field_elements(&folded);
+    let queries = (0..params.queries)
+        .map(|_| {
+            let index = transcript.index(word.len());
+            layers.iter().map(|layer| layer.open(index)).collect()
+        })
+        .collect();
+    FriProof {
+        layer_roots: layers.iter().map(|layer| layer.tree.root()).collect(),
+        final_layer: folded,
+        queries,
+    }
+}
+
+pub fn fri_prove(codeword: &[F], params: &FriParams) -> FriProof {
+    prove(codeword, codeword, params)
+}
+
+// A prover cheating on a word that is not a codeword, but close to one: `honest` with a fraction of its
+// entries changed
+// It commits to the word, but from the first fold on, it folds `honest` instead: every layer after the first
+// is a genuine low-degree word, and only the queries hitting a changed entry of the first layer detect the lie
+pub fn fri_prove_cheating(word: &[F], honest: &[F], params: &FriParams) -> FriProof {
+    prove(word, honest, params)
+}
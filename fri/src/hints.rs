@@ -0,0 +1,42 @@
+use runner::hints::Hints;
+use runner::lang::Catalog;
+
+pub const CATALOGS: &[Catalog] = &[
+    Catalog::english(HINTS, include_str!("main.rs")),
+    crate::fr::CATALOG,
+];
+
+pub const HINTS: Hints = &[
+    (
+        "Q1",
+        &[
+            "Split p(X) = p_e(X^2) + X p_o(X^2) into its even and odd coefficients",
+            "The squares of the subgroup generated by omega form the subgroup generated by omega^2, half the size",
+            "With E and O the NTTs of p_e and p_o on omega^2: p(omega^i) = E_i + omega^i O_i and p(omega^(i + n/2)) = E_i - omega^i O_i",
+        ],
+    ),
+    (
+        "Q3",
+        &[
+            "x and -x are both in the domain, and have the same square",
+            "In a subgroup of order n generated by omega, -omega^i = omega^(i + n/2)",
+            "For i < n / 2, with x = omega^i: f'(x^2) = (f[i] + f[i + n/2]) / 2 + beta (f[i] - f[i + n/2]) / (2 x)",
+        ],
+    ),
+    (
+        "Q6",
+        &[
+            "p = 2^64 - 2^32 + 1: 2^64 = 2^32 - 1 mod p and 2^96 = -1 mod p",
+            "Split x = x_lo + 2^64 (x_hi_lo + 2^32 x_hi_hi) with x_hi_lo and x_hi_hi on 32 bits",
+            "x = x_lo - x_hi_hi + x_hi_lo (2^32 - 1) mod p: handle the borrow and the carry, then subtract p once if needed",
+        ],
+    ),
+    (
+        "Q8",
+        &[
+            "The iterative FFT runs the recursion bottom-up, all in the same slice",
+            "After permuting the entries to the bit-reversed order of their indexes, the butterflies of each level work on contiguous blocks",
+            "For size = 2, 4, ..., n, with w a root of unity of order size: (a, b) <- (a + w^j b, a - w^j b) on the halves of each block",
+        ],
+    ),
+];
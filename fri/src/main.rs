@@ -0,0 +1,454 @@
+use ark_ff::{FftField, Field, One, PrimeField, Zero};
+use ark_serialize::CanonicalSerialize;
+use ark_std::UniformRand;
+use field::*;
+use fri::*;
+use merkle::*;
+use rand::{seq::index::sample, Rng};
+use rand_chacha::ChaChaRng;
+use runner::{memory, Runner};
+use std::time::Instant;
+
+mod field;
+mod fr;
+mod fri;
+mod hints;
+mod merkle;
+mod properties;
+
+const DEGREE_BOUND: usize = 64;
+const BLOWUP: usize = 8;
+const QUERIES: usize = 32;
+const ALLOCATION_FACTOR: u64 = 100;
+
+fn main() {
+    let runner = Runner::start(env!("CARGO_PKG_NAME"), hints::CATALOGS);
+    let mut rng = seed::rng();
+    let random_polynomial = |degree_bound: usize, rng: &mut ChaChaRng| -> Vec<F> {
+        (0..degree_bound).map(|_| F::rand(rng)).collect()
+    };
+
+    // Take a look at module `field`
+    // Q1: write `ntt`, which evaluates a polynomial on the subgroup generated by omega, of order the number
+    // of coefficients, and `intt`, which interpolates it back
+    // Use the recursive Cooley-Tukey FFT: p(X) = p_e(X^2) + X p_o(X^2), and p_e, p_o are evaluated on the
+    // subgroup generated by omega^2, half the size
+    runner.question("Q1");
+    let p = random_polynomial(16, &mut rng);
+    let omega = root_of_unity(16);
+    let evaluations = ntt(&p, omega);
+    assert_eq!(
+        evaluations,
+        domain(16)
+            .iter()
+            .map(|x| evaluate(&p, *x))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(intt(&evaluations, omega), p);
+    let p = random_polynomial(1 << 12, &mut rng);
+    let omega = root_of_unity(1 << 12);
+    assert_eq!(intt(&ntt(&p, omega), omega), p);
+    assert_eq!(ntt(&[F::from(5)], F::one()), vec![F::from(5)]);
+    properties::interpolation();
+
+    // Q2: write `rs_encode`, the Reed-Solomon codeword of a polynomial of degree < k: its evaluations on the
+    // subgroup of order N = k * blowup
+    // Two different codewords agree on at most k - 1 entries (their difference has at most k - 1 roots), so
+    // they differ on more than 1 - 1/blowup of them
+    runner.question("Q2");
+    let params = FriParams {
+        degree_bound: DEGREE_BOUND,
+        blowup: BLOWUP,
+        queries: QUERIES,
+    };
+    let n = params.codeword_length();
+    let p = random_polynomial(DEGREE_BOUND, &mut rng);
+    let codeword = rs_encode(&p, BLOWUP);
+    assert_eq!(codeword.len(), n);
+    assert_eq!(codeword[1], evaluate(&p, root_of_unity(n)));
+    assert!(degree(&intt(&codeword, root_of_unity(n))) < Some(DEGREE_BOUND));
+    let q = random_polynomial(DEGREE_BOUND, &mut rng);
+    let agreements = codeword
+        .iter()
+        .zip(rs_encode(&q, BLOWUP))
+        .filter(|(a, b)| **a == *b)
+        .count();
+    assert!(agreements < DEGREE_BOUND);
+
+    // Take a look at module `fri`
+    // Q3: write `fold`, the folded layer f'(x^2) = (f(x) + f(-x)) / 2 + beta (f(x) - f(-x)) / (2 x),
+    // for a layer evaluated on the subgroup generated by omega
+    // Folding the codeword of p gives the codeword of p_e + beta p_o, with the same blowup
+    runner.question("Q3");
+    let beta = F::rand(&mut rng);
+    let folded = fold(&codeword, beta, root_of_unity(n));
+    let even_odd: Vec<F> = p.chunks(2).map(|pair| pair[0] + beta * pair[1]).collect();
+    assert_eq!(folded, rs_encode(&even_odd, BLOWUP));
+    assert_eq!(fold(&folded, F::zero(), root_of_unity(n / 2)).len(), n / 4);
+
+    // Q4: write `fri_verify`, which replays the transcript and checks:
+    // - the shape of the proof: one root per fold but the last, `blowup` entries in the final layer, and
+    //   every query opening every layer
+    // - the final layer is a constant, the codeword of a polynomial of degree 0
+    // - for each query, the Merkle paths of both openings of every layer, and that folding the two values gives
+    //   the value opened in the next layer (the final layer after the last fold)
+    runner.question("Q4");
+    for degree_bound in [1, 2, DEGREE_BOUND / 2, DEGREE_BOUND] {
+        let codeword = rs_encode(&random_polynomial(degree_bound, &mut rng), n / degree_bound);
+        let proof = fri_prove(&codeword, &params);
+        assert!(fri_verify(&proof, &params));
+    }
+    let proof = fri_prove(&codeword, &params);
+    assert_eq!(proof.layer_roots.len(), 6);
+    assert_eq!(proof.layer_roots[0], MerkleTree::new(&codeword).root());
+    // a polynomial of degree k is one too many
+    let mut too_high = random_polynomial(DEGREE_BOUND + 1, &mut rng);
+    too_high.resize(n, F::zero());
+    let too_high = ntt(&too_high, root_of_unity(n));
+    assert!(!fri_verify(&fri_prove(&too_high, &params), &params));
+    // a random word, of degree N - 1
+    let random_word = random_polynomial(n, &mut rng);
+    assert!(!fri_verify(&fri_prove(&random_word, &params), &params));
+    // tampering with the proof of an honest codeword
+    let mut tampered = fri_prove(&codeword, &params);
+    tampered.queries[3][2].high += F::one();
+    assert!(!fri_verify(&tampered, &params));
+    let mut tampered = fri_prove(&codeword, &params);
+    tampered.final_layer.pop();
+    assert!(!fri_verify(&tampered, &params));
+    let mut tampered = fri_prove(&codeword, &params);
+    tampered.queries.pop();
+    assert!(!fri_verify(&tampered, &params));
+    println!(
+        "FRI proof for a word of {} entries and degree < {}: {} bytes with {} queries",
+        n,
+        DEGREE_BOUND,
+        proof.size(),
+        QUERIES
+    );
+
+    // Q5: a cheating prover holds a word with a fraction delta of its entries changed from a codeword: take a
+    // look at `fri_prove_cheating`
+    // A query catches it when one of the two entries it opens in the first layer was changed: it passes a
+    // query with probability (1 - delta)^2
+    // Write `queries_for_security`, the number of queries it passes with probability at most 2^-bits
+    runner.question("Q5");
+    let delta = 1.0 / 8.0;
+    assert_eq!(queries_for_security(delta, 100), 260);
+    assert_eq!(queries_for_security(0.5, 128), 64);
+    let corrupt = |codeword: &[F], rng: &mut ChaChaRng| -> Vec<F> {
+        let mut word = codeword.to_vec();
+        for i in sample(rng, n, (delta * n as f64) as usize) {
+            word[i] = F::rand(rng);
+        }
+        word
+    };
+    for _ in 0..20 {
+        let word = corrupt(&codeword, &mut rng);
+        assert!(!fri_verify(
+            &fri_prove_cheating(&word, &codeword, &params),
+            &params
+        ));
+    }
+    // with only a few queries, the cheat passes often enough to measure how often
+    let few_queries = FriParams {
+        degree_bound: DEGREE_BOUND,
+        blowup: BLOWUP,
+        queries: 4,
+    };
+    // the standard deviation of the measure is sqrt(p (1 - p) / trials) < 0.016: 0.1 is over 6 of them
+    let trials = 1000;
+    let accepted = (0..trials)
+        .filter(|_| {
+            let word = corrupt(&codeword, &mut rng);
+            fri_verify(
+                &fri_prove_cheating(&word, &codeword, &few_queries),
+                &few_queries,
+            )
+        })
+        .count();
+    let measured = accepted as f64 / trials as f64;
+    let expected = (1.0 - delta).powi(2 * few_queries.queries as i32);
+    assert!((measured - expected).abs() < 0.1);
+    println!(
+        "A word 1/8 away from the code passes {} queries in {:.0}% of the runs, {:.0}% expected",
+        few_queries.queries,
+        100.0 * measured,
+        100.0 * expected
+    );
+
+    // Back to module `field`: the field of this chapter is defined with `MontConfig`, like every field of the
+    // course, and ark-ff multiplies in Montgomery form whatever the modulus
+    // Q6: write `reduce128`, which reduces a 128-bit integer modulo the Goldilocks prime with the trick of
+    // module `field`, and `goldilocks_add`, both returning canonical values in [0, p)
+    // Beware of the carries: `overflowing_add` and `overflowing_sub` tell when they happen
+    runner.question("Q6");
+    assert_eq!(GOLDILOCKS, F::MODULUS.0[0]);
+    assert_eq!(reduce128(0), 0);
+    assert_eq!(reduce128(GOLDILOCKS as u128), 0);
+    assert_eq!(reduce128(1 << 64), EPSILON);
+    assert_eq!(reduce128(1 << 96), GOLDILOCKS - 1);
+    assert_eq!(
+        reduce128(u128::MAX),
+        (u128::MAX % GOLDILOCKS as u128) as u64
+    );
+    assert_eq!(goldilocks_add(GOLDILOCKS - 1, 1), 0);
+    assert_eq!(
+        goldilocks_add(GOLDILOCKS - 1, GOLDILOCKS - 1),
+        GOLDILOCKS - 2
+    );
+    for _ in 0..10000 {
+        let (a, b) = (F::rand(&mut rng), F::rand(&mut rng));
+        let (x, y) = (to_canonical(&a), to_canonical(&b));
+        assert_eq!(goldilocks_mul(x, y), to_canonical(&(a * b)));
+        assert_eq!(goldilocks_add(x, y), to_canonical(&(a + b)));
+        let wide = rng.gen::<u128>();
+        assert_eq!(reduce128(wide), (wide % GOLDILOCKS as u128) as u64);
+    }
+
+    // Q7: nothing to write, the hand-written arithmetic against the generic Montgomery backend
+    runner.question("Q7");
+    let (x, y, c) = (F::rand(&mut rng), F::rand(&mut rng), F::rand(&mut rng));
+    let iterations = 10_000_000;
+    let start = Instant::now();
+    let ark = mul_add_chain_ark(x, y, c, iterations);
+    let ark_time = start.elapsed();
+    let start = Instant::now();
+    let by_hand = mul_add_chain_goldilocks(
+        to_canonical(&x),
+        to_canonical(&y),
+        to_canonical(&c),
+        iterations,
+    );
+    let by_hand_time = start.elapsed();
+    assert_eq!(by_hand, to_canonical(&ark));
+    println!(
+        "{} multiply-adds in Goldilocks: {:.2?} with Montgomery, {:.2?} with the specialized reduction",
+        iterations, ark_time, by_hand_time
+    );
+
+    // Q8: the recursive `ntt` allocates at least one vector in each of its 2n - 1 calls: write `ntt_in_place`,
+    // the iterative FFT which overwrites its input, with at least ALLOCATION_FACTOR times fewer allocations
+    // Permute the coefficients to the bit-reversed order, then run log n rounds of butterflies
+    // (a, b) <- (a + w b, a - w b) on blocks of size 2, 4, ..., n
+    // The allocations are counted with the `memprofile` feature of this chapter, on by default
+    runner.question("Q8");
+    let p = random_polynomial(1 << 12, &mut rng);
+    let omega = root_of_unity(1 << 12);
+    let (expected, recursive) = memory::measure(|| ntt(&p, omega));
+    let mut values = p.clone();
+    let ((), in_place) = memory::measure(|| ntt_in_place(&mut values, omega));
+    assert_eq!(values, expected);
+    let mut values = vec![F::from(5)];
+    ntt_in_place(&mut values, F::one());
+    assert_eq!(values, vec![F::from(5)]);
+    if memory::ENABLED {
+        println!(
+            "NTT of 2^12 points: {} allocations and a peak of {} recursively, {} allocations and a peak of {} in place",
+            recursive.count,
+            memory::format_bytes(recursive.peak),
+            in_place.count,
+            memory::format_bytes(in_place.peak)
+        );
+        assert!(in_place.count * ALLOCATION_FACTOR <= recursive.count);
+    } else {
+        println!("Build with the memprofile feature to check the allocations of `ntt_in_place`");
+    }
+
+    println!("Good job!");
+    runner.finish();
+}
+
+fn ntt(coefficients: &[F], omega: F) -> Vec<F> {
+    // SOLUTION-BEGIN
+    let n = coefficients.len();
+    if n == 1 {
+        return coefficients.to_vec();
+    }
+    let even: Vec<F> = coefficients.iter().step_by(2).copied().collect();
+    let odd: Vec<F> = coefficients.iter().skip(1).step_by(2).copied().collect();
+    let omega_squared = omega.square();
+    let (even, odd) = (ntt(&even, omega_squared), ntt(&odd, omega_squared));
+    let mut evaluations = vec![F::zero(); n];
+    let mut x = F::one();
+    for i in 0..n / 2 {
+        // p(-x) = p_e(x^2) - x p_o(x^2), and -w^i = w^(i + n/2)
+        let t = x * odd[i];
+        evaluations[i] = even[i] + t;
+        evaluations[i + n / 2] = even[i] - t;
+        x *= omega;
+    }
+    evaluations
+    // SOLUTION-END
+}
+
+fn intt(evaluations: &[F], omega: F) -> Vec<F> {
+    // SOLUTION-BEGIN
+    // the inverse DFT is the DFT with omega^-1, divided by n
+    let n_inverse = F::from(evaluations.len() as u64).inverse().unwrap();
+    ntt(evaluations, omega.inverse().unwrap())
+        .into_iter()
+        .map(|c| c * n_inverse)
+        .collect()
+    // SOLUTION-END
+}
+
+fn ntt_in_place(values: &mut [F], omega: F) {
+    // SOLUTION-BEGIN
+    let n = values.len();
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i
+            .reverse_bits()
+            .checked_shr(usize::BITS - bits)
+            .unwrap_or(0);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+    let mut size = 2;
+    while size <= n {
+        // a primitive root of unity of order size
+        let step = omega.pow([(n / size) as u64]);
+        for block in values.chunks_mut(size) {
+            let (low, high) = block.split_at_mut(size / 2);
+            let mut w = F::one();
+            for (a, b) in low.iter_mut().zip(high) {
+                let t = w * *b;
+                (*a, *b) = (*a + t, *a - t);
+                w *= step;
+            }
+        }
+        size *= 2;
+    }
+    // SOLUTION-END
+}
+
+fn rs_encode(coefficients: &[F], blowup: usize) -> Vec<F> {
+    // SOLUTION-BEGIN
+    let n = coefficients.len() * blowup;
+    let mut padded = coefficients.to_vec();
+    padded.resize(n, F::zero());
+    ntt(&padded, root_of_unity(n))
+    // SOLUTION-END
+}
+
+fn fold(layer: &[F], beta: F, omega: F) -> Vec<F> {
+    // SOLUTION-BEGIN
+    let half = layer.len() / 2;
+    let two_inverse = F::from(2).inverse().unwrap();
+    let omega_inverse = omega.inverse().unwrap();
+    let mut x_inverse = F::one();
+    (0..half)
+        .map(|i| {
+            let (plus, minus) = (layer[i], layer[i + half]);
+            let folded = (plus + minus + beta * (plus - minus) * x_inverse) * two_inverse;
+            x_inverse *= omega_inverse;
+            folded
+        })
+        .collect()
+    // SOLUTION-END
+}
+
+fn fri_verify(proof: &FriProof, params: &FriParams) -> bool {
+    // SOLUTION-BEGIN
+    let n = params.codeword_length();
+    let rounds = (n / params.blowup).trailing_zeros() as usize;
+    if proof.layer_roots.len() != rounds
+        || proof.final_layer.len() != params.blowup
+        || proof.queries.len() != params.queries
+    {
+        return false;
+    }
+    let mut transcript = Transcript::new(TRANSCRIPT_LABEL);
+    let betas: Vec<F> = proof
+        .layer_roots
+        .iter()
+        .map(|root| {
+            transcript.append_root(root);
+            transcript.challenge()
+        })
+        .collect();
+    transcript.append_field_elements(&proof.final_layer);
+    if proof.final_layer.iter().any(|e| *e != proof.final_layer[0]) {
+        return false;
+    }
+    proof.queries.iter().all(|openings| {
+        let index = transcript.index(n);
+        if openings.len() != rounds {
+            return false;
+        }
+        let mut size = n;
+        let mut omega = root_of_unity(n);
+        for (round, opening) in openings.iter().enumerate() {
+            let half = size / 2;
+            let i = index % half;
+            let root = &proof.layer_roots[round];
+            if !merkle_verify(root, i, &opening.low, &opening.low_path)
+                || !merkle_verify(root, i + half, &opening.high, &opening.high_path)
+            {
+                return false;
+            }
+            let x_inverse = omega.pow([i as u64]).inverse().unwrap();
+            let folded = (opening.low
+                + opening.high
+                + betas[round] * (opening.low - opening.high) * x_inverse)
+                * F::from(2).inverse().unwrap();
+            // entry i of the next layer, of size `half`
+            let next = match openings.get(round + 1) {
+                Some(next) if i < half / 2 => next.low,
+                Some(next) => next.high,
+                None => proof.final_layer[i],
+            };
+            if folded != next {
+                return false;
+            }
+            size = half;
+            omega.square_in_place();
+        }
+        true
+    })
+    // SOLUTION-END
+}
+
+fn queries_for_security(delta: f64, bits: u32) -> usize {
+    // SOLUTION-BEGIN
+    // (1 - delta)^(2 q) <= 2^-bits
+    let bits_per_query = -2.0 * (1.0 - delta).log2();
+    (bits as f64 / bits_per_query).ceil() as usize
+    // SOLUTION-END
+}
+
+fn reduce128(x: u128) -> u64 {
+    // SOLUTION-BEGIN
+    let (x_lo, x_hi) = (x as u64, (x >> 64) as u64);
+    let (x_hi_hi, x_hi_lo) = (x_hi >> 32, x_hi & EPSILON);
+    let (mut t0, borrow) = x_lo.overflowing_sub(x_hi_hi);
+    if borrow {
+        // t0 wrapped around 2^64 = p + EPSILON: remove the EPSILON, x_lo < x_hi_hi < 2^32 so it cannot wrap again
+        t0 -= EPSILON;
+    }
+    let t1 = x_hi_lo * EPSILON;
+    let (mut result, carry) = t0.overflowing_add(t1);
+    if carry {
+        // result + 2^64 = result + EPSILON (mod p), and result < t1 < 2^64 - 2^32 so it fits
+        result += EPSILON;
+    }
+    if result >= GOLDILOCKS {
+        result -= GOLDILOCKS;
+    }
+    result
+    // SOLUTION-END
+}
+
+fn goldilocks_add(a: u64, b: u64) -> u64 {
+    // SOLUTION-BEGIN
+    let (sum, carry) = a.overflowing_add(b);
+    let (mut sum, carry) = sum.overflowing_add(if carry { EPSILON } else { 0 });
+    debug_assert!(!carry);
+    if sum >= GOLDILOCKS {
+        sum -= GOLDILOCKS;
+    }
+    sum
+    // SOLUTION-END
+}
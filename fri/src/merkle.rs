@@ -0,0 +1,79 @@
+use crate::*;
+use sha2::{Digest, Sha256};
+
+// A binary Merkle tree over field elements, one leaf per element, with SHA-256
+// Leaves and nodes are hashed with different prefixes (as in RFC 6962), so that a node cannot pass for a leaf
+
+pub type Hash = [u8; 32];
+
+pub struct MerkleTree {
+    pub layers: Vec<Vec<Hash>>, // leaves' hashes first, root last
+}
+
+pub fn hash_leaf(leaf: &F) -> Hash {
+    let mut bytes = Vec::new();
+    leaf.serialize_compressed(&mut bytes).unwrap();
+    Sha256::new()
+        .chain_update([0])
+        .chain_update(bytes)
+        .finalize()
+        .into()
+}
+
+pub fn hash_node(left: &Hash, right: &Hash) -> Hash {
+    Sha256::new()
+        .chain_update([1])
+        .chain_update(left)
+        .chain_update(right)
+        .finalize()
+        .into()
+}
+
+impl MerkleTree {
+    pub fn new(leaves: &[F]) -> MerkleTree {
+        assert!(leaves.len().is_power_of_two());
+        let mut layers = vec![leaves.iter().map(hash_leaf).collect::<Vec<_>>()];
+        while layers.last().unwrap().len() > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| hash_node(&pair[0], &pair[1]))
+                .collect();
+            layers.push(next);
+        }
+        MerkleTree { layers }
+    }
+
+    pub fn root(&self) -> Hash {
+        self.layers.last().unwrap()[0]
+    }
+
+    // the siblings on the path from the leaf to the root
+    pub fn open(&self, index: usize) -> Vec<Hash> {
+        let mut index = index;
+        let mut siblings = vec![];
+        for layer in &self.layers[..self.layers.len() - 1] {
+            siblings.push(layer[index ^ 1]);
+            index /= 2;
+        }
+        siblings
+    }
+}
+
+pub fn merkle_verify(root: &Hash, index: usize, leaf: &F, siblings: &[Hash]) -> bool {
+    if index >> siblings.len() != 0 {
+        return false;
+    }
+    let mut index = index;
+    let mut current = hash_leaf(leaf);
+    for sibling in siblings {
+        current = if index & 1 == 0 {
+            hash_node(&current, sibling)
+        } else {
+            hash_node(sibling, &current)
+        };
+        index /= 2;
+    }
+    current == *root
+}
@@ -0,0 +1,34 @@
+use crate::*;
+use runner::property::for_all;
+
+// Hidden property checks of `ntt` and `intt` against the naive evaluation of module `field`, on every size up
+// to 2^6 and hundreds of random polynomials, the zero and constant ones included
+// The polynomials come from their own generator, so that the instances of the next questions do not depend on it
+
+const MAX_LOG_SIZE: u32 = 6;
+
+pub fn interpolation() {
+    let mut rng = seed::rng();
+    let edge_cases = (0..=MAX_LOG_SIZE).flat_map(|log| {
+        let n = 1 << log;
+        [vec![F::zero(); n], vec![F::one(); n], {
+            let mut constant = vec![F::zero(); n];
+            constant[0] = F::from(7);
+            constant
+        }]
+    });
+    for_all(
+        "ntt evaluates on the subgroup of omega and intt interpolates back",
+        edge_cases,
+        || {
+            let n = 1 << rng.gen_range(0..=MAX_LOG_SIZE);
+            (0..n).map(|_| F::rand(&mut rng)).collect::<Vec<F>>()
+        },
+        |p| {
+            let n = p.len();
+            let naive: Vec<F> = domain(n).iter().map(|x| evaluate(p, *x)).collect();
+            let evaluations = ntt(p, root_of_unity(n));
+            evaluations == naive && intt(&evaluations, root_of_unity(n)) == *p
+        },
+    );
+}
@@ -0,0 +1,205 @@
+// Grades a student's fork in one command: `cargo run --release` from this directory runs every chapter with
+// `--report json`, and prints which questions pass, fail or were not reached, chapter by chapter, then the
+// totals. The exit status is 1 unless every question of every chapter passes
+//
+//   cargo run --release                          every chapter
+//   cargo run --release -- ff-ec schnorr         some of them
+//   cargo run --release -- --seed alice          the instance of a student (or TRAINING_SEED=alice)
+//   cargo run --release -- --student alice       the challenges of a student (or TRAINING_STUDENT=alice)
+//
+// A chapter is a directory next to this one whose `src/main.rs` calls `Runner::start`: its questions are those
+// of its report, the ones run and the ones not reached. The seed and the student, in any of the forms the seed
+// crate accepts, are handed to the runs in `TRAINING_SEED` and `TRAINING_STUDENT`. The runs set
+// `TRAINING_GRADE`, so that they leave the progress of the student alone (see the runner crate)
+// A chapter which does not build, or dies without a report, fails
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+
+// the questions per line of the scoreboard
+const COLUMNS: usize = 8;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Status {
+    Passed,
+    Failed(String),
+    NotReached,
+}
+
+struct Chapter {
+    name: String,
+    directory: PathBuf,
+}
+
+fn chapters(root: &Path) -> Vec<Chapter> {
+    let mut chapters: Vec<Chapter> = std::fs::read_dir(root)
+        .expect("cannot list the chapters")
+        .filter_map(|entry| {
+            let directory = entry.ok()?.path();
+            let source = std::fs::read_to_string(directory.join("src/main.rs")).ok()?;
+            let name = directory.file_name()?.to_string_lossy().into_owned();
+            // this crate names the call too, without making it
+            (source.contains("Runner::start(") && name != env!("CARGO_PKG_NAME"))
+                .then_some(Chapter { name, directory })
+        })
+        .collect();
+    chapters.sort_by(|a, b| a.name.cmp(&b.name));
+    chapters
+}
+
+// the string starting after an opening quote, unescaped, and what follows its closing quote
+fn json_string(s: &str) -> (String, &str) {
+    let mut unescaped = String::new();
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return (unescaped, &s[i + 1..]),
+            '\\' => match chars.next() {
+                Some((_, 'n')) => unescaped.push('\n'),
+                Some((_, 'u')) => {
+                    let code: String = (0..4)
+                        .filter_map(|_| chars.next())
+                        .map(|(_, c)| c)
+                        .collect();
+                    let c = u32::from_str_radix(&code, 16).ok().and_then(char::from_u32);
+                    unescaped.push(c.unwrap_or('?'));
+                }
+                Some((_, c)) => unescaped.push(c),
+                None => break,
+            },
+            c => unescaped.push(c),
+        }
+    }
+    (unescaped, "")
+}
+
+// the questions of the JSON report of the runner, which prints it on its last line
+fn parse_report(line: &str) -> Option<Vec<(String, Status)>> {
+    let (_, questions) = line.split_once("\"questions\": [")?;
+    let mut results = vec![];
+    for entry in questions.split("{\"id\": \"").skip(1) {
+        let (id, rest) = json_string(entry);
+        let status = if rest.starts_with(", \"passed\": true") {
+            Status::Passed
+        } else {
+            let message = match rest.split_once("\"message\": \"") {
+                Some((_, message)) => json_string(message).0,
+                None => String::new(),
+            };
+            Status::Failed(message)
+        };
+        results.push((id, status));
+    }
+    let (_, mut not_reached) = line.split_once("\"not_reached\": [")?;
+    while let Some((_, quoted)) = not_reached.split_once('"') {
+        let (id, rest) = json_string(quoted);
+        results.push((id, Status::NotReached));
+        not_reached = rest;
+    }
+    Some(results)
+}
+
+// every question of the chapter with its status, or why the run gave no report
+fn grade(chapter: &Chapter) -> Result<Vec<(String, Status)>, String> {
+    let mut command = Command::new("cargo");
+    command
+        .args([
+            "run",
+            "--release",
+            "--quiet",
+            "--",
+            runner::REPORT_OPTION,
+            "json",
+        ])
+        .current_dir(&chapter.directory)
+        .env(runner::GRADE_VARIABLE, "1");
+    if let Some(text) = seed::seed_text() {
+        command.env(seed::SEED_VARIABLE, text);
+    }
+    if let Some(student) = seed::challenge::student_id() {
+        command.env(seed::challenge::STUDENT_VARIABLE, student);
+    }
+    let output = command
+        .output()
+        .map_err(|error| format!("cannot run cargo: {}", error))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout.lines().last().and_then(parse_report).ok_or_else(|| {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        stderr
+            .lines()
+            .find(|line| line.starts_with("error"))
+            .or(stderr.lines().last())
+            .unwrap_or("no report")
+            .to_string()
+    })
+}
+
+fn main() -> ExitCode {
+    let selected = seed::args();
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let chapters: Vec<Chapter> = chapters(&root)
+        .into_iter()
+        .filter(|chapter| selected.is_empty() || selected.contains(&chapter.name))
+        .collect();
+    if chapters.is_empty() {
+        eprintln!("no such chapter: {}", selected.join(", "));
+        return ExitCode::FAILURE;
+    }
+
+    let (mut passed, mut total, mut complete) = (0, 0, 0);
+    let mut totals = vec![];
+    for chapter in &chapters {
+        println!("Grading {}...", chapter.name);
+        let (results, error) = match grade(chapter) {
+            Ok(results) => (results, None),
+            Err(reason) => (vec![], Some(reason)),
+        };
+        let chapter_passed = results.iter().filter(|(_, s)| *s == Status::Passed).count();
+        println!("{}: {}/{}", chapter.name, chapter_passed, results.len());
+        for row in results.chunks(COLUMNS) {
+            let cells: Vec<String> = row
+                .iter()
+                .map(|(id, status)| {
+                    let mark = match status {
+                        Status::Passed => "ok",
+                        Status::Failed(_) => "FAIL",
+                        Status::NotReached => "--",
+                    };
+                    format!("{:>4} {:<5}", id, mark)
+                })
+                .collect();
+            println!("  {}", cells.join(" ").trim_end());
+        }
+        match &error {
+            Some(reason) => println!("  no report: {}", reason),
+            None => {
+                for (id, status) in &results {
+                    if let Status::Failed(message) = status {
+                        println!("  {} failed: {}", id, message);
+                    }
+                }
+            }
+        }
+        passed += chapter_passed;
+        total += results.len();
+        complete += (error.is_none() && chapter_passed == results.len()) as usize;
+        totals.push((chapter.name.clone(), chapter_passed, results.len()));
+    }
+
+    println!();
+    for (name, chapter_passed, questions) in &totals {
+        println!("{:<20} {:>3}/{:<3}", name, chapter_passed, questions);
+    }
+    println!(
+        "{:<20} {:>3}/{:<3} questions, {}/{} chapters complete",
+        "total",
+        passed,
+        total,
+        complete,
+        chapters.len()
+    );
+    match complete == chapters.len() {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
+}
@@ -0,0 +1,84 @@
+use runner::lang::{Catalog, Lang, Statements};
+
+pub const CATALOG: Catalog = Catalog {
+    lang: Lang::Fr,
+    statements: Statements::Table(&[
+        ("Q1", "écrire `sbox` et `poseidon_permutation`"),
+        ("Q2", "écrire `poseidon_hash`"),
+        ("Q3", "dans un SNARK, chaque multiplication coûte une contrainte : combien de contraintes la permutation prend-elle ?\nComparer avec SHA-256 (environ 25000 contraintes par compression). Pourquoi les tours partiels sont-ils sûrs ?"),
+        ("Q4", "écrire `cube_root`, l'inverse de x -> x^3"),
+        ("Q5", "écrire `mimc_encrypt` et `mimc_decrypt`"),
+        ("Q6", "écrire `mimc_feistel_encrypt` et `mimc_feistel_decrypt`"),
+        ("Q7", "écrire `mimc_hash` avec la construction de Miyaguchi-Preneel, en partant de h_0 = 0"),
+        ("Q8", "pourquoi faut-il pgcd(3, p - 1) = 1 pour MiMC-n/n ? Qu'est-ce qui ne va pas dans le corps des scalaires de BLS12-381,\net pourquoi la variante de Feistel ne s'en soucie-t-elle pas ? Pourquoi le déchiffrement est-il bien plus cher que le chiffrement ?"),
+        ("Q9", "écrire `pedersen_hash`"),
+        ("Q10", "écrire `dlog_relation`, un vecteur non nul c tel que c_1 G_1 + ... + c_n G_n = 0"),
+        ("Q11", "écrire `dlog_from_relation` et résoudre le défi du logarithme discret"),
+        ("Q12", "trouver une collision de `pedersen_hash` sans aucun logarithme discret : qu'est-ce que cela dit des messages\nde longueurs différentes ? Pourquoi des générateurs choisis comme G_i = i G seraient-ils un désastre ?"),
+        ("Q13", "écrire `MerkleTree::new`, `MerkleTree::prove` et `verify_membership`"),
+        ("Q14", "écrire `forge_membership`, qui prouve qu'un nœud interne de l'arbre est une de ses feuilles"),
+        ("Q15", "pourquoi la contrefaçon échoue-t-elle avec `Sha256Hasher` ? Pourquoi le remplissage par des zéros y est-il sûr ?\nLes clients SPV de Bitcoin acceptent des transactions de 64 octets comme nœuds internes : en quoi fixer la profondeur de l'arbre aiderait-il ?"),
+        ("Q16", "écrire `sha256_from_state`, qui hache `data` à partir d'un état intermédiaire après `length` octets (des blocs entiers)"),
+        ("Q17", "écrire `hmac_key`, qui compresse une fois les deux clés complétées, et `hmac_sha256`"),
+        ("Q18", "écrire `hkdf_extract` et `hkdf_expand`"),
+        ("Q19", "avec l'état intermédiaire de H(K || m), que prolonge `sha256_from_state`, pourquoi H(K || m) est-il un MAC cassé\nalors que HMAC ne l'est pas ? Pourquoi HKDF extrait-il avant d'étendre, au lieu d'utiliser un secret ECDH comme clé ?\nLa même clé a deux MAC en Q17 : pourquoi est-ce sans danger pour HMAC, et qu'en est-il d'une clé dérivée d'un\nmot de passe ?"),
+    ]),
+    hints: &[
+        (
+            "Q4",
+            &[
+                "x -> x^3 est une bijection de F_p si et seulement si pgcd(3, p - 1) = 1",
+                "Si 3 d = 1 mod p - 1, alors (x^3)^d = x par le petit théorème de Fermat",
+                "La racine cubique est x^d avec d l'inverse de 3 modulo p - 1",
+            ],
+        ),
+        (
+            "Q7",
+            &[
+                "Miyaguchi-Preneel transforme un chiffrement par bloc en fonction de compression",
+                "h_i = E(h_(i-1), m_i) + m_i + h_(i-1), où h_(i-1) est la clé",
+                "Replier les blocs du message avec h = mimc_encrypt(h, m) + m + h, à partir de h = 0",
+            ],
+        ),
+        (
+            "Q10",
+            &[
+                "Deux messages de même haché donnent une relation linéaire entre les générateurs",
+                "Si sum m_i G_i = sum m'_i G_i, alors sum (m_i - m'_i) G_i = 0",
+                "La relation est c_i = m_i - m'_i, en complétant le message le plus court par des zéros",
+            ],
+        ),
+        (
+            "Q14",
+            &[
+                "Le hacheur naïf utilise une feuille telle quelle : une feuille et un nœud interne font tous deux 32 octets",
+                "Un nœud interne remonte jusqu'à la racine exactement comme le ferait une feuille, à partir de son propre niveau",
+                "Prendre le premier nœud du niveau 1 comme feuille, à l'indice 0, avec les frères de son chemin à partir du niveau 1",
+            ],
+        ),
+        (
+            "Q16",
+            &[
+                "Compléter les données comme SHA-256 complète un message, mais compter dans sa longueur les `length` octets déjà compressés",
+                "Ajouter 0x80, des zéros jusqu'à une longueur de 56 mod 64, puis la longueur totale en bits sur 8 octets gros-boutistes",
+                "Compresser chaque bloc de 64 octets des données complétées dans l'état, puis `state_to_bytes`",
+            ],
+        ),
+        (
+            "Q17",
+            &[
+                "Compléter la clé par des zéros jusqu'à 64 octets, après l'avoir hachée si elle est plus longue",
+                "Chaque clé complétée, XORée avec 0x36 ou 0x5c, est un bloc : la compresser à partir de `SHA256_IV` pour obtenir un état intermédiaire",
+                "Le haché interne prolonge l'état interne sur les données, l'externe prolonge l'état externe sur le haché interne, tous deux après 64 octets",
+            ],
+        ),
+        (
+            "Q18",
+            &[
+                "Extract est un seul HMAC, avec le sel pour clé",
+                "Expand enchaîne les blocs : T(i) = HMAC(PRK, T(i - 1) || info || i), avec i sur un octet à partir de 1",
+                "Calculer ceil(L / 32) blocs, les concaténer et tronquer à L octets",
+            ],
+        ),
+    ],
+};
@@ -0,0 +1,66 @@
+use runner::hints::Hints;
+use runner::lang::Catalog;
+
+pub const CATALOGS: &[Catalog] = &[
+    Catalog::english(HINTS, include_str!("main.rs")),
+    crate::fr::CATALOG,
+];
+
+pub const HINTS: Hints = &[
+    (
+        "Q4",
+        &[
+            "x -> x^3 is a bijection of F_p iff gcd(3, p - 1) = 1",
+            "If 3 d = 1 mod p - 1, then (x^3)^d = x by Fermat's little theorem",
+            "The cube root is x^d with d the inverse of 3 modulo p - 1",
+        ],
+    ),
+    (
+        "Q7",
+        &[
+            "Miyaguchi-Preneel turns a block cipher into a compression function",
+            "h_i = E(h_(i-1), m_i) + m_i + h_(i-1), where h_(i-1) is the key",
+            "Fold the message blocks with h = mimc_encrypt(h, m) + m + h, from h = 0",
+        ],
+    ),
+    (
+        "Q10",
+        &[
+            "Two messages with the same hash give a linear relation between the generators",
+            "If sum m_i G_i = sum m'_i G_i, then sum (m_i - m'_i) G_i = 0",
+            "The relation is c_i = m_i - m'_i, padding the shorter message with zeros",
+        ],
+    ),
+    (
+        "Q14",
+        &[
+            "The naive hasher uses a leaf as it is: a leaf and an inner node are both 32 bytes",
+            "An inner node hashes up to the root exactly as a leaf would, from its own level",
+            "Take the first node of level 1 as the leaf, at index 0, with the siblings of its path from level 1 up",
+        ],
+    ),
+    (
+        "Q16",
+        &[
+            "Pad the data as SHA-256 pads a message, but count the `length` bytes already compressed in its length",
+            "Append 0x80, zeros until the length is 56 mod 64, then the total length in bits on 8 big-endian bytes",
+            "Compress every 64-byte block of the padded data into the state, then `state_to_bytes`",
+        ],
+    ),
+    (
+        "Q17",
+        &[
+            "Pad the key with zeros to 64 bytes, after hashing it if it is longer",
+            "Each padded key, XORed with 0x36 or 0x5c, is one block: compress it from `SHA256_IV` to get a midstate",
+            "The inner hash continues the inner midstate on the data, the outer one continues the outer midstate on the inner hash, both after 64 bytes",
+        ],
+    ),
+    (
+        "Q18",
+        &[
+            "Extract is a single HMAC, keyed by the salt",
+            "Expand chains the blocks: T(i) = HMAC(PRK, T(i - 1) || info || i), with i on one byte from 1",
+            "Compute ceil(L / 32) blocks, concatenate them and truncate to L bytes",
+        ],
+    ),
+];
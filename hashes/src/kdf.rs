@@ -0,0 +1,122 @@
+use sha2::digest::generic_array::GenericArray;
+
+// SHA-256 is a Merkle-Damgard hash: the state, 8 words starting from `SHA256_IV`, goes through the compression
+// function once per 64-byte block of the padded message, and the final state is the digest
+// - padding: the byte 0x80, zeros until the length is 56 mod 64, then the length of the message in bits on 8
+//   bytes, big-endian
+// - the state after some blocks, a "midstate", is all it takes to hash any continuation of these blocks
+//
+// HMAC(K, m) = H((K' ^ opad) || H((K' ^ ipad) || m)), RFC 2104, where K' is the key padded with zeros to the
+// 64 bytes of a block (hashed first when longer), ipad = 0x36 0x36 ... and opad = 0x5c 0x5c ...
+// (K' ^ ipad) and (K' ^ opad) are exactly one block each: their midstates can be computed once per key
+//
+// HKDF (RFC 5869) turns a shared secret into keys, in two steps:
+// - extract: PRK = HMAC(salt, IKM), a uniform key from input keying material that may not be uniform (an ECDH
+//   point), with a salt of 32 zeros when there is none
+// - expand: T(i) = HMAC(PRK, T(i - 1) || info || i) from T(0) = "", and OKM is the first L bytes of
+//   T(1) || T(2) || ..., L <= 255 * 32; `info` binds the key to its use ("client key", "server key"...)
+// `hmac_sha512` of BIP32, in the schnorr chapter, is the same construction with SHA-512
+
+pub const SHA256_IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+pub const BLOCK_SIZE: usize = 64;
+
+// the midstates of (K' ^ ipad) and (K' ^ opad)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HmacKey {
+    pub inner: [u32; 8],
+    pub outer: [u32; 8],
+}
+
+// the raw compression function: one 64-byte block into the state
+pub fn compress(state: &mut [u32; 8], block: &[u8; BLOCK_SIZE]) {
+    sha2::compress256(state, &[*GenericArray::from_slice(block)]);
+}
+
+// the digest, big-endian, of a final state
+pub fn state_to_bytes(state: &[u32; 8]) -> [u8; 32] {
+    let mut digest = [0; 32];
+    for (chunk, word) in digest.chunks_mut(4).zip(state) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+}
+
+// RFC 4231, test cases 1, 2, 3, 4, 6 and 7 (test case 5 truncates the output): key, data, HMAC-SHA256
+pub fn hmac_vectors() -> Vec<(Vec<u8>, Vec<u8>, &'static str)> {
+    vec![
+        (
+            vec![0x0b; 20],
+            b"Hi There".to_vec(),
+            "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7",
+        ),
+        (
+            b"Jefe".to_vec(),
+            b"what do ya want for nothing?".to_vec(),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+        ),
+        (
+            vec![0xaa; 20],
+            vec![0xdd; 50],
+            "773ea91e36800e46854db8ebd09181a72959098b3ef8c122d9635514ced565fe",
+        ),
+        (
+            (1..=25).collect(),
+            vec![0xcd; 50],
+            "82558a389a443c0ea4cc819899f2083a85f0faa3e578f8077a2e3ff46729665b",
+        ),
+        (
+            vec![0xaa; 131],
+            b"Test Using Larger Than Block-Size Key - Hash Key First".to_vec(),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54",
+        ),
+        (
+            vec![0xaa; 131],
+            b"This is a test using a larger than block-size key and a larger than block-size data. \
+              The key needs to be hashed before being used by the HMAC algorithm."
+                .to_vec(),
+            "9b09ffa71b942fcb27635fbcd5b0e944bfdc63644f0713938a7f51535c3a35e2",
+        ),
+    ]
+}
+
+pub struct HkdfVector {
+    pub ikm: Vec<u8>,
+    pub salt: Vec<u8>,
+    pub info: Vec<u8>,
+    pub prk: &'static str,
+    pub okm: &'static str,
+}
+
+// RFC 5869, the three test cases with SHA-256 (appendix A.1 to A.3)
+pub fn hkdf_vectors() -> Vec<HkdfVector> {
+    vec![
+        HkdfVector {
+            ikm: vec![0x0b; 22],
+            salt: (0x00..=0x0c).collect(),
+            info: (0xf0..=0xf9).collect(),
+            prk: "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5",
+            okm: "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865",
+        },
+        HkdfVector {
+            ikm: (0x00..=0x4f).collect(),
+            salt: (0x60..=0xaf).collect(),
+            info: (0xb0..=0xff).collect(),
+            prk: "06a6b88c5853361a06104c9ceb35b45cef760014904671014a193f40c15fc244",
+            okm: "b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71cc30c58179ec3e87c14c01d5c1f3434f1d87",
+        },
+        HkdfVector {
+            ikm: vec![0x0b; 22],
+            salt: vec![],
+            info: vec![],
+            prk: "19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04",
+            okm: "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8",
+        },
+    ]
+}
+
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
@@ -0,0 +1,579 @@
+use ark_bls12_381::{Fr, G1Projective};
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_std::{UniformRand, Zero};
+use kdf::*;
+use merkle::*;
+use mimc::*;
+use pedersen::*;
+use poseidon::*;
+use rand::Rng;
+use runner::Runner;
+use sha2::{Digest, Sha256};
+
+mod fr;
+mod hints;
+mod kdf;
+mod merkle;
+mod mimc;
+mod pedersen;
+mod poseidon;
+
+fn main() {
+    let runner = Runner::start(env!("CARGO_PKG_NAME"), hints::CATALOGS);
+    let mut rng = seed::rng();
+
+    // Take a look at module `poseidon`
+    let parameters = PoseidonParameters::bls12_381_width_3();
+    assert_eq!(parameters.round_constants.len(), 65);
+
+    // Q1: write `sbox` and `poseidon_permutation`
+    runner.question("Q1");
+    assert_eq!(sbox(Fr::from(2)), Fr::from(32));
+    // the test vector of the reference implementation (poseidonperm_x5_255_3)
+    let mut state = [Fr::from(0), Fr::from(1), Fr::from(2)];
+    poseidon_permutation(&parameters, &mut state);
+    assert_eq!(
+        state,
+        [
+            fr_from_hex("28ce19420fc246a05553ad1e8c98f5c9d67166be2c18e9e4cb4b4e317dd2a78a"),
+            fr_from_hex("51f3e312c95343a896cfd8945ea82ba956c1118ce9b9859b6ea56637b4b1ddc4"),
+            fr_from_hex("3b2b69139b235626a0bfb56c9527ae66a7bf486ad8c11c14d1da0c69bbe0f79a"),
+        ]
+    );
+
+    // A sponge turns the permutation into a hash function: the state is split into the rate (2 elements)
+    // and the capacity (1 element, never touched by the inputs nor output)
+    // - absorb: add the inputs RATE by RATE to the rate part of the state, permuting after each chunk
+    //   (the last chunk is padded with zeros)
+    // - squeeze: output the first element of the rate
+    // The capacity element starts as the number of inputs, so that padding with zeros is not ambiguous
+    // Q2: write `poseidon_hash`
+    runner.question("Q2");
+    assert_eq!(
+        poseidon_hash(&parameters, &[Fr::from(1), Fr::from(2)]),
+        fr_from_hex("261c25cf30c9266202d48adb9663a74b76592aa5a0d32fe88c57776958606dda")
+    );
+    assert_eq!(
+        poseidon_hash(&parameters, &[Fr::from(1), Fr::from(2), Fr::from(3)]),
+        fr_from_hex("3838a0c7f2129f7004dbb01109172bc1af03c0bf619ba6e545c041821025f129")
+    );
+    let inputs: Vec<Fr> = (0..5).map(|_| Fr::rand(&mut rng)).collect();
+    let digest = poseidon_hash(&parameters, &inputs);
+    assert_ne!(poseidon_hash(&parameters, &inputs[..4]), digest);
+    let mut padded = inputs.clone();
+    padded.push(Fr::zero());
+    assert_ne!(poseidon_hash(&parameters, &padded), digest);
+
+    // Q3: inside a SNARK, each multiplication costs a constraint: how many constraints does the permutation take?
+    // Compare with SHA-256 (about 25000 constraints per compression). Why are partial rounds secure at all?
+    runner.question("Q3");
+
+    // Take a look at module `mimc`
+    let constants = mimc_round_constants(MIMC_FEISTEL_ROUNDS);
+    // Q4: write `cube_root`, the inverse of x -> x^3
+    runner.question("Q4");
+    for _ in 0..10 {
+        let x = Fp::rand(&mut rng);
+        assert_eq!(cube_root(x).pow([3]), x);
+    }
+    // Q5: write `mimc_encrypt` and `mimc_decrypt`
+    runner.question("Q5");
+    let (key, plaintext) = (Fp::from(1), Fp::from(2));
+    assert_eq!(
+        mimc_encrypt(&constants, key, plaintext),
+        fp_from_hex("39e3532099c5a14c2532f6a19a139c91d73c43566bf372bab805d7657a258462")
+    );
+    for _ in 0..10 {
+        let (key, plaintext) = (Fp::rand(&mut rng), Fp::rand(&mut rng));
+        let ciphertext = mimc_encrypt(&constants, key, plaintext);
+        assert_eq!(mimc_decrypt(&constants, key, ciphertext), plaintext);
+    }
+    // Q6: write `mimc_feistel_encrypt` and `mimc_feistel_decrypt`
+    runner.question("Q6");
+    assert_eq!(
+        mimc_feistel_encrypt(&constants, Fp::from(1), (Fp::from(2), Fp::from(3))),
+        (
+            fp_from_hex("2a07aef9aafef3bef636b9fc961d4eaa97bb48ee435e5529ffcc1c816ae72d10"),
+            fp_from_hex("08807813febeb4ca887a960b611e055fca294dbc25ff73a6e9b63c043e5ad3bc")
+        )
+    );
+    for _ in 0..10 {
+        let key = Fp::rand(&mut rng);
+        let plaintext = (Fp::rand(&mut rng), Fp::rand(&mut rng));
+        let ciphertext = mimc_feistel_encrypt(&constants, key, plaintext);
+        assert_eq!(mimc_feistel_decrypt(&constants, key, ciphertext), plaintext);
+    }
+    // Q7: write `mimc_hash` with the Miyaguchi-Preneel construction, starting from h_0 = 0
+    runner.question("Q7");
+    assert_eq!(
+        mimc_hash(&constants, &[Fp::from(1), Fp::from(2), Fp::from(3)]),
+        fp_from_hex("48129f1e280342e30ce1a5688592532ed4515257793141aeecaff1ca52cc27fe")
+    );
+    // Q8: why must gcd(3, p - 1) = 1 for MiMC-n/n? What goes wrong in the scalar field of BLS12-381,
+    // and why does the Feistel variant not care? Why is decryption much more expensive than encryption?
+    runner.question("Q8");
+
+    // Take a look at module `pedersen`
+    let generators = pedersen_generators(PEDERSEN_BITS);
+    assert!(generators.iter().all(|g| !g.is_zero()));
+    // Q9: write `pedersen_hash`
+    runner.question("Q9");
+    assert!(pedersen_hash(&generators, &[]).is_zero());
+    assert_eq!(pedersen_hash(&generators, &[false, true]), generators[1]);
+    let message = random_bits(PEDERSEN_BITS, &mut rng);
+    let (left, right): (Vec<bool>, Vec<bool>) = message
+        .iter()
+        .enumerate()
+        .map(|(i, bit)| (*bit && i % 2 == 0, *bit && i % 2 == 1))
+        .unzip();
+    assert_eq!(
+        pedersen_hash(&generators, &message),
+        pedersen_hash(&generators, &left) + pedersen_hash(&generators, &right)
+    );
+    assert_ne!(
+        pedersen_hash(&generators, &message),
+        pedersen_hash(&generators, &random_bits(PEDERSEN_BITS, &mut rng))
+    );
+
+    // Someone hands us a collision of the Pedersen hash whose generators were built from a discrete log challenge
+    let (reduction, message1, message2) =
+        DlogReduction::with_planted_collision(PEDERSEN_BITS, &mut rng);
+    let generators = reduction.generators();
+    assert_ne!(message1, message2);
+    assert_eq!(
+        pedersen_hash(&generators, &message1),
+        pedersen_hash(&generators, &message2)
+    );
+    // Q10: write `dlog_relation`, a non-zero vector c such that c_1 G_1 + ... + c_n G_n = 0
+    runner.question("Q10");
+    let relation = dlog_relation(&message1, &message2);
+    assert!(relation.iter().any(|c| !c.is_zero()));
+    assert!(generators
+        .iter()
+        .zip(&relation)
+        .map(|(g, c)| *g * c)
+        .sum::<G1Projective>()
+        .is_zero());
+    // Q11: write `dlog_from_relation` and solve the discrete log challenge
+    runner.question("Q11");
+    let x = dlog_from_relation(&reduction, &relation);
+    assert_eq!(reduction.base * x, reduction.target);
+    // Q12: find a collision of `pedersen_hash` without any discrete log: what does it say about messages
+    // of different lengths? Why would generators chosen as G_i = i G be a disaster?
+    runner.question("Q12");
+
+    // Take a look at module `merkle`
+    // Q13: write `MerkleTree::new`, `MerkleTree::prove` and `verify_membership`
+    runner.question("Q13");
+    let leaves: Vec<[u8; 32]> = (0..11).map(|_| rng.gen()).collect();
+    let tree = MerkleTree::new(Sha256Hasher, &leaves);
+    assert_eq!(tree.levels.len(), 5);
+    assert_eq!(tree.levels[0].len(), 16);
+    let pair = MerkleTree::new(Sha256Hasher, &leaves[..2]);
+    assert_eq!(
+        pair.root(),
+        Sha256Hasher.hash_node(
+            &Sha256Hasher.hash_leaf(&leaves[0]),
+            &Sha256Hasher.hash_leaf(&leaves[1])
+        )
+    );
+    for (index, leaf) in leaves.iter().enumerate() {
+        let proof = tree.prove(index);
+        assert_eq!(proof.siblings.len(), 4);
+        assert!(verify_membership(&tree.hasher, &tree.root(), leaf, &proof));
+        // tamper detection: another leaf, another position, another path
+        let other = &leaves[(index + 1) % leaves.len()];
+        assert!(!verify_membership(
+            &tree.hasher,
+            &tree.root(),
+            other,
+            &proof
+        ));
+        let mut moved = proof.clone();
+        moved.index ^= 1;
+        assert!(!verify_membership(&tree.hasher, &tree.root(), leaf, &moved));
+        moved.index = index + 16;
+        assert!(!verify_membership(&tree.hasher, &tree.root(), leaf, &moved));
+        let mut tampered = proof.clone();
+        tampered.siblings[2][0] ^= 1;
+        assert!(!verify_membership(
+            &tree.hasher,
+            &tree.root(),
+            leaf,
+            &tampered
+        ));
+        assert!(!verify_membership(&tree.hasher, &pair.root(), leaf, &proof));
+    }
+    // the same code with your Poseidon
+    let hasher = PoseidonHasher {
+        parameters: PoseidonParameters::bls12_381_width_3(),
+    };
+    let leaves: Vec<Fr> = (0..5).map(|_| Fr::rand(&mut rng)).collect();
+    let tree = MerkleTree::new(hasher, &leaves);
+    assert_eq!(tree.levels.len(), 4);
+    for (index, leaf) in leaves.iter().enumerate() {
+        let proof = tree.prove(index);
+        assert!(verify_membership(&tree.hasher, &tree.root(), leaf, &proof));
+        let tampered = *leaf + Fr::from(1);
+        assert!(!verify_membership(
+            &tree.hasher,
+            &tree.root(),
+            &tampered,
+            &proof
+        ));
+    }
+
+    // Padding pitfalls: Bitcoin duplicates the last node of odd levels (CVE-2012-2459)...
+    let [a, b, c]: [[u8; 32]; 3] = rng.gen();
+    assert_eq!(
+        bitcoin_merkle_root(&[a, b, c]),
+        bitcoin_merkle_root(&[a, b, c, c])
+    );
+    // ... and padding with zeros is no better when leaves are not hashed
+    assert_eq!(
+        MerkleTree::new(NaiveSha256Hasher, &[a, b, c]).root(),
+        MerkleTree::new(NaiveSha256Hasher, &[a, b, c, [0; 32]]).root()
+    );
+    assert_ne!(
+        MerkleTree::new(Sha256Hasher, &[a, b, c]).root(),
+        MerkleTree::new(Sha256Hasher, &[a, b, c, [0; 32]]).root()
+    );
+    // Q14: write `forge_membership`, which proves that an inner node of the tree is one of its leaves
+    runner.question("Q14");
+    let leaves: Vec<[u8; 32]> = (0..8).map(|_| rng.gen()).collect();
+    let tree = MerkleTree::new(NaiveSha256Hasher, &leaves);
+    let (leaf, proof) = forge_membership(&tree);
+    assert!(!leaves.contains(&leaf));
+    assert!(verify_membership(&tree.hasher, &tree.root(), &leaf, &proof));
+    let tree = MerkleTree::new(Sha256Hasher, &leaves);
+    let (leaf, proof) = forge_membership(&tree);
+    assert!(!verify_membership(
+        &tree.hasher,
+        &tree.root(),
+        &leaf,
+        &proof
+    ));
+    // Q15: why does the forgery fail with `Sha256Hasher`? Why is the zero padding safe there?
+    // Bitcoin SPV clients accept 64-byte transactions as inner nodes: how would fixing the depth of the tree help?
+    runner.question("Q15");
+
+    // Take a look at module `kdf`: HMAC and HKDF, built on the compression function of SHA-256
+    // Q16: write `sha256_from_state`, which hashes `data` on from a midstate after `length` bytes (whole blocks)
+    runner.question("Q16");
+    for length in [0, 1, 55, 56, 63, 64, 65, 119, 120, 200] {
+        let data: Vec<u8> = (0..length).map(|_| rng.gen()).collect();
+        let digest: [u8; 32] = Sha256::digest(&data).into();
+        assert_eq!(sha256_from_state(SHA256_IV, 0, &data), digest, "{}", length);
+        // the same digest from the midstate after the first block
+        if length >= BLOCK_SIZE {
+            let mut state = SHA256_IV;
+            compress(&mut state, data[..BLOCK_SIZE].try_into().unwrap());
+            assert_eq!(
+                sha256_from_state(state, BLOCK_SIZE as u64, &data[BLOCK_SIZE..]),
+                digest
+            );
+        }
+    }
+
+    // Q17: write `hmac_key`, which compresses the two padded keys once, and `hmac_sha256`
+    runner.question("Q17");
+    for (key, data, mac) in hmac_vectors() {
+        assert_eq!(to_hex(&hmac_sha256(&hmac_key(&key), &data)), mac);
+    }
+    // the key is padded with zeros: a short key and the same key followed by zeros have the same MAC
+    assert_eq!(hmac_key(b"Jefe"), hmac_key(b"Jefe\0\0"));
+
+    // Q18: write `hkdf_extract` and `hkdf_expand`
+    runner.question("Q18");
+    for vector in hkdf_vectors() {
+        let prk = hkdf_extract(&vector.salt, &vector.ikm);
+        assert_eq!(to_hex(&prk), vector.prk);
+        let okm = hkdf_expand(&prk, &vector.info, vector.okm.len() / 2);
+        assert_eq!(to_hex(&okm), vector.okm);
+        // a shorter output is a prefix of a longer one
+        assert_eq!(hkdf_expand(&prk, &vector.info, 20), okm[..20]);
+    }
+    let prk = hkdf_extract(&[], b"shared secret");
+    assert_eq!(hkdf_expand(&prk, b"", 255 * 32).len(), 255 * 32);
+    assert_ne!(
+        hkdf_expand(&prk, b"client key", 32),
+        hkdf_expand(&prk, b"server key", 32)
+    );
+
+    // Q19: with the midstate of H(K || m), which `sha256_from_state` continues, why is H(K || m) a broken MAC
+    // while HMAC is not? Why does HKDF extract before expanding, instead of using an ECDH secret as the key?
+    // The same key has two MACs in Q17: why is it harmless for HMAC, and what about a key derived from a
+    // password?
+    runner.question("Q19");
+
+    println!("Good job!");
+    runner.finish();
+}
+
+fn cube_root(x: Fp) -> Fp {
+    // SOLUTION-BEGIN
+    // 3 (2p - 1) / 3 = 2(p - 1) + 1, so (2p - 1) / 3 is the inverse of 3 mod p - 1
+    let mut exponent = Fp::MODULUS;
+    exponent.mul2();
+    exponent.sub_with_borrow(&1u64.into());
+    let mut remainder = 0u128;
+    for limb in exponent.0.iter_mut().rev() {
+        let value = (remainder << 64) | *limb as u128;
+        *limb = (value / 3) as u64;
+        remainder = value % 3;
+    }
+    assert_eq!(remainder, 0);
+    x.pow(exponent)
+    // SOLUTION-END
+}
+
+fn mimc_encrypt(constants: &[Fp], key: Fp, plaintext: Fp) -> Fp {
+    // SOLUTION-BEGIN
+    constants[..MIMC_ROUNDS]
+        .iter()
+        .fold(plaintext, |x, c| (x + key + c).pow([3]))
+        + key
+    // SOLUTION-END
+}
+
+fn mimc_decrypt(constants: &[Fp], key: Fp, ciphertext: Fp) -> Fp {
+    // SOLUTION-BEGIN
+    constants[..MIMC_ROUNDS]
+        .iter()
+        .rev()
+        .fold(ciphertext - key, |x, c| cube_root(x) - key - c)
+    // SOLUTION-END
+}
+
+fn mimc_feistel_encrypt(constants: &[Fp], key: Fp, plaintext: (Fp, Fp)) -> (Fp, Fp) {
+    // SOLUTION-BEGIN
+    constants[..MIMC_FEISTEL_ROUNDS]
+        .iter()
+        .fold(plaintext, |(l, r), c| (r + (l + key + c).pow([3]), l))
+    // SOLUTION-END
+}
+
+fn mimc_feistel_decrypt(constants: &[Fp], key: Fp, ciphertext: (Fp, Fp)) -> (Fp, Fp) {
+    // SOLUTION-BEGIN
+    constants[..MIMC_FEISTEL_ROUNDS]
+        .iter()
+        .rev()
+        .fold(ciphertext, |(l, r), c| (r, l - (r + key + c).pow([3])))
+    // SOLUTION-END
+}
+
+fn mimc_hash(constants: &[Fp], inputs: &[Fp]) -> Fp {
+    // SOLUTION-BEGIN
+    inputs
+        .iter()
+        .fold(Fp::from(0), |h, m| mimc_encrypt(constants, h, *m) + h + m)
+    // SOLUTION-END
+}
+
+fn pedersen_hash(generators: &[G1Projective], bits: &[bool]) -> G1Projective {
+    // SOLUTION-BEGIN
+    assert!(bits.len() <= generators.len());
+    generators
+        .iter()
+        .zip(bits)
+        .filter(|(_, bit)| **bit)
+        .map(|(g, _)| *g)
+        .sum()
+    // SOLUTION-END
+}
+
+fn dlog_relation(message1: &[bool], message2: &[bool]) -> Vec<Fr> {
+    // SOLUTION-BEGIN
+    message1
+        .iter()
+        .zip(message2)
+        .map(|(m1, m2)| Fr::from(*m1 as u64) - Fr::from(*m2 as u64))
+        .collect()
+    // SOLUTION-END
+}
+
+fn dlog_from_relation(reduction: &DlogReduction, relation: &[Fr]) -> Fr {
+    // SOLUTION-BEGIN
+    // (sum c_i a_i) G + (sum c_i b_i) x G = 0
+    let sum_a: Fr = relation.iter().zip(&reduction.a).map(|(c, a)| *c * a).sum();
+    let sum_b: Fr = relation.iter().zip(&reduction.b).map(|(c, b)| *c * b).sum();
+    -sum_a / sum_b
+    // SOLUTION-END
+}
+
+impl<H: MerkleHasher> MerkleTree<H> {
+    fn new(hasher: H, leaves: &[H::Digest]) -> MerkleTree<H> {
+        // SOLUTION-BEGIN
+        let mut level: Vec<H::Digest> = leaves.iter().map(|leaf| hasher.hash_leaf(leaf)).collect();
+        level.resize(leaves.len().next_power_of_two(), hasher.padding());
+        let mut levels = vec![level];
+        while levels.last().unwrap().len() > 1 {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| hasher.hash_node(&pair[0], &pair[1]))
+                .collect();
+            levels.push(next);
+        }
+        MerkleTree { hasher, levels }
+        // SOLUTION-END
+    }
+
+    fn prove(&self, index: usize) -> MerkleProof<H::Digest> {
+        // SOLUTION-BEGIN
+        let depth = self.levels.len() - 1;
+        MerkleProof {
+            index,
+            siblings: (0..depth)
+                .map(|height| self.levels[height][(index >> height) ^ 1])
+                .collect(),
+        }
+        // SOLUTION-END
+    }
+}
+
+fn verify_membership<H: MerkleHasher>(
+    hasher: &H,
+    root: &H::Digest,
+    leaf: &H::Digest,
+    proof: &MerkleProof<H::Digest>,
+) -> bool {
+    // SOLUTION-BEGIN
+    if proof.index >> proof.siblings.len() != 0 {
+        return false;
+    }
+    let computed = proof.siblings.iter().enumerate().fold(
+        hasher.hash_leaf(leaf),
+        |node, (height, sibling)| {
+            if (proof.index >> height) & 1 == 0 {
+                hasher.hash_node(&node, sibling)
+            } else {
+                hasher.hash_node(sibling, &node)
+            }
+        },
+    );
+    computed == *root
+    // SOLUTION-END
+}
+
+fn forge_membership<H: MerkleHasher>(tree: &MerkleTree<H>) -> (H::Digest, MerkleProof<H::Digest>) {
+    // SOLUTION-BEGIN
+    // the first node of level 1, with its authentication path from level 1 up
+    let depth = tree.levels.len() - 1;
+    let proof = MerkleProof {
+        index: 0,
+        siblings: (1..depth).map(|height| tree.levels[height][1]).collect(),
+    };
+    (tree.levels[1][0], proof)
+    // SOLUTION-END
+}
+
+fn sha256_from_state(state: [u32; 8], length: u64, data: &[u8]) -> [u8; 32] {
+    // SOLUTION-BEGIN
+    let total_bits = (length + data.len() as u64) * 8;
+    let mut padded = data.to_vec();
+    padded.push(0x80);
+    while padded.len() % BLOCK_SIZE != 56 {
+        padded.push(0);
+    }
+    padded.extend(total_bits.to_be_bytes());
+    let mut state = state;
+    for block in padded.chunks(BLOCK_SIZE) {
+        compress(&mut state, block.try_into().unwrap());
+    }
+    state_to_bytes(&state)
+    // SOLUTION-END
+}
+
+fn hmac_key(key: &[u8]) -> HmacKey {
+    // SOLUTION-BEGIN
+    let mut padded_key = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        padded_key[..32].copy_from_slice(&sha256_from_state(SHA256_IV, 0, key));
+    } else {
+        padded_key[..key.len()].copy_from_slice(key);
+    }
+    let midstate = |pad: u8| {
+        let mut state = SHA256_IV;
+        compress(&mut state, &padded_key.map(|byte| byte ^ pad));
+        state
+    };
+    HmacKey {
+        inner: midstate(0x36),
+        outer: midstate(0x5c),
+    }
+    // SOLUTION-END
+}
+
+fn hmac_sha256(key: &HmacKey, data: &[u8]) -> [u8; 32] {
+    // SOLUTION-BEGIN
+    let inner = sha256_from_state(key.inner, BLOCK_SIZE as u64, data);
+    sha256_from_state(key.outer, BLOCK_SIZE as u64, &inner)
+    // SOLUTION-END
+}
+
+fn hkdf_extract(salt: &[u8], ikm: &[u8]) -> [u8; 32] {
+    // SOLUTION-BEGIN
+    // an empty salt is 32 zeros, which the zero padding of the key turns into the same key anyway
+    hmac_sha256(&hmac_key(salt), ikm)
+    // SOLUTION-END
+}
+
+fn hkdf_expand(prk: &[u8], info: &[u8], length: usize) -> Vec<u8> {
+    // SOLUTION-BEGIN
+    assert!(length <= 255 * 32, "HKDF outputs at most 255 blocks");
+    let key = hmac_key(prk);
+    let mut okm = vec![];
+    let mut block = vec![];
+    for i in 1..=length.div_ceil(32) as u8 {
+        block = hmac_sha256(&key, &[&block[..], info, &[i]].concat()).to_vec();
+        okm.extend(&block);
+    }
+    okm.truncate(length);
+    okm
+    // SOLUTION-END
+}
+
+fn sbox(x: Fr) -> Fr {
+    // SOLUTION-BEGIN
+    x.pow([ALPHA])
+    // SOLUTION-END
+}
+
+fn poseidon_permutation(parameters: &PoseidonParameters, state: &mut [Fr; WIDTH]) {
+    // SOLUTION-BEGIN
+    let half_full_rounds = parameters.full_rounds / 2;
+    for (round, constants) in parameters.round_constants.iter().enumerate() {
+        for (x, c) in state.iter_mut().zip(constants) {
+            *x += c;
+        }
+        let is_full_round =
+            round < half_full_rounds || round >= half_full_rounds + parameters.partial_rounds;
+        if is_full_round {
+            for x in state.iter_mut() {
+                *x = sbox(*x);
+            }
+        } else {
+            state[0] = sbox(state[0]);
+        }
+        *state = parameters
+            .mds
+            .map(|row| row.iter().zip(state.iter()).map(|(m, x)| *m * x).sum());
+    }
+    // SOLUTION-END
+}
+
+fn poseidon_hash(parameters: &PoseidonParameters, inputs: &[Fr]) -> Fr {
+    // SOLUTION-BEGIN
+    let mut state = [Fr::zero(); WIDTH];
+    state[0] = Fr::from(inputs.len() as u64);
+    for chunk in inputs.chunks(RATE) {
+        for (x, input) in state[1..].iter_mut().zip(chunk) {
+            *x += input;
+        }
+        poseidon_permutation(parameters, &mut state);
+    }
+    state[1]
+    // SOLUTION-END
+}
@@ -0,0 +1,126 @@
+use crate::*;
+use sha2::{Digest, Sha256};
+use std::fmt::Debug;
+
+// A Merkle tree commits to a list of leaves with a single digest, the root:
+// the leaves are hashed, then every level is hashed pairwise into the next one up to the root
+// The membership proof of a leaf is the list of the siblings on its path to the root (the authentication path),
+// and the bits of its index tell whether each sibling is on the left or on the right
+//
+// The tree is generic over the hash function, so that the same code works with SHA-256 and with Poseidon
+// (whose cost inside a SNARK is what matters when the membership is proven in zero-knowledge)
+// The leaves are filled up to a power of two with the `padding` digest
+
+pub trait MerkleHasher {
+    type Digest: Copy + Debug + PartialEq;
+
+    fn hash_leaf(&self, leaf: &Self::Digest) -> Self::Digest;
+    fn hash_node(&self, left: &Self::Digest, right: &Self::Digest) -> Self::Digest;
+    fn padding(&self) -> Self::Digest;
+}
+
+// RFC 6962 (Certificate Transparency): leaves and nodes are hashed with distinct prefixes
+pub struct Sha256Hasher;
+
+impl MerkleHasher for Sha256Hasher {
+    type Digest = [u8; 32];
+
+    fn hash_leaf(&self, leaf: &[u8; 32]) -> [u8; 32] {
+        Sha256::new()
+            .chain_update([0x00])
+            .chain_update(leaf)
+            .finalize()
+            .into()
+    }
+
+    fn hash_node(&self, left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        Sha256::new()
+            .chain_update([0x01])
+            .chain_update(left)
+            .chain_update(right)
+            .finalize()
+            .into()
+    }
+
+    fn padding(&self) -> [u8; 32] {
+        [0; 32]
+    }
+}
+
+// the leaves are used as they are (say they are already transaction hashes) and nodes hash
+// the concatenation of their children, with no domain separation
+pub struct NaiveSha256Hasher;
+
+impl MerkleHasher for NaiveSha256Hasher {
+    type Digest = [u8; 32];
+
+    fn hash_leaf(&self, leaf: &[u8; 32]) -> [u8; 32] {
+        *leaf
+    }
+
+    fn hash_node(&self, left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        Sha256::new()
+            .chain_update(left)
+            .chain_update(right)
+            .finalize()
+            .into()
+    }
+
+    fn padding(&self) -> [u8; 32] {
+        [0; 32]
+    }
+}
+
+// your Poseidon sponge, whose capacity separates leaves (1 input) from nodes (2 inputs)
+pub struct PoseidonHasher {
+    pub parameters: PoseidonParameters,
+}
+
+impl MerkleHasher for PoseidonHasher {
+    type Digest = Fr;
+
+    fn hash_leaf(&self, leaf: &Fr) -> Fr {
+        poseidon_hash(&self.parameters, &[*leaf])
+    }
+
+    fn hash_node(&self, left: &Fr, right: &Fr) -> Fr {
+        poseidon_hash(&self.parameters, &[*left, *right])
+    }
+
+    fn padding(&self) -> Fr {
+        Fr::zero()
+    }
+}
+
+pub struct MerkleTree<H: MerkleHasher> {
+    pub hasher: H,
+    // levels[0] holds the hashed and padded leaves, the last level holds the root alone
+    pub levels: Vec<Vec<H::Digest>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MerkleProof<D> {
+    pub index: usize,
+    pub siblings: Vec<D>, // from the leaf level up
+}
+
+impl<H: MerkleHasher> MerkleTree<H> {
+    pub fn root(&self) -> H::Digest {
+        self.levels.last().unwrap()[0]
+    }
+}
+
+// Bitcoin's Merkle root: the leaves are txids, and an odd level is completed by duplicating its last node
+pub fn bitcoin_merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        if level.len() % 2 == 1 {
+            level.push(*level.last().unwrap());
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| NaiveSha256Hasher.hash_node(&pair[0], &pair[1]))
+            .collect();
+    }
+    level[0]
+}
@@ -0,0 +1,51 @@
+use crate::*;
+use sha2::{Digest, Sha256};
+
+// MiMC ("minimal multiplicative complexity"), a block cipher over F_p built from the cube map x -> x^3
+// - MiMC-n/n: r rounds of x -> (x + k + c_i)^3, then a final key addition
+//   it is a permutation only if x -> x^3 is one, i.e. gcd(3, p - 1) = 1
+//   interpolation attacks require the degree 3^r to exceed p: r = ceil(log_3(p)) rounds
+// - MiMC-2n/n: a Feistel network whose round function is (L, R) -> (R + (L + k + c_i)^3, L)
+//   it is invertible whatever the round function, and needs twice as many rounds
+// - a hash function, with the Miyaguchi-Preneel construction h_i = E_{h_(i-1)}(m_i) + h_(i-1) + m_i
+// The round constants are c_0 = 0 and c_i = SHA-256("MiMC" || i) mod p
+// (the original MiMC uses Keccak, and fields like the scalar field of BN254 where 3 divides p - 1, see MiMC-7)
+
+pub mod field {
+    #![allow(non_local_definitions)] // the derive macro writes its impl blocks inside a function
+    use ark_ff::{Fp256, MontBackend, MontConfig};
+
+    // p = 2^255 - 46545 is a safe prime with p = 2 mod 3
+    #[derive(MontConfig)]
+    #[modulus = "57896044618658097711785492504343953926634992332820282019728792003956564773423"]
+    #[generator = "5"]
+    pub struct MimcFieldConfig;
+    pub type Fp = Fp256<MontBackend<MimcFieldConfig, 4>>;
+}
+pub use field::Fp;
+
+pub const MIMC_ROUNDS: usize = 161;
+pub const MIMC_FEISTEL_ROUNDS: usize = 2 * MIMC_ROUNDS;
+
+pub fn mimc_round_constants(rounds: usize) -> Vec<Fp> {
+    (0..rounds)
+        .map(|i| {
+            if i == 0 {
+                return Fp::from(0);
+            }
+            let digest = Sha256::new()
+                .chain_update(b"MiMC")
+                .chain_update((i as u32).to_be_bytes())
+                .finalize();
+            Fp::from_be_bytes_mod_order(&digest)
+        })
+        .collect()
+}
+
+pub fn fp_from_hex(hex: &str) -> Fp {
+    let bytes: Vec<u8> = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect();
+    Fp::from_be_bytes_mod_order(&bytes)
+}
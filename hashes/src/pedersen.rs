@@ -0,0 +1,94 @@
+use crate::*;
+use ark_bls12_381::{Fq, G1Affine, G1Projective};
+use ark_ec::AffineRepr;
+use sha2::{Digest, Sha256};
+
+// The Pedersen hash maps a bit string m_1 ... m_n to the point H(m) = m_1 G_1 + ... + m_n G_n
+// of a prime order group, here the group G1 of BLS12-381 (of order r, the modulus of Fr)
+// The generators G_i must be independent: nobody may know a relation c_1 G_1 + ... + c_n G_n = 0
+// with c != 0, since a collision H(m) = H(m') is exactly such a relation, with c_i = m_i - m'_i
+// Conversely, a collision finder solves discrete logs: given a challenge (G, Y = x G), choose
+// G_i = a_i G + b_i Y with random a_i, b_i; these generators look independent and uniform, and a relation
+// gives (sum c_i a_i) G + (sum c_i b_i) Y = 0, hence x (unless sum c_i b_i = 0, which happens with probability 1/r)
+// Real-world Pedersen hashes (Zcash Sapling) encode chunks of 3 bits as small signed integers rather than
+// single bits, and prefix the message with a personalization string
+
+pub const PEDERSEN_BITS: usize = 256;
+
+// "nothing-up-my-sleeve" generators: try-and-increment on x = SHA-256(domain || index || counter),
+// then multiply by the cofactor of G1 to land in the subgroup of order r
+pub fn hash_to_g1(domain: &[u8], index: u32) -> G1Projective {
+    (0u32..)
+        .find_map(|counter| {
+            let digest = Sha256::new()
+                .chain_update(domain)
+                .chain_update(index.to_be_bytes())
+                .chain_update(counter.to_be_bytes())
+                .finalize();
+            let point =
+                G1Affine::get_point_from_x_unchecked(Fq::from_be_bytes_mod_order(&digest), false)?
+                    .clear_cofactor();
+            (!point.is_zero()).then(|| point.into_group())
+        })
+        .unwrap()
+}
+
+pub fn pedersen_generators(n: usize) -> Vec<G1Projective> {
+    (0..n as u32)
+        .map(|i| hash_to_g1(b"Pedersen hash generators", i))
+        .collect()
+}
+
+pub fn random_bits<R: Rng>(n: usize, rng: &mut R) -> Vec<bool> {
+    (0..n).map(|_| rng.gen()).collect()
+}
+
+// the generators built by a reduction from a discrete log challenge (base, target = x base)
+pub struct DlogReduction {
+    pub base: G1Projective,
+    pub target: G1Projective,
+    pub a: Vec<Fr>,
+    pub b: Vec<Fr>,
+}
+
+impl DlogReduction {
+    pub fn generators(&self) -> Vec<G1Projective> {
+        self.a
+            .iter()
+            .zip(&self.b)
+            .map(|(a, b)| self.base * a + self.target * b)
+            .collect()
+    }
+
+    // A collision finder is out of reach, so we plant a collision instead: the challenger, who knows x,
+    // picks two messages and solves for the last a_k such that they collide
+    pub fn with_planted_collision<R: Rng>(
+        n: usize,
+        rng: &mut R,
+    ) -> (DlogReduction, Vec<bool>, Vec<bool>) {
+        let base = pedersen_generators(1)[0];
+        let x = Fr::rand(rng);
+        let message1 = random_bits(n, rng);
+        let mut message2 = random_bits(n, rng);
+        let k = rng.gen_range(0..n);
+        message2[k] = !message1[k];
+        let c: Vec<Fr> = message1
+            .iter()
+            .zip(&message2)
+            .map(|(m1, m2)| Fr::from(*m1 as u64) - Fr::from(*m2 as u64))
+            .collect();
+        let mut a: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+        let b: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+        // sum c_i (a_i + x b_i) = 0
+        let others: Fr = (0..n).filter(|i| *i != k).map(|i| c[i] * a[i]).sum::<Fr>()
+            + x * (0..n).map(|i| c[i] * b[i]).sum::<Fr>();
+        a[k] = -others / c[k];
+        let reduction = DlogReduction {
+            base,
+            target: base * x,
+            a,
+            b,
+        };
+        (reduction, message1, message2)
+    }
+}
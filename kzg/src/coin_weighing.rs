@@ -0,0 +1,89 @@
+use crate::*;
+
+// The twelve-coin puzzle, with Pedersen commitments instead of a balance
+// One of the coins is counterfeit, lighter or heavier than the others: find it, and tell whether it is lighter or heavier
+// The game master commits to the weight of each coin, C_i = w_i G + r_i H, and answers queries:
+// for coefficients c_i in {-1, 0, 1} (a coin on the left pan, off the scale, or on the right pan)
+// it opens the combination sum c_i C_i, a commitment to sum c_i w_i by homomorphism
+// The player checks every opening against the commitments: the game master may lie!
+// Score: the number of openings needed, the fewer the better
+
+pub const COINS: usize = 12;
+pub const WEIGHT: i64 = 10;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Opening {
+    pub value: i64,
+    pub blinding: Fr,
+}
+
+pub struct CoinGame {
+    pub params: PedersenParams,
+    pub commitments: Vec<G1Affine>,
+    pub openings: usize,
+    weights: Vec<i64>,
+    blindings: Vec<Fr>,
+    lie_at: Option<usize>, // the opening at which a dishonest game master lies
+}
+
+impl CoinGame {
+    pub fn new<R: Rng>(counterfeit: usize, heavier: bool, rng: &mut R) -> CoinGame {
+        let params = PedersenParams::new();
+        let weights: Vec<i64> = (0..COINS)
+            .map(|i| match i == counterfeit {
+                false => WEIGHT,
+                true if heavier => WEIGHT + 1,
+                true => WEIGHT - 1,
+            })
+            .collect();
+        let blindings: Vec<Fr> = (0..COINS).map(|_| Fr::rand(rng)).collect();
+        let commitments = weights
+            .iter()
+            .zip(&blindings)
+            .map(|(w, r)| params.commit(Fr::from(*w), *r))
+            .collect();
+        CoinGame {
+            params,
+            commitments,
+            openings: 0,
+            weights,
+            blindings,
+            lie_at: None,
+        }
+    }
+
+    // a game master lying on its n-th opening (starting from 0)
+    pub fn dishonest<R: Rng>(
+        counterfeit: usize,
+        heavier: bool,
+        lie_at: usize,
+        rng: &mut R,
+    ) -> CoinGame {
+        CoinGame {
+            lie_at: Some(lie_at),
+            ..CoinGame::new(counterfeit, heavier, rng)
+        }
+    }
+
+    pub fn open(&mut self, coefficients: &[i64; COINS]) -> Opening {
+        assert!(
+            coefficients.iter().all(|c| (-1..=1).contains(c)),
+            "a coin is on the left pan, on the right pan, or off the scale"
+        );
+        let mut value: i64 = coefficients
+            .iter()
+            .zip(&self.weights)
+            .map(|(c, w)| c * w)
+            .sum();
+        let blinding = coefficients
+            .iter()
+            .zip(&self.blindings)
+            .map(|(c, r)| Fr::from(*c) * r)
+            .sum();
+        if self.lie_at == Some(self.openings) {
+            value += 1;
+        }
+        self.openings += 1;
+        Opening { value, blinding }
+    }
+}
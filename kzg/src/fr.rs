@@ -0,0 +1,66 @@
+use runner::lang::{Catalog, Lang, Statements};
+
+pub const CATALOG: Catalog = Catalog {
+    lang: Lang::Fr,
+    statements: Statements::Table(&[
+        ("Q1", "vérification groupée d'ouvertures de polynômes *différents* en des points *différents*\nRéécrire l'équation de vérification sous la forme e(C - [y]_1 + z*pi, [1]_2) = e(pi, [tau]_2)\nLe couplage de droite ne dépend plus de z, donc n équations de ce type peuvent être fusionnées\nen une seule en prenant une combinaison linéaire aléatoire de scalaires r_1, ..., r_n\nÉcrire une fonction `batch_verify` qui vérifie toutes les ouvertures avec seulement deux couplages"),
+        ("Q2", "une seule preuve pour les évaluations d'*un* polynôme en *plusieurs* points z_1, ..., z_k\nSoit I(X) le polynôme de degré < k qui interpole les valeurs annoncées (utiliser `interpolate`)\net Z_S(X) = (X - z_1)...(X - z_k) le polynôme annulateur de l'ensemble S (utiliser `vanishing_polynomial`)\nAlors p(X) - I(X) est divisible par Z_S(X) et pi = [(p(tau) - I(tau)) / Z_S(tau)]_1 est la preuve\nÉcrire une fonction `multiproof_open` qui renvoie les valeurs et cette preuve unique"),
+        ("Q3", "écrire une fonction `multiproof_verify` qui vérifie l'équation e(C - [I(tau)]_1, [1]_2) = e(pi, [Z_S(tau)]_2)\nNB : c'est pourquoi le SRS contient quelques puissances de tau dans G2\nElle renvoie false, plutôt que de paniquer, pour les ouvertures qu'elle ne peut pas vérifier"),
+        ("Q4", "le vérifieur de la multipreuve fait encore un travail linéaire en k, où est-il dépensé ?\nPourquoi est-il malgré tout bien moins cher que la vérification de k preuves individuelles ?"),
+        ("Q5", "écrire une fonction `verkle_prove` qui renvoie la preuve de la feuille en position `index` :\nles engagements des nœuds du chemin (sauf la racine) et les ouvertures KZG à chaque niveau"),
+        ("Q6", "écrire une fonction `verkle_verify` qui parcourt le chemin depuis l'engagement de la racine\nen vérifiant chaque ouverture (point, valeur et preuve KZG) jusqu'à la feuille"),
+        ("Q7", "une preuve de Merkle croît comme (width - 1) * log_width(n) hachés, une preuve Verkle comme log_width(n)\nouvertures seulement. Pourquoi ne pas simplement utiliser des arbres de Merkle très larges ?\nQue paient les arbres Verkle pour leurs preuves courtes ?"),
+        ("Q8", "jeter un œil au module `pedersen`\nÉcrire `update_pedersen` qui transforme un engagement sur m en engagement sur m + delta\nsans connaître m ; l'ouverture du nouvel engagement est (m + delta, r)"),
+        ("Q9", "un rollup garde les soldes de ses comptes dans un engagement vectoriel KZG (`commit_vector`)\nQuand le solde i change de delta, le polynôme change de delta * L_i(X) où L_i est le i-ème\npolynôme de la base de Lagrange : écrire `update_vector_commitment` avec les [L_i(tau)]_1 précalculés"),
+        ("Q10", "la mise à jour est gratuite pour l'engagement, mais qu'en est-il des preuves d'ouverture détenues par les utilisateurs ?\nComment la preuve du compte j change-t-elle quand le solde i != j change ?"),
+        ("Q11", "écrire `verified_open` qui demande une ouverture au maître du jeu et la vérifie contre\nla combinaison homomorphe des engagements, et renvoie None si le maître du jeu a menti"),
+        ("Q12", "écrire `find_counterfeit` qui trouve la fausse pièce et si elle est plus lourde, en aussi peu\nd'ouvertures que possible : elle est jouée contre les 24 situations possibles et notée sur la pire"),
+        ("Q13", "quel est le meilleur score possible, et pourquoi ?\nEt si les coefficients pouvaient être des entiers quelconques ? Et si le maître du jeu ouvrait les engagements un par un ?"),
+        ("Q14", "écrire `accumulator` qui renvoie les n + 1 valeurs Z(w^0), ..., Z(w^n) de l'accumulateur\npour les défis donnés : la dernière revient à 1 si et seulement si les contraintes de copie sont satisfaites"),
+        ("Q15", "écrire `permutation_prove`, qui renvoie None si le témoin viole une contrainte de copie, et sinon :\n- s'engager sur les polynômes de fils v_j, obtenir beta et gamma de la transcription\n- s'engager sur Z, obtenir alpha de la transcription\n- s'engager sur le quotient t(X) = (L_1(X) (Z(X) - 1) + alpha * seconde identité) / Z_H(X),\nobtenir le point d'évaluation zeta de la transcription\n- ouvrir v_j, S_j, Z et t en zeta, et Z en zeta * w"),
+        ("Q16", "écrire `permutation_verify` qui rejoue la transcription, vérifie les ouvertures\n(points compris) et les deux identités en zeta : L_1(zeta) (Z(zeta) - 1) + alpha * ... = t(zeta) Z_H(zeta)"),
+        ("Q17", "pourquoi beta et gamma doivent-ils être tirés après les engagements des fils, et zeta après l'engagement du quotient ?\nPourquoi la seconde identité a-t-elle besoin de l'ouverture de Z en zeta * w ?\nLes ouvertures en zeta révèlent des évaluations des polynômes de fils : comment PLONK garde-t-il le témoin caché ?"),
+    ]),
+    hints: &[
+        (
+            "Q1",
+            &[
+                "Une combinaison linéaire aléatoire d'équations est vraie pour toutes, sauf avec une probabilité négligeable",
+                "Sommer r_i (C_i - [y_i]_1 + z_i pi_i) à gauche et r_i pi_i à droite",
+                "Vérifier e(sum r_i (C_i - [y_i]_1 + z_i pi_i), [1]_2) = e(sum r_i pi_i, [tau]_2) avec des r_i aléatoires",
+            ],
+        ),
+        (
+            "Q2",
+            &[
+                "p(X) - I(X) s'annule en chaque z_i, donc Z_S(X) le divise",
+                "Le quotient q(X) = (p(X) - I(X)) / Z_S(X) est un polynôme de degré deg p - k",
+                "La preuve est l'engagement sur le quotient, calculé avec les puissances de tau dans G1 comme pour une ouverture simple",
+            ],
+        ),
+        (
+            "Q9",
+            &[
+                "Un engagement KZG est linéaire en le polynôme",
+                "Le nouveau polynôme est p(X) + delta L_i(X), dont l'engagement est C + delta [L_i(tau)]_1",
+                "Ajouter delta fois l'engagement précalculé de L_i : aucune arithmétique de polynômes",
+            ],
+        ),
+        (
+            "Q12",
+            &[
+                "Il y a 24 situations : 12 pièces, plus lourde ou plus légère",
+                "Une ouverture comparée au total honnête a 3 issues : plus léger, équilibré ou plus lourd",
+                "Garder les situations candidates, et choisir chaque requête pour que ses 3 issues les partagent aussi également que possible",
+            ],
+        ),
+        (
+            "Q13",
+            &[
+                "Compter les situations possibles et les issues possibles",
+                "k ouvertures à 3 issues chacune distinguent au plus 3^k situations",
+                "3^2 < 24 <= 3^3 : aucune stratégie ne fait mieux que 3 ouvertures",
+            ],
+        ),
+    ],
+};
@@ -0,0 +1,50 @@
+use runner::hints::Hints;
+use runner::lang::Catalog;
+
+pub const CATALOGS: &[Catalog] = &[
+    Catalog::english(HINTS, include_str!("main.rs")),
+    crate::fr::CATALOG,
+];
+
+pub const HINTS: Hints = &[
+    (
+        "Q1",
+        &[
+            "A random linear combination of equations holds for all of them, except with negligible probability",
+            "Sum r_i (C_i - [y_i]_1 + z_i pi_i) on the left and sum r_i pi_i on the right",
+            "Check e(sum r_i (C_i - [y_i]_1 + z_i pi_i), [1]_2) = e(sum r_i pi_i, [tau]_2) with random r_i",
+        ],
+    ),
+    (
+        "Q2",
+        &[
+            "p(X) - I(X) vanishes on every z_i, so Z_S(X) divides it",
+            "The quotient q(X) = (p(X) - I(X)) / Z_S(X) is a polynomial of degree deg p - k",
+            "The proof is the commitment to the quotient, computed with the powers of tau in G1 as for a single opening",
+        ],
+    ),
+    (
+        "Q9",
+        &[
+            "A KZG commitment is linear in the polynomial",
+            "The new polynomial is p(X) + delta L_i(X), whose commitment is C + delta [L_i(tau)]_1",
+            "Add delta times the precomputed commitment of L_i: no polynomial arithmetic at all",
+        ],
+    ),
+    (
+        "Q12",
+        &[
+            "There are 24 situations: 12 coins, heavier or lighter",
+            "An opening compared with the honest total has 3 outcomes: lighter, balanced or heavier",
+            "Keep the candidate situations, and choose each query so that its 3 outcomes split them as evenly as possible",
+        ],
+    ),
+    (
+        "Q13",
+        &[
+            "Count the possible situations and the possible outcomes",
+            "k openings with 3 outcomes each distinguish at most 3^k situations",
+            "3^2 < 24 <= 3^3: no strategy does better than 3 openings",
+        ],
+    ),
+];
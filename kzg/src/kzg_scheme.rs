@@ -0,0 +1,133 @@
+use crate::*;
+
+// Structured reference string: powers of a secret tau in both source groups
+// [tau^i]_1 for i = 0..=max_degree and [tau^i]_2 for i = 0..=max_batch_size
+pub struct Srs {
+    pub g1_powers: Vec<G1Affine>,
+    pub g2_powers: Vec<G2Affine>,
+}
+
+#[derive(Clone)]
+pub struct KzgOpening {
+    pub point: Fr,       // z
+    pub value: Fr,       // y = p(z)
+    pub proof: G1Affine, // pi = [q(tau)]_1 with q(X) = (p(X) - y) / (X - z)
+}
+
+// a single proof for the evaluations of one polynomial at several points
+#[derive(Clone)]
+pub struct KzgMultiOpening {
+    pub points: Vec<Fr>,
+    pub values: Vec<Fr>,
+    pub proof: G1Affine, // pi = [q(tau)]_1 with q(X) = (p(X) - I(X)) / Z_S(X)
+}
+
+// trusted setup; tau is sampled and dropped right away (in real life, this is a multi-party ceremony)
+pub fn kzg_setup<R: Rng>(max_degree: usize, max_batch_size: usize, rng: &mut R) -> Srs {
+    let tau = Fr::rand(rng);
+    let g1 = G1Affine::generator();
+    let g2 = G2Affine::generator();
+    let mut g1_powers = Vec::with_capacity(max_degree + 1);
+    let mut g2_powers = Vec::with_capacity(max_batch_size + 1);
+    let mut power = Fr::one();
+    for i in 0..=max_degree.max(max_batch_size) {
+        if i <= max_degree {
+            g1_powers.push(g1.mul(power).into_affine());
+        }
+        if i <= max_batch_size {
+            g2_powers.push(g2.mul(power).into_affine());
+        }
+        power *= tau;
+    }
+    Srs {
+        g1_powers,
+        g2_powers,
+    }
+}
+
+// [p(tau)]_1 computed as a multi-scalar multiplication of the coefficients with the powers of tau
+pub fn commit_g1(srs: &Srs, p: &DensePolynomial<Fr>) -> G1Affine {
+    assert!(
+        p.coeffs.len() <= srs.g1_powers.len(),
+        "degree too large for this SRS"
+    );
+    G1Projective::msm(&srs.g1_powers[..p.coeffs.len()], &p.coeffs)
+        .unwrap()
+        .into_affine()
+}
+
+// [p(tau)]_2, only needed for small polynomials (vanishing polynomials of batches)
+pub fn commit_g2(srs: &Srs, p: &DensePolynomial<Fr>) -> G2Affine {
+    assert!(
+        p.coeffs.len() <= srs.g2_powers.len(),
+        "degree too large for this SRS"
+    );
+    G2Projective::msm(&srs.g2_powers[..p.coeffs.len()], &p.coeffs)
+        .unwrap()
+        .into_affine()
+}
+
+pub fn kzg_commit(srs: &Srs, p: &DensePolynomial<Fr>) -> G1Affine {
+    commit_g1(srs, p)
+}
+
+// exact polynomial division, panics if the remainder is not zero
+pub fn divide_exact(p: &DensePolynomial<Fr>, d: &DensePolynomial<Fr>) -> DensePolynomial<Fr> {
+    let (q, r) = DenseOrSparsePolynomial::from(p)
+        .divide_with_q_and_r(&DenseOrSparsePolynomial::from(d))
+        .unwrap();
+    assert!(r.is_zero(), "division is not exact");
+    q
+}
+
+pub fn kzg_open(srs: &Srs, p: &DensePolynomial<Fr>, z: Fr) -> KzgOpening {
+    let y = p.evaluate(&z);
+    // q(X) := (p(X) - y) / (X - z)
+    let numerator = p - &DensePolynomial::from_coefficients_vec(vec![y]);
+    let divisor = DensePolynomial::from_coefficients_vec(vec![-z, Fr::one()]);
+    let q = divide_exact(&numerator, &divisor);
+    KzgOpening {
+        point: z,
+        value: y,
+        proof: commit_g1(srs, &q),
+    }
+}
+
+pub fn kzg_verify(srs: &Srs, commitment: &G1Affine, opening: &KzgOpening) -> bool {
+    let g1 = srs.g1_powers[0];
+    let g2 = srs.g2_powers[0];
+    let tau_g2 = srs.g2_powers[1];
+    // e(C - [y]_1, [1]_2) = e(pi, [tau - z]_2) ?
+    let lhs = Bls12_381::pairing(commitment.into_group() - g1.mul(opening.value), g2);
+    let rhs = Bls12_381::pairing(opening.proof, tau_g2.into_group() - g2.mul(opening.point));
+    lhs == rhs
+}
+
+// vanishing polynomial Z_S(X) = prod_{z in S} (X - z)
+pub fn vanishing_polynomial(points: &[Fr]) -> DensePolynomial<Fr> {
+    points.iter().fold(
+        DensePolynomial::from_coefficients_vec(vec![Fr::one()]),
+        |acc, z| &acc * &DensePolynomial::from_coefficients_vec(vec![-*z, Fr::one()]),
+    )
+}
+
+// Lagrange interpolation: the unique polynomial I of degree < n with I(points[i]) = values[i]
+pub fn interpolate(points: &[Fr], values: &[Fr]) -> DensePolynomial<Fr> {
+    assert_eq!(points.len(), values.len());
+    let mut result = DensePolynomial::from_coefficients_vec(vec![]);
+    for (i, (z_i, y_i)) in points.iter().zip(values).enumerate() {
+        let mut basis = DensePolynomial::from_coefficients_vec(vec![Fr::one()]);
+        let mut denominator = Fr::one();
+        for (j, z_j) in points.iter().enumerate() {
+            if i != j {
+                basis = &basis * &DensePolynomial::from_coefficients_vec(vec![-*z_j, Fr::one()]);
+                denominator *= *z_i - z_j;
+            }
+        }
+        let scale = *y_i * denominator.inverse().unwrap();
+        result += &DensePolynomial::from_coefficients_vec(
+            basis.coeffs.iter().map(|c| *c * scale).collect(),
+        );
+    }
+    result
+}
@@ -0,0 +1,788 @@
+use ark_bls12_381::{Bls12_381, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup, VariableBaseMSM};
+use ark_ff::PrimeField;
+use ark_ff::{FftField, Field, One, UniformRand, Zero};
+use ark_poly::{
+    univariate::{DenseOrSparsePolynomial, DensePolynomial},
+    DenseUVPolynomial, EvaluationDomain, Polynomial,
+};
+use ark_serialize::CanonicalSerialize;
+use ark_std::ops::Mul;
+use coin_weighing::*;
+use kzg_scheme::*;
+use pedersen::*;
+use permutation::*;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+use runner::Runner;
+use std::time::Instant;
+use verkle::*;
+
+mod coin_weighing;
+mod fr;
+mod hints;
+mod kzg_scheme;
+mod pedersen;
+mod permutation;
+mod verkle;
+
+const DEGREE: usize = 63;
+const BATCH_SIZE: usize = 16;
+const VERKLE_LEAVES: usize = 256;
+const ACCOUNTS: usize = 64;
+const TRANSACTIONS: usize = 100;
+const WIRING_ROWS: usize = 16;
+
+fn main() {
+    let runner = Runner::start(env!("CARGO_PKG_NAME"), hints::CATALOGS);
+    let mut rng = seed::rng();
+
+    // Take a look at module `kzg_scheme`
+    // It implements the KZG polynomial commitment scheme over the BLS12-381 pairing-friendly curve
+    // `kzg_setup` samples the structured reference string [tau^i]_1, [tau^i]_2
+    // `kzg_commit` commits to a polynomial p as C = [p(tau)]_1
+    // `kzg_open` proves that p(z) = y with the quotient q(X) = (p(X) - y) / (X - z), and pi = [q(tau)]_1
+    // `kzg_verify` checks the pairing equation e(C - [y]_1, [1]_2) = e(pi, [tau - z]_2)
+    let srs = kzg_setup(DEGREE, BATCH_SIZE, &mut rng);
+    let p = DensePolynomial::<Fr>::rand(DEGREE, &mut rng);
+    let c = kzg_commit(&srs, &p);
+    let opening = kzg_open(&srs, &p, Fr::rand(&mut rng));
+    assert!(kzg_verify(&srs, &c, &opening));
+
+    // Verifying one opening costs two pairings, the most expensive operation of the scheme
+    // A verifier receiving many openings (e.g. a rollup node, a data availability sampler) wants to do better
+
+    // Q1: batch verification of openings of *different* polynomials at *different* points
+    // Rewrite the verification equation as e(C - [y]_1 + z*pi, [1]_2) = e(pi, [tau]_2)
+    // The right pairing no longer depends on z, so n such equations can be merged
+    // into a single one by taking a random linear combination with scalars r_1, ..., r_n
+    // Write a function `batch_verify` that checks all openings with only two pairings
+    runner.question("Q1");
+    let commitments_and_openings: Vec<_> = (0..BATCH_SIZE)
+        .map(|_| {
+            let p = DensePolynomial::<Fr>::rand(DEGREE, &mut rng);
+            let opening = kzg_open(&srs, &p, Fr::rand(&mut rng));
+            (kzg_commit(&srs, &p), opening)
+        })
+        .collect();
+    assert!(batch_verify(&srs, &commitments_and_openings, &mut rng));
+
+    // The batched check must still reject a single bad opening in the batch
+    let mut tampered = commitments_and_openings;
+    tampered[BATCH_SIZE / 2].1.value += Fr::one();
+    assert!(!batch_verify(&srs, &tampered, &mut rng));
+    tampered[BATCH_SIZE / 2].1.value -= Fr::one();
+    tampered[0].1.point += Fr::one();
+    assert!(!batch_verify(&srs, &tampered, &mut rng));
+
+    // Q2: a single proof for the evaluations of *one* polynomial at *several* points z_1, ..., z_k
+    // Let I(X) be the polynomial of degree < k interpolating the claimed values (use `interpolate`)
+    // and Z_S(X) = (X - z_1)...(X - z_k) the vanishing polynomial of the set S (use `vanishing_polynomial`)
+    // Then p(X) - I(X) is divisible by Z_S(X) and pi = [(p(tau) - I(tau)) / Z_S(tau)]_1 is the proof
+    // Write a function `multiproof_open` that outputs the values and this single proof
+    runner.question("Q2");
+    let points: Vec<Fr> = (0..BATCH_SIZE).map(|_| Fr::rand(&mut rng)).collect();
+    let multi_opening = multiproof_open(&srs, &p, &points);
+    for (z, y) in points.iter().zip(&multi_opening.values) {
+        assert_eq!(p.evaluate(z), *y);
+    }
+
+    // Q3: write a function `multiproof_verify` that checks the equation e(C - [I(tau)]_1, [1]_2) = e(pi, [Z_S(tau)]_2)
+    // NB: this is why the SRS contains a few powers of tau in G2
+    // It returns false, rather than panicking, on openings it cannot check
+    runner.question("Q3");
+    assert!(multiproof_verify(&srs, &c, &multi_opening));
+
+    // It must reject wrong values and proofs meant for another set of points
+    let mut wrong_value = multi_opening.clone();
+    wrong_value.values[1] += Fr::one();
+    assert!(!multiproof_verify(&srs, &c, &wrong_value));
+    let mut wrong_points = multi_opening.clone();
+    wrong_points.points[0] = Fr::rand(&mut rng);
+    assert!(!multiproof_verify(&srs, &c, &wrong_points));
+    let other = kzg_commit(&srs, &DensePolynomial::<Fr>::rand(DEGREE, &mut rng));
+    assert!(!multiproof_verify(&srs, &other, &multi_opening));
+    // and, without panicking, the openings it cannot check: Z_S(X) of more points than the powers of tau in G2,
+    // values which do not match the points, a point given twice
+    let too_many: Vec<Fr> = (0..srs.g2_powers.len())
+        .map(|_| Fr::rand(&mut rng))
+        .collect();
+    assert!(!multiproof_verify(
+        &srs,
+        &c,
+        &multiproof_open(&srs, &p, &too_many)
+    ));
+    let mut missing_value = multi_opening.clone();
+    missing_value.values.pop();
+    assert!(!multiproof_verify(&srs, &c, &missing_value));
+    let mut repeated_point = multi_opening.clone();
+    repeated_point.points[1] = repeated_point.points[0];
+    repeated_point.values[1] = repeated_point.values[0];
+    assert!(!multiproof_verify(&srs, &c, &repeated_point));
+
+    // Let us now compare the cost of the three approaches
+    let individual_openings: Vec<_> = points.iter().map(|z| kzg_open(&srs, &p, *z)).collect();
+    let start = Instant::now();
+    for opening in &individual_openings {
+        assert!(kzg_verify(&srs, &c, opening));
+    }
+    let individual_time = start.elapsed();
+    let batch: Vec<_> = individual_openings
+        .into_iter()
+        .map(|opening| (c, opening))
+        .collect();
+    let start = Instant::now();
+    assert!(batch_verify(&srs, &batch, &mut rng));
+    let batch_time = start.elapsed();
+    let start = Instant::now();
+    assert!(multiproof_verify(&srs, &c, &multi_opening));
+    let multiproof_time = start.elapsed();
+
+    let single_proof_size = multi_opening.proof.compressed_size();
+    println!(
+        "Verifying {} openings of a degree {} polynomial:",
+        BATCH_SIZE, DEGREE
+    );
+    println!(
+        "  individual proofs: {:>10.2?}, {} bytes of proofs",
+        individual_time,
+        BATCH_SIZE * single_proof_size
+    );
+    println!(
+        "  batched proofs:    {:>10.2?}, {} bytes of proofs",
+        batch_time,
+        BATCH_SIZE * single_proof_size
+    );
+    println!(
+        "  multiproof:        {:>10.2?}, {} bytes of proofs\n",
+        multiproof_time, single_proof_size
+    );
+
+    // Q4: the multiproof verifier still does work linear in k, where is it spent?
+    // Why is it nonetheless much cheaper than verifying k individual proofs?
+    runner.question("Q4");
+
+    // Now take a look at module `verkle`
+    // A Verkle trie replaces the hashes of a Merkle tree by vector commitments:
+    // each node commits with `commit_vector` to the values of its `width` children
+    // (the leaves themselves on the last level, `commitment_to_field` of the child commitments above)
+    // A path proof then only needs one opening per level instead of all the siblings
+    let leaves: Vec<Fr> = (0..VERKLE_LEAVES).map(|_| Fr::rand(&mut rng)).collect();
+    let verkle_srs = kzg_setup(VERKLE_LEAVES - 1, 1, &mut rng);
+    let width = 16;
+    let trie = build_verkle(&verkle_srs, width, &leaves);
+
+    // Q5: write a function `verkle_prove` that outputs the proof for the leaf at position `index`:
+    // the commitments of the nodes on the path (except the root) and the KZG openings at each level
+    runner.question("Q5");
+    let index = 0xa7;
+    let proof = verkle_prove(&verkle_srs, &trie, width, index);
+
+    // Q6: write a function `verkle_verify` that walks the path from the root commitment
+    // checking every opening (point, value and KZG proof) down to the leaf
+    runner.question("Q6");
+    let root = trie.commitment;
+    assert!(verkle_verify(
+        &verkle_srs,
+        &root,
+        width,
+        index,
+        &leaves[index],
+        &proof
+    ));
+    assert!(!verkle_verify(
+        &verkle_srs,
+        &root,
+        width,
+        index,
+        &leaves[index + 1],
+        &proof
+    ));
+    assert!(!verkle_verify(
+        &verkle_srs,
+        &root,
+        width,
+        index + 1,
+        &leaves[index],
+        &proof
+    ));
+    let mut forged = verkle_prove(&verkle_srs, &trie, width, index);
+    forged.commitments[0] = trie.children[0].commitment;
+    assert!(!verkle_verify(
+        &verkle_srs,
+        &root,
+        width,
+        index,
+        &leaves[index],
+        &forged
+    ));
+
+    // Let us compare proof sizes with a binary Merkle tree holding the same data
+    let merkle = MerkleTree::new(&leaves);
+    let siblings = merkle.prove(index);
+    assert!(merkle_verify(
+        &merkle.root(),
+        index,
+        &leaves[index],
+        &siblings
+    ));
+    println!("Proving one leaf out of {}:", VERKLE_LEAVES);
+    println!("  binary Merkle tree:      {} bytes", siblings.len() * 32);
+    for width in [2, 4, 16, 256] {
+        let trie = build_verkle(&verkle_srs, width, &leaves);
+        let proof = verkle_prove(&verkle_srs, &trie, width, index);
+        assert!(verkle_verify(
+            &verkle_srs,
+            &trie.commitment,
+            width,
+            index,
+            &leaves[index],
+            &proof
+        ));
+        println!("  Verkle trie, width {:>3}: {} bytes", width, proof.size());
+    }
+    println!();
+
+    // Q7: a Merkle proof grows with (width - 1) * log_width(n) hashes, a Verkle proof with log_width(n)
+    // openings only. Why can't we simply use very wide Merkle trees instead?
+    // What do Verkle tries pay for their short proofs?
+    runner.question("Q7");
+
+    // Commitments are homomorphic: this is what lets a rollup update a commitment to its state
+    // after each transaction without recomputing it from scratch
+
+    // Q8: take a look at module `pedersen`
+    // Write `update_pedersen` which turns a commitment to m into a commitment to m + delta
+    // without knowing m; the opening of the new commitment is (m + delta, r)
+    runner.question("Q8");
+    let params = PedersenParams::new();
+    let (m, r, delta) = (Fr::rand(&mut rng), Fr::rand(&mut rng), Fr::rand(&mut rng));
+    let commitment = params.commit(m, r);
+    let updated = update_pedersen(&params, &commitment, delta);
+    assert_eq!(updated, params.commit(m + delta, r));
+
+    // Q9: a rollup keeps the balances of its accounts in a KZG vector commitment (`commit_vector`)
+    // When the balance i changes by delta, the polynomial changes by delta * L_i(X) where L_i is the i-th
+    // Lagrange basis polynomial: write `update_vector_commitment` using the precomputed [L_i(tau)]_1
+    runner.question("Q9");
+    let mut balances: Vec<Fr> = (0..ACCOUNTS).map(|i| Fr::from(1000 + i as u64)).collect();
+    let state_srs = kzg_setup(ACCOUNTS - 1, 1, &mut rng);
+    let lagrange = lagrange_commitments(&state_srs, ACCOUNTS);
+    let (_, mut state) = commit_vector(&state_srs, &balances);
+    let transactions: Vec<(usize, Fr)> = (0..TRANSACTIONS)
+        .map(|_| {
+            (
+                rng.gen_range(0..ACCOUNTS),
+                Fr::from(rng.gen_range(0..100u64)),
+            )
+        })
+        .collect();
+    let start = Instant::now();
+    for (i, amount) in &transactions {
+        state = update_vector_commitment(&lagrange, &state, *i, *amount);
+        balances[*i] += amount;
+    }
+    let update_time = start.elapsed();
+    let start = Instant::now();
+    let (polynomial, recomputed) = commit_vector(&state_srs, &balances);
+    let recompute_time = start.elapsed();
+    assert_eq!(state, recomputed);
+    println!(
+        "Applying {} transactions to the state of {} accounts:",
+        TRANSACTIONS, ACCOUNTS
+    );
+    println!("  homomorphic updates: {:>10.2?}", update_time);
+    println!("  one full recommit:   {:>10.2?}\n", recompute_time);
+
+    // The updated commitment can be opened like a fresh one
+    let opening = kzg_open(&state_srs, &polynomial, Fr::from(3));
+    assert!(kzg_verify(&state_srs, &state, &opening));
+    assert_eq!(opening.value, balances[3]);
+    // and a wrong update is caught
+    let wrong = update_vector_commitment(&lagrange, &state, 4, Fr::one());
+    assert!(!kzg_verify(
+        &state_srs,
+        &wrong,
+        &kzg_open(&state_srs, &polynomial, Fr::from(4))
+    ));
+
+    // Q10: the update is free for the commitment, but what about the opening proofs held by the users?
+    // How does the proof for account j change when balance i != j changes?
+    runner.question("Q10");
+
+    // Time for a game: take a look at module `coin_weighing`
+    // Q11: write `verified_open` which asks the game master for an opening and checks it against
+    // the homomorphic combination of the commitments, returning None if the game master lied
+    runner.question("Q11");
+    let mut game = CoinGame::new(3, true, &mut rng);
+    let mut all_left = [0; COINS];
+    all_left[..6].fill(1);
+    assert_eq!(verified_open(&mut game, &all_left), Some(6 * WEIGHT + 1));
+    let mut liar = CoinGame::dishonest(3, true, 0, &mut rng);
+    assert_eq!(verified_open(&mut liar, &all_left), None);
+
+    // Q12: write `find_counterfeit` which finds the counterfeit coin and whether it is heavier, in as few
+    // openings as you can: it is played against the 24 possible situations and scored on the worst one
+    runner.question("Q12");
+    let mut score = 0;
+    for counterfeit in 0..COINS {
+        for heavier in [false, true] {
+            let mut game = CoinGame::new(counterfeit, heavier, &mut rng);
+            assert_eq!(find_counterfeit(&mut game), Some((counterfeit, heavier)));
+            score = score.max(game.openings);
+        }
+    }
+    println!(
+        "Coin weighing: found the counterfeit coin with at most {} openings\n",
+        score
+    );
+    // a lying game master is caught
+    for lie_at in 0..score {
+        let mut liar = CoinGame::dishonest(5, false, lie_at, &mut rng);
+        assert_eq!(find_counterfeit(&mut liar), None);
+    }
+    // Q13: what is the best possible score, and why?
+    // What if the coefficients could be any integer? What if the game master opened the commitments one by one?
+    runner.question("Q13");
+
+    // Modern proof systems (PLONK and its descendants) are built on top of polynomial commitments
+    // Take a look at module `permutation`: it describes the permutation argument which proves that
+    // the wires of a circuit are connected, a.k.a. the copy constraints
+    // Here is the circuit of x^3 + x + 5 = 35 in 8 rows of 3 wires a * b = c, or a + b = c
+    // (the gate constraints are the other half of PLONK, they are left out)
+    //   row 0: x  * x = x2
+    //   row 1: x2 * x = x3
+    //   row 2: x3 + x = t
+    //   row 3: t  + 5 = 35
+    // the other rows are padding
+    let wire = |column, row| Wire { column, row };
+    let copies = [
+        (wire(0, 0), wire(1, 0)), // x
+        (wire(0, 0), wire(1, 1)),
+        (wire(0, 0), wire(1, 2)),
+        (wire(2, 0), wire(0, 1)), // x2
+        (wire(2, 1), wire(0, 2)), // x3
+        (wire(2, 2), wire(0, 3)), // t
+    ];
+    let circuit_key = PermutationKey::new(&srs, Wiring::new(8, &copies));
+    let mut witness = vec![vec![Fr::zero(); 8]; COLUMNS];
+    for (row, [a, b, c]) in [[3, 3, 9], [9, 3, 27], [27, 3, 30], [30, 5, 35]]
+        .into_iter()
+        .enumerate()
+    {
+        witness[0][row] = Fr::from(a);
+        witness[1][row] = Fr::from(b);
+        witness[2][row] = Fr::from(c);
+    }
+
+    // Q14: write `accumulator` which outputs the n + 1 values Z(w^0), ..., Z(w^n) of the accumulator
+    // for the given challenges: the last one is back to 1 iff the copy constraints hold
+    runner.question("Q14");
+    let (beta, gamma) = (Fr::rand(&mut rng), Fr::rand(&mut rng));
+    let z = accumulator(&circuit_key.wiring, &witness, beta, gamma);
+    assert_eq!(z.len(), 9);
+    assert_eq!(z[0], Fr::one());
+    assert_eq!(z[8], Fr::one());
+    assert!(z[1..8].iter().any(|value| !value.is_one()));
+    // a prover claiming x3 = 28 in row 2 breaks a copy constraint
+    let mut broken = witness.clone();
+    broken[0][2] = Fr::from(28);
+    assert_ne!(
+        accumulator(&circuit_key.wiring, &broken, beta, gamma)[8],
+        Fr::one()
+    );
+
+    // Q15: write `permutation_prove`, returning None if the witness breaks a copy constraint, else:
+    // - commit to the wire polynomials v_j, get beta and gamma from the transcript
+    // - commit to Z, get alpha from the transcript
+    // - commit to the quotient t(X) = (L_1(X) (Z(X) - 1) + alpha * second identity) / Z_H(X),
+    //   get the evaluation point zeta from the transcript
+    // - open v_j, S_j, Z and t at zeta, and Z at zeta * w
+    runner.question("Q15");
+    let proof = permutation_prove(&srs, &circuit_key, &witness).unwrap();
+    assert!(permutation_prove(&srs, &circuit_key, &broken).is_none());
+
+    // Q16: write `permutation_verify` which replays the transcript, checks the openings
+    // (points included) and both identities at zeta: L_1(zeta) (Z(zeta) - 1) + alpha * ... = t(zeta) Z_H(zeta)
+    runner.question("Q16");
+    assert!(permutation_verify(&srs, &circuit_key, &proof));
+
+    // Any tampering is caught
+    let mut tampered = permutation_prove(&srs, &circuit_key, &witness).unwrap();
+    tampered.wire_openings[0] = kzg_open(&srs, &DensePolynomial::rand(7, &mut rng), Fr::one());
+    assert!(!permutation_verify(&srs, &circuit_key, &tampered));
+    let mut tampered = permutation_prove(&srs, &circuit_key, &witness).unwrap();
+    tampered.shifted_accumulator_opening = tampered.accumulator_opening.clone();
+    assert!(!permutation_verify(&srs, &circuit_key, &tampered));
+    // the proof is bound to the wiring: x3 + x = t is not x3 + x2 = t
+    let mut other_copies = copies;
+    other_copies[2] = (wire(2, 0), wire(1, 2));
+    let other_key = PermutationKey::new(&srs, Wiring::new(8, &other_copies));
+    assert!(!permutation_verify(&srs, &other_key, &proof));
+
+    // A larger random wiring
+    let random_copies: Vec<(Wire, Wire)> = (0..2 * WIRING_ROWS)
+        .map(|_| {
+            (
+                wire(rng.gen_range(0..COLUMNS), rng.gen_range(0..WIRING_ROWS)),
+                wire(rng.gen_range(0..COLUMNS), rng.gen_range(0..WIRING_ROWS)),
+            )
+        })
+        .collect();
+    let key = PermutationKey::new(&srs, Wiring::new(WIRING_ROWS, &random_copies));
+    let witness = key.wiring.random_witness(&mut rng);
+    let proof = permutation_prove(&srs, &key, &witness).unwrap();
+    assert!(permutation_verify(&srs, &key, &proof));
+    let size: usize = proof
+        .wire_commitments
+        .iter()
+        .chain([&proof.accumulator_commitment, &proof.quotient_commitment])
+        .map(|c| c.compressed_size())
+        .sum::<usize>()
+        + proof
+            .wire_openings
+            .iter()
+            .chain(&proof.sigma_openings)
+            .chain([
+                &proof.accumulator_opening,
+                &proof.shifted_accumulator_opening,
+                &proof.quotient_opening,
+            ])
+            .map(|o| o.proof.compressed_size() + o.value.compressed_size())
+            .sum::<usize>();
+    println!(
+        "Permutation argument for {} wires: {} bytes of proof\n",
+        COLUMNS * WIRING_ROWS,
+        size
+    );
+
+    // Q17: why must beta and gamma be drawn after the wire commitments, and zeta after the quotient commitment?
+    // Why does the second identity need the opening of Z at zeta * w?
+    // The openings at zeta leak evaluations of the wire polynomials: how does PLONK keep the witness hidden?
+    runner.question("Q17");
+
+    println!("Good job!");
+    runner.finish();
+}
+
+fn batch_verify<R: Rng>(srs: &Srs, openings: &[(G1Affine, KzgOpening)], rng: &mut R) -> bool {
+    // SOLUTION-BEGIN
+    let g1 = srs.g1_powers[0];
+    let g2 = srs.g2_powers[0];
+    let tau_g2 = srs.g2_powers[1];
+    let mut lhs = G1Projective::zero();
+    let mut rhs = G1Projective::zero();
+    for (commitment, opening) in openings {
+        let r = Fr::rand(rng);
+        // r * (C - [y]_1 + z * pi)
+        lhs += (commitment.into_group() - g1.mul(opening.value) + opening.proof.mul(opening.point))
+            .mul(r);
+        // r * pi
+        rhs += opening.proof.mul(r);
+    }
+    Bls12_381::pairing(lhs, g2) == Bls12_381::pairing(rhs, tau_g2)
+    // SOLUTION-END
+}
+
+fn multiproof_open(srs: &Srs, p: &DensePolynomial<Fr>, points: &[Fr]) -> KzgMultiOpening {
+    // SOLUTION-BEGIN
+    let values: Vec<Fr> = points.iter().map(|z| p.evaluate(z)).collect();
+    let interpolation = interpolate(points, &values);
+    let q = divide_exact(&(p - &interpolation), &vanishing_polynomial(points));
+    KzgMultiOpening {
+        points: points.to_vec(),
+        values,
+        proof: commit_g1(srs, &q),
+    }
+    // SOLUTION-END
+}
+
+fn multiproof_verify(srs: &Srs, commitment: &G1Affine, opening: &KzgMultiOpening) -> bool {
+    // SOLUTION-BEGIN
+    let distinct: std::collections::HashSet<&Fr> = opening.points.iter().collect();
+    if opening.points.len() >= srs.g2_powers.len()
+        || opening.points.len() != opening.values.len()
+        || distinct.len() != opening.points.len()
+    {
+        return false;
+    }
+    let interpolation = commit_g1(srs, &interpolate(&opening.points, &opening.values));
+    let vanishing = commit_g2(srs, &vanishing_polynomial(&opening.points));
+    let lhs = Bls12_381::pairing(commitment.into_group() - interpolation, srs.g2_powers[0]);
+    let rhs = Bls12_381::pairing(opening.proof, vanishing);
+    lhs == rhs
+    // SOLUTION-END
+}
+
+fn verkle_prove(srs: &Srs, root: &VerkleNode, width: usize, index: usize) -> VerkleProof {
+    // SOLUTION-BEGIN
+    let depth = root.depth();
+    let mut node = root;
+    let mut commitments = vec![];
+    let mut openings = vec![];
+    for i in path_indices(width, depth, index) {
+        openings.push(kzg_open(srs, &node.polynomial, Fr::from(i as u64)));
+        if !node.children.is_empty() {
+            node = &node.children[i];
+            commitments.push(node.commitment);
+        }
+    }
+    VerkleProof {
+        commitments,
+        openings,
+    }
+    // SOLUTION-END
+}
+
+fn verkle_verify(
+    srs: &Srs,
+    root: &G1Affine,
+    width: usize,
+    index: usize,
+    leaf: &Fr,
+    proof: &VerkleProof,
+) -> bool {
+    // SOLUTION-BEGIN
+    let depth = proof.openings.len();
+    if proof.commitments.len() + 1 != depth || index >= width.pow(depth as u32) {
+        return false;
+    }
+    let mut current = *root;
+    for (level, i) in path_indices(width, depth, index).into_iter().enumerate() {
+        let opening = &proof.openings[level];
+        if opening.point != Fr::from(i as u64) || !kzg_verify(srs, &current, opening) {
+            return false;
+        }
+        let expected = if level + 1 < depth {
+            current = proof.commitments[level];
+            commitment_to_field(&current)
+        } else {
+            *leaf
+        };
+        if opening.value != expected {
+            return false;
+        }
+    }
+    true
+    // SOLUTION-END
+}
+
+fn update_pedersen(params: &PedersenParams, commitment: &G1Affine, delta: Fr) -> G1Affine {
+    // SOLUTION-BEGIN
+    (*commitment + params.g.mul(delta)).into_affine()
+    // SOLUTION-END
+}
+
+fn update_vector_commitment(
+    lagrange: &[G1Affine],
+    commitment: &G1Affine,
+    i: usize,
+    delta: Fr,
+) -> G1Affine {
+    // SOLUTION-BEGIN
+    (*commitment + lagrange[i].mul(delta)).into_affine()
+    // SOLUTION-END
+}
+
+fn verified_open(game: &mut CoinGame, coefficients: &[i64; COINS]) -> Option<i64> {
+    // SOLUTION-BEGIN
+    let opening = game.open(coefficients);
+    let combination: G1Projective = game
+        .commitments
+        .iter()
+        .zip(coefficients)
+        .map(|(commitment, c)| commitment.mul(Fr::from(*c)))
+        .sum();
+    (game
+        .params
+        .commit(Fr::from(opening.value), opening.blinding)
+        == combination.into_affine())
+    .then_some(opening.value)
+    // SOLUTION-END
+}
+
+fn find_counterfeit(game: &mut CoinGame) -> Option<(usize, bool)> {
+    // SOLUTION-BEGIN
+    // the possible situations (coin, heavier), each query is chosen to split them as evenly as possible
+    // into the 3 outcomes: the opening is sum c_i w_i = WEIGHT sum c_i + c_k (+1 if heavier, -1 if lighter)
+    let mut candidates: Vec<(usize, bool)> = (0..COINS)
+        .flat_map(|coin| [(coin, false), (coin, true)])
+        .collect();
+    let outcome = |coefficients: &[i64; COINS], (coin, heavier): (usize, bool)| {
+        coefficients[coin] * if heavier { 1 } else { -1 }
+    };
+    while candidates.len() > 1 {
+        let mut best = ([0; COINS], usize::MAX);
+        for index in 0..3usize.pow(COINS as u32) {
+            let mut coefficients = [0; COINS];
+            let mut digits = index;
+            for c in coefficients.iter_mut() {
+                *c = (digits % 3) as i64 - 1;
+                digits /= 3;
+            }
+            let mut counts = [0; 3];
+            for candidate in &candidates {
+                counts[(outcome(&coefficients, *candidate) + 1) as usize] += 1;
+            }
+            let worst = *counts.iter().max().unwrap();
+            if worst < best.1 {
+                best = (coefficients, worst);
+            }
+            if worst == candidates.len().div_ceil(3) {
+                break; // can't do better
+            }
+        }
+        let coefficients = best.0;
+        let value = verified_open(game, &coefficients)?;
+        let deviation = value - WEIGHT * coefficients.iter().sum::<i64>();
+        candidates.retain(|candidate| outcome(&coefficients, *candidate) == deviation);
+    }
+    candidates.first().copied()
+    // SOLUTION-END
+}
+
+fn accumulator(wiring: &Wiring, witness: &[Vec<Fr>], beta: Fr, gamma: Fr) -> Vec<Fr> {
+    // SOLUTION-BEGIN
+    let mut z = vec![Fr::one()];
+    for row in 0..wiring.rows() {
+        let mut numerator = Fr::one();
+        let mut denominator = Fr::one();
+        for (column, values) in witness.iter().enumerate() {
+            let wire = Wire { column, row };
+            numerator *= values[row] + beta * wiring.label(wire) + gamma;
+            denominator *= values[row] + beta * wiring.label(wiring.sigma(wire)) + gamma;
+        }
+        z.push(z[row] * numerator * denominator.inverse().unwrap());
+    }
+    z
+    // SOLUTION-END
+}
+
+fn permutation_prove(
+    srs: &Srs,
+    key: &PermutationKey,
+    witness: &[Vec<Fr>],
+) -> Option<PermutationProof> {
+    // SOLUTION-BEGIN
+    let domain = key.wiring.domain;
+    let n = domain.size();
+    let mut transcript = Transcript::new(key);
+    let wires: Vec<DensePolynomial<Fr>> = witness
+        .iter()
+        .map(|values| interpolate_on(&domain, values.clone()))
+        .collect();
+    let wire_commitments: Vec<G1Affine> = wires.iter().map(|v| kzg_commit(srs, v)).collect();
+    wire_commitments.iter().for_each(|c| transcript.append(c));
+    let beta = transcript.challenge();
+    let gamma = transcript.challenge();
+
+    let mut z_values = accumulator(&key.wiring, witness, beta, gamma);
+    if z_values.pop() != Some(Fr::one()) {
+        return None;
+    }
+    let z = interpolate_on(&domain, z_values);
+    let accumulator_commitment = kzg_commit(srs, &z);
+    transcript.append(&accumulator_commitment);
+    let alpha = transcript.challenge();
+
+    let constant = |c: Fr| DensePolynomial::from_coefficients_vec(vec![c]);
+    let x = DensePolynomial::from_coefficients_vec(vec![Fr::zero(), Fr::one()]);
+    let mut unit = vec![Fr::zero(); n];
+    unit[0] = Fr::one();
+    let l1 = interpolate_on(&domain, unit);
+    // Z(wX): the coefficient of X^i is multiplied by w^i
+    let z_shifted = DensePolynomial::from_coefficients_vec(
+        z.coeffs
+            .iter()
+            .enumerate()
+            .map(|(i, c)| *c * domain.element(i))
+            .collect(),
+    );
+    let mut identity_side = z.clone();
+    let mut sigma_side = z_shifted;
+    for (j, (v, s)) in wires.iter().zip(&key.sigma_polynomials).enumerate() {
+        let shifted_x = &x * coset_shifts()[j];
+        identity_side = &identity_side * &(&(v + &(&shifted_x * beta)) + &constant(gamma));
+        sigma_side = &sigma_side * &(&(v + &(s * beta)) + &constant(gamma));
+    }
+    let first = &l1 * &(&z - &constant(Fr::one()));
+    let numerator = &first + &(&(&sigma_side - &identity_side) * alpha);
+    let quotient = divide_exact(&numerator, &domain.vanishing_polynomial().into());
+    let quotient_commitment = kzg_commit(srs, &quotient);
+    transcript.append(&quotient_commitment);
+    let zeta = transcript.challenge();
+
+    Some(PermutationProof {
+        wire_commitments,
+        accumulator_commitment,
+        quotient_commitment,
+        wire_openings: wires.iter().map(|v| kzg_open(srs, v, zeta)).collect(),
+        sigma_openings: key
+            .sigma_polynomials
+            .iter()
+            .map(|s| kzg_open(srs, s, zeta))
+            .collect(),
+        accumulator_opening: kzg_open(srs, &z, zeta),
+        shifted_accumulator_opening: kzg_open(srs, &z, zeta * domain.group_gen),
+        quotient_opening: kzg_open(srs, &quotient, zeta),
+    })
+    // SOLUTION-END
+}
+
+fn permutation_verify(srs: &Srs, key: &PermutationKey, proof: &PermutationProof) -> bool {
+    // SOLUTION-BEGIN
+    let domain = key.wiring.domain;
+    if proof.wire_commitments.len() != COLUMNS
+        || proof.wire_openings.len() != COLUMNS
+        || proof.sigma_openings.len() != COLUMNS
+    {
+        return false;
+    }
+    let mut transcript = Transcript::new(key);
+    proof
+        .wire_commitments
+        .iter()
+        .for_each(|c| transcript.append(c));
+    let beta = transcript.challenge();
+    let gamma = transcript.challenge();
+    transcript.append(&proof.accumulator_commitment);
+    let alpha = transcript.challenge();
+    transcript.append(&proof.quotient_commitment);
+    let zeta = transcript.challenge();
+
+    let openings = proof
+        .wire_commitments
+        .iter()
+        .zip(&proof.wire_openings)
+        .chain(key.sigma_commitments.iter().zip(&proof.sigma_openings))
+        .chain([
+            (&proof.accumulator_commitment, &proof.accumulator_opening),
+            (&proof.quotient_commitment, &proof.quotient_opening),
+        ]);
+    for (commitment, opening) in openings {
+        if opening.point != zeta || !kzg_verify(srs, commitment, opening) {
+            return false;
+        }
+    }
+    let shifted = &proof.shifted_accumulator_opening;
+    if shifted.point != zeta * domain.group_gen
+        || !kzg_verify(srs, &proof.accumulator_commitment, shifted)
+    {
+        return false;
+    }
+
+    let z = proof.accumulator_opening.value;
+    let mut identity_side = z;
+    let mut sigma_side = shifted.value;
+    for j in 0..COLUMNS {
+        let v = proof.wire_openings[j].value;
+        identity_side *= v + beta * coset_shifts()[j] * zeta + gamma;
+        sigma_side *= v + beta * proof.sigma_openings[j].value + gamma;
+    }
+    let vanishing = domain.evaluate_vanishing_polynomial(zeta);
+    // L_1(zeta) = (zeta^n - 1) / (n (zeta - 1))
+    let l1 = vanishing / (domain.size_as_field_element() * (zeta - Fr::one()));
+    l1 * (z - Fr::one()) + alpha * (sigma_side - identity_side)
+        == proof.quotient_opening.value * vanishing
+    // SOLUTION-END
+}
@@ -0,0 +1,36 @@
+use crate::*;
+
+// Pedersen commitment to a scalar m with blinding r: C = mG + rH
+// H must be a generator whose discrete log w.r.t. G nobody knows, otherwise the scheme is not binding
+pub struct PedersenParams {
+    pub g: G1Affine,
+    pub h: G1Affine,
+}
+
+impl PedersenParams {
+    // H is derived from a public seed, so that anyone can check that it was not chosen with a known discrete log
+    pub fn new() -> PedersenParams {
+        let mut rng = ChaChaRng::from_seed(*b"Pedersen commitment generator H!");
+        PedersenParams {
+            g: G1Affine::generator(),
+            h: G1Projective::rand(&mut rng).into_affine(),
+        }
+    }
+
+    pub fn commit(&self, m: Fr, r: Fr) -> G1Affine {
+        (self.g.mul(m) + self.h.mul(r)).into_affine()
+    }
+}
+
+// [L_i(tau)]_1 for the Lagrange basis polynomials L_i of the points 0..n used by `commit_vector`
+// they can be computed once and for all from the SRS
+pub fn lagrange_commitments(srs: &Srs, n: usize) -> Vec<G1Affine> {
+    let points: Vec<Fr> = (0..n).map(|i| Fr::from(i as u64)).collect();
+    (0..n)
+        .map(|i| {
+            let mut unit = vec![Fr::zero(); n];
+            unit[i] = Fr::one();
+            kzg_commit(srs, &interpolate(&points, &unit))
+        })
+        .collect()
+}
@@ -0,0 +1,189 @@
+use crate::*;
+use ark_poly::Radix2EvaluationDomain;
+use sha2::{Digest, Sha256};
+
+// The permutation argument of PLONK proves copy constraints: some wires of a circuit carry the same value
+// The witness is laid out in 3 columns a, b, c of n rows, interpolated over the subgroup H = {1, w, ..., w^(n-1)}
+// Each wire (column j, row i) gets a distinct label k_j w^i (the cosets H, kH and k^2 H do not intersect),
+// and the wiring is a permutation sigma of the wires whose cycles are the sets of wires that must be equal
+// For random beta and gamma, the copy constraints hold iff (up to a negligible probability)
+//   prod_(j, i) (v_(j, i) + beta * k_j w^i + gamma) = prod_(j, i) (v_(j, i) + beta * sigma(j, i) + gamma)
+// The prover shows it with the accumulator Z: Z(1) = 1 and, row by row,
+//   Z(w^(i+1)) = Z(w^i) * prod_j (v_(j, i) + beta * k_j w^i + gamma) / prod_j (v_(j, i) + beta * S_j(w^i) + gamma)
+// where S_j interpolates the labels sigma(j, i); the products match iff Z wraps around to Z(w^n) = Z(1) = 1
+// Both facts are polynomial identities on H, i.e. their left-hand sides are divisible by Z_H(X) = X^n - 1:
+//   L_1(X) (Z(X) - 1) = 0
+//   Z(wX) prod_j (v_j(X) + beta S_j(X) + gamma) - Z(X) prod_j (v_j(X) + beta k_j X + gamma) = 0
+// where L_1 is the Lagrange polynomial equal to 1 at w^0 = 1 and 0 on the rest of H
+
+pub const COLUMNS: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Wire {
+    pub column: usize,
+    pub row: usize,
+}
+
+// the copy constraints of a circuit with `rows` rows, as the permutation sigma of its 3 * rows wires
+pub struct Wiring {
+    pub domain: Radix2EvaluationDomain<Fr>,
+    pub sigma: Vec<Wire>, // sigma[column * rows + row]
+}
+
+impl Wiring {
+    // each copy constraint merges the cycles of two wires
+    pub fn new(rows: usize, copies: &[(Wire, Wire)]) -> Wiring {
+        assert!(rows.is_power_of_two());
+        let domain = Radix2EvaluationDomain::new(rows).unwrap();
+        let mut sigma: Vec<Wire> = (0..COLUMNS * rows)
+            .map(|k| Wire {
+                column: k / rows,
+                row: k % rows,
+            })
+            .collect();
+        let index = |wire: Wire| wire.column * rows + wire.row;
+        for (left, right) in copies {
+            // swapping the successors of two wires merges their cycles (or splits a cycle, if already merged)
+            if !Self::same_cycle(&sigma, rows, *left, *right) {
+                sigma.swap(index(*left), index(*right));
+            }
+        }
+        Wiring { domain, sigma }
+    }
+
+    fn same_cycle(sigma: &[Wire], rows: usize, start: Wire, target: Wire) -> bool {
+        let mut wire = start;
+        loop {
+            if wire == target {
+                return true;
+            }
+            wire = sigma[wire.column * rows + wire.row];
+            if wire == start {
+                return false;
+            }
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.domain.size()
+    }
+
+    // the label k_j w^i of a wire
+    pub fn label(&self, wire: Wire) -> Fr {
+        coset_shifts()[wire.column] * self.domain.element(wire.row)
+    }
+
+    pub fn sigma(&self, wire: Wire) -> Wire {
+        self.sigma[wire.column * self.rows() + wire.row]
+    }
+
+    // S_j(X) interpolating the labels of sigma(j, i) over H
+    pub fn sigma_polynomials(&self) -> Vec<DensePolynomial<Fr>> {
+        (0..COLUMNS)
+            .map(|column| {
+                let labels = (0..self.rows())
+                    .map(|row| self.label(self.sigma(Wire { column, row })))
+                    .collect();
+                interpolate_on(&self.domain, labels)
+            })
+            .collect()
+    }
+
+    // a witness satisfying the copy constraints: one random value per cycle
+    pub fn random_witness<R: Rng>(&self, rng: &mut R) -> Vec<Vec<Fr>> {
+        let mut columns = vec![vec![None; self.rows()]; COLUMNS];
+        for column in 0..COLUMNS {
+            for row in 0..self.rows() {
+                if columns[column][row].is_some() {
+                    continue;
+                }
+                let value = Fr::rand(rng);
+                let mut wire = Wire { column, row };
+                while columns[wire.column][wire.row].is_none() {
+                    columns[wire.column][wire.row] = Some(value);
+                    wire = self.sigma(wire);
+                }
+            }
+        }
+        columns
+            .into_iter()
+            .map(|column| column.into_iter().map(Option::unwrap).collect())
+            .collect()
+    }
+}
+
+// 1, k, k^2 with k the multiplicative generator of Fr: k^n and k^2n are not 1 for n a power of 2 (n < 2^32)
+// so the cosets H, kH, k^2 H are disjoint
+pub fn coset_shifts() -> [Fr; COLUMNS] {
+    let k = Fr::GENERATOR;
+    [Fr::one(), k, k.square()]
+}
+
+// the polynomial of degree < n taking the given values on H, with an inverse FFT
+pub fn interpolate_on(domain: &Radix2EvaluationDomain<Fr>, values: Vec<Fr>) -> DensePolynomial<Fr> {
+    DensePolynomial::from_coefficients_vec(domain.ifft(&values))
+}
+
+// the preprocessed part, computed once per circuit: the polynomials S_j and their commitments
+pub struct PermutationKey {
+    pub wiring: Wiring,
+    pub sigma_polynomials: Vec<DensePolynomial<Fr>>,
+    pub sigma_commitments: Vec<G1Affine>,
+}
+
+impl PermutationKey {
+    pub fn new(srs: &Srs, wiring: Wiring) -> PermutationKey {
+        let sigma_polynomials = wiring.sigma_polynomials();
+        let sigma_commitments = sigma_polynomials
+            .iter()
+            .map(|s| kzg_commit(srs, s))
+            .collect();
+        PermutationKey {
+            wiring,
+            sigma_polynomials,
+            sigma_commitments,
+        }
+    }
+}
+
+// the openings carry their points and values: zeta for all of them, except zeta * w for the shifted accumulator
+pub struct PermutationProof {
+    pub wire_commitments: Vec<G1Affine>,
+    pub accumulator_commitment: G1Affine,
+    // t(X) = (L_1(X) (Z(X) - 1) + alpha * second identity) / Z_H(X)
+    pub quotient_commitment: G1Affine,
+    pub wire_openings: Vec<KzgOpening>,
+    pub sigma_openings: Vec<KzgOpening>,
+    pub accumulator_opening: KzgOpening,
+    pub shifted_accumulator_opening: KzgOpening,
+    pub quotient_opening: KzgOpening,
+}
+
+// Fiat-Shamir: the challenges are hashes of everything the prover sent so far
+pub struct Transcript {
+    hasher: Sha256,
+}
+
+impl Transcript {
+    pub fn new(key: &PermutationKey) -> Transcript {
+        let mut transcript = Transcript {
+            hasher: Sha256::new().chain_update(b"plonk permutation argument"),
+        };
+        for commitment in &key.sigma_commitments {
+            transcript.append(commitment);
+        }
+        transcript
+    }
+
+    pub fn append(&mut self, commitment: &G1Affine) {
+        let mut bytes = Vec::new();
+        commitment.serialize_compressed(&mut bytes).unwrap();
+        self.hasher.update(bytes);
+    }
+
+    pub fn challenge(&mut self) -> Fr {
+        let digest = self.hasher.clone().finalize();
+        self.hasher.update(digest);
+        Fr::from_be_bytes_mod_order(&digest)
+    }
+}
@@ -0,0 +1,173 @@
+use crate::*;
+use sha2::{Digest, Sha256};
+
+// A Verkle trie is a Merkle tree where each node commits to its children with a vector commitment
+// Here the vector commitment is KZG: the node polynomial p satisfies p(i) = v_i for i = 0..width
+// where v_i is the value of the i-th leaf (last level) or the hash of the i-th child commitment
+pub struct VerkleNode {
+    pub polynomial: DensePolynomial<Fr>,
+    pub commitment: G1Affine,
+    pub children: Vec<VerkleNode>, // empty on the last level
+}
+
+pub struct VerkleProof {
+    pub commitments: Vec<G1Affine>, // commitments of the nodes on the path, below the root
+    pub openings: Vec<KzgOpening>,  // one opening per level, from the root down
+}
+
+impl VerkleNode {
+    pub fn depth(&self) -> usize {
+        match self.children.first() {
+            Some(child) => 1 + child.depth(),
+            None => 1,
+        }
+    }
+}
+
+impl VerkleProof {
+    // only the group elements need to be sent: the points are derived from the index
+    // and the values from the next commitment or the leaf itself
+    pub fn size(&self) -> usize {
+        let commitments: usize = self.commitments.iter().map(|c| c.compressed_size()).sum();
+        let proofs: usize = self
+            .openings
+            .iter()
+            .map(|o| o.proof.compressed_size())
+            .sum();
+        commitments + proofs
+    }
+}
+
+// vector commitment to `values`, i.e. KZG commitment to the polynomial interpolating them over 0..width
+pub fn commit_vector(srs: &Srs, values: &[Fr]) -> (DensePolynomial<Fr>, G1Affine) {
+    let points: Vec<Fr> = (0..values.len()).map(|i| Fr::from(i as u64)).collect();
+    let polynomial = interpolate(&points, values);
+    let commitment = kzg_commit(srs, &polynomial);
+    (polynomial, commitment)
+}
+
+// map a child commitment to the value stored in its parent
+pub fn commitment_to_field(commitment: &G1Affine) -> Fr {
+    let mut bytes = Vec::new();
+    commitment.serialize_compressed(&mut bytes).unwrap();
+    Fr::from_be_bytes_mod_order(&Sha256::digest(bytes))
+}
+
+// depth of a trie of the given width holding `leaf_count` leaves
+pub fn verkle_depth(width: usize, leaf_count: usize) -> usize {
+    let mut depth = 1;
+    let mut capacity = width;
+    while capacity < leaf_count {
+        capacity *= width;
+        depth += 1;
+    }
+    assert_eq!(
+        capacity, leaf_count,
+        "the number of leaves must be a power of the width"
+    );
+    depth
+}
+
+// digits of `index` in base `width`, most significant first: the child to follow at each level
+pub fn path_indices(width: usize, depth: usize, index: usize) -> Vec<usize> {
+    let mut digits = vec![0; depth];
+    let mut index = index;
+    for digit in digits.iter_mut().rev() {
+        *digit = index % width;
+        index /= width;
+    }
+    digits
+}
+
+pub fn build_verkle(srs: &Srs, width: usize, leaves: &[Fr]) -> VerkleNode {
+    if verkle_depth(width, leaves.len()) == 1 {
+        let (polynomial, commitment) = commit_vector(srs, leaves);
+        return VerkleNode {
+            polynomial,
+            commitment,
+            children: vec![],
+        };
+    }
+    let children: Vec<VerkleNode> = leaves
+        .chunks(leaves.len() / width)
+        .map(|chunk| build_verkle(srs, width, chunk))
+        .collect();
+    let values: Vec<Fr> = children
+        .iter()
+        .map(|child| commitment_to_field(&child.commitment))
+        .collect();
+    let (polynomial, commitment) = commit_vector(srs, &values);
+    VerkleNode {
+        polynomial,
+        commitment,
+        children,
+    }
+}
+
+// A plain binary Merkle tree with SHA-256, the reference point for proof sizes
+pub struct MerkleTree {
+    pub layers: Vec<Vec<[u8; 32]>>, // leaves' hashes first, root last
+}
+
+pub fn hash_leaf(leaf: &Fr) -> [u8; 32] {
+    let mut bytes = Vec::new();
+    leaf.serialize_compressed(&mut bytes).unwrap();
+    Sha256::digest(bytes).into()
+}
+
+impl MerkleTree {
+    pub fn new(leaves: &[Fr]) -> MerkleTree {
+        assert!(leaves.len().is_power_of_two());
+        let mut layers = vec![leaves.iter().map(hash_leaf).collect::<Vec<_>>()];
+        while layers.last().unwrap().len() > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| {
+                    Sha256::new()
+                        .chain_update(pair[0])
+                        .chain_update(pair[1])
+                        .finalize()
+                        .into()
+                })
+                .collect();
+            layers.push(next);
+        }
+        MerkleTree { layers }
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.layers.last().unwrap()[0]
+    }
+
+    // the siblings on the path from the leaf to the root
+    pub fn prove(&self, index: usize) -> Vec<[u8; 32]> {
+        let mut index = index;
+        let mut siblings = vec![];
+        for layer in &self.layers[..self.layers.len() - 1] {
+            siblings.push(layer[index ^ 1]);
+            index /= 2;
+        }
+        siblings
+    }
+}
+
+pub fn merkle_verify(root: &[u8; 32], index: usize, leaf: &Fr, siblings: &[[u8; 32]]) -> bool {
+    let mut index = index;
+    let mut current = hash_leaf(leaf);
+    for sibling in siblings {
+        let (left, right) = if index & 1 == 0 {
+            (current, *sibling)
+        } else {
+            (*sibling, current)
+        };
+        current = Sha256::new()
+            .chain_update(left)
+            .chain_update(right)
+            .finalize()
+            .into();
+        index /= 2;
+    }
+    current == *root
+}
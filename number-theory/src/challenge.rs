@@ -0,0 +1,334 @@
+use crate::*;
+use ark_ff::{BigInteger, PrimeField};
+use ark_secp256k1::Fq;
+use sage_fixtures::Fixtures;
+use seed::challenge::{from_hex, to_hex, Challenge, Handout};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// The discrete log challenge ladder: `cargo run --release -- challenge dlp [bsgs|kangaroo|rho] [budget in seconds]`
+// Instances on toy curves get larger and larger until your solver fails or runs out of time,
+// the largest size solved is recorded in the progress report at the root of the repository
+
+pub const PROGRESS_REPORT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../progress.report");
+pub const DEFAULT_BUDGET: Duration = Duration::from_secs(10);
+pub const LADDER: [u32; 12] = [16, 20, 24, 28, 32, 36, 40, 44, 48, 52, 56, 59];
+
+// The student challenges, derived from the identifier of a student and the seed of the class (see module
+// `challenge` of the seed crate). The instructor, with the seed: `cargo run --release -- --seed <class> challenge
+// export <id>` writes the public instances of the student to `challenges-<id>.txt`, with the keys of the
+// receipts; the student: `cargo run --release -- challenge student challenges-<id>.txt` solves them with your
+// functions, and prints a receipt per challenge, a MAC of the answer; the instructor again: `cargo run --release
+// -- --seed <class> challenge verify <id> <receipts>...` derives the instances with their secrets, and checks the
+// receipts of the student. The handout holds no secret: a valid receipt takes a solved instance
+// - dlp: a discrete log of STUDENT_DLP_BITS bits on a toy curve, for `BabyStepGiantStep` (Q5)
+// - hnp: a secp256k1 key signing with biased nonces, for `hnp_recover_key` (Q26)
+pub const STUDENT_CHALLENGES: [&str; 2] = ["dlp", "hnp"];
+pub const STUDENT_DLP_BITS: u32 = 40;
+
+// any generic discrete log algorithm (baby-step giant-step, Pollard's rho, kangaroo...)
+pub trait DlpSolver: Send + Sync {
+    fn name(&self) -> &str;
+
+    // the x in [0, order) such that target = x generator
+    fn solve(&self, instance: &DlpInstance) -> u64;
+}
+
+// climbs the ladder and returns the largest bit size solved within the budget
+pub fn run_dlp_challenge<R: Rng>(
+    solver: Arc<dyn DlpSolver>,
+    budget: Duration,
+    rng: &mut R,
+) -> Option<u32> {
+    let mut solved = None;
+    for bits in LADDER {
+        let (instance, _) = DlpInstance::random(bits, rng);
+        let (sender, receiver) = mpsc::channel();
+        let worker = solver.clone();
+        let start = Instant::now();
+        // a solver running out of time is not interrupted, only abandoned
+        thread::spawn(move || sender.send(worker.solve(&instance)));
+        match receiver.recv_timeout(budget) {
+            Ok(x) if instance.check(x) => {
+                println!("{} bits: solved in {:.2?}", bits, start.elapsed());
+                solved = Some(bits);
+            }
+            Ok(x) => {
+                println!("{} bits: wrong answer {}", bits, x);
+                break;
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                println!("{} bits: out of time", bits);
+                break;
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                println!("{} bits: the solver panicked", bits);
+                break;
+            }
+        }
+    }
+    solved
+}
+
+// keeps the best score for a given budget
+pub fn record_dlp_progress(solver: &str, budget: Duration, bits: u32) -> std::io::Result<()> {
+    let mut report = match std::fs::read_to_string(PROGRESS_REPORT) {
+        Ok(text) => text
+            .parse()
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Fixtures::new(),
+        Err(e) => return Err(e),
+    };
+    let section = format!("dlp.{}s", budget.as_secs());
+    if report.contains(&section, "bits") && report.get::<u32>(&section, "bits") >= bits {
+        return Ok(());
+    }
+    report.set(&section, "bits", bits);
+    report.set(&section, "solver", solver);
+    std::fs::write(PROGRESS_REPORT, report.to_string())
+}
+
+pub fn challenge_dlp(solver: Arc<dyn DlpSolver>, args: &[String]) {
+    let budget = match args {
+        [] => DEFAULT_BUDGET,
+        [seconds] => Duration::from_secs(seconds.parse().expect("budget in seconds")),
+        _ => panic!("usage: challenge dlp [bsgs|kangaroo|rho] [budget in seconds]"),
+    };
+    println!(
+        "Discrete log challenge with {}, {:?} per instance",
+        solver.name(),
+        budget
+    );
+    let mut rng = ChaChaRng::from_entropy();
+    match run_dlp_challenge(solver.clone(), budget, &mut rng) {
+        Some(bits) => {
+            println!("Largest instance solved: {} bits", bits);
+            record_dlp_progress(solver.name(), budget, bits)
+                .expect("cannot write the progress report");
+        }
+        None => println!("No instance solved"),
+    }
+}
+
+// a student challenge as the student gets it: the public instance, without its secret
+enum StudentInstance {
+    Dlp(DlpInstance),
+    Hnp(Affine, Vec<Signed>),
+}
+
+// the instance of a student challenge, with its answer as bytes
+fn student_instance(challenge: &Challenge) -> (StudentInstance, Vec<u8>) {
+    let mut rng = challenge.rng();
+    match challenge.name.as_str() {
+        "dlp" => {
+            let (instance, x) = DlpInstance::random(STUDENT_DLP_BITS, &mut rng);
+            (StudentInstance::Dlp(instance), x.to_le_bytes().to_vec())
+        }
+        "hnp" => {
+            let (secret_key, public_key, signatures) =
+                biased_signatures_with_key(HNP_SIGNATURES, &mut rng);
+            (
+                StudentInstance::Hnp(public_key, signatures),
+                secret_key.into_bigint().to_bytes_le(),
+            )
+        }
+        _ => panic!("no student challenge {}", challenge.name),
+    }
+}
+
+// the answer of your functions, as bytes, if it solves the instance
+fn solve_student_instance(instance: &StudentInstance) -> Option<Vec<u8>> {
+    match instance {
+        StudentInstance::Dlp(instance) => {
+            let x = BabyStepGiantStep.solve(instance);
+            instance.check(x).then(|| x.to_le_bytes().to_vec())
+        }
+        StudentInstance::Hnp(public_key, signatures) => {
+            let key = hnp_recover_key(public_key, signatures)?;
+            (Affine::generator().mul(key).into_affine() == *public_key)
+                .then(|| key.into_bigint().to_bytes_le())
+        }
+    }
+}
+
+// `(x, y)`, or `()` for the point at infinity
+fn toy_point_text(point: &ToyPoint) -> String {
+    match point {
+        ToyPoint::Infinity => "()".to_string(),
+        ToyPoint::Affine(x, y) => format!("({}, {})", x, y),
+    }
+}
+
+fn toy_point(handout: &Fixtures, section: &str, key: &str) -> ToyPoint {
+    match handout.get_list::<u64>(section, key)[..] {
+        [] => ToyPoint::Infinity,
+        [x, y] => ToyPoint::Affine(x, y),
+        _ => panic!("{}.{} is not a point", section, key),
+    }
+}
+
+fn list_text<T: std::fmt::Display>(items: impl Iterator<Item = T>) -> String {
+    let items: Vec<String> = items.map(|item| item.to_string()).collect();
+    format!("[{}]", items.join(", "))
+}
+
+// the section `name` of the handout: the public instance and the key of the receipts
+fn write_handout(handout: &mut Fixtures, challenge: &Challenge, instance: &StudentInstance) {
+    let name = challenge.name.as_str();
+    handout.set(
+        name,
+        "receipt_key",
+        to_hex(&challenge.handout().receipt_key),
+    );
+    match instance {
+        StudentInstance::Dlp(instance) => {
+            handout.set(name, "p", instance.curve.p);
+            handout.set(name, "a", instance.curve.a);
+            handout.set(name, "b", instance.curve.b);
+            handout.set(name, "generator", toy_point_text(&instance.generator));
+            handout.set(name, "order", instance.order);
+            handout.set(name, "target", toy_point_text(&instance.target));
+        }
+        StudentInstance::Hnp(public_key, signatures) => {
+            handout.set(
+                name,
+                "public_key",
+                format!("({}, {})", public_key.x, public_key.y),
+            );
+            handout.set(
+                name,
+                "z",
+                list_text(signatures.iter().map(|signed| signed.z)),
+            );
+            handout.set(
+                name,
+                "r",
+                list_text(signatures.iter().map(|signed| signed.r)),
+            );
+            handout.set(
+                name,
+                "s",
+                list_text(signatures.iter().map(|signed| signed.s)),
+            );
+        }
+    }
+}
+
+fn read_handout(handout: &Fixtures, student: &str, name: &str) -> (Handout, StudentInstance) {
+    let receipt_key = from_hex(handout.raw(name, "receipt_key"))
+        .and_then(|key| key.try_into().ok())
+        .unwrap_or_else(|| panic!("invalid {}.receipt_key", name));
+    let instance = match name {
+        "dlp" => StudentInstance::Dlp(DlpInstance {
+            curve: ToyCurve {
+                p: handout.get(name, "p"),
+                a: handout.get(name, "a"),
+                b: handout.get(name, "b"),
+            },
+            generator: toy_point(handout, name, "generator"),
+            order: handout.get(name, "order"),
+            target: toy_point(handout, name, "target"),
+        }),
+        "hnp" => {
+            let [x, y] = handout.get_list::<Fq>(name, "public_key")[..] else {
+                panic!("{}.public_key is not a point", name);
+            };
+            let public_key = Affine::new_unchecked(x, y);
+            assert!(
+                public_key.is_on_curve(),
+                "{}.public_key is not on secp256k1",
+                name
+            );
+            let (z, r, s) = (
+                handout.get_list::<Fr>(name, "z"),
+                handout.get_list::<Fr>(name, "r"),
+                handout.get_list::<Fr>(name, "s"),
+            );
+            assert!(
+                z.len() == r.len() && r.len() == s.len(),
+                "{}: as many z, r and s",
+                name
+            );
+            let signatures = (0..z.len())
+                .map(|i| Signed {
+                    z: z[i],
+                    r: r[i],
+                    s: s[i],
+                })
+                .collect();
+            StudentInstance::Hnp(public_key, signatures)
+        }
+        _ => panic!("no student challenge {}", name),
+    };
+    let handout = Handout {
+        chapter: env!("CARGO_PKG_NAME").to_string(),
+        name: name.to_string(),
+        student: student.to_string(),
+        receipt_key,
+    };
+    (handout, instance)
+}
+
+pub fn challenge_export(args: &[String]) {
+    let [student] = args else {
+        panic!("usage: challenge export <student>");
+    };
+    let seed = seed::seed();
+    let mut handout = Fixtures::new();
+    handout.set("student", "id", student);
+    for name in STUDENT_CHALLENGES {
+        let challenge = Challenge::derive(&seed, student, env!("CARGO_PKG_NAME"), name);
+        let (instance, _) = student_instance(&challenge);
+        write_handout(&mut handout, &challenge, &instance);
+    }
+    let path = format!("challenges-{}.txt", student);
+    std::fs::write(&path, handout.to_string()).expect("cannot write the handout");
+    println!(
+        "Challenges of {} written to {}: hand it to the student",
+        student, path
+    );
+}
+
+pub fn challenge_student(args: &[String]) {
+    let [path] = args else {
+        panic!("usage: challenge student <handout>");
+    };
+    let handout: Fixtures = std::fs::read_to_string(path)
+        .expect("cannot read the handout")
+        .parse()
+        .expect("invalid handout");
+    let student: String = handout.get("student", "id");
+    println!("Challenges of {}: hand in the receipts", student);
+    for name in STUDENT_CHALLENGES {
+        let (handout, instance) = read_handout(&handout, &student, name);
+        let start = Instant::now();
+        match solve_student_instance(&instance) {
+            Some(answer) => println!(
+                "{} (solved in {:.2?})",
+                handout.receipt(&answer),
+                start.elapsed()
+            ),
+            None => println!("{}: wrong answer", name),
+        }
+    }
+}
+
+pub fn challenge_verify(args: &[String]) {
+    let [student, receipts @ ..] = args else {
+        panic!("usage: challenge verify <student> <receipt>...");
+    };
+    let seed = seed::seed();
+    for name in STUDENT_CHALLENGES {
+        let challenge = Challenge::derive(&seed, student, env!("CARGO_PKG_NAME"), name);
+        let (_, answer) = student_instance(&challenge);
+        let prefix = format!("{}:", name);
+        let verdict = match receipts.iter().find(|receipt| receipt.starts_with(&prefix)) {
+            None => "missing",
+            Some(receipt) if challenge.verify(&answer, receipt) => "valid",
+            Some(_) => "invalid",
+        };
+        println!("{} {}: {}", student, name, verdict);
+    }
+}
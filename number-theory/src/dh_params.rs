@@ -0,0 +1,44 @@
+use crate::*;
+
+// Parameters of finite-field Diffie-Hellman: a safe prime p = 2q + 1 with q prime, and g of order q
+// - F_p* has order p - 1 = 2q: its only subgroups have orders 1, 2, q and 2q, and Pohlig-Hellman (Q2) has
+//   nothing to split. The squares mod p are the subgroup of order q, so g = h^2 for a random h != 1, p - 1
+// - Alice and Bob draw a and b in [1, q), exchange g^a and g^b and share g^ab mod p
+// Finding p is slow: a random odd number of n bits is prime with probability about 2 / (n ln 2), and both q
+// and 2q + 1 must be, so that the search draws about (n ln 2)^2 / 4 candidates before a hit, about 125000 for
+// 1024 bits. Sieving both by the small primes discards most of them before any Miller-Rabin
+//
+// `cargo run --release -- dh-params 1024` times the generation of one set of parameters
+
+// the sizes of the checks, the real ones start at 2048 bits
+pub const DH_BITS: [u64; 3] = [64, 128, 256];
+pub const DEFAULT_DH_BITS: u64 = 512;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DhParams {
+    pub p: BigUint,
+    pub q: BigUint,
+    pub g: BigUint,
+}
+
+pub fn dh_params_command(args: &[String]) {
+    let bits = match args {
+        [] => DEFAULT_DH_BITS,
+        [bits] => bits.parse().expect("bits"),
+        _ => panic!("usage: dh-params [bits]"),
+    };
+    assert!(bits >= 16, "at least 16 bits");
+    let mut rng = seed::rng();
+    let start = Instant::now();
+    let (params, candidates) = dh_params(bits, &mut rng);
+    println!(
+        "{}-bit safe prime found after {} candidates in {:.2?}",
+        bits,
+        candidates,
+        start.elapsed()
+    );
+    println!(
+        "p = 0x{:x}\nq = 0x{:x}\ng = 0x{:x}",
+        params.p, params.q, params.g
+    );
+}
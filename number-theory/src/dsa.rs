@@ -0,0 +1,42 @@
+use crate::*;
+
+// The schemes of secp256k1 before elliptic curves, in a Schnorr group: the subgroup of prime order q of F_p*,
+// for primes p = kq + 1 and q much smaller than p
+// - g = h^k mod p for a random h, unless it is 1: g^q = h^(p - 1) = 1, so that g has order q
+// - the discrete log is as hard as in F_p* (index calculus attacks F_p* as a whole) and in a group of order q
+//   (generic attacks): FIPS 186 pairs a 2048-bit p with a 224 or 256-bit q
+// Pedersen commitments: C = g^m h^r mod p, with h of order q and a discrete log to base g known to nobody
+// DSA (FIPS 186-4), with a private key x in [1, q), the public key y = g^x mod p and a hash z of the message:
+// - sign: k random in [1, q), r = (g^k mod p) mod q, s = k^-1 (z + x r) mod q, start again if r or s is 0
+// - verify: 0 < r, s < q, w = s^-1 mod q, then (g^(z w) y^(r w) mod p) mod q = r
+// ECDSA is the same scheme with kG instead of g^k and x(kG) instead of g^k mod p
+
+// (bits of p, bits of q), the first pair of FIPS 186-4 is deprecated
+pub const SCHNORR_GROUP_SIZES: [(u64, u64); 2] = [(1024, 160), (2048, 256)];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchnorrGroup {
+    pub p: BigUint,
+    pub q: BigUint,
+    pub g: BigUint,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DsaSignature {
+    pub r: BigUint,
+    pub s: BigUint,
+}
+
+// the second base of the commitments, drawn from a public seed: nobody knows its discrete log to base g
+pub fn commitment_base(group: &SchnorrGroup) -> BigUint {
+    let mut rng = ChaChaRng::from_seed(*b"schnorr group commitment base h!");
+    let k = (&group.p - 1u32) / &group.q;
+    loop {
+        let h = rng
+            .gen_biguint_range(&BigUint::from(2u32), &group.p)
+            .modpow(&k, &group.p);
+        if !h.is_one() {
+            return h;
+        }
+    }
+}
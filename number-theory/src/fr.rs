@@ -0,0 +1,249 @@
+use runner::lang::{Catalog, Lang, Statements};
+
+pub const CATALOG: Catalog = Catalog {
+    lang: Lang::Fr,
+    statements: Statements::Table(&[
+        ("Q1", "écrire `crt` qui le calcule"),
+        ("Q2", "écrire `dlog_prime_power` qui trouve x mod q^e par force brute dans le sous-groupe d'ordre q^e"),
+        ("Q3", "écrire `pohlig_hellman` qui les combine avec `crt`"),
+        ("Q4", "le plus grand facteur premier de p - 1 est 59 : combien d'opérations de groupe votre attaque prend-elle,\ncomparée au sqrt(p) générique de baby-step giant-step ? Et si p - 1 = 2q avec q premier ?"),
+        ("Q5", "implémenter baby-step giant-step comme un `DlpSolver`\navec m = ceil(sqrt(q)), x = i m + j où jG est un pas de bébé stocké dans une table\net Q - i(mG) un pas de géant cherché dans la table"),
+        ("Q6", "jusqu'où va-t-il en 10 secondes ? Jeter un œil au module `challenge` et lancer\n`cargo run --release -- challenge dlp`, votre meilleur score est enregistré dans le rapport de progression\nLa mémoire est aussi une limite : combien prend la table à 48 bits ?"),
+        ("Q7", "écrire `kangaroo` qui trouve x dans [lower, lower + width)"),
+        ("Q8", "de combien de mémoire a-t-il besoin ? Comment répartir le travail sur de nombreuses machines ?"),
+        ("Q9", "écrire `parallel_rho` où chaque thread lance des marches depuis des points de départ aléatoires\net envoie ses points distingués par un canal au thread principal, qui cherche une collision"),
+        ("Q10", "le gain devrait être proche du nombre de cœurs : pourquoi est-il linéaire, contrairement à des attaques\nrho indépendantes sur un seul thread ? Comparer avec `cargo run --release -- challenge dlp rho`"),
+        ("Q11", "écrire `negation_step` qui marche sur les classes {W, -W}, représentées par le point de plus petit y\net évite les 2-cycles stériles : quand le point suivant utiliserait la même branche i, utiliser la branche i + 1 à la place"),
+        ("Q12", "quel est le gain attendu ? secp256k1 a aussi l'endomorphisme (x, y) -> (beta x, y)\nd'ordre 3, avec beta une racine cubique de l'unité dans F_p : de combien rho est-il plus rapide sur les classes\n{P, -P, phi(P), -phi(P), phi^2(P), -phi^2(P)} ? Pourquoi nos courbes jouets ne l'ont-elles pas ?"),
+        ("Q13", "écrire `is_in_subgroup_by_cofactor` : P est dans le sous-groupe si et seulement si P = h(h^-1 mod q)P\n(la multiplication par le cofacteur envoie E sur le sous-groupe, et est une bijection sur celui-ci)"),
+        ("Q14", "écrire `miller_loop` qui calcule f_{h,T}(S) à l'aide de `line_function`,\npuis `is_in_subgroup_by_pairing`, en essayant les décalages R jusqu'à ce que l'évaluation soit définie"),
+        ("Q15", "Ed25519 a pour cofacteur 8 = 2^3 : pourquoi le test par couplage ne peut-il pas y être utilisé tel quel ?"),
+        ("Q16", "écrire `double_and_add_steps` qui calcule kP à partir du bit de poids fort de k :\nen partant de P, pour chaque bit suivant, doubler, puis ajouter P si le bit est à un\nElle renvoie chaque point intermédiaire avec l'opération qui l'a produit\npuis les regarder sur la courbe avec `cargo run --release -- visualize` (jeter un œil au module `visualizer`)"),
+        ("Q17", "quels points la marche visite-t-elle pour k = 2^n ? pour k = 2^n - 1 ?\nPourquoi la suite des opérations révèle-t-elle k à qui observe l'exécution, et comment\nl'échelle de Montgomery du chapitre curve25519 l'évite-t-elle ?"),
+        ("Q18", "écrire `legendre_symbol` qui renvoie 1, 0 ou -1 selon que a est un carré non nul, zéro ou un non-carré mod p"),
+        ("Q19", "écrire `recover_legendre_key` qui trouve la clé de l'oracle sur le premier 2^31 - 1\navec environ sqrt(p) requêtes et symboles de Legendre : une rencontre au milieu\n- demander une fenêtre de sorties consécutives L_K(x), ..., L_K(x + 63) pour de nombreux x, stockées par fenêtre\n- calculer des fenêtres L_0(y), ..., L_0(y + 63) pour des y aléatoires jusqu'à ce que l'une d'elles soit stockée : alors K + x = y"),
+        ("Q20", "comment les requêtes et le temps évoluent-ils avec p ? Comparer avec une recherche exhaustive de la clé\nQuelle taille doit avoir p pour 128 bits de sécurité contre cette attaque, et la PRF en devient-elle plus lente ?"),
+        ("Q21", "écrire `knapsack_decrypt` qui déchiffre avec la clé privée de Merkle-Hellman"),
+        ("Q22", "écrire `break_knapsack` qui retrouve le message à partir de la seule clé publique et du chiffré\nRéduire avec `lll` le réseau des lignes (2 e_i, N b_i) pour chaque i et (1, ..., 1, N c), avec N > sqrt(n) :\nsum m_i (2 e_i, N b_i) - (1, ..., 1, N c) = (2 m_1 - 1, ..., 2 m_n - 1, 0) est un vecteur de norme sqrt(n),\nle plus court du réseau quand la densité est faible"),
+        ("Q23", "pourquoi l'attaque a-t-elle besoin d'une faible densité ? Quelles densités Merkle-Hellman atteint-il avec une\nsuite super-croissante, et pourquoi ajouter du jeu aux poids privés a-t-il facilité l'attaque ?"),
+        ("Q24", "la clé publique ci-dessous cache x dans [lower, lower + 2^40) : le retrouver avec votre `kangaroo` de Q7\ndans un budget d'opérations de groupe qui exclut tout sauf une marche d'environ 2 sqrt(2^40) pas"),
+        ("Q25", "combien de points distingués vos kangourous ont-ils stockés ? Quel paramètre les échange contre les\npas parcourus après la collision ? Avec k kangourous sur k machines, de combien la recherche est-elle plus rapide, et\npourquoi les apprivoisés et les sauvages doivent-ils sauter des mêmes distances ?"),
+        ("Q26", "un signataire a mis à zéro les 8 bits de poids fort de ses nonces ECDSA. Écrire `hnp_recover_key`, qui construit le réseau\ndu problème du nombre caché à partir de ses signatures, le réduit avec `lll_floating` et renvoie la clé privée"),
+        ("Q27", "combien de signatures l'attaque demande-t-elle avec 8 bits biaisés, et avec 4 ? Pourquoi un biais d'un seul\nbit met-il LLL en échec en pratique, et qu'utilisent à la place les attaques sur 1 bit ou moins ? Les nonces de la RFC 6979 sont\nuniformes : quelles erreurs d'implémentation leur donnent encore un biais ?"),
+        ("Q28", "écrire `fermat_test` et `miller_rabin_test`, qui disent si le n > 2 impair passe le test pour la\nbase a dans [2, n - 2]"),
+        ("Q29", "écrire `find_carmichael`, qui renvoie les nombres de Carmichael inférieurs à la limite, dans l'ordre"),
+        ("Q30", "écrire `is_probable_prime` pour tout n, avec `MILLER_RABIN_ROUNDS` tours de Miller-Rabin sur des bases aléatoires"),
+        ("Q31", "écrire `safe_prime`, qui renvoie un premier p d'exactement `bits` bits avec (p - 1) / 2 premier, et le\nnombre de candidats q qu'elle a tirés, avec votre `is_probable_prime`"),
+        ("Q32", "écrire `dh_params`, qui renvoie un premier sûr de `bits` bits, avec q et un générateur g du\nsous-groupe d'ordre q, et le nombre de candidats tirés"),
+        ("Q33", "pourquoi TLS 1.3 utilise-t-il les groupes fixes de la RFC 7919 plutôt que des paramètres générés par chaque serveur ?\nQu'a précalculé l'attaque Logjam, et pourquoi cela en valait-il la peine pour des premiers de 512 bits ? Avec un premier p dont\nle p - 1 est friable au lieu de 2q, que donnerait Pohlig-Hellman à un espion ?"),
+        ("Q34", "écrire `schnorr_group`, qui renvoie des premiers p de `p_bits` bits et q de `q_bits` bits avec q | p - 1,\net g d'ordre q"),
+        ("Q35", "écrire `pedersen_commit`, g^m h^r mod p avec h = `commitment_base(group)`"),
+        ("Q36", "écrire `dsa_sign` et `dsa_verify`"),
+        ("Q37", "pourquoi DSA a-t-il besoin d'un p de 2048 bits pour la sécurité que secp256k1 atteint avec 256 bits, alors que son q et\nses signatures ont la même taille ? Que révélerait un k réutilisé, et quelle attaque sur ECDSA de ce chapitre\ns'applique telle quelle à DSA ?"),
+        ("Q38", "écrire `hensel_lift`, qui relève un point de la courbe sur la courbe relevée modulo p^2, en gardant x"),
+        ("Q39", "écrire `p_adic_log`, t(pP') / p mod p pour le relevé P' de P, qui est un morphisme de groupes de E(F_p) dans F_p"),
+        ("Q40", "écrire `smart_attack`, qui trouve le logarithme discret de la cible avec un relèvement aléatoire de la courbe"),
+        ("Q41", "l'attaque tient en quelques multiplications scalaires modulo p^2 : quelle condition sur #E les\nstandards de courbes imposent-ils depuis 1999, et à quoi succombent plutôt les courbes du module `toy_curve`, à p + 1 points ?\nPourquoi le relèvement canonique donne-t-il un morphisme nul ?"),
+        ("Q42", "écrire `cube_root_of_unity`, (-1 + sqrt(-3)) / 2 dans F_{p^2}, et `distortion_map`, (x, y) -> (zeta x, y)"),
+        ("Q43", "écrire `fp2_discrete_log`, pas de bébé pas de géant dans le sous-groupe d'ordre q de F_{p^2}*"),
+        ("Q44", "écrire `mov_attack`, qui transporte l'instance dans F_{p^2} avec le couplage et l'y résout"),
+        ("Q45", "pas de bébé pas de géant coûte sqrt(q) des deux côtés : que gagne l'attaquant à passer dans F_{p^2} ?\nLe degré de plongement k est le plus petit k tel que q divise p^k - 1 : que vaut-il pour secp256k1, et pour\nles courbes BN et BLS des protocoles à base de couplages ? Quelle taille doit avoir p^k pour une sécurité de 128 bits ?"),
+    ]),
+    hints: &[
+        (
+            "Q1",
+            &[
+                "Construire x à partir de nombres qui valent 1 modulo un m_i et 0 modulo tous les autres",
+                "M_i = M / m_i vaut 0 modulo chaque m_j sauf m_i, et M_i (M_i^-1 mod m_i) vaut 1 modulo m_i",
+                "x = sum r_i M_i (M_i^-1 mod m_i) mod M, où M_i = M / m_i : réduire le résultat dans [0, M)",
+            ],
+        ),
+        (
+            "Q2",
+            &[
+                "L'élévation à la puissance (p - 1) / q^e envoie F_p* sur son sous-groupe d'ordre q^e",
+                "Si h = g^x, alors h^((p - 1) / q^e) = (g^((p - 1) / q^e))^x, qui ne dépend que de x mod q^e",
+                "Calculer les deux puissances avec `modpow`, puis multiplier 1 par g^((p - 1) / q^e) jusqu'à atteindre le h projeté",
+            ],
+        ),
+        (
+            "Q5",
+            &[
+                "Écrire x = i m + j avec 0 <= i, j < m = ceil(sqrt(q))",
+                "Q - i(mG) = jG : stocker les pas de bébé jG, puis parcourir les pas de géant Q - i(mG)",
+                "Stocker jG -> j dans une `HashMap` pour j < m, puis pour i = 0, 1, ... chercher Q - i(mG) et renvoyer i m + j",
+            ],
+        ),
+        (
+            "Q9",
+            &[
+                "Des marches issues de points de départ différents qui atteignent le même point distingué entrent en collision",
+                "Chaque thread envoie (point distingué, a, b) avec le point égal à aG + bQ",
+                "Le thread principal garde les points dans une `HashMap` : sur une répétition avec un b différent, x = (a - a') / (b' - b) mod q",
+            ],
+        ),
+        (
+            "Q11",
+            &[
+                "W et -W ont le même x : la marche peut travailler sur les classes {W, -W}",
+                "Choisir le représentant de plus petit y, et changer le signe de ses coefficients (a, b) avec lui",
+                "Un 2-cycle stérile fait W -> W + R_i -> -(W + R_i) + R_i = -W : quand la branche se répète, prendre la branche i + 1",
+            ],
+        ),
+        (
+            "Q18",
+            &[
+                "Les carrés non nuls forment le sous-groupe d'indice 2 de F_p*",
+                "Critère d'Euler : a^((p - 1) / 2) vaut 1 si a est un carré non nul, p - 1 sinon",
+                "Réduire a mod p, renvoyer 0 s'il est nul, sinon comparer `a.modpow(&((p - 1u32) >> 1), p)` à 1",
+            ],
+        ),
+        (
+            "Q19",
+            &[
+                "L_K(x) = L_0(K + x) : les sorties de l'oracle sont une fenêtre de la suite L_0, commençant en K",
+                "Stocker les fenêtres de 64 bits de 2^17 requêtes consécutives dans une `HashMap`, puis glisser sur des y aléatoires : une correspondance donne K = y - x",
+                "Avec 2^17 fenêtres stockées, un y aléatoire en touche une avec probabilité 2^17 / p : environ 2^14 essais, vérifier chaque candidat sur de nouvelles requêtes",
+            ],
+        ),
+        (
+            "Q21",
+            &[
+                "Multiplier c par r^-1 mod q : on obtient sum m_i w_i, sans réduction puisque q est plus grand que la somme des w_i",
+                "Les poids sont super-croissants : w_n est dans la somme si et seulement si la somme vaut au moins w_n",
+                "Partir du plus grand poids, soustraire chaque w_i qui ne dépasse pas le reste et mettre son bit à un",
+            ],
+        ),
+        (
+            "Q22",
+            &[
+                "Le réseau a n + 1 lignes de n + 1 entrées : 2 sur la diagonale et N b_i dans la dernière colonne, puis (1, ..., 1, N c)",
+                "Le facteur N rend long tout vecteur dont la dernière entrée est non nulle : les vecteurs courts résolvent la somme de sous-ensemble",
+                "Après `lll`, chercher une ligne d'entrées 1 ou -1 et de dernière entrée 0 : m_i = (1 + v_i) / 2, ou (1 - v_i) / 2 pour son opposée, vérifié en chiffrant",
+            ],
+        ),
+        (
+            "Q24",
+            &[
+                "Rien de nouveau à écrire : l'intervalle ne commence pas en 0, donc `lower` doit atteindre à la fois le départ apprivoisé et la réponse",
+                "La force brute demande 2^40 additions, le budget 2^24 : une marche dont le saut moyen est loin de sqrt(width) / 2 le dépasse",
+                "Compter vos propres opérations de groupe : les multiples de G pour les sauts et le départ apprivoisé en coûtent quelques centaines, le reste est la marche",
+            ],
+        ),
+        (
+            "Q26",
+            &[
+                "Chaque signature donne k_i = t_i x + u_i mod n avec t_i = r_i / s_i et u_i = z_i / s_i, et 0 <= k_i < n / 2^8",
+                "Éliminer x avec la première signature : k_i' = a_i k_1' + b_i mod n, avec les k_i' = k_i - B / 2 recentrés autour de 0",
+                "Les lignes (a_2, ..., a_m, 1, 0), (b_2, ..., b_m, 0, B / 2) et n e_i contiennent (k_2', ..., k_m', k_1', B / 2) : après `lll_floating`, trouver la ligne qui finit par +-B / 2 et vérifier x contre la clé publique",
+            ],
+        ),
+        (
+            "Q28",
+            &[
+                "Fermat est un seul `modpow` : a^(n - 1) mod n vaut 1 pour un premier probable",
+                "Pour Miller-Rabin, retirer les facteurs 2 de n - 1 avec `trailing_zeros` : n - 1 = 2^s d",
+                "Réussir si a^d vaut 1 ou n - 1, ou si l'une des s - 1 élévations au carré suivantes donne n - 1 : sinon a est un témoin",
+            ],
+        ),
+        (
+            "Q29",
+            &[
+                "Tester chaque base première avec chaque n, c'est cent millions d'exponentiations : chercher un critère sur les facteurs",
+                "Korselt : n est un nombre de Carmichael si et seulement s'il est composé, sans facteur carré et si p - 1 divise n - 1 pour chaque premier p divisant n",
+                "Factoriser chaque n impair par divisions successives, s'arrêter au premier facteur carré ou au premier p - 1 qui ne divise pas n - 1",
+            ],
+        ),
+        (
+            "Q30",
+            &[
+                "Traiter d'abord n < 4 et les nombres pairs : les bases aléatoires demandent 2 <= a <= n - 2",
+                "`gen_biguint_range` de `RandBigInt` tire les bases",
+                "Des bases fixes échouent sur les pseudo-premiers forts de `PSEUDOPRIMES` : tirer de nouvelles bases pour chaque n",
+            ],
+        ),
+        (
+            "Q31",
+            &[
+                "Tirer q de bits - 1 bits avec ses bits de poids fort et de poids faible à un, alors p = 2q + 1 a exactement `bits` bits",
+                "La plupart des candidats meurent sur un petit premier : q mod r = 0 exclut q, q mod r = (r - 1) / 2 exclut 2q + 1",
+                "Lancer Miller-Rabin sur q d'abord, puis sur 2q + 1, seulement pour les candidats qui survivent au crible",
+            ],
+        ),
+        (
+            "Q32",
+            &[
+                "Le sous-groupe d'ordre q est celui des carrés de F_p*",
+                "Élever au carré un h aléatoire dans [2, p - 2] : h^2 ne vaut pas 1, et son ordre divise q, un premier",
+            ],
+        ),
+        (
+            "Q34",
+            &[
+                "Tirer q d'abord, un premier aléatoire de q_bits bits, puis chercher p parmi les nombres kq + 1",
+                "Arrondir un nombre aléatoire de p_bits bits au multiple de 2q inférieur et ajouter 1 : p est impair et q divise p - 1",
+                "g = h^((p - 1) / q) pour un h aléatoire, retiré tant que g vaut 1",
+            ],
+        ),
+        (
+            "Q35",
+            &["Deux `modpow` et un produit mod p : les exposants vivent mod q, le résultat dans F_p*"],
+        ),
+        (
+            "Q36",
+            &[
+                "Chaque scalaire vit mod q et chaque élément du groupe mod p : r est g^k réduit mod p, puis mod q",
+                "q est premier : k^-1 mod q vaut k^(q - 2) mod q",
+                "Rejeter r et s hors de [1, q) avant toute chose lors de la vérification",
+            ],
+        ),
+        (
+            "Q38",
+            &[
+                "Garder x et chercher y' = y + tp, avec t dans [0, p)",
+                "(y + tp)^2 = y^2 + 2ytp mod p^2 : x^3 + a'x + b' - y^2 est un multiple de p, et 2yt doit être son quotient par p, mod p",
+                "t = ((x^3 + a'x + b' - y^2 mod p^2) / p) (2y)^-1 mod p, avec les a' et b' de la courbe relevée",
+            ],
+        ),
+        (
+            "Q39",
+            &[
+                "`CurveMod::mul` sur la courbe relevée calcule (p - 1)P' en coordonnées affines : chaque dénominateur est inversible jusqu'à la dernière addition",
+                "pP' = (p - 1)P' + P', et la pente de cette dernière addition a un dénominateur divisible par p : l'inverser dans l'autre sens",
+                "t = (x(P') - x((p - 1)P')) / (y(P') - y((p - 1)P')) mod p^2 est un multiple de p : renvoyer t / p",
+            ],
+        ),
+        (
+            "Q40",
+            &[
+                "Le morphisme transforme target = k generator en log(target) = k log(generator) mod p",
+                "Relever la courbe avec a' = a + rp pour un r aléatoire dans [1, p), pour que le relèvement ne soit pas le canonique",
+                "k = log(target) / log(generator) mod p, avec `modinv`",
+            ],
+        ),
+        (
+            "Q42",
+            &[
+                "zeta est une racine de X^2 + X + 1, qui n'a pas de racine dans F_p quand p = 2 mod 3",
+                "zeta = (-1 + sqrt(-3)) / 2 avec sqrt(-3) = sqrt(3) i, et 3 est un carré modulo p = 11 mod 12",
+                "p = 3 mod 4 : sqrt(3) = 3^((p + 1) / 4) mod p ; l'application de distorsion garde y et multiplie x par zeta",
+            ],
+        ),
+        (
+            "Q43",
+            &[
+                "Le même algorithme qu'à Q5, avec la multiplication de F_{p^2} pour loi de groupe",
+                "`Fp2` est `Hash` : stocker base^j -> j dans une `HashMap` pour j < m = ceil(sqrt(q))",
+                "Le pas de géant est base^-m = base^(q - m), puisque base est d'ordre q",
+            ],
+        ),
+        (
+            "Q44",
+            &[
+                "e(xG, phi(G)) = e(G, phi(G))^x par bilinéarité",
+                "Les deux couplages prennent le générateur distordu en second argument, le premier étant le générateur ou la cible",
+                "Renvoyer `fp2_discrete_log` de e(target, phi(G)) en base e(G, phi(G)), avec l'ordre q de l'instance",
+            ],
+        ),
+    ],
+};
@@ -0,0 +1,205 @@
+use runner::hints::Hints;
+use runner::lang::Catalog;
+
+pub const CATALOGS: &[Catalog] = &[
+    Catalog::english(HINTS, include_str!("main.rs")),
+    crate::fr::CATALOG,
+];
+
+pub const HINTS: Hints = &[
+    (
+        "Q1",
+        &[
+            "Build x from numbers which are 1 modulo one m_i and 0 modulo all the others",
+            "M_i = M / m_i is 0 modulo every m_j but m_i, and M_i (M_i^-1 mod m_i) is 1 modulo m_i",
+            "x = sum r_i M_i (M_i^-1 mod m_i) mod M, where M_i = M / m_i: reduce the result into [0, M)",
+        ],
+    ),
+    (
+        "Q2",
+        &[
+            "Raising to the power (p - 1) / q^e sends F_p* onto its subgroup of order q^e",
+            "If h = g^x, then h^((p - 1) / q^e) = (g^((p - 1) / q^e))^x, which only depends on x mod q^e",
+            "Compute both powers with `modpow`, then multiply 1 by g^((p - 1) / q^e) until reaching the projected h",
+        ],
+    ),
+    (
+        "Q5",
+        &[
+            "Write x = i m + j with 0 <= i, j < m = ceil(sqrt(q))",
+            "Q - i(mG) = jG: store the baby steps jG, then walk the giant steps Q - i(mG)",
+            "Store jG -> j in a `HashMap` for j < m, then for i = 0, 1, ... look up Q - i(mG) and return i m + j",
+        ],
+    ),
+    (
+        "Q9",
+        &[
+            "Walks from different starting points which reach the same distinguished point collide",
+            "Each thread sends (distinguished point, a, b) with the point equal to aG + bQ",
+            "The main thread keeps the points in a `HashMap`: on a repeat with a different b, x = (a - a') / (b' - b) mod q",
+        ],
+    ),
+    (
+        "Q11",
+        &[
+            "W and -W have the same x: the walk can work on the classes {W, -W}",
+            "Pick the representative with the smallest y, and negate its coefficients (a, b) along with it",
+            "A fruitless 2-cycle goes W -> W + R_i -> -(W + R_i) + R_i = -W: when the branch repeats, take branch i + 1",
+        ],
+    ),
+    (
+        "Q18",
+        &[
+            "The non-zero squares form the subgroup of index 2 of F_p*",
+            "Euler's criterion: a^((p - 1) / 2) is 1 if a is a non-zero square, p - 1 otherwise",
+            "Reduce a mod p, return 0 if it is zero, else compare `a.modpow(&((p - 1u32) >> 1), p)` with 1",
+        ],
+    ),
+    (
+        "Q19",
+        &[
+            "L_K(x) = L_0(K + x): the outputs of the oracle are a window of the sequence L_0, starting at K",
+            "Store the 64-bit windows of 2^17 consecutive queries in a `HashMap`, then slide over random y: a match gives K = y - x",
+            "With 2^17 stored windows, a random y hits one of them with probability 2^17 / p: about 2^14 tries, check each candidate on fresh queries",
+        ],
+    ),
+    (
+        "Q21",
+        &[
+            "Multiply c by r^-1 mod q: you get sum m_i w_i, without reduction since q is larger than the sum of the w_i",
+            "The weights are superincreasing: w_n is in the sum if and only if the sum is at least w_n",
+            "Go from the largest weight down, subtract each w_i not larger than the rest and set its bit",
+        ],
+    ),
+    (
+        "Q22",
+        &[
+            "The lattice has n + 1 rows of n + 1 entries: 2 on the diagonal and N b_i in the last column, then (1, ..., 1, N c)",
+            "The factor N makes any vector with a non-zero last entry long: the short ones solve the subset sum",
+            "After `lll`, look for a row with entries 1 or -1 and a last entry 0: m_i = (1 + v_i) / 2, or (1 - v_i) / 2 for its opposite, checked by encrypting",
+        ],
+    ),
+    (
+        "Q24",
+        &[
+            "Nothing new to write: the interval does not start at 0, so `lower` must reach both the tame start and the answer",
+            "Brute force needs 2^40 additions, the budget 2^24: a walk whose mean jump is far from sqrt(width) / 2 overshoots it",
+            "Count your own group operations: the multiples of G for the jumps and the tame start cost a few hundred, the rest is the walk",
+        ],
+    ),
+    (
+        "Q26",
+        &[
+            "Each signature gives k_i = t_i x + u_i mod n with t_i = r_i / s_i and u_i = z_i / s_i, and 0 <= k_i < n / 2^8",
+            "Eliminate x with the first signature: k_i' = a_i k_1' + b_i mod n, with the k_i' = k_i - B / 2 recentred around 0",
+            "The rows (a_2, ..., a_m, 1, 0), (b_2, ..., b_m, 0, B / 2) and n e_i hold (k_2', ..., k_m', k_1', B / 2): after `lll_floating`, find the row ending with +-B / 2 and check x against the public key",
+        ],
+    ),
+    (
+        "Q28",
+        &[
+            "Fermat is a single `modpow`: a^(n - 1) mod n is 1 for a probable prime",
+            "For Miller-Rabin, strip the factors 2 of n - 1 with `trailing_zeros`: n - 1 = 2^s d",
+            "Pass if a^d is 1 or n - 1, or if one of the s - 1 next squarings gives n - 1: otherwise a is a witness",
+        ],
+    ),
+    (
+        "Q29",
+        &[
+            "Testing every coprime base of every n is a hundred million exponentiations: look for a criterion on the factors",
+            "Korselt: n is a Carmichael number if and only if it is composite, squarefree and p - 1 divides n - 1 for each prime p dividing n",
+            "Factor each odd n by trial division, stop at the first square factor or the first p - 1 not dividing n - 1",
+        ],
+    ),
+    (
+        "Q30",
+        &[
+            "Handle n < 4 and the even numbers first: the random bases need 2 <= a <= n - 2",
+            "`gen_biguint_range` of `RandBigInt` draws the bases",
+            "Fixed bases fail on the strong pseudoprimes of `PSEUDOPRIMES`: draw new bases for each n",
+        ],
+    ),
+    (
+        "Q31",
+        &[
+            "Draw q of bits - 1 bits with its top and bottom bits set, then p = 2q + 1 has exactly `bits` bits",
+            "Most candidates die on a small prime: q mod r = 0 rules q out, q mod r = (r - 1) / 2 rules 2q + 1 out",
+            "Run Miller-Rabin on q first, then on 2q + 1, only for the candidates which survive the sieve",
+        ],
+    ),
+    (
+        "Q32",
+        &[
+            "The subgroup of order q is the squares of F_p*",
+            "Square a random h in [2, p - 2]: h^2 is not 1, and its order divides q, a prime",
+        ],
+    ),
+    (
+        "Q34",
+        &[
+            "Draw q first, a random prime of q_bits bits, then look for p among the numbers kq + 1",
+            "Round a random number of p_bits bits down to a multiple of 2q and add 1: p is odd and q divides p - 1",
+            "g = h^((p - 1) / q) for a random h, drawn again while g is 1",
+        ],
+    ),
+    (
+        "Q35",
+        &["Two `modpow` and a product mod p: the exponents live mod q, the result in F_p*"],
+    ),
+    (
+        "Q36",
+        &[
+            "Every scalar lives mod q and every group element mod p: r is g^k reduced mod p, then mod q",
+            "q is prime: k^-1 mod q is k^(q - 2) mod q",
+            "Reject r and s outside of [1, q) before anything else when verifying",
+        ],
+    ),
+    (
+        "Q38",
+        &[
+            "Keep x and look for y' = y + tp, with t in [0, p)",
+            "(y + tp)^2 = y^2 + 2ytp mod p^2: x^3 + a'x + b' - y^2 is a multiple of p, and 2yt must be its quotient by p, mod p",
+            "t = ((x^3 + a'x + b' - y^2 mod p^2) / p) (2y)^-1 mod p, with the a' and b' of the lifted curve",
+        ],
+    ),
+    (
+        "Q39",
+        &[
+            "`CurveMod::mul` on the lifted curve computes (p - 1)P' in affine coordinates: every denominator is invertible until the last addition",
+            "pP' = (p - 1)P' + P', and the slope of this last addition has a denominator divisible by p: invert it the other way",
+            "t = (x(P') - x((p - 1)P')) / (y(P') - y((p - 1)P')) mod p^2 is a multiple of p: return t / p",
+        ],
+    ),
+    (
+        "Q40",
+        &[
+            "The morphism turns target = k generator into log(target) = k log(generator) mod p",
+            "Lift the curve with a' = a + rp for a random r in [1, p), so that the lift is not the canonical one",
+            "k = log(target) / log(generator) mod p, with `modinv`",
+        ],
+    ),
+    (
+        "Q42",
+        &[
+            "zeta is a root of X^2 + X + 1, which has no root in F_p when p = 2 mod 3",
+            "zeta = (-1 + sqrt(-3)) / 2 with sqrt(-3) = sqrt(3) i, and 3 is a square mod p = 11 mod 12",
+            "p = 3 mod 4: sqrt(3) = 3^((p + 1) / 4) mod p; the distortion map keeps y and multiplies x by zeta",
+        ],
+    ),
+    (
+        "Q43",
+        &[
+            "The same algorithm as Q5, with the multiplication of F_{p^2} for the group law",
+            "`Fp2` is `Hash`: store base^j -> j in a `HashMap` for j < m = ceil(sqrt(q))",
+            "The giant step is base^-m = base^(q - m), since base has order q",
+        ],
+    ),
+    (
+        "Q44",
+        &[
+            "e(xG, phi(G)) = e(G, phi(G))^x by bilinearity",
+            "Both pairings take the distorted generator as second argument, the first one being the generator or the target",
+            "Return `fp2_discrete_log` of e(target, phi(G)) to the base e(G, phi(G)), with the order q of the instance",
+        ],
+    ),
+];
@@ -0,0 +1,83 @@
+use crate::*;
+use ark_ff::{BigInteger, PrimeField};
+
+// ECDSA on secp256k1: s = (z + r x) / k with r = (kG).x mod n, so each signature ties its nonce to the key:
+//   k = t x + u mod n, with t = r / s and u = z / s known
+// When every nonce is below B = n / 2^l, this is the hidden number problem of Boneh and Venkatesan: find x
+// from many t_i x + u_i mod n whose l top bits are zero. Each signature leaks l bits of information about x:
+// a little more than 256 / l signatures determine it, and a lattice finds it
+// - recentred around B / 2, the nonces k_i' = k_i - B / 2 are in [-B / 2, B / 2)
+// - the first equation gives x in terms of k_1', and the others become k_i' = a_i k_1' + b_i mod n
+// - the rows n e_i (i = 2..m), (a_2, ..., a_m, 1, 0) and (b_2, ..., b_m, 0, B / 2) span a lattice holding the
+//   short vector (k_2', ..., k_m', k_1', B / 2), whose entries are all at most B / 2, much shorter than
+//   det^(1 / (m + 1)) ~ n^((m - 1) / (m + 1)) when m l is well above 256: LLL finds it
+// This is how keys leaked from the nonces of biased generators, and from timing side channels on their bits
+// (Minerva, TPM-Fail, LadderLeak with less than one bit per signature)
+
+// the top bits of the nonces cleared by the faulty signer
+pub const NONCE_BIAS_BITS: u32 = 8;
+// enough for LLL with the lattice above: 40 * 8 = 320 bits leaked of a 256-bit key
+pub const HNP_SIGNATURES: usize = 40;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Signed {
+    pub z: Fr,
+    pub r: Fr,
+    pub s: Fr,
+}
+
+// n, the order of the group of secp256k1
+pub fn scalar_order() -> BigInt {
+    BigInt::from(BigUint::from(Fr::MODULUS))
+}
+
+pub fn to_bigint(x: &Fr) -> BigInt {
+    BigInt::from(BigUint::from(*x))
+}
+
+pub fn from_bigint(x: &BigInt) -> Fr {
+    Fr::from(x.mod_floor(&scalar_order()).to_biguint().unwrap())
+}
+
+// the x-coordinate of a point, reduced mod n
+fn x_mod_n(point: &Affine) -> Fr {
+    Fr::from_be_bytes_mod_order(&point.x.into_bigint().to_bytes_be())
+}
+
+// `None` when k, r or s is zero
+pub fn ecdsa_sign_with_nonce(secret_key: &Fr, z: &Fr, k: &Fr) -> Option<Signed> {
+    let r = x_mod_n(&Affine::generator().mul(k).into_affine());
+    let s = (*z + r * secret_key) * k.inverse()?;
+    (!r.is_zero() && !s.is_zero()).then_some(Signed { z: *z, r, s })
+}
+
+pub fn ecdsa_verify(public_key: &Affine, signed: &Signed) -> bool {
+    let Some(w) = signed.s.inverse() else {
+        return false;
+    };
+    let point =
+        (Affine::generator().mul(signed.z * w) + public_key.mul(signed.r * w)).into_affine();
+    !signed.r.is_zero() && !point.is_zero() && x_mod_n(&point) == signed.r
+}
+
+// a fresh key pair and its signatures of random hashes, with nonces below n / 2^NONCE_BIAS_BITS: only the
+// public key and the signatures leave this function
+pub fn biased_signatures<R: Rng>(count: usize, rng: &mut R) -> (Affine, Vec<Signed>) {
+    let (_, public_key, signatures) = biased_signatures_with_key(count, rng);
+    (public_key, signatures)
+}
+
+// the same, with the secret key, for the verifier of the student challenges
+pub fn biased_signatures_with_key<R: Rng>(count: usize, rng: &mut R) -> (Fr, Affine, Vec<Signed>) {
+    let secret_key = Fr::rand(rng);
+    let public_key = Affine::generator().mul(secret_key).into_affine();
+    let bound = BigUint::from(Fr::MODULUS) >> NONCE_BIAS_BITS;
+    let mut signatures = vec![];
+    while signatures.len() < count {
+        let k = Fr::from(rng.gen_biguint_below(&bound));
+        if let Some(signed) = ecdsa_sign_with_nonce(&secret_key, &Fr::rand(rng), &k) {
+            signatures.push(signed);
+        }
+    }
+    (secret_key, public_key, signatures)
+}
@@ -0,0 +1,68 @@
+use crate::*;
+
+// Pollard's kangaroo (lambda) algorithm: find x = log_G(Q) knowing that x lies in [lower, lower + width)
+// in about 2 sqrt(width) group operations and little memory, whatever the size of the group
+// - a pseudorandom walk jumps from P to P + s_i G where the index i is derived from P
+// - the tame kangaroo starts at (lower + width) G, the wild one at Q, both record their travelled distance
+// - once the wild kangaroo lands on a point of the tame trail, it follows the same path
+//   and they meet at the next "distinguished" point (a point whose hash has a given number of leading zeros):
+//   lower + width + d_tame = x + d_wild
+// Only the distinguished points are stored, which is also how the search is distributed among many machines
+
+// a hash of the point deciding both the jumps and which points are distinguished
+pub fn point_hash(point: &ToyPoint) -> u64 {
+    match *point {
+        ToyPoint::Infinity => 0,
+        ToyPoint::Affine(x, _) => x.wrapping_mul(0x9e37_79b9_7f4a_7c15),
+    }
+}
+
+// jumps 2^0, 2^1, ..., 2^(k-1) whose mean (2^k - 1) / k is about sqrt(width) / 2
+pub fn jump_distances(width: u64) -> Vec<u64> {
+    let target = (width as f64).sqrt() / 2.0;
+    let k = (1..63)
+        .find(|k| ((1u64 << k) - 1) as f64 / *k as f64 >= target)
+        .unwrap();
+    (0..k).map(|i| 1u64 << i).collect()
+}
+
+// about one point out of 2^(log2(width) / 4) is distinguished
+pub fn distinguished_bits(width: u64) -> u32 {
+    (64 - width.leading_zeros()) / 4
+}
+
+pub fn is_distinguished(point: &ToyPoint, bits: u32) -> bool {
+    point_hash(point).leading_zeros() >= bits
+}
+
+pub fn jump_index(point: &ToyPoint, jump_count: usize) -> usize {
+    (point_hash(point) % jump_count as u64) as usize
+}
+
+// the interval version of the challenge: a scalar known to lie in [0, 2^bits)
+pub fn random_interval_instance<R: Rng>(
+    bits: u32,
+    curve_bits: u32,
+    rng: &mut R,
+) -> (DlpInstance, u64) {
+    let (mut instance, _) = DlpInstance::random(curve_bits, rng);
+    let x = rng.gen_range(0..1u64 << bits);
+    instance.target = instance.curve.mul(&instance.generator, x);
+    (instance, x)
+}
+
+// The secret of Q24 is a scalar of a group of 56-bit order drawn with 40 bits of entropy: x lies in [lower, lower + 2^40)
+// and the question only sees the public instance and the bounds. The kangaroo walks about 2 * 2^20 steps,
+// brute force 2^40: the budget leaves room for the setup, restarts and unlucky walks, not for more
+pub const HIDDEN_INTERVAL_BITS: u32 = 40;
+pub const HIDDEN_CURVE_BITS: u32 = 56;
+pub const KANGAROO_BUDGET: u64 = 1 << 24;
+
+// the instance and the lower bound of the interval, the secret stays here
+pub fn hidden_interval_instance<R: Rng>(rng: &mut R) -> (DlpInstance, u64) {
+    let (instance, _) = DlpInstance::random(HIDDEN_CURVE_BITS, rng);
+    let lower = rng.gen_range(0..instance.order - (1 << HIDDEN_INTERVAL_BITS));
+    let x = lower + rng.gen_range(0..1 << HIDDEN_INTERVAL_BITS);
+    let target = instance.curve.mul(&instance.generator, x);
+    (DlpInstance { target, ..instance }, lower)
+}
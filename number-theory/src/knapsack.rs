@@ -0,0 +1,68 @@
+use crate::*;
+
+// The Merkle-Hellman knapsack cryptosystem (1978), broken by Shamir in 1982, then by lattice reduction
+// - the private key is a superincreasing sequence w_1, ..., w_n (each w_i larger than the sum of the previous
+//   ones), a modulus q larger than their sum and a multiplier r coprime to q
+// - the public key is the sequence b_i = r w_i mod q, which looks random
+// - a message of n bits m_i is encrypted into the subset sum c = sum m_i b_i
+// - decryption: c r^-1 = sum m_i w_i mod q, and this subset sum of a superincreasing sequence is solved greedily,
+//   from w_n down to w_1
+// Recovering m from (b, c) is a subset sum problem, NP-hard in general, but easy for lattices when the density
+// n / log2(max b_i) is low (Lagarias-Odlyzko, then Coster, Joux, LaMacchia, Odlyzko, Schnorr and Stern below 0.94)
+
+// the bits of a message
+pub const KNAPSACK_BITS: usize = 24;
+// each w_i exceeds the sum of the previous ones by up to 2^SLACK_BITS, which lowers the density to about 1/2
+pub const SLACK_BITS: u32 = 24;
+
+pub struct KnapsackPrivateKey {
+    pub weights: Vec<BigInt>, // superincreasing
+    pub modulus: BigInt,
+    pub multiplier: BigInt,
+}
+
+pub struct KnapsackPublicKey {
+    pub weights: Vec<BigInt>,
+}
+
+pub fn knapsack_keygen<R: Rng>(rng: &mut R) -> (KnapsackPrivateKey, KnapsackPublicKey) {
+    let mut weights: Vec<BigInt> = vec![];
+    let mut sum = BigInt::zero();
+    for _ in 0..KNAPSACK_BITS {
+        let w = &sum + 1 + rng.gen_range(0..1u64 << SLACK_BITS);
+        sum += &w;
+        weights.push(w);
+    }
+    let modulus = &sum + 1 + rng.gen_range(0..1u64 << SLACK_BITS);
+    let multiplier = loop {
+        let r = rng.gen_bigint_range(&BigInt::from(2), &modulus);
+        if r.gcd(&modulus).is_one() {
+            break r;
+        }
+    };
+    let public = weights.iter().map(|w| w * &multiplier % &modulus).collect();
+    (
+        KnapsackPrivateKey {
+            weights,
+            modulus,
+            multiplier,
+        },
+        KnapsackPublicKey { weights: public },
+    )
+}
+
+pub fn knapsack_encrypt(public_key: &KnapsackPublicKey, message: &[bool]) -> BigInt {
+    public_key
+        .weights
+        .iter()
+        .zip(message)
+        .filter(|(_, bit)| **bit)
+        .map(|(b, _)| b)
+        .sum()
+}
+
+// n / log2(max b_i)
+pub fn density(public_key: &KnapsackPublicKey) -> f64 {
+    let max = public_key.weights.iter().max().unwrap();
+    public_key.weights.len() as f64 / max.bits() as f64
+}
@@ -0,0 +1,93 @@
+use crate::*;
+use std::cell::Cell;
+
+// The Legendre PRF (Damgard 1988): with a secret key K in F_p, the output on x is the bit
+//   L_K(x) = 0 if K + x is a square modulo p (zero included), 1 otherwise
+// i.e. the Legendre symbol ((K + x) / p) mapped from {1, 0, -1} to {0, 0, 1}
+// A single multiplication per bit in multi-party computation, which makes it a candidate PRF for MPC
+// Its outputs on consecutive inputs are a window of the Legendre sequence L_0, shifted by K:
+// the key recovery attacks look for that window (Khovratovich 2019)
+
+// the Mersenne prime 2^127 - 1, congruent to 3 mod 4: -1 is not a square
+pub fn legendre_prime() -> BigUint {
+    (BigUint::one() << 127u32) - 1u32
+}
+
+// the tiny prime of the key recovery challenge, 2^31 - 1
+pub const TINY_PRIME: u64 = (1 << 31) - 1;
+// the oracle answers at most this many queries, about 2^5 sqrt(p)
+pub const QUERY_BUDGET: u64 = 1 << 20;
+// the bits of the statistical tests
+pub const PRF_BITS: u64 = 1 << 14;
+
+pub fn legendre_prf(key: &BigUint, x: &BigUint, p: &BigUint) -> bool {
+    legendre_symbol(&((key + x) % p), p) == -1
+}
+
+// L_K on a tiny prime, with Euler's criterion on 64-bit integers
+pub fn legendre_prf_tiny(key: u64, x: u64) -> bool {
+    let a = (key + x % TINY_PRIME) % TINY_PRIME;
+    pow_mod(a, (TINY_PRIME - 1) / 2, TINY_PRIME) == TINY_PRIME - 1
+}
+
+// answers L_K(x) for a secret K, and counts the queries
+pub struct LegendreOracle {
+    key: u64,
+    queries: Cell<u64>,
+}
+
+impl LegendreOracle {
+    pub fn new<R: Rng>(rng: &mut R) -> LegendreOracle {
+        LegendreOracle {
+            key: rng.gen_range(0..TINY_PRIME),
+            queries: Cell::new(0),
+        }
+    }
+
+    pub fn query(&self, x: u64) -> bool {
+        let queries = self.queries.get() + 1;
+        assert!(
+            queries <= QUERY_BUDGET,
+            "the oracle answers {} queries at most",
+            QUERY_BUDGET
+        );
+        self.queries.set(queries);
+        legendre_prf_tiny(self.key, x)
+    }
+
+    pub fn queries(&self) -> u64 {
+        self.queries.get()
+    }
+
+    pub fn is_key(&self, key: u64) -> bool {
+        self.key == key
+    }
+}
+
+// Statistical tests on the bits of a PRF (see NIST SP 800-22), all of them near 0 for random bits
+// - monobit: the number of ones, centered and normalized, ~ N(0, 1)
+pub fn monobit_z(bits: &[bool]) -> f64 {
+    let n = bits.len() as f64;
+    let ones = bits.iter().filter(|bit| **bit).count() as f64;
+    (ones - n / 2.0) / (n / 4.0).sqrt()
+}
+
+// - runs: the number of maximal runs of equal bits, ~ N(n / 2, n / 4) for balanced bits
+pub fn runs_z(bits: &[bool]) -> f64 {
+    let n = bits.len() as f64;
+    let runs = 1 + bits.windows(2).filter(|pair| pair[0] != pair[1]).count();
+    (runs as f64 - n / 2.0) / (n / 4.0).sqrt()
+}
+
+// - serial: chi-square of the frequencies of the 4 patterns of 2 consecutive bits, 3 degrees of freedom
+pub fn serial_chi2(bits: &[bool]) -> f64 {
+    let mut counts = [0.0; 4];
+    for pair in bits.windows(2) {
+        counts[2 * pair[0] as usize + pair[1] as usize] += 1.0;
+    }
+    let expected = (bits.len() - 1) as f64 / 4.0;
+    counts
+        .iter()
+        .map(|count| (count - expected).powi(2) / expected)
+        .sum()
+}
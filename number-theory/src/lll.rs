@@ -0,0 +1,179 @@
+use crate::*;
+use num_traits::{FromPrimitive, Signed, ToPrimitive};
+
+// LLL lattice reduction, in exact integer arithmetic (Cohen, A Course in Computational Algebraic Number Theory,
+// algorithm 2.6.7): the Gram-Schmidt coefficients are kept as integers lambda_{k,j} = d_j mu_{k,j},
+// where d_j is the Gram determinant of the first j vectors, so that no rational number is ever needed
+// The output basis spans the same lattice with short, nearly orthogonal vectors:
+// its first vector is at most (4 / 3)^((n - 1) / 2) times longer than the shortest one, and much better in practice
+// The rows of `basis` are the vectors, linearly independent
+
+// the Lovasz condition with delta = 99 / 100
+const DELTA_NUMERATOR: u32 = 99;
+const DELTA_DENOMINATOR: u32 = 100;
+
+fn dot(a: &[BigInt], b: &[BigInt]) -> BigInt {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+struct Lll {
+    b: Vec<Vec<BigInt>>, // 1-indexed as in Cohen, b[0] is unused
+    d: Vec<BigInt>,
+    lambda: Vec<Vec<BigInt>>,
+}
+
+impl Lll {
+    // size reduction of b_k against b_l: |mu_{k,l}| <= 1/2
+    fn reduce(&mut self, k: usize, l: usize) {
+        if (&self.lambda[k][l] * 2u32).abs() <= self.d[l] {
+            return;
+        }
+        // the integer nearest to lambda_{k,l} / d_l
+        let q = (&self.lambda[k][l] * 2u32 + &self.d[l]).div_floor(&(&self.d[l] * 2u32));
+        let b_l = self.b[l].clone();
+        for (x, y) in self.b[k].iter_mut().zip(&b_l) {
+            *x -= &q * y;
+        }
+        self.lambda[k][l] -= &q * &self.d[l];
+        for i in 1..l {
+            let delta = &q * &self.lambda[l][i];
+            self.lambda[k][i] -= delta;
+        }
+    }
+
+    fn swap(&mut self, k: usize, k_max: usize) {
+        self.b.swap(k, k - 1);
+        for j in 1..k - 1 {
+            let t = self.lambda[k][j].clone();
+            self.lambda[k][j] = std::mem::replace(&mut self.lambda[k - 1][j], t);
+        }
+        let lambda = self.lambda[k][k - 1].clone();
+        let b = (&self.d[k - 2] * &self.d[k] + &lambda * &lambda) / &self.d[k - 1];
+        for i in k + 1..=k_max {
+            let t = self.lambda[i][k].clone();
+            self.lambda[i][k] =
+                (&self.d[k] * &self.lambda[i][k - 1] - &lambda * &t) / &self.d[k - 1];
+            self.lambda[i][k - 1] = (&b * &t + &lambda * &self.lambda[i][k]) / &self.d[k];
+        }
+        self.d[k - 1] = b;
+    }
+}
+
+pub fn lll(basis: &[Vec<BigInt>]) -> Vec<Vec<BigInt>> {
+    let n = basis.len();
+    if n < 2 {
+        return basis.to_vec();
+    }
+    let mut state = Lll {
+        b: [vec![vec![]], basis.to_vec()].concat(),
+        d: vec![BigInt::zero(); n + 1],
+        lambda: vec![vec![BigInt::zero(); n + 1]; n + 1],
+    };
+    state.d[0] = BigInt::one();
+    state.d[1] = dot(&state.b[1], &state.b[1]);
+    let (mut k, mut k_max) = (2, 1);
+    while k <= n {
+        // incremental Gram-Schmidt
+        if k > k_max {
+            k_max = k;
+            for j in 1..=k {
+                let mut u = dot(&state.b[k], &state.b[j]);
+                for i in 1..j {
+                    u = (&state.d[i] * u - &state.lambda[k][i] * &state.lambda[j][i])
+                        / &state.d[i - 1];
+                }
+                if j < k {
+                    state.lambda[k][j] = u;
+                } else {
+                    assert!(!u.is_zero(), "the vectors of the basis are not independent");
+                    state.d[k] = u;
+                }
+            }
+        }
+        loop {
+            state.reduce(k, k - 1);
+            // Lovasz: d_k d_{k-2} >= delta d_{k-1}^2 - lambda_{k,k-1}^2
+            let lambda = &state.lambda[k][k - 1];
+            let lhs = &state.d[k] * &state.d[k - 2] * DELTA_DENOMINATOR;
+            let rhs = &state.d[k - 1] * &state.d[k - 1] * DELTA_NUMERATOR
+                - lambda * lambda * DELTA_DENOMINATOR;
+            if lhs >= rhs {
+                break;
+            }
+            state.swap(k, k_max);
+            k = (k - 1).max(2);
+        }
+        for l in (1..k - 1).rev() {
+            state.reduce(k, l);
+        }
+        k += 1;
+    }
+    state.b.split_off(1)
+}
+
+// The same reduction after Schnorr and Euchner, for the larger lattices of the hidden number problem: the basis
+// stays exact, the Gram-Schmidt coefficients are recomputed in floating point from exact dot products, each
+// time a row changes. Their rounding errors are caught by size-reducing the row again until it no longer
+// changes. Much faster than the integer version, which carries huge Gram determinants, and
+// reliable as long as the dimension stays well below the precision of an f64 (a few dozen rows)
+pub fn lll_floating(basis: &[Vec<BigInt>]) -> Vec<Vec<BigInt>> {
+    let n = basis.len();
+    if n < 2 {
+        return basis.to_vec();
+    }
+    let delta = DELTA_NUMERATOR as f64 / DELTA_DENOMINATOR as f64;
+    let to_f64 = |x: &BigInt| x.to_f64().unwrap();
+    let mut b = basis.to_vec();
+    let mut mu = vec![vec![0f64; n]; n];
+    // the squared norms of the Gram-Schmidt vectors
+    let mut c = vec![0f64; n];
+    c[0] = to_f64(&dot(&b[0], &b[0]));
+    let mut k = 1;
+    while k < n {
+        loop {
+            for j in 0..k {
+                let mut s = to_f64(&dot(&b[k], &b[j]));
+                for i in 0..j {
+                    s -= mu[j][i] * mu[k][i] * c[i];
+                }
+                mu[k][j] = s / c[j];
+            }
+            c[k] = to_f64(&dot(&b[k], &b[k]))
+                - (0..k).map(|j| mu[k][j] * mu[k][j] * c[j]).sum::<f64>();
+            // the coefficients of a vector which got shorter are more accurate: recompute them
+            let mut reduced = false;
+            for j in (0..k).rev() {
+                if mu[k][j].abs() <= 0.5 {
+                    continue;
+                }
+                let q = mu[k][j].round();
+                reduced = true;
+                let q_exact = BigInt::from_f64(q).unwrap();
+                let b_j = b[j].clone();
+                for (x, y) in b[k].iter_mut().zip(&b_j) {
+                    *x -= &q_exact * y;
+                }
+                let (lower, upper) = mu.split_at_mut(k);
+                for (x, y) in upper[0][..j].iter_mut().zip(&lower[j][..j]) {
+                    *x -= q * y;
+                }
+                mu[k][j] -= q;
+            }
+            if !reduced {
+                break;
+            }
+        }
+        // Lovasz
+        if c[k] + mu[k][k - 1] * mu[k][k - 1] * c[k - 1] >= delta * c[k - 1] {
+            k += 1;
+            continue;
+        }
+        b.swap(k, k - 1);
+        if k == 1 {
+            c[0] = to_f64(&dot(&b[0], &b[0]));
+        } else {
+            k -= 1;
+        }
+    }
+    b
+}
@@ -0,0 +1,1496 @@
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::Field;
+use ark_secp256k1::{Affine, Fr};
+use ark_std::{ops::Mul, UniformRand};
+use challenge::*;
+use dh_params::*;
+use dsa::*;
+use hnp::*;
+use kangaroo::*;
+use knapsack::*;
+use legendre_prf::*;
+use lll::*;
+use mov::*;
+use num_bigint::{BigInt, BigUint, RandBigInt};
+use num_integer::Integer;
+use num_traits::{One, Zero};
+use pohlig_hellman::*;
+use primality::*;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+use rho::*;
+use runner::Runner;
+use smart::*;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Instant;
+use subgroup::*;
+use toy_curve::*;
+use visualizer::*;
+
+mod challenge;
+mod dh_params;
+mod dsa;
+mod fr;
+mod hints;
+mod hnp;
+mod kangaroo;
+mod knapsack;
+mod legendre_prf;
+mod lll;
+mod mov;
+mod pohlig_hellman;
+mod primality;
+mod rho;
+mod smart;
+mod subgroup;
+mod toy_curve;
+mod visualizer;
+
+fn main() {
+    let args = runner::args();
+    if let [command, rest @ ..] = args.as_slice() {
+        if command == "visualize" {
+            return visualize(rest);
+        }
+        if command == "dh-params" {
+            return dh_params_command(rest);
+        }
+    }
+    if let [command, name, rest @ ..] = args.as_slice() {
+        if command == "challenge" && name == "dlp" {
+            let (solver, rest): (Arc<dyn DlpSolver>, _) = match rest {
+                [solver, rest @ ..] if solver == "kangaroo" => (Arc::new(Kangaroo), rest),
+                [solver, rest @ ..] if solver == "bsgs" => (Arc::new(BabyStepGiantStep), rest),
+                [solver, rest @ ..] if solver == "rho" => (Arc::new(ParallelRho::new()), rest),
+                _ => (Arc::new(BabyStepGiantStep), rest),
+            };
+            return challenge_dlp(solver, rest);
+        }
+        if command == "challenge" && name == "export" {
+            return challenge_export(rest);
+        }
+        if command == "challenge" && name == "student" {
+            return challenge_student(rest);
+        }
+        if command == "challenge" && name == "verify" {
+            return challenge_verify(rest);
+        }
+    }
+
+    let runner = Runner::start(env!("CARGO_PKG_NAME"), hints::CATALOGS);
+    let mut rng = seed::rng();
+
+    // The Chinese remainder theorem: for pairwise coprime moduli m_1, ..., m_k and any residues r_1, ..., r_k
+    // there is a unique x in [0, m_1 ... m_k) such that x = r_i mod m_i for all i
+    // Q1: write `crt` which computes it
+    runner.question("Q1");
+    let residues = [2, 3, 2].map(BigInt::from);
+    let moduli = [3, 5, 7].map(BigInt::from);
+    assert_eq!(crt(&residues, &moduli), BigInt::from(23));
+    // residues may be negative or larger than their modulus
+    let residues = [-1, 8, 16].map(BigInt::from);
+    assert_eq!(crt(&residues, &moduli), BigInt::from(23));
+    assert_eq!(crt(&[BigInt::from(5)], &[BigInt::from(7)]), BigInt::from(5));
+
+    // large random instances
+    for _ in 0..100 {
+        let mut moduli: Vec<BigInt> = vec![];
+        while moduli.len() < 8 {
+            let candidate = BigInt::from(rng.gen_biguint(256)) + 2;
+            if moduli.iter().all(|m| m.gcd(&candidate).is_one()) {
+                moduli.push(candidate);
+            }
+        }
+        let product: BigInt = moduli.iter().product();
+        let x = rng.gen_bigint_range(&BigInt::zero(), &product);
+        let residues: Vec<BigInt> = moduli.iter().map(|m| &x % m).collect();
+        assert_eq!(crt(&residues, &moduli), x);
+    }
+
+    // Take a look at module `pohlig_hellman`
+    // Q2: write `dlog_prime_power` which finds x mod q^e by brute force in the subgroup of order q^e
+    // Q3: write `pohlig_hellman` which combines them with `crt`
+    runner.questions(&["Q2", "Q3"]);
+    let p = smooth_prime();
+    let g = BigUint::from(GENERATOR);
+    let factors = smooth_order_factors();
+    let order: BigUint = factors
+        .iter()
+        .map(|(q, e)| BigUint::from(*q).pow(*e))
+        .product();
+    assert_eq!(order, &p - 1u32);
+    for _ in 0..10 {
+        let x = rng.gen_biguint_below(&order);
+        let h = g.modpow(&x, &p);
+        assert_eq!(pohlig_hellman(&g, &h, &p, &factors), x);
+    }
+    // Q4: the largest prime factor of p - 1 is 59: how many group operations does your attack take,
+    // compared to the generic sqrt(p) of baby-step giant-step? What if p - 1 = 2q with q prime?
+    runner.question("Q4");
+
+    // In a group of prime order q there is no shortcut left: generic algorithms need sqrt(q) operations
+    // Take a look at module `toy_curve`, whose curves have a subgroup of prime order of a chosen size
+    // Q5: implement baby-step giant-step as a `DlpSolver`
+    // with m = ceil(sqrt(q)), x = i m + j where jG is a baby step stored in a table
+    // and Q - i(mG) a giant step looked up in the table
+    runner.question("Q5");
+    for bits in [8, 16, 24] {
+        let (instance, x) = DlpInstance::random(bits, &mut rng);
+        assert_eq!(BabyStepGiantStep.solve(&instance), x);
+    }
+    // Q6: how far does it go in 10 seconds? Take a look at module `challenge` and run
+    // `cargo run --release -- challenge dlp`, your best score is recorded in the progress report
+    // Memory is also a limit: how much does the table take at 48 bits?
+    runner.question("Q6");
+
+    // When x is known to lie in a small interval, the cost should depend on the width of the interval,
+    // not on the size of the group: this happens with short exponents, or leaked high bits of a secret key
+    // Take a look at module `kangaroo`
+    // Q7: write `kangaroo` which finds x in [lower, lower + width)
+    runner.question("Q7");
+    let (instance, x) = random_interval_instance(16, 56, &mut rng);
+    assert_eq!(kangaroo(&instance, 0, 1 << 16), x);
+    let (instance, x) = random_interval_instance(24, 56, &mut rng);
+    let lower = x.saturating_sub(rng.gen_range(0..1 << 20));
+    assert_eq!(kangaroo(&instance, lower, 1 << 20), x);
+    for _ in 0..3 {
+        let (instance, x) = random_interval_instance(40, 56, &mut rng);
+        assert_eq!(kangaroo(&instance, 0, 1 << 40), x);
+    }
+    // A full interval [0, q) is just another generic solver: compare it with baby-step giant-step
+    // with `cargo run --release -- challenge dlp kangaroo`
+    // Q8: how much memory does it need? How would you share the work among many machines?
+    runner.question("Q8");
+
+    // Pollard's rho needs as little memory as the kangaroo but works in the whole group, and it parallelizes perfectly
+    // Take a look at module `rho`
+    // Q9: write `parallel_rho` where each thread runs walks from random starting points
+    // and sends its distinguished points over a channel to the main thread, which looks for a collision
+    runner.question("Q9");
+    for threads in [1, 2, 4] {
+        for bits in [12, 24, 32] {
+            let (instance, x) = DlpInstance::random(bits, &mut rng);
+            assert_eq!(parallel_rho(&instance, threads, &mut rng), x);
+        }
+    }
+    // a small benchmark: the time to solve the same instances with 1 thread, then with all the cores
+    let cores = thread::available_parallelism().map_or(1, |n| n.get());
+    let instances: Vec<(DlpInstance, u64)> =
+        (0..8).map(|_| DlpInstance::random(36, &mut rng)).collect();
+    let mut timings = vec![];
+    for threads in [1, cores] {
+        let start = Instant::now();
+        for (instance, x) in &instances {
+            assert_eq!(parallel_rho(instance, threads, &mut rng), *x);
+        }
+        timings.push(start.elapsed());
+        println!("rho on {} thread(s): {:.2?}", threads, start.elapsed());
+    }
+    println!(
+        "speedup with {} cores: {:.2}",
+        cores,
+        timings[0].as_secs_f64() / timings[1].as_secs_f64()
+    );
+    // Q10: the speedup should be close to the number of cores: why is it linear, unlike running
+    // independent single-threaded rho attacks? Compare with `cargo run --release -- challenge dlp rho`
+    runner.question("Q10");
+
+    // Q11: write `negation_step` which walks on the classes {W, -W}, represented by the point with the smallest y
+    // and avoids the fruitless 2-cycles: when the next point would use the same branch i, use branch i + 1 instead
+    runner.question("Q11");
+    // The speedup is a statistic: the walks draw from a generator of their own, the same whatever the seed, and
+    // 400 of them keep the ratio within a few hundredths of its mean, about 1.3
+    let mut walks_rng = ChaChaRng::seed_from_u64(11);
+    let (mut plain_steps, mut negation_steps) = (0, 0);
+    for _ in 0..400 {
+        let (instance, x) = DlpInstance::random(24, &mut walks_rng);
+        let walk = RhoWalk::with_branches(&instance, NEGATION_RHO_BRANCHES, &mut walks_rng);
+        let (plain_x, steps) = serial_rho(&walk, RhoWalk::step, &mut walks_rng);
+        assert_eq!(plain_x, x);
+        plain_steps += steps;
+        let (negation_x, steps) = serial_rho(&walk, negation_step, &mut walks_rng);
+        assert_eq!(negation_x, x);
+        negation_steps += steps;
+    }
+    let speedup = plain_steps as f64 / negation_steps as f64;
+    println!(
+        "the negation map divides the number of rho steps by {:.2}",
+        speedup
+    );
+    assert!(speedup > 1.15);
+    // Q12: what is the expected speedup? secp256k1 also has the endomorphism (x, y) -> (beta x, y)
+    // of order 3, with beta a cube root of unity in F_p: how much faster is rho on the classes
+    // {P, -P, phi(P), -phi(P), phi^2(P), -phi^2(P)}? Why don't our toy curves have it?
+    runner.question("Q12");
+
+    // Take a look at module `subgroup`
+    let curve = PAIRING_CURVE;
+    let (h, q) = (PAIRING_COFACTOR, PAIRING_ORDER);
+    let points = all_points(&curve);
+    assert_eq!(points.len() as u64, h * q);
+    // Q13: write `is_in_subgroup_by_cofactor`: P is in the subgroup iff P = h(h^-1 mod q)P
+    // (the cofactor multiplication maps E onto the subgroup, and is a bijection on it)
+    runner.question("Q13");
+    for point in &points {
+        assert_eq!(
+            is_in_subgroup_by_cofactor(&curve, h, q, point),
+            curve.is_in_prime_order_subgroup(point, q)
+        );
+    }
+    // Q14: write `miller_loop` computing f_{h,T}(S) with the help of `line_function`,
+    // then `is_in_subgroup_by_pairing`, trying the shifts R until the evaluation is defined
+    runner.question("Q14");
+    let t = *points
+        .iter()
+        .find(|point| **point != ToyPoint::Infinity && curve.mul(point, h) == ToyPoint::Infinity)
+        .unwrap();
+    for point in &points {
+        assert_eq!(
+            is_in_subgroup_by_pairing(&curve, &t, h, point, &points),
+            curve.is_in_prime_order_subgroup(point, q)
+        );
+    }
+    // Q15: Ed25519 has cofactor 8 = 2^3: why can't the pairing check be used there as is?
+    runner.question("Q15");
+
+    // Q16: write `double_and_add_steps` which computes kP from the most significant bit of k down:
+    // starting from P, for every following bit, double, then add P if the bit is set
+    // It returns every intermediate point with the operation that produced it
+    // then watch them on the curve with `cargo run --release -- visualize` (take a look at module `visualizer`)
+    runner.question("Q16");
+    let curve = ToyCurve {
+        p: DEFAULT_P,
+        a: 0,
+        b: VISUALIZER_B,
+    };
+    let (x, y) = affine_points(&curve)[1];
+    let base = ToyPoint::Affine(x, y);
+    let steps = double_and_add_steps(&curve, &base, 45); // 45 = 0b101101
+    let kinds: Vec<Step> = steps.iter().map(|(step, _)| *step).collect();
+    use Step::{Add, Double};
+    assert_eq!(
+        kinds,
+        [Double, Double, Add, Double, Add, Double, Double, Add]
+    );
+    assert_eq!(steps.last().unwrap().1, curve.mul(&base, 45));
+    assert_eq!(steps[2].1, curve.mul(&base, 5));
+    assert!(double_and_add_steps(&curve, &base, 1).is_empty());
+    for k in 1..200 {
+        let steps = double_and_add_steps(&curve, &base, k);
+        assert_eq!(steps.len() as u32, 62 - k.leading_zeros() + k.count_ones());
+        assert_eq!(
+            steps.last().map_or(base, |(_, point)| *point),
+            curve.mul(&base, k)
+        );
+    }
+    // Q17: which points does the walk visit for k = 2^n? for k = 2^n - 1?
+    // Why does the sequence of operations leak k to someone watching the execution, and how does
+    // the Montgomery ladder of the curve25519 chapter avoid it?
+    runner.question("Q17");
+
+    // Take a look at module `legendre_prf`
+    // Q18: write `legendre_symbol` which returns 1, 0 or -1 as a is a non-zero square, zero or not a square mod p
+    runner.question("Q18");
+    let p = BigUint::from(23u32);
+    let squares: Vec<BigUint> = (1..23u32).map(|r| BigUint::from(r * r % 23)).collect();
+    for a in 0..46u32 {
+        let a = BigUint::from(a);
+        let expected = match &a % 23u32 {
+            r if r.is_zero() => 0,
+            r if squares.contains(&r) => 1,
+            _ => -1,
+        };
+        assert_eq!(legendre_symbol(&a, &p), expected);
+    }
+    let p = legendre_prime();
+    for _ in 0..100 {
+        let r = rng.gen_biguint_below(&p);
+        let square = &r * &r % &p;
+        if !r.is_zero() {
+            assert_eq!(legendre_symbol(&square, &p), 1);
+            // -1 is not a square modulo 2^127 - 1
+            assert_eq!(legendre_symbol(&(&p - &square), &p), -1);
+        }
+        let (a, b) = (rng.gen_biguint_below(&p), rng.gen_biguint_below(&p));
+        // the symbol is multiplicative
+        assert_eq!(
+            legendre_symbol(&(&a * &b % &p), &p),
+            legendre_symbol(&a, &p) * legendre_symbol(&b, &p)
+        );
+    }
+    assert_eq!(legendre_symbol(&BigUint::zero(), &p), 0);
+    assert_eq!(legendre_symbol(&p, &p), 0);
+    // the bits of the PRF on consecutive inputs should look random
+    let key = rng.gen_biguint_below(&p);
+    let bits: Vec<bool> = (0..PRF_BITS)
+        .map(|x| legendre_prf(&key, &BigUint::from(x), &p))
+        .collect();
+    let (monobit, runs, serial) = (monobit_z(&bits), runs_z(&bits), serial_chi2(&bits));
+    println!(
+        "{} bits of the Legendre PRF: monobit z = {:.2}, runs z = {:.2}, serial chi2 = {:.2} (3 degrees of freedom)",
+        PRF_BITS, monobit, runs, serial
+    );
+    // beyond 4 standard deviations, or a chi-square of 3 degrees of freedom above 20, is very unlikely (< 10^-3)
+    assert!(monobit.abs() < 4.0 && runs.abs() < 4.0 && serial < 20.0);
+
+    // Q19: write `recover_legendre_key` which finds the key of the oracle on the prime 2^31 - 1
+    // with about sqrt(p) queries and Legendre symbols: a meet in the middle
+    // - query a window of consecutive outputs L_K(x), ..., L_K(x + 63) for many x, stored by window
+    // - compute windows L_0(y), ..., L_0(y + 63) for random y until one of them was stored: then K + x = y
+    runner.question("Q19");
+    let oracle = LegendreOracle::new(&mut rng);
+    let start = Instant::now();
+    let key = recover_legendre_key(&oracle, &mut rng);
+    assert!(oracle.is_key(key));
+    println!(
+        "Key of the Legendre PRF on 2^31 - 1 recovered in {:.2?} with {} queries\n",
+        start.elapsed(),
+        oracle.queries()
+    );
+
+    // Q20: how do the queries and the time scale with p? Compare with an exhaustive search of the key
+    // How large must p be for 128-bit security against this attack, and does the PRF become slower for it?
+    runner.question("Q20");
+
+    // Take a look at modules `knapsack` and `lll`
+    // Q21: write `knapsack_decrypt` which decrypts with the private key of Merkle-Hellman
+    runner.question("Q21");
+    let (private_key, public_key) = knapsack_keygen(&mut rng);
+    for _ in 0..100 {
+        let message: Vec<bool> = (0..KNAPSACK_BITS).map(|_| rng.gen()).collect();
+        let c = knapsack_encrypt(&public_key, &message);
+        assert_eq!(knapsack_decrypt(&private_key, &c), message);
+    }
+    let ones = vec![true; KNAPSACK_BITS];
+    assert_eq!(
+        knapsack_decrypt(&private_key, &knapsack_encrypt(&public_key, &ones)),
+        ones
+    );
+
+    // Q22: write `break_knapsack` which recovers the message from the public key and the ciphertext alone
+    // Reduce with `lll` the lattice of the rows (2 e_i, N b_i) for each i and (1, ..., 1, N c), with N > sqrt(n):
+    // sum m_i (2 e_i, N b_i) - (1, ..., 1, N c) = (2 m_1 - 1, ..., 2 m_n - 1, 0) is a vector of norm sqrt(n),
+    // the shortest of the lattice when the density is low
+    runner.question("Q22");
+    println!(
+        "Density of the knapsack: {:.2} (< 0.94 for the lattice attack)",
+        density(&public_key)
+    );
+    let start = Instant::now();
+    for _ in 0..10 {
+        let message: Vec<bool> = (0..KNAPSACK_BITS).map(|_| rng.gen()).collect();
+        let c = knapsack_encrypt(&public_key, &message);
+        assert_eq!(break_knapsack(&public_key, &c), Some(message));
+    }
+    println!(
+        "10 knapsack ciphertexts of {} bits decrypted without the key in {:.2?}\n",
+        KNAPSACK_BITS,
+        start.elapsed()
+    );
+
+    // Q23: why does the attack need a low density? Which densities does Merkle-Hellman reach with a
+    // superincreasing sequence, and why did adding slack to the private weights make the break easier?
+
+    runner.question("Q23");
+
+    // Back to the kangaroo: a wallet drew its key in a group of 56-bit order from only 40 bits of entropy
+    // Q24: the public key below hides x in [lower, lower + 2^40): recover it with your `kangaroo` of Q7
+    // within a budget of group operations which rules out anything but a walk of about 2 sqrt(2^40) steps
+    runner.question("Q24");
+    let (instance, lower) = hidden_interval_instance(&mut rng);
+    let operations = group_operations();
+    let start = Instant::now();
+    let x = kangaroo(&instance, lower, 1 << HIDDEN_INTERVAL_BITS);
+    let operations = group_operations() - operations;
+    assert!(instance.check(x), "wrong secret {}", x);
+    assert!(
+        operations <= KANGAROO_BUDGET,
+        "{} group operations, over the budget of {}",
+        operations,
+        KANGAROO_BUDGET
+    );
+    println!(
+        "Secret found in [{}, {} + 2^{}) with {} group operations ({:.1} sqrt(2^{})) in {:.2?}",
+        lower,
+        lower,
+        HIDDEN_INTERVAL_BITS,
+        operations,
+        operations as f64 / (1u64 << (HIDDEN_INTERVAL_BITS / 2)) as f64,
+        HIDDEN_INTERVAL_BITS,
+        start.elapsed()
+    );
+    // Q25: how many distinguished points did your kangaroos store? Which parameter trades them against the
+    // steps walked after the collision? With k kangaroos on k machines, how much faster is the search, and
+    // why must the tame and wild ones jump with the same distances?
+    runner.question("Q25");
+
+    // Back to lattices: take a look at module `hnp`
+    // Q26: a signer cleared the top 8 bits of its ECDSA nonces. Write `hnp_recover_key`, which builds the lattice
+    // of the hidden number problem from its signatures, reduces it with `lll_floating` and returns the private key
+    runner.question("Q26");
+    let (public_key, signatures) = biased_signatures(HNP_SIGNATURES, &mut rng);
+    let start = Instant::now();
+    let secret_key = hnp_recover_key(&public_key, &signatures).expect("no key found");
+    println!(
+        "Private key recovered from {} signatures with {}-bit biased nonces in {:.2?}",
+        signatures.len(),
+        NONCE_BIAS_BITS,
+        start.elapsed()
+    );
+    // the recovered key signs for the public key
+    let z = Fr::rand(&mut rng);
+    let signed = ecdsa_sign_with_nonce(&secret_key, &z, &Fr::rand(&mut rng)).unwrap();
+    assert!(ecdsa_verify(&public_key, &signed));
+    // and a key found from fewer signatures than the lattice needs would not
+    assert_eq!(hnp_recover_key(&public_key, &signatures[..2]), None);
+    // Q27: how many signatures does the attack need with 8 biased bits, and with 4? Why does a bias of a single
+    // bit defeat LLL in practice, and what do the attacks on 1 bit or less use instead? RFC 6979 nonces are
+    // uniform: which implementation mistakes still give them a bias?
+    runner.question("Q27");
+
+    // Primality: take a look at module `primality`
+    // Q28: write `fermat_test` and `miller_rabin_test`, which tell whether the odd n > 2 passes the test for the
+    // base a in [2, n - 2]
+    runner.question("Q28");
+    for p in (3..200u64).filter(|p| is_prime(*p)) {
+        for a in 2..p - 1 {
+            assert!(fermat_test(&BigUint::from(p), &BigUint::from(a)));
+            assert!(miller_rabin_test(&BigUint::from(p), &BigUint::from(a)));
+        }
+    }
+    let two = BigUint::from(2u32);
+    assert!(!fermat_test(&BigUint::from(15u32), &two));
+    assert!(!miller_rabin_test(&BigUint::from(15u32), &two));
+    // 2^10 = 1 mod 341 = 11 * 31, but 2^85 = 32 mod 341 and 32^2 = 1
+    assert!(fermat_test(&BigUint::from(341u32), &two));
+    assert!(!miller_rabin_test(&BigUint::from(341u32), &two));
+    // 2047 = 23 * 89 is a strong pseudoprime to base 2, not to base 3
+    assert!(miller_rabin_test(&BigUint::from(2047u32), &two));
+    assert!(!miller_rabin_test(
+        &BigUint::from(2047u32),
+        &BigUint::from(3u32)
+    ));
+
+    // Q29: write `find_carmichael`, which returns the Carmichael numbers below the limit, in order
+    runner.question("Q29");
+    let carmichael = find_carmichael(CARMICHAEL_LIMIT);
+    assert_eq!(carmichael, [561, 1105, 1729, 2465, 2821, 6601, 8911]);
+    for n in carmichael {
+        let big_n = BigUint::from(n);
+        // every base coprime to n fools Fermat, base 2 is enough for Miller-Rabin
+        assert!((2..n - 1)
+            .filter(|a| a.gcd(&n) == 1)
+            .all(|a| fermat_test(&big_n, &BigUint::from(a))));
+        assert!(!miller_rabin_test(&big_n, &two));
+    }
+    println!(
+        "Carmichael numbers below {}: {:?}",
+        CARMICHAEL_LIMIT,
+        find_carmichael(CARMICHAEL_LIMIT)
+    );
+
+    // Q30: write `is_probable_prime` for any n, with `MILLER_RABIN_ROUNDS` rounds of Miller-Rabin on random bases
+    runner.question("Q30");
+    for n in 0..5000u64 {
+        assert_eq!(
+            is_probable_prime(&BigUint::from(n), &mut rng),
+            is_prime(n),
+            "{}",
+            n
+        );
+    }
+    let start = Instant::now();
+    for (n, expected) in pseudoprimes() {
+        assert_eq!(is_probable_prime(&n, &mut rng), expected, "{}", n);
+    }
+    println!(
+        "{} pseudoprimes and primes classified in {:.2?}",
+        PSEUDOPRIMES.len(),
+        start.elapsed()
+    );
+
+    // Diffie-Hellman parameters: take a look at module `dh_params`
+    // Q31: write `safe_prime`, which returns a prime p of exactly `bits` bits with (p - 1) / 2 prime, and the
+    // number of candidates q it drew, with your `is_probable_prime`
+    runner.question("Q31");
+    for bits in DH_BITS {
+        let start = Instant::now();
+        let (p, candidates) = safe_prime(bits, &mut rng);
+        assert_eq!(p.bits(), bits);
+        assert!(is_probable_prime(&p, &mut rng));
+        assert!(is_probable_prime(&(&p >> 1), &mut rng));
+        println!(
+            "{}-bit safe prime found after {} candidates ({:.0} expected) in {:.2?}",
+            bits,
+            candidates,
+            (bits as f64 * 2f64.ln()).powi(2) / 4.0,
+            start.elapsed()
+        );
+    }
+
+    // Q32: write `dh_params`, which returns a safe prime of `bits` bits, with q and a generator g of the
+    // subgroup of order q, and the number of candidates drawn
+    runner.question("Q32");
+    for bits in DH_BITS {
+        let (params, _) = dh_params(bits, &mut rng);
+        let DhParams { p, q, g } = &params;
+        assert_eq!(p.bits(), bits);
+        assert_eq!(p, &(q * 2u32 + 1u32));
+        assert!(is_probable_prime(p, &mut rng) && is_probable_prime(q, &mut rng));
+        assert!(!g.is_one() && g < p);
+        assert!(g.modpow(q, p).is_one(), "g is not of order q");
+        // a key exchange
+        let a = rng.gen_biguint_range(&BigUint::one(), q);
+        let b = rng.gen_biguint_range(&BigUint::one(), q);
+        let (big_a, big_b) = (g.modpow(&a, p), g.modpow(&b, p));
+        assert_eq!(big_b.modpow(&a, p), big_a.modpow(&b, p));
+    }
+    // Q33: why does TLS 1.3 use the fixed groups of RFC 7919 rather than parameters generated by each server?
+    // What did the Logjam attack precompute, and why was it worth it for 512-bit primes? With a prime p whose
+    // p - 1 is smooth instead of 2q, what would Pohlig-Hellman give an eavesdropper?
+    runner.question("Q33");
+
+    // Before elliptic curves: take a look at module `dsa`
+    // Q34: write `schnorr_group`, which returns primes p of `p_bits` bits and q of `q_bits` bits with q | p - 1,
+    // and g of order q
+    runner.question("Q34");
+    let mut groups = vec![];
+    for (p_bits, q_bits) in SCHNORR_GROUP_SIZES {
+        let start = Instant::now();
+        let group = schnorr_group(p_bits, q_bits, &mut rng);
+        let SchnorrGroup { p, q, g } = &group;
+        assert_eq!((p.bits(), q.bits()), (p_bits, q_bits));
+        assert!(is_probable_prime(p, &mut rng) && is_probable_prime(q, &mut rng));
+        assert!((p - 1u32).is_multiple_of(q));
+        assert!(!g.is_one() && g < p && g.modpow(q, p).is_one());
+        println!(
+            "Schnorr group with a {}-bit p and a {}-bit q generated in {:.2?}",
+            p_bits,
+            q_bits,
+            start.elapsed()
+        );
+        groups.push(group);
+    }
+
+    // Q35: write `pedersen_commit`, g^m h^r mod p with h = `commitment_base(group)`
+    runner.question("Q35");
+    for group in &groups {
+        let h = commitment_base(group);
+        let q = &group.q;
+        let (m1, r1) = (rng.gen_biguint_below(q), rng.gen_biguint_below(q));
+        let (m2, r2) = (rng.gen_biguint_below(q), rng.gen_biguint_below(q));
+        let c1 = pedersen_commit(group, &h, &m1, &r1);
+        let c2 = pedersen_commit(group, &h, &m2, &r2);
+        assert!(c1 < group.p && c1.modpow(q, &group.p).is_one());
+        assert_ne!(c1, pedersen_commit(group, &h, &(&m1 + 1u32), &r1));
+        // homomorphic: the product commits to the sums, mod q
+        assert_eq!(
+            &c1 * &c2 % &group.p,
+            pedersen_commit(group, &h, &((&m1 + &m2) % q), &((&r1 + &r2) % q))
+        );
+        // the exponents only matter mod q
+        assert_eq!(c1, pedersen_commit(group, &h, &(&m1 + q), &r1));
+    }
+
+    // Q36: write `dsa_sign` and `dsa_verify`
+    runner.question("Q36");
+    let group = &groups[1];
+    let x = rng.gen_biguint_range(&BigUint::one(), &group.q);
+    let y = group.g.modpow(&x, &group.p);
+    let z = rng.gen_biguint(group.q.bits());
+    let start = Instant::now();
+    let signature = dsa_sign(group, &x, &z, &mut rng);
+    let dsa_time = start.elapsed();
+    assert!(dsa_verify(group, &y, &z, &signature));
+    assert!(!dsa_verify(group, &y, &(&z + 1u32), &signature));
+    let other_key = group.g.modpow(&(&x + 1u32), &group.p);
+    assert!(!dsa_verify(group, &other_key, &z, &signature));
+    let s_plus_q = DsaSignature {
+        r: signature.r.clone(),
+        s: &signature.s + &group.q,
+    };
+    assert!(!dsa_verify(group, &y, &z, &s_plus_q));
+    let zero_r = DsaSignature {
+        r: BigUint::zero(),
+        s: signature.s.clone(),
+    };
+    assert!(!dsa_verify(group, &y, &z, &zero_r));
+    // two signatures of the same message differ, with random nonces
+    assert_ne!(dsa_sign(group, &x, &z, &mut rng), signature);
+    // against ECDSA on secp256k1, for the same 128 bits of security
+    let secret_key = Fr::rand(&mut rng);
+    let start = Instant::now();
+    ecdsa_sign_with_nonce(&secret_key, &Fr::rand(&mut rng), &Fr::rand(&mut rng)).unwrap();
+    let ecdsa_time = start.elapsed();
+    println!(
+        "DSA: {}-byte public key, {}-byte signature, signed in {:.2?}",
+        group.p.bits().div_ceil(8),
+        2 * group.q.bits().div_ceil(8),
+        dsa_time
+    );
+    println!(
+        "ECDSA on secp256k1: 33-byte public key, 64-byte signature, signed in {:.2?}",
+        ecdsa_time
+    );
+    // Q37: why does DSA need a 2048-bit p for the security secp256k1 reaches with 256 bits, while its q and
+    // its signatures have the same size? What would a reused k reveal, and which ECDSA attack of this chapter
+    // carries over to DSA unchanged?
+    runner.question("Q37");
+
+    // Anomalous curves: take a look at module `smart`
+    let p: BigUint = ANOMALOUS_P.parse().unwrap();
+    let curve = anomalous_curve();
+    let g = anomalous_generator();
+    assert!(curve.is_on_curve(&g));
+    assert_eq!(curve.mul(&g, &p), BigPoint::Infinity);
+    // Q38: write `hensel_lift`, which lifts a point of the curve to the lifted curve modulo p^2, keeping x
+    runner.question("Q38");
+    let lifted = CurveMod {
+        modulus: &p * &p,
+        a: rng.gen_biguint_below(&p) * &p,
+        b: BigUint::from(ANOMALOUS_B) + rng.gen_biguint_below(&p) * &p,
+    };
+    assert_eq!(
+        hensel_lift(&lifted, &p, &BigPoint::Infinity),
+        BigPoint::Infinity
+    );
+    for _ in 0..10 {
+        let point = curve.mul(&g, &rng.gen_biguint_below(&p));
+        let lift = hensel_lift(&lifted, &p, &point);
+        assert!(lifted.is_on_curve(&lift));
+        match (&point, &lift) {
+            (BigPoint::Affine(x, y), BigPoint::Affine(lx, ly)) => {
+                assert_eq!(lx, x);
+                assert_eq!(&(ly % &p), y);
+            }
+            _ => assert_eq!(point, lift),
+        }
+    }
+    // Q39: write `p_adic_log`, t(pP') / p mod p for the lift P' of P, which is a group morphism from E(F_p) to F_p
+    runner.question("Q39");
+    let canonical = CurveMod {
+        modulus: &p * &p,
+        a: BigUint::zero(),
+        b: BigUint::from(ANOMALOUS_B),
+    };
+    assert!(p_adic_log(&canonical, &p, &g).is_zero());
+    let log_g = p_adic_log(&lifted, &p, &g);
+    assert!(!log_g.is_zero() && log_g < p);
+    for _ in 0..5 {
+        let (i, j) = (rng.gen_biguint_below(&p), rng.gen_biguint_below(&p));
+        let (a, b) = (curve.mul(&g, &i), curve.mul(&g, &j));
+        let (log_a, log_b) = (p_adic_log(&lifted, &p, &a), p_adic_log(&lifted, &p, &b));
+        assert_eq!(log_a, &i * &log_g % &p);
+        assert_eq!(
+            p_adic_log(&lifted, &p, &curve.add(&a, &b)),
+            (log_a + log_b) % &p
+        );
+    }
+    // Q40: write `smart_attack`, which finds the discrete log of the target with a random lift of the curve
+    runner.question("Q40");
+    for _ in 0..5 {
+        let instance = AnomalousInstance::random(&mut rng);
+        let start = Instant::now();
+        let k = smart_attack(&instance, &mut rng);
+        let elapsed = start.elapsed();
+        assert!(instance.is_secret(&k));
+        println!(
+            "discrete log on a {}-bit anomalous curve in {:.2?}, baby-step giant-step would take 2^{} steps",
+            p.bits(),
+            elapsed,
+            p.bits() / 2
+        );
+    }
+    // Q41: the attack is a couple of scalar multiplications modulo p^2: which condition on #E do the curve
+    // standards impose since 1999, and what do the curves of module `toy_curve`, with p + 1 points, fall to
+    // instead? Why does the canonical lift give a zero morphism?
+    runner.question("Q41");
+
+    // The MOV attack: take a look at module `mov`
+    // Q42: write `cube_root_of_unity`, (-1 + sqrt(-3)) / 2 in F_{p^2}, and `distortion_map`, (x, y) -> (zeta x, y)
+    runner.question("Q42");
+    let (instance, _) = DlpInstance::random(32, &mut rng);
+    let (curve, g, q) = (instance.curve, instance.generator, instance.order);
+    let field = Fp2Field { p: curve.p };
+    let zeta = cube_root_of_unity(curve.p);
+    assert_ne!(zeta, field.one());
+    assert_ne!(zeta.c1, 0);
+    assert_eq!(field.pow(zeta, 3), field.one());
+    for _ in 0..10 {
+        let point = curve.mul(&g, rng.gen_range(1..q));
+        let image = distortion_map(&curve, &point);
+        assert!(is_on_curve_fp2(&curve, &image));
+        assert!(matches!(image, Fp2Point::Affine(x, _) if x.c1 != 0));
+    }
+    assert_eq!(
+        distortion_map(&curve, &ToyPoint::Infinity),
+        Fp2Point::Infinity
+    );
+    let e_gg = tate_pairing(&curve, q, &g, &distortion_map(&curve, &g));
+    assert_ne!(e_gg, field.one());
+    assert_eq!(field.pow(e_gg, q as u128), field.one());
+    for _ in 0..5 {
+        let (a, b) = (rng.gen_range(1..q), rng.gen_range(1..q));
+        let (a_g, b_g) = (curve.mul(&g, a), curve.mul(&g, b));
+        assert_eq!(
+            tate_pairing(&curve, q, &a_g, &distortion_map(&curve, &b_g)),
+            field.pow(e_gg, a as u128 * b as u128)
+        );
+    }
+    // Q43: write `fp2_discrete_log`, baby-step giant-step in the subgroup of order q of F_{p^2}*
+    runner.question("Q43");
+    for _ in 0..5 {
+        let x = rng.gen_range(0..q);
+        assert_eq!(
+            fp2_discrete_log(&field, e_gg, field.pow(e_gg, x as u128), q),
+            x
+        );
+    }
+    // Q44: write `mov_attack`, which moves the instance to F_{p^2} with the pairing and solves it there
+    runner.question("Q44");
+    for bits in [24, 32, 36] {
+        let (instance, x) = DlpInstance::random(bits, &mut rng);
+        let start = Instant::now();
+        assert_eq!(mov_attack(&instance), x);
+        println!(
+            "{}-bit discrete log moved to F_{{p^2}} and solved in {:.2?}",
+            bits,
+            start.elapsed()
+        );
+    }
+    // Q45: baby-step giant-step costs sqrt(q) on both sides: what does the attacker gain by moving to F_{p^2}?
+    // The embedding degree k is the smallest k such that q divides p^k - 1: what is it for secp256k1, and for
+    // the BN and BLS curves of the pairing-based protocols? How large must p^k be for 128-bit security?
+    runner.question("Q45");
+
+    println!("Good job!");
+    runner.finish();
+}
+
+fn hensel_lift(lifted: &CurveMod, p: &BigUint, point: &BigPoint) -> BigPoint {
+    // SOLUTION-BEGIN
+    let BigPoint::Affine(x, y) = point else {
+        return BigPoint::Infinity;
+    };
+    let n = &lifted.modulus;
+    // (y + tp)^2 = y^2 + 2ytp mod p^2: t corrects the error of y^2, a multiple of p
+    let rhs = (x * x * x + &lifted.a * x + &lifted.b) % n;
+    let error = (rhs + n - y * y % n) % n / p;
+    let t = error * (2u32 * y).modinv(p).expect("no point of order 2") % p;
+    BigPoint::Affine(x.clone(), (y + t * p) % n)
+    // SOLUTION-END
+}
+
+fn p_adic_log(lifted: &CurveMod, p: &BigUint, point: &BigPoint) -> BigUint {
+    // SOLUTION-BEGIN
+    let lift = hensel_lift(lifted, p, point);
+    let BigPoint::Affine(x, y) = &lift else {
+        return BigUint::zero();
+    };
+    let BigPoint::Affine(x1, y1) = lifted.mul(&lift, &(p - 1u32)) else {
+        unreachable!("(p - 1)P has order p");
+    };
+    let n = &lifted.modulus;
+    // t(pP') = 1 / lambda, a multiple of p
+    let t = (x + n - x1) % n * ((y + n - y1) % n).modinv(n).unwrap() % n;
+    t / p
+    // SOLUTION-END
+}
+
+fn smart_attack<R: Rng>(instance: &AnomalousInstance, rng: &mut R) -> BigUint {
+    // SOLUTION-BEGIN
+    let p = &instance.curve.modulus;
+    let lifted = CurveMod {
+        modulus: p * p,
+        a: &instance.curve.a + rng.gen_biguint_range(&BigUint::one(), p) * p,
+        b: instance.curve.b.clone(),
+    };
+    let log_generator = p_adic_log(&lifted, p, &instance.generator);
+    let log_target = p_adic_log(&lifted, p, &instance.target);
+    log_target * log_generator.modinv(p).unwrap() % p
+    // SOLUTION-END
+}
+
+fn cube_root_of_unity(p: u64) -> Fp2 {
+    // SOLUTION-BEGIN
+    // p = 3 mod 4: the square root of 3, a square since p = 11 mod 12, is 3^((p + 1) / 4), and sqrt(-3) = sqrt(3) i
+    let sqrt_3 = pow_mod(3, (p + 1) / 4, p);
+    let half = p.div_ceil(2); // 1 / 2
+    Fp2 {
+        c0: mul_mod(p - 1, half, p),
+        c1: mul_mod(sqrt_3, half, p),
+    }
+    // SOLUTION-END
+}
+
+fn distortion_map(curve: &ToyCurve, point: &ToyPoint) -> Fp2Point {
+    // SOLUTION-BEGIN
+    let field = Fp2Field { p: curve.p };
+    match *point {
+        ToyPoint::Infinity => Fp2Point::Infinity,
+        ToyPoint::Affine(x, y) => Fp2Point::Affine(
+            field.mul(cube_root_of_unity(curve.p), field.embed(x)),
+            field.embed(y),
+        ),
+    }
+    // SOLUTION-END
+}
+
+fn fp2_discrete_log(field: &Fp2Field, base: Fp2, target: Fp2, order: u64) -> u64 {
+    // SOLUTION-BEGIN
+    let m = (order as f64).sqrt().ceil() as u64;
+    let mut baby_steps = HashMap::with_capacity(m as usize);
+    let mut power = field.one();
+    for j in 0..m {
+        baby_steps.entry(power).or_insert(j);
+        power = field.mul(power, base);
+    }
+    // base^-m = base^(q - m)
+    let giant_step = field.pow(base, (order - m % order) as u128);
+    let mut power = target;
+    for i in 0..=m {
+        if let Some(j) = baby_steps.get(&power) {
+            return (i * m + j) % order;
+        }
+        power = field.mul(power, giant_step);
+    }
+    panic!("no discrete log")
+    // SOLUTION-END
+}
+
+fn mov_attack(instance: &DlpInstance) -> u64 {
+    // SOLUTION-BEGIN
+    let (curve, q) = (&instance.curve, instance.order);
+    let field = Fp2Field { p: curve.p };
+    let distorted = distortion_map(curve, &instance.generator);
+    let base = tate_pairing(curve, q, &instance.generator, &distorted);
+    let target = tate_pairing(curve, q, &instance.target, &distorted);
+    fp2_discrete_log(&field, base, target, q)
+    // SOLUTION-END
+}
+
+struct BabyStepGiantStep;
+
+impl DlpSolver for BabyStepGiantStep {
+    fn name(&self) -> &str {
+        "baby-step giant-step"
+    }
+
+    fn solve(&self, instance: &DlpInstance) -> u64 {
+        // SOLUTION-BEGIN
+        let curve = &instance.curve;
+        let m = (instance.order as f64).sqrt().ceil() as u64;
+        let mut baby_steps = HashMap::with_capacity(m as usize);
+        let mut point = ToyPoint::Infinity;
+        for j in 0..m {
+            baby_steps.entry(point).or_insert(j);
+            point = curve.add(&point, &instance.generator);
+        }
+        let giant_step = curve.neg(&curve.mul(&instance.generator, m));
+        let mut point = instance.target;
+        for i in 0..=m {
+            if let Some(j) = baby_steps.get(&point) {
+                return (i * m + j) % instance.order;
+            }
+            point = curve.add(&point, &giant_step);
+        }
+        panic!("no discrete log")
+        // SOLUTION-END
+    }
+}
+
+struct Kangaroo;
+
+impl DlpSolver for Kangaroo {
+    fn name(&self) -> &str {
+        "kangaroo"
+    }
+
+    fn solve(&self, instance: &DlpInstance) -> u64 {
+        kangaroo(instance, 0, instance.order)
+    }
+}
+
+fn kangaroo(instance: &DlpInstance, lower: u64, width: u64) -> u64 {
+    // SOLUTION-BEGIN
+    let curve = &instance.curve;
+    let order = instance.order as u128;
+    let distances = jump_distances(width);
+    let jumps: Vec<ToyPoint> = distances
+        .iter()
+        .map(|s| curve.mul(&instance.generator, *s))
+        .collect();
+    let bits = distinguished_bits(width);
+    // distinguished point -> (travelled distance, is it from the tame kangaroo?)
+    let mut traps: HashMap<ToyPoint, (u64, bool)> = HashMap::new();
+    let start = ((lower as u128 + width as u128) % order) as u64;
+    // (position, travelled distance, steps since the last distinguished point)
+    let mut tame = (curve.mul(&instance.generator, start), 0u64, 0u64);
+    let mut wild = (instance.target, 0u64, 0u64);
+    loop {
+        for (kangaroo, is_tame) in [(&mut tame, true), (&mut wild, false)] {
+            let i = jump_index(&kangaroo.0, jumps.len());
+            kangaroo.0 = curve.add(&kangaroo.0, &jumps[i]);
+            kangaroo.1 += distances[i];
+            kangaroo.2 += 1;
+            let lost = kangaroo.2 > 32 << bits;
+            if !lost && !is_distinguished(&kangaroo.0, bits) {
+                continue;
+            }
+            match traps.get(&kangaroo.0) {
+                Some(&(distance, was_tame)) if !lost && was_tame != is_tame => {
+                    let (tame_distance, wild_distance) = if is_tame {
+                        (kangaroo.1, distance)
+                    } else {
+                        (distance, kangaroo.1)
+                    };
+                    // lower + width + d_tame = x + d_wild
+                    let x = (start as u128 + tame_distance as u128 + order
+                        - wild_distance as u128 % order)
+                        % order;
+                    return x as u64;
+                }
+                // the kangaroo runs in a cycle without meeting the other one (in a small group,
+                // or when the width is the whole group): it restarts a pseudorandom distance ahead,
+                // which depends on its travelled distance so that it changes at each lap
+                Some(_) | None if lost || traps.contains_key(&kangaroo.0) => {
+                    let restart = point_hash(&kangaroo.0).wrapping_mul(kangaroo.1) % width + 1;
+                    let ahead = curve.mul(&instance.generator, restart);
+                    kangaroo.0 = curve.add(&kangaroo.0, &ahead);
+                    kangaroo.1 += restart;
+                    kangaroo.2 = 0;
+                }
+                _ => {
+                    traps.insert(kangaroo.0, (kangaroo.1, is_tame));
+                    kangaroo.2 = 0;
+                }
+            }
+        }
+    }
+    // SOLUTION-END
+}
+
+struct ParallelRho {
+    threads: usize,
+}
+
+impl ParallelRho {
+    fn new() -> ParallelRho {
+        ParallelRho {
+            threads: thread::available_parallelism().map_or(1, |n| n.get()),
+        }
+    }
+}
+
+impl DlpSolver for ParallelRho {
+    fn name(&self) -> &str {
+        "parallel rho"
+    }
+
+    fn solve(&self, instance: &DlpInstance) -> u64 {
+        parallel_rho(instance, self.threads, &mut ChaChaRng::from_entropy())
+    }
+}
+
+fn parallel_rho<R: Rng>(instance: &DlpInstance, threads: usize, rng: &mut R) -> u64 {
+    // SOLUTION-BEGIN
+    let q = instance.order;
+    // in a tiny group everything is distinguished, the table is then a plain collision search
+    let bits = distinguished_bits(q);
+    let walk = Arc::new(RhoWalk::new(instance, rng));
+    let found = Arc::new(AtomicBool::new(false));
+    let (sender, receiver) = mpsc::channel::<WalkPoint>();
+    let workers: Vec<_> = (0..threads)
+        .map(|_| {
+            let walk = walk.clone();
+            let found = found.clone();
+            let sender = sender.clone();
+            let mut rng = ChaChaRng::from_rng(&mut *rng).unwrap();
+            thread::spawn(move || {
+                let mut w = walk.instance.random_walk_point(&mut rng);
+                let mut steps = 0u64;
+                while !found.load(Ordering::Relaxed) {
+                    w = walk.step(&w);
+                    steps += 1;
+                    // a walk stuck in a cycle without distinguished point starts over
+                    if is_distinguished(&w.point, bits) || steps > 32 << bits {
+                        if is_distinguished(&w.point, bits) && sender.send(w).is_err() {
+                            return;
+                        }
+                        w = walk.instance.random_walk_point(&mut rng);
+                        steps = 0;
+                    }
+                }
+            })
+        })
+        .collect();
+    drop(sender);
+    let mut table: HashMap<ToyPoint, WalkPoint> = HashMap::new();
+    let x = receiver
+        .iter()
+        .find_map(|w| match table.insert(w.point, w) {
+            Some(previous) => solve_collision(&previous, &w, q),
+            None => None,
+        })
+        .unwrap();
+    found.store(true, Ordering::Relaxed);
+    for worker in workers {
+        worker.join().unwrap();
+    }
+    x
+    // SOLUTION-END
+}
+
+fn negation_step(walk: &RhoWalk, w: &WalkPoint) -> WalkPoint {
+    // SOLUTION-BEGIN
+    let p = walk.instance.curve.p;
+    let canonical = |v: WalkPoint| match v.point {
+        ToyPoint::Affine(_, y) if y > p - y => walk.negate(&v),
+        _ => v,
+    };
+    let r = walk.steps.len();
+    let i = jump_index(&w.point, r);
+    let next = canonical(walk.add(w, i));
+    if jump_index(&next.point, r) != i {
+        return next;
+    }
+    canonical(walk.add(w, (i + 1) % r))
+    // SOLUTION-END
+}
+
+fn is_in_subgroup_by_cofactor(curve: &ToyCurve, h: u64, q: u64, point: &ToyPoint) -> bool {
+    // SOLUTION-BEGIN
+    let h_inverse = pow_mod(h % q, q - 2, q);
+    curve.is_on_curve(point) && curve.mul(&curve.mul(point, h_inverse), h) == *point
+    // SOLUTION-END
+}
+
+fn miller_loop(curve: &ToyCurve, t: &ToyPoint, h: u64, s: &ToyPoint) -> Option<u64> {
+    // SOLUTION-BEGIN
+    let p = curve.p;
+    let mut f = 1;
+    let mut v = *t;
+    for i in (0..63 - h.leading_zeros()).rev() {
+        f = mul_mod(mul_mod(f, f, p), line_function(curve, &v, &v, s)?, p);
+        v = curve.add(&v, &v);
+        if (h >> i) & 1 == 1 {
+            f = mul_mod(f, line_function(curve, &v, t, s)?, p);
+            v = curve.add(&v, t);
+        }
+    }
+    assert_eq!(v, ToyPoint::Infinity);
+    Some(f)
+    // SOLUTION-END
+}
+
+fn is_in_subgroup_by_pairing(
+    curve: &ToyCurve,
+    t: &ToyPoint,
+    h: u64,
+    point: &ToyPoint,
+    shifts: &[ToyPoint],
+) -> bool {
+    // SOLUTION-BEGIN
+    if !curve.is_on_curve(point) {
+        return false;
+    }
+    let p = curve.p;
+    let pairing = shifts
+        .iter()
+        .find_map(|r| {
+            // f(P + R) / f(R)
+            let numerator = miller_loop(curve, t, h, &curve.add(point, r))?;
+            let denominator = miller_loop(curve, t, h, r)?;
+            Some(pow_mod(
+                mul_mod(numerator, curve.inverse(denominator), p),
+                (p - 1) / h,
+                p,
+            ))
+        })
+        .unwrap();
+    pairing == 1
+    // SOLUTION-END
+}
+
+fn crt(residues: &[BigInt], moduli: &[BigInt]) -> BigInt {
+    // SOLUTION-BEGIN
+    let product: BigInt = moduli.iter().product();
+    let x: BigInt = residues
+        .iter()
+        .zip(moduli)
+        .map(|(r, m)| {
+            let cofactor = &product / m;
+            // the Bezout coefficient u of u M_i + v m_i = 1 is the inverse of M_i mod m_i
+            let bezout = cofactor.extended_gcd(m);
+            assert!(bezout.gcd.is_one(), "the moduli must be pairwise coprime");
+            r * &cofactor * bezout.x
+        })
+        .sum();
+    x.mod_floor(&product)
+    // SOLUTION-END
+}
+
+fn dlog_prime_power(g: &BigUint, h: &BigUint, p: &BigUint, q: u64, e: u32) -> BigUint {
+    // SOLUTION-BEGIN
+    let subgroup_order = BigUint::from(q).pow(e);
+    let cofactor = (p - 1u32) / &subgroup_order;
+    let g_i = g.modpow(&cofactor, p);
+    let h_i = h.modpow(&cofactor, p);
+    let mut power = BigUint::one();
+    let mut x = BigUint::zero();
+    while power != h_i {
+        power = power * &g_i % p;
+        x += 1u32;
+        assert!(x < subgroup_order, "no discrete log");
+    }
+    x
+    // SOLUTION-END
+}
+
+fn pohlig_hellman(g: &BigUint, h: &BigUint, p: &BigUint, factors: &[(u64, u32)]) -> BigUint {
+    // SOLUTION-BEGIN
+    let (residues, moduli): (Vec<BigInt>, Vec<BigInt>) = factors
+        .iter()
+        .map(|&(q, e)| {
+            (
+                BigInt::from(dlog_prime_power(g, h, p, q, e)),
+                BigInt::from(BigUint::from(q).pow(e)),
+            )
+        })
+        .unzip();
+    crt(&residues, &moduli).to_biguint().unwrap()
+    // SOLUTION-END
+}
+
+fn double_and_add_steps(curve: &ToyCurve, point: &ToyPoint, k: u64) -> Vec<(Step, ToyPoint)> {
+    // SOLUTION-BEGIN
+    assert!(k > 0);
+    let mut steps = vec![];
+    let mut current = *point;
+    for i in (0..63 - k.leading_zeros()).rev() {
+        current = curve.add(&current, &current);
+        steps.push((Step::Double, current));
+        if k >> i & 1 == 1 {
+            current = curve.add(&current, point);
+            steps.push((Step::Add, current));
+        }
+    }
+    steps
+    // SOLUTION-END
+}
+
+fn legendre_symbol(a: &BigUint, p: &BigUint) -> i8 {
+    // SOLUTION-BEGIN
+    // Euler's criterion: a^((p - 1) / 2) is 1 for squares, -1 otherwise
+    let a = a % p;
+    if a.is_zero() {
+        return 0;
+    }
+    match a.modpow(&((p - 1u32) >> 1), p).is_one() {
+        true => 1,
+        false => -1,
+    }
+    // SOLUTION-END
+}
+
+fn recover_legendre_key<R: Rng>(oracle: &LegendreOracle, rng: &mut R) -> u64 {
+    // SOLUTION-BEGIN
+    const WINDOW: u64 = 64;
+    let window = |prf: &dyn Fn(u64) -> bool, start: u64| {
+        (0..WINDOW).fold(0u64, |bits, i| bits << 1 | prf(start + i) as u64)
+    };
+    // consecutive queries: the windows at x = 0, 1, ... share all but one bit
+    let queried = 1u64 << 17;
+    let outputs: Vec<bool> = (0..queried + WINDOW).map(|x| oracle.query(x)).collect();
+    let mut windows: HashMap<u64, u64> = HashMap::new();
+    let mut bits = window(&|x| outputs[x as usize], 0);
+    for x in 0..queried {
+        windows.insert(bits, x);
+        bits = bits << 1 | outputs[(x + WINDOW) as usize] as u64;
+    }
+    // about p / 2^17 random windows of L_0 until K + x = y, each candidate checked on fresh queries
+    loop {
+        let y = rng.gen_range(0..TINY_PRIME);
+        let Some(x) = windows.get(&window(&|i| legendre_prf_tiny(0, i), y)) else {
+            continue;
+        };
+        let key = (y + TINY_PRIME - x) % TINY_PRIME;
+        if (0..WINDOW).all(|i| {
+            oracle.query(queried + WINDOW + i) == legendre_prf_tiny(key, queried + WINDOW + i)
+        }) {
+            return key;
+        }
+    }
+    // SOLUTION-END
+}
+
+fn knapsack_decrypt(private_key: &KnapsackPrivateKey, c: &BigInt) -> Vec<bool> {
+    // SOLUTION-BEGIN
+    let q = &private_key.modulus;
+    let inverse = private_key.multiplier.modinv(q).unwrap();
+    let mut rest = c * inverse % q;
+    let mut message = vec![false; private_key.weights.len()];
+    // the largest weight is needed exactly when the rest reaches it: the others sum to less
+    for (bit, w) in message.iter_mut().zip(&private_key.weights).rev() {
+        if rest >= *w {
+            rest -= w;
+            *bit = true;
+        }
+    }
+    message
+    // SOLUTION-END
+}
+
+fn break_knapsack(public_key: &KnapsackPublicKey, c: &BigInt) -> Option<Vec<bool>> {
+    // SOLUTION-BEGIN
+    let n = public_key.weights.len();
+    let scale = BigInt::from(n);
+    let mut basis: Vec<Vec<BigInt>> = public_key
+        .weights
+        .iter()
+        .enumerate()
+        .map(|(i, b)| {
+            let mut row = vec![BigInt::zero(); n + 1];
+            row[i] = BigInt::from(2);
+            row[n] = &scale * b;
+            row
+        })
+        .collect();
+    let mut last = vec![BigInt::one(); n + 1];
+    last[n] = &scale * c;
+    basis.push(last);
+    // the short vector, or its opposite, with entries in {-1, 1} and a zero last entry
+    lll(&basis).into_iter().find_map(|v| {
+        if !v[n].is_zero() || !v[..n].iter().all(|x| x.magnitude().is_one()) {
+            return None;
+        }
+        [BigInt::one(), -BigInt::one()]
+            .into_iter()
+            .find_map(|sign| {
+                let message: Vec<bool> =
+                    v[..n].iter().map(|x| x * &sign == BigInt::one()).collect();
+                (knapsack_encrypt(public_key, &message) == *c).then_some(message)
+            })
+    })
+    // SOLUTION-END
+}
+
+fn hnp_recover_key(public_key: &Affine, signatures: &[Signed]) -> Option<Fr> {
+    // SOLUTION-BEGIN
+    let (first, others) = signatures.split_first()?;
+    let m = signatures.len();
+    let n = scalar_order();
+    let half_bound = (&n >> NONCE_BIAS_BITS) / 2;
+    let half = from_bigint(&half_bound);
+    // k_i = t_i x + u_i, then k_i' = k_i - B / 2 = t_i x + u_i - B / 2
+    let equation = |signed: &Signed| {
+        let w = signed.s.inverse().unwrap();
+        (signed.r * w, signed.z * w - half)
+    };
+    // x = (k_1' - u_1') / t_1, so k_i' = (t_i / t_1) k_1' + u_i' - (t_i / t_1) u_1'
+    let (t1, u1) = equation(first);
+    let t1_inverse = t1.inverse()?;
+    let (a, b): (Vec<Fr>, Vec<Fr>) = others
+        .iter()
+        .map(|signed| {
+            let (t, u) = equation(signed);
+            let a = t * t1_inverse;
+            (a, u - a * u1)
+        })
+        .unzip();
+    // the rows with a and b first: after n e_i, their Gram-Schmidt vectors would be tiny next to the rows
+    // themselves, too much cancellation for the floating point of `lll_floating`
+    let mut a_row: Vec<BigInt> = a.iter().map(to_bigint).collect();
+    a_row.extend([BigInt::one(), BigInt::zero()]);
+    let mut b_row: Vec<BigInt> = b.iter().map(to_bigint).collect();
+    b_row.extend([BigInt::zero(), half_bound.clone()]);
+    let mut basis = vec![a_row, b_row];
+    basis.extend((0..m - 1).map(|i| {
+        let mut row = vec![BigInt::zero(); m + 1];
+        row[i] = n.clone();
+        row
+    }));
+    // the short vector (k_2', ..., k_m', k_1', B / 2), or its opposite
+    lll_floating(&basis).into_iter().find_map(|v| {
+        let k1 = match &v[m] {
+            last if *last == half_bound => &v[m - 1] + &half_bound,
+            last if *last == -&half_bound => -&v[m - 1] + &half_bound,
+            _ => return None,
+        };
+        let x = (first.s * from_bigint(&k1) - first.z) * first.r.inverse()?;
+        (Affine::generator().mul(x).into_affine() == *public_key).then_some(x)
+    })
+    // SOLUTION-END
+}
+
+fn fermat_test(n: &BigUint, a: &BigUint) -> bool {
+    // SOLUTION-BEGIN
+    a.modpow(&(n - 1u32), n).is_one()
+    // SOLUTION-END
+}
+
+fn miller_rabin_test(n: &BigUint, a: &BigUint) -> bool {
+    // SOLUTION-BEGIN
+    let minus_one = n - 1u32;
+    let s = minus_one.trailing_zeros().unwrap();
+    let d = &minus_one >> s;
+    let mut x = a.modpow(&d, n);
+    if x.is_one() || x == minus_one {
+        return true;
+    }
+    for _ in 1..s {
+        x = &x * &x % n;
+        if x == minus_one {
+            return true;
+        }
+    }
+    false
+    // SOLUTION-END
+}
+
+fn find_carmichael(limit: u64) -> Vec<u64> {
+    // SOLUTION-BEGIN
+    // Korselt's criterion: n is squarefree, composite, and p - 1 divides n - 1 for each prime p dividing n
+    let is_carmichael = |n: u64| {
+        let (mut m, mut p, mut factors) = (n, 2, 0);
+        while p * p <= m {
+            if m.is_multiple_of(p) {
+                m /= p;
+                if m.is_multiple_of(p) || !(n - 1).is_multiple_of(p - 1) {
+                    return false;
+                }
+                factors += 1;
+            }
+            p += 1;
+        }
+        // the last prime factor
+        factors > 0 && m > 1 && (n - 1).is_multiple_of(m - 1)
+    };
+    (3..limit)
+        .filter(|n| n % 2 == 1 && is_carmichael(*n))
+        .collect()
+    // SOLUTION-END
+}
+
+fn is_probable_prime<R: Rng>(n: &BigUint, rng: &mut R) -> bool {
+    // SOLUTION-BEGIN
+    if n < &BigUint::from(4u32) {
+        return n > &BigUint::one();
+    }
+    if n.is_even() {
+        return false;
+    }
+    let upper = n - 1u32;
+    (0..MILLER_RABIN_ROUNDS)
+        .all(|_| miller_rabin_test(n, &rng.gen_biguint_range(&BigUint::from(2u32), &upper)))
+    // SOLUTION-END
+}
+
+fn safe_prime<R: Rng>(bits: u64, rng: &mut R) -> (BigUint, u64) {
+    // SOLUTION-BEGIN
+    const SMALL_PRIMES: [u32; 24] = [
+        3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    ];
+    let mut candidates = 0;
+    loop {
+        candidates += 1;
+        // q of bits - 1 bits exactly, odd
+        let mut q = rng.gen_biguint(bits - 1);
+        q.set_bit(bits - 2, true);
+        q.set_bit(0, true);
+        let p = &q * 2u32 + 1u32;
+        // q and 2q + 1 are not multiples of a small prime r: q is neither 0 nor (r - 1) / 2 mod r
+        let sieved = SMALL_PRIMES.iter().any(|r| {
+            let residue = (&q % *r).to_u32_digits().first().copied().unwrap_or(0);
+            residue == 0 || residue == (r - 1) / 2
+        });
+        if !sieved && is_probable_prime(&q, rng) && is_probable_prime(&p, rng) {
+            return (p, candidates);
+        }
+    }
+    // SOLUTION-END
+}
+
+fn dh_params<R: Rng>(bits: u64, rng: &mut R) -> (DhParams, u64) {
+    // SOLUTION-BEGIN
+    let (p, candidates) = safe_prime(bits, rng);
+    let q = &p >> 1;
+    let minus_one = &p - 1u32;
+    // a square other than 1: h^2 with h not in {0, 1, p - 1}
+    let h = rng.gen_biguint_range(&BigUint::from(2u32), &minus_one);
+    let g = h.modpow(&BigUint::from(2u32), &p);
+    (DhParams { p, q, g }, candidates)
+    // SOLUTION-END
+}
+
+fn schnorr_group<R: Rng>(p_bits: u64, q_bits: u64, rng: &mut R) -> SchnorrGroup {
+    // SOLUTION-BEGIN
+    let random_prime = |bits: u64, rng: &mut R| loop {
+        let mut candidate = rng.gen_biguint(bits);
+        candidate.set_bit(bits - 1, true);
+        candidate.set_bit(0, true);
+        if is_probable_prime(&candidate, rng) {
+            return candidate;
+        }
+    };
+    let q = random_prime(q_bits, rng);
+    // p = kq + 1 with k even, p of p_bits bits
+    let p = loop {
+        let mut p = rng.gen_biguint(p_bits);
+        p.set_bit(p_bits - 1, true);
+        p -= &p % (&q * 2u32);
+        p += 1u32;
+        if p.bits() == p_bits && is_probable_prime(&p, rng) {
+            break p;
+        }
+    };
+    let k = (&p - 1u32) / &q;
+    let g = loop {
+        let g = rng
+            .gen_biguint_range(&BigUint::from(2u32), &p)
+            .modpow(&k, &p);
+        if !g.is_one() {
+            break g;
+        }
+    };
+    SchnorrGroup { p, q, g }
+    // SOLUTION-END
+}
+
+fn pedersen_commit(group: &SchnorrGroup, h: &BigUint, m: &BigUint, r: &BigUint) -> BigUint {
+    // SOLUTION-BEGIN
+    group.g.modpow(m, &group.p) * h.modpow(r, &group.p) % &group.p
+    // SOLUTION-END
+}
+
+fn dsa_sign<R: Rng>(group: &SchnorrGroup, x: &BigUint, z: &BigUint, rng: &mut R) -> DsaSignature {
+    // SOLUTION-BEGIN
+    let SchnorrGroup { p, q, g } = group;
+    loop {
+        let k = rng.gen_biguint_range(&BigUint::one(), q);
+        let r = g.modpow(&k, p) % q;
+        // k^-1 by Fermat, q is prime
+        let k_inverse = k.modpow(&(q - 2u32), q);
+        let s = k_inverse * (z + x * &r) % q;
+        if !r.is_zero() && !s.is_zero() {
+            return DsaSignature { r, s };
+        }
+    }
+    // SOLUTION-END
+}
+
+fn dsa_verify(group: &SchnorrGroup, y: &BigUint, z: &BigUint, signature: &DsaSignature) -> bool {
+    // SOLUTION-BEGIN
+    let SchnorrGroup { p, q, g } = group;
+    let DsaSignature { r, s } = signature;
+    if r.is_zero() || s.is_zero() || r >= q || s >= q {
+        return false;
+    }
+    let w = s.modpow(&(q - 2u32), q);
+    let (u1, u2) = (z * &w % q, r * &w % q);
+    &(g.modpow(&u1, p) * y.modpow(&u2, p) % p % q) == r
+    // SOLUTION-END
+}
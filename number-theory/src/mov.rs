@@ -0,0 +1,160 @@
+use crate::*;
+
+// The MOV attack (Menezes, Okamoto and Vanstone): a pairing moves a discrete log from the curve to a finite field
+// The toy curves y^2 = x^3 + b with p = 12q - 1 of module `toy_curve` have p + 1 points: q divides p + 1 = p^2 - 1
+// over p - 1, so the q-th roots of unity live in F_{p^2}, and the embedding degree is 2
+// - F_{p^2} = F_p[i] / (i^2 + 1), since -1 is not a square when p = 3 mod 4
+// - both points of the Tate pairing cannot come from E(F_p): f_{q,P}(Q) would be in F_p, and the final
+//   exponentiation by (p^2 - 1) / q, a multiple of p - 1, would send it to 1. The distortion map
+//   phi(x, y) = (zeta x, y), with zeta a cube root of unity of F_{p^2} not in F_p, maps Q out of E(F_p):
+//   e(P, Q) = f_{q,P}(phi(Q))^((p^2 - 1) / q) is then bilinear and non-degenerate on the subgroup of order q
+// - from Q = xP: e(Q, P) = e(P, P)^x, a discrete log in the subgroup of order q of F_{p^2}*
+// The finite field then has its own, subexponential, index calculus algorithms: a curve is only as strong as
+// the discrete log in F_{p^k}, k its embedding degree
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Fp2 {
+    pub c0: u64,
+    pub c1: u64,
+}
+
+// c0 + c1 i in F_p[i] / (i^2 + 1), p = 3 mod 4
+#[derive(Clone, Copy, Debug)]
+pub struct Fp2Field {
+    pub p: u64,
+}
+
+impl Fp2Field {
+    pub fn one(&self) -> Fp2 {
+        Fp2 { c0: 1, c1: 0 }
+    }
+
+    pub fn embed(&self, c0: u64) -> Fp2 {
+        Fp2 {
+            c0: c0 % self.p,
+            c1: 0,
+        }
+    }
+
+    pub fn add(&self, a: Fp2, b: Fp2) -> Fp2 {
+        Fp2 {
+            c0: (a.c0 + b.c0) % self.p,
+            c1: (a.c1 + b.c1) % self.p,
+        }
+    }
+
+    pub fn sub(&self, a: Fp2, b: Fp2) -> Fp2 {
+        Fp2 {
+            c0: (a.c0 + self.p - b.c0) % self.p,
+            c1: (a.c1 + self.p - b.c1) % self.p,
+        }
+    }
+
+    pub fn mul(&self, a: Fp2, b: Fp2) -> Fp2 {
+        let p = self.p;
+        Fp2 {
+            c0: (mul_mod(a.c0, b.c0, p) + p - mul_mod(a.c1, b.c1, p)) % p,
+            c1: (mul_mod(a.c0, b.c1, p) + mul_mod(a.c1, b.c0, p)) % p,
+        }
+    }
+
+    // the conjugate over the norm a0^2 + a1^2, which is in F_p
+    pub fn inverse(&self, a: Fp2) -> Option<Fp2> {
+        let p = self.p;
+        let norm = (mul_mod(a.c0, a.c0, p) + mul_mod(a.c1, a.c1, p)) % p;
+        if norm == 0 {
+            return None;
+        }
+        let inverse = pow_mod(norm, p - 2, p);
+        Some(Fp2 {
+            c0: mul_mod(a.c0, inverse, p),
+            c1: mul_mod((p - a.c1) % p, inverse, p),
+        })
+    }
+
+    pub fn pow(&self, mut base: Fp2, mut exponent: u128) -> Fp2 {
+        let mut result = self.one();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exponent >>= 1;
+        }
+        result
+    }
+}
+
+// a point of the curve over F_{p^2}, the image of the distortion map
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fp2Point {
+    Infinity,
+    Affine(Fp2, Fp2),
+}
+
+pub fn is_on_curve_fp2(curve: &ToyCurve, point: &Fp2Point) -> bool {
+    let field = Fp2Field { p: curve.p };
+    match *point {
+        Fp2Point::Infinity => true,
+        Fp2Point::Affine(x, y) => {
+            let rhs = field.add(
+                field.mul(field.mul(x, x), x),
+                field.add(field.mul(field.embed(curve.a), x), field.embed(curve.b)),
+            );
+            field.mul(y, y) == rhs
+        }
+    }
+}
+
+// l(S) / v(S) where l is the line through A and B (the tangent if A = B) and v the vertical line through A + B,
+// for S outside of E(F_p), where they never vanish
+fn line_over_vertical(curve: &ToyCurve, a: &ToyPoint, b: &ToyPoint, s: (Fp2, Fp2)) -> Fp2 {
+    let (field, p) = (Fp2Field { p: curve.p }, curve.p);
+    let (xs, ys) = s;
+    let (ToyPoint::Affine(xa, ya), ToyPoint::Affine(xb, yb)) = (*a, *b) else {
+        return field.one();
+    };
+    match curve.add(a, b) {
+        // A + B = O: l is the vertical line through A, and v = 1
+        ToyPoint::Infinity => field.sub(xs, field.embed(xa)),
+        ToyPoint::Affine(x_sum, _) => {
+            let lambda = if xa != xb {
+                mul_mod((yb + p - ya) % p, curve.inverse((xb + p - xa) % p), p)
+            } else {
+                let numerator = (3 * mul_mod(xa, xa, p) + curve.a) % p;
+                mul_mod(numerator, curve.inverse(2 * ya % p), p)
+            };
+            let l = field.sub(
+                field.sub(ys, field.embed(ya)),
+                field.mul(field.embed(lambda), field.sub(xs, field.embed(xa))),
+            );
+            let v = field.sub(xs, field.embed(x_sum));
+            field.mul(l, field.inverse(v).expect("S is not in E(F_p)"))
+        }
+    }
+}
+
+// the reduced Tate pairing f_{q,P}(S)^((p^2 - 1) / q), for P of order q in E(F_p) and S in E(F_{p^2}) outside
+// of E(F_p), by Miller's algorithm as in module `subgroup`
+pub fn tate_pairing(curve: &ToyCurve, order: u64, point: &ToyPoint, s: &Fp2Point) -> Fp2 {
+    let field = Fp2Field { p: curve.p };
+    let Fp2Point::Affine(xs, ys) = *s else {
+        return field.one();
+    };
+    if *point == ToyPoint::Infinity {
+        return field.one();
+    }
+    let mut f = field.one();
+    let mut t = *point;
+    for i in (0..u64::BITS - 1 - order.leading_zeros()).rev() {
+        f = field.mul(field.mul(f, f), line_over_vertical(curve, &t, &t, (xs, ys)));
+        t = curve.add(&t, &t);
+        if (order >> i) & 1 == 1 {
+            f = field.mul(f, line_over_vertical(curve, &t, point, (xs, ys)));
+            t = curve.add(&t, point);
+        }
+    }
+    assert_eq!(t, ToyPoint::Infinity, "the point must have order q");
+    let p = curve.p as u128;
+    field.pow(f, (p * p - 1) / order as u128)
+}
@@ -0,0 +1,26 @@
+use crate::*;
+
+// The Pohlig-Hellman algorithm: a discrete log in a group of order n = q_1^e_1 ... q_k^e_k
+// is only as hard as a discrete log in a group of order max q_i^e_i
+// - for each prime power q^e of n, x mod q^e is the discrete log of h^(n / q^e) w.r.t. g^(n / q^e),
+//   which live in the subgroup of order q^e
+// - the Chinese remainder theorem glues the x mod q^e back into x mod n
+// This is why the groups used in cryptography have a (large) prime order
+
+// a prime p such that p - 1 = 2^7 3^3 5^2 7 11 13 ... 59 is smooth, and a generator of the multiplicative group
+pub const SMOOTH_PRIME: &str = "5537549808444132400521601";
+pub const GENERATOR: u64 = 101;
+
+pub fn smooth_order_factors() -> Vec<(u64, u32)> {
+    let mut factors = vec![(2, 7), (3, 3), (5, 2)];
+    factors.extend(
+        [7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59]
+            .iter()
+            .map(|q| (*q, 1)),
+    );
+    factors
+}
+
+pub fn smooth_prime() -> BigUint {
+    SMOOTH_PRIME.parse().unwrap()
+}
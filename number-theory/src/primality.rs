@@ -0,0 +1,51 @@
+use crate::*;
+
+// Probabilistic primality tests, for a number n > 2
+// - Fermat: for a prime n, a^(n - 1) = 1 mod n for every a coprime to n. A composite n which passes for the
+//   base a is a Fermat pseudoprime to base a; the Carmichael numbers (561 = 3 * 11 * 17, ...) pass for every
+//   base coprime to them, so that no number of rounds catches them
+// - Miller-Rabin: write n - 1 = 2^s d with d odd. For a prime n the sequence a^d, a^2d, ..., a^(2^s d) = 1
+//   mod n either starts with 1 or reaches -1 before 1, since 1 has no square root but 1 and -1 mod a prime.
+//   A composite n passes for at most a quarter of the bases: k random bases leave a chance below 4^-k
+// Fixed bases are another story: the strong pseudoprimes below pass for all the small primes up to 31 or 37
+
+// 20 random bases, a chance below 2^-40 to declare a composite prime
+pub const MILLER_RABIN_ROUNDS: usize = 20;
+
+// the Carmichael numbers up to this bound are few: 7 of them
+pub const CARMICHAEL_LIMIT: u64 = 10_000;
+
+// (n, is n prime?): Carmichael numbers, strong pseudoprimes to the first prime bases, and primes
+pub const PSEUDOPRIMES: [(&str, bool); 14] = [
+    // Carmichael numbers, the last of the form (6k + 1)(12k + 1)(18k + 1) with its three factors prime
+    ("561", false),
+    ("41041", false),
+    ("1722679487144027224942814568581450379409", false),
+    // strong pseudoprimes to base 2
+    ("2047", false),
+    ("3277", false),
+    ("4033", false),
+    // a strong pseudoprime to the bases 2, 3, 5 and 7, then to the primes up to 31, and up to 37
+    ("3215031751", false),
+    ("3825123056546413051", false),
+    ("318665857834031151167461", false),
+    // primes: 2^61 - 1, 2^64 - 59, 2^127 - 1 and the fields of secp256k1
+    ("2305843009213693951", true),
+    ("18446744073709551557", true),
+    ("170141183460469231731687303715884105727", true),
+    (
+        "115792089237316195423570985008687907853269984665640564039457584007908834671663",
+        true,
+    ),
+    (
+        "115792089237316195423570985008687907852837564279074904382605163141518161494337",
+        true,
+    ),
+];
+
+pub fn pseudoprimes() -> Vec<(BigUint, bool)> {
+    PSEUDOPRIMES
+        .iter()
+        .map(|(n, is_prime)| (n.parse().unwrap(), *is_prime))
+        .collect()
+}
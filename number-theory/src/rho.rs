@@ -0,0 +1,126 @@
+use crate::*;
+
+// Pollard's rho: a pseudorandom walk in the group runs into a cycle after about sqrt(pi q / 2) steps
+// - every point of the walk is known as W = aG + bQ
+// - the walk is an "r-adding walk" (Teske): W -> W + R_i where R_i = a_i G + b_i Q and i is derived from W
+// - two different representations of the same point aG + bQ = a'G + b'Q give x = (a - a') / (b' - b) mod q
+//
+// van Oorschot-Wiener parallel rho: many walks start from random points and report their
+// distinguished points to a central table; a point reported twice reveals the discrete log.
+// All the walks share the same step function, so once two of them collide they follow the same path
+// and reach the same next distinguished point: m walks find a collision m times faster
+//
+// Speeding up rho with the negation map: P and -P are computed for free from each other, so the walk can run
+// on the q/2 classes {P, -P} instead of the q points, by always moving to a canonical representative of the class.
+// The step function must then only depend on the class (here i is derived from x only).
+// Walks on classes fall into "fruitless cycles": W -> V = ~(W + R_i) -> ~(V + R_i) = W when V = -(W + R_i),
+// which must be avoided by looking ahead, with a larger number of branches r making them rarer
+
+pub const RHO_BRANCHES: usize = 20;
+pub const NEGATION_RHO_BRANCHES: usize = 128;
+
+// a point of the walk with its representation aG + bQ
+#[derive(Clone, Copy, Debug)]
+pub struct WalkPoint {
+    pub point: ToyPoint,
+    pub a: u64,
+    pub b: u64,
+}
+
+pub struct RhoWalk {
+    pub instance: DlpInstance,
+    pub steps: Vec<WalkPoint>,
+}
+
+impl RhoWalk {
+    pub fn new<R: Rng>(instance: &DlpInstance, rng: &mut R) -> RhoWalk {
+        RhoWalk::with_branches(instance, RHO_BRANCHES, rng)
+    }
+
+    pub fn with_branches<R: Rng>(instance: &DlpInstance, branches: usize, rng: &mut R) -> RhoWalk {
+        let steps = (0..branches)
+            .map(|_| instance.random_walk_point(rng))
+            .collect();
+        RhoWalk {
+            instance: *instance,
+            steps,
+        }
+    }
+
+    // W + R_i
+    pub fn add(&self, w: &WalkPoint, i: usize) -> WalkPoint {
+        let q = self.instance.order;
+        let r = &self.steps[i];
+        WalkPoint {
+            point: self.instance.curve.add(&w.point, &r.point),
+            a: (w.a + r.a) % q,
+            b: (w.b + r.b) % q,
+        }
+    }
+
+    pub fn step(&self, w: &WalkPoint) -> WalkPoint {
+        self.add(w, jump_index(&w.point, self.steps.len()))
+    }
+
+    // -W = (-a)G + (-b)Q
+    pub fn negate(&self, w: &WalkPoint) -> WalkPoint {
+        let q = self.instance.order;
+        WalkPoint {
+            point: self.instance.curve.neg(&w.point),
+            a: (q - w.a) % q,
+            b: (q - w.b) % q,
+        }
+    }
+}
+
+impl DlpInstance {
+    // aG + bQ for random a and b
+    pub fn random_walk_point<R: Rng>(&self, rng: &mut R) -> WalkPoint {
+        let a = rng.gen_range(0..self.order);
+        let b = rng.gen_range(0..self.order);
+        let point = self.curve.add(
+            &self.curve.mul(&self.generator, a),
+            &self.curve.mul(&self.target, b),
+        );
+        WalkPoint { point, a, b }
+    }
+}
+
+// x such that aG + bQ = a'G + b'Q, unless b = b' (the collision is useless, try again)
+pub fn solve_collision(w1: &WalkPoint, w2: &WalkPoint, q: u64) -> Option<u64> {
+    if w1.b == w2.b {
+        return None;
+    }
+    let numerator = (w1.a + q - w2.a) % q;
+    let denominator = (w2.b + q - w1.b) % q;
+    Some(mul_mod(numerator, pow_mod(denominator, q - 2, q), q))
+}
+
+// rho with distinguished points on a single thread, for any step function
+// returns the discrete log and the number of steps it took
+pub fn serial_rho<R: Rng>(
+    walk: &RhoWalk,
+    step: impl Fn(&RhoWalk, &WalkPoint) -> WalkPoint,
+    rng: &mut R,
+) -> (u64, u64) {
+    let q = walk.instance.order;
+    let bits = distinguished_bits(q);
+    let mut table: HashMap<ToyPoint, WalkPoint> = HashMap::new();
+    let mut total = 0;
+    loop {
+        let mut w = walk.instance.random_walk_point(rng);
+        // a walk trapped in a cycle without distinguished point is abandoned
+        for _ in 0..8 << bits {
+            w = step(walk, &w);
+            total += 1;
+            if is_distinguished(&w.point, bits) {
+                if let Some(previous) = table.insert(w.point, w) {
+                    if let Some(x) = solve_collision(&previous, &w, q) {
+                        return (x, total);
+                    }
+                }
+                break;
+            }
+        }
+    }
+}
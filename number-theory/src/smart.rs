@@ -0,0 +1,121 @@
+use crate::*;
+
+// Smart's attack on anomalous curves, whose number of points is p, the characteristic of the field
+// The discrete log then moves to the additive group of F_p, where it is a division:
+// - lift the curve and the points from F_p to Z/p^2 (Hensel's lemma keeps x and corrects y by a multiple of p)
+// - for a lifted point P', pP' reduces to the point at infinity mod p: it lies in the kernel of the reduction,
+//   where the parameter t = -x/y is a multiple of p, and P -> t(pP') / p mod p is a group morphism E(F_p) -> F_p
+// - affine coordinates cannot hold pP', so it is reached from (p - 1)P' + P', whose slope lambda has a denominator
+//   divisible by p: t(pP') = 1 / lambda mod p^2, that is (x(P') - x((p - 1)P')) / (y(P') - y((p - 1)P'))
+// The lifted curve y^2 = x^3 + a'x + b' only needs a' = a and b' = b mod p. The canonical lift, for this curve
+// the one with a' = 0 (j = 0, like the curve), makes the morphism zero: take a' a random multiple of p instead
+// The curve of the exercise is y^2 = x^3 + 5 over a 128-bit p, found by complex multiplication: with
+// 4p = 1 + 3v^2, one of the six curves y^2 = x^3 + b has trace 1, hence p points
+
+pub const ANOMALOUS_P: &str = "220873112927196885770612109212883617017";
+pub const ANOMALOUS_B: u32 = 5;
+pub const ANOMALOUS_GENERATOR: (&str, &str) = (
+    "40125655066622386354123033417875897284",
+    "62425329260605597795642130639161367750",
+);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BigPoint {
+    Infinity,
+    Affine(BigUint, BigUint),
+}
+
+// y^2 = x^3 + ax + b over Z/modulus, the modulus being p for the curve and p^2 for its lifts
+#[derive(Clone, Debug)]
+pub struct CurveMod {
+    pub modulus: BigUint,
+    pub a: BigUint,
+    pub b: BigUint,
+}
+
+impl CurveMod {
+    pub fn is_on_curve(&self, point: &BigPoint) -> bool {
+        match point {
+            BigPoint::Infinity => true,
+            BigPoint::Affine(x, y) => {
+                (y * y) % &self.modulus == (x * x * x + &self.a * x + &self.b) % &self.modulus
+            }
+        }
+    }
+
+    // the affine group law, which panics when a denominator is not invertible: modulo p^2, adding two points
+    // equal modulo p but not modulo p^2 leaves the affine points
+    pub fn add(&self, a: &BigPoint, b: &BigPoint) -> BigPoint {
+        let n = &self.modulus;
+        let (x1, y1, x2, y2) = match (a, b) {
+            (BigPoint::Infinity, _) => return b.clone(),
+            (_, BigPoint::Infinity) => return a.clone(),
+            (BigPoint::Affine(x1, y1), BigPoint::Affine(x2, y2)) => (x1, y1, x2, y2),
+        };
+        let inverse = |d: BigUint| {
+            d.modinv(n)
+                .expect("not invertible: the points are equal modulo p")
+        };
+        let lambda = if x1 != x2 {
+            (y2 + n - y1) * inverse((x2 + n - x1) % n) % n
+        } else if y1 == y2 && !y1.is_zero() {
+            (3u32 * x1 * x1 + &self.a) * inverse(2u32 * y1 % n) % n
+        } else {
+            return BigPoint::Infinity;
+        };
+        let x3 = (&lambda * &lambda + 2u32 * n - x1 - x2) % n;
+        let y3 = (lambda * ((x1 + n - &x3) % n) + n - y1) % n;
+        BigPoint::Affine(x3, y3)
+    }
+
+    pub fn mul(&self, point: &BigPoint, k: &BigUint) -> BigPoint {
+        let mut result = BigPoint::Infinity;
+        for i in (0..k.bits()).rev() {
+            result = self.add(&result, &result);
+            if k.bit(i) {
+                result = self.add(&result, point);
+            }
+        }
+        result
+    }
+}
+
+pub fn anomalous_curve() -> CurveMod {
+    CurveMod {
+        modulus: ANOMALOUS_P.parse().unwrap(),
+        a: BigUint::zero(),
+        b: BigUint::from(ANOMALOUS_B),
+    }
+}
+
+pub fn anomalous_generator() -> BigPoint {
+    let (x, y) = ANOMALOUS_GENERATOR;
+    BigPoint::Affine(x.parse().unwrap(), y.parse().unwrap())
+}
+
+// target = secret generator on the anomalous curve
+pub struct AnomalousInstance {
+    pub curve: CurveMod,
+    pub generator: BigPoint,
+    pub target: BigPoint,
+    secret: BigUint,
+}
+
+impl AnomalousInstance {
+    pub fn random<R: Rng>(rng: &mut R) -> AnomalousInstance {
+        let curve = anomalous_curve();
+        let generator = anomalous_generator();
+        let secret = rng.gen_biguint_below(&curve.modulus);
+        let target = curve.mul(&generator, &secret);
+        AnomalousInstance {
+            curve,
+            generator,
+            target,
+            secret,
+        }
+    }
+
+    pub fn is_secret(&self, k: &BigUint) -> bool {
+        *k == self.secret
+    }
+}
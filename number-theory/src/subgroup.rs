@@ -0,0 +1,76 @@
+use crate::*;
+
+// Subgroup membership: protocols work in a subgroup of prime order q of a curve with #E = hq,
+// and must reject the points outside of it (small subgroup attacks, see ff-ec and curve25519)
+// - the generic check is qP = O, a full scalar multiplication
+// - with the Tate pairing: when h is a prime dividing p - 1 (but h^2 does not divide #E),
+//   the subgroup of order q is hE(F_p), and the reduced Tate pairing
+//     t_h : E[h] x E(F_p) / hE(F_p) -> mu_h,  t_h(T, P) = f_{h,T}(P)^((p - 1) / h)
+//   is non-degenerate: for T of order h, P is in the subgroup iff t_h(T, P) = 1
+//   This only costs a Miller loop of length log(h) and an exponentiation, instead of log(q) point operations
+// f_{h,T} is the function of divisor h(T) - h(O), computed by Miller's algorithm: f_{i+j} = f_i f_j l / v
+// where l is the line through iT and jT and v the vertical line through (i+j)T
+// It is evaluated at a divisor (P + R) - (R) equivalent to (P) - (O), R being chosen to avoid the zeros and poles
+
+// a small curve whose order 2119 = 13 * 163, with 13 dividing p - 1 = 2080
+pub const PAIRING_CURVE: ToyCurve = ToyCurve {
+    p: 2081,
+    a: 1,
+    b: 5,
+};
+pub const PAIRING_COFACTOR: u64 = 13;
+pub const PAIRING_ORDER: u64 = 163;
+
+// all the points of a small curve
+pub fn all_points(curve: &ToyCurve) -> Vec<ToyPoint> {
+    let mut square_roots: Vec<Vec<u64>> = vec![vec![]; curve.p as usize];
+    for y in 0..curve.p {
+        square_roots[mul_mod(y, y, curve.p) as usize].push(y);
+    }
+    let mut points = vec![ToyPoint::Infinity];
+    for x in 0..curve.p {
+        let rhs =
+            (mul_mod(mul_mod(x, x, curve.p), x, curve.p) + mul_mod(curve.a, x, curve.p) + curve.b)
+                % curve.p;
+        points.extend(
+            square_roots[rhs as usize]
+                .iter()
+                .map(|y| ToyPoint::Affine(x, *y)),
+        );
+    }
+    points
+}
+
+// l(S) / v(S) where l is the line through A and B (the tangent if A = B) and v the vertical line through A + B
+// None if S is a zero or a pole
+pub fn line_function(curve: &ToyCurve, a: &ToyPoint, b: &ToyPoint, s: &ToyPoint) -> Option<u64> {
+    let p = curve.p;
+    let ToyPoint::Affine(xs, ys) = *s else {
+        return None;
+    };
+    let (ToyPoint::Affine(xa, ya), ToyPoint::Affine(_, _)) = (*a, *b) else {
+        return Some(1); // adding O: l / v is constant
+    };
+    let sum = curve.add(a, b);
+    let ToyPoint::Affine(x_sum, _) = sum else {
+        // A + B = O: l is the vertical line through A, and v = 1
+        let l = (xs + p - xa) % p;
+        return (l != 0).then_some(l);
+    };
+    let lambda = match *b {
+        ToyPoint::Affine(xb, yb) if xb != xa => {
+            mul_mod((yb + p - ya) % p, curve.inverse((xb + p - xa) % p), p)
+        }
+        _ => mul_mod(
+            (3 * mul_mod(xa, xa, p) + curve.a) % p,
+            curve.inverse(2 * ya % p),
+            p,
+        ),
+    };
+    let l = (ys + p - ya + p - mul_mod(lambda, (xs + p - xa) % p, p)) % p;
+    let v = (xs + p - x_sum) % p;
+    if l == 0 || v == 0 {
+        return None;
+    }
+    Some(mul_mod(l, curve.inverse(v), p))
+}
@@ -0,0 +1,205 @@
+use crate::*;
+use std::sync::atomic::AtomicU64;
+
+// Toy elliptic curves y^2 = x^3 + ax + b over F_p, with p of a few dozens bits so that the arithmetic fits in a u64
+// The discrete log instances use a = 0: when p = 2 mod 3, x -> x^3 is a bijection of F_p: for every y there is exactly one x on the curve,
+// hence p + 1 points (the curve is supersingular: pairings would make the DLP easy, see the MOV attack)
+// Choosing p = 12q - 1 with q prime gives a subgroup of prime order q, the cofactor being 12
+
+pub const COFACTOR: u64 = 12;
+
+// the calls to `ToyCurve::add` by all the threads, doublings and those of `mul` included, for the budgets
+// of the questions
+static GROUP_OPERATIONS: AtomicU64 = AtomicU64::new(0);
+
+pub fn group_operations() -> u64 {
+    GROUP_OPERATIONS.load(Ordering::Relaxed)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ToyPoint {
+    Infinity,
+    Affine(u64, u64),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ToyCurve {
+    pub p: u64,
+    pub a: u64,
+    pub b: u64,
+}
+
+pub fn mul_mod(a: u64, b: u64, p: u64) -> u64 {
+    (a as u128 * b as u128 % p as u128) as u64
+}
+
+pub fn pow_mod(mut base: u64, mut exponent: u64, p: u64) -> u64 {
+    let mut result = 1 % p;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, p);
+        }
+        base = mul_mod(base, base, p);
+        exponent >>= 1;
+    }
+    result
+}
+
+// Miller-Rabin with the first 12 primes as witnesses is deterministic below 2^64
+pub fn is_prime(n: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    if let Some(w) = WITNESSES.iter().find(|w| n.is_multiple_of(**w)) {
+        return n == *w;
+    }
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    WITNESSES.iter().all(|a| {
+        let mut x = pow_mod(*a, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+impl ToyCurve {
+    // extended Euclidean algorithm, much faster than a^(p-2)
+    pub fn inverse(&self, a: u64) -> u64 {
+        assert_ne!(a, 0);
+        let (mut r0, mut r1) = (self.p as i128, a as i128);
+        let (mut v0, mut v1) = (0i128, 1i128);
+        while r1 != 0 {
+            let q = r0 / r1;
+            (r0, r1) = (r1, r0 - q * r1);
+            (v0, v1) = (v1, v0 - q * v1);
+        }
+        v0.rem_euclid(self.p as i128) as u64
+    }
+
+    pub fn is_on_curve(&self, point: &ToyPoint) -> bool {
+        match *point {
+            ToyPoint::Infinity => true,
+            ToyPoint::Affine(x, y) => {
+                let rhs = (mul_mod(mul_mod(x, x, self.p), x, self.p)
+                    + mul_mod(self.a, x, self.p)
+                    + self.b)
+                    % self.p;
+                mul_mod(y, y, self.p) == rhs
+            }
+        }
+    }
+
+    pub fn neg(&self, point: &ToyPoint) -> ToyPoint {
+        match *point {
+            ToyPoint::Infinity => ToyPoint::Infinity,
+            ToyPoint::Affine(x, y) => ToyPoint::Affine(x, (self.p - y) % self.p),
+        }
+    }
+
+    pub fn add(&self, a: &ToyPoint, b: &ToyPoint) -> ToyPoint {
+        GROUP_OPERATIONS.fetch_add(1, Ordering::Relaxed);
+        let p = self.p;
+        let (x1, y1, x2, y2) = match (*a, *b) {
+            (ToyPoint::Infinity, _) => return *b,
+            (_, ToyPoint::Infinity) => return *a,
+            (ToyPoint::Affine(x1, y1), ToyPoint::Affine(x2, y2)) => (x1, y1, x2, y2),
+        };
+        let lambda = if x1 != x2 {
+            mul_mod((y2 + p - y1) % p, self.inverse((x2 + p - x1) % p), p)
+        } else if y1 == y2 && y1 != 0 {
+            let numerator = (3 * mul_mod(x1, x1, p) + self.a) % p;
+            mul_mod(numerator, self.inverse(2 * y1 % p), p)
+        } else {
+            return ToyPoint::Infinity;
+        };
+        let x3 = (mul_mod(lambda, lambda, p) + 2 * p - x1 - x2) % p;
+        let y3 = (mul_mod(lambda, (x1 + p - x3) % p, p) + p - y1) % p;
+        ToyPoint::Affine(x3, y3)
+    }
+
+    // when #E = hq with q a prime not dividing the cofactor h, the points of order q (and O) are those with qP = O
+    pub fn is_in_prime_order_subgroup(&self, point: &ToyPoint, order: u64) -> bool {
+        self.is_on_curve(point) && self.mul(point, order) == ToyPoint::Infinity
+    }
+
+    pub fn mul(&self, point: &ToyPoint, mut k: u64) -> ToyPoint {
+        let mut result = ToyPoint::Infinity;
+        let mut base = *point;
+        while k > 0 {
+            if k & 1 == 1 {
+                result = self.add(&result, &base);
+            }
+            base = self.add(&base, &base);
+            k >>= 1;
+        }
+        result
+    }
+
+    // pick y and solve for x = cbrt(y^2 - b), which always exists when a = 0 and p = 2 mod 3
+    pub fn random_point<R: Rng>(&self, rng: &mut R) -> ToyPoint {
+        assert_eq!(self.a, 0);
+        let y = rng.gen_range(0..self.p);
+        let rhs = (mul_mod(y, y, self.p) + self.p - self.b) % self.p;
+        // the inverse of 3 mod p - 1 exists since p = 2 mod 3
+        let cube_root_exponent = (2 * self.p - 1) / 3;
+        let x = pow_mod(rhs, cube_root_exponent, self.p);
+        let point = ToyPoint::Affine(x, y);
+        assert!(self.is_on_curve(&point));
+        point
+    }
+}
+
+// a discrete log instance: find x such that target = x generator, the generator having prime order `order`
+#[derive(Clone, Copy, Debug)]
+pub struct DlpInstance {
+    pub curve: ToyCurve,
+    pub generator: ToyPoint,
+    pub order: u64,
+    pub target: ToyPoint,
+}
+
+impl DlpInstance {
+    // a random instance whose subgroup order has `bits` bits
+    pub fn random<R: Rng>(bits: u32, rng: &mut R) -> (DlpInstance, u64) {
+        assert!((8..=59).contains(&bits), "p = 12q - 1 must fit in a u64");
+        let (p, q) = loop {
+            let q = rng.gen_range(1u64 << (bits - 1)..1u64 << bits);
+            if is_prime(q) && is_prime(COFACTOR * q - 1) {
+                break (COFACTOR * q - 1, q);
+            }
+        };
+        let curve = ToyCurve {
+            p,
+            a: 0,
+            b: rng.gen_range(1..p),
+        };
+        let generator = loop {
+            let point = curve.mul(&curve.random_point(rng), COFACTOR);
+            if point != ToyPoint::Infinity {
+                break point;
+            }
+        };
+        assert_eq!(curve.mul(&generator, q), ToyPoint::Infinity);
+        let x = rng.gen_range(0..q);
+        let instance = DlpInstance {
+            curve,
+            generator,
+            order: q,
+            target: curve.mul(&generator, x),
+        };
+        (instance, x)
+    }
+
+    pub fn check(&self, x: u64) -> bool {
+        x < self.order && self.curve.mul(&self.generator, x) == self.target
+    }
+}
@@ -0,0 +1,131 @@
+use crate::*;
+use std::time::Duration;
+
+// Double-and-add on a grid: `cargo run --release -- visualize [p] [scalar]`
+// All the points of y^2 = x^3 + 7 over a small F_p are plotted, x to the right and y upwards,
+// and the frames follow your `double_and_add_steps` from P to kP, one doubling or addition at a time
+//   .  a point of the curve
+//   P  the base point
+//   *  a point already visited
+//   @  the current point
+
+pub const VISUALIZER_B: u64 = 7;
+pub const DEFAULT_P: u64 = 83;
+pub const DEFAULT_SCALAR: u64 = 45;
+pub const FRAME_DELAY: Duration = Duration::from_millis(800);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Step {
+    Double,
+    Add,
+}
+
+// all the affine points, by brute force
+pub fn affine_points(curve: &ToyCurve) -> Vec<(u64, u64)> {
+    (0..curve.p)
+        .flat_map(|x| (0..curve.p).map(move |y| (x, y)))
+        .filter(|(x, y)| curve.is_on_curve(&ToyPoint::Affine(*x, *y)))
+        .collect()
+}
+
+pub fn render(
+    curve: &ToyCurve,
+    points: &[(u64, u64)],
+    base: &ToyPoint,
+    visited: &[ToyPoint],
+    current: &ToyPoint,
+) -> String {
+    let p = curve.p as usize;
+    let mut grid = vec![vec![' '; p]; p];
+    for (x, y) in points {
+        grid[*y as usize][*x as usize] = '.';
+    }
+    let mut mark = |point: &ToyPoint, c: char| {
+        if let ToyPoint::Affine(x, y) = point {
+            grid[*y as usize][*x as usize] = c;
+        }
+    };
+    visited.iter().for_each(|point| mark(point, '*'));
+    mark(base, 'P');
+    mark(current, '@');
+    let mut frame = String::new();
+    for (y, row) in grid.iter().enumerate().rev() {
+        frame += &format!("{:>3} |{}\n", y, row.iter().collect::<String>());
+    }
+    frame += &format!("    +{}\n", "-".repeat(p));
+    frame
+}
+
+pub fn visualize(args: &[String]) {
+    let (p, scalar) = match args {
+        [] => (DEFAULT_P, DEFAULT_SCALAR),
+        [p] => (p.parse().expect("p"), DEFAULT_SCALAR),
+        [p, scalar] => (p.parse().expect("p"), scalar.parse().expect("scalar")),
+        _ => panic!("usage: visualize [p < 200] [scalar]"),
+    };
+    assert!(
+        is_prime(p) && p > 3 && p < 200,
+        "p must be a prime in (3, 200)"
+    );
+    assert!(scalar > 0, "the scalar must be positive");
+    let curve = ToyCurve {
+        p,
+        a: 0,
+        b: VISUALIZER_B,
+    };
+    let points = affine_points(&curve);
+    // the point of largest order, so that the multiples do not cycle too soon
+    let order = |point: &ToyPoint| {
+        (1..)
+            .find(|k| curve.mul(point, *k) == ToyPoint::Infinity)
+            .unwrap()
+    };
+    let base = points
+        .iter()
+        .map(|(x, y)| ToyPoint::Affine(*x, *y))
+        .max_by_key(order)
+        .expect("the curve has no affine point");
+
+    let steps = double_and_add_steps(&curve, &base, scalar);
+    let mut visited = vec![];
+    let mut current = base;
+    let mut k = 1;
+    let frame = |current: &ToyPoint, visited: &[ToyPoint], caption: &str| {
+        // clear the terminal before drawing
+        print!(
+            "\x1b[2J\x1b[H{}",
+            render(&curve, &points, &base, visited, current)
+        );
+        println!(
+            "y^2 = x^3 + {} over F_{}: {} points, P = {:?} of order {}",
+            VISUALIZER_B,
+            p,
+            points.len() + 1,
+            base,
+            order(&base)
+        );
+        println!("{}", caption);
+        thread::sleep(FRAME_DELAY);
+    };
+    frame(
+        &current,
+        &visited,
+        &format!("computing {}P: start from P", scalar),
+    );
+    for (step, point) in steps {
+        visited.push(current);
+        current = point;
+        let caption = match step {
+            Step::Double => {
+                k *= 2;
+                format!("double:  {}P = {:?}", k, current)
+            }
+            Step::Add => {
+                k += 1;
+                format!("add P:   {}P = {:?}", k, current)
+            }
+        };
+        frame(&current, &visited, &caption);
+    }
+    assert_eq!(current, curve.mul(&base, scalar));
+}
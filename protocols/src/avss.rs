@@ -0,0 +1,157 @@
+use crate::*;
+use std::collections::{HashMap, HashSet};
+
+// Asynchronous verifiable secret sharing (AVSS), a toy version on top of Bracha's reliable broadcast
+// n = 3f + 1 parties, at most f of them faulty, over the asynchronous network of module `simnet`
+// - the dealer shares s with a polynomial a of degree f, and sends to party j the Feldman commitments
+//   C_k = a_k G of the coefficients together with the share a(j + 1)
+// - Send: a party receiving a valid share (a(j + 1) G = sum C_k (j + 1)^k) broadcasts Echo(H(C))
+// - Echo: a party receiving 2f + 1 echoes for the same H(C) broadcasts Ready(H(C))
+// - Ready: a party receiving f + 1 readies for H(C) broadcasts Ready(H(C)) as well (at least one honest
+//   party saw 2f + 1 echoes), and completes once it has 2f + 1 readies
+// Each party echoes and readies at most once. Every party counts the messages of each sender once:
+// the network duplicates messages!
+// Properties checked by the harness:
+// - agreement: all the honest parties that complete output the same commitments
+// - validity: if the dealer is honest, all honest parties complete and their shares recover s
+
+pub type Fingerprint = [u8; 32];
+
+#[derive(Clone, Debug)]
+pub enum AvssMessage {
+    Send { commitments: Vec<Affine>, share: Fr },
+    Echo(Fingerprint),
+    Ready(Fingerprint),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AvssOutput {
+    pub commitments: Fingerprint,
+    pub share: Option<Fr>, // None if the dealer did not send a valid share to this party
+}
+
+pub struct AvssParty {
+    pub id: usize,
+    pub n: usize,
+    pub f: usize,
+    pub commitments: Option<(Fingerprint, Vec<Affine>)>,
+    pub share: Option<Fr>,
+    pub echoes: HashMap<Fingerprint, HashSet<usize>>,
+    pub readies: HashMap<Fingerprint, HashSet<usize>>,
+    pub sent_echo: bool,
+    pub sent_ready: bool,
+    pub output: Option<AvssOutput>,
+}
+
+pub fn commitments_fingerprint(commitments: &[Affine]) -> Fingerprint {
+    let mut bytes = Vec::new();
+    commitments.serialize_compressed(&mut bytes).unwrap();
+    Sha256::new().chain_update(bytes).finalize().into()
+}
+
+// Feldman: a(i) G = sum C_k i^k
+pub fn verify_share(commitments: &[Affine], index: u64, share: &Fr) -> bool {
+    let expected: Projective = commitments
+        .iter()
+        .rev()
+        .fold(Projective::zero(), |acc, commitment| {
+            acc * Fr::from(index) + commitment
+        });
+    Affine::generator().mul(share) == expected
+}
+
+// the sharing of an honest dealer: the commitments and the shares of parties 0..n
+pub fn deal<R: Rng>(secret: Fr, f: usize, n: usize, rng: &mut R) -> (Vec<Affine>, Vec<Fr>) {
+    let mut coefficients = vec![secret];
+    coefficients.extend((0..f).map(|_| Fr::rand(rng)));
+    let commitments = coefficients
+        .iter()
+        .map(|a| Affine::generator().mul(a).into_affine())
+        .collect();
+    let shares = (1..=n as u64)
+        .map(|index| evaluate(&coefficients, Fr::from(index)))
+        .collect();
+    (commitments, shares)
+}
+
+impl AvssParty {
+    pub fn new(id: usize, n: usize, f: usize) -> AvssParty {
+        assert!(n > 3 * f);
+        AvssParty {
+            id,
+            n,
+            f,
+            commitments: None,
+            share: None,
+            echoes: HashMap::new(),
+            readies: HashMap::new(),
+            sent_echo: false,
+            sent_ready: false,
+            output: None,
+        }
+    }
+
+    // the messages to broadcast in reaction to a message
+    pub fn handle(&mut self, from: PartyId, message: AvssMessage) -> Vec<AvssMessage> {
+        match message {
+            AvssMessage::Send { commitments, share } => self.on_send(from, commitments, share),
+            AvssMessage::Echo(fingerprint) => self.on_echo(from, fingerprint),
+            AvssMessage::Ready(fingerprint) => self.on_ready(from, fingerprint),
+        }
+    }
+}
+
+impl Party<AvssMessage> for AvssParty {
+    fn receive(
+        &mut self,
+        from: PartyId,
+        message: AvssMessage,
+        _rng: &mut ChaChaRng,
+    ) -> Vec<Outgoing<AvssMessage>> {
+        self.handle(from, message)
+            .into_iter()
+            .map(Outgoing::Broadcast)
+            .collect()
+    }
+}
+
+// the dealer is the node n, it sends `dealt[j]` to party j and listens to nothing
+pub struct AvssDealer {
+    pub dealt: Vec<(Vec<Affine>, Fr)>,
+}
+
+impl Party<AvssMessage> for AvssDealer {
+    fn start(&mut self, _rng: &mut ChaChaRng) -> Vec<Outgoing<AvssMessage>> {
+        self.dealt
+            .drain(..)
+            .enumerate()
+            .map(|(to, (commitments, share))| {
+                Outgoing::To(to, AvssMessage::Send { commitments, share })
+            })
+            .collect()
+    }
+
+    fn receive(
+        &mut self,
+        _from: PartyId,
+        _message: AvssMessage,
+        _rng: &mut ChaChaRng,
+    ) -> Vec<Outgoing<AvssMessage>> {
+        vec![]
+    }
+}
+
+// runs the protocol until the network is quiet, the dealer having sent `dealt[j]` to party j
+pub fn run_avss(
+    parties: &mut [AvssParty],
+    dealt: Vec<(Vec<Affine>, Fr)>,
+    simnet: &mut Simnet<AvssMessage>,
+) {
+    let mut dealer = AvssDealer { dealt };
+    let mut nodes: Vec<&mut dyn Party<AvssMessage>> = parties
+        .iter_mut()
+        .map(|party| party as &mut dyn Party<AvssMessage>)
+        .collect();
+    nodes.push(&mut dealer);
+    simnet.run(&mut nodes);
+}
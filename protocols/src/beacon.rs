@@ -0,0 +1,126 @@
+use crate::*;
+
+// A randomness beacon from commit-reveal among n parties
+// 1. every party posts a deposit and a commitment H(id, value, salt) to a random value
+// 2. once all commitments are posted, every party reveals (value, salt)
+// 3. the output is the hash of all valid revealed values
+// The last party to reveal sees the others' values and can compute the output before revealing:
+// by refusing to reveal when she doesn't like it ("selective abort") she biases the beacon
+// Deposits make this costly: parties that do not reveal a valid opening are slashed
+// and their deposits are shared between the honest revealers
+
+pub type Commitment = [u8; 32];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Reveal {
+    pub value: [u8; 32],
+    pub salt: [u8; 32],
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Strategy {
+    Honest,
+    // never reveals
+    Withhold,
+    // reveals only if the output it would produce has its first bit set to 0, its lucky outcome
+    Grind,
+    // reveals a value which does not match its commitment
+    WrongOpening,
+    // copies the commitment of another party, then its opening once revealed
+    Copycat(usize),
+}
+
+pub struct BeaconParty {
+    pub id: usize,
+    pub strategy: Strategy,
+    pub secret: Option<Reveal>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BeaconOutcome {
+    pub output: [u8; 32],
+    pub slashed: Vec<usize>,
+    pub payouts: Vec<u64>,
+}
+
+// the commitment is bound to the identity of the party, so that it cannot be replayed by another one
+pub fn commit_value(id: usize, reveal: &Reveal) -> Commitment {
+    Sha256::new()
+        .chain_update((id as u64).to_be_bytes())
+        .chain_update(reveal.value)
+        .chain_update(reveal.salt)
+        .finalize()
+        .into()
+}
+
+pub fn is_lucky(output: &[u8; 32]) -> bool {
+    output[0] & 0x80 == 0
+}
+
+impl BeaconParty {
+    pub fn new(id: usize, strategy: Strategy) -> BeaconParty {
+        BeaconParty {
+            id,
+            strategy,
+            secret: None,
+        }
+    }
+
+    // adversarial parties are "rushing": they post their commitment after seeing the others'
+    pub fn commit<R: Rng>(&mut self, board: &[Commitment], rng: &mut R) -> Commitment {
+        if let Strategy::Copycat(victim) = self.strategy {
+            return board[victim];
+        }
+        let secret = Reveal {
+            value: rng.gen(),
+            salt: rng.gen(),
+        };
+        self.secret = Some(secret);
+        commit_value(self.id, &secret)
+    }
+
+    // adversarial parties reveal last, after seeing the others' openings
+    pub fn reveal(&self, revealed: &[(usize, Reveal)]) -> Option<Reveal> {
+        let secret = self.secret;
+        match self.strategy {
+            Strategy::Honest => secret,
+            Strategy::Withhold => None,
+            Strategy::Grind => {
+                let mut with_mine = revealed.to_vec();
+                with_mine.push((self.id, secret.unwrap()));
+                with_mine.sort_by_key(|(id, _)| *id);
+                is_lucky(&aggregate(&with_mine)).then_some(secret.unwrap())
+            }
+            Strategy::WrongOpening => secret.map(|mut reveal| {
+                reveal.value[0] ^= 1;
+                reveal
+            }),
+            Strategy::Copycat(victim) => revealed
+                .iter()
+                .find(|(id, _)| *id == victim)
+                .map(|(_, reveal)| *reveal),
+        }
+    }
+}
+
+// one run of the beacon; honest parties commit and reveal first
+pub fn run_beacon<R: Rng>(parties: &mut [BeaconParty], deposit: u64, rng: &mut R) -> BeaconOutcome {
+    let mut order: Vec<usize> = (0..parties.len()).collect();
+    order.sort_by_key(|i| parties[*i].strategy != Strategy::Honest);
+    let mut commitments = vec![[0u8; 32]; parties.len()];
+    let mut board = vec![];
+    for i in &order {
+        commitments[*i] = parties[*i].commit(&board, rng);
+        board = commitments.clone();
+    }
+    let mut reveals: Vec<Option<Reveal>> = vec![None; parties.len()];
+    for i in &order {
+        let revealed: Vec<(usize, Reveal)> = reveals
+            .iter()
+            .enumerate()
+            .filter_map(|(id, reveal)| reveal.map(|reveal| (id, reveal)))
+            .collect();
+        reveals[*i] = parties[*i].reveal(&revealed);
+    }
+    finalize(&commitments, &reveals, deposit)
+}
@@ -0,0 +1,48 @@
+use crate::*;
+
+// Two-party computation on additive shares: x is split into x = x_0 + x_1, a uniformly random x_0 to party 0
+// and x_1 = x - x_0 to party 1, so that each share alone is uniformly random and says nothing about x
+// - addition is local: party i adds its shares, x_i + y_i
+// - multiplication is not: x_0 y_1 + x_1 y_0 mixes the shares of both parties
+// Beaver's trick spends a multiplication triple (a, b, c = ab), shared in advance by a dealer:
+//   the parties open d = x - a and e = y - b (a and b are one-time pads on x and y), then
+//   xy = (d + a)(e + b) = de + d b + e a + c, and party i outputs z_i = c_i + d b_i + e a_i (+ de for party 0)
+// The online phase is then as cheap as a few additions; the triples come from a trusted dealer here,
+// from oblivious transfer or homomorphic encryption in real protocols (SPDZ, MASCOT)
+
+pub type Shares = [Fr; 2];
+
+#[derive(Clone, Copy, Debug)]
+pub struct BeaverTriple {
+    pub a: Shares,
+    pub b: Shares,
+    pub c: Shares,
+}
+
+// The simulated dealer of the preprocessing phase: it hands out fresh triples and counts them
+pub struct Dealer {
+    pub triples: usize,
+}
+
+impl Dealer {
+    pub fn new() -> Dealer {
+        Dealer { triples: 0 }
+    }
+
+    pub fn triple<R: Rng>(&mut self, rng: &mut R) -> BeaverTriple {
+        self.triples += 1;
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        BeaverTriple {
+            a: split(a, rng),
+            b: split(b, rng),
+            c: split(a * b, rng),
+        }
+    }
+}
+
+// the dealer's own sharing, so that the triples do not depend on the student's `share`
+fn split<R: Rng>(x: Fr, rng: &mut R) -> Shares {
+    let x_0 = Fr::rand(rng);
+    [x_0, x - x_0]
+}
@@ -0,0 +1,277 @@
+use crate::*;
+use std::collections::HashMap;
+
+// Pedersen's distributed key generation: n parties generate a key pair x, X = xG, shared with threshold t,
+// without anybody ever knowing x
+// 1. every party i deals a random secret a_i with Feldman VSS: it broadcasts the commitments C_i and sends
+//    the share f_i(j) privately to every party j
+// 2. every party checks the shares it received and complains publicly against the dealers of invalid ones,
+//    it broadcasts its complaints even if there are none, so that the others know it is done checking
+// 3. a dealer answers each complaint by revealing the share of the complainer: everybody checks it against
+//    C_i, the complainer adopts it if it is valid, otherwise the dealer is disqualified
+// The qualified dealers QUAL are the same for everybody, since complaints and answers are public. Party j
+// then holds x_j = sum_{i in QUAL} f_i(j), a Shamir share of x = sum a_i, and X = sum C_i[0]
+//
+// The group signs with Schnorr signatures (s G = R + H(R, X, m) X): each signer i of a set S of t parties
+// draws a nonce k_i and publishes R_i = k_i G, then with R = sum R_i and c = H(R, X, m) sends
+//   s_i = k_i + c lambda_i x_i, where lambda_i is the Lagrange coefficient of i at 0 among S
+// and s = sum s_i. This is FROST without its binding factors, which protect concurrent signing sessions
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DealerStrategy {
+    Honest,
+    // sends an invalid share to a party, then reveals the valid one when it complains
+    BadShare(u64),
+    // sends an invalid share to a party, and reveals another invalid one when it complains
+    BadAnswer(u64),
+}
+
+#[derive(Clone)]
+pub struct Dealing {
+    pub dealer: u64,
+    pub commitments: FeldmanCommitments,
+    pub shares: Vec<Share>,  // sent privately, shares[j - 1] to party j
+    pub answers: Vec<Share>, // revealed to answer the complaint of party j
+}
+
+#[derive(Clone, Debug)]
+pub enum DkgMessage {
+    Commitments(FeldmanCommitments), // broadcast by a dealer
+    Share(Share),                    // sent privately by a dealer
+    Complaints(Vec<u64>), // broadcast by every party: the dealers of the invalid shares it received
+    Answer(Share),        // broadcast by a dealer: the share of a party which complained against it
+}
+
+// party j is the node j - 1 of the network of module `simnet`, and the dealer of its own dealing
+pub struct DkgParty {
+    pub index: u64,
+    pub dealing: Dealing,
+    pub commitments: HashMap<u64, FeldmanCommitments>,
+    pub shares: HashMap<u64, Share>,
+    pub complained: bool,
+    pub complaints: HashMap<u64, Vec<u64>>, // complainer -> dealers
+    pub answers: HashMap<(u64, u64), Share>, // (dealer, complainer) -> share
+    // the commitments and the valid share received from each dealer
+    pub received: HashMap<u64, (FeldmanCommitments, Fr)>,
+    pub qualified: Option<Vec<u64>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DkgOutput {
+    pub share: Share,
+    pub public_key: Affine,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SchnorrSignature {
+    pub r: Affine,
+    pub s: Fr,
+}
+
+impl Dealing {
+    pub fn new<R: Rng>(
+        dealer: u64,
+        strategy: DealerStrategy,
+        threshold: usize,
+        n: usize,
+        rng: &mut R,
+    ) -> Dealing {
+        let (commitments, shares) = feldman_share(Fr::rand(rng), threshold, n, rng);
+        let mut dealing = Dealing {
+            dealer,
+            commitments,
+            answers: shares.clone(),
+            shares,
+        };
+        let one = Fr::from(1u64);
+        match strategy {
+            DealerStrategy::Honest => {}
+            DealerStrategy::BadShare(victim) => dealing.shares[victim as usize - 1].value += one,
+            DealerStrategy::BadAnswer(victim) => {
+                dealing.shares[victim as usize - 1].value += one;
+                dealing.answers[victim as usize - 1].value += one;
+            }
+        }
+        dealing
+    }
+}
+
+fn node(index: u64) -> PartyId {
+    index as usize - 1
+}
+
+impl DkgParty {
+    pub fn new(dealing: Dealing) -> DkgParty {
+        DkgParty {
+            index: dealing.dealer,
+            dealing,
+            commitments: HashMap::new(),
+            shares: HashMap::new(),
+            complained: false,
+            complaints: HashMap::new(),
+            answers: HashMap::new(),
+            received: HashMap::new(),
+            qualified: None,
+        }
+    }
+
+    pub fn n(&self) -> usize {
+        self.dealing.shares.len()
+    }
+
+    // the messages to send in reaction to a message from party `from`, counted once: the network duplicates
+    // messages!
+    pub fn handle(&mut self, from: u64, message: DkgMessage) -> Vec<Outgoing<DkgMessage>> {
+        let mut outgoing = vec![];
+        match message {
+            DkgMessage::Commitments(commitments) => {
+                self.commitments.entry(from).or_insert(commitments);
+            }
+            DkgMessage::Share(share) => {
+                self.shares.entry(from).or_insert(share);
+            }
+            DkgMessage::Complaints(dealers) => {
+                if !self.complaints.contains_key(&from) {
+                    // step 3: the dealer answers the complaints against it
+                    if dealers.contains(&self.index) {
+                        let answer = self.dealing.answers[from as usize - 1];
+                        outgoing.push(Outgoing::Broadcast(DkgMessage::Answer(answer)));
+                    }
+                    self.complaints.insert(from, dealers);
+                }
+            }
+            DkgMessage::Answer(share) => {
+                self.answers.entry((from, share.index)).or_insert(share);
+            }
+        }
+        outgoing.extend(self.progress());
+        outgoing
+    }
+
+    // step 2 once the party has the shares of every dealer, QUAL once it has every complaint and its answer
+    fn progress(&mut self) -> Vec<Outgoing<DkgMessage>> {
+        let dealers = 1..=self.n() as u64;
+        if !self.complained {
+            if !dealers.clone().all(|dealer| {
+                self.commitments.contains_key(&dealer) && self.shares.contains_key(&dealer)
+            }) {
+                return vec![];
+            }
+            let invalid: Vec<u64> = dealers
+                .filter(|dealer| {
+                    let commitments = self.commitments[dealer].clone();
+                    let share = self.shares[dealer];
+                    !self.on_share(*dealer, &commitments, &share)
+                })
+                .collect();
+            self.complained = true;
+            return vec![Outgoing::Broadcast(DkgMessage::Complaints(invalid))];
+        }
+        if self.qualified.is_some() || self.complaints.len() < self.n() {
+            return vec![];
+        }
+        let complaints: Vec<(u64, u64)> = self
+            .complaints
+            .iter()
+            .flat_map(|(complainer, dealers)| dealers.iter().map(|dealer| (*dealer, *complainer)))
+            .collect();
+        if !complaints.iter().all(|key| self.answers.contains_key(key)) {
+            return vec![];
+        }
+        // everybody checks the answers against the commitments, the complainer adopts the valid ones
+        let mut disqualified = vec![];
+        for (dealer, complainer) in complaints {
+            let commitments = self.commitments[&dealer].clone();
+            let answer = self.answers[&(dealer, complainer)];
+            let valid = match complainer == self.index {
+                true => self.on_share(dealer, &commitments, &answer),
+                false => answer.index == complainer && feldman_verify(&commitments, &answer),
+            };
+            if !valid {
+                disqualified.push(dealer);
+            }
+        }
+        self.qualified = Some(
+            (1..=self.n() as u64)
+                .filter(|dealer| !disqualified.contains(dealer))
+                .collect(),
+        );
+        vec![]
+    }
+}
+
+impl Party<DkgMessage> for DkgParty {
+    // step 1: deal
+    fn start(&mut self, _rng: &mut ChaChaRng) -> Vec<Outgoing<DkgMessage>> {
+        let mut outgoing = vec![Outgoing::Broadcast(DkgMessage::Commitments(
+            self.dealing.commitments.clone(),
+        ))];
+        outgoing.extend(
+            self.dealing
+                .shares
+                .iter()
+                .map(|share| Outgoing::To(node(share.index), DkgMessage::Share(*share))),
+        );
+        outgoing
+    }
+
+    fn receive(
+        &mut self,
+        from: PartyId,
+        message: DkgMessage,
+        _rng: &mut ChaChaRng,
+    ) -> Vec<Outgoing<DkgMessage>> {
+        self.handle(from as u64 + 1, message)
+    }
+}
+
+// the three steps of the protocol, run until the network is quiet: returns the qualified dealers, the same
+// for every party
+pub fn run_dkg(parties: &mut [DkgParty], simnet: &mut Simnet<DkgMessage>) -> Vec<u64> {
+    let mut nodes: Vec<&mut dyn Party<DkgMessage>> = parties
+        .iter_mut()
+        .map(|party| party as &mut dyn Party<DkgMessage>)
+        .collect();
+    simnet.run(&mut nodes);
+    let qualified = parties[0]
+        .qualified
+        .clone()
+        .expect("the DKG did not complete");
+    assert!(parties
+        .iter()
+        .all(|party| party.qualified.as_ref() == Some(&qualified)));
+    qualified
+}
+
+pub fn schnorr_challenge(r: &Affine, public_key: &Affine, m: &[u8]) -> Fr {
+    let mut bytes = Vec::new();
+    (*r, *public_key).serialize_compressed(&mut bytes).unwrap();
+    let digest = Sha256::new()
+        .chain_update(b"toy-threshold-schnorr")
+        .chain_update(bytes)
+        .chain_update(m)
+        .finalize();
+    Fr::from_be_bytes_mod_order(&digest)
+}
+
+pub fn schnorr_verify(public_key: &Affine, m: &[u8], signature: &SchnorrSignature) -> bool {
+    let c = schnorr_challenge(&signature.r, public_key, m);
+    Affine::generator().mul(signature.s) == signature.r + public_key.mul(c)
+}
+
+// the two rounds of signing among the parties `signers`: the nonces, then the partial signatures
+pub fn threshold_sign<R: Rng>(signers: &[DkgOutput], m: &[u8], rng: &mut R) -> SchnorrSignature {
+    let indices: Vec<u64> = signers.iter().map(|output| output.share.index).collect();
+    let nonces: Vec<Fr> = signers.iter().map(|_| Fr::rand(rng)).collect();
+    let r = nonces
+        .iter()
+        .map(|k| Affine::generator().mul(k))
+        .sum::<Projective>()
+        .into_affine();
+    let s = signers
+        .iter()
+        .zip(&nonces)
+        .map(|(output, nonce)| partial_sign(output, &indices, nonce, &r, m))
+        .sum();
+    SchnorrSignature { r, s }
+}
@@ -0,0 +1,127 @@
+use runner::lang::{Catalog, Lang, Statements};
+
+pub const CATALOG: Catalog = Catalog {
+    lang: Lang::Fr,
+    statements: Statements::Table(&[
+        ("Q1", "écrire `aggregate` qui calcule la sortie comme le haché SHA-256 des valeurs révélées, dans l'ordre des identifiants des participants"),
+        ("Q2", "écrire `finalize` qui\n- vérifie chaque ouverture contre l'engagement de son participant\n- calcule la sortie à partir des seules ouvertures valides, pour que la balise produise toujours une sortie\n- confisque le dépôt des participants qui n'ont pas révélé d'ouverture valide\n- rembourse aux révélateurs honnêtes leur dépôt plus une part égale des dépôts confisqués\n(ce qui ne peut être partagé également est brûlé)"),
+        ("Q3", "quel est le dépôt minimal, en fonction du gain, qui rend le broyage non rentable ?"),
+        ("Q4", "avec k participants de connivence qui révèlent en dernier, combien de bits de la sortie peuvent-ils biaiser ?\nPourquoi les fonctions à délai vérifiable (VDF) suppriment-elles complètement le problème ?"),
+        ("Q5", "écrire `PsiClient::request` qui masque chaque élément de l'ensemble du client (garder les masques !)"),
+        ("Q6", "écrire `PsiServer::respond` qui évalue l'OPRF sur les points masqués, et envoie\nles sorties de la PRF de ses propres éléments dans un ordre aléatoire"),
+        ("Q7", "écrire `PsiClient::intersect` qui démasque les évaluations et renvoie les éléments\ndont la sortie de la PRF a été envoyée par le serveur"),
+        ("Q8", "le client peut relancer le protocole avec n'importe quel ensemble : qu'apprend-il après\nde nombreuses exécutions avec des ensembles d'un seul élément ? Comment le serveur peut-il le limiter ?"),
+        ("Q9", "écrire `OtSender::setup` et `OtReceiver::choose`, les deux premiers messages"),
+        ("Q10", "écrire `OtSender::transfer` qui chiffre les deux messages, et `OtReceiver::output` qui déchiffre le sien"),
+        ("Q11", "un receveur malveillant choisit B comme il l'entend : peut-il le choisir de façon à connaître\nles logarithmes discrets de B et de B - A ? Pourquoi cela casserait-il l'hypothèse de Diffie-Hellman ?"),
+        ("Q12", "un émetteur malveillant choisit A comme il l'entend : et si A est le point à l'infini ?\n(c'est pourquoi les implémentations vérifient A, voir l'article « The Simplest Protocol for Oblivious Transfer »)"),
+        ("Q13", "écrire `garble_and` qui construit les 4 lignes de la table, ordonnées par les bits de pointeur"),
+        ("Q14", "écrire `evaluate_and` qui déchiffre la seule ligne que Bob peut déchiffrer"),
+        ("Q15", "Alice pourrait brouiller un autre circuit que celui qu'elle a annoncé (par exemple la sortie b) : comment Bob s'en apercevrait-il ?\nPourquoi révéler la correspondance des étiquettes de sortie avant l'évaluation poserait-il problème ?"),
+        ("Q16", "écrire `send` qui paie `amount` à une adresse à usage unique fraîche du destinataire"),
+        ("Q17", "écrire `scan` qui trouve dans le registre les paiements destinés à la clé de scan a et à la clé de dépense B,\net `Recipient::receive` qui retrouve aussi la clé privée de chaque adresse à usage unique"),
+        ("Q18", "pourquoi l'émetteur ne doit-il jamais réutiliser une clé éphémère pour deux paiements au même destinataire ?\nAvec quoi croît le coût du scan pour le destinataire, et comment BIP352 le réduit-il ?"),
+        ("Q19", "écrire `zero_sharing`, les parts d'un polynôme aléatoire de degré threshold - 1 de terme constant 0"),
+        ("Q20", "écrire `refresh` qui simule une époque de rafraîchissement : chaque participant distribue un partage de 0,\net chaque participant ajoute à sa part les sous-parts qu'il a reçues"),
+        ("Q21", "l'adversaire ci-dessus quitte les participants 1 et 2 à la fin de l'époque 0 : pourquoi le rafraîchissement doit-il avoir lieu\n*après* son départ ? Et s'il garde le participant 2 pendant le rafraîchissement, de quels participants voit-il les sous-parts ?"),
+        ("Q22", "le rafraîchissement ci-dessus fait confiance à chaque distributeur : comment un distributeur malveillant peut-il casser le secret, et comment\nles engagements de Feldman sur les coefficients des g_i (avec g_i(0) = 0) l'en empêchent-ils ?"),
+        ("Q23", "écrire `AvssParty::on_send`, qui vérifie la part contre les engagements et fait écho"),
+        ("Q24", "écrire `AvssParty::on_echo` et `AvssParty::on_ready`"),
+        ("Q25", "pourquoi 2f + 1 échos avant d'envoyer Ready ? Pourquoi f + 1 Ready suffisent-ils pour envoyer Ready,\net pourquoi un participant a-t-il besoin de 2f + 1 Ready pour terminer ?"),
+        ("Q26", "écrire `share_hierarchical` et `reconstruct_hierarchical`, qui renvoie None pour les ensembles non autorisés"),
+        ("Q27", "les managers détiennent deux fois plus de matériel secret : pourquoi est-ce inévitable dans cette construction ?\nComment exiger au moins un manager *et* au moins un auditeur ?"),
+        ("Q28", "écrire `share_additive` et `reconstruct_additive`, puis `add_shared` qui additionne deux valeurs partagées"),
+        ("Q29", "écrire `multiply_shared`, qui multiplie deux valeurs partagées avec un triplet du distributeur"),
+        ("Q30", "les participants ouvrent d = x - a et e = y - b : que révèlent deux multiplications avec le même triplet ?\nQue peut obtenir un participant en mentant sur sa part de d ? Comment les MAC de SPDZ l'attrapent-ils ?"),
+        ("Q31", "écrire `feldman_share`, qui renvoie aussi les engagements sur les coefficients du polynôme, et\n`feldman_verify`, qui vérifie une part contre eux"),
+        ("Q32", "une plainte est publique : comment le distributeur y répond-il sans nuire aux autres participants ? Que révèlent\nles engagements sur s, et comment les engagements de Pedersen sG + s'H corrigent-ils cela ?"),
+        ("Q33", "écrire `DkgParty::on_share`, qui garde les parts valides et rejette les autres, et `DkgParty::finish`,\nqui dérive la part du participant dans la clé commune et la clé publique commune à partir des distributeurs qualifiés"),
+        ("Q34", "écrire `partial_sign`, la part s_i d'un signataire dans `threshold_sign`"),
+        ("Q35", "un distributeur qui distribue en dernier voit les engagements des autres et peut se faire disqualifier exprès :\ncomment cela biaise-t-il la clé publique commune ? (Gennaro, Jarecki, Krawczyk et Rabin, 1999)\nPourquoi deux sessions de signature concurrentes avec les nonces ci-dessus seraient-elles dangereuses, et comment les facteurs\nde liaison de FROST l'empêchent-ils ?"),
+        ("Q36", "écrire les signatures adaptatrices : `pre_sign`, `pre_verify`, `adapt` et `extract`"),
+        ("Q37", "écrire `Bob::react`, qui transforme la signature de la réclamation d'Alice, publiée par la chaîne B, en sa\nréclamation des pièces d'Alice sur la chaîne A"),
+        ("Q38", "et si le verrou de Bob expirait après celui d'Alice ? Et si Bob reste hors ligne entre la réclamation d'Alice et TIMEOUT_A ?\nUn HTLC verrouille les deux paiements avec le même haché H(t) : qu'apprend un observateur des deux chaînes ?"),
+    ]),
+    hints: &[
+        (
+            "Q5",
+            &[
+                "Le client doit cacher ses éléments au serveur, et pourtant recevoir k H(x)",
+                "Masquer H(x) avec un scalaire aléatoire r : le serveur voit r H(x), uniformément aléatoire",
+                "Envoyer r H(x) et garder r : l'évaluation k r H(x) se démasque avec r^-1",
+            ],
+        ),
+        (
+            "Q8",
+            &[
+                "Chaque exécution dit au client si son unique élément est dans l'ensemble du serveur",
+                "De nombreuses exécutions testent l'ensemble du serveur élément par élément",
+                "Limiter le débit et renouveler la clé bornent le nombre d'éléments qu'un client peut tester",
+            ],
+        ),
+        (
+            "Q15",
+            &[
+                "Bob ne peut pas savoir d'après les tables quelle porte elles implémentent",
+                "Alice brouille plusieurs copies du circuit, et Bob lui demande d'en ouvrir certaines",
+                "Couper-et-choisir : Bob vérifie les copies ouvertes et évalue les autres",
+            ],
+        ),
+        (
+            "Q24",
+            &[
+                "Un participant envoie Ready après 2f + 1 échos ou f + 1 Ready, et termine après 2f + 1 Ready",
+                "Les messages Send, Echo et Ready peuvent arriver dans n'importe quel ordre",
+                "Le message Send peut arriver après que le participant a terminé : il doit encore enregistrer sa part",
+            ],
+        ),
+        (
+            "Q29",
+            &[
+                "Chaque participant calcule localement sa part de d = x - a et de e = y - b, puis les deux parts sont ouvertes",
+                "xy = (d + a)(e + b) = de + db + ea + ab : seul de n'est pas une fonction linéaire des parts",
+                "Le participant i renvoie c_i + d b_i + e a_i, et seul le participant 0 ajoute de",
+            ],
+        ),
+        (
+            "Q31",
+            &[
+                "Partager comme `share_secret`, mais garder les coefficients : les engagements sont les a_k G",
+                "f(i) G = sum a_k i^k G = sum C_k i^k : le membre de droite n'a besoin que des engagements publics",
+                "Évaluer sum C_k i^k avec la méthode de Horner sur les points, en partant de Projective::zero()",
+            ],
+        ),
+        (
+            "Q33",
+            &[
+                "Une part est valide si elle est adressée à ce participant et passe `feldman_verify`",
+                "Un participant peut voir un distributeur deux fois : la part privée, puis la réponse publique à sa plainte",
+                "x_j est la somme des parts des distributeurs qualifiés, X la somme de leurs premiers engagements",
+            ],
+        ),
+        (
+            "Q34",
+            &[
+                "sum lambda_i x_i sur les signataires vaut x : les signatures partielles s'additionnent en k + cx",
+                "lambda_i = prod_{j in S, j != i} j / (j - i), comme dans `reconstruct`",
+                "c = schnorr_challenge(R, X, m), le même pour chaque signataire",
+            ],
+        ),
+        (
+            "Q36",
+            &[
+                "Le défi d'une pré-signature s'engage déjà sur le point de nonce final R' + T",
+                "pre_verify est la vérification de Schnorr avec R' à la place de R, et c = H(R' + T, X, m)",
+                "adapt ajoute t à s' et T à R', extract soustrait s' de s",
+            ],
+        ),
+        (
+            "Q37",
+            &[
+                "Tout ce dont Bob a besoin est public sur la chaîne B une fois qu'Alice a réclamé",
+                "t = extract(sa propre pré-signature, la signature de la réclamation sur la chaîne B)",
+                "Adapter la pré-signature d'Alice avec t : c'est la signature de sa réclamation sur la chaîne A",
+            ],
+        ),
+    ],
+};
@@ -0,0 +1,69 @@
+use crate::*;
+
+// Yao's garbled circuits, on the smallest circuit there is: a single AND gate
+// Alice (the garbler) holds a bit a, Bob (the evaluator) holds a bit b, they want to compute a AND b
+// and nothing more
+// - Alice picks two random labels per wire, standing for 0 and 1: Bob will only ever see one label per wire
+//   and cannot tell which bit it stands for
+// - for each of the 4 input combinations, she encrypts the output label with both input labels:
+//   E(La, Lb) = H(La, Lb) xor Lc, where Lc is the label of a AND b
+// - point-and-permute: each label carries a random "pointer" bit, opposite for the two labels of a wire,
+//   and the table is ordered by the pointer bits of the input labels, so that Bob knows which row to decrypt
+//   while the row order says nothing about the bits
+// - Alice sends the table and her input label, Bob gets his input label through oblivious transfer
+//   without Alice learning b, then Alice reveals the meaning of the output labels
+
+pub type Label = OtMessage;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Wire {
+    pub labels: [Label; 2], // labels[v] stands for the bit v
+}
+
+pub struct GarbledGate {
+    pub table: [Label; 4], // the row of the input labels (La, Lb) is 2 p(La) + p(Lb)
+}
+
+// the pointer bit is the last bit of the label
+pub fn pointer_bit(label: &Label) -> usize {
+    (label[31] & 1) as usize
+}
+
+impl Wire {
+    pub fn random<R: Rng>(rng: &mut R) -> Wire {
+        let mut labels: [Label; 2] = [rng.gen(), rng.gen()];
+        labels[1][31] = (labels[1][31] & !1) | (1 - pointer_bit(&labels[0]) as u8);
+        Wire { labels }
+    }
+
+    // the bit a label stands for, if any
+    pub fn decode(&self, label: &Label) -> Option<bool> {
+        self.labels.iter().position(|l| l == label).map(|v| v == 1)
+    }
+}
+
+// the one-time pad for the row of (La, Lb)
+pub fn row_key(la: &Label, lb: &Label) -> Label {
+    Sha256::new()
+        .chain_update(b"toy-garbled-and")
+        .chain_update(la)
+        .chain_update(lb)
+        .finalize()
+        .into()
+}
+
+// returns Bob's output and what Bob received from Alice: the table and Alice's label
+pub fn run_garbled_and<R: Rng>(a: bool, b: bool, rng: &mut R) -> (bool, (GarbledGate, Label)) {
+    // Alice garbles
+    let (wire_a, wire_b, wire_c) = (Wire::random(rng), Wire::random(rng), Wire::random(rng));
+    let gate = garble_and(&wire_a, &wire_b, &wire_c);
+    let label_a = wire_a.labels[a as usize];
+    // Bob gets his label with an OT, Alice being the sender
+    let mut sender = OtSender::new(wire_b.labels);
+    let mut receiver = OtReceiver::new(b);
+    let (label_b, _) = run_ot(&mut sender, &mut receiver, rng);
+    // Bob evaluates, Alice decodes the output label for him
+    let label_c = evaluate_and(&gate, &label_a, &label_b);
+    let output = wire_c.decode(&label_c).expect("invalid output label");
+    (output, (gate, label_a))
+}
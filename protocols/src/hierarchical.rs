@@ -0,0 +1,45 @@
+use crate::*;
+
+// Hierarchical access structure: the secret is recovered by any `threshold` parties among which at least
+// `manager_threshold` are managers, e.g. 4 employees including 2 managers for a company wallet
+// Plain Shamir cannot express it, since it treats all the shares alike, but access structures compose:
+// - AND: split s = s_1 + s_2 and share s_1 and s_2 with the two access structures
+// - the parties of the inner structure may be a subset of all the parties (here the managers)
+// so we share s_1 with a threshold-of-n Shamir among all the parties, and s_2 with a
+// manager_threshold-of-m Shamir among the m managers, which hold two shares each
+// (Tassa's hierarchical threshold sharing gives a single share to everyone, managers receiving
+// evaluations of derivatives of the polynomial and reconstruction using Birkhoff interpolation)
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HierarchicalShare {
+    pub share: Share,                 // of s_1, among all the parties
+    pub manager_share: Option<Share>, // of s_2, among the managers only
+}
+
+pub struct AccessStructure {
+    pub n: usize,
+    pub threshold: usize,
+    pub managers: Vec<u64>, // the indices of the managers, in 1..=n
+    pub manager_threshold: usize,
+}
+
+impl AccessStructure {
+    pub fn is_authorized(&self, parties: &[u64]) -> bool {
+        let managers = parties
+            .iter()
+            .filter(|party| self.managers.contains(party))
+            .count();
+        parties.len() >= self.threshold && managers >= self.manager_threshold
+    }
+}
+
+// all the sets of parties, as lists of indices
+pub fn all_subsets(n: usize) -> Vec<Vec<u64>> {
+    (0..1u32 << n)
+        .map(|bits| {
+            (1..=n as u64)
+                .filter(|i| bits >> (i - 1) & 1 == 1)
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,90 @@
+use runner::hints::Hints;
+use runner::lang::Catalog;
+
+pub const CATALOGS: &[Catalog] = &[
+    Catalog::english(HINTS, include_str!("main.rs")),
+    crate::fr::CATALOG,
+];
+
+pub const HINTS: Hints = &[
+    (
+        "Q5",
+        &[
+            "The client must hide its elements from the server, yet get k H(x) back",
+            "Blind H(x) with a random scalar r: the server sees r H(x), which is uniformly random",
+            "Send r H(x) and keep r: the evaluation k r H(x) is unblinded with r^-1",
+        ],
+    ),
+    (
+        "Q8",
+        &[
+            "Each run tells the client whether its single element is in the server's set",
+            "Many runs test the server's set element by element",
+            "Rate limiting and key rotation bound the number of elements a client can test",
+        ],
+    ),
+    (
+        "Q15",
+        &[
+            "Bob cannot tell from the tables which gate they implement",
+            "Alice garbles several copies of the circuit, and Bob asks her to open some of them",
+            "Cut-and-choose: Bob checks the opened copies and evaluates the others",
+        ],
+    ),
+    (
+        "Q24",
+        &[
+            "A party sends Ready after 2f + 1 echoes or f + 1 readies, and completes after 2f + 1 readies",
+            "Send, Echo and Ready messages may arrive in any order",
+            "The Send message may arrive after the party completed: it must then still record its share",
+        ],
+    ),
+    (
+        "Q29",
+        &[
+            "Each party computes its share of d = x - a and e = y - b locally, then both shares are opened",
+            "xy = (d + a)(e + b) = de + db + ea + ab: only de is not a linear function of the shares",
+            "Party i outputs c_i + d b_i + e a_i, and party 0 alone adds de",
+        ],
+    ),
+    (
+        "Q31",
+        &[
+            "Share as `share_secret` does, but keep the coefficients: the commitments are a_k G",
+            "f(i) G = sum a_k i^k G = sum C_k i^k: the right-hand side only needs the public commitments",
+            "Evaluate sum C_k i^k with Horner's rule on points, starting from Projective::zero()",
+        ],
+    ),
+    (
+        "Q33",
+        &[
+            "A share is valid if it is addressed to this party and passes `feldman_verify`",
+            "A party may see a dealer twice: the private share, then the public answer to its complaint",
+            "x_j is the sum of the shares of the qualified dealers, X the sum of their first commitments",
+        ],
+    ),
+    (
+        "Q34",
+        &[
+            "sum lambda_i x_i over the signers is x: the partial signatures add up to k + cx",
+            "lambda_i = prod_{j in S, j != i} j / (j - i), as in `reconstruct`",
+            "c = schnorr_challenge(R, X, m), the same for every signer",
+        ],
+    ),
+    (
+        "Q36",
+        &[
+            "The challenge of a pre-signature already commits to the final nonce point R' + T",
+            "pre_verify is the Schnorr verification with R' in place of R, and c = H(R' + T, X, m)",
+            "adapt adds t to s' and T to R', extract subtracts s' from s",
+        ],
+    ),
+    (
+        "Q37",
+        &[
+            "Everything Bob needs is public on chain B once Alice claimed",
+            "t = extract(his own pre-signature, the claim signature on chain B)",
+            "Adapt Alice's pre-signature with t: this is the signature of his claim on chain A",
+        ],
+    ),
+];
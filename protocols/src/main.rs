@@ -0,0 +1,1357 @@
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{Field, PrimeField};
+use ark_secp256k1::{Affine, Fq, Fr, Projective};
+use ark_serialize::CanonicalSerialize;
+use ark_std::{ops::Mul, UniformRand, Zero};
+use avss::*;
+use beacon::*;
+use beaver::*;
+use dkg::*;
+use garbled::*;
+use hierarchical::*;
+use oprf::*;
+use ot::*;
+use psi::*;
+use ptlc::*;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+use runner::Runner;
+use sha2::{Digest, Sha256};
+use shamir::*;
+use simnet::*;
+use stealth::*;
+
+mod avss;
+mod beacon;
+mod beaver;
+mod dkg;
+mod fr;
+mod garbled;
+mod hierarchical;
+mod hints;
+mod oprf;
+mod ot;
+mod psi;
+mod ptlc;
+mod shamir;
+mod simnet;
+mod stealth;
+
+const PARTIES: usize = 5;
+const DEPOSIT: u64 = 200;
+const PRIZE: u64 = 100;
+
+fn main() {
+    let runner = Runner::start(env!("CARGO_PKG_NAME"), hints::CATALOGS);
+    let mut rng = seed::rng();
+
+    // Take a look at module `beacon`
+    // n parties run a commit-reveal protocol to produce a public random value everybody agrees on
+    // `run_beacon` plays the protocol, with the adversarial parties acting last, and settles it with `finalize`
+
+    // Q1: write `aggregate` which computes the output as the SHA-256 hash of the revealed values, ordered by party id
+    // Q2: write `finalize` which
+    // - checks every opening against the commitment of its party
+    // - computes the output from the valid openings only, so that the beacon always produces an output
+    // - slashes the parties which did not reveal a valid opening
+    // - pays back the honest revealers their deposit plus an equal share of the slashed deposits
+    //   (what cannot be shared equally is burnt)
+    runner.questions(&["Q1", "Q2"]);
+    let mut parties: Vec<BeaconParty> = (0..PARTIES)
+        .map(|id| BeaconParty::new(id, Strategy::Honest))
+        .collect();
+    let outcome = run_beacon(&mut parties, DEPOSIT, &mut rng);
+    let reveals: Vec<(usize, Reveal)> = parties
+        .iter()
+        .map(|party| (party.id, party.secret.unwrap()))
+        .collect();
+    assert_eq!(outcome.output, aggregate(&reveals));
+    assert!(outcome.slashed.is_empty());
+    assert_eq!(outcome.payouts, vec![DEPOSIT; PARTIES]);
+
+    // The output depends on every single contribution
+    let mut modified = reveals.clone();
+    modified[3].1.value[31] ^= 1;
+    assert_ne!(aggregate(&modified), outcome.output);
+
+    // Now let's see how `finalize` handles adversarial parties
+    let share = DEPOSIT / (PARTIES as u64 - 1);
+    for strategy in [
+        Strategy::Withhold,
+        Strategy::WrongOpening,
+        Strategy::Copycat(0),
+    ] {
+        let mut parties: Vec<BeaconParty> = (0..PARTIES)
+            .map(|id| BeaconParty::new(id, Strategy::Honest))
+            .collect();
+        parties[2].strategy = strategy;
+        let outcome = run_beacon(&mut parties, DEPOSIT, &mut rng);
+        assert_eq!(outcome.slashed, vec![2], "{:?} must be slashed", strategy);
+        let expected: Vec<u64> = (0..PARTIES)
+            .map(|id| if id == 2 { 0 } else { DEPOSIT + share })
+            .collect();
+        assert_eq!(outcome.payouts, expected);
+        // the output only uses the honest contributions
+        let honest: Vec<(usize, Reveal)> = parties
+            .iter()
+            .filter(|party| party.id != 2)
+            .map(|party| (party.id, party.secret.unwrap()))
+            .collect();
+        assert_eq!(outcome.output, aggregate(&honest));
+    }
+
+    // Several cheaters share nothing
+    let mut parties: Vec<BeaconParty> = (0..PARTIES)
+        .map(|id| BeaconParty::new(id, Strategy::Honest))
+        .collect();
+    parties[0].strategy = Strategy::Withhold;
+    parties[4].strategy = Strategy::Copycat(1);
+    let outcome = run_beacon(&mut parties, DEPOSIT, &mut rng);
+    assert_eq!(outcome.slashed, vec![0, 4]);
+    let total: u64 = outcome.payouts.iter().sum();
+    assert!(total <= PARTIES as u64 * DEPOSIT);
+    assert_eq!(outcome.payouts[1], DEPOSIT + 2 * DEPOSIT / 3);
+
+    // Finally, a grinding party wins a prize whenever the output is "lucky" (first bit 0)
+    // By withholding its opening when the output is unlucky, it wins much more often than half of the time...
+    let runs = 400;
+    let mut wins = 0;
+    let mut balance: i64 = 0;
+    for _ in 0..runs {
+        let mut parties: Vec<BeaconParty> = (0..PARTIES)
+            .map(|id| BeaconParty::new(id, Strategy::Honest))
+            .collect();
+        parties[PARTIES - 1].strategy = Strategy::Grind;
+        let outcome = run_beacon(&mut parties, DEPOSIT, &mut rng);
+        if is_lucky(&outcome.output) {
+            wins += 1;
+            balance += PRIZE as i64;
+        }
+        balance += outcome.payouts[PARTIES - 1] as i64 - DEPOSIT as i64;
+    }
+    println!(
+        "The grinder got a lucky output in {}/{} runs, for a net gain of {} (an honest party expects {})\n",
+        wins,
+        runs,
+        balance,
+        runs * PRIZE / 2
+    );
+    assert!(wins > runs * 65 / 100);
+    // ... but the slashing makes it a losing strategy
+    assert!(balance < (runs * PRIZE / 2) as i64);
+
+    // Q3: what is the minimal deposit, as a function of the prize, that makes grinding unprofitable?
+    // Q4: with k colluding parties revealing last, how many bits of the output can they bias?
+    // Why do verifiable delay functions (VDFs) remove the problem altogether?
+    runner.questions(&["Q3", "Q4"]);
+
+    // Now take a look at modules `oprf` and `psi`
+    // Two companies want to find their common customers without disclosing their customer lists
+    let client_set = [
+        "alice@example.com",
+        "bob@example.com",
+        "carol@example.com",
+        "dave@example.com",
+        "erin@example.com",
+    ];
+    let server_set = [
+        "bob@example.com",
+        "frank@example.com",
+        "erin@example.com",
+        "grace@example.com",
+        "heidi@example.com",
+        "alice@example.org",
+    ];
+    let mut client = PsiClient::new(&client_set);
+    let mut server = PsiServer::new(&server_set, &mut rng);
+
+    // Q5: write `PsiClient::request` which blinds every element of the client's set (keep the blinds!)
+    // Q6: write `PsiServer::respond` which evaluates the OPRF on the blinded points, and sends
+    // the PRF outputs of its own elements in a random order
+    // Q7: write `PsiClient::intersect` which unblinds the evaluations and outputs the elements
+    // whose PRF output was sent by the server
+    runner.questions(&["Q5", "Q6", "Q7"]);
+    let (intersection, transcript) = run_psi(&mut client, &mut server, &mut rng);
+    let expected: Vec<Vec<u8>> = ["bob@example.com", "erin@example.com"]
+        .iter()
+        .map(|x| x.as_bytes().to_vec())
+        .collect();
+    assert_eq!(intersection, expected);
+
+    // Let's inspect the transcript
+    let Message::Request(request) = &transcript[0] else {
+        panic!("the client speaks first");
+    };
+    let Message::Response {
+        evaluations,
+        server_outputs,
+    } = &transcript[1]
+    else {
+        panic!("the server answers");
+    };
+    assert_eq!(request.len(), client_set.len());
+    assert_eq!(evaluations.len(), client_set.len());
+    assert_eq!(server_outputs.len(), server_set.len());
+    // - the server only sees blinded points: neither H1(x) nor F_k(x) for the client's elements
+    for x in &client_set {
+        let h = hash_to_curve(x.as_bytes());
+        assert!(!request.contains(&h));
+        assert!(!evaluations.contains(&server.oprf.evaluate(&h)));
+    }
+    // - and a fresh run of the protocol gives unrelated blinded points
+    let (_, other_transcript) = run_psi(&mut client, &mut server, &mut rng);
+    let Message::Request(other_request) = &other_transcript[0] else {
+        panic!("the client speaks first");
+    };
+    assert!(request.iter().all(|b| !other_request.contains(b)));
+    // - the client only sees pseudorandom outputs for the server's elements, in a random order
+    //   without the key, she cannot test whether "grace@example.com" is one of them
+    let outputs_in_order: Vec<[u8; 32]> = server_set
+        .iter()
+        .map(|y| server.oprf.prf(y.as_bytes()))
+        .collect();
+    assert_ne!(*server_outputs, outputs_in_order);
+    for y in &server_set {
+        assert!(server_outputs.contains(&server.oprf.prf(y.as_bytes())));
+        let unkeyed = oprf_finalize(y.as_bytes(), &hash_to_curve(y.as_bytes()));
+        assert!(!server_outputs.contains(&unkeyed));
+    }
+
+    // Q8: the client can run the protocol again with any set she likes: what does she learn after
+    // many runs with sets of size one? How can the server limit this?
+    runner.question("Q8");
+
+    // Now take a look at module `ot`
+    // Oblivious transfer is the building block of secure two-party computation (see garbled circuits)
+    // Q9: write `OtSender::setup` and `OtReceiver::choose`, the first two messages
+    // Q10: write `OtSender::transfer` which encrypts both messages, and `OtReceiver::output` which decrypts hers
+    runner.questions(&["Q9", "Q10"]);
+    let messages: [OtMessage; 2] = rng.gen();
+    for choice in [false, true] {
+        let mut sender = OtSender::new(messages);
+        let mut receiver = OtReceiver::new(choice);
+        let (output, (a, b, ciphertexts)) = run_ot(&mut sender, &mut receiver, &mut rng);
+        // the receiver learns exactly the message she chose...
+        assert_eq!(output, messages[choice as usize]);
+        // ... and her key does not open the other ciphertext
+        let (b_secret, _, _) = receiver.secret.unwrap();
+        let key = ot_key(&a, &b, &a.mul(b_secret).into_affine());
+        assert_ne!(
+            xor(&ciphertexts[!choice as usize], &key),
+            messages[!choice as usize]
+        );
+        // changing her mind after sending B does not help her: the decryption of the other message fails
+        let wrong_choice = OtReceiver {
+            choice: !choice,
+            secret: receiver.secret,
+        };
+        assert_ne!(
+            wrong_choice.output(&ciphertexts),
+            messages[!choice as usize]
+        );
+        // the ciphertexts are not the messages
+        assert!(ciphertexts.iter().all(|e| !messages.contains(e)));
+        // B alone does not reveal the choice: bG and A + bG are both uniformly random points
+        // (the sender can't tell which of B and B - A the receiver knows the discrete log of)
+    }
+
+    // The protocol is not a one-shot: each transfer uses fresh randomness on both sides
+    let mut sender = OtSender::new(messages);
+    let mut receiver = OtReceiver::new(true);
+    let (_, (a1, b1, _)) = run_ot(&mut sender, &mut receiver, &mut rng);
+    let (_, (a2, b2, _)) = run_ot(&mut sender, &mut receiver, &mut rng);
+    assert_ne!(a1, a2);
+    assert_ne!(b1, b2);
+
+    // Q11: a malicious receiver picks B as she likes: can she choose it so that she knows
+    // the discrete logs of both B and B - A? Why would this break the Diffie-Hellman assumption?
+    // Q12: a malicious sender picks A as she likes: what if A is the point at infinity?
+    // (this is why implementations check A, see the paper "The Simplest Protocol for Oblivious Transfer")
+    runner.questions(&["Q11", "Q12"]);
+
+    // Now take a look at module `garbled`, a teaser for secure two-party computation
+    // Q13: write `garble_and` which builds the 4 rows of the table, ordered by the pointer bits
+    // Q14: write `evaluate_and` which decrypts the only row Bob can decrypt
+    runner.questions(&["Q13", "Q14"]);
+    for (a, b) in [(false, false), (false, true), (true, false), (true, true)] {
+        let (output, (gate, label_a)) = run_garbled_and(a, b, &mut rng);
+        assert_eq!(output, a && b, "{} AND {}", a, b);
+        // none of the rows hides an input label, and Alice's label is not the table
+        assert!(!gate.table.contains(&label_a));
+    }
+
+    // The position of the row encrypting the 1 output is random, so the table doesn't leak anything
+    let mut positions = [0; 4];
+    for _ in 0..100 {
+        let (wire_a, wire_b, wire_c) = (
+            Wire::random(&mut rng),
+            Wire::random(&mut rng),
+            Wire::random(&mut rng),
+        );
+        let gate = garble_and(&wire_a, &wire_b, &wire_c);
+        let row = 2 * pointer_bit(&wire_a.labels[1]) + pointer_bit(&wire_b.labels[1]);
+        positions[row] += 1;
+        // Bob holding one label per wire can decrypt exactly one row: the other ones are garbage
+        let (label_a, label_b) = (wire_a.labels[1], wire_b.labels[0]);
+        let decryptions: Vec<Option<bool>> = gate
+            .table
+            .iter()
+            .map(|row| wire_c.decode(&xor(row, &row_key(&label_a, &label_b))))
+            .collect();
+        assert_eq!(decryptions.iter().filter(|d| d.is_some()).count(), 1);
+        assert!(decryptions.contains(&Some(false)));
+    }
+    assert!(positions.iter().all(|&count| count > 10));
+
+    // Q15: Alice could garble a different circuit than the one she announced (e.g. output b): how would Bob notice?
+    // Why would leaking the output labels mapping before the evaluation be a problem?
+    runner.question("Q15");
+
+    // Now take a look at module `stealth`
+    // Q16: write `send` which pays `amount` to a fresh one-time address of the recipient
+    // Q17: write `scan` which finds the payments to the scan key a and spend key B on the ledger,
+    // and `Recipient::receive` which also recovers the private key of each one-time address
+    runner.questions(&["Q16", "Q17"]);
+    let alice = Recipient::new(&mut rng);
+    let bob = Recipient::new(&mut rng);
+    let mut ledger = vec![];
+    for (recipient, amount) in [
+        (&alice, 10),
+        (&bob, 20),
+        (&alice, 30),
+        (&alice, 40),
+        (&bob, 50),
+    ] {
+        ledger.push(send(&recipient.meta_address(), amount, &mut rng));
+    }
+    let received = alice.receive(&ledger);
+    let indices: Vec<usize> = received.iter().map(|(index, _)| *index).collect();
+    assert_eq!(indices, vec![0, 2, 3]);
+    // Alice can spend each payment: she knows the private key of the one-time address
+    for (index, sk) in &received {
+        assert_eq!(
+            Affine::generator().mul(sk).into_affine(),
+            ledger[*index].one_time
+        );
+    }
+    let balance: u64 = received
+        .iter()
+        .map(|(index, _)| ledger[*index].amount)
+        .sum();
+    assert_eq!(balance, 80);
+    assert_eq!(bob.receive(&ledger).len(), 2);
+
+    // A watch-only wallet holding the scan key and the public spend key finds the same payments
+    let meta = alice.meta_address();
+    assert_eq!(scan(alice.scan_key.expose(), &meta.spend, &ledger), indices);
+    // but the one-time keys are not H(aR) alone, so it can't spend them
+    for &index in &indices {
+        let partial = shared_secret(
+            &ledger[index]
+                .ephemeral
+                .mul(alice.scan_key.expose())
+                .into_affine(),
+        );
+        assert_ne!(
+            Affine::generator().mul(partial).into_affine(),
+            ledger[index].one_time
+        );
+    }
+
+    // A third party only sees the meta-addresses and the ledger
+    // - no payment goes to a published key, and the one-time addresses are all distinct
+    for payment in &ledger {
+        for recipient in [&alice, &bob] {
+            let meta = recipient.meta_address();
+            assert!(payment.one_time != meta.scan && payment.one_time != meta.spend);
+        }
+    }
+    for (i, first) in ledger.iter().enumerate() {
+        assert!(ledger[i + 1..].iter().all(|p| p.one_time != first.one_time));
+    }
+    // - guessing the scan key is the only way to link payments: a wrong one finds nothing
+    let eve = Recipient::new(&mut rng);
+    assert!(scan(eve.scan_key.expose(), &meta.spend, &ledger).is_empty());
+    // - paying twice to the same meta-address gives unrelated one-time addresses
+    let first = send(&meta, 1, &mut rng);
+    let second = send(&meta, 1, &mut rng);
+    assert_ne!(first.one_time, second.one_time);
+    assert_ne!(first.ephemeral, second.ephemeral);
+
+    // Q18: why must the sender never reuse an ephemeral key for two payments to the same recipient?
+    // What does the recipient's scanning cost grow with, and how does BIP352 reduce it?
+    runner.question("Q18");
+
+    // Now take a look at module `shamir`
+    // Q19: write `zero_sharing`, the shares of a random polynomial of degree threshold - 1 with constant term 0
+    // Q20: write `refresh` which simulates one refresh epoch: every party deals a sharing of 0,
+    // and every party adds the sub-shares it received to its share
+    runner.questions(&["Q19", "Q20"]);
+    let threshold = 3;
+    let secret = Fr::rand(&mut rng);
+    let epoch0 = share_secret(secret, threshold, PARTIES, &mut rng);
+    assert_eq!(reconstruct(&epoch0[..threshold]), secret);
+    let zeros = zero_sharing(threshold, PARTIES, &mut rng);
+    assert_eq!(reconstruct(&zeros[1..threshold + 1]), Fr::from(0));
+    assert!(zeros.iter().all(|share| share.value != Fr::from(0)));
+
+    let epoch1 = refresh(&epoch0, threshold, &mut rng);
+    let epoch2 = refresh(&epoch1, threshold, &mut rng);
+    for shares in [&epoch1, &epoch2] {
+        // the secret is unchanged, whatever t shares are used...
+        assert_eq!(reconstruct(&shares[..threshold]), secret);
+        assert_eq!(reconstruct(&shares[PARTIES - threshold..]), secret);
+        assert_eq!(reconstruct(shares), secret);
+        // ... but every share has changed
+        assert!(shares
+            .iter()
+            .zip(&epoch0)
+            .all(|(new, old)| new.index == old.index && new.value != old.value));
+    }
+    // shares from different epochs don't combine
+    assert_ne!(reconstruct(&[epoch0[0], epoch0[1], epoch1[2]]), secret);
+    assert_ne!(reconstruct(&[epoch1[0], epoch2[3], epoch2[4]]), secret);
+
+    // A mobile adversary corrupts t - 1 parties per epoch, but different ones at each epoch:
+    // parties 1 and 2 during epoch 0, then parties 3 and 4 during epoch 1
+    // With 4 shares she would know the secret without refresh, here no 3 of them recover it
+    let stolen = [epoch0[0], epoch0[1], epoch1[2], epoch1[3]];
+    for skipped in 0..stolen.len() {
+        let subset: Vec<Share> = (0..stolen.len())
+            .filter(|&i| i != skipped)
+            .map(|i| stolen[i])
+            .collect();
+        assert_ne!(reconstruct(&subset), secret);
+    }
+
+    // Q21: the adversary above leaves parties 1 and 2 at the end of epoch 0: why must the refresh happen
+    // *after* she leaves? What if she keeps party 2 during the refresh, and which parties' sub-shares does she see?
+    // Q22: the refresh above trusts every dealer: how can a malicious dealer break the secret, and how do
+    // Feldman commitments to the coefficients of g_i (with g_i(0) = 0) prevent it?
+    runner.questions(&["Q21", "Q22"]);
+
+    // Now take a look at modules `simnet` and `avss`, an advanced one
+    // Q23: write `AvssParty::on_send`, which checks the share against the commitments and echoes
+    // Q24: write `AvssParty::on_echo` and `AvssParty::on_ready`
+    runner.questions(&["Q23", "Q24"]);
+    let (f, n) = (1, 4);
+    let new_parties = || {
+        (0..n)
+            .map(|id| AvssParty::new(id, n, f))
+            .collect::<Vec<_>>()
+    };
+    let secret = Fr::rand(&mut rng);
+    let (commitments, shares) = deal(secret, f, n, &mut rng);
+    let fingerprint = commitments_fingerprint(&commitments);
+    let honest_dealing: Vec<(Vec<Affine>, Fr)> = shares
+        .iter()
+        .map(|share| (commitments.clone(), *share))
+        .collect();
+
+    // validity, over a lossy network which reorders and duplicates messages
+    let lossy = SimnetConfig {
+        latency: (1, 10),
+        drop_rate: 0.3,
+        duplicate_rate: 0.1,
+    };
+    for seed in 0..20 {
+        let mut parties = new_parties();
+        let mut simnet = Simnet::new(lossy, seed);
+        run_avss(&mut parties, honest_dealing.clone(), &mut simnet);
+        let expected = (0..n).map(|j| {
+            Some(AvssOutput {
+                commitments: fingerprint,
+                share: Some(shares[j]),
+            })
+        });
+        assert!(parties.iter().map(|p| p.output.clone()).eq(expected));
+        // any f + 1 shares recover the secret
+        let recovered: Vec<Share> = parties[n - f - 1..]
+            .iter()
+            .map(|p| Share {
+                index: p.id as u64 + 1,
+                value: p.output.as_ref().unwrap().share.unwrap(),
+            })
+            .collect();
+        assert_eq!(reconstruct(&recovered), secret);
+    }
+
+    // validity, with f crashed parties
+    let mut parties = new_parties();
+    let mut simnet = Simnet::new(lossy, 42);
+    simnet.crashed.push(2);
+    run_avss(&mut parties, honest_dealing.clone(), &mut simnet);
+    assert!(parties[2].output.is_none());
+    for party in parties.iter().filter(|p| p.id != 2) {
+        assert_eq!(party.output.as_ref().unwrap().share, Some(shares[party.id]));
+    }
+    println!(
+        "AVSS: {} messages delivered, {} dropped and retransmitted",
+        simnet.delivered, simnet.dropped
+    );
+
+    // a Byzantine party echoes and readies for commitments the dealer never sent
+    let mut parties = new_parties();
+    let mut simnet = Simnet::new(lossy, 44);
+    simnet.byzantine.insert(
+        3,
+        Box::new(|_, _, message| match message {
+            AvssMessage::Echo(_) => Some(AvssMessage::Echo([0xff; 32])),
+            AvssMessage::Ready(_) => Some(AvssMessage::Ready([0xff; 32])),
+            other => Some(other),
+        }),
+    );
+    run_avss(&mut parties, honest_dealing.clone(), &mut simnet);
+    for party in parties.iter().filter(|p| p.id != 3) {
+        assert_eq!(party.output.as_ref().unwrap().commitments, fingerprint);
+    }
+
+    // a dishonest dealer sends a wrong share to party 1: the others complete without him
+    // and party 1 knows it has to ask for help to recover its share
+    let mut dealing = honest_dealing.clone();
+    dealing[1].1 += Fr::from(1);
+    let mut parties = new_parties();
+    run_avss(&mut parties, dealing, &mut Simnet::new(lossy, 43));
+    for party in &parties {
+        let output = party.output.as_ref().unwrap();
+        assert_eq!(output.commitments, fingerprint);
+        assert_eq!(output.share.is_some(), party.id != 1);
+    }
+
+    // agreement: a dishonest dealer equivocates, sending different commitments to different parties
+    let (other_commitments, other_shares) = deal(secret, f, n, &mut rng);
+    for split in 1..n {
+        let dealing: Vec<(Vec<Affine>, Fr)> = (0..n)
+            .map(|j| {
+                if j < split {
+                    (commitments.clone(), shares[j])
+                } else {
+                    (other_commitments.clone(), other_shares[j])
+                }
+            })
+            .collect();
+        let mut parties = new_parties();
+        run_avss(&mut parties, dealing, &mut Simnet::new(lossy, split as u64));
+        let outputs: Vec<Fingerprint> = parties
+            .iter()
+            .filter_map(|p| p.output.as_ref().map(|o| o.commitments))
+            .collect();
+        // either nobody completes, or everybody completes with the same commitments
+        assert!(
+            outputs.is_empty() || (outputs.len() == n && outputs.iter().all(|d| *d == outputs[0]))
+        );
+    }
+
+    // Q25: why 2f + 1 echoes before sending Ready? Why is f + 1 readies enough to send Ready,
+    // and why does a party need 2f + 1 readies to complete?
+    runner.question("Q25");
+
+    // Back to secret sharing, take a look at module `hierarchical`
+    // Q26: write `share_hierarchical` and `reconstruct_hierarchical`, which returns None for unauthorized sets
+    runner.question("Q26");
+    let structure = AccessStructure {
+        n: 7,
+        threshold: 4,
+        managers: vec![1, 2, 3],
+        manager_threshold: 2,
+    };
+    let secret = Fr::rand(&mut rng);
+    let shares = share_hierarchical(secret, &structure, &mut rng);
+    assert_eq!(shares.len(), structure.n);
+    for (share, index) in shares.iter().zip(1..) {
+        assert_eq!(share.share.index, index);
+        assert_eq!(
+            share.manager_share.is_some(),
+            structure.managers.contains(&index)
+        );
+    }
+    let mut authorized_sets = 0;
+    for parties in all_subsets(structure.n) {
+        let subset: Vec<HierarchicalShare> = parties
+            .iter()
+            .map(|index| shares[*index as usize - 1])
+            .collect();
+        let recovered = reconstruct_hierarchical(&subset, &structure);
+        if structure.is_authorized(&parties) {
+            assert_eq!(recovered, Some(secret));
+            authorized_sets += 1;
+        } else {
+            assert_eq!(recovered, None);
+        }
+    }
+    assert_eq!(authorized_sets, 48);
+    // 4 employees but a single manager: plain Shamir on their shares recovers s_1, which is not the secret
+    let employees: Vec<Share> = [1, 4, 5, 6].iter().map(|i| shares[i - 1].share).collect();
+    assert_ne!(reconstruct(&employees), secret);
+    // Q27: the managers hold twice as much secret material: why is it unavoidable in this construction?
+    // How would you require at least one manager *and* at least one auditor?
+    runner.question("Q27");
+
+    // Secure computation on shares, take a look at module `beaver`
+    // Q28: write `share_additive` and `reconstruct_additive`, then `add_shared` which adds two shared values
+    runner.question("Q28");
+    for _ in 0..10 {
+        let x = Fr::rand(&mut rng);
+        let y = Fr::rand(&mut rng);
+        let shares = share_additive(x, &mut rng);
+        assert_eq!(reconstruct_additive(&shares), x);
+        // a fresh sharing each time: a share alone is uniformly random
+        assert_ne!(share_additive(x, &mut rng)[0], shares[0]);
+        let sum = add_shared(&shares, &share_additive(y, &mut rng));
+        assert_eq!(reconstruct_additive(&sum), x + y);
+    }
+
+    // Q29: write `multiply_shared`, which multiplies two shared values with a triple of the dealer
+    runner.question("Q29");
+    let mut dealer = Dealer::new();
+    for _ in 0..10 {
+        let x = Fr::rand(&mut rng);
+        let y = Fr::rand(&mut rng);
+        let triple = dealer.triple(&mut rng);
+        let product = multiply_shared(
+            &share_additive(x, &mut rng),
+            &share_additive(y, &mut rng),
+            &triple,
+        );
+        assert_eq!(reconstruct_additive(&product), x * y);
+    }
+    // party 0 inputs x, party 1 inputs y and z, and together they compute (x + y) z + xy, with two triples
+    let mut dealer = Dealer::new();
+    let (x, y, z) = (Fr::from(6u64), Fr::from(7u64), Fr::from(-3i64));
+    let [x_shared, y_shared, z_shared] = [x, y, z].map(|input| share_additive(input, &mut rng));
+    let sum = add_shared(&x_shared, &y_shared);
+    let first = multiply_shared(&sum, &z_shared, &dealer.triple(&mut rng));
+    let second = multiply_shared(&x_shared, &y_shared, &dealer.triple(&mut rng));
+    let result = add_shared(&first, &second);
+    assert_eq!(reconstruct_additive(&result), Fr::from(3u64));
+    assert_eq!(dealer.triples, 2);
+    // a dealer handing out c = ab + 1 shifts the product without anybody noticing
+    let mut bad_triple = dealer.triple(&mut rng);
+    bad_triple.c[1] += Fr::from(1u64);
+    let product = multiply_shared(&x_shared, &y_shared, &bad_triple);
+    assert_eq!(reconstruct_additive(&product), x * y + Fr::from(1u64));
+
+    // Q30: the parties open d = x - a and e = y - b: what do two multiplications with the same triple reveal?
+    // What can a party achieve by lying about its share of d? How do the MACs of SPDZ catch it?
+    runner.question("Q30");
+
+    // Back to module `shamir`, for its verifiable version
+    // Q31: write `feldman_share`, which also returns the commitments to the coefficients of the polynomial, and
+    // `feldman_verify`, which checks a share against them
+    runner.question("Q31");
+    let (threshold, n) = (3, 5);
+    let secret = Fr::rand(&mut rng);
+    let (commitments, shares) = feldman_share(secret, threshold, n, &mut rng);
+    assert_eq!(commitments.len(), threshold);
+    assert_eq!(
+        commitments[0],
+        Affine::generator().mul(secret).into_affine()
+    );
+    assert_eq!(shares.len(), n);
+    assert!(shares
+        .iter()
+        .all(|share| feldman_verify(&commitments, share)));
+    assert_eq!(reconstruct(&shares[1..1 + threshold]), secret);
+    // a share checked under the index of another party does not verify
+    let misplaced = Share {
+        index: shares[1].index,
+        value: shares[0].value,
+    };
+    assert!(!feldman_verify(&commitments, &misplaced));
+    // a cheating dealer sends a bad share to party 3: only its recipient complains
+    let mut cheated = shares.clone();
+    cheated[2].value += Fr::from(1u64);
+    let complaints: Vec<u64> = cheated
+        .iter()
+        .filter(|share| !feldman_verify(&commitments, share))
+        .map(|share| share.index)
+        .collect();
+    assert_eq!(complaints, vec![3]);
+    // another one shares with a polynomial of a higher degree than committed: every party complains
+    let coefficients: Vec<Fr> = (0..=threshold).map(|_| Fr::rand(&mut rng)).collect();
+    let truncated: FeldmanCommitments = coefficients[..threshold]
+        .iter()
+        .map(|a| Affine::generator().mul(a).into_affine())
+        .collect();
+    assert!((1..=n as u64).all(|index| {
+        let share = Share {
+            index,
+            value: evaluate(&coefficients, Fr::from(index)),
+        };
+        !feldman_verify(&truncated, &share)
+    }));
+
+    // Q32: a complaint is public: how does the dealer answer it without hurting the other parties? What do the
+    // commitments reveal about s, and how do Pedersen's commitments sG + s'H fix it?
+    runner.question("Q32");
+
+    // The multi-party capstone, take a look at module `dkg`
+    // Q33: write `DkgParty::on_share`, which keeps the valid shares and rejects the others, and `DkgParty::finish`,
+    // which derives the party's share of the joint key and the joint public key from the qualified dealers
+    runner.question("Q33");
+    let (threshold, n) = (3, 5);
+    let dkg = |strategies: &[DealerStrategy], rng: &mut ChaChaRng| {
+        let dealings: Vec<Dealing> = (1..=n as u64)
+            .zip(strategies)
+            .map(|(dealer, strategy)| Dealing::new(dealer, *strategy, threshold, n, rng))
+            .collect();
+        let mut parties: Vec<DkgParty> = dealings.iter().cloned().map(DkgParty::new).collect();
+        let qualified = run_dkg(&mut parties, &mut Simnet::new(lossy, rng.gen()));
+        let outputs: Vec<DkgOutput> = parties.iter().map(|p| p.finish(&qualified)).collect();
+        (dealings, qualified, outputs)
+    };
+    let mut dkg_rng = ChaChaRng::from_rng(&mut rng).unwrap();
+    let honest = [DealerStrategy::Honest; 5];
+    // dealer 2 cheats party 4 but reveals a valid share, dealer 5 cheats party 1 twice and is disqualified
+    let mut cheating = honest;
+    cheating[1] = DealerStrategy::BadShare(4);
+    cheating[4] = DealerStrategy::BadAnswer(1);
+    for (strategies, expected) in [(honest, vec![1, 2, 3, 4, 5]), (cheating, vec![1, 2, 3, 4])] {
+        let (dealings, qualified, outputs) = dkg(&strategies, &mut dkg_rng);
+        assert_eq!(qualified, expected);
+        let public_key = qualified
+            .iter()
+            .map(|dealer| dealings[*dealer as usize - 1].commitments[0])
+            .sum::<Projective>()
+            .into_affine();
+        for (output, index) in outputs.iter().zip(1..) {
+            assert_eq!(output.share.index, index);
+            assert_eq!(output.public_key, public_key);
+        }
+        // any t parties recover the joint key, fewer do not
+        let shares: Vec<Share> = outputs.iter().map(|output| output.share).collect();
+        for parties in all_subsets(n) {
+            let subset: Vec<Share> = parties.iter().map(|i| shares[*i as usize - 1]).collect();
+            let recovered = Affine::generator().mul(reconstruct(&subset)).into_affine();
+            assert_eq!(recovered == public_key, parties.len() >= threshold);
+        }
+    }
+    // a party never keeps a share which does not match its dealer's commitments
+    let (commitments, shares) = feldman_share(Fr::rand(&mut rng), threshold, n, &mut rng);
+    let mut party = DkgParty::new(Dealing::new(
+        2,
+        DealerStrategy::Honest,
+        threshold,
+        n,
+        &mut rng,
+    ));
+    let bad = Share {
+        index: 2,
+        value: shares[1].value + Fr::from(1u64),
+    };
+    assert!(!party.on_share(1, &commitments, &bad));
+    assert!(party.received.is_empty());
+    assert!(party.on_share(1, &commitments, &shares[1]));
+    assert_eq!(party.received[&1].1, shares[1].value);
+
+    // Q34: write `partial_sign`, the share s_i of a signer in `threshold_sign`
+    runner.question("Q34");
+    let (_, _, outputs) = dkg(&cheating, &mut dkg_rng);
+    let public_key = outputs[0].public_key;
+    let m = b"signed by 3 of 5";
+    for parties in all_subsets(n) {
+        let signers: Vec<DkgOutput> = parties.iter().map(|i| outputs[*i as usize - 1]).collect();
+        let signature = threshold_sign(&signers, m, &mut rng);
+        assert_eq!(
+            schnorr_verify(&public_key, m, &signature),
+            parties.len() >= threshold
+        );
+        if parties.len() >= threshold {
+            assert!(!schnorr_verify(&public_key, b"another message", &signature));
+        }
+    }
+
+    // Q35: a dealer who deals last sees the others' commitments and can get itself disqualified on purpose:
+    // how does this bias the joint public key? (Gennaro, Jarecki, Krawczyk and Rabin, 1999)
+    // Why would two concurrent signing sessions with the nonces above be dangerous, and how do the binding
+    // factors of FROST prevent it?
+    runner.question("Q35");
+
+    // Take a look at module `ptlc`
+    // Q36: write the adaptor signatures: `pre_sign`, `pre_verify`, `adapt` and `extract`
+    runner.question("Q36");
+    let x = Fr::rand(&mut rng);
+    let public_key = Affine::generator().mul(x).into_affine();
+    let t = Fr::rand(&mut rng);
+    let adaptor = Affine::generator().mul(t).into_affine();
+    let m = b"pay 3 coins to alice";
+    let pre_signature = pre_sign(&x, m, &adaptor, &mut rng);
+    assert!(pre_verify(&public_key, m, &adaptor, &pre_signature));
+    assert!(!pre_verify(
+        &public_key,
+        b"pay 4 coins to alice",
+        &adaptor,
+        &pre_signature
+    ));
+    assert!(!pre_verify(&public_key, m, &public_key, &pre_signature));
+    // a pre-signature is not a signature...
+    let incomplete = SchnorrSignature {
+        r: pre_signature.r,
+        s: pre_signature.s,
+    };
+    assert!(!schnorr_verify(&public_key, m, &incomplete));
+    // ... until it is adapted with t, which the signature then reveals
+    let signature = adapt(&pre_signature, &t);
+    assert!(schnorr_verify(&public_key, m, &signature));
+    assert_eq!(signature.r, (pre_signature.r + adaptor).into_affine());
+    assert_eq!(extract(&pre_signature, &signature), t);
+    assert!(!schnorr_verify(
+        &public_key,
+        m,
+        &adapt(&pre_signature, &(t + Fr::from(1u64)))
+    ));
+
+    // Q37: write `Bob::react`, which turns the signature of Alice's claim, published by chain B, into his claim of
+    // her coins on chain A
+    runner.question("Q37");
+    let swapped = |chains: &(Chain, Chain)| {
+        let (a, b) = chains;
+        (
+            a.balance("alice"),
+            a.balance("bob"),
+            b.balance("alice"),
+            b.balance("bob"),
+        )
+    };
+    let done = (0, ALICE_AMOUNT, BOB_AMOUNT, 0);
+    let undone = (ALICE_AMOUNT, 0, 0, BOB_AMOUNT);
+    for (scenario, expected) in [
+        // the messages take a tick each: both locks are on their chains by tick 4
+        (SwapScenario::AliceClaimsAt(5), done),
+        // at the last moment on chain B, Bob still has until TIMEOUT_A on chain A
+        (SwapScenario::AliceClaimsAt(TIMEOUT_B - 1), done),
+        // too late: Bob gets his coins back, and so does Alice
+        (SwapScenario::AliceClaimsAt(TIMEOUT_B), undone),
+        (SwapScenario::AliceNeverClaims, undone),
+        (SwapScenario::BobNeverLocks, undone),
+        (SwapScenario::BobBadPreSignature, undone),
+    ] {
+        let chains = run_swap(scenario, &mut rng);
+        assert_eq!(swapped(&chains), expected, "{:?}", scenario);
+        // the coins of each chain stay on their chain, nothing remains locked
+        let (a, b) = &chains;
+        for lock in a.lock.iter().chain(b.lock.iter()) {
+            assert!(lock.claim.is_some() != lock.refunded);
+        }
+    }
+
+    // Q38: what if Bob's lock expired after Alice's? What if Bob stays offline between Alice's claim and TIMEOUT_A?
+    // An HTLC locks both payments with the same hash H(t): what does an observer of both chains learn?
+    runner.question("Q38");
+
+    println!("Good job!");
+    runner.finish();
+}
+
+fn aggregate(reveals: &[(usize, Reveal)]) -> [u8; 32] {
+    // SOLUTION-BEGIN
+    let mut sorted = reveals.to_vec();
+    sorted.sort_by_key(|(id, _)| *id);
+    sorted
+        .iter()
+        .fold(Sha256::new(), |hasher, (_, reveal)| {
+            hasher.chain_update(reveal.value)
+        })
+        .finalize()
+        .into()
+    // SOLUTION-END
+}
+
+fn finalize(commitments: &[Commitment], reveals: &[Option<Reveal>], deposit: u64) -> BeaconOutcome {
+    // SOLUTION-BEGIN
+    let valid: Vec<(usize, Reveal)> = reveals
+        .iter()
+        .enumerate()
+        .filter_map(|(id, reveal)| reveal.map(|reveal| (id, reveal)))
+        .filter(|(id, reveal)| commit_value(*id, reveal) == commitments[*id])
+        .collect();
+    let slashed: Vec<usize> = (0..commitments.len())
+        .filter(|id| !valid.iter().any(|(valid_id, _)| valid_id == id))
+        .collect();
+    let bonus = match valid.len() {
+        0 => 0,
+        n => slashed.len() as u64 * deposit / n as u64,
+    };
+    let payouts = (0..commitments.len())
+        .map(|id| {
+            if slashed.contains(&id) {
+                0
+            } else {
+                deposit + bonus
+            }
+        })
+        .collect();
+    BeaconOutcome {
+        output: aggregate(&valid),
+        slashed,
+        payouts,
+    }
+    // SOLUTION-END
+}
+
+impl PsiClient {
+    pub fn request<R: Rng>(&mut self, rng: &mut R) -> Vec<Affine> {
+        // SOLUTION-BEGIN
+        let (blinds, blinded) = self.set.iter().map(|x| blind(x, rng)).unzip();
+        self.blinds = blinds;
+        blinded
+        // SOLUTION-END
+    }
+
+    pub fn intersect(&self, evaluations: &[Affine], server_outputs: &[[u8; 32]]) -> Vec<Vec<u8>> {
+        // SOLUTION-BEGIN
+        self.set
+            .iter()
+            .zip(&self.blinds)
+            .zip(evaluations)
+            .filter(|((x, r), evaluation)| {
+                server_outputs.contains(&oprf_finalize(x, &unblind(r, evaluation)))
+            })
+            .map(|((x, _), _)| x.clone())
+            .collect()
+        // SOLUTION-END
+    }
+}
+
+impl PsiServer {
+    pub fn respond<R: Rng>(&self, request: &[Affine], rng: &mut R) -> (Vec<Affine>, Vec<[u8; 32]>) {
+        // SOLUTION-BEGIN
+        let evaluations = request.iter().map(|b| self.oprf.evaluate(b)).collect();
+        let mut outputs: Vec<[u8; 32]> = self.set.iter().map(|y| self.oprf.prf(y)).collect();
+        outputs.shuffle(rng);
+        (evaluations, outputs)
+        // SOLUTION-END
+    }
+}
+
+impl OtSender {
+    pub fn setup<R: Rng>(&mut self, rng: &mut R) -> Affine {
+        // SOLUTION-BEGIN
+        let a = Fr::rand(rng);
+        let a_point = Affine::generator().mul(a).into_affine();
+        self.secret = Some((a, a_point));
+        a_point
+        // SOLUTION-END
+    }
+
+    pub fn transfer(&self, b: Affine) -> [OtMessage; 2] {
+        // SOLUTION-BEGIN
+        let (a, a_point) = self.secret.unwrap();
+        let k0 = ot_key(&a_point, &b, &b.mul(a).into_affine());
+        let k1 = ot_key(
+            &a_point,
+            &b,
+            &(b.into_group() - a_point).mul(a).into_affine(),
+        );
+        [xor(&self.messages[0], &k0), xor(&self.messages[1], &k1)]
+        // SOLUTION-END
+    }
+}
+
+impl OtReceiver {
+    pub fn choose<R: Rng>(&mut self, a: Affine, rng: &mut R) -> Affine {
+        // SOLUTION-BEGIN
+        let b = Fr::rand(rng);
+        let b_point = Affine::generator().mul(b);
+        let b_point = if self.choice {
+            (b_point + a).into_affine()
+        } else {
+            b_point.into_affine()
+        };
+        self.secret = Some((b, a, b_point));
+        b_point
+        // SOLUTION-END
+    }
+
+    pub fn output(&self, ciphertexts: &[OtMessage; 2]) -> OtMessage {
+        // SOLUTION-BEGIN
+        let (b, a_point, b_point) = self.secret.unwrap();
+        let key = ot_key(&a_point, &b_point, &a_point.mul(b).into_affine());
+        xor(&ciphertexts[self.choice as usize], &key)
+        // SOLUTION-END
+    }
+}
+
+fn garble_and(wire_a: &Wire, wire_b: &Wire, wire_c: &Wire) -> GarbledGate {
+    // SOLUTION-BEGIN
+    let mut table = [[0u8; 32]; 4];
+    for a in 0..2 {
+        for b in 0..2 {
+            let (la, lb) = (&wire_a.labels[a], &wire_b.labels[b]);
+            let row = 2 * pointer_bit(la) + pointer_bit(lb);
+            table[row] = xor(&wire_c.labels[a & b], &row_key(la, lb));
+        }
+    }
+    GarbledGate { table }
+    // SOLUTION-END
+}
+
+fn evaluate_and(gate: &GarbledGate, label_a: &Label, label_b: &Label) -> Label {
+    // SOLUTION-BEGIN
+    let row = 2 * pointer_bit(label_a) + pointer_bit(label_b);
+    xor(&gate.table[row], &row_key(label_a, label_b))
+    // SOLUTION-END
+}
+
+fn send<R: Rng>(meta: &MetaAddress, amount: u64, rng: &mut R) -> Payment {
+    // SOLUTION-BEGIN
+    let r = Fr::rand(rng);
+    let ephemeral = Affine::generator().mul(r).into_affine();
+    let t = shared_secret(&meta.scan.mul(r).into_affine());
+    Payment {
+        ephemeral,
+        one_time: tweak::tweak_add(&meta.spend, &t).expect("invalid one-time address"),
+        amount,
+    }
+    // SOLUTION-END
+}
+
+fn scan(scan_key: &Fr, spend: &Affine, ledger: &[Payment]) -> Vec<usize> {
+    // SOLUTION-BEGIN
+    ledger
+        .iter()
+        .enumerate()
+        .filter(|(_, payment)| {
+            let t = shared_secret(&payment.ephemeral.mul(scan_key).into_affine());
+            tweak::tweak_add(spend, &t) == Ok(payment.one_time)
+        })
+        .map(|(index, _)| index)
+        .collect()
+    // SOLUTION-END
+}
+
+impl Recipient {
+    pub fn receive(&self, ledger: &[Payment]) -> Vec<(usize, Fr)> {
+        // SOLUTION-BEGIN
+        let spend = self.meta_address().spend;
+        scan(self.scan_key.expose(), &spend, ledger)
+            .into_iter()
+            .map(|index| {
+                let shared = ledger[index]
+                    .ephemeral
+                    .mul(self.scan_key.expose())
+                    .into_affine();
+                let t = shared_secret(&shared);
+                let key = tweak::tweak_add_private(self.spend_key.expose(), &t).unwrap();
+                (index, key)
+            })
+            .collect()
+        // SOLUTION-END
+    }
+}
+
+fn zero_sharing<R: Rng>(threshold: usize, n: usize, rng: &mut R) -> Vec<Share> {
+    // SOLUTION-BEGIN
+    share_secret(Fr::from(0), threshold, n, rng)
+    // SOLUTION-END
+}
+
+fn refresh<R: Rng>(shares: &[Share], threshold: usize, rng: &mut R) -> Vec<Share> {
+    // SOLUTION-BEGIN
+    // dealt[i][j] is the sub-share sent by party i to party j
+    let dealt: Vec<Vec<Share>> = shares
+        .iter()
+        .map(|_| zero_sharing(threshold, shares.len(), rng))
+        .collect();
+    shares
+        .iter()
+        .enumerate()
+        .map(|(j, share)| Share {
+            index: share.index,
+            value: share.value
+                + dealt
+                    .iter()
+                    .map(|sub_shares| sub_shares[j].value)
+                    .sum::<Fr>(),
+        })
+        .collect()
+    // SOLUTION-END
+}
+
+fn share_hierarchical<R: Rng>(
+    secret: Fr,
+    structure: &AccessStructure,
+    rng: &mut R,
+) -> Vec<HierarchicalShare> {
+    // SOLUTION-BEGIN
+    // s = s_1 + s_2
+    let s2 = Fr::rand(rng);
+    let shares = share_secret(secret - s2, structure.threshold, structure.n, rng);
+    let manager_shares = share_secret(
+        s2,
+        structure.manager_threshold,
+        structure.managers.len(),
+        rng,
+    );
+    shares
+        .into_iter()
+        .map(|share| HierarchicalShare {
+            share,
+            manager_share: structure
+                .managers
+                .iter()
+                .position(|manager| *manager == share.index)
+                .map(|position| manager_shares[position]),
+        })
+        .collect()
+    // SOLUTION-END
+}
+
+fn reconstruct_hierarchical(
+    shares: &[HierarchicalShare],
+    structure: &AccessStructure,
+) -> Option<Fr> {
+    // SOLUTION-BEGIN
+    let parties: Vec<u64> = shares.iter().map(|share| share.share.index).collect();
+    if !structure.is_authorized(&parties) {
+        return None;
+    }
+    let s1: Vec<Share> = shares.iter().map(|share| share.share).collect();
+    let s2: Vec<Share> = shares
+        .iter()
+        .filter_map(|share| share.manager_share)
+        .collect();
+    Some(reconstruct(&s1) + reconstruct(&s2))
+    // SOLUTION-END
+}
+
+impl AvssParty {
+    pub fn on_send(
+        &mut self,
+        from: usize,
+        commitments: Vec<Affine>,
+        share: Fr,
+    ) -> Vec<AvssMessage> {
+        // SOLUTION-BEGIN
+        // only the dealer sends shares, and only once
+        if from != self.n || self.commitments.is_some() {
+            return vec![];
+        }
+        let fingerprint = commitments_fingerprint(&commitments);
+        let valid = verify_share(&commitments, self.id as u64 + 1, &share);
+        self.commitments = Some((fingerprint, commitments));
+        if !valid {
+            return vec![];
+        }
+        self.share = Some(share);
+        if let Some(output) = self.output.as_mut() {
+            if output.commitments == fingerprint {
+                output.share = Some(share);
+            }
+        }
+        if self.sent_echo {
+            return vec![];
+        }
+        self.sent_echo = true;
+        vec![AvssMessage::Echo(fingerprint)]
+        // SOLUTION-END
+    }
+
+    pub fn on_echo(&mut self, from: usize, fingerprint: Fingerprint) -> Vec<AvssMessage> {
+        // SOLUTION-BEGIN
+        if from >= self.n {
+            return vec![];
+        }
+        let echoes = self.echoes.entry(fingerprint).or_default();
+        echoes.insert(from);
+        if echoes.len() > 2 * self.f && !self.sent_ready {
+            self.sent_ready = true;
+            return vec![AvssMessage::Ready(fingerprint)];
+        }
+        vec![]
+        // SOLUTION-END
+    }
+
+    pub fn on_ready(&mut self, from: usize, fingerprint: Fingerprint) -> Vec<AvssMessage> {
+        // SOLUTION-BEGIN
+        if from >= self.n {
+            return vec![];
+        }
+        let readies = self.readies.entry(fingerprint).or_default();
+        readies.insert(from);
+        let count = readies.len();
+        if count > 2 * self.f && self.output.is_none() {
+            let share = match &self.commitments {
+                Some((received, _)) if *received == fingerprint => self.share,
+                _ => None,
+            };
+            self.output = Some(AvssOutput {
+                commitments: fingerprint,
+                share,
+            });
+        }
+        if count > self.f && !self.sent_ready {
+            self.sent_ready = true;
+            return vec![AvssMessage::Ready(fingerprint)];
+        }
+        vec![]
+        // SOLUTION-END
+    }
+}
+
+fn share_additive<R: Rng>(x: Fr, rng: &mut R) -> Shares {
+    // SOLUTION-BEGIN
+    let x_0 = Fr::rand(rng);
+    [x_0, x - x_0]
+    // SOLUTION-END
+}
+
+fn reconstruct_additive(shares: &Shares) -> Fr {
+    // SOLUTION-BEGIN
+    shares[0] + shares[1]
+    // SOLUTION-END
+}
+
+fn add_shared(x: &Shares, y: &Shares) -> Shares {
+    // SOLUTION-BEGIN
+    [x[0] + y[0], x[1] + y[1]]
+    // SOLUTION-END
+}
+
+fn multiply_shared(x: &Shares, y: &Shares, triple: &BeaverTriple) -> Shares {
+    // SOLUTION-BEGIN
+    // each party masks its shares, then both open d and e
+    let d = reconstruct_additive(&[x[0] - triple.a[0], x[1] - triple.a[1]]);
+    let e = reconstruct_additive(&[y[0] - triple.b[0], y[1] - triple.b[1]]);
+    let z = |i: usize| triple.c[i] + d * triple.b[i] + e * triple.a[i];
+    [z(0) + d * e, z(1)]
+    // SOLUTION-END
+}
+
+fn feldman_share<R: Rng>(
+    secret: Fr,
+    threshold: usize,
+    n: usize,
+    rng: &mut R,
+) -> (FeldmanCommitments, Vec<Share>) {
+    // SOLUTION-BEGIN
+    let mut coefficients = vec![secret];
+    coefficients.extend((1..threshold).map(|_| Fr::rand(rng)));
+    let commitments = coefficients
+        .iter()
+        .map(|a| Affine::generator().mul(a).into_affine())
+        .collect();
+    let shares = (1..=n as u64)
+        .map(|index| Share {
+            index,
+            value: evaluate(&coefficients, Fr::from(index)),
+        })
+        .collect();
+    (commitments, shares)
+    // SOLUTION-END
+}
+
+fn feldman_verify(commitments: &FeldmanCommitments, share: &Share) -> bool {
+    // SOLUTION-BEGIN
+    // sum C_k i^k with Horner's rule
+    let i = Fr::from(share.index);
+    let expected = commitments
+        .iter()
+        .rev()
+        .fold(Projective::zero(), |acc, commitment| acc * i + commitment);
+    Affine::generator().mul(share.value) == expected
+    // SOLUTION-END
+}
+
+impl DkgParty {
+    pub fn on_share(
+        &mut self,
+        dealer: u64,
+        commitments: &FeldmanCommitments,
+        share: &Share,
+    ) -> bool {
+        // SOLUTION-BEGIN
+        if share.index != self.index || !feldman_verify(commitments, share) {
+            return false;
+        }
+        self.received
+            .insert(dealer, (commitments.clone(), share.value));
+        true
+        // SOLUTION-END
+    }
+
+    pub fn finish(&self, qualified: &[u64]) -> DkgOutput {
+        // SOLUTION-BEGIN
+        let value = qualified.iter().map(|dealer| self.received[dealer].1).sum();
+        let public_key = qualified
+            .iter()
+            .map(|dealer| self.received[dealer].0[0])
+            .sum::<Projective>()
+            .into_affine();
+        DkgOutput {
+            share: Share {
+                index: self.index,
+                value,
+            },
+            public_key,
+        }
+        // SOLUTION-END
+    }
+}
+
+fn partial_sign(output: &DkgOutput, signers: &[u64], nonce: &Fr, r: &Affine, m: &[u8]) -> Fr {
+    // SOLUTION-BEGIN
+    let i = Fr::from(output.share.index);
+    let lambda: Fr = signers
+        .iter()
+        .filter(|j| **j != output.share.index)
+        .map(|j| {
+            let j = Fr::from(*j);
+            j * (j - i).inverse().unwrap()
+        })
+        .product();
+    let c = schnorr_challenge(r, &output.public_key, m);
+    *nonce + c * lambda * output.share.value
+    // SOLUTION-END
+}
+
+fn pre_sign<R: Rng>(secret_key: &Fr, m: &[u8], adaptor: &Affine, rng: &mut R) -> PreSignature {
+    // SOLUTION-BEGIN
+    let k = Fr::rand(rng);
+    let r = Affine::generator().mul(k).into_affine();
+    let public_key = Affine::generator().mul(secret_key).into_affine();
+    let c = schnorr_challenge(&(r + adaptor).into_affine(), &public_key, m);
+    PreSignature {
+        r,
+        s: k + c * secret_key,
+    }
+    // SOLUTION-END
+}
+
+fn pre_verify(
+    public_key: &Affine,
+    m: &[u8],
+    adaptor: &Affine,
+    pre_signature: &PreSignature,
+) -> bool {
+    // SOLUTION-BEGIN
+    let c = schnorr_challenge(&(pre_signature.r + adaptor).into_affine(), public_key, m);
+    Affine::generator().mul(pre_signature.s) == pre_signature.r + public_key.mul(c)
+    // SOLUTION-END
+}
+
+fn adapt(pre_signature: &PreSignature, t: &Fr) -> SchnorrSignature {
+    // SOLUTION-BEGIN
+    SchnorrSignature {
+        r: (pre_signature.r + Affine::generator().mul(t)).into_affine(),
+        s: pre_signature.s + t,
+    }
+    // SOLUTION-END
+}
+
+fn extract(pre_signature: &PreSignature, signature: &SchnorrSignature) -> Fr {
+    // SOLUTION-BEGIN
+    signature.s - pre_signature.s
+    // SOLUTION-END
+}
+
+impl Bob {
+    pub fn react(&self, signature: &SchnorrSignature) -> Option<SchnorrSignature> {
+        // SOLUTION-BEGIN
+        let (own, alice) = (self.own_pre_signature?, self.alice_pre_signature?);
+        let t = extract(&own, signature);
+        Some(adapt(&alice, &t))
+        // SOLUTION-END
+    }
+}
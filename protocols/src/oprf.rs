@@ -0,0 +1,66 @@
+use crate::*;
+
+// Diffie-Hellman oblivious pseudorandom function (the 2HashDH construction)
+// F_k(x) = H2(x, k H1(x)) where H1 hashes to the curve and H2 to bytes
+// The client learns F_k(x) without learning k, and the server learns nothing about x:
+// - the client blinds its input as B = r H1(x) for a random scalar r
+// - the server answers with k B
+// - the client unblinds it with r^-1 to get k H1(x)
+
+pub struct OprfServer {
+    pub key: Fr,
+}
+
+// "try-and-increment" hash to curve (not constant-time, see RFC 9380 for better)
+pub fn hash_to_curve(x: &[u8]) -> Affine {
+    for counter in 0u32.. {
+        let digest = Sha256::new()
+            .chain_update(b"toy-oprf-hash-to-curve")
+            .chain_update(x)
+            .chain_update(counter.to_be_bytes())
+            .finalize();
+        let candidate = Fq::from_be_bytes_mod_order(&digest);
+        if let Some(point) = Affine::get_point_from_x_unchecked(candidate, false) {
+            return point;
+        }
+    }
+    unreachable!()
+}
+
+// H2(x, k H1(x))
+pub fn oprf_finalize(x: &[u8], evaluation: &Affine) -> [u8; 32] {
+    let mut point = Vec::new();
+    evaluation.serialize_compressed(&mut point).unwrap();
+    Sha256::new()
+        .chain_update(b"toy-oprf-finalize")
+        .chain_update((x.len() as u64).to_be_bytes())
+        .chain_update(x)
+        .chain_update(point)
+        .finalize()
+        .into()
+}
+
+pub fn blind<R: Rng>(x: &[u8], rng: &mut R) -> (Fr, Affine) {
+    let r = Fr::rand(rng);
+    (r, hash_to_curve(x).mul(r).into_affine())
+}
+
+pub fn unblind(r: &Fr, evaluation: &Affine) -> Affine {
+    evaluation.mul(r.inverse().unwrap()).into_affine()
+}
+
+impl OprfServer {
+    pub fn new<R: Rng>(rng: &mut R) -> OprfServer {
+        OprfServer { key: Fr::rand(rng) }
+    }
+
+    // the oblivious evaluation, on blinded points
+    pub fn evaluate(&self, blinded: &Affine) -> Affine {
+        blinded.mul(self.key).into_affine()
+    }
+
+    // the direct evaluation, on the server's own inputs
+    pub fn prf(&self, x: &[u8]) -> [u8; 32] {
+        oprf_finalize(x, &hash_to_curve(x).mul(self.key).into_affine())
+    }
+}
@@ -0,0 +1,97 @@
+use crate::*;
+use training_common::protocol_sim::{duplex, Channel};
+
+// 1-out-of-2 oblivious transfer: the sender holds two messages m0, m1, the receiver a choice bit c
+// The receiver learns m_c and nothing about m_(1-c), the sender learns nothing about c
+// The "simplest OT" of Chou and Orlandi is a Diffie-Hellman key exchange where the receiver
+// hides her choice in her public key:
+//
+//   Sender(m0, m1)                                   Receiver(c)
+//   a unif. random, A := aG           --A-->
+//                                     <--B--         b unif. random, B := bG if c = 0, A + bG if c = 1
+//   k0 := H(A, B, aB)
+//   k1 := H(A, B, a(B - A))
+//   e0 := m0 xor k0, e1 := m1 xor k1  --e0, e1-->
+//                                                    k_c := H(A, B, bA), m_c := e_c xor k_c
+// B is a uniformly random point whatever c is, and computing the other key a(B - A) (resp. aB)
+// from A and b is the computational Diffie-Hellman problem
+
+pub type OtMessage = [u8; 32];
+
+pub struct OtSender {
+    pub messages: [OtMessage; 2],
+    pub secret: Option<(Fr, Affine)>, // (a, A), from the first message
+}
+
+pub struct OtReceiver {
+    pub choice: bool,
+    pub secret: Option<(Fr, Affine, Affine)>, // (b, A, B), from the second message
+}
+
+// H(A, B, shared point)
+pub fn ot_key(a: &Affine, b: &Affine, shared: &Affine) -> OtMessage {
+    let mut bytes = Vec::new();
+    (*a, *b, *shared).serialize_compressed(&mut bytes).unwrap();
+    Sha256::new()
+        .chain_update(b"toy-simplest-ot")
+        .chain_update(bytes)
+        .finalize()
+        .into()
+}
+
+pub fn xor(x: &OtMessage, y: &OtMessage) -> OtMessage {
+    let mut out = [0u8; 32];
+    for (o, (a, b)) in out.iter_mut().zip(x.iter().zip(y)) {
+        *o = a ^ b;
+    }
+    out
+}
+
+impl OtSender {
+    pub fn new(messages: [OtMessage; 2]) -> OtSender {
+        OtSender {
+            messages,
+            secret: None,
+        }
+    }
+}
+
+impl OtReceiver {
+    pub fn new(choice: bool) -> OtReceiver {
+        OtReceiver {
+            choice,
+            secret: None,
+        }
+    }
+}
+
+// the messages of the protocol, exchanged over a `Channel` of the training-common crate (module `protocol_sim`)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OtMove {
+    Setup(Affine),            // A
+    Choice(Affine),           // B
+    Transfer([OtMessage; 2]), // e0, e1
+}
+
+// the three messages of the protocol, each party only reading and writing its own end of a reliable link
+// returns the receiver's output and the transcript (A, B, [e0, e1])
+pub fn run_ot<R: Rng>(
+    sender: &mut OtSender,
+    receiver: &mut OtReceiver,
+    rng: &mut R,
+) -> (OtMessage, (Affine, Affine, [OtMessage; 2])) {
+    let (mut sender_end, mut receiver_end) = duplex(0, 1);
+    sender_end.send(OtMove::Setup(sender.setup(rng)));
+    let Some(OtMove::Setup(a)) = receiver_end.receive() else {
+        panic!("the receiver expects A")
+    };
+    receiver_end.send(OtMove::Choice(receiver.choose(a, rng)));
+    let Some(OtMove::Choice(b)) = sender_end.receive() else {
+        panic!("the sender expects B")
+    };
+    sender_end.send(OtMove::Transfer(sender.transfer(b)));
+    let Some(OtMove::Transfer(ciphertexts)) = receiver_end.receive() else {
+        panic!("the receiver expects e0, e1")
+    };
+    (receiver.output(&ciphertexts), (a, b, ciphertexts))
+}
@@ -0,0 +1,110 @@
+use crate::*;
+
+// Private set intersection from the DH-OPRF
+// The client learns which of its elements the server also holds, and nothing about the other elements
+// of the server; the server learns nothing (but the size of the client's set)
+//
+//   Client(X)                                     Server(Y, k)
+//   B_x := r_x H1(x) for x in X   --B_x-->
+//                                 <--k B_x, {F_k(y) for y in Y} (shuffled)--
+//   F_k(x) := H2(x, r_x^-1 k B_x)
+//   X inter Y = {x | F_k(x) in {F_k(y)}}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    Request(Vec<Affine>),
+    Response {
+        evaluations: Vec<Affine>,
+        server_outputs: Vec<[u8; 32]>,
+    },
+}
+
+pub struct PsiClient {
+    pub set: Vec<Vec<u8>>,
+    pub blinds: Vec<Fr>, // kept between the request and the response
+    pub intersection: Option<Vec<Vec<u8>>>,
+}
+
+pub struct PsiServer {
+    pub set: Vec<Vec<u8>>,
+    pub oprf: OprfServer,
+}
+
+impl PsiClient {
+    pub fn new(set: &[&str]) -> PsiClient {
+        PsiClient {
+            set: set.iter().map(|x| x.as_bytes().to_vec()).collect(),
+            blinds: vec![],
+            intersection: None,
+        }
+    }
+}
+
+impl PsiServer {
+    pub fn new<R: Rng>(set: &[&str], rng: &mut R) -> PsiServer {
+        PsiServer {
+            set: set.iter().map(|y| y.as_bytes().to_vec()).collect(),
+            oprf: OprfServer::new(rng),
+        }
+    }
+}
+
+// the client is the node 0 and the server the node 1 of the network
+impl Party<Message> for PsiClient {
+    fn start(&mut self, rng: &mut ChaChaRng) -> Vec<Outgoing<Message>> {
+        vec![Outgoing::To(1, Message::Request(self.request(rng)))]
+    }
+
+    fn receive(
+        &mut self,
+        _from: PartyId,
+        message: Message,
+        _rng: &mut ChaChaRng,
+    ) -> Vec<Outgoing<Message>> {
+        if let Message::Response {
+            evaluations,
+            server_outputs,
+        } = message
+        {
+            self.intersection = Some(self.intersect(&evaluations, &server_outputs));
+        }
+        vec![]
+    }
+}
+
+impl Party<Message> for PsiServer {
+    fn receive(
+        &mut self,
+        from: PartyId,
+        message: Message,
+        rng: &mut ChaChaRng,
+    ) -> Vec<Outgoing<Message>> {
+        match message {
+            Message::Request(request) => {
+                let (evaluations, server_outputs) = self.respond(&request, rng);
+                vec![Outgoing::To(
+                    from,
+                    Message::Response {
+                        evaluations,
+                        server_outputs,
+                    },
+                )]
+            }
+            Message::Response { .. } => vec![],
+        }
+    }
+}
+
+// runs the protocol and returns the client's output together with the transcript of all the messages
+pub fn run_psi<R: Rng>(
+    client: &mut PsiClient,
+    server: &mut PsiServer,
+    rng: &mut R,
+) -> (Vec<Vec<u8>>, Vec<Message>) {
+    let mut simnet = Simnet::new(SimnetConfig::default(), rng.gen());
+    simnet.run(&mut [client as &mut dyn Party<Message>, server]);
+    (
+        client.intersection.take().unwrap(),
+        simnet.log.into_messages(),
+    )
+}
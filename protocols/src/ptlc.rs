@@ -0,0 +1,390 @@
+use crate::*;
+use std::collections::HashMap;
+
+// Adaptor signatures: a pre-signature of m under X for an adaptor point T = tG is a Schnorr signature
+// missing t. It can be checked without t, completed with t, and the completed signature reveals t
+// - pre-sign: k random, R' = kG, c = H(R' + T, X, m), s' = k + cx, the pre-signature is (R', s')
+// - pre-verify: s'G = R' + cX, with the same c
+// - adapt: (R' + T, s' + t) is a valid signature of m (see `schnorr_verify` in module `dkg`)
+// - extract: from the pre-signature and the signature, t = s - s'
+//
+// Atomic swap with Point Time-Locked Contracts (PTLC): Alice trades 10 coins on chain A for 3 coins of Bob
+// on chain B. A lock pays its recipient upon a signature of the lock's key, or refunds its owner after a
+// timeout. Alice draws t and sends T, then:
+// 1. Bob pre-signs the claim of his future lock on B for T, Alice pre-signs the claim of hers on A for T,
+//    and each pre-verifies the other's pre-signature
+// 2. Alice locks on A, Bob locks on B with an earlier timeout
+// 3. Alice claims on B: she adapts Bob's pre-signature with t, and publishes the signature on chain B
+// 4. Bob extracts t from the signature on chain B, adapts Alice's pre-signature and claims on A
+// The claim on B is the only way for Alice to get paid, and it hands t to Bob: either both claims happen,
+// or neither and both locks are refunded. Unlike HTLCs, the two chains do not show the same hash either:
+// nobody can link the two payments
+//
+// Alice, Bob, the two chains and a clock are the nodes of the network of module `simnet`. A chain takes
+// locks and claims as messages, and publishes the claims it accepts to everybody. The clock ticks once per
+// unit of time, and a chain refunds an expired lock on the tick of its timeout
+
+pub const ALICE_AMOUNT: u64 = 10; // on chain A
+pub const BOB_AMOUNT: u64 = 3; // on chain B
+
+// Bob's lock expires first, so that he still has time to claim on A after Alice claims on B
+pub const TIMEOUT_A: u64 = 20;
+pub const TIMEOUT_B: u64 = 10;
+pub const SWAP_END: u64 = 30;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PreSignature {
+    pub r: Affine, // R' = kG, the nonce point of the signature is R' + T
+    pub s: Fr,
+}
+
+// the nodes of the network
+pub const ALICE: PartyId = 0;
+pub const BOB: PartyId = 1;
+pub const CHAIN_A: PartyId = 2;
+pub const CHAIN_B: PartyId = 3;
+pub const CLOCK: PartyId = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SwapScenario {
+    // Alice claims on chain B at the given time
+    AliceClaimsAt(u64),
+    AliceNeverClaims,
+    BobNeverLocks,
+    // Bob's pre-signature does not pre-verify
+    BobBadPreSignature,
+}
+
+#[derive(Clone, Debug)]
+pub enum SwapMessage {
+    Tick(u64),                  // from the clock to everybody
+    Adaptor(Affine),            // T, from Alice to Bob
+    PreSignature(PreSignature), // Bob's for his lock on B, then Alice's for hers on A
+    // to a chain, from the owner of the coins
+    Lock {
+        to: &'static str,
+        amount: u64,
+        key: Affine,
+        timeout: u64,
+    },
+    Claim(SchnorrSignature),   // to a chain
+    Claimed(SchnorrSignature), // from a chain to everybody, once it accepted a claim
+}
+
+#[derive(Clone, Debug)]
+pub struct Lock {
+    pub from: &'static str,
+    pub to: &'static str,
+    pub amount: u64,
+    pub key: Affine,
+    pub timeout: u64,
+    pub claim: Option<SchnorrSignature>, // published on the chain once claimed
+    pub refunded: bool,
+}
+
+pub struct Chain {
+    pub name: &'static str,
+    pub now: u64, // the last tick of the clock
+    pub balances: HashMap<&'static str, u64>,
+    pub lock: Option<Lock>, // a single lock per chain is enough for the swap
+}
+
+pub struct Alice {
+    pub key: Fr,
+    pub t: Fr,
+    pub bob_key: Affine,
+    pub claim_at: Option<u64>, // the tick at which she claims on chain B, if ever
+    pub bob_pre_signature: Option<PreSignature>, // for his lock on B
+}
+
+pub struct Bob {
+    pub key: Fr,
+    pub alice_key: Affine,
+    pub adaptor: Option<Affine>,
+    pub own_pre_signature: Option<PreSignature>, // the one he gave Alice, for his lock on B
+    pub alice_pre_signature: Option<PreSignature>, // for her lock on A
+}
+
+pub struct Clock {
+    pub end: u64,
+}
+
+// the message signed to claim a lock, known before the lock exists
+pub fn claim_message(chain: &str, from: &str, to: &str, amount: u64, timeout: u64) -> Vec<u8> {
+    format!("claim on {chain}: {amount} from {from} to {to}, timeout {timeout}").into_bytes()
+}
+
+impl Chain {
+    pub fn new(name: &'static str, owner: &'static str, amount: u64) -> Chain {
+        Chain {
+            name,
+            now: 0,
+            balances: HashMap::from([(owner, amount)]),
+            lock: None,
+        }
+    }
+
+    pub fn balance(&self, party: &str) -> u64 {
+        self.balances.get(party).copied().unwrap_or(0)
+    }
+
+    pub fn lock(
+        &mut self,
+        from: &'static str,
+        to: &'static str,
+        amount: u64,
+        key: Affine,
+        timeout: u64,
+    ) {
+        let balance = self.balances.get_mut(from).unwrap();
+        *balance -= amount;
+        self.lock = Some(Lock {
+            from,
+            to,
+            amount,
+            key,
+            timeout,
+            claim: None,
+            refunded: false,
+        });
+    }
+
+    // a valid signature of the claim message before the timeout pays the recipient, and stays public
+    pub fn claim(&mut self, signature: &SchnorrSignature, now: u64) -> bool {
+        let Some(lock) = self.lock.as_mut() else {
+            return false;
+        };
+        let m = claim_message(self.name, lock.from, lock.to, lock.amount, lock.timeout);
+        if lock.claim.is_some() || lock.refunded || now >= lock.timeout {
+            return false;
+        }
+        if !schnorr_verify(&lock.key, &m, signature) {
+            return false;
+        }
+        lock.claim = Some(*signature);
+        *self.balances.entry(lock.to).or_insert(0) += lock.amount;
+        true
+    }
+
+    pub fn refund(&mut self, now: u64) -> bool {
+        let Some(lock) = self.lock.as_mut() else {
+            return false;
+        };
+        if lock.claim.is_some() || lock.refunded || now < lock.timeout {
+            return false;
+        }
+        lock.refunded = true;
+        *self.balances.entry(lock.from).or_insert(0) += lock.amount;
+        true
+    }
+}
+
+pub fn name(party: PartyId) -> &'static str {
+    match party {
+        ALICE => "alice",
+        BOB => "bob",
+        _ => "nobody",
+    }
+}
+
+fn point(scalar: &Fr) -> Affine {
+    Affine::generator().mul(scalar).into_affine()
+}
+
+impl Party<SwapMessage> for Alice {
+    fn start(&mut self, _rng: &mut ChaChaRng) -> Vec<Outgoing<SwapMessage>> {
+        vec![Outgoing::To(BOB, SwapMessage::Adaptor(point(&self.t)))]
+    }
+
+    fn receive(
+        &mut self,
+        from: PartyId,
+        message: SwapMessage,
+        rng: &mut ChaChaRng,
+    ) -> Vec<Outgoing<SwapMessage>> {
+        match message {
+            // 1. and 2. she walks away before locking anything if Bob's pre-signature is invalid
+            SwapMessage::PreSignature(pre_signature)
+                if from == BOB && self.bob_pre_signature.is_none() =>
+            {
+                let m_b = claim_message("B", "bob", "alice", BOB_AMOUNT, TIMEOUT_B);
+                if !pre_verify(&self.bob_key, &m_b, &point(&self.t), &pre_signature) {
+                    return vec![];
+                }
+                self.bob_pre_signature = Some(pre_signature);
+                let m_a = claim_message("A", "alice", "bob", ALICE_AMOUNT, TIMEOUT_A);
+                let own = pre_sign(&self.key, &m_a, &point(&self.t), rng);
+                let lock = SwapMessage::Lock {
+                    to: "bob",
+                    amount: ALICE_AMOUNT,
+                    key: point(&self.key),
+                    timeout: TIMEOUT_A,
+                };
+                vec![
+                    Outgoing::To(BOB, SwapMessage::PreSignature(own)),
+                    Outgoing::To(CHAIN_A, lock),
+                ]
+            }
+            // 3.
+            SwapMessage::Tick(now) if self.claim_at == Some(now) => self
+                .bob_pre_signature
+                .map(|pre_signature| {
+                    Outgoing::To(CHAIN_B, SwapMessage::Claim(adapt(&pre_signature, &self.t)))
+                })
+                .into_iter()
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
+impl Party<SwapMessage> for Bob {
+    fn receive(
+        &mut self,
+        from: PartyId,
+        message: SwapMessage,
+        rng: &mut ChaChaRng,
+    ) -> Vec<Outgoing<SwapMessage>> {
+        match message {
+            // 1.
+            SwapMessage::Adaptor(adaptor) if from == ALICE && self.adaptor.is_none() => {
+                self.adaptor = Some(adaptor);
+                let m_b = claim_message("B", "bob", "alice", BOB_AMOUNT, TIMEOUT_B);
+                let own = pre_sign(&self.key, &m_b, &adaptor, rng);
+                self.own_pre_signature = Some(own);
+                vec![Outgoing::To(ALICE, SwapMessage::PreSignature(own))]
+            }
+            // 2.
+            SwapMessage::PreSignature(pre_signature)
+                if from == ALICE && self.alice_pre_signature.is_none() =>
+            {
+                let m_a = claim_message("A", "alice", "bob", ALICE_AMOUNT, TIMEOUT_A);
+                let Some(adaptor) = self.adaptor else {
+                    return vec![];
+                };
+                if !pre_verify(&self.alice_key, &m_a, &adaptor, &pre_signature) {
+                    return vec![];
+                }
+                self.alice_pre_signature = Some(pre_signature);
+                let lock = SwapMessage::Lock {
+                    to: "alice",
+                    amount: BOB_AMOUNT,
+                    key: point(&self.key),
+                    timeout: TIMEOUT_B,
+                };
+                vec![Outgoing::To(CHAIN_B, lock)]
+            }
+            // 4.
+            SwapMessage::Claimed(signature) if from == CHAIN_B => self
+                .react(&signature)
+                .map(|claim| Outgoing::To(CHAIN_A, SwapMessage::Claim(claim)))
+                .into_iter()
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
+impl Party<SwapMessage> for Chain {
+    fn receive(
+        &mut self,
+        from: PartyId,
+        message: SwapMessage,
+        _rng: &mut ChaChaRng,
+    ) -> Vec<Outgoing<SwapMessage>> {
+        match message {
+            SwapMessage::Tick(now) if from == CLOCK => {
+                self.now = now;
+                self.refund(now);
+                vec![]
+            }
+            SwapMessage::Lock {
+                to,
+                amount,
+                key,
+                timeout,
+            } if self.lock.is_none() && self.balance(name(from)) >= amount => {
+                self.lock(name(from), to, amount, key, timeout);
+                vec![]
+            }
+            SwapMessage::Claim(signature) if self.claim(&signature, self.now) => {
+                vec![Outgoing::Broadcast(SwapMessage::Claimed(signature))]
+            }
+            _ => vec![],
+        }
+    }
+}
+
+impl Party<SwapMessage> for Clock {
+    fn start(&mut self, _rng: &mut ChaChaRng) -> Vec<Outgoing<SwapMessage>> {
+        vec![Outgoing::Broadcast(SwapMessage::Tick(1))]
+    }
+
+    fn receive(
+        &mut self,
+        from: PartyId,
+        message: SwapMessage,
+        _rng: &mut ChaChaRng,
+    ) -> Vec<Outgoing<SwapMessage>> {
+        match message {
+            SwapMessage::Tick(now) if from == CLOCK && now < self.end => {
+                vec![Outgoing::Broadcast(SwapMessage::Tick(now + 1))]
+            }
+            _ => vec![],
+        }
+    }
+}
+
+// the swap in the given scenario, over a reliable network delivering every message in one tick: a cheating
+// Bob is a Byzantine node. Returns the chains A and B at the end
+pub fn run_swap<R: Rng>(scenario: SwapScenario, rng: &mut R) -> (Chain, Chain) {
+    let (alice_key, bob_key) = (Fr::rand(rng), Fr::rand(rng));
+    let mut alice = Alice {
+        key: alice_key,
+        t: Fr::rand(rng),
+        bob_key: point(&bob_key),
+        claim_at: match scenario {
+            SwapScenario::AliceClaimsAt(now) => Some(now),
+            _ => None,
+        },
+        bob_pre_signature: None,
+    };
+    let mut bob = Bob {
+        key: bob_key,
+        alice_key: point(&alice_key),
+        adaptor: None,
+        own_pre_signature: None,
+        alice_pre_signature: None,
+    };
+    let mut chain_a = Chain::new("A", "alice", ALICE_AMOUNT);
+    let mut chain_b = Chain::new("B", "bob", BOB_AMOUNT);
+    let mut simnet = Simnet::new(SimnetConfig::default(), rng.gen());
+    match scenario {
+        SwapScenario::BobNeverLocks => {
+            let hook = |_, _, message| match message {
+                SwapMessage::Lock { .. } => None,
+                message => Some(message),
+            };
+            simnet.byzantine.insert(BOB, Box::new(hook));
+        }
+        SwapScenario::BobBadPreSignature => {
+            let hook = |_, _, message| match message {
+                SwapMessage::PreSignature(mut pre_signature) => {
+                    pre_signature.s += Fr::from(1u64);
+                    Some(SwapMessage::PreSignature(pre_signature))
+                }
+                message => Some(message),
+            };
+            simnet.byzantine.insert(BOB, Box::new(hook));
+        }
+        _ => {}
+    }
+    simnet.run(&mut [
+        &mut alice,
+        &mut bob,
+        &mut chain_a,
+        &mut chain_b,
+        &mut Clock { end: SWAP_END },
+    ]);
+    (chain_a, chain_b)
+}
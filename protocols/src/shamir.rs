@@ -0,0 +1,62 @@
+use crate::*;
+
+// Shamir secret sharing: the secret s is the constant term of a random polynomial f of degree t - 1
+// and party i (i = 1..n) holds the share f(i); any t shares recover s by Lagrange interpolation at 0,
+// t - 1 shares reveal nothing about it
+//
+// Proactive secret sharing: shares are refreshed at every epoch, so that an adversary must corrupt
+// t parties *within the same epoch*. To refresh, each party deals a sharing of 0 (a random polynomial
+// g_i with g_i(0) = 0) and every party adds the sub-shares it received to its share:
+// the shares are now those of f + sum g_i, with the same constant term s
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Share {
+    pub index: u64, // the party i, the share is f(i)
+    pub value: Fr,
+}
+
+// evaluation of the polynomial with coefficients `coefficients` (constant term first) at x
+pub fn evaluate(coefficients: &[Fr], x: Fr) -> Fr {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Fr::from(0), |acc, coefficient| acc * x + coefficient)
+}
+
+// a random polynomial of degree threshold - 1 with constant term `secret`, evaluated at 1..=n
+pub fn share_secret<R: Rng>(secret: Fr, threshold: usize, n: usize, rng: &mut R) -> Vec<Share> {
+    let mut coefficients = vec![secret];
+    coefficients.extend((1..threshold).map(|_| Fr::rand(rng)));
+    (1..=n as u64)
+        .map(|index| Share {
+            index,
+            value: evaluate(&coefficients, Fr::from(index)),
+        })
+        .collect()
+}
+
+// Lagrange interpolation at 0: s = sum f(i) prod_{j != i} j / (j - i)
+pub fn reconstruct(shares: &[Share]) -> Fr {
+    shares
+        .iter()
+        .map(|share| {
+            let i = Fr::from(share.index);
+            let coefficient: Fr = shares
+                .iter()
+                .filter(|other| other.index != share.index)
+                .map(|other| {
+                    let j = Fr::from(other.index);
+                    j * (j - i).inverse().unwrap()
+                })
+                .product();
+            coefficient * share.value
+        })
+        .sum()
+}
+
+// Feldman verifiable secret sharing: the dealer also publishes C_k = a_k G for the coefficients a_k of f,
+// so that party i checks its share without learning anything more than the public key sG = C_0:
+//   f(i) G = sum C_k i^k
+// A dealer sending a share off the committed polynomial is caught by its recipient, which complains
+// publicly. The commitments are only computationally hiding: they reveal sG, hence s to an unbounded adversary
+pub type FeldmanCommitments = Vec<Affine>;
@@ -0,0 +1,5 @@
+// The network simulator of the protocol exercises is shared with the other chapters: it lives in the
+// training-common crate, take a look at its module `protocol_sim`
+// Every `Party` is a state machine reacting to the messages it receives with messages to send, and `Simnet`
+// delivers them with latencies, losses, duplicates, crashes and Byzantine senders
+pub use training_common::protocol_sim::{Outgoing, Party, PartyId, Simnet, SimnetConfig};
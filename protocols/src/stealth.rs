@@ -0,0 +1,61 @@
+use crate::*;
+use secret::Scalar;
+
+// Dual-key stealth addresses (as in Monero, or BIP352 silent payments for Bitcoin)
+// The recipient publishes once a meta-address (A, B) = (aG, bG): a is the scan key, b the spend key
+// For each payment, the sender derives a fresh one-time address nobody but the recipient can link to (A, B)
+// - the sender picks an ephemeral r, publishes R = rG along with the payment to P = H(rA) G + B
+// - the recipient computes H(aR) = H(rA) for every payment on the ledger, and recognizes P = H(aR) G + B
+// - the private key of P is H(aR) + b
+// P is the spend key B tweaked by H(rA), see crate `tweak`
+// The scan key a can be given to a watch-only wallet: it detects the payments but cannot spend them
+
+#[derive(Clone, Copy, Debug)]
+pub struct MetaAddress {
+    pub scan: Affine,
+    pub spend: Affine,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Payment {
+    pub ephemeral: Affine, // R
+    pub one_time: Affine,  // P
+    pub amount: u64,
+}
+
+pub struct Recipient {
+    pub scan_key: Scalar<Fr>,
+    pub spend_key: Scalar<Fr>,
+}
+
+// H(rA) = H(aR) mapped to a scalar
+pub fn shared_secret(point: &Affine) -> Fr {
+    let mut bytes = Vec::new();
+    point.serialize_compressed(&mut bytes).unwrap();
+    Fr::from_be_bytes_mod_order(
+        &Sha256::new()
+            .chain_update(b"toy-stealth-address")
+            .chain_update(bytes)
+            .finalize(),
+    )
+}
+
+impl Recipient {
+    pub fn new<R: Rng>(rng: &mut R) -> Recipient {
+        Recipient {
+            scan_key: Scalar::new(Fr::rand(rng)),
+            spend_key: Scalar::new(Fr::rand(rng)),
+        }
+    }
+
+    pub fn meta_address(&self) -> MetaAddress {
+        MetaAddress {
+            scan: Affine::generator()
+                .mul(self.scan_key.expose())
+                .into_affine(),
+            spend: Affine::generator()
+                .mul(self.spend_key.expose())
+                .into_affine(),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::*;
+use num_traits::Signed;
+
+// Continued fractions: a / b = a_0 + 1 / (a_1 + 1 / (a_2 + ...)), written [a_0; a_1, a_2, ...]
+// - the partial quotients a_i are the quotients of the Euclidean algorithm on (a, b)
+// - the convergents p_k / q_k = [a_0; a_1, ..., a_k] follow p_k = a_k p_{k-1} + p_{k-2}, and the same for q_k,
+//   from p_{-1} / q_{-1} = 1 / 0 and p_{-2} / q_{-2} = 0 / 1; each convergent is a best approximation of a / b:
+//   no fraction with a smaller denominator is closer
+// - the path of a / b in the Stern-Brocot tree, from 1 / 1, is R^a_0 L^a_1 R^a_2 ... with the last exponent
+//   decreased by one: each step halves the interval between two fractions, and the fractions met are the
+//   convergents and the semiconvergents (t p_{k-1} + p_{k-2}) / (t q_{k-1} + q_{k-2}) in between
+// Used by Wiener's attack on RSA with a small private exponent (e / N is close to k / d) and by the
+// decomposition of scalars with an endomorphism (the remainders of Euclid on (n, lambda) give short vectors)
+
+// the curve secp256k1: its order n, and lambda, a cube root of unity mod n such that (beta x, y) = lambda (x, y)
+pub const SECP256K1_ORDER: &str =
+    "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+pub const SECP256K1_LAMBDA: &str =
+    "5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72";
+
+pub fn continued_fraction(a: &BigUint, b: &BigUint) -> Vec<BigUint> {
+    let (mut a, mut b) = (a.clone(), b.clone());
+    let mut quotients = vec![];
+    while !b.is_zero() {
+        let (q, r) = a.div_rem(&b);
+        quotients.push(q);
+        (a, b) = (b, r);
+    }
+    quotients
+}
+
+pub fn stern_brocot_path(a: &BigUint, b: &BigUint) -> String {
+    let quotients = continued_fraction(a, b);
+    let mut path = String::new();
+    for (i, q) in quotients.iter().enumerate() {
+        let mut steps: usize = q.try_into().expect("the path is too long to print");
+        if i == quotients.len() - 1 {
+            steps -= 1;
+        }
+        path.push_str(&(if i % 2 == 0 { "R" } else { "L" }).repeat(steps));
+    }
+    path
+}
+
+// the closest fraction to a / b whose denominator is at most `max_denominator`: the last convergent within the
+// bound, or the largest semiconvergent after it if closer
+pub fn best_approximation(
+    a: &BigUint,
+    b: &BigUint,
+    max_denominator: &BigUint,
+) -> (BigUint, BigUint) {
+    let (mut p0, mut q0) = (BigUint::zero(), BigUint::one());
+    let (mut p1, mut q1) = (BigUint::one(), BigUint::zero());
+    for quotient in continued_fraction(a, b) {
+        let q2 = &quotient * &q1 + &q0;
+        if &q2 > max_denominator {
+            let t = (max_denominator - &q0) / &q1;
+            let (p, q) = (&t * &p1 + &p0, &t * &q1 + &q0);
+            // |a / b - p / q| = |a q - b p| / (b q): compare |a q - b p| q1 with |a q1 - b p1| q
+            let numerator =
+                |p: &BigUint, q: &BigUint| (BigInt::from(a * q) - BigInt::from(b * p)).abs();
+            return match numerator(&p, &q) * BigInt::from(q1.clone())
+                < numerator(&p1, &q1) * BigInt::from(q.clone())
+            {
+                true => (p, q),
+                false => (p1, q1),
+            };
+        }
+        let p2 = &quotient * &p1 + &p0;
+        (p0, q0, p1, q1) = (p1, q1, p2, q2);
+    }
+    (p1, q1)
+}
@@ -0,0 +1,117 @@
+use runner::lang::{Catalog, Lang, Statements};
+
+pub const CATALOG: Catalog = Catalog {
+    lang: Lang::Fr,
+    statements: Statements::Table(&[
+        ("Q1", "écrire `keygen` qui calcule la paire de clés à partir de p, q et e"),
+        ("Q2", "écrire `encrypt` et `decrypt`"),
+        ("Q3", "écrire `sign` et `verify`"),
+        ("Q4", "forger la signature de m1 m2 à partir des signatures de m1 et m2, sans la clé privée"),
+        ("Q5", "écrire `recover_private_key` qui calcule d à partir de la clé publique et de phi(n),\npuis `factor_with_phi` qui retrouve même p et q"),
+        ("Q6", "écrire `hastad_broadcast_attack` qui retrouve m à partir des trois chiffrés"),
+        ("Q7", "pourquoi un bourrage aléatoire de m (comme dans RSA-OAEP) déjouerait-il l'attaque ?"),
+        ("Q8", "écrire `paillier_keygen`, `paillier_encrypt` et `paillier_decrypt`"),
+        ("Q9", "écrire `add_ciphertexts` et `scalar_mul_ciphertext`, qui ne travaillent que sur les chiffrés"),
+        ("Q10", "écrire `tally` qui additionne tous les bulletins"),
+        ("Q11", "un votant chiffre 50 au lieu de 0 ou 1 : que se passe-t-il ? Comment protéger les autres votants ?"),
+        ("Q12", "écrire `convergents` qui renvoie les réduites (p_k, q_k) de a / b, de k = 0 à la dernière, a / b elle-même"),
+        ("Q13", "pourquoi d est-il le dénominateur d'une réduite quand d < N^(1/4) / 3 ? Comment un vecteur court du réseau\ndécompose-t-il un scalaire k de 256 bits en k1 + k2 lambda avec k1 et k2 de 128 bits ?"),
+        ("Q14", "écrire `shamir_trick` qui, à partir de x^a = y^b mod n avec pgcd(a, b) = 1, calcule z tel que z^a = y"),
+        ("Q15", "écrire `root_of_product` qui, à partir de racines r_i^(e_i) = g pour des e_i premiers entre eux deux à deux, calcule la\nracine de g pour le produit des e_i"),
+        ("Q16", "pour quels exposants peut-on calculer une racine de g à partir des racines pour e_1, ..., e_k ? Pourquoi une racine\npour un autre premier casserait-elle l'hypothèse RSA forte, et que forgerait-elle dans un accumulateur RSA ?\nQue se passe-t-il avec pgcd(a, b) > 1, et dans un groupe dont l'ordre est connu ?"),
+        ("Q17", "écrire `hash_to_prime`, le défi l = H_prime(x, y, t)"),
+        ("Q18", "écrire `wesolowski_prove`, qui évalue y = x^(2^t) par t élévations au carré et calcule la preuve"),
+        ("Q19", "écrire `wesolowski_verify`"),
+        ("Q20", "pourquoi l doit-il être premier, imprévisible et dérivé de y ? Que donne une preuve valide pour un mauvais y, en\ntermes de racines comme en Q16 ? -y a passé la vérification ci-dessus : comment le groupe quotient Z_n^* / {1, -1}\nl'empêche-t-il, et qui peut générer n pour une VDF dont personne ne peut sauter le délai ?"),
+    ]),
+    hints: &[
+        (
+            "Q1",
+            &[
+                "L'exposant privé d défait e : m^(e d) = m mod n pour tout m",
+                "Par le théorème d'Euler, il suffit que e d = 1 mod phi(n), avec phi(n) = (p - 1)(q - 1)",
+                "d est l'inverse de e modulo phi(n), donné par l'algorithme d'Euclide étendu : `modinv` dans num-bigint",
+            ],
+        ),
+        (
+            "Q4",
+            &[
+                "RSA sans bourrage est multiplicatif : le chiffré d'un produit est le produit des chiffrés",
+                "s1 = m1^d et s2 = m2^d mod n, donc s1 s2 = (m1 m2)^d mod n",
+                "La contrefaçon est s1 * s2 % n",
+            ],
+        ),
+        (
+            "Q5",
+            &[
+                "d se déduit de e et phi(n) exactement comme dans `keygen`",
+                "p + q = n - phi(n) + 1, et p q = n : p et q sont les racines d'un polynôme de degré 2",
+                "p et q sont les racines de X^2 - (n - phi(n) + 1) X + n : calculer le discriminant et sa racine carrée entière (`sqrt` dans num-bigint)",
+            ],
+        ),
+        (
+            "Q6",
+            &[
+                "Les trois chiffrés sont m^3 modulo trois modules différents",
+                "Le théorème des restes chinois donne m^3 modulo n_1 n_2 n_3, c'est-à-dire m^3 lui-même puisque m^3 < n_1 n_2 n_3",
+                "Combiner les chiffrés avec le TRC, puis prendre la racine cubique entière (`cbrt` dans num-bigint)",
+            ],
+        ),
+        (
+            "Q10",
+            &[
+                "Paillier est homomorphe pour l'addition : le produit des chiffrés se déchiffre en la somme des clairs",
+                "Un bulletin est le chiffré de 0 ou 1, le dépouillement est le chiffré de leur somme",
+                "Replier les bulletins avec `add_ciphertexts`, en partant d'un chiffré de 0",
+            ],
+        ),
+        (
+            "Q12",
+            &[
+                "Les quotients partiels viennent de `continued_fraction`, les quotients de l'algorithme d'Euclide",
+                "p_k = a_k p_{k-1} + p_{k-2} et q_k = a_k q_{k-1} + q_{k-2}, en partant de p_{-2} / q_{-2} = 0 / 1 et p_{-1} / q_{-1} = 1 / 0",
+                "Garder les deux derniers (p, q) dans des variables et ajouter chaque nouvelle paire en repliant sur les quotients",
+            ],
+        ),
+        (
+            "Q14",
+            &[
+                "Bézout : puisque pgcd(a, b) = 1, il existe des entiers s et t tels que s a + t b = 1 (`extended_gcd` sur `BigInt`)",
+                "z = y^s x^t : alors z^a = y^(s a) x^(t a) = y^(s a) y^(t b) = y",
+                "L'un de s et t est négatif : `pow_signed` élève l'inverse mod n à sa valeur absolue",
+            ],
+        ),
+        (
+            "Q15",
+            &[
+                "Replier les racines deux par deux : une racine pour e et une racine pour e_i donnent une racine pour e e_i",
+                "Avec r^e = g = r_i^(e_i), une racine e-ième z de r_i vérifie z^(e e_i) = r_i^(e_i) = g",
+                "shamir_trick(r, r_i, e, e_i, n) est ce z, puis e devient e e_i",
+            ],
+        ),
+        (
+            "Q17",
+            &[
+                "Hacher x, y et t comme le décrit le module `wesolowski` : `to_bytes_padded` sur la longueur en octets de n, puis t sur 8 octets",
+                "Lire les 16 premiers octets du haché comme un entier gros-boutiste et mettre son bit 127 à un",
+                "Avancer de un jusqu'à `is_probable_prime`",
+            ],
+        ),
+        (
+            "Q18",
+            &[
+                "y est t élévations au carré de x mod n, l'une après l'autre",
+                "Le défi a besoin de y : calculer l = hash_to_prime(n, x, y, t) après l'évaluation",
+                "q = 2^t / l avec un décalage et une division de `BigUint`, et pi = x^q mod n",
+            ],
+        ),
+        (
+            "Q19",
+            &[
+                "Recalculer l à partir de (x, y, t) : ne jamais le prendre du prouveur",
+                "r = 2^t mod l est un `modpow` avec un module de 128 bits",
+                "Accepter si et seulement si pi^l x^r = y mod n, puisque pi^l x^r = x^(q l + r) = x^(2^t)",
+            ],
+        ),
+    ],
+};
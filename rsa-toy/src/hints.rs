@@ -0,0 +1,98 @@
+use runner::hints::Hints;
+use runner::lang::Catalog;
+
+pub const CATALOGS: &[Catalog] = &[
+    Catalog::english(HINTS, include_str!("main.rs")),
+    crate::fr::CATALOG,
+];
+
+pub const HINTS: Hints = &[
+    (
+        "Q1",
+        &[
+            "The private exponent d undoes e: m^(e d) = m mod n for every m",
+            "By Euler's theorem it is enough that e d = 1 mod phi(n), with phi(n) = (p - 1)(q - 1)",
+            "d is the inverse of e modulo phi(n), given by the extended Euclidean algorithm: `modinv` in num-bigint",
+        ],
+    ),
+    (
+        "Q4",
+        &[
+            "Textbook RSA is multiplicative: the encryption of a product is the product of the encryptions",
+            "s1 = m1^d and s2 = m2^d mod n, so s1 s2 = (m1 m2)^d mod n",
+            "The forgery is s1 * s2 % n",
+        ],
+    ),
+    (
+        "Q5",
+        &[
+            "d comes from e and phi(n) exactly as in `keygen`",
+            "p + q = n - phi(n) + 1, and p q = n: p and q are the roots of a polynomial of degree 2",
+            "p and q are the roots of X^2 - (n - phi(n) + 1) X + n: compute the discriminant and its integer square root (`sqrt` in num-bigint)",
+        ],
+    ),
+    (
+        "Q6",
+        &[
+            "The three ciphertexts are m^3 modulo three different moduli",
+            "The Chinese remainder theorem gives m^3 modulo n_1 n_2 n_3, which is m^3 itself since m^3 < n_1 n_2 n_3",
+            "Combine the ciphertexts with the CRT, then take the integer cube root (`cbrt` in num-bigint)",
+        ],
+    ),
+    (
+        "Q10",
+        &[
+            "Paillier is additively homomorphic: the product of ciphertexts decrypts to the sum of the plaintexts",
+            "A ballot is the encryption of 0 or 1, the tally is the encryption of their sum",
+            "Fold the ballots with `add_ciphertexts`, starting from an encryption of 0",
+        ],
+    ),
+    (
+        "Q12",
+        &[
+            "The partial quotients come from `continued_fraction`, the quotients of the Euclidean algorithm",
+            "p_k = a_k p_{k-1} + p_{k-2} and q_k = a_k q_{k-1} + q_{k-2}, starting from p_{-2} / q_{-2} = 0 / 1 and p_{-1} / q_{-1} = 1 / 0",
+            "Keep the last two (p, q) in variables and push each new pair while folding over the quotients",
+        ],
+    ),
+    (
+        "Q14",
+        &[
+            "Bezout: since gcd(a, b) = 1, there are integers s and t with s a + t b = 1 (`extended_gcd` on `BigInt`)",
+            "z = y^s x^t: then z^a = y^(s a) x^(t a) = y^(s a) y^(t b) = y",
+            "One of s and t is negative: `pow_signed` raises the inverse mod n to its absolute value",
+        ],
+    ),
+    (
+        "Q15",
+        &[
+            "Fold the roots two by two: a root for e and a root for e_i give a root for e e_i",
+            "With r^e = g = r_i^(e_i), an e-th root z of r_i satisfies z^(e e_i) = r_i^(e_i) = g",
+            "shamir_trick(r, r_i, e, e_i, n) is that z, then e becomes e e_i",
+        ],
+    ),
+    (
+        "Q17",
+        &[
+            "Hash x, y and t as module `wesolowski` describes: `to_bytes_padded` on the byte length of n, then t on 8 bytes",
+            "Read the first 16 bytes of the hash as a big-endian integer and set its bit 127",
+            "Step by one until `is_probable_prime`",
+        ],
+    ),
+    (
+        "Q18",
+        &[
+            "y is t squarings of x mod n, one after the other",
+            "The challenge needs y: compute l = hash_to_prime(n, x, y, t) after the evaluation",
+            "q = 2^t / l with a shift and a division of `BigUint`s, and pi = x^q mod n",
+        ],
+    ),
+    (
+        "Q19",
+        &[
+            "Recompute l from (x, y, t): never take it from the prover",
+            "r = 2^t mod l is a `modpow` with a 128-bit modulus",
+            "Accept iff pi^l x^r = y mod n, since pi^l x^r = x^(q l + r) = x^(2^t)",
+        ],
+    ),
+];
@@ -0,0 +1,519 @@
+use contfrac::*;
+use num_bigint::{BigInt, BigUint, RandBigInt};
+use num_integer::Integer;
+use num_traits::{One, Zero};
+use paillier::*;
+use rand::Rng;
+use rsa::*;
+use runner::Runner;
+use sage_fixtures::Fixtures;
+use std::time::Instant;
+use strong_rsa::*;
+use wesolowski::*;
+
+mod contfrac;
+mod fr;
+mod hints;
+mod paillier;
+mod rsa;
+mod strong_rsa;
+mod wesolowski;
+
+fn main() {
+    let runner = Runner::start(env!("CARGO_PKG_NAME"), hints::CATALOGS);
+    let mut rng = seed::rng();
+    let fixtures: Fixtures = include_str!("../fixtures/primes.fixtures").parse().unwrap();
+
+    // Take a look at module `rsa`
+    // The instructor generated two 512-bit primes with Sage (see fixtures/primes.sage)
+    let p: BigUint = fixtures.get("rsa", "p");
+    let q: BigUint = fixtures.get("rsa", "q");
+    let e: BigUint = fixtures.get("rsa", "e");
+    // Q1: write `keygen` which computes the key pair from p, q and e
+    runner.question("Q1");
+    let (public_key, private_key) = keygen(&p, &q, &e);
+    assert_eq!(public_key.n, &p * &q);
+    assert_eq!(private_key.n, public_key.n);
+    let phi = (&p - 1u32) * (&q - 1u32);
+    assert!((&e * &private_key.d % &phi).is_one());
+
+    // Q2: write `encrypt` and `decrypt`
+    runner.question("Q2");
+    let m = encode_message("Attack at dawn");
+    let c = encrypt(&public_key, &m);
+    assert_ne!(c, m);
+    assert_eq!(decode_message(&decrypt(&private_key, &c)), "Attack at dawn");
+    for _ in 0..10 {
+        let m = rng.gen_biguint_below(&public_key.n);
+        assert_eq!(decrypt(&private_key, &encrypt(&public_key, &m)), m);
+    }
+
+    // Q3: write `sign` and `verify`
+    runner.question("Q3");
+    let m = encode_message("I owe you 10 euros");
+    let s = sign(&private_key, &m);
+    assert!(verify(&public_key, &m, &s));
+    assert!(!verify(
+        &public_key,
+        &encode_message("I owe you 1000 euros"),
+        &s
+    ));
+
+    // Textbook RSA is multiplicative: (m1 m2)^d = m1^d m2^d mod n
+    // Q4: forge the signature of m1 m2 from the signatures of m1 and m2, without the private key
+    runner.question("Q4");
+    let m1 = BigUint::from(1000u32);
+    let m2 = encode_message("a harmless message");
+    let (s1, s2) = (sign(&private_key, &m1), sign(&private_key, &m2));
+    let forged = forge_product_signature(&public_key, &s1, &s2);
+    assert!(verify(&public_key, &(&m1 * &m2 % &public_key.n), &forged));
+
+    // Knowing phi(n) is as good as knowing the private key
+    // Q5: write `recover_private_key` which computes d from the public key and phi(n),
+    // then `factor_with_phi` which even recovers p and q
+    runner.question("Q5");
+    assert_eq!(recover_private_key(&public_key, &phi), private_key);
+    let (p1, q1) = factor_with_phi(&public_key.n, &phi);
+    assert!((p1 == p && q1 == q) || (p1 == q && q1 == p));
+
+    // Alice broadcasts the same message to three recipients who all use e = 3
+    // If m^3 < n_1 n_2 n_3, the CRT gives m^3 over the integers, where cube roots are easy
+    // Q6: write `hastad_broadcast_attack` which recovers m from the three ciphertexts
+    runner.question("Q6");
+    let e3: BigUint = fixtures.get("broadcast", "e");
+    let moduli: Vec<BigUint> = fixtures.get_list("broadcast", "moduli");
+    let recipients: Vec<RsaPublicKey> = moduli
+        .iter()
+        .map(|n| RsaPublicKey {
+            n: n.clone(),
+            e: e3.clone(),
+        })
+        .collect();
+    let m = encode_message("The launch code is 0000, please do not tell anyone about it");
+    assert!(m.pow(3) > moduli[0]); // the cube root of a single ciphertext is not enough
+    let ciphertexts: Vec<BigUint> = recipients.iter().map(|pk| encrypt(pk, &m)).collect();
+    assert_eq!(hastad_broadcast_attack(&ciphertexts, &moduli), m);
+    // Q7: why would a random padding of m (as in RSA-OAEP) defeat the attack?
+    runner.question("Q7");
+
+    // Take a look at module `paillier`
+    // Q8: write `paillier_keygen`, `paillier_encrypt` and `paillier_decrypt`
+    runner.question("Q8");
+    let paillier_key = paillier_keygen(&p, &q);
+    let public_key = &paillier_key.public_key;
+    assert_eq!(public_key.n, &p * &q);
+    let m = rng.gen_biguint_below(&public_key.n);
+    let c1 = paillier_encrypt(public_key, &m, &mut rng);
+    let c2 = paillier_encrypt(public_key, &m, &mut rng);
+    assert_ne!(c1, c2); // encryption is randomized
+    assert_eq!(paillier_decrypt(&paillier_key, &c1), m);
+    assert_eq!(paillier_decrypt(&paillier_key, &c2), m);
+
+    // Q9: write `add_ciphertexts` and `scalar_mul_ciphertext`, working on ciphertexts only
+    runner.question("Q9");
+    for _ in 0..10 {
+        let m1 = rng.gen_biguint_below(&public_key.n);
+        let m2 = rng.gen_biguint_below(&public_key.n);
+        let k = rng.gen_biguint_below(&public_key.n);
+        let c1 = paillier_encrypt(public_key, &m1, &mut rng);
+        let c2 = paillier_encrypt(public_key, &m2, &mut rng);
+        let sum = add_ciphertexts(public_key, &c1, &c2);
+        assert_eq!(
+            paillier_decrypt(&paillier_key, &sum),
+            (&m1 + &m2) % &public_key.n
+        );
+        let product = scalar_mul_ciphertext(public_key, &c1, &k);
+        assert_eq!(
+            paillier_decrypt(&paillier_key, &product),
+            &m1 * &k % &public_key.n
+        );
+    }
+
+    // An encrypted vote: the authority only decrypts the tally
+    // Q10: write `tally` which adds up all the ballots
+    runner.question("Q10");
+    let votes: Vec<bool> = (0..101).map(|_| rng.gen_bool(0.6)).collect();
+    let ballots = cast_ballots(public_key, &votes, &mut rng);
+    let encrypted_tally = tally(public_key, &ballots);
+    let yes = votes.iter().filter(|vote| **vote).count();
+    assert_eq!(
+        paillier_decrypt(&paillier_key, &encrypted_tally),
+        BigUint::from(yes)
+    );
+    // Q11: a voter encrypts 50 instead of 0 or 1: what happens? How can the other voters be protected?
+    runner.question("Q11");
+
+    // Take a look at module `contfrac`
+    // Q12: write `convergents` which returns the convergents (p_k, q_k) of a / b, from k = 0 to the last, a / b itself
+    runner.question("Q12");
+    let fraction = |a: u32, b: u32| (BigUint::from(a), BigUint::from(b));
+    let (a, b) = fraction(415, 93); // [4; 2, 6, 7]
+    let expected: Vec<_> = [(4, 1), (9, 2), (58, 13), (415, 93)]
+        .map(|(p, q)| fraction(p, q))
+        .into();
+    assert_eq!(convergents(&a, &b), expected);
+    assert_eq!(convergents(&b, &a)[0], fraction(0, 1));
+    println!(
+        "415/93 = {:?}, at {} in the Stern-Brocot tree",
+        continued_fraction(&a, &b),
+        stern_brocot_path(&a, &b)
+    );
+    let (a, b) = fraction(314159265, 100000000);
+    assert_eq!(
+        best_approximation(&a, &b, &BigUint::from(1000u32)),
+        fraction(355, 113)
+    );
+    assert_eq!(
+        best_approximation(&a, &b, &BigUint::from(100u32)),
+        fraction(311, 99)
+    );
+    // an RSA key with a 200-bit private exponent: e d - k phi(N) = 1, so e / N is very close to k / d
+    let d = loop {
+        let d = rng.gen_biguint(200) | BigUint::one();
+        if d.gcd(&phi).is_one() {
+            break d;
+        }
+    };
+    let e = d.modinv(&phi).unwrap();
+    let n = &p * &q;
+    let convergents_e_n = convergents(&e, &n);
+    for window in convergents_e_n.windows(2) {
+        // p_k q_{k-1} - p_{k-1} q_k = (-1)^(k-1)
+        let ((p0, q0), (p1, q1)) = (&window[0], &window[1]);
+        assert!((BigInt::from(p1 * q0) - BigInt::from(p0 * q1))
+            .magnitude()
+            .is_one());
+    }
+    let position = convergents_e_n.iter().position(|(_, q)| *q == d);
+    println!(
+        "The private exponent is the denominator of convergent {} of e/N (Wiener)",
+        position.expect("d is the denominator of a convergent of e / N")
+    );
+    // the convergents (p_k, q_k) of n / lambda give vectors (q_k n - p_k lambda, p_k) of the lattice of the
+    // decompositions a + b lambda = 0 mod n of secp256k1; half-way through, both entries have about 128 bits
+    let order = BigUint::parse_bytes(SECP256K1_ORDER.as_bytes(), 16).unwrap();
+    let lambda = BigUint::parse_bytes(SECP256K1_LAMBDA.as_bytes(), 16).unwrap();
+    assert!(lambda.modpow(&BigUint::from(3u32), &order).is_one());
+    let short = convergents(&order, &lambda)
+        .into_iter()
+        .map(|(p, q)| (BigInt::from(&q * &order) - BigInt::from(&p * &lambda), p))
+        .find(|(a, b)| a.bits() <= 129 && b.bits() <= 129)
+        .expect("a convergent of n / lambda gives a short vector");
+    assert!(
+        ((&short.0 + BigInt::from(short.1.clone()) * BigInt::from(lambda)) % BigInt::from(order))
+            .is_zero()
+    );
+    println!(
+        "A short vector of the decomposition lattice of secp256k1: ({}, {})\n",
+        short.0, short.1
+    );
+    // Q13: why is d a denominator of a convergent when d < N^(1/4) / 3? How does a short vector of the lattice
+    // split a 256-bit scalar k into k1 + k2 lambda with k1 and k2 of 128 bits?
+    runner.question("Q13");
+
+    // Take a look at module `strong_rsa`
+    // `RootOracle` hands out e-th roots of a random g mod n = pq: without p and q, Z_n^* has an unknown order
+    // Q14: write `shamir_trick` which, from x^a = y^b mod n with gcd(a, b) = 1, computes z with z^a = y
+    runner.question("Q14");
+    let oracle = RootOracle::new(&p, &q, &mut rng);
+    let n = &oracle.n;
+    let exponents = oracle.exponents(8);
+    for pair in exponents.windows(2) {
+        let (a, b) = (&pair[0], &pair[1]);
+        let w = rng.gen_biguint_below(n);
+        let (x, y) = (w.modpow(b, n), w.modpow(a, n));
+        let z = shamir_trick(&x, &y, a, b, n);
+        assert_eq!(z.modpow(a, n), y);
+        assert_eq!(z, w); // e-th roots are unique when e is coprime with phi(n)
+                          // u^a = g = v^b: the trick gives an a-th root of v
+        let (u, v) = (oracle.root(a), oracle.root(b));
+        assert_eq!(shamir_trick(&u, &v, a, b, n).modpow(a, n), v);
+    }
+
+    // Q15: write `root_of_product` which, from roots r_i^(e_i) = g for pairwise coprime e_i, computes the
+    // root of g for the product of the e_i
+    runner.question("Q15");
+    let g = &oracle.g;
+    for count in 1..=exponents.len() {
+        let roots: Vec<(BigUint, BigUint)> = exponents[..count]
+            .iter()
+            .map(|e| (oracle.root(e), e.clone()))
+            .collect();
+        let product: BigUint = exponents[..count].iter().product();
+        let root = root_of_product(&roots, n);
+        assert_eq!(&root.modpow(&product, n), g);
+        assert_eq!(root, oracle.root(&product));
+    }
+
+    // Q16: which exponents have a root of g you can compute from the roots for e_1, ..., e_k? Why would a root
+    // for another prime break the strong RSA assumption, and what would it forge in an RSA accumulator? What
+    // goes wrong with gcd(a, b) > 1, and in a group whose order is known?
+    runner.question("Q16");
+
+    // Take a look at module `wesolowski`: a proof that y = x^(2^t) mod n, checked with two small exponentiations
+    // The instructor computed a proof with Sage over the modulus of Q1 (see fixtures/primes.sage)
+    let n = &p * &q;
+    let x: BigUint = fixtures.get("wesolowski", "x");
+    let t: u64 = fixtures.get("wesolowski", "t");
+    let y: BigUint = fixtures.get("wesolowski", "y");
+    let pi: BigUint = fixtures.get("wesolowski", "pi");
+    // Q17: write `hash_to_prime`, the challenge l = H_prime(x, y, t)
+    runner.question("Q17");
+    let l = hash_to_prime(&n, &x, &y, t);
+    assert_eq!(l, fixtures.get("wesolowski", "l"));
+    assert!(is_probable_prime(&l) && l.bits() == CHALLENGE_BITS);
+    assert_ne!(hash_to_prime(&n, &x, &y, t + 1), l);
+
+    // Q18: write `wesolowski_prove`, which evaluates y = x^(2^t) by t squarings and computes the proof
+    runner.question("Q18");
+    let start = Instant::now();
+    assert_eq!(wesolowski_prove(&n, &x, t), (y.clone(), pi.clone()));
+    let evaluation = start.elapsed();
+
+    // Q19: write `wesolowski_verify`
+    runner.question("Q19");
+    let start = Instant::now();
+    assert!(wesolowski_verify(&n, &x, t, &y, &pi));
+    let verification = start.elapsed();
+    assert!(!wesolowski_verify(&n, &x, t, &(&y * &x % &n), &pi));
+    assert!(!wesolowski_verify(&n, &x, t, &y, &(&pi * &x % &n)));
+    assert!(!wesolowski_verify(&n, &x, t + 1, &y, &pi));
+    for _ in 0..10 {
+        let x = rng.gen_biguint_below(&n);
+        let t = rng.gen_range(1..1000);
+        let (y, pi) = wesolowski_prove(&n, &x, t);
+        assert!(wesolowski_verify(&n, &x, t, &y, &pi));
+        assert!(!wesolowski_verify(&n, &x, t, &(&n - &y), &pi));
+    }
+    // with the factorization, 2^t shrinks mod phi(n): the delay is gone
+    let start = Instant::now();
+    let exponent = BigUint::from(2u32).modpow(&BigUint::from(t), &phi);
+    assert_eq!(x.modpow(&exponent, &n), y);
+    println!(
+        "{} squarings: {:?}, with the trapdoor: {:?}, verification: {:?}",
+        t,
+        evaluation,
+        start.elapsed(),
+        verification
+    );
+    // -1 has order 2: -y has a valid proof too, for its own challenge
+    let l_minus = hash_to_prime(&n, &x, &(&n - &y), t);
+    let forged = &n - x.modpow(&((BigUint::one() << t) / &l_minus), &n);
+    assert!(wesolowski_verify(&n, &x, t, &(&n - &y), &forged));
+
+    // Q20: why must l be prime, unpredictable and derived from y? What does a valid proof for a wrong y give, in
+    // terms of roots as in Q16? -y passed the verification above: how does the quotient group Z_n^* / {1, -1}
+    // prevent it, and who can generate n for a VDF whose delay nobody can skip?
+    runner.question("Q20");
+
+    println!("Good job!");
+    runner.finish();
+}
+
+fn hash_to_prime(n: &BigUint, x: &BigUint, y: &BigUint, t: u64) -> BigUint {
+    // SOLUTION-BEGIN
+    let size = n.bits().div_ceil(8) as usize;
+    let hash = sha256(
+        &[
+            to_bytes_padded(x, size),
+            to_bytes_padded(y, size),
+            t.to_be_bytes().to_vec(),
+        ]
+        .concat(),
+    );
+    let mut l = BigUint::from_bytes_be(&hash[..16]);
+    l.set_bit(CHALLENGE_BITS - 1, true);
+    while !is_probable_prime(&l) {
+        l += 1u32;
+    }
+    l
+    // SOLUTION-END
+}
+
+fn wesolowski_prove(n: &BigUint, x: &BigUint, t: u64) -> (BigUint, BigUint) {
+    // SOLUTION-BEGIN
+    let mut y = x.clone();
+    for _ in 0..t {
+        y = &y * &y % n;
+    }
+    let l = hash_to_prime(n, x, &y, t);
+    // 2^t has t bits: the quotient is computed once, then a single exponentiation
+    let quotient = (BigUint::one() << t) / &l;
+    let pi = x.modpow(&quotient, n);
+    (y, pi)
+    // SOLUTION-END
+}
+
+fn wesolowski_verify(n: &BigUint, x: &BigUint, t: u64, y: &BigUint, pi: &BigUint) -> bool {
+    // SOLUTION-BEGIN
+    let l = hash_to_prime(n, x, y, t);
+    let r = BigUint::from(2u32).modpow(&BigUint::from(t), &l);
+    y < n && pi < n && pi.modpow(&l, n) * x.modpow(&r, n) % n == *y
+    // SOLUTION-END
+}
+
+fn shamir_trick(x: &BigUint, y: &BigUint, a: &BigUint, b: &BigUint, n: &BigUint) -> BigUint {
+    // SOLUTION-BEGIN
+    // s a + t b = 1, and z = y^s x^t
+    let gcd = BigInt::from(a.clone()).extended_gcd(&BigInt::from(b.clone()));
+    assert!(gcd.gcd.is_one(), "a and b must be coprime");
+    pow_signed(y, &gcd.x, n) * pow_signed(x, &gcd.y, n) % n
+    // SOLUTION-END
+}
+
+fn root_of_product(roots: &[(BigUint, BigUint)], n: &BigUint) -> BigUint {
+    // SOLUTION-BEGIN
+    // from r^e = g and r_i^(e_i) = g: r^(e e_i) = g^(e_i) = r_i^(e_i), so an (e e_i)-th root of g is an e-th
+    // root of r_i, which the trick computes from r^e = r_i^(e_i)
+    let (first, rest) = roots.split_first().expect("at least one root");
+    let (mut root, mut exponent) = first.clone();
+    for (r_i, e_i) in rest {
+        root = shamir_trick(&root, r_i, &exponent, e_i, n);
+        exponent *= e_i;
+    }
+    root
+    // SOLUTION-END
+}
+
+fn convergents(a: &BigUint, b: &BigUint) -> Vec<(BigUint, BigUint)> {
+    // SOLUTION-BEGIN
+    let (mut p0, mut q0) = (BigUint::zero(), BigUint::one());
+    let (mut p1, mut q1) = (BigUint::one(), BigUint::zero());
+    let mut convergents = vec![];
+    for quotient in continued_fraction(a, b) {
+        let (p2, q2) = (&quotient * &p1 + &p0, &quotient * &q1 + &q0);
+        convergents.push((p2.clone(), q2.clone()));
+        (p0, q0, p1, q1) = (p1, q1, p2, q2);
+    }
+    convergents
+    // SOLUTION-END
+}
+
+fn keygen(p: &BigUint, q: &BigUint, e: &BigUint) -> (RsaPublicKey, RsaPrivateKey) {
+    // SOLUTION-BEGIN
+    let n = p * q;
+    let phi = (p - 1u32) * (q - 1u32);
+    let d = e.modinv(&phi).expect("e must be coprime with phi(n)");
+    (
+        RsaPublicKey {
+            n: n.clone(),
+            e: e.clone(),
+        },
+        RsaPrivateKey { n, d },
+    )
+    // SOLUTION-END
+}
+
+fn encrypt(public_key: &RsaPublicKey, m: &BigUint) -> BigUint {
+    // SOLUTION-BEGIN
+    assert!(m < &public_key.n);
+    m.modpow(&public_key.e, &public_key.n)
+    // SOLUTION-END
+}
+
+fn decrypt(private_key: &RsaPrivateKey, c: &BigUint) -> BigUint {
+    // SOLUTION-BEGIN
+    c.modpow(&private_key.d, &private_key.n)
+    // SOLUTION-END
+}
+
+fn sign(private_key: &RsaPrivateKey, m: &BigUint) -> BigUint {
+    // SOLUTION-BEGIN
+    assert!(m < &private_key.n);
+    m.modpow(&private_key.d, &private_key.n)
+    // SOLUTION-END
+}
+
+fn verify(public_key: &RsaPublicKey, m: &BigUint, s: &BigUint) -> bool {
+    // SOLUTION-BEGIN
+    s < &public_key.n && &s.modpow(&public_key.e, &public_key.n) == m
+    // SOLUTION-END
+}
+
+fn forge_product_signature(public_key: &RsaPublicKey, s1: &BigUint, s2: &BigUint) -> BigUint {
+    // SOLUTION-BEGIN
+    s1 * s2 % &public_key.n
+    // SOLUTION-END
+}
+
+fn recover_private_key(public_key: &RsaPublicKey, phi: &BigUint) -> RsaPrivateKey {
+    // SOLUTION-BEGIN
+    RsaPrivateKey {
+        n: public_key.n.clone(),
+        d: public_key.e.modinv(phi).unwrap(),
+    }
+    // SOLUTION-END
+}
+
+fn factor_with_phi(n: &BigUint, phi: &BigUint) -> (BigUint, BigUint) {
+    // SOLUTION-BEGIN
+    // p and q are the roots of X^2 - (p + q) X + n
+    let sum = n + 1u32 - phi;
+    let discriminant = &sum * &sum - n * 4u32;
+    let root = discriminant.sqrt();
+    assert_eq!(&root * &root, discriminant);
+    let p = (&sum + &root) / 2u32;
+    let (q, remainder) = n.div_rem(&p);
+    assert!(remainder == BigUint::ZERO);
+    (p, q)
+    // SOLUTION-END
+}
+
+fn hastad_broadcast_attack(ciphertexts: &[BigUint], moduli: &[BigUint]) -> BigUint {
+    // SOLUTION-BEGIN
+    let cube = crt(ciphertexts, moduli);
+    let m = cube.cbrt();
+    assert_eq!(m.pow(3), cube);
+    m
+    // SOLUTION-END
+}
+
+fn paillier_keygen(p: &BigUint, q: &BigUint) -> PaillierPrivateKey {
+    // SOLUTION-BEGIN
+    let public_key = PaillierPublicKey::new(p * q);
+    let lambda = (p - 1u32).lcm(&(q - 1u32));
+    let mu = lambda.modinv(&public_key.n).unwrap();
+    PaillierPrivateKey {
+        public_key,
+        lambda,
+        mu,
+    }
+    // SOLUTION-END
+}
+
+fn paillier_encrypt<R: Rng>(public_key: &PaillierPublicKey, m: &BigUint, rng: &mut R) -> BigUint {
+    // SOLUTION-BEGIN
+    assert!(m < &public_key.n);
+    let r = public_key.random_nonce(rng);
+    public_key.g().modpow(m, &public_key.n_squared) * r.modpow(&public_key.n, &public_key.n_squared)
+        % &public_key.n_squared
+    // SOLUTION-END
+}
+
+fn paillier_decrypt(private_key: &PaillierPrivateKey, c: &BigUint) -> BigUint {
+    // SOLUTION-BEGIN
+    let public_key = &private_key.public_key;
+    let u = c.modpow(&private_key.lambda, &public_key.n_squared);
+    l_function(&u, &public_key.n) * &private_key.mu % &public_key.n
+    // SOLUTION-END
+}
+
+fn add_ciphertexts(public_key: &PaillierPublicKey, c1: &BigUint, c2: &BigUint) -> BigUint {
+    // SOLUTION-BEGIN
+    c1 * c2 % &public_key.n_squared
+    // SOLUTION-END
+}
+
+fn scalar_mul_ciphertext(public_key: &PaillierPublicKey, c: &BigUint, k: &BigUint) -> BigUint {
+    // SOLUTION-BEGIN
+    c.modpow(k, &public_key.n_squared)
+    // SOLUTION-END
+}
+
+fn tally(public_key: &PaillierPublicKey, ballots: &[BigUint]) -> BigUint {
+    // SOLUTION-BEGIN
+    ballots.iter().fold(BigUint::one(), |sum, ballot| {
+        add_ciphertexts(public_key, &sum, ballot)
+    })
+    // SOLUTION-END
+}
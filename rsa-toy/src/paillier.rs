@@ -0,0 +1,63 @@
+use crate::*;
+
+// Paillier encryption, additively homomorphic, secure under the decisional composite residuosity assumption
+// (distinguishing n-th powers mod n^2), another problem than discrete logs which is easy when n can be factored
+// - key generation: n = pq, g = n + 1, lambda = lcm(p - 1, q - 1) and mu = lambda^-1 mod n
+// - encryption of m in Z_n: c = g^m r^n mod n^2 for a random r in Z_n^*
+// - decryption: m = L(c^lambda mod n^2) mu mod n where L(x) = (x - 1) / n
+// It works because (n + 1)^m = 1 + mn mod n^2, and r^(n lambda) = 1 mod n^2
+// Multiplying ciphertexts adds the plaintexts: Enc(m1) Enc(m2) = Enc(m1 + m2)
+
+#[derive(Clone, Debug)]
+pub struct PaillierPublicKey {
+    pub n: BigUint,
+    pub n_squared: BigUint,
+}
+
+#[derive(Clone, Debug)]
+pub struct PaillierPrivateKey {
+    pub public_key: PaillierPublicKey,
+    pub lambda: BigUint,
+    pub mu: BigUint,
+}
+
+impl PaillierPublicKey {
+    pub fn new(n: BigUint) -> PaillierPublicKey {
+        PaillierPublicKey {
+            n_squared: &n * &n,
+            n,
+        }
+    }
+
+    pub fn g(&self) -> BigUint {
+        &self.n + 1u32
+    }
+
+    pub fn random_nonce<R: Rng>(&self, rng: &mut R) -> BigUint {
+        loop {
+            let r = rng.gen_biguint_below(&self.n);
+            if r.gcd(&self.n).is_one() {
+                return r;
+            }
+        }
+    }
+}
+
+// L(x) = (x - 1) / n
+pub fn l_function(x: &BigUint, n: &BigUint) -> BigUint {
+    (x - 1u32) / n
+}
+
+// e-voting: every voter encrypts 1 for "yes" or 0 for "no", the product of all ballots is decrypted
+// by the election authority, which learns the number of "yes" but no individual vote
+// (a real election also needs proofs that each ballot encrypts 0 or 1, and a threshold decryption)
+pub fn cast_ballots<R: Rng>(
+    public_key: &PaillierPublicKey,
+    votes: &[bool],
+    rng: &mut R,
+) -> Vec<BigUint> {
+    votes
+        .iter()
+        .map(|vote| paillier_encrypt(public_key, &BigUint::from(*vote as u32), rng))
+        .collect()
+}
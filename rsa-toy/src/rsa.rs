@@ -0,0 +1,44 @@
+use crate::*;
+
+// Textbook RSA
+// - key generation: n = pq for two large primes p and q, e coprime with phi(n) = (p - 1)(q - 1)
+//   and d = e^-1 mod phi(n), so that (m^e)^d = m mod n for all m (Euler's theorem)
+// - encryption c = m^e mod n, decryption m = c^d mod n
+// - signature s = m^d mod n, verification s^e = m mod n
+// "Textbook" means without padding: deterministic, malleable and insecure, real RSA uses OAEP and PSS
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RsaPublicKey {
+    pub n: BigUint,
+    pub e: BigUint,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RsaPrivateKey {
+    pub n: BigUint,
+    pub d: BigUint,
+}
+
+// a message is the big-endian integer of its bytes, it must be smaller than n
+pub fn encode_message(message: &str) -> BigUint {
+    BigUint::from_bytes_be(message.as_bytes())
+}
+
+pub fn decode_message(m: &BigUint) -> String {
+    String::from_utf8_lossy(&m.to_bytes_be()).into_owned()
+}
+
+// the x in [0, m_1 ... m_k) with x = r_i mod m_i, for pairwise coprime moduli
+pub fn crt(residues: &[BigUint], moduli: &[BigUint]) -> BigUint {
+    let product: BigUint = moduli.iter().product();
+    residues
+        .iter()
+        .zip(moduli)
+        .map(|(r, m)| {
+            let cofactor = &product / m;
+            let inverse = (&cofactor % m).modinv(m).expect("moduli must be coprime");
+            r * cofactor * inverse
+        })
+        .sum::<BigUint>()
+        % product
+}
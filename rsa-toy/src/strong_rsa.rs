@@ -0,0 +1,56 @@
+use crate::*;
+
+// Roots in a group of unknown order: Z_n^* for an RSA modulus n whose factorization nobody knows
+// - RSA assumption: given n, e and a random y, computing the x with x^e = y mod n is hard
+// - strong RSA assumption: given n and a random y, computing any x and any e > 1 with x^e = y is hard, the
+//   solver choosing e
+// Both are easy with phi(n): x = y^(e^-1 mod phi(n)), which is what `RootOracle` does for the exercise
+// Shamir's trick turns roots for coprime exponents into a root for their product: from x^a = y^b with
+// gcd(a, b) = 1, Bezout's s a + t b = 1 gives z = y^s x^t with z^a = y^(s a) x^(t a) = y^(s a + t b) = y
+// It is how RSA accumulators aggregate membership witnesses, and why their security rests on the strong RSA
+// assumption: a root of g for an exponent whose prime factors all have known roots is no forgery
+
+// e-th roots of `g` for whoever asks: the factorization of n stays inside
+pub struct RootOracle {
+    pub n: BigUint,
+    pub g: BigUint,
+    phi: BigUint,
+}
+
+impl RootOracle {
+    pub fn new<R: Rng>(p: &BigUint, q: &BigUint, rng: &mut R) -> RootOracle {
+        let n = p * q;
+        let g = rng.gen_biguint_below(&n);
+        RootOracle {
+            phi: (p - 1u32) * (q - 1u32),
+            n,
+            g,
+        }
+    }
+
+    // `count` primes from 65537 on, coprime with phi(n): exponents which have roots
+    pub fn exponents(&self, count: usize) -> Vec<BigUint> {
+        (65537u32..)
+            .step_by(2)
+            .filter(|k| (3..*k).take_while(|d| d * d <= *k).all(|d| k % d != 0))
+            .map(BigUint::from)
+            .filter(|e| e.gcd(&self.phi).is_one())
+            .take(count)
+            .collect()
+    }
+
+    // the e-th root of g
+    pub fn root(&self, e: &BigUint) -> BigUint {
+        let d = e.modinv(&self.phi).expect("e must be coprime with phi(n)");
+        self.g.modpow(&d, &self.n)
+    }
+}
+
+// x^k mod n for a negative k too: (x^-1)^|k|
+pub fn pow_signed(x: &BigUint, k: &BigInt, n: &BigUint) -> BigUint {
+    let x = match k.sign() {
+        num_bigint::Sign::Minus => x.modinv(n).expect("x must be invertible mod n"),
+        _ => x.clone(),
+    };
+    x.modpow(k.magnitude(), n)
+}
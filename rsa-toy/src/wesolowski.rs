@@ -0,0 +1,56 @@
+use crate::*;
+use sha2::{Digest, Sha256};
+
+// A verifiable delay function (VDF) over the RSA group: y = x^(2^t) mod n takes t squarings one after the
+// other, with no shortcut without the order phi(n) of the group, and the evaluator proves its result with a
+// single group element (Wesolowski, "Efficient verifiable delay functions")
+// - the challenge is a 128-bit prime l = H_prime(x, y, t), chosen after y (Fiat-Shamir)
+// - the proof is pi = x^q with 2^t = q l + r, 0 <= r < l
+// - the verifier computes r = 2^t mod l, two small exponentiations, and checks pi^l x^r = y
+// H_prime hashes x, y (big-endian, on the byte length of n) and t (8 bytes, big-endian) with SHA-256, takes the
+// first 16 bytes with the top bit set, and returns the smallest prime from there
+// The same proof shows that y = x^e for any large exponent e, the product of the primes of an RSA accumulator
+// for instance: the verifier then does a 128-bit exponentiation instead of one of the size of e
+
+pub const CHALLENGE_BITS: u64 = 128;
+
+// Miller-Rabin with the first 20 primes as witnesses: no composite of 128 bits is known to pass
+pub fn is_probable_prime(n: &BigUint) -> bool {
+    const WITNESSES: [u32; 20] = [
+        2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71,
+    ];
+    if n < &BigUint::from(2u32) {
+        return false;
+    }
+    for witness in WITNESSES {
+        if (n % witness).is_zero() {
+            return n == &BigUint::from(witness);
+        }
+    }
+    let n_minus_1 = n - 1u32;
+    let s = n_minus_1.trailing_zeros().unwrap();
+    let d = &n_minus_1 >> s;
+    WITNESSES.iter().all(|witness| {
+        let mut x = BigUint::from(*witness).modpow(&d, n);
+        if x.is_one() || x == n_minus_1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = x.modpow(&BigUint::from(2u32), n);
+            if x == n_minus_1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+pub fn sha256(bytes: &[u8]) -> [u8; 32] {
+    Sha256::digest(bytes).into()
+}
+
+// x big-endian on `size` bytes
+pub fn to_bytes_padded(x: &BigUint, size: usize) -> Vec<u8> {
+    let bytes = x.to_bytes_be();
+    [vec![0; size - bytes.len()], bytes].concat()
+}
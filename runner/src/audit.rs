@@ -0,0 +1,154 @@
+// `cargo run --release -- --audit-determinism` runs the chapter twice with the same seed and compares the two
+// runs question by question: the output of each question, the digests it prints and the fields of the JSON
+// report must be the same. A randomized exercise or a per-student instance must not depend on anything but the
+// seed: not on the iteration order of a `HashMap` (randomized in each process), nor on the scheduling of threads
+//
+// The two runs are child processes, marked by an environment variable: they print a marker line at the start
+// of each question, and leave `progress.report` and `.training-progress.toml` alone
+// Timings vary from run to run, so the numbers with a decimal point (durations, ratios of durations, seconds
+// of the report) and the durations printed with `{:?}` are masked before the comparison
+
+use crate::messages;
+use std::collections::BTreeMap;
+use std::process::{Command, Stdio};
+
+pub const AUDIT_OPTION: &str = "--audit-determinism";
+const CHILD_VARIABLE: &str = "TRAINING_AUDIT_CHILD";
+const QUESTION_MARKER: &str = "\u{1e}question ";
+const REPORT: &str = "report";
+const BEFORE_QUESTIONS: &str = "before Q1";
+const TIME_UNITS: [&str; 4] = ["ns", "µs", "ms", "s"];
+// the differences printed per question
+const MAX_DIFFERENCES: usize = 3;
+
+pub(crate) fn requested() -> bool {
+    seed::args().iter().any(|arg| arg == AUDIT_OPTION)
+}
+
+pub(crate) fn is_child() -> bool {
+    std::env::var_os(CHILD_VARIABLE).is_some()
+}
+
+pub(crate) fn mark_question(ids: &[&str]) {
+    println!("{}{}", QUESTION_MARKER, ids.join(","));
+}
+
+// the lines printed during each question, the JSON report under `REPORT`
+type Run = BTreeMap<String, Vec<String>>;
+
+fn run() -> Run {
+    let args = std::env::args()
+        .skip(1)
+        .filter(|arg| arg != AUDIT_OPTION)
+        .chain(["--report".to_string(), "json".to_string()]);
+    let output = Command::new(std::env::current_exe().expect("cannot find the executable"))
+        .args(args)
+        .env(CHILD_VARIABLE, "1")
+        .stderr(Stdio::null())
+        .output()
+        .expect("cannot run the chapter");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines: Vec<&str> = stdout.lines().collect();
+    let mut run = Run::new();
+    if let Some(report) = lines.pop() {
+        run.insert(REPORT.to_string(), vec![mask(report)]);
+    }
+    let mut question = BEFORE_QUESTIONS.to_string();
+    for line in lines {
+        match line.strip_prefix(QUESTION_MARKER) {
+            Some(ids) => {
+                question = ids.to_string();
+                run.entry(question.clone()).or_default();
+            }
+            None => run.entry(question.clone()).or_default().push(mask(line)),
+        }
+    }
+    run
+}
+
+// '#' in place of the numbers with a decimal point and of the numbers followed by a unit of time
+fn mask(line: &str) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let mut masked = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let starts_number = chars[i].is_ascii_digit()
+            && (i == 0 || !(chars[i - 1].is_alphanumeric() || chars[i - 1] == '.'));
+        if !starts_number {
+            masked.push(chars[i]);
+            i += 1;
+            continue;
+        }
+        let mut end = i;
+        while end < chars.len() && chars[end].is_ascii_digit() {
+            end += 1;
+        }
+        let mut decimal = false;
+        if end + 1 < chars.len() && chars[end] == '.' && chars[end + 1].is_ascii_digit() {
+            decimal = true;
+            end += 1;
+            while end < chars.len() && chars[end].is_ascii_digit() {
+                end += 1;
+            }
+        }
+        let rest: String = chars[end..].iter().take(2).collect();
+        let unit = TIME_UNITS.iter().find(|unit| {
+            rest.starts_with(*unit)
+                && !chars
+                    .get(end + unit.chars().count())
+                    .is_some_and(|c| c.is_alphanumeric())
+        });
+        match (decimal, unit) {
+            (_, Some(unit)) => {
+                masked.push('#');
+                end += unit.chars().count();
+            }
+            (true, None) => masked.push('#'),
+            (false, None) => masked.extend(&chars[i..end]),
+        }
+        i = end;
+    }
+    masked
+}
+
+// runs the chapter twice, prints the differences and exits: with status 1 if the runs differ
+pub(crate) fn audit(chapter: &str) -> ! {
+    println!("{}", messages::running_twice(chapter));
+    let (first, second) = (run(), run());
+    let mut differing = 0;
+    let empty = vec![];
+    for question in first.keys().filter(|q| !second.contains_key(*q)) {
+        println!("{}", messages::only_reached_by(question, true));
+        differing += 1;
+    }
+    for question in second.keys().filter(|q| !first.contains_key(*q)) {
+        println!("{}", messages::only_reached_by(question, false));
+        differing += 1;
+    }
+    for (question, lines) in &first {
+        let other = second.get(question).unwrap_or(&empty);
+        if !second.contains_key(question) || lines == other {
+            continue;
+        }
+        differing += 1;
+        println!("{}", messages::runs_differ(question));
+        let differences = (0..lines.len().max(other.len()))
+            .filter(|i| lines.get(*i) != other.get(*i))
+            .take(MAX_DIFFERENCES);
+        for i in differences {
+            println!("{}", messages::run_line(true, lines.get(i)));
+            println!("{}", messages::run_line(false, other.get(i)));
+        }
+    }
+    let lines: usize = first.values().map(|lines| lines.len()).sum();
+    let questions = first
+        .keys()
+        .filter(|key| *key != REPORT && *key != BEFORE_QUESTIONS)
+        .count();
+    if differing > 0 {
+        println!("{}", messages::not_deterministic(chapter, differing));
+        std::process::exit(1);
+    }
+    println!("{}", messages::deterministic(chapter, questions, lines));
+    std::process::exit(0);
+}
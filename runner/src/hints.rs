@@ -0,0 +1,56 @@
+// Progressive hints: each chapter lists, in its module `hints`, a few hints per question, from the concept
+// to the formula to nearly the solution, and `cargo run --release -- hint q3` reveals them one at a time
+// The number of hints revealed is kept in the `hints.<chapter>` section of `.training-progress.toml`,
+// for the instructors
+// The hints come in the language of the run, after the statement of the question, see module `lang`
+
+use crate::lang::{self, Catalog, Lang};
+use crate::messages;
+use crate::progress::{read, write};
+
+pub const HINT_COMMAND: &str = "hint";
+
+// (question, hints in the order they are revealed)
+pub type Hints = &'static [(&'static str, &'static [&'static str])];
+
+fn section(chapter: &str) -> String {
+    format!("hints.{}", chapter)
+}
+
+/// Prints the statement of `question`, its hints revealed so far and the next one, `q3` or `Q3`
+pub fn hint(
+    chapter: &'static str,
+    catalogs: &'static [Catalog],
+    question: &str,
+) -> std::io::Result<()> {
+    let question = question.to_uppercase();
+    let Some(levels) = lang::hints(catalogs, &question) else {
+        let english = catalogs.iter().filter(|catalog| catalog.lang == Lang::En);
+        let ids: Vec<&str> = english
+            .flat_map(|catalog| catalog.hints.iter().map(|(id, _)| *id))
+            .collect();
+        println!(
+            "{}",
+            messages::no_hints(&question, chapter, &ids.join(", "))
+        );
+        return Ok(());
+    };
+    if let Some(statement) = lang::statement(catalogs, &question) {
+        println!("{}", messages::statement(&question, &statement));
+    }
+    let mut file = read()?;
+    let section = section(chapter);
+    let revealed = match file.contains(&section, &question) {
+        true => file.get::<usize>(&section, &question),
+        false => 0,
+    };
+    let revealed = (revealed + 1).min(levels.len());
+    for (i, level) in levels.iter().take(revealed).enumerate() {
+        println!("{}", messages::hint(i + 1, levels.len(), &question, level));
+    }
+    if revealed == levels.len() {
+        println!("{}", messages::last_hint(&question));
+    }
+    file.set(&section, &question, revealed);
+    write(&file)
+}
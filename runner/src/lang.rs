@@ -0,0 +1,174 @@
+// The language of the training: the messages of the runner, the hints and the statements of the questions
+// English by default, French with `cargo run --release -- --lang fr` or the `TRAINING_LANG` environment
+// variable, the command line winning over the environment as for `--seed`
+//
+// Each chapter hands `Runner::start` one catalog per language, the `CATALOGS` of its module `hints`. The
+// English one is built from the `HINTS` next to it and from the `// Qn:` comments of its `main.rs`, which stay
+// the reference statements; the French one is the `CATALOG` of its module `fr`, statements and hints
+// translated. A question missing from the catalog of the language falls back to English, so that a new
+// question can ship before its translation
+// The messages of the runner itself are in module `messages`
+
+use crate::hints::Hints;
+use std::sync::OnceLock;
+
+pub const LANG_OPTION: &str = "--lang";
+pub const LANG_VARIABLE: &str = "TRAINING_LANG";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Lang {
+    En,
+    Fr,
+}
+
+impl Lang {
+    pub fn parse(code: &str) -> Option<Lang> {
+        match code.to_lowercase().as_str() {
+            "en" => Some(Lang::En),
+            "fr" => Some(Lang::Fr),
+            _ => None,
+        }
+    }
+}
+
+pub enum Statements {
+    // the `// Qn:` comments of a `main.rs` and the comment lines that follow them
+    Comments(&'static str),
+    // (question, statement)
+    Table(&'static [(&'static str, &'static str)]),
+}
+
+pub struct Catalog {
+    pub lang: Lang,
+    pub hints: Hints,
+    pub statements: Statements,
+}
+
+impl Catalog {
+    /// The English catalog of a chapter: `Catalog::english(HINTS, include_str!("main.rs"))`
+    pub const fn english(hints: Hints, source: &'static str) -> Catalog {
+        Catalog {
+            lang: Lang::En,
+            hints,
+            statements: Statements::Comments(source),
+        }
+    }
+
+    pub fn statement(&self, question: &str) -> Option<String> {
+        match &self.statements {
+            Statements::Table(table) => table
+                .iter()
+                .find(|(id, _)| *id == question)
+                .map(|(_, statement)| statement.to_string()),
+            Statements::Comments(source) => comment_statement(source, question),
+        }
+    }
+}
+
+// `// Q3: text` and the comment lines right after it, without their `//`
+fn comment_statement(source: &str, question: &str) -> Option<String> {
+    let start = format!("// {}: ", question);
+    let mut lines = source.lines().map(str::trim);
+    let first = lines.find_map(|line| line.strip_prefix(&start))?;
+    let mut statement = first.to_string();
+    for line in lines.map_while(|line| line.strip_prefix("//")) {
+        let line = line.trim();
+        if starts_question(line) {
+            break;
+        }
+        statement += "\n";
+        statement += line;
+    }
+    Some(statement)
+}
+
+// `Q12: ...`, the statement of the next question
+fn starts_question(line: &str) -> bool {
+    let Some(rest) = line.strip_prefix('Q') else {
+        return false;
+    };
+    let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    digits > 0 && rest[digits..].starts_with(':')
+}
+
+/// The questions of the chapter, in the order of their `// Qn:` comments in the English catalog
+pub fn questions(catalogs: &'static [Catalog]) -> Vec<&'static str> {
+    let mut ids: Vec<&'static str> = vec![];
+    for catalog in catalogs.iter().filter(|catalog| catalog.lang == Lang::En) {
+        let Statements::Comments(source) = catalog.statements else {
+            continue;
+        };
+        for line in source.lines() {
+            let Some(comment) = line.trim().strip_prefix("//") else {
+                continue;
+            };
+            let comment = comment.trim_start();
+            if starts_question(comment) {
+                let id = &comment[..comment.find(':').unwrap()];
+                if !ids.contains(&id) {
+                    ids.push(id);
+                }
+            }
+        }
+    }
+    ids
+}
+
+/// The language chosen on the command line, in the environment, or English
+/// Panics on another language than `en` and `fr`
+pub fn lang() -> Lang {
+    static LANG: OnceLock<Lang> = OnceLock::new();
+    *LANG.get_or_init(|| {
+        let code = split_lang(std::env::args().skip(1).collect())
+            .1
+            .or_else(|| std::env::var(LANG_VARIABLE).ok());
+        match code {
+            Some(code) => Lang::parse(&code).unwrap_or_else(|| panic!("usage: --lang en|fr")),
+            None => Lang::En,
+        }
+    })
+}
+
+// accepts `--lang <code>` and `--lang=<code>`, the last one wins
+pub(crate) fn split_lang(args: Vec<String>) -> (Vec<String>, Option<String>) {
+    let mut rest = vec![];
+    let mut code = None;
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == LANG_OPTION {
+            code = Some(args.next().expect("usage: --lang en|fr"));
+        } else if let Some(value) = arg.strip_prefix("--lang=") {
+            code = Some(value.to_string());
+        } else {
+            rest.push(arg);
+        }
+    }
+    (rest, code)
+}
+
+// the catalog of the language of the run, then the English one
+fn in_order(catalogs: &'static [Catalog]) -> impl Iterator<Item = &'static Catalog> {
+    let lang = lang();
+    let first = catalogs.iter().filter(move |catalog| catalog.lang == lang);
+    first.chain(
+        catalogs
+            .iter()
+            .filter(move |catalog| catalog.lang == Lang::En && lang != Lang::En),
+    )
+}
+
+/// The hints of `question` in the language of the run, in English if they are not translated
+pub fn hints(catalogs: &'static [Catalog], question: &str) -> Option<&'static [&'static str]> {
+    in_order(catalogs).find_map(|catalog| {
+        catalog
+            .hints
+            .iter()
+            .find(|(id, _)| *id == question)
+            .map(|(_, hints)| *hints)
+    })
+}
+
+/// The statement of `question` in the language of the run, in English if it is not translated
+pub fn statement(catalogs: &'static [Catalog], question: &str) -> Option<String> {
+    in_order(catalogs).find_map(|catalog| catalog.statement(question))
+}
@@ -0,0 +1,414 @@
+//! Question-by-question bookkeeping of the exercises
+//!
+//! The `main` of a chapter is a single script: each question is a comment followed by the checks of the
+//! student's answer. `Runner::question` marks where a question's checks start, so that the first failing
+//! check is attributed to its question, and every question before it is known to pass.
+//!
+//! Every run counts an attempt for each question it reaches, in the `attempts.<chapter>` section of the
+//! progress report at the root of the repository.
+//!
+//! With `--report json`, the last line printed is a JSON document for the instructors' scripts:
+//!
+//! ```text
+//! {"chapter": "ff-ec", "seed": "default", "completed": false, "seconds": 1.234,
+//!  "questions": [{"id": "Q1", "passed": true, "seconds": 0.012, "attempts": 3}, ...,
+//!                {"id": "Q5", "passed": false, "seconds": 0.001, "attempts": 1, "message": "..."}],
+//!  "not_reached": ["Q6", ...]}
+//! ```
+//!
+//! Questions after the failing one are not run: `not_reached` lists them, in the order of their `// Qn:`
+//! comments. Discussion questions have no checks: they always pass.
+//!
+//! The questions passed are saved in `.training-progress.toml` at the root of the repository: the next runs
+//! announce the first unfinished question, and `cargo run --release -- reset` starts the chapter over, see
+//! module `progress`.
+//!
+//! Each chapter registers progressive hints for its questions: `cargo run --release -- hint q3` reveals the
+//! next one, see module `hints`.
+//!
+//! `--lang fr` switches the messages of the runner, the hints and the statements of the questions to French,
+//! from the catalogs each chapter hands to `Runner::start`, see module `lang`. A failing question is
+//! followed by a reminder of its statement.
+//!
+//! `property::for_all` checks a student's function against a reference on edge cases and hundreds of random
+//! inputs, see module `property`.
+//!
+//! `cargo run --release -- --audit-determinism` runs the chapter twice with the same seed and fails if the output
+//! of a question or the report differs between the two runs, see module `audit`.
+//!
+//! The *grade* crate runs every chapter with the `TRAINING_GRADE` environment variable set: the run starts from
+//! scratch, records neither attempts nor progress, and prints the JSON report last whatever `--report` says.
+//!
+//! With the `memprofile` feature, e.g. `cargo run --release --features runner/memprofile`, every question also
+//! reports its allocations, the bytes allocated and its peak of bytes in use, see module `memory`.
+
+use lang::Catalog;
+use memory::{Allocations, Snapshot};
+use progress::Progress;
+use sage_fixtures::Fixtures;
+use std::fmt::Write;
+use std::panic::{self, PanicHookInfo};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub mod audit;
+pub mod hints;
+pub mod lang;
+pub mod memory;
+pub mod messages;
+pub mod progress;
+pub mod property;
+
+pub const PROGRESS_REPORT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../progress.report");
+pub const REPORT_OPTION: &str = "--report";
+pub const GRADE_VARIABLE: &str = "TRAINING_GRADE";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReportFormat {
+    Json,
+}
+
+#[derive(Clone, Debug)]
+pub struct QuestionResult {
+    pub id: String,
+    pub passed: bool,
+    pub time: Duration,
+    pub attempts: u64,
+    pub message: Option<String>,
+    pub memory: Option<Allocations>, // with the `memprofile` feature
+}
+
+struct State {
+    chapter: &'static str,
+    catalogs: &'static [Catalog],
+    format: Option<ReportFormat>,
+    start: Instant,
+    results: Vec<QuestionResult>,
+    // the questions whose checks are running, and since when
+    current: Vec<String>,
+    current_start: Instant,
+    current_memory: Snapshot,
+    progress: Progress,
+    // whether the first unfinished question was announced
+    resumed: bool,
+    finished: bool,
+    // a run of `--audit-determinism`
+    audited: bool,
+    // a run of the grader
+    graded: bool,
+}
+
+pub struct Runner {
+    state: Arc<Mutex<State>>,
+}
+
+/// The arguments of the program (without its name), `--seed <text>`, `--report <format>`, `--lang <code>`
+/// and `--audit-determinism` removed, for the binaries dispatching subcommands
+pub fn args() -> Vec<String> {
+    let mut args = split_report(lang::split_lang(seed::args()).0).0;
+    args.retain(|arg| arg != audit::AUDIT_OPTION);
+    args
+}
+
+fn split_report(args: Vec<String>) -> (Vec<String>, Option<ReportFormat>) {
+    let mut rest = vec![];
+    let mut format = None;
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let value = if arg == REPORT_OPTION {
+            args.next()
+        } else if let Some(value) = arg.strip_prefix("--report=") {
+            Some(value.to_string())
+        } else {
+            rest.push(arg);
+            continue;
+        };
+        format = match value.as_deref() {
+            Some("json") => Some(ReportFormat::Json),
+            _ => panic!("usage: --report json"),
+        };
+    }
+    (rest, format)
+}
+
+impl Runner {
+    /// Starts the bookkeeping of the chapter: call it first thing in `main`, after the subcommands
+    /// It serves the `reset` and `hint` commands itself, and exits after them
+    /// `catalogs` holds the hints and statements of the chapter, one catalog per language
+    pub fn start(chapter: &'static str, catalogs: &'static [Catalog]) -> Runner {
+        match args().as_slice() {
+            [command] if command == progress::RESET_COMMAND => {
+                progress::reset(chapter).unwrap_or_else(|error| {
+                    panic!("{}: {}", messages::cannot_write_progress(), error)
+                });
+                println!("{}", messages::reset(chapter));
+                std::process::exit(0);
+            }
+            [command, question] if command == hints::HINT_COMMAND => {
+                hints::hint(chapter, catalogs, question).unwrap_or_else(|error| {
+                    panic!("{}: {}", messages::cannot_write_progress(), error)
+                });
+                std::process::exit(0);
+            }
+            _ => {}
+        }
+        let child = audit::is_child();
+        if audit::requested() && !child {
+            audit::audit(chapter);
+        }
+        // the runs of an audit or of the grader start from scratch, and leave no trace
+        let graded = std::env::var_os(GRADE_VARIABLE).is_some();
+        let progress = match child || graded {
+            true => Ok(Progress {
+                chapter,
+                passed: Default::default(),
+            }),
+            false => Progress::load(chapter),
+        };
+        let progress = progress.unwrap_or_else(|error| {
+            eprintln!("{}: {}", messages::cannot_read_progress(), error);
+            Progress {
+                chapter,
+                passed: Default::default(),
+            }
+        });
+        let now = Instant::now();
+        let state = Arc::new(Mutex::new(State {
+            chapter,
+            catalogs,
+            format: split_report(lang::split_lang(seed::args()).0).1,
+            start: now,
+            results: vec![],
+            current: vec![],
+            current_start: now,
+            current_memory: Snapshot::now(),
+            progress,
+            resumed: false,
+            finished: false,
+            audited: child,
+            graded,
+        }));
+        // the first failing check of the main thread ends the run, the report comes after its message
+        let hook_state = state.clone();
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            previous_hook(info);
+            if thread::current().name() == Some("main") {
+                if let Ok(mut state) = hook_state.lock() {
+                    state.fail(info);
+                }
+            }
+        }));
+        Runner { state }
+    }
+
+    /// The checks that follow belong to question `id`
+    pub fn question(&self, id: &str) {
+        self.questions(&[id]);
+    }
+
+    /// The checks that follow are shared by several questions
+    pub fn questions(&self, ids: &[&str]) {
+        let mut state = self.state.lock().unwrap();
+        state.close_current(true, None);
+        if state.audited {
+            audit::mark_question(ids);
+        }
+        state.current = ids.iter().map(|id| id.to_string()).collect();
+        state.announce_resume();
+        state.current_start = Instant::now();
+        state.current_memory = Snapshot::now();
+    }
+
+    /// All the checks passed
+    pub fn finish(self) {
+        let mut state = self.state.lock().unwrap();
+        state.close_current(true, None);
+        state.finished = true;
+        state.end();
+    }
+}
+
+impl State {
+    fn announce_resume(&mut self) {
+        let passed = &self.progress.passed;
+        if self.resumed || passed.is_empty() {
+            return;
+        }
+        if let Some(id) = self.current.iter().find(|id| !passed.contains(*id)) {
+            println!("{}", messages::resuming(id, passed.len()));
+            self.resumed = true;
+        }
+    }
+
+    fn close_current(&mut self, passed: bool, message: Option<String>) {
+        let time = self.current_start.elapsed();
+        let memory = memory::ENABLED.then(|| self.current_memory.since());
+        for id in std::mem::take(&mut self.current) {
+            self.results.push(QuestionResult {
+                id,
+                passed,
+                time,
+                attempts: 0,
+                message: message.clone(),
+                memory,
+            });
+        }
+    }
+
+    fn fail(&mut self, info: &PanicHookInfo) {
+        if self.finished {
+            return;
+        }
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        let message = match info.location() {
+            Some(location) => format!("{} at {}:{}", message, location.file(), location.line()),
+            None => message,
+        };
+        for id in &self.current {
+            if self.progress.passed.contains(id) {
+                eprintln!("{}", messages::fails_again(id));
+            }
+            if let Some(statement) = lang::statement(self.catalogs, id) {
+                eprintln!("{}", messages::reminder(id, &statement));
+            }
+        }
+        self.close_current(false, Some(message));
+        self.finished = true;
+        self.end();
+    }
+
+    // counts the attempts, saves the progress and prints the report
+    fn end(&mut self) {
+        if self.audited || self.graded {
+            println!("{}", self.to_json());
+            return;
+        }
+        if let Err(error) = self.record_attempts() {
+            eprintln!("{}: {}", messages::cannot_write_report(), error);
+        }
+        for result in &self.results {
+            if result.passed {
+                self.progress.passed.insert(result.id.clone());
+            } else {
+                self.progress.passed.remove(&result.id);
+            }
+        }
+        if let Err(error) = self.progress.save() {
+            eprintln!("{}: {}", messages::cannot_write_progress(), error);
+        }
+        match self.format {
+            Some(ReportFormat::Json) => println!("{}", self.to_json()),
+            None if memory::ENABLED => self.print_memory(),
+            None => {}
+        }
+    }
+
+    fn record_attempts(&mut self) -> std::io::Result<()> {
+        let mut report = match std::fs::read_to_string(PROGRESS_REPORT) {
+            Ok(text) => text
+                .parse()
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Fixtures::new(),
+            Err(e) => return Err(e),
+        };
+        let section = format!("attempts.{}", self.chapter);
+        for result in &mut self.results {
+            let previous = if report.contains(&section, &result.id) {
+                report.get::<u64>(&section, &result.id)
+            } else {
+                0
+            };
+            result.attempts = previous + 1;
+            report.set(&section, &result.id, result.attempts);
+        }
+        std::fs::write(PROGRESS_REPORT, report.to_string())
+    }
+
+    fn print_memory(&self) {
+        let [question, allocations, allocated, peak] = messages::memory_columns();
+        println!(
+            "{:<8} {:>12} {:>12} {:>12}",
+            question, allocations, allocated, peak
+        );
+        for result in &self.results {
+            let memory = result.memory.unwrap_or_default();
+            println!(
+                "{:<8} {:>12} {:>12} {:>12}",
+                result.id,
+                memory.count,
+                memory::format_bytes(memory.bytes),
+                memory::format_bytes(memory.peak)
+            );
+        }
+    }
+
+    fn to_json(&self) -> String {
+        let seed = if seed::seed() == seed::DEFAULT_SEED {
+            "default"
+        } else {
+            "custom"
+        };
+        let all_passed = self.results.iter().all(|result| result.passed);
+        let mut json = format!(
+            "{{\"chapter\": {}, \"seed\": \"{}\", \"completed\": {}, \"seconds\": {:.3}, \"questions\": [",
+            json_string(self.chapter),
+            seed,
+            self.finished && all_passed,
+            self.start.elapsed().as_secs_f64()
+        );
+        for (i, result) in self.results.iter().enumerate() {
+            if i > 0 {
+                json += ", ";
+            }
+            write!(
+                json,
+                "{{\"id\": {}, \"passed\": {}, \"seconds\": {:.3}, \"attempts\": {}",
+                json_string(&result.id),
+                result.passed,
+                result.time.as_secs_f64(),
+                result.attempts
+            )
+            .unwrap();
+            if let Some(memory) = &result.memory {
+                write!(
+                    json,
+                    ", \"allocations\": {}, \"allocated_bytes\": {}, \"peak_bytes\": {}",
+                    memory.count, memory.bytes, memory.peak
+                )
+                .unwrap();
+            }
+            if let Some(message) = &result.message {
+                write!(json, ", \"message\": {}", json_string(message)).unwrap();
+            }
+            json += "}";
+        }
+        json += "], \"not_reached\": [";
+        let not_reached = lang::questions(self.catalogs)
+            .into_iter()
+            .filter(|id| !self.results.iter().any(|result| result.id == *id))
+            .map(json_string);
+        json += &not_reached.collect::<Vec<_>>().join(", ");
+        json + "]}"
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => escaped += "\\\"",
+            '\\' => escaped += "\\\\",
+            '\n' => escaped += "\\n",
+            c if (c as u32) < 0x20 => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped + "\""
+}
@@ -0,0 +1,112 @@
+// Allocation counting, with the `memprofile` feature: a global allocator wrapping the system one counts the
+// allocations, the bytes allocated and the peak of the bytes in use, for all the threads of the program
+// Without the feature, nothing is counted and every measure is zero
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+pub const ENABLED: bool = cfg!(feature = "memprofile");
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+static IN_USE: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+pub struct CountingAllocator;
+
+#[cfg(feature = "memprofile")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn count(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size as u64, Ordering::Relaxed);
+    let in_use = IN_USE.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+    PEAK.fetch_max(in_use, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        IN_USE.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+
+    // a reallocation counts as a new allocation of the new size, the old one being freed
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        IN_USE.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+        count(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Allocations {
+    pub count: u64,
+    pub bytes: u64,
+    // the most bytes in use at once, on top of those in use at the start
+    pub peak: u64,
+}
+
+// The counters at some point, to measure what happens after it
+#[derive(Clone, Copy, Debug)]
+pub struct Snapshot {
+    count: u64,
+    bytes: u64,
+    in_use: u64,
+    // the peak before the snapshot, restored by `since` for the enclosing measures
+    outer_peak: u64,
+}
+
+impl Snapshot {
+    pub fn now() -> Snapshot {
+        let in_use = IN_USE.load(Ordering::Relaxed);
+        Snapshot {
+            count: ALLOCATIONS.load(Ordering::Relaxed),
+            bytes: ALLOCATED.load(Ordering::Relaxed),
+            in_use,
+            outer_peak: PEAK.swap(in_use, Ordering::Relaxed),
+        }
+    }
+
+    pub fn since(&self) -> Allocations {
+        let peak = PEAK.fetch_max(self.outer_peak, Ordering::Relaxed);
+        Allocations {
+            count: ALLOCATIONS.load(Ordering::Relaxed) - self.count,
+            bytes: ALLOCATED.load(Ordering::Relaxed) - self.bytes,
+            peak: peak.saturating_sub(self.in_use),
+        }
+    }
+}
+
+/// The allocations of `f`
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Allocations) {
+    let snapshot = Snapshot::now();
+    let result = f();
+    (result, snapshot.since())
+}
+
+// 1536 -> "1.5 KiB"
+pub fn format_bytes(bytes: u64) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < units.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, units[unit])
+    }
+}
@@ -0,0 +1,173 @@
+// The messages of the runner, in every language of module `lang`
+// One function per message, so that a translation keeps the arguments of the English text
+
+use crate::lang::{lang, Lang};
+
+pub fn reset(chapter: &str) -> String {
+    match lang() {
+        Lang::En => format!("The progress of {} is reset, back to Q1", chapter),
+        Lang::Fr => format!(
+            "La progression de {} est remise à zéro, retour à Q1",
+            chapter
+        ),
+    }
+}
+
+pub fn resuming(question: &str, passed: usize) -> String {
+    match lang() {
+        Lang::En => format!(
+            "Resuming at {}: {} questions passed in a previous run",
+            question, passed
+        ),
+        Lang::Fr => format!(
+            "Reprise à {} : {} questions réussies lors d'une exécution précédente",
+            question, passed
+        ),
+    }
+}
+
+pub fn fails_again(question: &str) -> String {
+    match lang() {
+        Lang::En => format!("{} passed in a previous run, it fails now", question),
+        Lang::Fr => format!(
+            "{} était réussie lors d'une exécution précédente, elle échoue maintenant",
+            question
+        ),
+    }
+}
+
+pub fn reminder(question: &str, statement: &str) -> String {
+    match lang() {
+        Lang::En => format!("Reminder, {}: {}", question, statement),
+        Lang::Fr => format!("Rappel, {} : {}", question, statement),
+    }
+}
+
+pub fn statement(question: &str, statement: &str) -> String {
+    match lang() {
+        Lang::En => format!("{}: {}", question, statement),
+        Lang::Fr => format!("{} : {}", question, statement),
+    }
+}
+
+pub fn no_hints(question: &str, chapter: &str, questions: &str) -> String {
+    match lang() {
+        Lang::En => format!(
+            "No hints for {} in {}, only for: {}",
+            question, chapter, questions
+        ),
+        Lang::Fr => format!(
+            "Pas d'indice pour {} dans {}, seulement pour : {}",
+            question, chapter, questions
+        ),
+    }
+}
+
+pub fn hint(level: usize, levels: usize, question: &str, hint: &str) -> String {
+    match lang() {
+        Lang::En => format!("Hint {}/{} for {}: {}", level, levels, question, hint),
+        Lang::Fr => format!("Indice {}/{} pour {} : {}", level, levels, question, hint),
+    }
+}
+
+pub fn last_hint(question: &str) -> String {
+    match lang() {
+        Lang::En => format!("That was the last hint for {}", question),
+        Lang::Fr => format!("C'était le dernier indice pour {}", question),
+    }
+}
+
+pub fn cannot_read_progress() -> &'static str {
+    match lang() {
+        Lang::En => "cannot read the training progress",
+        Lang::Fr => "impossible de lire la progression de la formation",
+    }
+}
+
+pub fn cannot_write_progress() -> &'static str {
+    match lang() {
+        Lang::En => "cannot write the training progress",
+        Lang::Fr => "impossible d'écrire la progression de la formation",
+    }
+}
+
+pub fn cannot_write_report() -> &'static str {
+    match lang() {
+        Lang::En => "cannot write the progress report",
+        Lang::Fr => "impossible d'écrire le rapport de progression",
+    }
+}
+
+// the columns of the table of the `memprofile` feature
+pub fn memory_columns() -> [&'static str; 4] {
+    match lang() {
+        Lang::En => ["question", "allocations", "allocated", "peak"],
+        Lang::Fr => ["question", "allocations", "alloués", "pic"],
+    }
+}
+
+pub fn property_fails(name: &str, input: &str) -> String {
+    match lang() {
+        Lang::En => format!("property `{}` fails on {}", name, input),
+        Lang::Fr => format!("la propriété `{}` est fausse pour {}", name, input),
+    }
+}
+
+pub fn running_twice(chapter: &str) -> String {
+    match lang() {
+        Lang::En => format!("Running {} twice with the same seed...", chapter),
+        Lang::Fr => format!("Deux exécutions de {} avec la même graine...", chapter),
+    }
+}
+
+pub fn only_reached_by(part: &str, first: bool) -> String {
+    match (lang(), first) {
+        (Lang::En, true) => format!("{}: only reached by the first run", part),
+        (Lang::En, false) => format!("{}: only reached by the second run", part),
+        (Lang::Fr, true) => format!("{} : atteinte par la première exécution seulement", part),
+        (Lang::Fr, false) => format!("{} : atteinte par la seconde exécution seulement", part),
+    }
+}
+
+pub fn runs_differ(part: &str) -> String {
+    match lang() {
+        Lang::En => format!("{}: the runs differ", part),
+        Lang::Fr => format!("{} : les exécutions diffèrent", part),
+    }
+}
+
+pub fn run_line(first: bool, line: Option<&String>) -> String {
+    let (label, missing) = match (lang(), first) {
+        (Lang::En, true) => ("first: ", "(no line)"),
+        (Lang::En, false) => ("second:", "(no line)"),
+        (Lang::Fr, true) => ("première :", "(pas de ligne)"),
+        (Lang::Fr, false) => ("seconde : ", "(pas de ligne)"),
+    };
+    format!("  {} {}", label, line.map_or(missing, |line| line))
+}
+
+pub fn not_deterministic(chapter: &str, differing: usize) -> String {
+    match lang() {
+        Lang::En => format!(
+            "{} is not deterministic: {} parts of the run differ",
+            chapter, differing
+        ),
+        Lang::Fr => format!(
+            "{} n'est pas déterministe : {} parties de l'exécution diffèrent",
+            chapter, differing
+        ),
+    }
+}
+
+pub fn deterministic(chapter: &str, questions: usize, lines: usize) -> String {
+    match lang() {
+        Lang::En => format!(
+            "{} is deterministic: {} questions and the report, {} lines compared",
+            chapter, questions, lines
+        ),
+        Lang::Fr => format!(
+            "{} est déterministe : {} questions et le rapport, {} lignes comparées",
+            chapter, questions, lines
+        ),
+    }
+}
@@ -0,0 +1,68 @@
+// The questions passed so far, one section per chapter, in `.training-progress.toml` at the root of the
+// repository, so that a training spread over several days picks up where it stopped:
+//
+// [ff-ec]
+// Q1 = true
+// Q2 = true
+//
+// The checks of a passed question still run on the next runs: the questions after it build on its values and
+// on the random instances drawn before them. The runner announces the first unfinished question instead, and
+// tells when a question passed before fails again
+// The same file counts the hints revealed, see module `hints`
+
+use sage_fixtures::Fixtures;
+use std::collections::BTreeSet;
+
+pub const TRAINING_PROGRESS: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/../.training-progress.toml");
+pub const RESET_COMMAND: &str = "reset";
+
+pub struct Progress {
+    pub chapter: &'static str,
+    pub passed: BTreeSet<String>,
+}
+
+pub(crate) fn read() -> std::io::Result<Fixtures> {
+    match std::fs::read_to_string(TRAINING_PROGRESS) {
+        Ok(text) => text
+            .parse()
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Fixtures::new()),
+        Err(e) => Err(e),
+    }
+}
+
+pub(crate) fn write(file: &Fixtures) -> std::io::Result<()> {
+    std::fs::write(TRAINING_PROGRESS, file.to_string())
+}
+
+impl Progress {
+    pub fn load(chapter: &'static str) -> std::io::Result<Progress> {
+        let file = read()?;
+        let passed = file
+            .keys(chapter)
+            .filter(|id| file.get::<bool>(chapter, id))
+            .map(|id| id.to_string())
+            .collect();
+        Ok(Progress { chapter, passed })
+    }
+
+    // the other chapters are left as they are
+    pub fn save(&self) -> std::io::Result<()> {
+        let mut file = read()?;
+        file.remove_section(self.chapter);
+        for id in &self.passed {
+            file.set(self.chapter, id, true);
+        }
+        write(&file)
+    }
+}
+
+/// Forgets the passed questions of the chapter: `cargo run --release -- reset`
+pub fn reset(chapter: &'static str) -> std::io::Result<()> {
+    Progress {
+        chapter,
+        passed: BTreeSet::new(),
+    }
+    .save()
+}
@@ -0,0 +1,30 @@
+// Property checks: a function of the student must agree with a reference (arkworks, a naive algorithm...)
+// on edge cases chosen by hand (zero, the point at infinity, non-residues...) and on many random inputs,
+// which catches what a handful of fixed asserts lets through
+// The random inputs come from the caller, drawn from the seeded generator of the chapter: a failure is
+// reproducible, and its message prints the counterexample (there is no shrinking, the edge cases are small)
+
+use crate::messages;
+use std::fmt::Debug;
+
+// the random inputs of a property
+pub const CASES: usize = 256;
+
+/// Checks `property` on every edge case, then on `CASES` inputs drawn by `random`
+/// Panics on the first input which breaks it, naming the property and printing the input
+pub fn for_all<T: Debug>(
+    name: &str,
+    edge_cases: impl IntoIterator<Item = T>,
+    random: impl FnMut() -> T,
+    property: impl Fn(&T) -> bool,
+) {
+    let random_cases = std::iter::repeat_with(random).take(CASES);
+    for input in edge_cases.into_iter().chain(random_cases) {
+        if !property(&input) {
+            panic!(
+                "{}",
+                messages::property_fails(name, &format!("{:?}", input))
+            );
+        }
+    }
+}
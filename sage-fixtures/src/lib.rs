@@ -0,0 +1,176 @@
+//! Loader for the `sage-fixtures` format
+//!
+//! Some answers are out of reach of a few lines of Rust (the order of a curve, a pairing value,
+//! a basis of the torsion, the codomain of an isogeny...). Instructors compute them offline with
+//! SageMath and ship them as fixtures, and the exercises check the students' answers against them.
+//!
+//! A fixture file is plain text, written by the `.sage` script sitting next to it:
+//!
+//! ```text
+//! # comments start with '#'
+//! [section]
+//! key = 1234
+//! list = [1, 2, 3]
+//! point = (5, 6)
+//! ```
+//!
+//! Values are strings, parsed on access with `FromStr` into the type the exercise asks for
+//! (`u64`, `num_bigint::BigUint`, or an arkworks prime field element, all read decimal integers).
+//! Lists and tuples hold comma-separated values. Keys are unique within a section.
+//!
+//! The same format stores the students' progress report: the challenge runners update their section
+//! with `set` and write the file back with `to_string`.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct FixtureError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for FixtureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for FixtureError {}
+
+pub struct Fixtures {
+    sections: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl FromStr for Fixtures {
+    type Err = FixtureError;
+
+    fn from_str(text: &str) -> Result<Fixtures, FixtureError> {
+        let mut sections: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+        let mut current: Option<String> = None;
+        for (index, line) in text.lines().enumerate() {
+            let error = |message: &str| FixtureError {
+                line: index + 1,
+                message: message.to_string(),
+            };
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                if sections.contains_key(name) {
+                    return Err(error("duplicate section"));
+                }
+                sections.insert(name.to_string(), BTreeMap::new());
+                current = Some(name.to_string());
+                continue;
+            }
+            let section = current.as_ref().ok_or_else(|| error("value outside of a section"))?;
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected `key = value`"))?;
+            let entries = sections.get_mut(section).unwrap();
+            if entries
+                .insert(key.trim().to_string(), value.trim().to_string())
+                .is_some()
+            {
+                return Err(error("duplicate key"));
+            }
+        }
+        Ok(Fixtures { sections })
+    }
+}
+
+impl Fixtures {
+    // the raw value, panics with the missing name since a fixture file is part of the exercise
+    pub fn raw(&self, section: &str, key: &str) -> &str {
+        self.sections
+            .get(section)
+            .and_then(|entries| entries.get(key))
+            .unwrap_or_else(|| panic!("missing fixture {}.{}", section, key))
+    }
+
+    pub fn get<T: FromStr>(&self, section: &str, key: &str) -> T {
+        parse(self.raw(section, key))
+            .unwrap_or_else(|| panic!("invalid fixture {}.{}", section, key))
+    }
+
+    // a list `[a, b, ...]` or a tuple `(a, b, ...)`
+    pub fn get_list<T: FromStr>(&self, section: &str, key: &str) -> Vec<T> {
+        let raw = self.raw(section, key);
+        let inner = raw
+            .strip_prefix('[')
+            .and_then(|r| r.strip_suffix(']'))
+            .or_else(|| raw.strip_prefix('(').and_then(|r| r.strip_suffix(')')))
+            .unwrap_or_else(|| panic!("fixture {}.{} is not a list", section, key));
+        if inner.trim().is_empty() {
+            return vec![];
+        }
+        inner
+            .split(',')
+            .map(|item| {
+                parse(item).unwrap_or_else(|| panic!("invalid fixture {}.{}", section, key))
+            })
+            .collect()
+    }
+}
+
+impl Fixtures {
+    pub fn new() -> Fixtures {
+        Fixtures {
+            sections: BTreeMap::new(),
+        }
+    }
+
+    pub fn sections(&self) -> impl Iterator<Item = &str> {
+        self.sections.keys().map(|name| name.as_str())
+    }
+
+    // the keys of a section, none if it is missing
+    pub fn keys<'a>(&'a self, section: &str) -> impl Iterator<Item = &'a str> {
+        self.sections
+            .get(section)
+            .into_iter()
+            .flat_map(|entries| entries.keys().map(|key| key.as_str()))
+    }
+
+    pub fn contains(&self, section: &str, key: &str) -> bool {
+        self.sections
+            .get(section)
+            .is_some_and(|entries| entries.contains_key(key))
+    }
+
+    pub fn set(&mut self, section: &str, key: &str, value: impl fmt::Display) {
+        self.sections
+            .entry(section.to_string())
+            .or_default()
+            .insert(key.to_string(), value.to_string());
+    }
+
+    pub fn remove_section(&mut self, section: &str) {
+        self.sections.remove(section);
+    }
+}
+
+impl Default for Fixtures {
+    fn default() -> Fixtures {
+        Fixtures::new()
+    }
+}
+
+impl fmt::Display for Fixtures {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (name, entries) in &self.sections {
+            writeln!(f, "[{}]", name)?;
+            for (key, value) in entries {
+                writeln!(f, "{} = {}", key, value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse<T: FromStr>(value: &str) -> Option<T> {
+    value.trim().parse().ok()
+}
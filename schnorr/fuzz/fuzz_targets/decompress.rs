@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use schnorr::decoders::{decompress, ser_p};
+
+// no panic, and a public key accepted only in the compressed SEC1 encoding `ser_p` writes back
+fuzz_target!(|bytes: &[u8]| {
+    if let Some(point) = decompress(bytes) {
+        assert_eq!(ser_p(&point), bytes, "accepted a non-canonical encoding");
+    }
+});
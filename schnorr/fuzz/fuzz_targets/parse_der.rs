@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use schnorr::decoders::{der_encode, parse_der};
+
+// no panic, and a signature accepted only in the strict DER encoding `der_encode` writes back
+fuzz_target!(|bytes: &[u8]| {
+    if let Some((r, s)) = parse_der(bytes) {
+        assert_eq!(
+            der_encode(&r, &s),
+            bytes,
+            "accepted a non-canonical encoding"
+        );
+    }
+});
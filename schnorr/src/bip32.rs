@@ -0,0 +1,102 @@
+use crate::*;
+use ark_ff::BigInteger;
+use sha2::{Digest, Sha256, Sha512};
+
+// BIP32 hierarchical deterministic wallets: a whole tree of key pairs derived from a single seed
+// - the master key (k, c) is derived from the seed with HMAC-SHA512, c being the "chain code"
+// - child i of (k, c) is (k + IL, IR) where IL || IR = HMAC-SHA512(c, data) with
+//   - data = 0x00 || ser256(k) || ser32(i) for hardened children (i >= 2^31), which need the private key
+//   - data = serP(kG) || ser32(i) for non-hardened children, which can be derived from the public key alone:
+//     K_i = K + IL G, this is what lets a watch-only wallet generate receiving addresses
+// Adding IL to a key is a tweak, see crate `tweak`
+
+pub const HARDENED: u32 = 1 << 31;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExtendedPrivateKey {
+    pub depth: u8,
+    pub child_number: u32,
+    pub chain_code: [u8; 32],
+    pub key: Fr,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExtendedPublicKey {
+    pub depth: u8,
+    pub child_number: u32,
+    pub chain_code: [u8; 32],
+    pub key: Affine,
+}
+
+pub fn hmac_sha512(key: &[u8], data: &[u8]) -> [u8; 64] {
+    const BLOCK_SIZE: usize = 128;
+    let mut padded_key = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        padded_key[..64].copy_from_slice(&Sha512::digest(key));
+    } else {
+        padded_key[..key.len()].copy_from_slice(key);
+    }
+    let inner_pad: Vec<u8> = padded_key.iter().map(|b| b ^ 0x36).collect();
+    let outer_pad: Vec<u8> = padded_key.iter().map(|b| b ^ 0x5c).collect();
+    let inner = Sha512::new()
+        .chain_update(inner_pad)
+        .chain_update(data)
+        .finalize();
+    Sha512::new()
+        .chain_update(outer_pad)
+        .chain_update(inner)
+        .finalize()
+        .into()
+}
+
+// parse256(p): None if p is not a valid scalar (p >= n), which happens with probability < 2^-127
+pub fn parse256(bytes: &[u8]) -> Option<Fr> {
+    tweak::parse_tweak(bytes.try_into().ok()?).ok()
+}
+
+pub fn ser256(scalar: &Fr) -> [u8; 32] {
+    scalar.into_bigint().to_bytes_be().try_into().unwrap()
+}
+
+// An extended key as found in the test vectors ("xprv...", "xpub..."), decoded from Base58Check
+pub struct SerializedKey {
+    pub depth: u8,
+    // bytes 5..9 hold the parent fingerprint, the first bytes of RIPEMD160(SHA256(serP(K_parent)))
+    pub child_number: u32,
+    pub chain_code: [u8; 32],
+    pub key_data: [u8; 33], // 0x00 || ser256(k) or serP(K)
+}
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+pub fn base58check_decode(encoded: &str) -> Option<Vec<u8>> {
+    let mut number: Vec<u8> = vec![]; // big-endian
+    for c in encoded.bytes() {
+        let mut carry = BASE58_ALPHABET.iter().position(|&a| a == c)? as u32;
+        for byte in number.iter_mut().rev() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            number.insert(0, carry as u8);
+            carry >>= 8;
+        }
+    }
+    let leading_zeros = encoded.bytes().take_while(|&c| c == b'1').count();
+    let mut decoded = vec![0u8; leading_zeros];
+    decoded.extend(number);
+    let (payload, checksum) = decoded.split_at(decoded.len().checked_sub(4)?);
+    (Sha256::digest(Sha256::digest(payload))[..4] == *checksum).then(|| payload.to_vec())
+}
+
+pub fn decode_extended_key(encoded: &str) -> SerializedKey {
+    let payload = base58check_decode(encoded).expect("invalid Base58Check encoding");
+    assert_eq!(payload.len(), 78);
+    SerializedKey {
+        depth: payload[4],
+        child_number: u32::from_be_bytes(payload[9..13].try_into().unwrap()),
+        chain_code: payload[13..45].try_into().unwrap(),
+        key_data: payload[45..78].try_into().unwrap(),
+    }
+}
@@ -0,0 +1,80 @@
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_secp256k1::{Affine, Fr};
+
+// The decoders of Q12 and Q13, and the encoders they must agree with
+// They parse bytes an attacker chooses: whatever the input, they return None rather than panic, and accept
+// only the bytes the encoder writes back. Two fuzzers check it: `cargo run --release -- fuzz` (see module
+// `fuzzing`), and the cargo-fuzz targets of `fuzz/`, `cargo +nightly fuzz run parse_der` for one, which link
+// this module through the library of the chapter
+
+pub fn der_encode(r: &Fr, s: &Fr) -> Vec<u8> {
+    let integer = |x: &Fr| {
+        let bytes = x.into_bigint().to_bytes_be();
+        let start = bytes.iter().position(|byte| *byte != 0).unwrap_or(31);
+        let mut minimal = bytes[start..].to_vec();
+        if minimal[0] >= 0x80 {
+            minimal.insert(0, 0);
+        }
+        [vec![0x02, minimal.len() as u8], minimal].concat()
+    };
+    let body = [integer(r), integer(s)].concat();
+    [vec![0x30, body.len() as u8], body].concat()
+}
+
+// SEC1 compressed encoding: 0x02 or 0x03 depending on the parity of y, then x
+pub fn ser_p(point: &Affine) -> [u8; 33] {
+    let (x, odd) = tweak::x_only(point);
+    let mut bytes = [0u8; 33];
+    bytes[0] = if odd { 0x03 } else { 0x02 };
+    bytes[1..].copy_from_slice(&x);
+    bytes
+}
+
+pub fn parse_der(bytes: &[u8]) -> Option<(Fr, Fr)> {
+    // SOLUTION-BEGIN
+    // one INTEGER: tag, length, then a minimal positive big-endian value in 1..n
+    fn integer(bytes: &[u8]) -> Option<(Fr, &[u8])> {
+        let (&[0x02, length], rest) = bytes.split_first_chunk::<2>()? else {
+            return None;
+        };
+        let length = length as usize;
+        if length == 0 || length > 33 || rest.len() < length {
+            return None;
+        }
+        let (value, rest) = rest.split_at(length);
+        if value[0] & 0x80 != 0 || (length > 1 && value[0] == 0 && value[1] & 0x80 == 0) {
+            return None; // negative, or not minimal
+        }
+        let value = &value[(value[0] == 0) as usize..];
+        if value.len() > 32 {
+            return None;
+        }
+        let x = Fr::from_be_bytes_mod_order(value);
+        let mut canonical = x.into_bigint().to_bytes_be();
+        canonical.drain(..32 - value.len());
+        (canonical == value && !x.is_zero()).then_some((x, rest))
+    }
+    let (&[0x30, length], body) = bytes.split_first_chunk::<2>()? else {
+        return None;
+    };
+    // a short length: the longest signature takes 2 + 33 + 2 + 33 bytes
+    if length >= 0x80 || body.len() != length as usize {
+        return None;
+    }
+    let (r, rest) = integer(body)?;
+    let (s, rest) = integer(rest)?;
+    rest.is_empty().then_some((r, s))
+    // SOLUTION-END
+}
+
+pub fn decompress(bytes: &[u8]) -> Option<Affine> {
+    // SOLUTION-BEGIN
+    let (&prefix, x) = bytes.split_first()?;
+    let even = tweak::lift_x(x.try_into().ok()?).ok()?;
+    match prefix {
+        2 => Some(even),
+        3 => Some(-even),
+        _ => None,
+    }
+    // SOLUTION-END
+}
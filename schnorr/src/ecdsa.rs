@@ -0,0 +1,123 @@
+use crate::*;
+use ark_ff::BigInteger;
+use sha2::Sha256;
+
+// ECDSA over secp256k1, the signature of Bitcoin transactions before Taproot
+// - the message is the sighash z, the double SHA-256 of a digest of the transaction (BIP143 for segwit
+//   inputs), read as a big-endian integer mod n
+// - a signature (r, s) with r = (kG).x mod n and s = (z + r x) / k verifies with w = 1 / s:
+//   R = (z w) G + (r w) Q, then R.x mod n = r
+// - in the witness, (r, s) is DER-encoded: 0x30, length, then 0x02, length, r and 0x02, length, s as
+//   minimal big-endian integers, with a leading 0x00 when the high bit is set (DER integers are signed),
+//   followed by the sighash type, 0x01 for SIGHASH_ALL
+// - the public key is a compressed SEC1 point: 0x02 or 0x03 for the parity of y, then x on 32 bytes
+// BIP66 made strict DER mandatory: before it, OpenSSL accepted encodings that other parsers rejected
+// RFC 6979 replaces the random nonce k with the output of HMAC_DRBG, a generator built on HMAC-SHA256, seeded
+// with the private key x and the hash h1 of the message (section 3.2):
+// - V = 0x01 0x01 ... 0x01 and K = 0x00 0x00 ... 0x00, as long as the hash
+// - K = HMAC_K(V || 0x00 || int2octets(x) || bits2octets(h1)), V = HMAC_K(V)
+// - K = HMAC_K(V || 0x01 || int2octets(x) || bits2octets(h1)), V = HMAC_K(V)
+// - then V = HMAC_K(V), concatenated until T has qlen bits, and k = bits2int(T) if 1 <= k < q, otherwise
+//   K = HMAC_K(V || 0x00), V = HMAC_K(V) and try again
+// where int2octets writes an integer on rlen = ceil(qlen / 8) bytes, big-endian, bits2int keeps the qlen
+// leftmost bits of a string, and bits2octets(h1) = int2octets(bits2int(h1) mod q)
+
+// The native P2WPKH example of BIP143: the preimage of the sighash of its second input, the signature in
+// its witness and the public key whose HASH160 (1d0f172a...) is in the scriptCode of the preimage
+pub const BIP143_PREIMAGE: &str = "\
+    0100000096b827c8483d4e9b96712b6713a7b68d6e8003a781feba36c31143470b4efd3752b0a642eea2fb7ae638c36f6252b6\
+    750293dbe574a806984b8e4d8548339a3bef51e1b804cc89d182d279655c3aa89e815b1b309fe287d9b2b55d57b90ec68a0100\
+    00001976a9141d0f172a0ecb48aee1be1f2687d2963ae33f71a188ac0046c32300000000ffffffff863ef3e1a92afbfdb97f31\
+    ad0fc7683ee943e9abcf2501590ff8f6551f47e5e51100000001000000";
+pub const BIP143_SIGHASH: &str = "c37af31116d1b27caf68aae9e3ac82f1477929014d5b917657d0eb49478cb670";
+pub const BIP143_SIGNATURE: &str = "\
+    304402203609e17b84f6a7d30c80bfa610b5b4542f32a8a0d5447a12fb1366d7f01cc44a0220573a954c4518331561406f\
+    90300e8f3358f51928d43c212a8caed02de67eebee01";
+pub const BIP143_PUBLIC_KEY: &str =
+    "025476c2e83188368da1ff3e292e7acafcdb3566bb0ad253f62fc70f07aeee6357";
+
+// The vectors of RFC 6979 for ECDSA over P-256 with SHA-256 (appendix A.2.5): the curve is not secp256k1, but
+// the nonce only depends on its order q, the private key and the hash of the message
+pub const P256_ORDER: &str = "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
+pub const RFC6979_P256_SECRET_KEY: &str =
+    "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+pub const RFC6979_P256_VECTORS: [(&str, &str); 2] = [
+    (
+        "sample",
+        "a6e3c57dd01abe90086538398355dd4c3b17aa873382b0f24d6129493d8aad60",
+    ),
+    (
+        "test",
+        "d16b6ae827f17175e040871a1c7ec3500192c4c92677336ec2537acaee0008e0",
+    ),
+];
+// The vector the Bitcoin libraries share on secp256k1: private key 1 and SHA-256("Satoshi Nakamoto"), its
+// nonce and the signature (r, s) before s is normalized to the lower half
+pub const RFC6979_SECP256K1_MESSAGE: &str = "Satoshi Nakamoto";
+pub const RFC6979_SECP256K1_NONCE: &str =
+    "8f8a276c19f4149656b280621e358cce24f5f52542772691ee69063b74f15d15";
+pub const RFC6979_SECP256K1_SIGNATURE: (&str, &str) = (
+    "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8",
+    "dbbd3162d46e9f9bef7feb87c16dc13b4f6568a87f4e83f728e2443ba586675c",
+);
+
+pub fn sighash(preimage: &[u8]) -> [u8; 32] {
+    Sha256::digest(Sha256::digest(preimage)).into()
+}
+
+pub fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    const BLOCK_SIZE: usize = 64;
+    let mut padded_key = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        padded_key[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        padded_key[..key.len()].copy_from_slice(key);
+    }
+    let inner_pad: Vec<u8> = padded_key.iter().map(|b| b ^ 0x36).collect();
+    let outer_pad: Vec<u8> = padded_key.iter().map(|b| b ^ 0x5c).collect();
+    let inner = Sha256::new()
+        .chain_update(inner_pad)
+        .chain_update(data)
+        .finalize();
+    Sha256::new()
+        .chain_update(outer_pad)
+        .chain_update(inner)
+        .finalize()
+        .into()
+}
+
+pub fn scalar_from_be(bytes: &[u8]) -> Fr {
+    Fr::from_be_bytes_mod_order(bytes)
+}
+
+// the x-coordinate of a point, reduced mod n
+pub fn x_mod_n(point: &Affine) -> Fr {
+    scalar_from_be(&point.x.into_bigint().to_bytes_be())
+}
+
+// with a random nonce, for the checks on random keys
+pub fn ecdsa_sign<R: Rng>(secret_key: &Fr, z: &Fr, rng: &mut R) -> (Fr, Fr) {
+    loop {
+        if let Some(signature) = ecdsa_sign_with_nonce(secret_key, z, &Fr::rand(rng)) {
+            return signature;
+        }
+    }
+}
+
+// with the nonce of the caller, `None` when k, r or s is zero
+pub fn ecdsa_sign_with_nonce(secret_key: &Fr, z: &Fr, k: &Fr) -> Option<(Fr, Fr)> {
+    let r = x_mod_n(&Affine::generator().mul(k).into_affine());
+    if r.is_zero() {
+        return None;
+    }
+    let s = (*z + r * secret_key) * k.inverse()?;
+    (!s.is_zero()).then_some((r, s))
+}
+
+// the lower of s and n - s, as BIP146 and the Bitcoin libraries sign: the nonce becomes -k when s flips
+pub fn low_s((r, s): (Fr, Fr)) -> (Fr, Fr) {
+    match s.into_bigint() > Fr::MODULUS_MINUS_ONE_DIV_TWO {
+        true => (r, -s),
+        false => (r, s),
+    }
+}
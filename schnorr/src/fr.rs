@@ -0,0 +1,152 @@
+use runner::lang::{Catalog, Lang, Statements};
+
+pub const CATALOG: Catalog = Catalog {
+    lang: Lang::Fr,
+    statements: Statements::Table(&[
+        ("Q1", "Casser ce schéma en forgeant une signature qui passe cet algorithme de vérification défaillant pour un message arbitraire\nVous n'avez que la clé publique (pas d'oracle de signature)\nÉcrire une fonction `break_wrong_schnorr` qui forge une signature"),
+        ("Q2", "Cassons `flawed_randomized_schnorr_sign`\nVous avez librement accès à la méthode `flawed_randomized_sign_oracle` implémentée sur une instance de clé secrète `sk`\nÉcrire une fonction `break_flawed_randomized_schnorr` qui retrouve cette clé secrète"),
+        ("Q3", "Cassons `flawed_deterministic_schnorr_sign`\nVous avez librement accès à la méthode `flawed_deterministic_sign_oracle` implémentée sur une instance de clé secrète `sk`\nÉcrire une fonction `break_flawed_deterministic_schnorr` qui retrouve cette clé secrète"),
+        ("Q4", "Cassons `two_for_one_schnorr sign`\nVous avez librement accès à la méthode `two_for_one_schnorr_sign_oracle`\nimplémentée sur une instance de clé secrète maîtresse `msk`\nÉcrire une fonction `break_two_for_one_schnorr_sign` qui retrouve cette clé secrète maîtresse"),
+        ("Q5", "écrire `key_aggregation` qui calcule les coefficients a_i = H(L, X_i) et la clé agrégée X = sum a_i X_i\nRappel : les clés BIP340 n'ont que leur x : si X a une ordonnée impaire, les signataires devront prendre l'opposé de leurs clés"),
+        ("Q6", "implémenter les deux tours du protocole de signature\n- `Signer::nonce_gen` tire deux nonces secrets k_1, k_2 et publie R_1 = k_1 G, R_2 = k_2 G\n- `session_context` somme les nonces de tous les signataires en (R_1, R_2), dérive b, R = R_1 + b R_2 et e\n- `Signer::partial_sign` renvoie s_i = k_1 + b k_2 + e a_i x_i (attention aux parités !)\n- `partial_verify` permet à l'agrégateur de vérifier chaque s_i contre les nonces publics et la clé du signataire i\n- `aggregate_partial_signatures` somme les s_i en une signature BIP340 (x(R), s)"),
+        ("Q7", "écrire `rogue_key_attack` qui, à partir de la clé publique d'Alice, renvoie une clé que Mallory annonce\navec la clé secrète de l'agrégat naïf des deux clés\nMallory peut alors signer seule au nom du groupe « 2 sur 2 »"),
+        ("Q8", "écrire `master_key`, `derive_private_child`, `derive_public_child` et `neuter`\nElles sont vérifiées contre le premier vecteur de test officiel de BIP32\n(sauf l'empreinte du parent, un haché RIPEMD-160 que nous n'avons pas sous la main)"),
+        ("Q9", "la xpub du compte est confiée à un service de comptabilité, et la clé privée d'une\nadresse de réception fuit (disons qu'elle a été importée dans un portefeuille bogué)\nécrire `recover_parent_key` qui retrouve la clé privée du compte, et donc de toutes ses adresses"),
+        ("Q10", "pourquoi ? Que faut-il durcir dans un portefeuille ?"),
+        ("Q11", "`x` lui-même est encore là après le second drop, pourquoi ? Quelles autres copies de la clé\n`schnorr_sign` laisse-t-elle derrière elle ? Pourquoi `SecretKey` ne dérive-t-elle plus `Copy` ?"),
+        ("Q12", "écrire `parse_der` dans le module `decoders`, qui n'accepte que l'encodage DER strict de BIP66 et 0 < r, s < n"),
+        ("Q13", "écrire `decompress` dans le module `decoders`, qui décode une clé publique SEC1 compressée"),
+        ("Q14", "écrire `ecdsa_verify`, et vérifier la signature de la transaction de BIP143"),
+        ("Q15", "BIP146 proposait de n'accepter que s <= n / 2 : la signature de BIP143 est-elle « low s » ? Pourquoi segwit\ncorrige-t-il de toute façon la malléabilité du txid ? Qu'a corrigé BIP66 qu'un analyseur DER laxiste casserait ?"),
+        ("Q16", "écrire `rfc6979_nonce`, le premier nonce de HMAC_DRBG pour un groupe d'ordre q (voir le module `ecdsa`)"),
+        ("Q17", "écrire `ecdsa_sign_rfc6979`, ECDSA avec le nonce de la RFC 6979 sur secp256k1"),
+        ("Q18", "le signataire de Q2 réutilisait son nonce d'un message à l'autre, et celui de Q3 le dérivait de la clé privée\net du message, comme la RFC 6979 : pourquoi deux signatures RFC 6979 ne peuvent-elles partager un nonce que si elles signent\nle même haché ? Qu'a omis le nonce de Q3 que le défi hachait, et pourquoi ECDSA n'a-t-il pas ce\nproblème ? Une faute pendant le calcul de s fait publier à un signataire déterministe une mauvaise signature\nà côté de la bonne, avec le même k : que cela révèle-t-il ?"),
+        ("Q19", "écrire `recover_key_related_nonces`, qui trouve x à partir de deux signatures de z_1 et z_2 dont les nonces\nsont liés par a et b, ou `None` si les équations ne le déterminent pas. Le signataire a aussi pu\nnormaliser s dans la moitié inférieure comme le fait Bitcoin, ce qui change le signe du nonce : vérifier la clé contre la\nclé publique"),
+        ("Q20", "quelles relations entre les nonces Q19 devait-elle connaître, et lesquelles révéleraient encore la clé si\na et b étaient inconnus mais petits ? Les nonces de la RFC 6979 sont uniques mais imprévisibles : pourquoi l'unicité n'est-elle pas\nce dont ECDSA a besoin, et comment quelques bits connus de nombreux nonces mènent-ils à la clé (le problème du nombre caché) ?"),
+        ("Q21", "écrire `taproot_tweak_pubkey`, qui renvoie la clé de sortie Q et la parité de son y, et\n`taproot_tweak_seckey`, la clé secrète qui signe pour Q par le chemin de clé"),
+        ("Q22", "écrire `verify_control_block`, qui vérifie une dépense par chemin de script de la clé de sortie"),
+        ("Q23", "pourquoi BIP341 ajuste-t-il P avec la racine de Merkle même quand la sortie n'est destinée qu'au chemin de clé,\nsans aucun script ? Que permettrait une clé de sortie Q = P + H(root) G sans P dans le haché ?"),
+    ]),
+    hints: &[
+        (
+            "Q1",
+            &[
+                "Comparer le défi de `wrong_schnorr_verif` avec celui de `schnorr_sign`",
+                "Le défi ne dépend pas de l'engagement R : choisir R après le défi",
+                "Prendre une réponse s quelconque, calculer c = H(pk, m), et poser R = sG - c pk",
+            ],
+        ),
+        (
+            "Q2",
+            &[
+                "Regarder comment le nonce est généré : de quoi dépend le générateur aléatoire ?",
+                "Chaque signature utilise le même nonce r, et s = r + c x",
+                "Avec deux signatures de messages différents, x = (s_1 - s_2) / (c_1 - c_2)",
+            ],
+        ),
+        (
+            "Q3",
+            &[
+                "Le nonce ne dépend que de la clé secrète et du message, mais le défi hache aussi pk",
+                "L'oracle prend la clé publique en argument : rien ne la vérifie",
+                "Signer le même message avec deux clés publiques différentes : même nonce, défis différents, et x = (s_1 - s_2) / (c_1 - c_2)",
+            ],
+        ),
+        (
+            "Q7",
+            &[
+                "Avec l'agrégation naïve, la clé agrégée est la somme des clés annoncées",
+                "Mallory annonce sa clé après avoir vu celle d'Alice",
+                "Annoncer X_M = xG - X_A : l'agrégat est xG, dont Mallory seule connaît la clé secrète x",
+            ],
+        ),
+        (
+            "Q10",
+            &[
+                "Une clé enfant non durcie est la clé parente plus un ajustement que quiconque possède la xpub peut calculer",
+                "Avec la xpub et une clé privée enfant, l'ajustement redonne la clé privée parente, et donc tous ses enfants",
+                "BIP44 durcit les chemins jusqu'au compte, m/44'/0'/account', pour qu'une fuite reste confinée à un compte",
+            ],
+        ),
+        (
+            "Q12",
+            &[
+                "Lire une étiquette et une longueur, vérifier que la longueur correspond à ce qui suit, deux fois pour les deux INTEGER",
+                "Un INTEGER est signé : un premier octet >= 0x80 signifie un nombre négatif, et un 0x00 en tête n'est permis que devant un tel octet",
+                "Rejeter une longueur en forme longue (>= 0x80), des octets en trop, zéro, et les valeurs >= n : réencoder le scalaire et comparer",
+            ],
+        ),
+        (
+            "Q13",
+            &[
+                "y^2 = x^3 + 7 donne y au signe près, le préfixe en choisit un",
+                "La crate `tweak` relève déjà une abscisse en le point d'ordonnée paire",
+                "Préfixe 0x02 : le y pair, 0x03 : son opposé ; tout autre préfixe, une mauvaise longueur ou x >= p est une erreur",
+            ],
+        ),
+        (
+            "Q14",
+            &[
+                "k = (z + r x) / s, donc kG = (z / s) G + (r / s) Q, dont l'abscisse a donné r",
+                "Calculer w = s^-1, u1 = z w et u2 = r w, puis R = u1 G + u2 Q",
+                "Accepter si et seulement si R n'est pas le point à l'infini et que son abscisse, réduite mod n, vaut r",
+            ],
+        ),
+        (
+            "Q15",
+            &[
+                "Comparer s à (n - 1) / 2",
+                "Segwit déplace les signatures dans le témoin, que le txid ne hache pas",
+                "Deux nœuds en désaccord sur la validité d'une signature scindent la chaîne : une règle de consensus ne peut dépendre des bizarreries d'un analyseur",
+            ],
+        ),
+        (
+            "Q16",
+            &[
+                "Suivre pas à pas la section 3.2 de la RFC 6979, avec `hmac_sha256` pour HMAC_K : V et K font 32 octets",
+                "x et h1 mod q entrent tous deux dans le HMAC sur rlen octets, gros-boutistes, après le séparateur 0x00 puis 0x01",
+                "T est V, ou plusieurs V concaténés, jusqu'à avoir rlen octets ; garder les qlen bits de gauche et rejeter 0 et les valeurs >= q",
+            ],
+        ),
+        (
+            "Q17",
+            &[
+                "L'ordre q est `Fr::MODULUS`, et un scalaire se convertit en `BigUint`",
+                "k = rfc6979_nonce(n, x, hash), puis r = (kG).x mod n comme dans `ecdsa_sign`",
+                "s = (z + r x) / k avec z le haché lu mod n : pas de normalisation de s",
+            ],
+        ),
+        (
+            "Q18",
+            &[
+                "Le nonce est une fonction de (x, h1) : la même paire donne le même k, un autre haché un autre k, sauf collision de HMAC",
+                "Le défi de Schnorr hache la clé publique mais pas le nonce de Q3 : un message sous deux clés publiques a réutilisé k. ECDSA ne signe que z, la clé n'est pas dans l'équation",
+                "s = (z + r x) / k et le s' fautif partagent le k inconnu : quand la faute touche une valeur que l'attaquant peut deviner ou recalculer, les deux équations donnent k, puis x ; d'où la vérification d'une signature avant de la publier",
+            ],
+        ),
+        (
+            "Q19",
+            &[
+                "Écrire k_1 et k_2 à partir des équations de signature : k_i = (z_i + r_i x) / s_i, toutes deux linéaires en x",
+                "Les substituer dans k_2 = a k_1 + b : x (r_2 s_1 - a r_1 s_2) = a z_1 s_2 + b s_1 s_2 - z_2 s_1",
+                "Avec low s, le vrai s_i est s_i ou -s_i : essayer les quatre combinaisons, sauter un dénominateur nul et garder le x tel que xG = Q",
+            ],
+        ),
+        (
+            "Q21",
+            &[
+                "t = H_TapTweak(x(P) || merkle_root), sans rien après x(P) quand il n'y a pas d'arbre de scripts",
+                "La crate `tweak` a les ajustements en x seul : `parse_tweak`, `x_only_tweak_add` et `x_only_tweak_add_private`",
+                "La clé secrète change de signe quand son point a un y impair, avant d'ajouter t",
+            ],
+        ),
+        (
+            "Q22",
+            &[
+                "33 octets, puis un multiple de 32 : tout le reste est mal formé",
+                "La version de feuille est le premier octet sans son bit de poids faible, qui est la parité de y(Q)",
+                "Replier le chemin avec `tapbranch_hash` à partir du haché de la feuille, puis vérifier l'ajustement avec `x_only_tweak_add_check`",
+            ],
+        ),
+    ],
+};
@@ -0,0 +1,54 @@
+use crate::*;
+use training_common::fuzz::{fuzz, print_report, DEFAULT_FUZZ_RUNS};
+
+// `cargo run --release -- fuzz [runs]` feeds mutants of valid encodings to `parse_der` of Q12 and `decompress`
+// of Q13: they may reject anything, but must never panic, and must only accept the bytes `der_encode` and
+// `ser_p` write back (see module `fuzz` of the training-common crate)
+
+fn round_trip<T>(
+    bytes: &[u8],
+    decoded: Option<T>,
+    encode: impl Fn(&T) -> Vec<u8>,
+) -> Result<bool, String> {
+    match decoded {
+        None => Ok(false),
+        Some(value) if encode(&value) == bytes => Ok(true),
+        Some(value) => Err(format!(
+            "accepted a non-canonical encoding of {}",
+            hex(&encode(&value))
+        )),
+    }
+}
+
+pub fn fuzz_decoders(args: &[String]) {
+    let runs = match args {
+        [] => DEFAULT_FUZZ_RUNS,
+        [runs] => runs.parse().expect("runs"),
+        _ => panic!("usage: fuzz [runs]"),
+    };
+    let mut rng = training_common::rng();
+
+    // short and long integers, with and without the 0x00 of a high bit
+    let mut seeds = vec![
+        der_encode(&Fr::one(), &-Fr::one()),
+        der_encode(&Fr::from(0x80u64), &Fr::from(0x7fu64)),
+    ];
+    seeds.extend((0..4).map(|_| der_encode(&Fr::rand(&mut rng), &Fr::rand(&mut rng))));
+    let signatures = fuzz(&seeds, runs, &mut rng, |bytes| {
+        round_trip(bytes, parse_der(bytes), |(r, s)| der_encode(r, s))
+    });
+
+    let g = Affine::generator();
+    let seeds: Vec<Vec<u8>> = [g, -g].iter().map(|point| ser_p(point).to_vec()).collect();
+    let public_keys = fuzz(&seeds, runs, &mut rng, |bytes| {
+        round_trip(bytes, decompress(bytes), |point| ser_p(point).to_vec())
+    });
+
+    let passed = [
+        print_report("parse_der", &signatures),
+        print_report("decompress", &public_keys),
+    ];
+    if passed.contains(&false) {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,130 @@
+use runner::hints::Hints;
+use runner::lang::Catalog;
+
+pub const CATALOGS: &[Catalog] = &[
+    Catalog::english(HINTS, include_str!("main.rs")),
+    crate::fr::CATALOG,
+];
+
+pub const HINTS: Hints = &[
+    (
+        "Q1",
+        &[
+            "Compare the challenge of `wrong_schnorr_verif` with the one of `schnorr_sign`",
+            "The challenge does not depend on the commitment R: choose R after the challenge",
+            "Pick any response s, compute c = H(pk, m), and set R = sG - c pk",
+        ],
+    ),
+    (
+        "Q2",
+        &[
+            "Look at how the nonce is generated: what does the random generator depend on?",
+            "Every signature uses the same nonce r, and s = r + c x",
+            "With two signatures on different messages, x = (s_1 - s_2) / (c_1 - c_2)",
+        ],
+    ),
+    (
+        "Q3",
+        &[
+            "The nonce depends on the secret key and the message only, but the challenge also hashes pk",
+            "The oracle takes the public key as an argument: nothing checks it",
+            "Sign the same message with two different public keys: same nonce, different challenges, and x = (s_1 - s_2) / (c_1 - c_2)",
+        ],
+    ),
+    (
+        "Q7",
+        &[
+            "With naive aggregation, the aggregate key is the sum of the announced keys",
+            "Mallory announces her key after seeing Alice's",
+            "Announce X_M = xG - X_A: the aggregate is xG, whose secret key x Mallory knows alone",
+        ],
+    ),
+    (
+        "Q10",
+        &[
+            "An unhardened child key is the parent key plus a tweak that anyone with the xpub can compute",
+            "With the xpub and one child private key, the tweak gives back the parent private key, hence all its children",
+            "BIP44 hardens the paths down to the account, m/44'/0'/account', so that a leak stays within one account",
+        ],
+    ),
+    (
+        "Q12",
+        &[
+            "Read a tag and a length, check that the length matches what follows, twice for the two INTEGERs",
+            "An INTEGER is signed: a first byte >= 0x80 means a negative number, and a leading 0x00 is only allowed before such a byte",
+            "Reject a long-form length (>= 0x80), bytes left over, zero, and values >= n: re-encode the scalar and compare",
+        ],
+    ),
+    (
+        "Q13",
+        &[
+            "y^2 = x^3 + 7 gives y up to its sign, the prefix picks one",
+            "Crate `tweak` already lifts an x-coordinate to the point with an even y",
+            "Prefix 0x02: the even y, 0x03: its opposite; anything else, a wrong length or x >= p is an error",
+        ],
+    ),
+    (
+        "Q14",
+        &[
+            "k = (z + r x) / s, so kG = (z / s) G + (r / s) Q, whose x-coordinate gave r",
+            "Compute w = s^-1, u1 = z w and u2 = r w, then R = u1 G + u2 Q",
+            "Accept iff R is not the point at infinity and its x-coordinate, reduced mod n, equals r",
+        ],
+    ),
+    (
+        "Q15",
+        &[
+            "Compare s with (n - 1) / 2",
+            "Segwit moves the signatures into the witness, which the txid does not hash",
+            "Two nodes disagreeing on the validity of a signature split the chain: a consensus rule cannot depend on the quirks of one parser",
+        ],
+    ),
+    (
+        "Q16",
+        &[
+            "Follow section 3.2 of RFC 6979 step by step, with `hmac_sha256` for HMAC_K: V and K are 32 bytes",
+            "x and h1 mod q both go into the HMAC on rlen bytes, big-endian, after the separator 0x00 then 0x01",
+            "T is V, or several V concatenated, until it has rlen bytes; keep the leftmost qlen bits and reject 0 and values >= q",
+        ],
+    ),
+    (
+        "Q17",
+        &[
+            "The order q is `Fr::MODULUS`, and a scalar converts into a `BigUint`",
+            "k = rfc6979_nonce(n, x, hash), then r = (kG).x mod n as in `ecdsa_sign`",
+            "s = (z + r x) / k with z the hash read mod n: no normalization of s",
+        ],
+    ),
+    (
+        "Q18",
+        &[
+            "The nonce is a function of (x, h1): the same pair gives the same k, another hash another k, barring an HMAC collision",
+            "The Schnorr challenge hashes the public key but the nonce of Q3 did not: one message under two public keys reused k. ECDSA signs z alone, the key is not in the equation",
+            "s = (z + r x) / k and the faulty s' share the unknown k: when the fault hits a value the attacker can guess or recompute, the two equations give k, then x; hence verifying a signature before releasing it",
+        ],
+    ),
+    (
+        "Q19",
+        &[
+            "Write k_1 and k_2 from the signature equations: k_i = (z_i + r_i x) / s_i, both linear in x",
+            "Substitute them into k_2 = a k_1 + b: x (r_2 s_1 - a r_1 s_2) = a z_1 s_2 + b s_1 s_2 - z_2 s_1",
+            "With low s, the true s_i is s_i or -s_i: try the four combinations, skip a zero denominator and keep the x with xG = Q",
+        ],
+    ),
+    (
+        "Q21",
+        &[
+            "t = H_TapTweak(x(P) || merkle_root), with nothing after x(P) when there is no script tree",
+            "Crate `tweak` has the x-only tweaks: `parse_tweak`, `x_only_tweak_add` and `x_only_tweak_add_private`",
+            "The secret key is negated when its point has an odd y, before adding t",
+        ],
+    ),
+    (
+        "Q22",
+        &[
+            "33 bytes, then a multiple of 32: anything else is malformed",
+            "The leaf version is the first byte without its lowest bit, which is the parity of y(Q)",
+            "Fold the path with `tapbranch_hash` from the leaf hash, then check the tweak with `x_only_tweak_add_check`",
+        ],
+    ),
+];
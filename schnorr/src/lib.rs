@@ -0,0 +1,3 @@
+// The library of the chapter: the decoders of Q12 and Q13, for `main.rs` and for the cargo-fuzz targets of
+// `fuzz/`, which cannot link a binary
+pub mod decoders;
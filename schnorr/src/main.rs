@@ -1,22 +1,49 @@
 use ark_ec::{AffineRepr, CurveGroup};
-use ark_ff::{Field, PrimeField};
-use ark_secp256k1::{Affine, Fr};
+use ark_ff::{BigInteger, Field, One, PrimeField};
+use ark_secp256k1::{Affine, Fr, Projective};
 use ark_serialize::{CanonicalSerialize, CanonicalSerializeHashExt};
-use ark_std::{ops::Mul, UniformRand};
-use rand::SeedableRng;
+use ark_std::{ops::Mul, UniformRand, Zero};
+use bip32::*;
+use ecdsa::*;
+use fuzzing::*;
+use musig2::*;
+use num_bigint::BigUint;
+use rand::{Rng, SeedableRng};
 use rand_chacha::ChaChaRng;
+use runner::Runner;
+use schnorr::decoders::*;
 use schnorr_scheme::*;
+use secret::{bytes_after_drop, Scalar};
+use sha2::Digest;
+use taproot::*;
 
+mod bip32;
+mod ecdsa;
+mod fr;
+mod fuzzing;
+mod hints;
+mod musig2;
 mod schnorr_scheme;
+mod taproot;
 
 fn main() {
+    // `cargo run --release -- fuzz` runs the decoders of Q12 and Q13 on mutated inputs, see module `fuzzing`
+    let args = runner::args();
+    if let [command, rest @ ..] = args.as_slice() {
+        if command == "fuzz" {
+            return fuzz_decoders(rest);
+        }
+    }
+
+    let runner = Runner::start(env!("CARGO_PKG_NAME"), hints::CATALOGS);
+    let mut rng = training_common::rng();
     // Take a look at module `schnorr_scheme`
     // In particular, look at `schnorr_keygen`, `schnorr_sign`, and `schnorr_verif`
     // This implements the randomized Schnorr scheme we have seen in the slides
     // We can check correctness:
-    let (sk, pk) = schnorr_keygen();
+    let (sk, pk) = schnorr_keygen(&mut rng);
     let m = "Crypto training exercises!".as_bytes();
-    let sig = schnorr_sign(&sk, m);
+    let sig = schnorr_sign(&sk, m, &mut rng);
     assert!(schnorr_verif(&pk, m, &sig));
 
     // Now look at function `wrong_schnorr_verif`
@@ -24,9 +51,10 @@ fn main() {
     // Q1: Break this scheme by forging a signature that will pass this flawed verification algorithm for an arbitrary message
     // You are only given the public key (no signing oracle)
     // Write a function `break_wrong_schnorr` that forges a signature
-    let (_, pk) = schnorr_keygen();
+    runner.question("Q1");
+    let (_, pk) = schnorr_keygen(&mut rng);
     let m = "Let's forge a signature for this message".as_bytes();
-    let sig = break_wrong_schnorr(&pk, m);
+    let sig = break_wrong_schnorr(&pk, m, &mut rng);
     assert!(wrong_schnorr_verif(&pk, m, &sig));
 
     // Now look at the variant of the signing algorithm `flawed_randomized_schnorr_sign`
@@ -34,7 +62,8 @@ fn main() {
     // Q2: Let's break `flawed_randomized_schnorr_sign`
     // You have free access to method `flawed_randomized_sign_oracle` implemented on some secret key instance `sk`
     // Write a function `break_flawed_randomized_schnorr` that retrieves this secret key
-    let (sk, pk) = schnorr_keygen();
+    runner.question("Q2");
+    let (sk, pk) = schnorr_keygen(&mut rng);
     let computed_sk = break_flawed_randomized_schnorr(&sk, &pk);
     assert_eq!(sk, computed_sk);
 
@@ -44,7 +73,8 @@ fn main() {
     // Q3: Let's break `flawed_deterministic_schnorr_sign`
     // You have free access to method `flawed_deterministic_sign_oracle` implemented on some secret key instance `sk`
     // Write a function `break_flawed_deterministic_schnorr` that retrieves this secret key
-    let (sk, pk) = schnorr_keygen();
+    runner.question("Q3");
+    let (sk, pk) = schnorr_keygen(&mut rng);
     let computed_sk = break_flawed_deterministic_schnorr(&sk, &pk);
     assert_eq!(sk, computed_sk);
 
@@ -59,28 +89,888 @@ fn main() {
     // You have free access to method `two_for_one_schnorr_sign_oracle`
     // implemented on some master secret key instance `msk`
     // Write a function `break_two_for_one_schnorr_sign` that retrieves this master secret key
-    let (msk, psk) = schnorr_keygen();
-    let computed_msk = break_two_for_one_schnorr_sign(&msk, &psk);
+    runner.question("Q4");
+    let (msk, psk) = schnorr_keygen(&mut rng);
+    let computed_msk = break_two_for_one_schnorr_sign(&msk, &psk, &mut rng);
     assert_eq!(msk, computed_msk);
 
+    // Now take a look at module `musig2`
+    // It implements BIP340, the flavor of Schnorr signatures used by Bitcoin, checked here against its first official test vector
+    let signature = bip340_sign(&Fr::from(3), &[0; 32], &[0; 32]);
+    assert_eq!(
+        hex(&signature),
+        "e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca8215\
+         25f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0"
+    );
+    // Taproot (BIP341) commits to scripts by tweaking an x-only internal key P into Q = P + H_TapTweak(P) G,
+    // with crate `tweak`, checked here against the first wallet test vector of BIP341 (no script tree)
+    let internal: [u8; 32] =
+        unhex("d6889cb081036e0faefa3a35157ad71086b123b2b144b649798b494c300a961d")
+            .try_into()
+            .unwrap();
+    let t = tweak::parse_tweak(&tagged_hash("TapTweak", &[&internal])).unwrap();
+    let (output, parity) = tweak::x_only_tweak_add(&internal, &t).unwrap();
+    assert_eq!(
+        hex(&output),
+        "53a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda343"
+    );
+    assert!(tweak::x_only_tweak_add_check(
+        &internal, &output, parity, &t
+    ));
+
+    // MuSig2 lets n signers produce a single BIP340 signature under an aggregate public key
+    // Nobody can tell it from a single-signer signature
+    // Q5: write `key_aggregation` computing the coefficients a_i = H(L, X_i) and the aggregate key X = sum a_i X_i
+    // Remember that BIP340 keys are x-only: if X has an odd y-coordinate, the signers will have to negate their keys
+    // Q6: implement the two rounds of the signing protocol
+    // - `Signer::nonce_gen` samples two secret nonces k_1, k_2 and publishes R_1 = k_1 G, R_2 = k_2 G
+    // - `session_context` sums the nonces of all signers into (R_1, R_2), derives b, R = R_1 + b R_2 and e
+    // - `Signer::partial_sign` outputs s_i = k_1 + b k_2 + e a_i x_i (mind the parities!)
+    // - `partial_verify` lets the aggregator check each s_i against the public nonces and key of signer i
+    // - `aggregate_partial_signatures` sums the s_i into a BIP340 signature (x(R), s)
+    runner.questions(&["Q5", "Q6"]);
+    let mut signers: Vec<Signer> = (0..3).map(|_| Signer::new(Fr::rand(&mut rng))).collect();
+    let pubkeys: Vec<Affine> = signers.iter().map(|signer| signer.pk).collect();
+    let aggregate_key = bytes_x(&key_aggregation(&pubkeys).aggregate_key);
+    for text in [
+        "MuSig2 is a two-round protocol",
+        "the nonces are not reused",
+    ] {
+        let m: [u8; 32] = sha2::Sha256::digest(text.as_bytes()).into();
+        let signature = run_musig2(&mut signers, &m, &mut rng);
+        assert!(bip340_verify(&aggregate_key, &m, &signature));
+        assert!(!bip340_verify(&bytes_x(&pubkeys[0]), &m, &signature));
+    }
+    // the aggregate key depends on the whole list of keys
+    let two_keys = key_aggregation(&pubkeys[..2]).aggregate_key;
+    assert_ne!(bytes_x(&two_keys), aggregate_key);
+
+    // Why coefficients? Naive key aggregation X = sum X_i is vulnerable to rogue-key attacks:
+    // Mallory announces her key *after* seeing Alice's one
+    // Q7: write `rogue_key_attack` which, given Alice's public key, returns a key for Mallory to announce
+    // together with the secret key of the naive aggregate of both keys
+    // Mallory can then sign alone on behalf of the "2-of-2" group
+    runner.question("Q7");
+    let (_, alice) = schnorr_keygen(&mut rng);
+    let (mallory_pk, naive_sk) = rogue_key_attack(&alice.0, &mut rng);
+    let naive_aggregate = (alice.0 + mallory_pk).into_affine();
+    let m: [u8; 32] = sha2::Sha256::digest("Alice agrees to pay Mallory".as_bytes()).into();
+    let forged = bip340_sign(&naive_sk, &m, &[0; 32]);
+    assert!(bip340_verify(&bytes_x(&naive_aggregate), &m, &forged));
+    // With MuSig2 key aggregation, Mallory's key is multiplied by a coefficient depending on itself
+    // so she cannot cancel Alice's contribution anymore
+    let musig_aggregate = key_aggregation(&[alice.0, mallory_pk]).aggregate_key;
+    assert!(!bip340_verify(&bytes_x(&musig_aggregate), &m, &forged));
+
+    // Now take a look at module `bip32`: hierarchical deterministic wallets
+    // Q8: write `master_key`, `derive_private_child`, `derive_public_child` and `neuter`
+    // They are checked against the first official test vector of BIP32
+    // (except for the parent fingerprint, a RIPEMD-160 hash we don't have at hand)
+    runner.question("Q8");
+    let seed = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f,
+    ];
+    let vectors = [
+        (
+            vec![],
+            "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi",
+            "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8",
+        ),
+        (
+            vec![HARDENED],
+            "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7",
+            "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw",
+        ),
+        (
+            vec![HARDENED, 1],
+            "xprv9wTYmMFdV23N2TdNG573QoEsfRrWKQgWeibmLntzniatZvR9BmLnvSxqu53Kw1UmYPxLgboyZQaXwTCg8MSY3H2EU4pWcQDnRnrVA1xe8fs",
+            "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ",
+        ),
+        (
+            vec![HARDENED, 1, HARDENED + 2],
+            "xprv9z4pot5VBttmtdRTWfWQmoH1taj2axGVzFqSb8C9xaxKymcFzXBDptWmT7FwuEzG3ryjH4ktypQSAewRiNMjANTtpgP4mLTj34bhnZX7UiM",
+            "xpub6D4BDPcP2GT577Vvch3R8wDkScZWzQzMMUm3PWbmWvVJrZwQY4VUNgqFJPMM3No2dFDFGTsxxpG5uJh7n7epu4trkrX7x7DogT5Uv6fcLW5",
+        ),
+        (
+            vec![HARDENED, 1, HARDENED + 2, 2],
+            "xprvA2JDeKCSNNZky6uBCviVfJSKyQ1mDYahRjijr5idH2WwLsEd4Hsb2Tyh8RfQMuPh7f7RtyzTtdrbdqqsunu5Mm3wDvUAKRHSC34sJ7in334",
+            "xpub6FHa3pjLCk84BayeJxFW2SP4XRrFd1JYnxeLeU8EqN3vDfZmbqBqaGJAyiLjTAwm6ZLRQUMv1ZACTj37sR62cfN7fe5JnJ7dh8zL4fiyLHV",
+        ),
+        (
+            vec![HARDENED, 1, HARDENED + 2, 2, 1000000000],
+            "xprvA41z7zogVVwxVSgdKUHDy1SKmdb533PjDz7J6N6mV6uS3ze1ai8FHa8kmHScGpWmj4WggLyQjgPie1rFSruoUihUZREPSL39UNdE3BBDu76",
+            "xpub6H1LXWLaKsWFhvm6RVpEL9P4KfRZSW7abD2ttkWP3SSQvnyA8FSVqNTEcYFgJS2UaFcxupHiYkro49S8yGasTvXEYBVPamhGW6cFJodrTHy",
+        ),
+    ];
+    for (path, xprv, xpub) in vectors {
+        let key = path
+            .iter()
+            .fold(master_key(&seed), |key, &i| derive_private_child(&key, i));
+        let expected = decode_extended_key(xprv);
+        assert_eq!(key.depth, expected.depth);
+        assert_eq!(key.child_number, expected.child_number);
+        assert_eq!(key.chain_code, expected.chain_code);
+        assert_eq!(expected.key_data[0], 0);
+        assert_eq!(ser256(&key.key), expected.key_data[1..]);
+        let public = neuter(&key);
+        let expected = decode_extended_key(xpub);
+        assert_eq!(public.chain_code, expected.chain_code);
+        assert_eq!(ser_p(&public.key), expected.key_data);
+    }
+    // a watch-only wallet derives the same public keys from the public parent, for non-hardened indices only
+    let account = [HARDENED, 1]
+        .iter()
+        .fold(master_key(&seed), |key, &i| derive_private_child(&key, i));
+    let account_public = neuter(&account);
+    for i in 0..5 {
+        assert_eq!(
+            derive_public_child(&account_public, i),
+            Some(neuter(&derive_private_child(&account, i)))
+        );
+    }
+    assert_eq!(derive_public_child(&account_public, HARDENED), None);
+
+    // Bonus
+    // Q9: the account xpub is handed to an accounting service, and the private key of one
+    // receiving address leaks (say it was imported in a buggy wallet)
+    // write `recover_parent_key` which recovers the private key of the account, hence of all its addresses
+    runner.question("Q9");
+    let leaked = derive_private_child(&account, 7);
+    let recovered = recover_parent_key(&account_public, &leaked);
+    assert_eq!(recovered, account.key);
+    // hardened derivation prevents this: the xpub of m/0H does not help with the master key
+    // Q10: why? What should be hardened in a wallet?
+    runner.question("Q10");
+
+    // Secret keys and MuSig2 nonces are wrapped in `secret::Scalar`, which wipes them when dropped
+    // Let's look at what a drop leaves behind in memory (reading it is the job of an attacker, hence the `unsafe`)
+    let x = Fr::rand(&mut rng);
+    let residue = unsafe { bytes_after_drop(x) };
+    assert!(residue.iter().any(|byte| *byte != 0));
+    let residue = unsafe { bytes_after_drop(SecretKey::new(x)) };
+    assert!(residue.iter().all(|byte| *byte == 0));
+    // Q11: `x` itself is still around after the second drop, why? Which other copies of the key
+    // does `schnorr_sign` leave behind? Why does `SecretKey` not derive `Copy` anymore?
+    runner.question("Q11");
+
+    // Before Taproot, Bitcoin signed with ECDSA: take a look at module `ecdsa`
+    // The example of BIP143 spends a segwit output: its witness holds a DER signature and a compressed key
+    let preimage = unhex(BIP143_PREIMAGE);
+    let z = sighash(&preimage);
+    assert_eq!(hex(&z), BIP143_SIGHASH);
+    let z = scalar_from_be(&z);
+    let witness_signature = unhex(BIP143_SIGNATURE);
+    let (der, sighash_type) = witness_signature.split_at(witness_signature.len() - 1);
+    assert_eq!(sighash_type, [0x01]); // SIGHASH_ALL
+
+    // Q12: write `parse_der` in module `decoders`, which accepts only the strict DER encoding of BIP66 and 0 < r, s < n
+    runner.question("Q12");
+    let (r, s) = parse_der(der).unwrap();
+    assert_eq!(der_encode(&r, &s), der);
+    let mut mutants = vec![
+        vec![],
+        der[..der.len() - 1].to_vec(),             // truncated
+        [der, &[0]].concat(),                      // trailing byte
+        [&[0x31], &der[1..]].concat(),             // not a sequence
+        [&[0x30, 0x81, 0x44], &der[2..]].concat(), // long form of a short length
+        [&[0x30, der[1] + 1, 0x02, 0x21, 0x00], &der[4..]].concat(), // r padded with a zero it does not need
+        der_encode(&Fr::zero(), &s),
+    ];
+    // r with its high bit set and without the 0x00 which keeps it positive
+    let high = loop {
+        let (r, s) = ecdsa_sign(&Fr::rand(&mut rng), &z, &mut rng);
+        let encoding = der_encode(&r, &s);
+        if encoding[3] == 33 {
+            break encoding;
+        }
+    };
+    assert!(parse_der(&high).is_some());
+    mutants.push([&[0x30, high[1] - 1, 0x02, 32], &high[5..]].concat());
+    // n itself, out of range
+    let n = Fr::MODULUS.to_bytes_be();
+    mutants.push([&[0x30, 6 + 33 + 1, 0x02, 33, 0], &n[..], &[0x02, 1, 1]].concat());
+    for mutant in &mutants {
+        assert_eq!(parse_der(mutant), None, "{}", hex(mutant));
+    }
+
+    // Q13: write `decompress` in module `decoders`, which decodes a compressed SEC1 public key
+    runner.question("Q13");
+    let public_key = decompress(&unhex(BIP143_PUBLIC_KEY)).unwrap();
+    assert!(public_key.is_on_curve());
+    for _ in 0..100 {
+        let point = Affine::rand(&mut rng);
+        let mut bytes = vec![if tweak::has_even_y(&point) { 2 } else { 3 }];
+        bytes.extend(tweak::x_only(&point).0);
+        assert_eq!(decompress(&bytes), Some(point));
+        bytes[0] ^= 1;
+        assert_eq!(decompress(&bytes), Some(-point));
+        bytes[0] = 4;
+        assert_eq!(decompress(&bytes), None);
+    }
+    let mut x_equal_p = vec![2];
+    x_equal_p.extend(ark_secp256k1::Fq::MODULUS.to_bytes_be());
+    assert_eq!(decompress(&x_equal_p), None);
+    assert_eq!(decompress(&unhex(BIP143_PUBLIC_KEY)[..32]), None);
+
+    // Q14: write `ecdsa_verify`, and check the signature of the BIP143 transaction
+    runner.question("Q14");
+    assert!(ecdsa_verify(&z, &(r, s), &public_key));
+    assert!(!ecdsa_verify(&(z + Fr::one()), &(r, s), &public_key));
+    assert!(!ecdsa_verify(&z, &(r, s), &-public_key));
+    for _ in 0..100 {
+        let secret_key = Fr::rand(&mut rng);
+        let public_key = Affine::generator().mul(secret_key).into_affine();
+        let z = Fr::rand(&mut rng);
+        let signature = ecdsa_sign(&secret_key, &z, &mut rng);
+        assert!(ecdsa_verify(&z, &signature, &public_key));
+        assert_eq!(
+            parse_der(&der_encode(&signature.0, &signature.1)),
+            Some(signature)
+        );
+        assert!(!ecdsa_verify(&Fr::rand(&mut rng), &signature, &public_key));
+    }
+    // (r, -s) is valid too: anyone can change the signature, hence the txid of a legacy transaction
+    assert!(ecdsa_verify(&z, &(r, -s), &public_key));
+
+    // Q15: BIP146 proposed to accept only s <= n / 2: is the signature of BIP143 "low s"? Why does segwit
+    // fix the malleability of the txid anyway? What did BIP66 fix that a lax DER parser would break?
+    runner.question("Q15");
+
+    // `ecdsa_sign` draws k from a random generator: a weak or repeated k leaks the private key, as in Q2
+    // RFC 6979 derives k deterministically from the private key and the hash of the message instead
+    // Q16: write `rfc6979_nonce`, the first nonce of HMAC_DRBG for a group of order q (see module `ecdsa`)
+    runner.question("Q16");
+    let p256_order = BigUint::from_bytes_be(&unhex(P256_ORDER));
+    let p256_key = BigUint::from_bytes_be(&unhex(RFC6979_P256_SECRET_KEY));
+    for (message, nonce) in RFC6979_P256_VECTORS {
+        let h1 = sha2::Sha256::digest(message.as_bytes());
+        let k = rfc6979_nonce(&p256_order, &p256_key, &h1);
+        assert_eq!(hex(&k.to_bytes_be()), nonce, "{}", message);
+    }
+    let n: BigUint = Fr::MODULUS.into();
+    let h1 = sha2::Sha256::digest(RFC6979_SECP256K1_MESSAGE.as_bytes());
+    let k = rfc6979_nonce(&n, &BigUint::one(), &h1);
+    assert_eq!(hex(&k.to_bytes_be()), RFC6979_SECP256K1_NONCE);
+    // bits2octets reduces the hash mod q before it enters the HMAC: h1 >= n and h1 - n give the same nonce
+    let high = BigUint::from_bytes_be(&[0xff; 32]);
+    let low = (&high - &n).to_bytes_be();
+    assert_eq!(
+        rfc6979_nonce(&n, &BigUint::one(), &high.to_bytes_be()),
+        rfc6979_nonce(
+            &n,
+            &BigUint::one(),
+            &[vec![0; 32 - low.len()], low].concat()
+        )
+    );
+
+    // Q17: write `ecdsa_sign_rfc6979`, ECDSA with the nonce of RFC 6979 on secp256k1
+    runner.question("Q17");
+    let signature = ecdsa_sign_rfc6979(&Fr::one(), &h1.into());
+    assert_eq!(
+        (hex(&ser256(&signature.0)), hex(&ser256(&signature.1))),
+        (
+            RFC6979_SECP256K1_SIGNATURE.0.to_string(),
+            RFC6979_SECP256K1_SIGNATURE.1.to_string()
+        )
+    );
+    for _ in 0..100 {
+        let secret_key = Fr::rand(&mut rng);
+        let public_key = Affine::generator().mul(secret_key).into_affine();
+        let hash: [u8; 32] = rng.gen();
+        let signature = ecdsa_sign_rfc6979(&secret_key, &hash);
+        assert!(ecdsa_verify(
+            &scalar_from_be(&hash),
+            &signature,
+            &public_key
+        ));
+        // the same message gives the same signature, another message another nonce
+        assert_eq!(ecdsa_sign_rfc6979(&secret_key, &hash), signature);
+        let other: [u8; 32] = rng.gen();
+        assert_ne!(ecdsa_sign_rfc6979(&secret_key, &other).0, signature.0);
+    }
+
+    // Q18: the signer of Q2 reused its nonce across messages, and the one of Q3 derived it from the private key
+    // and the message, like RFC 6979: why can two RFC 6979 signatures not share a nonce unless they sign the
+    // same hash? What did the nonce of Q3 miss that the challenge hashed, and why does ECDSA not have this
+    // problem? A fault during the computation of s makes a deterministic signer publish a wrong signature
+    // next to the right one, on the same k: what does that leak?
+    runner.question("Q18");
+
+    // Q2 reused k: two equations s_i k = z_i + r_i x with two unknowns k and x. A nonce which never repeats
+    // is not enough: a signer drawing k from a linear congruential generator, or counting k, k + 1, ...,
+    // publishes two nonces with k_2 = a k_1 + b for a and b an attacker knows, and the unknowns are still two
+    // Q19: write `recover_key_related_nonces`, which finds x from two signatures of z_1 and z_2 whose nonces
+    // are related by a and b, or `None` if the equations do not determine it. The signer may also have
+    // normalized s to the lower half as Bitcoin does, which negates the nonce: check the key against the
+    // public key
+    runner.question("Q19");
+    let lcg = (
+        Fr::from(6364136223846793005u64),
+        Fr::from(1442695040888963407u64),
+    );
+    let relations = [
+        (Fr::one(), Fr::zero()),                  // the same nonce twice
+        (Fr::one(), Fr::one()),                   // a counter
+        (Fr::one(), -Fr::from(1u64 << 32)),       // a counter going down
+        lcg,                                      // a linear congruential generator
+        (Fr::from(2), Fr::zero()),                // a doubling
+        (Fr::rand(&mut rng), Fr::rand(&mut rng)), // anything affine
+    ];
+    for (a, b) in relations {
+        for normalize in [false, true] {
+            let secret_key = Fr::rand(&mut rng);
+            let public_key = Affine::generator().mul(secret_key).into_affine();
+            let (z1, z2) = (Fr::rand(&mut rng), Fr::rand(&mut rng));
+            let k1 = Fr::rand(&mut rng);
+            let sign = |z: &Fr, k: &Fr| {
+                let signature = ecdsa_sign_with_nonce(&secret_key, z, k).unwrap();
+                match normalize {
+                    true => low_s(signature),
+                    false => signature,
+                }
+            };
+            let signed = [(z1, sign(&z1, &k1)), (z2, sign(&z2, &(a * k1 + b)))];
+            assert!(signed
+                .iter()
+                .all(|(z, signature)| ecdsa_verify(z, signature, &public_key)));
+            assert_eq!(
+                recover_key_related_nonces(&public_key, &signed, &a, &b),
+                Some(secret_key)
+            );
+            // the wrong relation gives a key which does not match
+            assert_eq!(
+                recover_key_related_nonces(&public_key, &signed, &a, &(b + Fr::one())),
+                None
+            );
+        }
+    }
+    // one message signed twice with the same nonce: twice the same equation
+    let secret_key = Fr::rand(&mut rng);
+    let public_key = Affine::generator().mul(secret_key).into_affine();
+    let z = Fr::rand(&mut rng);
+    let signature = ecdsa_sign_with_nonce(&secret_key, &z, &Fr::rand(&mut rng)).unwrap();
+    let signed = [(z, signature), (z, signature)];
+    assert_eq!(
+        recover_key_related_nonces(&public_key, &signed, &Fr::one(), &Fr::zero()),
+        None
+    );
+
+    // Q20: which relations between the nonces did Q19 need to know, and which would still leak the key if
+    // a and b were unknown but small? RFC 6979 nonces are unique but unpredictable: why is uniqueness not
+    // what ECDSA needs, and how do a few known bits of many nonces lead to the key (the hidden number problem)?
+    runner.question("Q20");
+
+    // Back to Taproot, take a look at module `taproot`
+    // Q21: write `taproot_tweak_pubkey`, which returns the output key Q and the parity of its y, and
+    // `taproot_tweak_seckey`, the secret key which signs for Q on the key path
+    runner.question("Q21");
+    let key32 = |hex: &str| -> [u8; 32] { unhex(hex).try_into().unwrap() };
+    let internal = key32(KEY_PATH_INTERNAL_KEY);
+    let (output, _) = taproot_tweak_pubkey(&internal, None);
+    assert_eq!(hex(&output), KEY_PATH_OUTPUT_KEY);
+    let internal_sk = scalar_from_be(&unhex(KEY_PATH_INTERNAL_SECRET_KEY));
+    assert_eq!(
+        bytes_x(&Affine::generator().mul(internal_sk).into_affine()),
+        internal
+    );
+    let tweaked_sk = taproot_tweak_seckey(&internal_sk, None);
+    assert_eq!(hex(&ser256(&tweaked_sk)), KEY_PATH_TWEAKED_SECRET_KEY);
+    // the key path spend is a plain BIP340 signature under Q, which the internal key cannot produce
+    let m = [0x42; 32];
+    assert!(bip340_verify(
+        &output,
+        &m,
+        &bip340_sign(&tweaked_sk, &m, &[1; 32])
+    ));
+    assert!(!bip340_verify(
+        &output,
+        &m,
+        &bip340_sign(&internal_sk, &m, &[1; 32])
+    ));
+    let one_leaf = key32(ONE_LEAF_INTERNAL_KEY);
+    let leaf = tapleaf_hash(TAPSCRIPT_LEAF_VERSION, &unhex(ONE_LEAF_SCRIPT));
+    assert_eq!(hex(&leaf), ONE_LEAF_HASH);
+    let (one_leaf_output, one_leaf_parity) = taproot_tweak_pubkey(&one_leaf, Some(&leaf));
+    assert_eq!(hex(&one_leaf_output), ONE_LEAF_OUTPUT_KEY);
+    let two_leaves = key32(TWO_LEAVES_INTERNAL_KEY);
+    let leaves = TWO_LEAVES.map(|(version, script)| tapleaf_hash(version, &unhex(script)));
+    let root = tapbranch_hash(&leaves[0], &leaves[1]);
+    assert_eq!(hex(&root), TWO_LEAVES_MERKLE_ROOT);
+    let (two_leaves_output, two_leaves_parity) = taproot_tweak_pubkey(&two_leaves, Some(&root));
+    assert_eq!(hex(&two_leaves_output), TWO_LEAVES_OUTPUT_KEY);
+    // the secret key follows the public key, with or without a script tree
+    for sk in [internal_sk, Fr::rand(&mut rng)] {
+        let p = bytes_x(&Affine::generator().mul(sk).into_affine());
+        for merkle_root in [None, Some(&root)] {
+            let q = bytes_x(
+                &Affine::generator()
+                    .mul(taproot_tweak_seckey(&sk, merkle_root))
+                    .into_affine(),
+            );
+            assert_eq!(q, taproot_tweak_pubkey(&p, merkle_root).0);
+        }
+    }
+
+    // Q22: write `verify_control_block`, which checks a script path spend of the output key
+    runner.question("Q22");
+    let control_block = |leaf_version: u8, parity: bool, internal: &[u8; 32], path: &[[u8; 32]]| {
+        let mut bytes = vec![leaf_version | parity as u8];
+        bytes.extend(internal);
+        bytes.extend(path.iter().flatten());
+        bytes
+    };
+    let one_leaf_script = unhex(ONE_LEAF_SCRIPT);
+    let valid = control_block(TAPSCRIPT_LEAF_VERSION, one_leaf_parity, &one_leaf, &[]);
+    assert!(verify_control_block(
+        &one_leaf_output,
+        &one_leaf_script,
+        &valid
+    ));
+    for (i, (version, script)) in TWO_LEAVES.iter().enumerate() {
+        let script = unhex(script);
+        let block = control_block(*version, two_leaves_parity, &two_leaves, &[leaves[1 - i]]);
+        assert!(verify_control_block(&two_leaves_output, &script, &block));
+        // another leaf version, the other parity, no path or another output key: rejected
+        let other_version = control_block(
+            version ^ 2,
+            two_leaves_parity,
+            &two_leaves,
+            &[leaves[1 - i]],
+        );
+        assert!(!verify_control_block(
+            &two_leaves_output,
+            &script,
+            &other_version
+        ));
+        let other_parity =
+            control_block(*version, !two_leaves_parity, &two_leaves, &[leaves[1 - i]]);
+        assert!(!verify_control_block(
+            &two_leaves_output,
+            &script,
+            &other_parity
+        ));
+        let no_path = control_block(*version, two_leaves_parity, &two_leaves, &[]);
+        assert!(!verify_control_block(&two_leaves_output, &script, &no_path));
+        assert!(!verify_control_block(&one_leaf_output, &script, &block));
+    }
+    // a script which is not in the tree, and malformed control blocks
+    assert!(!verify_control_block(
+        &one_leaf_output,
+        &unhex(TWO_LEAVES[1].1),
+        &valid
+    ));
+    assert!(!verify_control_block(
+        &one_leaf_output,
+        &one_leaf_script,
+        &valid[..32]
+    ));
+    let mut truncated_path = valid.clone();
+    truncated_path.push(0);
+    assert!(!verify_control_block(
+        &one_leaf_output,
+        &one_leaf_script,
+        &truncated_path
+    ));
+
+    // Q23: why does BIP341 tweak P with the Merkle root even when the output is meant for the key path only,
+    // with no script at all? What would an output key Q = P + H(root) G without P in the hash allow?
+    runner.question("Q23");
+
     println!("Good job!");
+    runner.finish();
 }
 
-fn break_wrong_schnorr(pk: &PublicKey, m: &[u8]) -> SchnorrSig {
-    todo!()
+fn break_wrong_schnorr<R: Rng>(pk: &PublicKey, m: &[u8], rng: &mut R) -> SchnorrSig {
+    // SOLUTION-BEGIN
+    // the challenge does not hash R: fix the response, then solve sG = R + cX for R
+    let response = Fr::rand(rng);
+    let challenge = hash_to_scalar_field(&(pk.0, m));
+    let commitment = (Affine::generator().mul(response) - pk.0.mul(challenge)).into_affine();
+    SchnorrSig {
+        commitment,
+        response,
+    }
+    // SOLUTION-END
 }
 
 fn break_flawed_randomized_schnorr(sk: &SecretKey, pk: &PublicKey) -> SecretKey {
-    todo!()
-    // all you are allowed do with `sk` is call `sk.flawed_randomized_sign_oracle` on messages of your choice
+    // all you are allowed to do with `sk` is call `sk.flawed_randomized_sign_oracle` on messages of your choice
+    // SOLUTION-BEGIN
+    // the generator is seeded with the secret key: every signature has the same nonce
+    let (m1, m2) = ("first".as_bytes(), "second".as_bytes());
+    let (sig1, sig2) = (
+        sk.flawed_randomized_sign_oracle(m1),
+        sk.flawed_randomized_sign_oracle(m2),
+    );
+    let c1 = hash_to_scalar_field(&(pk.0, sig1.commitment, m1));
+    let c2 = hash_to_scalar_field(&(pk.0, sig2.commitment, m2));
+    // s_1 = r + c_1 x and s_2 = r + c_2 x for the same nonce r
+    SecretKey::new((sig1.response - sig2.response) / (c1 - c2))
+    // SOLUTION-END
 }
 
 fn break_flawed_deterministic_schnorr(sk: &SecretKey, pk: &PublicKey) -> SecretKey {
-    todo!()
-    // all you are allowed do is with `sk` is call `sk.flawed_deterministic_sign_oracle` on inputs of your choice
+    // all you are allowed to do with `sk` is call `sk.flawed_deterministic_sign_oracle` on inputs of your choice
+    // SOLUTION-BEGIN
+    // the nonce hashes the secret key and the message, but not the public key the challenge hashes: the same
+    // message under another public key gets the same nonce and another challenge
+    let m = "message".as_bytes();
+    let other = PublicKey((pk.0 + Affine::generator()).into_affine());
+    let (sig1, sig2) = (
+        sk.flawed_deterministic_sign_oracle(pk, m),
+        sk.flawed_deterministic_sign_oracle(&other, m),
+    );
+    let c1 = hash_to_scalar_field(&(*pk, sig1.commitment, m));
+    let c2 = hash_to_scalar_field(&(other, sig2.commitment, m));
+    SecretKey::new((sig1.response - sig2.response) / (c1 - c2))
+    // SOLUTION-END
+}
+
+fn break_two_for_one_schnorr_sign<R: Rng>(
+    msk: &SecretKey,
+    mpk: &PublicKey,
+    rng: &mut R,
+) -> SecretKey {
+    // all you are allowed to do with `msk` is call `msk.two_for_one_schnorr_sign_oracle` on messages of your choice
+    // SOLUTION-BEGIN
+    // the tweaks are public: s_i - t_i - c_i t_i = r + c_i x, the same nonce r for both signatures
+    let (m1, m2) = ("first".as_bytes(), "second".as_bytes());
+    let (sig1, sig2) = msk.two_for_one_schnorr_sign_oracle(m1, m2, rng);
+    let untweak = |index: &str, sig: &SchnorrSig, m: &[u8]| {
+        let tweak = hash_to_scalar_field(&(mpk.0, index.as_bytes()));
+        let pk = (mpk.0 + Affine::generator().mul(tweak)).into_affine();
+        let challenge = hash_to_scalar_field(&(pk, sig.commitment, m));
+        (sig.response - tweak - challenge * tweak, challenge)
+    };
+    let ((a1, c1), (a2, c2)) = (untweak("1", &sig1, m1), untweak("2", &sig2, m2));
+    SecretKey::new((a1 - a2) / (c1 - c2))
+    // SOLUTION-END
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn unhex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn ecdsa_verify(z: &Fr, signature: &(Fr, Fr), public_key: &Affine) -> bool {
+    // SOLUTION-BEGIN
+    let (r, s) = signature;
+    let Some(w) = s.inverse() else {
+        return false;
+    };
+    if r.is_zero() {
+        return false;
+    }
+    let point = (Affine::generator().mul(*z * w) + public_key.mul(*r * w)).into_affine();
+    !point.is_zero() && x_mod_n(&point) == *r
+    // SOLUTION-END
+}
+
+fn rfc6979_nonce(q: &BigUint, x: &BigUint, h1: &[u8]) -> BigUint {
+    // SOLUTION-BEGIN
+    let qlen = q.bits() as usize;
+    let rlen = qlen.div_ceil(8);
+    let int2octets = |v: &BigUint| {
+        let bytes = v.to_bytes_be();
+        [vec![0; rlen - bytes.len()], bytes].concat()
+    };
+    let bits2int = |bytes: &[u8]| {
+        let v = BigUint::from_bytes_be(bytes);
+        match bytes.len() * 8 > qlen {
+            true => v >> (bytes.len() * 8 - qlen),
+            false => v,
+        }
+    };
+    let seed = [int2octets(x), int2octets(&(bits2int(h1) % q))].concat();
+    let mut v = [1u8; 32];
+    let mut k = [0u8; 32];
+    for separator in [0, 1] {
+        k = hmac_sha256(&k, &[&v[..], &[separator], &seed].concat());
+        v = hmac_sha256(&k, &v);
+    }
+    loop {
+        let mut t = vec![];
+        while t.len() < rlen {
+            v = hmac_sha256(&k, &v);
+            t.extend(v);
+        }
+        let nonce = bits2int(&t);
+        if !nonce.is_zero() && nonce < *q {
+            return nonce;
+        }
+        k = hmac_sha256(&k, &[&v[..], &[0]].concat());
+        v = hmac_sha256(&k, &v);
+    }
+    // SOLUTION-END
+}
+
+fn ecdsa_sign_rfc6979(secret_key: &Fr, hash: &[u8; 32]) -> (Fr, Fr) {
+    // SOLUTION-BEGIN
+    // r = 0 or s = 0 would call for the next output of the generator, with probability 2^-256
+    let k = Fr::from(rfc6979_nonce(
+        &Fr::MODULUS.into(),
+        &(*secret_key).into(),
+        hash,
+    ));
+    let r = x_mod_n(&Affine::generator().mul(k).into_affine());
+    let s = (scalar_from_be(hash) + r * secret_key) * k.inverse().unwrap();
+    (r, s)
+    // SOLUTION-END
+}
+
+fn key_aggregation(pubkeys: &[Affine]) -> KeyAggContext {
+    // SOLUTION-BEGIN
+    let list_hash = key_list_hash(pubkeys);
+    let coefficients: Vec<Fr> = pubkeys
+        .iter()
+        .map(|pk| key_agg_coefficient(&list_hash, pk))
+        .collect();
+    let aggregate = pubkeys
+        .iter()
+        .zip(&coefficients)
+        .map(|(pk, a)| pk.mul(a))
+        .sum::<Projective>()
+        .into_affine();
+    let (aggregate_key, parity) = if has_even_y(&aggregate) {
+        (aggregate, Fr::one())
+    } else {
+        (-aggregate, -Fr::one())
+    };
+    KeyAggContext {
+        pubkeys: pubkeys.to_vec(),
+        coefficients,
+        aggregate_key,
+        parity,
+    }
+    // SOLUTION-END
+}
+
+impl Signer {
+    fn nonce_gen<R: Rng>(&mut self, rng: &mut R) -> PublicNonce {
+        // SOLUTION-BEGIN
+        let k1 = Fr::rand(rng);
+        let k2 = Fr::rand(rng);
+        self.secret_nonce = Some((Scalar::new(k1), Scalar::new(k2)));
+        PublicNonce(
+            Affine::generator().mul(k1).into_affine(),
+            Affine::generator().mul(k2).into_affine(),
+        )
+        // SOLUTION-END
+    }
+
+    fn partial_sign(&mut self, key_agg: &KeyAggContext, session: &SessionContext) -> Fr {
+        // SOLUTION-BEGIN
+        let (k1, k2) = self
+            .secret_nonce
+            .take()
+            .expect("nonces can only be used once");
+        let i = key_agg
+            .pubkeys
+            .iter()
+            .position(|pk| *pk == self.pk)
+            .expect("not a signer of this session");
+        let k = session.nonce_parity * (*k1.expose() + session.nonce_coefficient * k2.expose());
+        k + session.challenge * key_agg.coefficients[i] * key_agg.parity * self.sk.expose()
+        // SOLUTION-END
+    }
+}
+
+fn session_context(
+    key_agg: &KeyAggContext,
+    nonces: &[PublicNonce],
+    m: &[u8; 32],
+) -> SessionContext {
+    // SOLUTION-BEGIN
+    let r1: Projective = nonces.iter().map(|nonce| nonce.0).sum();
+    let r2: Projective = nonces.iter().map(|nonce| nonce.1).sum();
+    let aggregate_nonce = PublicNonce(r1.into_affine(), r2.into_affine());
+    let b = nonce_coefficient(&aggregate_nonce, &key_agg.aggregate_key, m);
+    let r = (r1 + r2.mul(b)).into_affine();
+    let (nonce, nonce_parity) = if has_even_y(&r) {
+        (r, Fr::one())
+    } else {
+        (-r, -Fr::one())
+    };
+    SessionContext {
+        nonce_coefficient: b,
+        nonce,
+        nonce_parity,
+        challenge: bip340_challenge(&bytes_x(&nonce), &bytes_x(&key_agg.aggregate_key), m),
+    }
+    // SOLUTION-END
+}
+
+fn partial_verify(
+    key_agg: &KeyAggContext,
+    session: &SessionContext,
+    i: usize,
+    nonce: &PublicNonce,
+    partial_signature: &Fr,
+) -> bool {
+    // SOLUTION-BEGIN
+    let r_i = (nonce.0 + nonce.1.mul(session.nonce_coefficient)).mul(session.nonce_parity);
+    let a_i = key_agg.coefficients[i] * key_agg.parity;
+    Affine::generator().mul(partial_signature)
+        == r_i + key_agg.pubkeys[i].mul(session.challenge * a_i)
+    // SOLUTION-END
+}
+
+fn aggregate_partial_signatures(session: &SessionContext, partial_signatures: &[Fr]) -> [u8; 64] {
+    // SOLUTION-BEGIN
+    encode_signature(&session.nonce, &partial_signatures.iter().sum())
+    // SOLUTION-END
+}
+
+fn rogue_key_attack<R: Rng>(victim: &Affine, rng: &mut R) -> (Affine, Fr) {
+    // SOLUTION-BEGIN
+    // X_M := x_M G - X_A so that X_A + X_M = x_M G
+    let x_m = Fr::rand(rng);
+    let rogue = (Affine::generator().mul(x_m) - victim).into_affine();
+    (rogue, x_m)
+    // SOLUTION-END
+}
+
+fn master_key(seed: &[u8]) -> ExtendedPrivateKey {
+    // SOLUTION-BEGIN
+    let i = hmac_sha512(b"Bitcoin seed", seed);
+    ExtendedPrivateKey {
+        depth: 0,
+        child_number: 0,
+        chain_code: i[32..].try_into().unwrap(),
+        key: parse256(&i[..32])
+            .filter(|k| !k.is_zero())
+            .expect("invalid master key"),
+    }
+    // SOLUTION-END
+}
+
+fn derive_private_child(parent: &ExtendedPrivateKey, index: u32) -> ExtendedPrivateKey {
+    // SOLUTION-BEGIN
+    let mut data = Vec::new();
+    if index >= HARDENED {
+        data.push(0);
+        data.extend(ser256(&parent.key));
+    } else {
+        data.extend(ser_p(&Affine::generator().mul(parent.key).into_affine()));
+    }
+    data.extend(index.to_be_bytes());
+    let i = hmac_sha512(&parent.chain_code, &data);
+    // the (negligible) invalid cases should move on to the next index, we just give up
+    let key =
+        tweak::tweak_add_private(&parent.key, &parse256(&i[..32]).expect("invalid child key"))
+            .expect("invalid child key");
+    ExtendedPrivateKey {
+        depth: parent.depth + 1,
+        child_number: index,
+        chain_code: i[32..].try_into().unwrap(),
+        key,
+    }
+    // SOLUTION-END
+}
+
+fn derive_public_child(parent: &ExtendedPublicKey, index: u32) -> Option<ExtendedPublicKey> {
+    // SOLUTION-BEGIN
+    if index >= HARDENED {
+        return None;
+    }
+    let mut data = ser_p(&parent.key).to_vec();
+    data.extend(index.to_be_bytes());
+    let i = hmac_sha512(&parent.chain_code, &data);
+    let key = tweak::tweak_add(&parent.key, &parse256(&i[..32])?).ok()?;
+    Some(ExtendedPublicKey {
+        depth: parent.depth + 1,
+        child_number: index,
+        chain_code: i[32..].try_into().unwrap(),
+        key,
+    })
+    // SOLUTION-END
+}
+
+fn neuter(key: &ExtendedPrivateKey) -> ExtendedPublicKey {
+    // SOLUTION-BEGIN
+    ExtendedPublicKey {
+        depth: key.depth,
+        child_number: key.child_number,
+        chain_code: key.chain_code,
+        key: Affine::generator().mul(key.key).into_affine(),
+    }
+    // SOLUTION-END
+}
+
+fn recover_parent_key(parent: &ExtendedPublicKey, child: &ExtendedPrivateKey) -> Fr {
+    // SOLUTION-BEGIN
+    // k_i = k + IL where IL only depends on public data
+    let mut data = ser_p(&parent.key).to_vec();
+    data.extend(child.child_number.to_be_bytes());
+    let i = hmac_sha512(&parent.chain_code, &data);
+    child.key - parse256(&i[..32]).unwrap()
+    // SOLUTION-END
+}
+
+fn recover_key_related_nonces(
+    public_key: &Affine,
+    signed: &[(Fr, (Fr, Fr)); 2],
+    a: &Fr,
+    b: &Fr,
+) -> Option<Fr> {
+    // SOLUTION-BEGIN
+    // k_i = (z_i + r_i x) / s_i and k_2 = a k_1 + b:
+    // (z_2 + r_2 x) / s_2 = a (z_1 + r_1 x) / s_1 + b, so x (r_2 s_1 - a r_1 s_2) = a z_1 s_2 + b s_1 s_2 - z_2 s_1
+    let [(z1, (r1, s1)), (z2, (r2, s2))] = *signed;
+    // s may have been negated, with its nonce: try the four signs
+    for (s1, s2) in [(s1, s2), (-s1, s2), (s1, -s2), (-s1, -s2)] {
+        let Some(denominator) = (r2 * s1 - *a * r1 * s2).inverse() else {
+            continue;
+        };
+        let x = (*a * z1 * s2 + *b * s1 * s2 - z2 * s1) * denominator;
+        if Affine::generator().mul(x).into_affine() == *public_key {
+            return Some(x);
+        }
+    }
+    None
+    // SOLUTION-END
+}
+
+fn taproot_tweak_pubkey(internal: &[u8; 32], merkle_root: Option<&[u8; 32]>) -> ([u8; 32], bool) {
+    // SOLUTION-BEGIN
+    let root: &[u8] = merkle_root.map_or(&[], |root| root);
+    let t = tweak::parse_tweak(&tagged_hash("TapTweak", &[internal, root])).expect("t >= n");
+    tweak::x_only_tweak_add(internal, &t).expect("invalid tweak")
+    // SOLUTION-END
+}
+
+fn taproot_tweak_seckey(sk: &Fr, merkle_root: Option<&[u8; 32]>) -> Fr {
+    // SOLUTION-BEGIN
+    let internal = bytes_x(&Affine::generator().mul(sk).into_affine());
+    let root: &[u8] = merkle_root.map_or(&[], |root| root);
+    let t = tweak::parse_tweak(&tagged_hash("TapTweak", &[&internal, root])).expect("t >= n");
+    tweak::x_only_tweak_add_private(sk, &t).expect("invalid tweak")
+    // SOLUTION-END
 }
 
-fn break_two_for_one_schnorr_sign(msk: &SecretKey, mpk: &PublicKey) -> SecretKey {
-    todo!()
-    // all you are allowed do is with `sk` is call `sk.flawed_deterministic_sign_oracle` on inputs of your choice
+fn verify_control_block(output: &[u8; 32], script: &[u8], control_block: &[u8]) -> bool {
+    // SOLUTION-BEGIN
+    if control_block.len() < 33 || !(control_block.len() - 33).is_multiple_of(32) {
+        return false;
+    }
+    let leaf_version = control_block[0] & 0xfe;
+    let parity = control_block[0] & 1 == 1;
+    let internal: [u8; 32] = control_block[1..33].try_into().unwrap();
+    let root = control_block[33..]
+        .chunks(32)
+        .fold(tapleaf_hash(leaf_version, script), |node, sibling| {
+            tapbranch_hash(&node, sibling.try_into().unwrap())
+        });
+    let Ok(t) = tweak::parse_tweak(&tagged_hash("TapTweak", &[&internal, &root])) else {
+        return false;
+    };
+    tweak::x_only_tweak_add_check(&internal, output, parity, &t)
+    // SOLUTION-END
 }
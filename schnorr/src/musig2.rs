@@ -0,0 +1,246 @@
+use crate::*;
+use ark_ff::BigInteger;
+use rand_chacha::ChaChaRng;
+use sha2::{Digest, Sha256};
+use training_common::protocol_sim::{Outgoing, Party, PartyId, Simnet, SimnetConfig};
+
+// BIP340 Schnorr signatures, as used by Bitcoin since Taproot
+// - public keys are x-only: a point P is encoded by its x-coordinate and the point with even y is implied
+// - the signature of m is (x(R), s) with s = k + e x, e = H_tag(x(R) || x(P) || m)
+// - the signer negates her secret key (resp. her nonce) if P (resp. R) has an odd y-coordinate
+// - hashes are "tagged" with the purpose of the hash: H_tag(x) = SHA256(SHA256(tag) || SHA256(tag) || x)
+
+pub fn tagged_hash(tag: &str, data: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag.as_bytes());
+    data.iter()
+        .fold(
+            Sha256::new().chain_update(tag_hash).chain_update(tag_hash),
+            |hasher, chunk| hasher.chain_update(chunk),
+        )
+        .finalize()
+        .into()
+}
+
+pub fn bytes_x(point: &Affine) -> [u8; 32] {
+    point.x.into_bigint().to_bytes_be().try_into().unwrap()
+}
+
+pub use tweak::has_even_y;
+
+// the point with x-coordinate `x` and an even y-coordinate, if any
+pub fn lift_x(x: &[u8; 32]) -> Option<Affine> {
+    tweak::lift_x(x).ok()
+}
+
+// e = H_BIP0340/challenge(x(R) || x(P) || m) mod n
+pub fn bip340_challenge(r_x: &[u8; 32], p_x: &[u8; 32], m: &[u8; 32]) -> Fr {
+    Fr::from_be_bytes_mod_order(&tagged_hash("BIP0340/challenge", &[r_x, p_x, m]))
+}
+
+// BIP340 signing, with the auxiliary randomness `aux` used to derive the nonce
+pub fn bip340_sign(sk: &Fr, m: &[u8; 32], aux: &[u8; 32]) -> [u8; 64] {
+    let p = Affine::generator().mul(sk).into_affine();
+    let d = if has_even_y(&p) { *sk } else { -*sk };
+    let d_bytes: [u8; 32] = d.into_bigint().to_bytes_be().try_into().unwrap();
+    let t: Vec<u8> = d_bytes
+        .iter()
+        .zip(tagged_hash("BIP0340/aux", &[aux]))
+        .map(|(a, b)| a ^ b)
+        .collect();
+    let nonce = tagged_hash("BIP0340/nonce", &[&t, &bytes_x(&p), m]);
+    let k = Fr::from_be_bytes_mod_order(&nonce);
+    assert!(!k.is_zero());
+    let r = Affine::generator().mul(k).into_affine();
+    let k = if has_even_y(&r) { k } else { -k };
+    let e = bip340_challenge(&bytes_x(&r), &bytes_x(&p), m);
+    encode_signature(&r, &(k + e * d))
+}
+
+pub fn encode_signature(r: &Affine, s: &Fr) -> [u8; 64] {
+    let mut signature = [0u8; 64];
+    signature[..32].copy_from_slice(&bytes_x(r));
+    signature[32..].copy_from_slice(&s.into_bigint().to_bytes_be());
+    signature
+}
+
+pub fn bip340_verify(pk: &[u8; 32], m: &[u8; 32], signature: &[u8; 64]) -> bool {
+    let Some(p) = lift_x(pk) else {
+        return false;
+    };
+    let r_x: [u8; 32] = signature[..32].try_into().unwrap();
+    let s = Fr::from_be_bytes_mod_order(&signature[32..]);
+    if s.into_bigint().to_bytes_be() != signature[32..] {
+        return false; // s >= n
+    }
+    let e = bip340_challenge(&r_x, pk, m);
+    let r = (Affine::generator().mul(s) - p.mul(e)).into_affine();
+    !r.is_zero() && has_even_y(&r) && bytes_x(&r) == r_x
+}
+
+// MuSig2: n signers produce a single BIP340 signature under an aggregate key X = sum a_i X_i
+// The coefficients a_i = H(L, X_i), where L commits to the whole list of keys, prevent rogue-key attacks
+pub struct KeyAggContext {
+    pub pubkeys: Vec<Affine>,
+    pub coefficients: Vec<Fr>,
+    pub aggregate_key: Affine, // the point with even y matching the x-only aggregate key
+    pub parity: Fr,            // -1 if sum a_i X_i had to be negated to get an even y, 1 otherwise
+}
+
+pub fn key_list_hash(pubkeys: &[Affine]) -> [u8; 32] {
+    let encoded: Vec<u8> = pubkeys.iter().flat_map(bytes_x).collect();
+    tagged_hash("KeyAgg list", &[&encoded])
+}
+
+// a_i = H_KeyAgg coefficient(L || x(X_i))
+pub fn key_agg_coefficient(list_hash: &[u8; 32], pubkey: &Affine) -> Fr {
+    Fr::from_be_bytes_mod_order(&tagged_hash(
+        "KeyAgg coefficient",
+        &[list_hash, &bytes_x(pubkey)],
+    ))
+}
+
+// each signer contributes two nonces per signing session
+#[derive(Clone, Copy, Debug)]
+pub struct PublicNonce(pub Affine, pub Affine);
+
+pub struct Signer {
+    pub sk: Scalar<Fr>,
+    pub pk: Affine,
+    pub secret_nonce: Option<(Scalar<Fr>, Scalar<Fr>)>, // must be used for one signature only!
+}
+
+// what every participant derives once all the public nonces are known
+pub struct SessionContext {
+    pub nonce_coefficient: Fr, // b = H_MuSig/noncecoef(R_1 || R_2 || x(X) || m)
+    pub nonce: Affine,         // the even-y point matching R = R_1 + b R_2
+    pub nonce_parity: Fr,      // -1 if R had to be negated, 1 otherwise
+    pub challenge: Fr,         // e = H_BIP0340/challenge(x(R) || x(X) || m)
+}
+
+pub fn nonce_coefficient(
+    aggregate_nonce: &PublicNonce,
+    aggregate_key: &Affine,
+    m: &[u8; 32],
+) -> Fr {
+    let mut r1 = Vec::new();
+    let mut r2 = Vec::new();
+    aggregate_nonce.0.serialize_compressed(&mut r1).unwrap();
+    aggregate_nonce.1.serialize_compressed(&mut r2).unwrap();
+    Fr::from_be_bytes_mod_order(&tagged_hash(
+        "MuSig/noncecoef",
+        &[&r1, &r2, &bytes_x(aggregate_key), m],
+    ))
+}
+
+impl Signer {
+    pub fn new(sk: Fr) -> Signer {
+        Signer {
+            sk: Scalar::new(sk),
+            pk: Affine::generator().mul(sk).into_affine(),
+            secret_nonce: None,
+        }
+    }
+}
+
+// the messages of a signing session, broadcast over a `Simnet` of the training-common crate (module `protocol_sim`)
+#[derive(Clone, Copy, Debug)]
+pub enum Musig2Message {
+    Nonce(PublicNonce),
+    PartialSignature(Fr),
+}
+
+// signer i in a signing session of m: she gathers the public nonces of everybody, then their partial
+// signatures, and aggregates the final signature herself (the messages can arrive in any order)
+pub struct SigningSession<'a> {
+    pub signer: &'a mut Signer,
+    pub key_agg: &'a KeyAggContext,
+    pub m: [u8; 32],
+    pub nonces: Vec<Option<PublicNonce>>,
+    pub partial_signatures: Vec<Option<Fr>>,
+    pub session: Option<SessionContext>,
+    pub signature: Option<[u8; 64]>,
+}
+
+impl<'a> SigningSession<'a> {
+    pub fn new(
+        signer: &'a mut Signer,
+        key_agg: &'a KeyAggContext,
+        m: &[u8; 32],
+    ) -> SigningSession<'a> {
+        let n = key_agg.pubkeys.len();
+        SigningSession {
+            signer,
+            key_agg,
+            m: *m,
+            nonces: vec![None; n],
+            partial_signatures: vec![None; n],
+            session: None,
+            signature: None,
+        }
+    }
+
+    // round 2 starts once every nonce is known, the aggregation once every partial signature is
+    fn progress(&mut self) -> Vec<Outgoing<Musig2Message>> {
+        let mut outgoing = vec![];
+        if self.session.is_none() && self.nonces.iter().all(Option::is_some) {
+            let nonces: Vec<PublicNonce> = self.nonces.iter().flatten().copied().collect();
+            let session = session_context(self.key_agg, &nonces, &self.m);
+            let partial_signature = self.signer.partial_sign(self.key_agg, &session);
+            outgoing.push(Outgoing::Broadcast(Musig2Message::PartialSignature(
+                partial_signature,
+            )));
+            self.session = Some(session);
+        }
+        if let (None, Some(session)) = (self.signature, &self.session) {
+            if self.partial_signatures.iter().all(Option::is_some) {
+                let partial_signatures: Vec<Fr> =
+                    self.partial_signatures.iter().flatten().copied().collect();
+                for (i, s_i) in partial_signatures.iter().enumerate() {
+                    let nonce = self.nonces[i].as_ref().unwrap();
+                    assert!(partial_verify(self.key_agg, session, i, nonce, s_i));
+                }
+                self.signature = Some(aggregate_partial_signatures(session, &partial_signatures));
+            }
+        }
+        outgoing
+    }
+}
+
+impl Party<Musig2Message> for SigningSession<'_> {
+    // round 1: exchange nonces (this can even be done before the message is known)
+    fn start(&mut self, rng: &mut ChaChaRng) -> Vec<Outgoing<Musig2Message>> {
+        let nonce = self.signer.nonce_gen(rng);
+        vec![Outgoing::Broadcast(Musig2Message::Nonce(nonce))]
+    }
+
+    fn receive(
+        &mut self,
+        from: PartyId,
+        message: Musig2Message,
+        _rng: &mut ChaChaRng,
+    ) -> Vec<Outgoing<Musig2Message>> {
+        match message {
+            Musig2Message::Nonce(nonce) => self.nonces[from] = Some(nonce),
+            Musig2Message::PartialSignature(s_i) => self.partial_signatures[from] = Some(s_i),
+        }
+        self.progress()
+    }
+}
+
+// the two rounds of MuSig2 between all the signers, signer i being the party i of the network
+pub fn run_musig2<R: Rng>(signers: &mut [Signer], m: &[u8; 32], rng: &mut R) -> [u8; 64] {
+    let pubkeys: Vec<Affine> = signers.iter().map(|signer| signer.pk).collect();
+    let key_agg = key_aggregation(&pubkeys);
+    let mut sessions: Vec<SigningSession> = signers
+        .iter_mut()
+        .map(|signer| SigningSession::new(signer, &key_agg, m))
+        .collect();
+    let mut parties: Vec<&mut dyn Party<Musig2Message>> = sessions
+        .iter_mut()
+        .map(|session| session as &mut dyn Party<Musig2Message>)
+        .collect();
+    Simnet::new(SimnetConfig::default(), rng.gen()).run(&mut parties);
+    sessions[0]
+        .signature
+        .expect("the signing session did not complete")
+}
@@ -1,7 +1,8 @@
 use crate::*;
 
-#[derive(CanonicalSerialize, Clone, Copy, Debug, PartialEq)]
-pub struct SecretKey(Fr);
+// wiped from memory when dropped
+#[derive(Clone, Debug, PartialEq)]
+pub struct SecretKey(Scalar<Fr>);
 
 #[derive(CanonicalSerialize, Clone, Copy, Debug)]
 pub struct PublicKey(pub Affine);
@@ -11,11 +12,10 @@ pub struct SchnorrSig {
     pub response: Fr,       // s
 }
 
-pub fn schnorr_keygen() -> (SecretKey, PublicKey) {
-    let mut rng = rand::thread_rng();
-    let sk = Fr::rand(&mut rng);
+pub fn schnorr_keygen<R: Rng>(rng: &mut R) -> (SecretKey, PublicKey) {
+    let sk = Fr::rand(rng);
     let pk = Affine::generator().mul(sk).into_affine();
-    (SecretKey(sk), PublicKey(pk))
+    (SecretKey(Scalar::new(sk)), PublicKey(pk))
 }
 
 // hash the inputs into the scalar field of secp256k1
@@ -25,17 +25,16 @@ pub fn hash_to_scalar_field<C: CanonicalSerialize>(input: &C) -> Fr {
     Fr::from_le_bytes_mod_order(&bytes)
 }
 
-pub fn schnorr_sign(sk: &SecretKey, m: &[u8]) -> SchnorrSig {
-    let mut rng = rand::thread_rng();
-    let pk = Affine::generator().mul(sk.0).into_affine();
+pub fn schnorr_sign<R: Rng>(sk: &SecretKey, m: &[u8], rng: &mut R) -> SchnorrSig {
+    let pk = Affine::generator().mul(*sk.0.expose()).into_affine();
     // r unif. random
-    let random_scalar = Fr::rand(&mut rng);
+    let random_scalar = Fr::rand(rng);
     // R := rG
     let commitment = Affine::generator().mul(random_scalar).into_affine();
     // c := H(X,R,m)
     let challenge = hash_to_scalar_field(&(pk, commitment, m));
     // s := r + cx
-    let response = random_scalar + (challenge * sk.0);
+    let response = random_scalar + (challenge * *sk.0.expose());
     SchnorrSig {
         commitment,
         response,
@@ -62,14 +61,14 @@ pub fn wrong_schnorr_verif(pk: &PublicKey, m: &[u8], sig: &SchnorrSig) -> bool {
 }
 
 pub fn flawed_randomized_schnorr_sign(sk: &SecretKey, m: &[u8]) -> SchnorrSig {
-    let x: num_bigint::BigUint = sk.0.into();
+    let x: num_bigint::BigUint = (*sk.0.expose()).into();
     let seed: [u8; 32] = x.to_bytes_le().try_into().unwrap();
     let mut rng = ChaChaRng::from_seed(seed);
-    let pk = Affine::generator().mul(sk.0).into_affine();
+    let pk = Affine::generator().mul(*sk.0.expose()).into_affine();
     let random_scalar = Fr::rand(&mut rng);
     let commitment = Affine::generator().mul(random_scalar).into_affine();
     let challenge = hash_to_scalar_field(&(pk, commitment, m));
-    let response = random_scalar + (challenge * sk.0);
+    let response = random_scalar + (challenge * *sk.0.expose());
     SchnorrSig {
         commitment,
         response,
@@ -77,26 +76,30 @@ pub fn flawed_randomized_schnorr_sign(sk: &SecretKey, m: &[u8]) -> SchnorrSig {
 }
 
 pub fn flawed_deterministic_schnorr_sign(sk: &SecretKey, pk: &PublicKey, m: &[u8]) -> SchnorrSig {
-    let pseudorandom_scalar = hash_to_scalar_field(&(*sk, m));
+    let pseudorandom_scalar = hash_to_scalar_field(&(*sk.0.expose(), m));
     let commitment = Affine::generator().mul(pseudorandom_scalar).into_affine();
     let challenge = hash_to_scalar_field(&(*pk, commitment, m));
-    let response = pseudorandom_scalar + (challenge * sk.0);
+    let response = pseudorandom_scalar + (challenge * *sk.0.expose());
     SchnorrSig {
         commitment,
         response,
     }
 }
 
-pub fn two_for_one_schnorr_sign(msk: &SecretKey, m1: &[u8], m2: &[u8]) -> (SchnorrSig, SchnorrSig) {
-    let mut rng = rand::thread_rng();
-    let mpk = Affine::generator().mul(msk.0).into_affine();
+pub fn two_for_one_schnorr_sign<R: Rng>(
+    msk: &SecretKey,
+    m1: &[u8],
+    m2: &[u8],
+    rng: &mut R,
+) -> (SchnorrSig, SchnorrSig) {
+    let mpk = Affine::generator().mul(*msk.0.expose()).into_affine();
     let tweak1 = hash_to_scalar_field(&(mpk, "1".as_bytes()));
     let tweak2 = hash_to_scalar_field(&(mpk, "2".as_bytes()));
-    let sk1 = msk.0 + tweak1;
-    let sk2 = msk.0 + tweak2;
+    let sk1 = *msk.0.expose() + tweak1;
+    let sk2 = *msk.0.expose() + tweak2;
     let pk1 = Affine::generator().mul(sk1).into_affine();
     let pk2 = Affine::generator().mul(sk2).into_affine();
-    let random_scalar = Fr::rand(&mut rng);
+    let random_scalar = Fr::rand(rng);
     let commitment = Affine::generator()
         .mul(random_scalar + tweak1)
         .into_affine();
@@ -121,7 +124,7 @@ pub fn two_for_one_schnorr_sign(msk: &SecretKey, m1: &[u8], m2: &[u8]) -> (Schno
 impl SecretKey {
     // the field `0` of the tuple struct SecretKey is private, but this function allows to create an instance
     pub fn new(x: Fr) -> SecretKey {
-        SecretKey(x)
+        SecretKey(Scalar::new(x))
     }
 
     pub fn flawed_randomized_sign_oracle(&self, m: &[u8]) -> SchnorrSig {
@@ -132,11 +135,12 @@ impl SecretKey {
         flawed_deterministic_schnorr_sign(self, pk, m)
     }
 
-    pub fn two_for_one_schnorr_sign_oracle(
+    pub fn two_for_one_schnorr_sign_oracle<R: Rng>(
         &self,
         m1: &[u8],
         m2: &[u8],
+        rng: &mut R,
     ) -> (SchnorrSig, SchnorrSig) {
-        two_for_one_schnorr_sign(self, m1, m2)
+        two_for_one_schnorr_sign(self, m1, m2, rng)
     }
 }
@@ -0,0 +1,62 @@
+use crate::*;
+
+// Taproot (BIP341): an output commits to an x-only internal key P and a Merkle tree of scripts, with the key
+//   Q = P + tG, t = H_TapTweak(x(P) || merkle_root), without the root when there is no script
+// - key path: the owner of P signs with the tweaked secret key x + t (x negated first if P has an odd y), a
+//   plain BIP340 signature under Q which does not reveal that scripts exist
+// - script path: the spender reveals a script and its control block, which proves that the script is a
+//   leaf of the tree committed in Q:
+//   - the first byte is the leaf version (0xc0 for tapscript, an even number) ORed with the parity of y(Q)
+//   - then the 32 bytes of x(P)
+//   - then the Merkle path, 32 bytes per level: the siblings from the leaf up to the root
+// - a leaf hashes to H_TapLeaf(version || compact size of the script || script), a branch to
+//   H_TapBranch(a || b) with its two children sorted, so that the path needs no left/right bits
+// The verifier recomputes the root from the leaf, then t, and checks that lift_x(P) + tG is Q with this parity
+
+pub const TAPSCRIPT_LEAF_VERSION: u8 = 0xc0;
+
+// the test vectors of BIP341 (bip-0341/wallet-test-vectors.json, scriptPubKey and keyPathSpending)
+// key path only: the internal private key and its tweaked version, the output key
+pub const KEY_PATH_INTERNAL_SECRET_KEY: &str =
+    "6b973d88838f27366ed61c9ad6367663045cb456e28335c109e30717ae0c6baa";
+pub const KEY_PATH_TWEAKED_SECRET_KEY: &str =
+    "2405b971772ad26915c8dcdf10f238753a9b837e5f8e6a86fd7c0cce5b7296d9";
+pub const KEY_PATH_INTERNAL_KEY: &str =
+    "d6889cb081036e0faefa3a35157ad71086b123b2b144b649798b494c300a961d";
+pub const KEY_PATH_OUTPUT_KEY: &str =
+    "53a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda343";
+
+// a single tapscript leaf, which is also the root
+pub const ONE_LEAF_INTERNAL_KEY: &str =
+    "93478e9488f956df2396be2ce6c5cced75f900dfa18e7dabd2428aae78451820";
+pub const ONE_LEAF_SCRIPT: &str =
+    "20b617298552a72ade070667e86ca63b8f5789a9fe8731ef91202a91c9f3459007ac";
+pub const ONE_LEAF_HASH: &str = "c525714a7f49c28aedbbba78c005931a81c234b2f6c99a73e4d06082adc8bf2b";
+pub const ONE_LEAF_OUTPUT_KEY: &str =
+    "e4d810fd50586274face62b8a807eb9719cef49c04177cc6b76a9a4251d5450e";
+
+// two leaves, a tapscript and one of the future leaf version 0xfa
+pub const TWO_LEAVES_INTERNAL_KEY: &str =
+    "ee4fe085983462a184015d1f782d6a5f8b9c2b60130aff050ce221ecf3786592";
+pub const TWO_LEAVES: [(u8, &str); 2] = [
+    (
+        TAPSCRIPT_LEAF_VERSION,
+        "20387671353e273264c495656e27e39ba899ea8fee3bb69fb2a680e22093447d48ac",
+    ),
+    (0xfa, "06424950333431"),
+];
+pub const TWO_LEAVES_MERKLE_ROOT: &str =
+    "6c2dc106ab816b73f9d07e3cd1ef2c8c1256f519748e0813e4edd2405d277bef";
+pub const TWO_LEAVES_OUTPUT_KEY: &str =
+    "712447206d7a5238acc7ff53fbe94a3b64539ad291c7cdbc490b7577e4b17df5";
+
+// the scripts of the vectors are shorter than 253 bytes: their compact size is a single byte
+pub fn tapleaf_hash(leaf_version: u8, script: &[u8]) -> [u8; 32] {
+    assert!(script.len() < 253);
+    tagged_hash("TapLeaf", &[&[leaf_version], &[script.len() as u8], script])
+}
+
+pub fn tapbranch_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    tagged_hash("TapBranch", &[first, second])
+}
@@ -0,0 +1,70 @@
+//! Wrapper for secret scalars (private keys, nonces)
+//!
+//! A private key stored as a bare field element outlives its use: dropping it leaves its limbs in memory,
+//! where a core dump, a swapped-out page or a buffer over-read may find them. `Scalar` wipes its value
+//! when dropped, and its `Debug` implementation does not print it, so that a stray `{:?}` or a failed
+//! `assert_eq!` does not end up in the logs.
+//!
+//! This is a best effort: every copy made before wrapping (arkworks field elements are `Copy`), or while
+//! computing with the exposed value, stays behind in registers or on the stack.
+//!
+//! The crate is `no_std` (with `alloc`) without its default `std` feature, for the targets of hardware
+//! wallets: `cargo build --no-default-features --target thumbv7em-none-eabihf`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem::{size_of, MaybeUninit};
+use core::ptr;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[derive(Clone)]
+pub struct Scalar<F: Zeroize>(F);
+
+impl<F: Zeroize> Scalar<F> {
+    pub fn new(value: F) -> Scalar<F> {
+        Scalar(value)
+    }
+
+    pub fn expose(&self) -> &F {
+        &self.0
+    }
+}
+
+impl<F: Zeroize> Drop for Scalar<F> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<F: Zeroize> ZeroizeOnDrop for Scalar<F> {}
+
+impl<F: Zeroize> fmt::Debug for Scalar<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Scalar(<redacted>)")
+    }
+}
+
+// not constant-time: good enough for the exercises, which compare recovered keys with the real ones
+impl<F: Zeroize + PartialEq> PartialEq for Scalar<F> {
+    fn eq(&self, other: &Scalar<F>) -> bool {
+        self.0 == other.0
+    }
+}
+
+/// Drops `value` in place and returns the bytes left behind in the memory it occupied.
+///
+/// # Safety
+///
+/// `T` must have no padding bytes, which are uninitialized memory.
+pub unsafe fn bytes_after_drop<T>(value: T) -> Vec<u8> {
+    let mut slot = MaybeUninit::new(value);
+    ptr::drop_in_place(slot.as_mut_ptr());
+    let bytes = slot.as_ptr() as *const u8;
+    (0..size_of::<T>())
+        .map(|i| ptr::read_volatile(bytes.add(i)))
+        .collect()
+}
@@ -0,0 +1,153 @@
+//! Per-student challenge instances, and receipts of their answers
+//!
+//! With the same seed, every student of a class gets the same instances, and may share the answers. A challenge
+//! derives its own generator from the identifier of the student, `--student <id>` or `TRAINING_STUDENT`, with
+//! HKDF-SHA256 (RFC 5869): the seed of the run is the salt, the identifier the input key material, and
+//! `<chapter>/<challenge>` the info, so that the instances of two students, or of two challenges, are unrelated.
+//!
+//! A solved challenge prints a receipt: an HMAC of the answer under a key derived alongside the generator.
+//! The instructor, who knows the seed of the class, derives each instance again along with its secret, and checks
+//! the receipts the students hand in: the expected answers are nowhere in the sources, and a receipt is only
+//! valid for the student it was computed for.
+//!
+//! Anyone who knows the seed can derive the instances, and read their secrets from the generator, then compute
+//! valid receipts without solving anything: the seed of the class stays with the instructor. The students get a
+//! [`Handout`] instead, written by the chapter along with the public instance: the key of the receipts, derived
+//! from the key of the challenge like the generator but independent of it, which tells nothing of the secret.
+
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
+use sha2::{Digest, Sha256};
+use std::env;
+
+pub const STUDENT_VARIABLE: &str = "TRAINING_STUDENT";
+pub const STUDENT_OPTION: &str = "--student";
+pub const ANONYMOUS: &str = "anonymous";
+
+/// The student identifier chosen on the command line, in either form, or in the environment
+pub fn student_id() -> Option<String> {
+    crate::split_option(env::args().skip(1), STUDENT_OPTION)
+        .1
+        .or_else(|| env::var(STUDENT_VARIABLE).ok())
+}
+
+/// The student identifier chosen on the command line, in the environment, or `anonymous`
+pub fn student() -> String {
+    student_id().unwrap_or_else(|| ANONYMOUS.to_string())
+}
+
+pub struct Challenge {
+    pub chapter: String,
+    pub name: String,
+    pub student: String,
+    key: [u8; 32],
+}
+
+impl Challenge {
+    /// The challenge `name` of `chapter` for the student and the seed of this run
+    pub fn new(chapter: &str, name: &str) -> Challenge {
+        Challenge::derive(&crate::seed(), &student(), chapter, name)
+    }
+
+    /// The challenge of any student, for the instructor's verifier
+    pub fn derive(seed: &[u8; 32], student: &str, chapter: &str, name: &str) -> Challenge {
+        let info = format!("{}/{}", chapter, name);
+        Challenge {
+            chapter: chapter.to_string(),
+            name: name.to_string(),
+            student: student.to_string(),
+            key: hkdf_sha256(seed, student.as_bytes(), info.as_bytes()),
+        }
+    }
+
+    /// The generator of the instance: the same for every run of the same student and seed
+    pub fn rng(&self) -> ChaChaRng {
+        ChaChaRng::from_seed(hmac_sha256(&self.key, b"instance"))
+    }
+
+    /// What the student needs to hand in receipts, without the generator of the instance
+    pub fn handout(&self) -> Handout {
+        Handout {
+            chapter: self.chapter.clone(),
+            name: self.name.clone(),
+            student: self.student.clone(),
+            receipt_key: hmac_sha256(&self.key, b"receipt"),
+        }
+    }
+
+    /// `<name>:<32 hex digits>`, the first 16 bytes of the HMAC of the answer
+    pub fn receipt(&self, answer: &[u8]) -> String {
+        self.handout().receipt(answer)
+    }
+
+    /// Whether `receipt` is the receipt of `answer`, the answer the instructor derives from the instance
+    pub fn verify(&self, answer: &[u8], receipt: &str) -> bool {
+        let expected = self.receipt(answer);
+        // compares every byte, whatever the first difference
+        expected.len() == receipt.len()
+            && expected
+                .bytes()
+                .zip(receipt.bytes())
+                .fold(0, |acc, (a, b)| acc | (a ^ b))
+                == 0
+    }
+}
+
+/// The challenge of a student as the instructor hands it out, along with the public instance
+pub struct Handout {
+    pub chapter: String,
+    pub name: String,
+    pub student: String,
+    pub receipt_key: [u8; 32],
+}
+
+impl Handout {
+    /// `<name>:<32 hex digits>`, the same receipt as the one of the challenge
+    pub fn receipt(&self, answer: &[u8]) -> String {
+        let tag = hmac_sha256(&self.receipt_key, answer);
+        format!("{}:{}", self.name, to_hex(&tag[..16]))
+    }
+}
+
+/// Lowercase hexadecimal, two digits per byte
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// The bytes of an even number of hexadecimal digits
+pub fn from_hex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) || !text.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&text[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// HMAC-SHA256 (RFC 2104)
+pub fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    let mut block = [0; 64];
+    if key.len() > block.len() {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+    let inner_pad = block.map(|b| b ^ 0x36);
+    let outer_pad = block.map(|b| b ^ 0x5c);
+    let inner = Sha256::new()
+        .chain_update(inner_pad)
+        .chain_update(data)
+        .finalize();
+    Sha256::new()
+        .chain_update(outer_pad)
+        .chain_update(inner)
+        .finalize()
+        .into()
+}
+
+/// HKDF-SHA256 with a single block of output: extract, then expand T(1)
+pub fn hkdf_sha256(salt: &[u8], ikm: &[u8], info: &[u8]) -> [u8; 32] {
+    let prk = hmac_sha256(salt, ikm);
+    hmac_sha256(&prk, &[info, &[1]].concat())
+}
@@ -0,0 +1,68 @@
+//! Seed of the random number generator of the exercises
+//!
+//! Every chapter draws its instances (keys, points, polynomials...) from a `ChaChaRng` seeded with
+//! `DEFAULT_SEED`, so that a run is deterministic and its output can be compared with the published one.
+//! Instructors hand out per-student instances with `cargo run --release -- --seed <any text>`, or with the
+//! `TRAINING_SEED` environment variable: the text is hashed with SHA-256 into the seed.
+//! The command line wins over the environment.
+//!
+//! Per-student challenges derive their instances from a student identifier as well, given with `--student <id>`
+//! or `TRAINING_STUDENT`, see module `challenge`.
+
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
+use sha2::{Digest, Sha256};
+use std::env;
+
+pub mod challenge;
+
+pub const DEFAULT_SEED: [u8; 32] = *b"Advanced cryptography training 1";
+pub const SEED_VARIABLE: &str = "TRAINING_SEED";
+pub const SEED_OPTION: &str = "--seed";
+
+/// The arguments of the program (without its name), `--seed <text>` and `--student <id>` removed, for the
+/// binaries dispatching subcommands
+pub fn args() -> Vec<String> {
+    let args = split_option(env::args().skip(1), SEED_OPTION).0;
+    split_option(args.into_iter(), challenge::STUDENT_OPTION).0
+}
+
+/// The text of the seed chosen on the command line, in either form, or in the environment
+pub fn seed_text() -> Option<String> {
+    split_option(env::args().skip(1), SEED_OPTION)
+        .1
+        .or_else(|| env::var(SEED_VARIABLE).ok())
+}
+
+/// The seed chosen on the command line, in the environment, or the default one
+pub fn seed() -> [u8; 32] {
+    match seed_text() {
+        Some(text) => Sha256::digest(text.as_bytes()).into(),
+        None => DEFAULT_SEED,
+    }
+}
+
+pub fn rng() -> ChaChaRng {
+    ChaChaRng::from_seed(seed())
+}
+
+// accepts `<option> <value>` and `<option>=<value>`, the last one wins
+fn split_option(args: impl Iterator<Item = String>, option: &str) -> (Vec<String>, Option<String>) {
+    let mut rest = vec![];
+    let mut value = None;
+    let mut args = args;
+    let prefix = format!("{}=", option);
+    while let Some(arg) = args.next() {
+        if arg == option {
+            value = Some(
+                args.next()
+                    .unwrap_or_else(|| panic!("{} needs a value", option)),
+            );
+        } else if let Some(text) = arg.strip_prefix(&prefix) {
+            value = Some(text.to_string());
+        } else {
+            rest.push(arg);
+        }
+    }
+    (rest, value)
+}
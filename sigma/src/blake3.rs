@@ -0,0 +1,126 @@
+// BLAKE3, hash mode with a 32-byte output (see the BLAKE3 specification)
+// - the input is split into chunks of 1024 bytes, each chunk into blocks of 64 bytes
+// - the blocks of a chunk are chained with a compression function of 7 rounds, derived from ChaCha
+// - the chaining values of the chunks are the leaves of a binary tree, whose left subtrees are complete:
+//   a parent node compresses the concatenation of the chaining values of its children
+// - the last compression (the only chunk, or the root of the tree) gets the ROOT flag
+
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+const MSG_PERMUTATION: [usize; 16] = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+const CHUNK_START: u32 = 1;
+const CHUNK_END: u32 = 2;
+const PARENT: u32 = 4;
+const ROOT: u32 = 8;
+
+const BLOCK_LEN: usize = 64;
+const CHUNK_LEN: usize = 1024;
+
+fn g(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize, mx: u32, my: u32) {
+    state[a] = state[a].wrapping_add(state[b]).wrapping_add(mx);
+    state[d] = (state[d] ^ state[a]).rotate_right(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_right(12);
+    state[a] = state[a].wrapping_add(state[b]).wrapping_add(my);
+    state[d] = (state[d] ^ state[a]).rotate_right(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_right(7);
+}
+
+fn round(state: &mut [u32; 16], m: &[u32; 16]) {
+    // the columns
+    g(state, 0, 4, 8, 12, m[0], m[1]);
+    g(state, 1, 5, 9, 13, m[2], m[3]);
+    g(state, 2, 6, 10, 14, m[4], m[5]);
+    g(state, 3, 7, 11, 15, m[6], m[7]);
+    // the diagonals
+    g(state, 0, 5, 10, 15, m[8], m[9]);
+    g(state, 1, 6, 11, 12, m[10], m[11]);
+    g(state, 2, 7, 8, 13, m[12], m[13]);
+    g(state, 3, 4, 9, 14, m[14], m[15]);
+}
+
+fn compress(cv: &[u32; 8], block: &[u8], counter: u64, flags: u32) -> [u32; 8] {
+    // the block is padded with zeros, its length is an input of the compression
+    let mut m = [0u32; 16];
+    for (i, word) in block.chunks(4).enumerate() {
+        let mut bytes = [0; 4];
+        bytes[..word.len()].copy_from_slice(word);
+        m[i] = u32::from_le_bytes(bytes);
+    }
+    let mut state = [
+        cv[0],
+        cv[1],
+        cv[2],
+        cv[3],
+        cv[4],
+        cv[5],
+        cv[6],
+        cv[7],
+        IV[0],
+        IV[1],
+        IV[2],
+        IV[3],
+        counter as u32,
+        (counter >> 32) as u32,
+        block.len() as u32,
+        flags,
+    ];
+    for r in 0..7 {
+        round(&mut state, &m);
+        if r < 6 {
+            m = MSG_PERMUTATION.map(|i| m[i]);
+        }
+    }
+    std::array::from_fn(|i| state[i] ^ state[i + 8])
+}
+
+// the chaining value of a chunk of at most 1024 bytes
+fn chunk(input: &[u8], counter: u64, root: bool) -> [u32; 8] {
+    let blocks: Vec<&[u8]> = match input.is_empty() {
+        true => vec![&[]],
+        false => input.chunks(BLOCK_LEN).collect(),
+    };
+    let mut cv = IV;
+    for (i, block) in blocks.iter().enumerate() {
+        let mut flags = 0;
+        if i == 0 {
+            flags |= CHUNK_START;
+        }
+        if i == blocks.len() - 1 {
+            flags |= CHUNK_END | if root { ROOT } else { 0 };
+        }
+        cv = compress(&cv, block, counter, flags);
+    }
+    cv
+}
+
+// the chaining value of a subtree whose first chunk has index `counter`
+fn subtree(input: &[u8], counter: u64, root: bool) -> [u32; 8] {
+    if input.len() <= CHUNK_LEN {
+        return chunk(input, counter, root);
+    }
+    // the left subtree holds the largest power of 2 of chunks leaving some input to the right one
+    let chunks = input.len().div_ceil(CHUNK_LEN);
+    let left_chunks = (chunks / 2).next_power_of_two();
+    let (left, right) = input.split_at(left_chunks * CHUNK_LEN);
+    let left = subtree(left, counter, false);
+    let right = subtree(right, counter + left_chunks as u64, false);
+    let block: Vec<u8> = left
+        .iter()
+        .chain(&right)
+        .flat_map(|word| word.to_le_bytes())
+        .collect();
+    compress(&IV, &block, 0, PARENT | if root { ROOT } else { 0 })
+}
+
+pub fn blake3(input: &[u8]) -> [u8; 32] {
+    let words = subtree(input, 0, true);
+    let mut output = [0; 32];
+    for (bytes, word) in output.chunks_mut(4).zip(words) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+    output
+}
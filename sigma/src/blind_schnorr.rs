@@ -0,0 +1,86 @@
+use crate::*;
+
+// Blind Schnorr signatures: a user gets a signature from the bank on a message the bank never sees,
+// and the bank cannot later link the signature to the signing session (think anonymous e-cash tokens)
+//
+//   Bank(x)                                 User(X, m)
+//   k unif. random, R := kG   --R-->
+//                                           alpha, beta unif. random
+//                                           R' := R + alpha G + beta X
+//                                           c' := H(X, R', m)
+//                             <--c--        c := c' + beta
+//   s := k + cx               --s-->
+//                                           s' := s + alpha
+//                                           signature (R', s') on m
+//
+// The final signature is an ordinary Schnorr signature: s'G = R' + c'X
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SchnorrSignature {
+    pub commitment: Affine, // R'
+    pub response: Fr,       // s'
+}
+
+// what the bank sees during one signing session
+#[derive(Clone, Copy, Debug)]
+pub struct BankView {
+    pub commitment: Affine, // R
+    pub challenge: Fr,      // c
+    pub response: Fr,       // s
+}
+
+pub struct Bank {
+    pub sk: Fr,
+    pub pk: Affine,
+    pub nonce: Option<Fr>,
+}
+
+pub struct BlindUser {
+    pub pk: Affine,
+    pub message: Vec<u8>,
+    // alpha, beta and the blinded commitment R', kept between the challenge and the unblinding
+    pub blinding: Option<(Fr, Fr, Affine)>,
+}
+
+impl Bank {
+    pub fn new(sk: Fr) -> Bank {
+        Bank {
+            sk,
+            pk: Affine::generator().mul(sk).into_affine(),
+            nonce: None,
+        }
+    }
+}
+
+impl BlindUser {
+    pub fn new(pk: Affine, message: &[u8]) -> BlindUser {
+        BlindUser {
+            pk,
+            message: message.to_vec(),
+            blinding: None,
+        }
+    }
+}
+
+// standard Schnorr verification with c = H(X, R, m)
+pub fn schnorr_verify(pk: &Affine, m: &[u8], signature: &SchnorrSignature) -> bool {
+    let challenge = hash_to_scalar_field(&(*pk, signature.commitment, m));
+    Affine::generator().mul(signature.response) == signature.commitment + pk.mul(challenge)
+}
+
+// one signing session between the bank and a user, returns the bank's view and the user's signature
+pub fn run_blind_signing<R: Rng>(
+    bank: &mut Bank,
+    user: &mut BlindUser,
+    rng: &mut R,
+) -> (BankView, SchnorrSignature) {
+    let commitment = bank.commit(rng);
+    let challenge = user.challenge(commitment, rng);
+    let response = bank.respond(challenge);
+    let view = BankView {
+        commitment,
+        challenge,
+        response,
+    };
+    (view, user.unblind(response))
+}
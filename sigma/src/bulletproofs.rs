@@ -0,0 +1,141 @@
+use crate::*;
+use ark_ec::VariableBaseMSM;
+
+// Bulletproofs: range proofs of logarithmic size, without trusted setup
+//
+// The inner-product argument proves knowledge of vectors a, b of length n (a power of 2) such that
+//   P = <a, G> + <b, H> + <a, b> U
+// for independent generators G = (G_1, ..., G_n), H = (H_1, ..., H_n) and U. Each round halves n:
+// the prover sends the cross terms (lo/hi are the first/second halves)
+//   L = <a_lo, G_hi> + <b_hi, H_lo> + <a_lo, b_hi> U
+//   R = <a_hi, G_lo> + <b_lo, H_hi> + <a_hi, b_lo> U
+// gets a challenge x and both parties fold the statement into one of half the size:
+//   G' = x^-1 G_lo + x G_hi, H' = x H_lo + x^-1 H_hi, P' = x^2 L + P + x^-2 R
+// while the prover folds her witness: a' = x a_lo + x^-1 a_hi, b' = x^-1 b_lo + x b_hi
+// After log2(n) rounds, the prover sends the two remaining scalars a and b
+//
+// The range proof shows that V = vG + gamma H commits to v in [0, 2^n) (see the Bulletproofs paper, section 4.2):
+// - a_L are the bits of v, a_R = a_L - 1^n: the prover commits to them with A = alpha H + <a_L, G> + <a_R, H>
+//   and to blinding vectors s_L, s_R with S = rho H + <s_L, G> + <s_R, H>, then gets the challenges y and z
+// - with y^n = (1, y, ..., y^(n-1)) and 2^n = (1, 2, ..., 2^(n-1)), the vector polynomials
+//     l(X) = (a_L - z 1^n) + s_L X
+//     r(X) = y^n o (a_R + z 1^n + s_R X) + z^2 2^n
+//   have an inner product t(X) = t_0 + t_1 X + t_2 X^2 with t_0 = z^2 v + delta(y, z) if (and only if, for random
+//   y and z) a_L are the bits of v, where delta(y, z) = (z - z^2) <1^n, y^n> - z^3 <1^n, 2^n>
+// - the prover commits to T_1 = t_1 G + tau_1 H, T_2 = t_2 G + tau_2 H and gets the challenge x
+// - she sends l = l(x), r = r(x), t = <l, r>, tau_x = tau_2 x^2 + tau_1 x + z^2 gamma and mu = alpha + rho x
+// - the verifier checks t G + tau_x H = z^2 V + delta(y, z) G + x T_1 + x^2 T_2, and that l, r are correct:
+//   with the generators H'_i = y^-(i-1) H_i,
+//     A + x S - z <1^n, G> + <z y^n + z^2 2^n, H'> = mu H + <l, G> + <r, H'>
+// - l and r are not sent: the last equation (plus t U) is proven with the inner-product argument instead
+// G and H of the range proof are the generators of `PedersenParams`, independent of the vectors of generators
+// All the challenges are hashed with the backend B of module `transcript`, chosen by the caller
+
+pub const RANGE_BITS: usize = 32;
+
+pub struct BulletproofsGens {
+    pub g: Vec<Affine>,
+    pub h: Vec<Affine>,
+    pub u: Affine,
+}
+
+impl BulletproofsGens {
+    // nothing up my sleeve: all generators come from `hash_to_curve`
+    pub fn new(n: usize) -> BulletproofsGens {
+        let generator =
+            |label: &str, i: usize| hash_to_curve(format!("{} {}", label, i).as_bytes());
+        BulletproofsGens {
+            g: (0..n).map(|i| generator("bulletproofs G", i)).collect(),
+            h: (0..n).map(|i| generator("bulletproofs H", i)).collect(),
+            u: hash_to_curve(b"bulletproofs U"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct InnerProductProof {
+    pub l: Vec<Affine>,
+    pub r: Vec<Affine>,
+    pub a: Fr,
+    pub b: Fr,
+}
+
+#[derive(Clone, Debug)]
+pub struct Bulletproof {
+    pub a: Affine,
+    pub s: Affine,
+    pub t1: Affine,
+    pub t2: Affine,
+    pub t: Fr,
+    pub tau_x: Fr,
+    pub mu: Fr,
+    pub ipp: InnerProductProof,
+}
+
+impl Bulletproof {
+    // 4 + 2 log2(n) points and 5 scalars
+    pub fn compressed_size(&self) -> usize {
+        let points = 4 + self.ipp.l.len() + self.ipp.r.len();
+        points * self.a.compressed_size() + 5 * self.t.compressed_size()
+    }
+}
+
+pub fn inner_product(a: &[Fr], b: &[Fr]) -> Fr {
+    a.iter().zip(b).map(|(a, b)| *a * b).sum()
+}
+
+// <scalars, points>
+pub fn msm(points: &[Affine], scalars: &[Fr]) -> Projective {
+    Projective::msm(points, scalars).unwrap()
+}
+
+// (1, x, ..., x^(n-1))
+pub fn powers(x: Fr, n: usize) -> Vec<Fr> {
+    let mut powers = Vec::with_capacity(n);
+    let mut power = Fr::from(1);
+    for _ in 0..n {
+        powers.push(power);
+        power *= x;
+    }
+    powers
+}
+
+// H'_i = y^-(i-1) H_i, the generators under which r is committed in the range proof
+pub fn scaled_h(gens: &BulletproofsGens, y: Fr) -> Vec<Affine> {
+    let scaled: Vec<Projective> = gens
+        .h
+        .iter()
+        .zip(powers(y.inverse().unwrap(), gens.h.len()))
+        .map(|(h, y_inv)| h.mul(y_inv))
+        .collect();
+    Projective::normalize_batch(&scaled)
+}
+
+// the challenge of a round of the inner-product argument, chained with the previous one
+// (the first round chains with B::challenge(P), binding the proof to the statement)
+pub fn ipa_challenge<B: HashBackend>(previous: Fr, l: &Affine, r: &Affine) -> Fr {
+    B::challenge(&(previous, *l, *r))
+}
+
+// the challenges y and z, then x, of the range proof
+pub fn range_challenges<B: HashBackend>(commitment: &Affine, a: &Affine, s: &Affine) -> (Fr, Fr) {
+    let y = B::challenge(&(*commitment, *a, *s));
+    let z = B::challenge(&(y, *commitment, *a, *s));
+    (y, z)
+}
+
+pub fn range_challenge_x<B: HashBackend>(z: Fr, t1: &Affine, t2: &Affine) -> Fr {
+    B::challenge(&(z, *t1, *t2))
+}
+
+// the challenge w scaling U in the inner-product argument, so that the prover cannot choose t freely
+pub fn range_challenge_w<B: HashBackend>(x: Fr, t: Fr, tau_x: Fr, mu: Fr) -> Fr {
+    B::challenge(&(x, t, tau_x, mu))
+}
+
+// delta(y, z) = (z - z^2) <1^n, y^n> - z^3 <1^n, 2^n>
+pub fn delta(y: Fr, z: Fr, n: usize) -> Fr {
+    let sum_y: Fr = powers(y, n).iter().sum();
+    let sum_2: Fr = powers(Fr::from(2), n).iter().sum();
+    (z - z * z) * sum_y - z * z * z * sum_2
+}
@@ -0,0 +1,120 @@
+use crate::*;
+use ark_secp256k1::Fq;
+use sha2::{Digest, Sha256};
+
+// The Chaum-Pedersen protocol proves that two points A = xG and B = xH share the same discrete log x
+// (w.r.t. two bases G and H) without revealing it: it runs two Schnorr identifications in parallel
+// with the same nonce and the same challenge
+//
+//   Prover(x)                                   Verifier(G, A, H, B)
+//   r unif. random, R1 := rG, R2 := rH  --R1, R2-->
+//                                       <--c--   c unif. random
+//   s := r + cx                         --s-->
+//                                                sG = R1 + cA and sH = R2 + cB ?
+
+#[derive(Clone, Copy, Debug)]
+pub struct DleqStatement {
+    pub g: Affine,
+    pub a: Affine,
+    pub h: Affine,
+    pub b: Affine,
+}
+
+#[derive(Clone)]
+pub struct DleqProver {
+    pub statement: DleqStatement,
+    pub x: Fr,
+    pub nonce: Option<Fr>,
+}
+
+pub struct DleqVerifier {
+    pub statement: DleqStatement,
+    pub commitments: Option<(Affine, Affine)>,
+    pub challenge: Option<Fr>,
+}
+
+// compact non-interactive proof: the commitments are recomputed by the verifier from (c, s)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DleqProof {
+    pub challenge: Fr, // c = H(G, A, H, B, R1, R2)
+    pub response: Fr,  // s
+}
+
+impl DleqStatement {
+    // the statement for x w.r.t. the bases G and H
+    pub fn new(g: Affine, h: Affine, x: Fr) -> DleqStatement {
+        DleqStatement {
+            g,
+            a: g.mul(x).into_affine(),
+            h,
+            b: h.mul(x).into_affine(),
+        }
+    }
+}
+
+impl DleqProver {
+    pub fn new(statement: DleqStatement, x: Fr) -> DleqProver {
+        DleqProver {
+            statement,
+            x,
+            nonce: None,
+        }
+    }
+}
+
+impl DleqVerifier {
+    pub fn new(statement: DleqStatement) -> DleqVerifier {
+        DleqVerifier {
+            statement,
+            commitments: None,
+            challenge: None,
+        }
+    }
+}
+
+pub fn run_dleq_protocol<R: Rng>(
+    prover: &mut DleqProver,
+    verifier: &mut DleqVerifier,
+    rng: &mut R,
+) -> bool {
+    let commitments = prover.commit(rng);
+    let challenge = verifier.challenge(commitments, rng);
+    let response = prover.respond(challenge);
+    verifier.verify(response)
+}
+
+// "try-and-increment" hash to curve: hash the message with a counter until we hit a valid x-coordinate
+// nobody knows the discrete log of the output w.r.t. G (but this is not constant-time, see RFC 9380 for better)
+pub fn hash_to_curve(m: &[u8]) -> Affine {
+    for counter in 0u32.. {
+        let digest = Sha256::new()
+            .chain_update(b"toy-hash-to-curve")
+            .chain_update(m)
+            .chain_update(counter.to_be_bytes())
+            .finalize();
+        let x = Fq::from_be_bytes_mod_order(&digest);
+        if let Some(point) = Affine::get_point_from_x_unchecked(x, false) {
+            return point;
+        }
+    }
+    unreachable!()
+}
+
+// the pseudorandom output of the VRF is derived from the point Gamma = xH(m)
+pub fn proof_to_hash(gamma: &Affine) -> [u8; 32] {
+    let mut bytes = Vec::new();
+    gamma.serialize_compressed(&mut bytes).unwrap();
+    Sha256::new()
+        .chain_update(b"toy-vrf-output")
+        .chain_update(bytes)
+        .finalize()
+        .into()
+}
+
+// output of a toy VRF: beta = H(Gamma) with Gamma = xH(m), and a DLEQ proof that log_G(X) = log_H(m)(Gamma)
+#[derive(Clone, Copy, Debug)]
+pub struct VrfOutput {
+    pub beta: [u8; 32],
+    pub gamma: Affine,
+    pub proof: DleqProof,
+}
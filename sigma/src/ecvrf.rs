@@ -0,0 +1,64 @@
+use crate::*;
+use sha2::{Digest, Sha256};
+
+// An elliptic-curve VRF in the style of RFC 9381 (ECVRF)
+// - H := hash_to_curve(Y, alpha), bound to the public key Y = xG
+// - Gamma := xH
+// - a DLEQ proof (c, s) that log_G(Y) = log_H(Gamma), with a *deterministic* nonce k = H(x, H)
+//   and a challenge truncated to 128 bits, c = H(Y, H, Gamma, kG, kH)
+// - beta := proof_to_hash(Gamma) is the pseudorandom output
+// Since Gamma is uniquely determined by (Y, alpha), so is beta: the prover cannot choose among several outputs
+
+pub const SUITE: &[u8] = b"toy-ECVRF-SECP256K1-SHA256-TAI";
+pub const CHALLENGE_BYTES: usize = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EcvrfProof {
+    pub gamma: Affine,
+    pub challenge: Fr, // c < 2^128
+    pub response: Fr,  // s
+}
+
+fn encode_point(point: &Affine) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    point.serialize_compressed(&mut bytes).unwrap();
+    bytes
+}
+
+pub fn ecvrf_hash_to_curve(pk: &Affine, alpha: &[u8]) -> Affine {
+    hash_to_curve(&[SUITE, &encode_point(pk), alpha].concat())
+}
+
+// k = H(x, H) reduced mod n, like RFC 6979 the nonce never depends on a random number generator
+pub fn ecvrf_nonce(sk: &Fr, h: &Affine) -> Fr {
+    let digest = Sha256::new()
+        .chain_update(SUITE)
+        .chain_update(sk.into_bigint().to_bytes_be())
+        .chain_update(encode_point(h))
+        .finalize();
+    Fr::from_be_bytes_mod_order(&digest)
+}
+
+// c = H(Y, H, Gamma, U, V) truncated to 128 bits
+pub fn ecvrf_challenge(points: [&Affine; 5]) -> Fr {
+    let mut hasher = Sha256::new().chain_update(SUITE).chain_update([0x02]);
+    for point in points {
+        hasher.update(encode_point(point));
+    }
+    Fr::from_be_bytes_mod_order(&hasher.finalize()[..CHALLENGE_BYTES])
+}
+
+pub fn ecvrf_proof_to_hash(proof: &EcvrfProof) -> [u8; 32] {
+    Sha256::new()
+        .chain_update(SUITE)
+        .chain_update([0x03])
+        .chain_update(encode_point(&proof.gamma))
+        .chain_update([0x00])
+        .finalize()
+        .into()
+}
+
+// leader election: a participant is elected for a slot when her VRF output on the slot number is below a threshold
+pub fn is_elected(beta: &[u8; 32]) -> bool {
+    beta[0] < 16
+}
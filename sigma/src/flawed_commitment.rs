@@ -0,0 +1,29 @@
+use crate::*;
+
+// A Pedersen commitment C = vG + rH is hiding whatever H is, but only binding as long as nobody knows log_G(H):
+// with H = sG, C = (v + rs)G, and any v' opens it with r' = r + (v - v') / s
+// The setup below derives H "transparently" from a public seed... by hashing the seed to a scalar s and
+// multiplying G by it. The seed is public, so s is too: the commitments of this setup bind nobody
+// The fix hashes the seed straight to a point, without going through a scalar (`hash_to_curve`)
+
+// the seed of the flawed setup, published so that anyone can recompute H
+pub const FLAWED_SEED: &[u8] = b"nothing up my sleeve";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Opening {
+    pub value: Fr,
+    pub blinding: Fr,
+}
+
+// H = sG with s = hash_to_scalar_field(FLAWED_SEED)
+pub fn flawed_setup() -> PedersenParams {
+    let s = hash_to_scalar_field(&FLAWED_SEED.to_vec());
+    PedersenParams {
+        g: Affine::generator(),
+        h: Affine::generator().mul(s).into_affine(),
+    }
+}
+
+pub fn verify_opening(params: &PedersenParams, commitment: &Affine, opening: &Opening) -> bool {
+    params.commit(opening.value, opening.blinding) == *commitment
+}
@@ -0,0 +1,135 @@
+use runner::lang::{Catalog, Lang, Statements};
+
+pub const CATALOG: Catalog = Catalog {
+    lang: Lang::Fr,
+    statements: Statements::Table(&[
+        ("Q1", "implémenter les trois coups du protocole\n- `Prover::commit` tire le nonce r, le retient et envoie R = rG\n- `Verifier::challenge` retient R et envoie un défi c uniformément aléatoire\n- `Prover::respond` répond s = r + cx (un nonce ne doit jamais servir deux fois !)\n- `Verifier::verify` accepte si et seulement si sG = R + cX\nComplétude : un prouveur honnête convainc toujours un vérifieur honnête"),
+        ("Q2", "robustesse spéciale\nÀ partir de deux transcriptions acceptantes (R, c, s) et (R, c', s') avec le même engagement et c != c'\non peut calculer la clé secrète ; c'est pourquoi un prouveur capable de répondre à deux défis « connaît » x\nÉcrire une fonction `extract` qui retrouve la clé secrète à partir de deux telles transcriptions\nLe harnais rembobine le prouveur (il le clone juste après l'engagement) pour obtenir les deux transcriptions"),
+        ("Q3", "divulgation nulle de connaissance face à un vérifieur honnête\nÉcrire une fonction `simulate` qui, à partir de la seule clé publique, produit des transcriptions acceptantes\ndistribuées exactement comme celles des exécutions honnêtes"),
+        ("Q4", "appliquer la transformation de Fiat-Shamir pour rendre le protocole non interactif\nLe défi est remplacé par c = H(G, X, R) calculé avec `hash_to_scalar_field` (SHA-256)\nÉcrire `fs_prove` et `fs_verify`"),
+        ("Q5", "le simulateur de Q3 produit des transcriptions interactives acceptantes, mais pas des preuves non interactives valides\nPourquoi la simulation à divulgation nulle ne se transpose-t-elle pas telle quelle au cadre non interactif ?\n(indice : dans le modèle de l'oracle aléatoire, quel pouvoir supplémentaire est donné au simulateur ?)"),
+        ("Q6", "implémenter les trois coups du protocole interactif\n`DleqProver::commit`, `DleqVerifier::challenge`, `DleqProver::respond` et `DleqVerifier::verify`"),
+        ("Q7", "le rendre non interactif avec Fiat-Shamir, le défi étant c = H(G, A, H, B, R1, R2)\n`DleqProof` ne contient que le défi et la réponse : le vérifieur recalcule R1 et R2\nà partir des équations de vérification et vérifie que leur haché redonne le défi\nÉcrire `dleq_prove` et `dleq_verify`"),
+        ("Q8", "une fonction aléatoire vérifiable jouet\nLa sortie VRF du message m sous la clé x est beta = H(Gamma) avec Gamma = xH(m) (`hash_to_curve`, `proof_to_hash`)\nQuiconque peut vérifier que beta est *la* sortie pour (X, m) grâce à une preuve DLEQ que log_G(X) = log_H(m)(Gamma)\nÉcrire `vrf_evaluate` et `vrf_verify`, cette dernière renvoyant beta quand la preuve est valide"),
+        ("Q9", "implémenter le prouveur et le vérifieur d'un tour\n- `ColoringProver::commit` applique une permutation aléatoire fraîche aux 3 couleurs et s'engage sur chaque sommet\n- `ColoringVerifier::challenge` stocke les engagements et choisit une arête aléatoire du graphe\n- `ColoringProver::respond` ouvre les engagements des deux extrémités\n- `ColoringVerifier::verify` vérifie les ouvertures contre les engagements et que les deux couleurs sont valides et différentes"),
+        ("Q10", "combien de tours faut-il pour qu'un prouveur tricheur réussisse avec une probabilité d'au plus 2^-40 ?\nLes tours pourraient-ils être exécutés en parallèle ? Fiat-Shamir pourrait-il rendre cette preuve non interactive ?"),
+        ("Q11", "écrire `ring_sign` qui signe `m` en tant que membre à la position `signer` de l'anneau\nCommencer l'anneau des défis à la position qui suit le signataire avec un nonce frais,\nfaire le tour de l'anneau avec des réponses aléatoires et le refermer avec la clé secrète"),
+        ("Q12", "écrire `ring_verify` qui recalcule l'anneau des défis à partir de c_0"),
+        ("Q13", "quelle est la taille d'une signature d'anneau en fonction de la taille de l'anneau ?\nPourquoi une cryptomonnaie utilisant des signatures d'anneau aurait-elle besoin d'« images de clé » (signatures d'anneau liables) ?"),
+        ("Q14", "implémenter les deux parties du protocole de Schnorr aveugle\n- `Bank::commit` et `Bank::respond` sont les coups du prouveur d'identification de Schnorr\n- `BlindUser::challenge` aveugle l'engagement et le défi avec alpha et beta\n- `BlindUser::unblind` vérifie la réponse de la banque et renvoie la signature (R', s')"),
+        ("Q15", "non-liabilité ; écrire `is_consistent` qui décide si une vue de la banque et une signature\n*pourraient* provenir de la même session, c'est-à-dire s'il existe des facteurs d'aveuglement alpha et beta\nenvoyant l'une sur l'autre\nComme chaque vue est cohérente avec chaque signature, la banque ne peut pas dire quelle session a produit quelle signature"),
+        ("Q16", "la banque signe à l'aveugle tout ce qu'on lui donne. Pourquoi est-ce un problème pour un système de monnaie électronique,\net comment les systèmes réels limitent-ils les dégâts ?\nSe renseigner sur l'attaque ROS : pourquoi est-il dangereux pour la banque d'exécuter de nombreuses sessions en parallèle ?"),
+        ("Q17", "écrire `prove_asset` qui s'engage sur le solde d'une adresse (ou sur 0 si la plateforme\nne la possède pas) et le prouve avec une preuve OU sur les branches données par `asset_branches`"),
+        ("Q18", "écrire `prove_solvency` qui renvoie la preuve complète et les facteurs d'aveuglement des engagements des clients\n(pour que chaque client puisse vérifier qu'il est inclus), ou `None` si la plateforme n'est pas solvable"),
+        ("Q19", "écrire `verify_solvency`"),
+        ("Q20", "que révèle la preuve ?\nComment deux plateformes pourraient-elles s'entendre pour prouver leur solvabilité avec les mêmes bitcoins ?"),
+        ("Q21", "écrire `ecvrf_prove` qui renvoie (Gamma, c, s) avec le nonce déterministe de `ecvrf_nonce`"),
+        ("Q22", "écrire `ecvrf_verify` qui vérifie la preuve et renvoie beta = `ecvrf_proof_to_hash(proof)`"),
+        ("Q23", "écrire `grind_gamma` qui renvoie le premier Gamma' = Gamma + iG (i = 1, 2, ...) donnant une sortie élue,\navec le (c, s) honnête : un vérifieur oubliant la vérification DLEQ l'accepterait, pas `ecvrf_verify`"),
+        ("Q24", "pourquoi le nonce doit-il dépendre de H et pas seulement de la clé secrète ?\nPourquoi est-il sans danger de tronquer le défi à 128 bits ?"),
+        ("Q25", "écrire `pvss_deal` et `pvss_verify_transcript`"),
+        ("Q26", "écrire `pvss_decrypt_share`, `pvss_verify_share` et `pvss_reconstruct`"),
+        ("Q27", "comparé au VSS de Feldman, qui peut vérifier la distribution ici ? Pourquoi révéler s lui-même\n(au lieu de sG) casserait-il le secret des parts chiffrées ?"),
+        ("Q28", "écrire `ipa_prove` qui prouve P = <a, G> + <b, H> + <a, b> U, en repliant l'énoncé en log2(n) tours"),
+        ("Q29", "écrire `ipa_verify` qui replie les générateurs et P avec les défis, puis vérifie le dernier tour\nLe haché des défis est le backend B du module `transcript` : `B::challenge(&P)`, `ipa_challenge::<B>`"),
+        ("Q30", "écrire `bulletproof_prove` qui prouve que V = vG + gamma H s'engage sur v dans [0, 2^32),\nou renvoie None si v est hors de l'intervalle"),
+        ("Q31", "écrire `bulletproof_verify`"),
+        ("Q32", "le vérifieur de Q29 replie les générateurs tour après tour : comment peut-il calculer les G et H finaux\navec une seule multiplication multi-scalaire de taille 2n ?\nLa vérification est-elle encore linéaire en n ? Comment les Bulletproofs agrègent-ils les preuves d'intervalle de m valeurs ?"),
+        ("Q33", "écrire `prover_time` qui renvoie le temps moyen de `bulletproof_prove` avec le backend B"),
+        ("Q34", "quelle part du temps du prouveur part dans le hachage ? Pourquoi BLAKE3 n'est-il pas plus rapide que SHA-256 sur des\ntranscriptions aussi courtes ? Poseidon est de loin le backend le plus lent ici : pourquoi un protocole le choisirait-il quand même ?"),
+        ("Q35", "sa mise en place dérive H d'une graine publique, mais en passant par un scalaire. Écrire `forge_opening` qui ouvre un\nengagement de la mise en place défaillante sur n'importe quelle autre valeur"),
+        ("Q36", "corriger la mise en place : écrire `pedersen_setup` qui dérive H d'un domaine avec `hash_to_curve`"),
+        ("Q37", "le concepteur de `hash_to_curve` pourrait-il connaître log_G(H) ? Que devrait vérifier un vérifieur avant de faire confiance\nà une mise en place qui publie H et sa graine ? La mise en place défaillante est toujours masquante : pourquoi ?"),
+        ("Q38", "écrire `run_over_channel`, qui joue les trois coups avec le prouveur et le vérifieur communiquant chacun par son\nextrémité d'un canal, et renvoie le verdict du vérifieur : un message manquant ou inattendu est un rejet"),
+        ("Q39", "pourquoi le rejeu d'une session entière échoue-t-il, et qu'est-ce qui le ferait réussir ? Le vérifieur accepte le relais :\nl'homme du milieu n'a rien prouvé, mais une porte qui s'ouvre pour quiconque relaie à distance le badge de son\npropriétaire reste ouverte. Que mesurent les protocoles de distance bounding contre cette attaque par relais ?"),
+    ]),
+    hints: &[
+        (
+            "Q2",
+            &[
+                "Les deux transcriptions partagent le nonce r derrière R",
+                "s = r + c x et s' = r + c' x",
+                "x = (s - s') / (c - c')",
+            ],
+        ),
+        (
+            "Q3",
+            &[
+                "Le simulateur ne connaît pas x, mais il peut choisir l'ordre des coups",
+                "Choisir d'abord le défi et la réponse",
+                "Tirer c et s au hasard, puis R = sG - cX rend la transcription acceptante",
+            ],
+        ),
+        (
+            "Q16",
+            &[
+                "La banque ne voit pas ce qu'elle signe, elle ne peut donc pas distinguer une pièce de 1 euro d'une pièce de 1000",
+                "La signature doit dire la valeur de la pièce sans que la banque voie le message",
+                "Une clé par valeur faciale : la clé qui a signé la pièce donne sa valeur",
+            ],
+        ),
+        (
+            "Q20",
+            &[
+                "Regarder ce que la preuve contient en plus des engagements",
+                "Chaque client a un engagement, chaque adresse de l'ensemble d'anonymat une preuve OU",
+                "La preuve révèle le nombre de clients et la taille de l'ensemble d'anonymat",
+            ],
+        ),
+        (
+            "Q27",
+            &[
+                "Quiconque peut vérifier les preuves NIZK de la distribution : c'est ce qui la rend publiquement vérifiable",
+                "E_i = p(i) Y_i et p(i) H sont publics : cacher p(i) est un problème de Diffie-Hellman décisionnel",
+                "Quelle hypothèse fait que E_i = p(i) Y_i cache p(i) quand p(i) H est public ? Révéler s = p(0) change ce qui est caché",
+            ],
+        ),
+        (
+            "Q32",
+            &[
+                "Après tous les tours, le G final est une combinaison des G_i initiaux",
+                "Quel coefficient G_i reçoit-il ? Chaque tour le multiplie par u_j ou u_j^-1",
+                "Le coefficient de G_i est le produit des u_j^(+-1) avec les signes donnés par les bits de i",
+            ],
+        ),
+        (
+            "Q33",
+            &[
+                "Chronométrer plusieurs preuves et diviser, une seule est trop bruitée",
+                "std::time::Instant::now() avant la boucle, start.elapsed() / runs après",
+                "Envelopper chaque preuve dans std::hint::black_box pour que le compilateur la garde, et appeler bulletproof_prove::<B, R>",
+            ],
+        ),
+        (
+            "Q34",
+            &[
+                "Compter les défis d'une preuve et comparer avec les multiplications multi-scalaires",
+                "Les transcriptions font quelques points : l'arbre et les voies SIMD de BLAKE3 ne paient que sur de longues entrées",
+                "Un circuit vérifiant la preuve recalcule ses défis : Poseidon y coûte quelques centaines de contraintes, SHA-256 des dizaines de milliers",
+            ],
+        ),
+        (
+            "Q35",
+            &[
+                "`flawed_setup` publie tout ce qu'il faut pour recalculer s = log_G(H)",
+                "vG + rH = (v + rs)G : l'engagement ne fixe que v + rs",
+                "Garder v + rs constant : r' = r + (v - v') / s",
+            ],
+        ),
+        (
+            "Q36",
+            &[
+                "G reste le générateur, seul H change",
+                "`hash_to_curve` renvoie un point sans jamais calculer un multiple scalaire de G",
+            ],
+        ),
+        (
+            "Q38",
+            &[
+                "Chaque partie ne parle que par son extrémité : le prouveur envoie et reçoit sur `prover_end`, le vérifieur sur `verifier_end`",
+                "L'ordre des coups est le protocole : engagement, défi, réponse",
+                "`let Some(IdentificationMessage::Commitment(a)) = verifier_end.receive() else { return false };`",
+            ],
+        ),
+    ],
+};
@@ -0,0 +1,97 @@
+use runner::hints::Hints;
+use runner::lang::Catalog;
+
+pub const CATALOGS: &[Catalog] = &[
+    Catalog::english(HINTS, include_str!("main.rs")),
+    crate::fr::CATALOG,
+];
+
+pub const HINTS: Hints = &[
+    (
+        "Q2",
+        &[
+            "Both transcripts share the nonce r behind R",
+            "s = r + c x and s' = r + c' x",
+            "x = (s - s') / (c - c')",
+        ],
+    ),
+    (
+        "Q3",
+        &[
+            "The simulator does not know x, but it may choose the order of the moves",
+            "Choose the challenge and the response first",
+            "Draw c and s at random, then R = sG - cX makes the transcript accepting",
+        ],
+    ),
+    (
+        "Q16",
+        &[
+            "The bank cannot see what it signs, so it cannot tell a coin of 1 euro from a coin of 1000",
+            "The signature must say the value of the coin without the bank seeing the message",
+            "One key per denomination: the key which signed the coin gives its value",
+        ],
+    ),
+    (
+        "Q20",
+        &[
+            "Look at what the proof contains besides the commitments",
+            "Each customer has a commitment, each address of the anonymity set an OR-proof",
+            "The proof leaks the number of customers and the size of the anonymity set",
+        ],
+    ),
+    (
+        "Q27",
+        &[
+            "Anyone can check the NIZK proofs of the dealing: this is what makes it publicly verifiable",
+            "E_i = p(i) Y_i and p(i) H are public: hiding p(i) is a decisional Diffie-Hellman problem",
+            "Which assumption makes E_i = p(i) Y_i hide p(i) when p(i) H is public? Revealing s = p(0) changes what is being hidden",
+        ],
+    ),
+    (
+        "Q32",
+        &[
+            "After all the rounds, the final G is a combination of the initial G_i",
+            "Which coefficient does G_i get? Each round multiplies it by u_j or u_j^-1",
+            "The coefficient of G_i is the product of u_j^(+-1) with the signs given by the bits of i",
+        ],
+    ),
+    (
+        "Q33",
+        &[
+            "Time several proofs and divide, a single one is too noisy",
+            "std::time::Instant::now() before the loop, start.elapsed() / runs after",
+            "Wrap each proof in std::hint::black_box so that the compiler keeps it, and call bulletproof_prove::<B, R>",
+        ],
+    ),
+    (
+        "Q34",
+        &[
+            "Count the challenges of a proof and compare with the multi-scalar multiplications",
+            "The transcripts are a few points: the tree and SIMD lanes of BLAKE3 only pay off on long inputs",
+            "A circuit verifying the proof recomputes its challenges: Poseidon costs a few hundred constraints there, SHA-256 tens of thousands",
+        ],
+    ),
+    (
+        "Q35",
+        &[
+            "`flawed_setup` publishes everything needed to recompute s = log_G(H)",
+            "vG + rH = (v + rs)G: the commitment only fixes v + rs",
+            "Keep v + rs constant: r' = r + (v - v') / s",
+        ],
+    ),
+    (
+        "Q36",
+        &[
+            "G stays the generator, only H changes",
+            "`hash_to_curve` returns a point without ever computing a scalar multiple of G",
+        ],
+    ),
+    (
+        "Q38",
+        &[
+            "Each party only talks through its own end: the prover sends and receives on `prover_end`, the verifier on `verifier_end`",
+            "The order of the moves is the protocol: commitment, challenge, response",
+            "`let Some(IdentificationMessage::Commitment(a)) = verifier_end.receive() else { return false };`",
+        ],
+    ),
+];
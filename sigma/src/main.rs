@@ -0,0 +1,1767 @@
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_secp256k1::{Affine, Fr, Projective};
+use ark_serialize::{CanonicalSerialize, CanonicalSerializeHashExt};
+use ark_std::{ops::Mul, UniformRand};
+use blind_schnorr::*;
+use bulletproofs::*;
+use dleq::*;
+use ecvrf::*;
+use flawed_commitment::*;
+use pvss::*;
+use rand::{seq::SliceRandom, Rng};
+use range_proof::*;
+use ring_signature::*;
+use runner::Runner;
+use schnorr_identification::*;
+use solvency::*;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use three_coloring::*;
+use training_common::protocol_sim::{duplex, Channel, Dropping, Replaying, Tampering};
+use transcript::*;
+
+mod blake3;
+mod blind_schnorr;
+mod bulletproofs;
+mod dleq;
+mod ecvrf;
+mod flawed_commitment;
+mod fr;
+mod hints;
+mod poseidon;
+mod pvss;
+mod range_proof;
+mod ring_signature;
+mod schnorr_identification;
+mod solvency;
+mod three_coloring;
+mod transcript;
+
+fn main() {
+    let runner = Runner::start(env!("CARGO_PKG_NAME"), hints::CATALOGS);
+    let mut rng = seed::rng();
+
+    // Take a look at module `schnorr_identification`
+    // It describes the interactive Schnorr identification protocol and defines a `Prover` and a `Verifier`
+    // which exchange messages through methods; the harness `run_protocol` plays the three moves between them
+
+    // Q1: implement the three moves of the protocol
+    // - `Prover::commit` samples the nonce r, remembers it and sends R = rG
+    // - `Verifier::challenge` remembers R and sends a uniformly random challenge c
+    // - `Prover::respond` answers s = r + cx (a nonce must never be used twice!)
+    // - `Verifier::verify` accepts iff sG = R + cX
+    // Completeness: an honest prover always convinces an honest verifier
+    runner.question("Q1");
+    let (sk, pk) = keygen(&mut rng);
+    for _ in 0..20 {
+        let mut prover = Prover::new(sk);
+        let mut verifier = Verifier::new(pk);
+        let (transcript, accepted) = run_protocol(&mut prover, &mut verifier, &mut rng);
+        assert!(accepted);
+        assert!(is_accepting(&pk, &transcript));
+    }
+
+    // A prover who does not know x should fail, whatever she sends
+    let mut impostor = Prover::new(Fr::rand(&mut rng));
+    let mut verifier = Verifier::new(pk);
+    let (_, accepted) = run_protocol(&mut impostor, &mut verifier, &mut rng);
+    assert!(!accepted);
+
+    // Q2: special soundness
+    // From two accepting transcripts (R, c, s) and (R, c', s') with the same commitment and c != c'
+    // one can compute the secret key; this is why a prover able to answer two challenges "knows" x
+    // Write a function `extract` that recovers the secret key from two such transcripts
+    // The harness rewinds the prover (it clones her right after the commitment) to get both transcripts
+    runner.question("Q2");
+    let mut prover = Prover::new(sk);
+    let commitment = prover.commit(&mut rng);
+    let mut rewound_prover = prover.clone();
+    let c1 = Fr::rand(&mut rng);
+    let c2 = Fr::rand(&mut rng);
+    let t1 = Transcript {
+        commitment,
+        challenge: c1,
+        response: prover.respond(c1),
+    };
+    let t2 = Transcript {
+        commitment,
+        challenge: c2,
+        response: rewound_prover.respond(c2),
+    };
+    assert!(is_accepting(&pk, &t1) && is_accepting(&pk, &t2));
+    let extracted = extract(&t1, &t2);
+    assert_eq!(Affine::generator().mul(extracted).into_affine(), pk);
+
+    // Q3: honest-verifier zero-knowledge
+    // Write a function `simulate` which, given only the public key, outputs accepting transcripts
+    // distributed exactly like those of honest executions
+    runner.question("Q3");
+    for _ in 0..20 {
+        let transcript = simulate(&pk, &mut rng);
+        assert!(is_accepting(&pk, &transcript));
+    }
+    // The simulated transcripts must not be stuck on a few values
+    let t1 = simulate(&pk, &mut rng);
+    let t2 = simulate(&pk, &mut rng);
+    assert_ne!(t1.commitment, t2.commitment);
+    assert_ne!(t1.challenge, t2.challenge);
+
+    // Q4: apply the Fiat-Shamir transform to make the protocol non-interactive
+    // The challenge is replaced by c = H(G, X, R) computed with `hash_to_scalar_field` (SHA-256)
+    // Write `fs_prove` and `fs_verify`
+    runner.question("Q4");
+    let proof = fs_prove(sk, &mut rng);
+    assert!(fs_verify(&pk, &proof));
+    // a proof is bound to the public key and cannot be tampered with
+    let (_, other_pk) = keygen(&mut rng);
+    assert!(!fs_verify(&other_pk, &proof));
+    let mut tampered = proof;
+    tampered.response += Fr::from(1);
+    assert!(!fs_verify(&pk, &tampered));
+
+    // Q5: the simulator of Q3 produces accepting interactive transcripts, but not valid non-interactive proofs
+    // Why doesn't the zero-knowledge simulation carry over to the non-interactive setting as is?
+    // (hint: in the random oracle model, what extra power is given to the simulator?)
+    runner.question("Q5");
+    let simulated = simulate(&pk, &mut rng);
+    let simulated_proof = SchnorrProof {
+        commitment: simulated.commitment,
+        response: simulated.response,
+    };
+    assert!(!fs_verify(&pk, &simulated_proof));
+
+    // Now take a look at module `dleq`
+    // The Chaum-Pedersen protocol proves that log_G(A) = log_H(B), it is the Schnorr protocol run twice in parallel
+    // It is used in VRFs, verifiable decryption, verifiable secret sharing, ...
+    let h = hash_to_curve(b"another generator");
+    let x = Fr::rand(&mut rng);
+    let statement = DleqStatement::new(Affine::generator(), h, x);
+
+    // Q6: implement the three moves of the interactive protocol
+    // `DleqProver::commit`, `DleqVerifier::challenge`, `DleqProver::respond` and `DleqVerifier::verify`
+    runner.question("Q6");
+    for _ in 0..20 {
+        let mut prover = DleqProver::new(statement, x);
+        let mut verifier = DleqVerifier::new(statement);
+        assert!(run_dleq_protocol(&mut prover, &mut verifier, &mut rng));
+    }
+    // A prover cannot convince the verifier when the two discrete logs differ,
+    // even if she knows both of them
+    let y = Fr::rand(&mut rng);
+    let false_statement = DleqStatement {
+        b: h.mul(y).into_affine(),
+        ..statement
+    };
+    let mut prover = DleqProver::new(false_statement, x);
+    let mut verifier = DleqVerifier::new(false_statement);
+    assert!(!run_dleq_protocol(&mut prover, &mut verifier, &mut rng));
+
+    // Q7: make it non-interactive with Fiat-Shamir, the challenge being c = H(G, A, H, B, R1, R2)
+    // `DleqProof` only contains the challenge and the response: the verifier recomputes R1 and R2
+    // from the verification equations and checks that hashing them gives back the challenge
+    // Write `dleq_prove` and `dleq_verify`
+    runner.question("Q7");
+    let proof = dleq_prove(&statement, x, &mut rng);
+    assert!(dleq_verify(&statement, &proof));
+    assert!(!dleq_verify(&false_statement, &proof));
+    let forged = dleq_prove(&false_statement, x, &mut rng);
+    assert!(!dleq_verify(&false_statement, &forged));
+
+    // Q8: a toy verifiable random function
+    // The VRF output of message m under key x is beta = H(Gamma) with Gamma = xH(m) (`hash_to_curve`, `proof_to_hash`)
+    // Anyone can check that beta is *the* output for (X, m) thanks to a DLEQ proof that log_G(X) = log_H(m)(Gamma)
+    // Write `vrf_evaluate` and `vrf_verify`, the latter returning beta when the proof is valid
+    runner.question("Q8");
+    let m = "Crypto training exercises!".as_bytes();
+    let output = vrf_evaluate(sk, m, &mut rng);
+    assert_eq!(vrf_verify(&pk, m, &output), Some(output.beta));
+    // the output is deterministic even though the proofs are randomized
+    let again = vrf_evaluate(sk, m, &mut rng);
+    assert_eq!(again.beta, output.beta);
+    // but it is bound to the key and to the message
+    assert_eq!(vrf_verify(&other_pk, m, &output), None);
+    assert_eq!(vrf_verify(&pk, b"another message", &output), None);
+    let (other_sk, _) = keygen(&mut rng);
+    let cheat = VrfOutput {
+        gamma: hash_to_curve(m).mul(other_sk).into_affine(),
+        ..output
+    };
+    assert_eq!(vrf_verify(&pk, m, &cheat), None);
+
+    // Now take a look at module `three_coloring`
+    // Sigma protocols are not limited to algebraic statements: here is a zero-knowledge proof
+    // for an NP-complete problem, built only from hash commitments and cut-and-choose
+    let (graph, coloring) = random_colorable_graph(30, 60, &mut rng);
+    let edges = graph.edges.clone();
+    let rounds = 20 * edges.len();
+
+    // Q9: implement the prover and verifier of one round
+    // - `ColoringProver::commit` applies a fresh random permutation to the 3 colors and commits to every vertex
+    // - `ColoringVerifier::challenge` stores the commitments and picks a random edge of the graph
+    // - `ColoringProver::respond` opens the commitments of the two endpoints
+    // - `ColoringVerifier::verify` checks the openings against the commitments and that the two colors are valid and different
+    runner.question("Q9");
+    let mut prover = ColoringProver::new(coloring.clone());
+    let mut verifier = ColoringVerifier::new(graph);
+    let mut color_pairs = [[0; 3]; 3];
+    for _ in 0..rounds {
+        let ((c1, c2), accepted) = run_coloring_round(&mut prover, &mut verifier, &mut rng);
+        assert!(accepted);
+        color_pairs[c1 as usize][c2 as usize] += 1;
+    }
+
+    // Zero-knowledge: thanks to the permutation, the two colors revealed in a round are
+    // a uniformly random pair of distinct colors, whatever the coloring is
+    for (c1, row) in color_pairs.iter().enumerate() {
+        for (c2, count) in row.iter().enumerate() {
+            if c1 == c2 {
+                assert_eq!(*count, 0);
+            } else {
+                assert!(*count > rounds / 12, "colors are not uniformly permuted");
+            }
+        }
+    }
+
+    // The verifier must reject openings which do not match the commitments
+    let commitments = prover.commit(&mut rng);
+    let edge = verifier.challenge(commitments, &mut rng);
+    let (mut o1, o2) = prover.respond(edge);
+    o1.color = (o1.color + 1) % 3;
+    if o1.color == o2.color {
+        o1.color = (o1.color + 1) % 3;
+    }
+    assert!(!verifier.verify((o1, o2)));
+
+    // Soundness: a prover holding an invalid coloring gets caught in one of the rounds
+    let mut bad_coloring = coloring;
+    let (u, v) = edges[0];
+    bad_coloring[u] = bad_coloring[v];
+    let mut cheater = ColoringProver::new(bad_coloring);
+    let caught = (0..rounds).any(|_| !run_coloring_round(&mut cheater, &mut verifier, &mut rng).1);
+    assert!(caught);
+
+    // Q10: how many rounds are needed so that a cheating prover succeeds with probability at most 2^-40?
+    // Could the rounds be run in parallel? Could Fiat-Shamir make this proof non-interactive?
+    runner.question("Q10");
+
+    // Now take a look at module `ring_signature`
+    // AOS ring signatures are the Fiat-Shamir transform of an OR-proof: "I know the secret key of X_0 or X_1 or ..."
+    let keys: Vec<(Fr, Affine)> = (0..8).map(|_| keygen(&mut rng)).collect();
+    let ring: Vec<Affine> = keys.iter().map(|(_, pk)| *pk).collect();
+    let m = "Anonymous whistleblowing".as_bytes();
+
+    // Q11: write `ring_sign` which signs `m` as the member at position `signer` of the ring
+    // Start the ring of challenges at the position following the signer with a fresh nonce,
+    // go around the ring with random responses and close it with the secret key
+    // Q12: write `ring_verify` which recomputes the ring of challenges starting from c_0
+    runner.questions(&["Q11", "Q12"]);
+    for (signer, (sk, _)) in keys.iter().enumerate() {
+        let signature = ring_sign(&ring, signer, *sk, m, &mut rng);
+        assert_eq!(signature.responses.len(), ring.len());
+        assert!(ring_verify(&ring, m, &signature));
+        assert!(!ring_verify(&ring, b"another message", &signature));
+    }
+
+    // Signatures by the same member under two different rings cannot be linked:
+    // unlike Schnorr signatures they share nothing which depends on the signer only
+    let other_ring: Vec<Affine> = ring[..4]
+        .iter()
+        .copied()
+        .chain((0..4).map(|_| keygen(&mut rng).1))
+        .collect();
+    let sig1 = ring_sign(&ring, 2, keys[2].0, m, &mut rng);
+    let sig2 = ring_sign(&other_ring, 2, keys[2].0, m, &mut rng);
+    assert!(ring_verify(&other_ring, m, &sig2));
+    assert!(!ring_verify(&other_ring, m, &sig1));
+    assert!(sig1.responses.iter().all(|s| !sig2.responses.contains(s)));
+    assert_ne!(sig1.challenge, sig2.challenge);
+
+    // Forged ring closures are rejected
+    // - a member outside of the ring cannot sign
+    let (outsider, _) = keygen(&mut rng);
+    let forged = ring_sign(&ring, 0, outsider, m, &mut rng);
+    assert!(!ring_verify(&ring, m, &forged));
+    // - the ring cannot be closed by tweaking one response or the starting challenge
+    let mut forged = ring_sign(&ring, 5, keys[5].0, m, &mut rng);
+    forged.responses[5] += Fr::from(1);
+    assert!(!ring_verify(&ring, m, &forged));
+    let mut forged = ring_sign(&ring, 5, keys[5].0, m, &mut rng);
+    forged.challenge += Fr::from(1);
+    assert!(!ring_verify(&ring, m, &forged));
+    // - the signature does not verify under a smaller ring or a reordered ring
+    let signature = ring_sign(&ring, 3, keys[3].0, m, &mut rng);
+    assert!(!ring_verify(&ring[1..], m, &signature));
+    let mut reordered = ring.clone();
+    reordered.swap(0, 7);
+    assert!(!ring_verify(&reordered, m, &signature));
+
+    // Q13: what is the size of a ring signature as a function of the ring size?
+    // Why would a cryptocurrency using ring signatures need "key images" (linkable ring signatures)?
+    runner.question("Q13");
+
+    // Now take a look at module `blind_schnorr`
+    // Q14: implement both parties of the blind Schnorr protocol
+    // - `Bank::commit` and `Bank::respond` are the moves of the Schnorr identification prover
+    // - `BlindUser::challenge` blinds the commitment and the challenge with alpha and beta
+    // - `BlindUser::unblind` checks the bank's response and outputs the signature (R', s')
+    runner.question("Q14");
+    let (bank_sk, bank_pk) = keygen(&mut rng);
+    let mut bank = Bank::new(bank_sk);
+    let mut sessions = vec![];
+    for token in ["token #1", "token #2"] {
+        let mut user = BlindUser::new(bank.pk, token.as_bytes());
+        let (view, signature) = run_blind_signing(&mut bank, &mut user, &mut rng);
+        assert!(schnorr_verify(&bank_pk, token.as_bytes(), &signature));
+        assert!(!schnorr_verify(&bank_pk, b"token #3", &signature));
+        sessions.push((view, signature));
+    }
+
+    // The bank never saw the messages, nor the final signatures
+    // Q15: unlinkability; write `is_consistent` which decides whether a bank view and a signature
+    // *could* come from the same session, i.e. whether there exist blinding factors alpha and beta
+    // mapping one to the other
+    // Since every view is consistent with every signature, the bank cannot tell which session produced which signature
+    runner.question("Q15");
+    for (view, _) in &sessions {
+        for (token, (_, signature)) in ["token #1", "token #2"].iter().zip(&sessions) {
+            assert!(is_consistent(&bank_pk, token.as_bytes(), view, signature));
+        }
+    }
+    // a random signature is not consistent with a view though
+    let (view, signature) = sessions[0];
+    let unrelated = SchnorrSignature {
+        response: signature.response + Fr::from(1),
+        ..signature
+    };
+    assert!(!is_consistent(&bank_pk, b"token #1", &view, &unrelated));
+
+    // Q16: the bank signs whatever it is given, blindly. Why is that a problem for an e-cash system,
+    // and how do real systems limit the damage?
+    // Look up the ROS attack: why is it dangerous for the bank to run many sessions concurrently?
+    runner.question("Q16");
+
+    // Capstone: proof of solvency
+    // Take a look at modules `range_proof` (Pedersen commitments, OR-proofs, range proofs) and `solvency`
+    // An exchange proves that it owns at least as many bitcoins as it owes to its customers
+    let params = PedersenParams::new();
+    let mut addresses = vec![];
+    let mut keys = vec![];
+    for i in 0..20 {
+        let (sk, pk) = keygen(&mut rng);
+        addresses.push(Address {
+            pk,
+            balance: rng.gen_range(1_000..1_000_000),
+        });
+        // the exchange owns one address out of three
+        keys.push(if i % 3 == 0 { Some(sk) } else { None });
+    }
+    let assets: u64 = addresses
+        .iter()
+        .zip(&keys)
+        .filter(|(_, key)| key.is_some())
+        .map(|(address, _)| address.balance)
+        .sum();
+    let mut customers: Vec<u64> = (0..10).map(|_| rng.gen_range(0..assets / 10)).collect();
+
+    // Q17: write `prove_asset` which commits to the balance of an address (or to 0 if the exchange
+    // does not own it) and proves it with an OR-proof on the branches given by `asset_branches`
+    runner.question("Q17");
+    let (commitment, _, proof) = prove_asset(&params, &addresses[0], keys[0], &mut rng);
+    assert!(or_verify(
+        &asset_branches(&params, &addresses[0], &commitment),
+        &proof,
+        b"asset"
+    ));
+    let (commitment, blinding, proof) = prove_asset(&params, &addresses[1], keys[1], &mut rng);
+    assert!(or_verify(
+        &asset_branches(&params, &addresses[1], &commitment),
+        &proof,
+        b"asset"
+    ));
+    assert_eq!(commitment, params.commit(Fr::from(0), blinding));
+    // claiming an address without its secret key does not work
+    let (fake_key, _) = keygen(&mut rng);
+    let (commitment, _, proof) = prove_asset(&params, &addresses[1], Some(fake_key), &mut rng);
+    assert!(!or_verify(
+        &asset_branches(&params, &addresses[1], &commitment),
+        &proof,
+        b"asset"
+    ));
+
+    // Q18: write `prove_solvency` which returns the whole proof and the blinding factors of the customers' commitments
+    // (so that each customer can check that she is included), or `None` if the exchange is not solvent
+    // Q19: write `verify_solvency`
+    runner.questions(&["Q18", "Q19"]);
+    let (proof, blindings) =
+        prove_solvency(&params, &addresses, &keys, &customers, &mut rng).unwrap();
+    assert!(verify_solvency(&params, &addresses, &proof));
+    for (balance, blinding) in customers.iter().zip(&blindings) {
+        let mine = params.commit(Fr::from(*balance), *blinding);
+        assert!(proof.liability_commitments.contains(&mine));
+    }
+    // the proof is bound to the public list of addresses
+    let mut richer = addresses.clone();
+    richer[1].balance += 1;
+    assert!(!verify_solvency(&params, &richer, &proof));
+
+    // An insolvent exchange cannot produce a proof...
+    customers[0] += assets;
+    assert!(prove_solvency(&params, &addresses, &keys, &customers, &mut rng).is_none());
+    // ... nor hide debts behind a customer with a negative balance (the range proofs forbid it)
+    assert!(range_prove(&params, -Fr::from(1000), Fr::rand(&mut rng), BITS, &mut rng).is_none());
+    // If it omits a customer, the proof verifies but that customer notices she is missing
+    let (proof, _) = prove_solvency(&params, &addresses, &keys, &customers[1..], &mut rng).unwrap();
+    assert!(verify_solvency(&params, &addresses, &proof));
+    assert_eq!(proof.liability_commitments.len(), customers.len() - 1);
+    // And the commitments cannot be tampered with after the fact
+    let mut tampered = proof;
+    tampered.liability_commitments[0] = params.commit(Fr::from(0), Fr::rand(&mut rng));
+    assert!(!verify_solvency(&params, &addresses, &tampered));
+
+    // Q20: what does the proof leak?
+    // How could two exchanges collude to prove their solvency with the same bitcoins?
+    runner.question("Q20");
+
+    // Now take a look at module `ecvrf`
+    // It describes a VRF close to RFC 9381, the standardized version of the toy VRF of Q8
+    // Q21: write `ecvrf_prove` which outputs (Gamma, c, s) with the deterministic nonce of `ecvrf_nonce`
+    // Q22: write `ecvrf_verify` which checks the proof and returns beta = `ecvrf_proof_to_hash(proof)`
+    runner.questions(&["Q21", "Q22"]);
+    let (vrf_sk, vrf_pk) = keygen(&mut rng);
+    let alpha = b"slot 42";
+    let proof = ecvrf_prove(&vrf_sk, alpha);
+    let beta = ecvrf_verify(&vrf_pk, alpha, &proof).unwrap();
+    assert_eq!(beta, ecvrf_proof_to_hash(&proof));
+    // the challenge is truncated to 128 bits
+    assert!(proof.challenge.into_bigint().num_bits() <= 8 * CHALLENGE_BYTES as u32);
+    // determinism: the same input always gives the same proof and the same output
+    assert_eq!(ecvrf_prove(&vrf_sk, alpha), proof);
+    // public verifiability: the proof is bound to the key and to the input
+    assert_eq!(ecvrf_verify(&other_pk, alpha, &proof), None);
+    assert_eq!(ecvrf_verify(&vrf_pk, b"slot 43", &proof), None);
+
+    // Pseudorandomness: the outputs over many inputs look uniformly random
+    let mut bit_counts = [0; 256];
+    let mut elected = 0;
+    let slots = 256;
+    for slot in 0..slots {
+        let alpha = format!("slot {}", slot);
+        let proof = ecvrf_prove(&vrf_sk, alpha.as_bytes());
+        let beta = ecvrf_verify(&vrf_pk, alpha.as_bytes(), &proof).unwrap();
+        for (i, count) in bit_counts.iter_mut().enumerate() {
+            *count += (beta[i / 8] >> (i % 8)) & 1;
+        }
+        elected += is_elected(&beta) as usize;
+    }
+    // each count is binomial(256, 1/2), 128 with a standard deviation of 8: 6 of them on each side
+    assert!(bit_counts.iter().all(|count| (80..=176).contains(count)));
+    println!(
+        "The honest participant was elected {} times out of {} slots",
+        elected, slots
+    );
+
+    // A dishonest participant would like to be elected more often, by grinding other values of Gamma
+    // Q23: write `grind_gamma` which returns the first Gamma' = Gamma + iG (i = 1, 2, ...) giving an electing output,
+    // together with the honest (c, s): a verifier forgetting the DLEQ check would accept it, `ecvrf_verify` does not
+    runner.question("Q23");
+    let mut candidates = 0;
+    for slot in 0..slots {
+        let alpha = format!("slot {}", slot);
+        let cheat = grind_gamma(&vrf_sk, alpha.as_bytes());
+        assert!(is_elected(&ecvrf_proof_to_hash(&cheat)));
+        assert_eq!(ecvrf_verify(&vrf_pk, alpha.as_bytes(), &cheat), None);
+        // Gamma' = Gamma + iG: the cheater tried i candidates, 16 on average
+        let mut gamma = ecvrf_prove(&vrf_sk, alpha.as_bytes()).gamma.into_group();
+        candidates += (1..=1024)
+            .find(|_| {
+                gamma += Affine::generator();
+                gamma.into_affine() == cheat.gamma
+            })
+            .expect("Gamma' is Gamma + iG");
+    }
+    println!(
+        "Against a verifier forgetting the DLEQ check, the cheater would be elected in all {} slots after trying {} candidate Gammas, the honest participant {} times\n",
+        slots, candidates, elected
+    );
+
+    // Q24: why does the nonce need to depend on H and not only on the secret key?
+    // Why is it fine to truncate the challenge to 128 bits?
+    runner.question("Q24");
+
+    // Now take a look at module `pvss`
+    let (threshold, n) = (3, 5);
+    let parties: Vec<PvssParty> = (1..=n).map(|i| PvssParty::new(i, &mut rng)).collect();
+    let public_keys: Vec<Affine> = parties.iter().map(|party| party.pk).collect();
+    let secret = Fr::rand(&mut rng);
+    // Q25: write `pvss_deal` and `pvss_verify_transcript`
+    runner.question("Q25");
+    let transcript = pvss_deal(secret, threshold, &public_keys, &mut rng);
+    assert_eq!(transcript.commitments.len(), threshold);
+    assert!(pvss_verify_transcript(&transcript, &public_keys));
+    // a dealer giving a wrong share to party 2, or announcing other commitments, is caught by everyone
+    let mut wrong_share = transcript.clone();
+    wrong_share.encrypted_shares[1] =
+        (wrong_share.encrypted_shares[1] + public_keys[1]).into_affine();
+    assert!(!pvss_verify_transcript(&wrong_share, &public_keys));
+    let mut wrong_commitment = transcript.clone();
+    wrong_commitment.commitments[2] = (wrong_commitment.commitments[2] + pvss_base()).into_affine();
+    assert!(!pvss_verify_transcript(&wrong_commitment, &public_keys));
+    let mut swapped = transcript.clone();
+    swapped.encrypted_shares.swap(0, 1);
+    swapped.proofs.swap(0, 1);
+    assert!(!pvss_verify_transcript(&swapped, &public_keys));
+    let mut missing = transcript.clone();
+    missing.encrypted_shares.pop();
+    missing.proofs.pop();
+    assert!(!pvss_verify_transcript(&missing, &public_keys));
+
+    // Q26: write `pvss_decrypt_share`, `pvss_verify_share` and `pvss_reconstruct`
+    runner.question("Q26");
+    let shares: Vec<DecryptedShare> = parties
+        .iter()
+        .map(|party| pvss_decrypt_share(party, &transcript, &mut rng))
+        .collect();
+    for (share, party) in shares.iter().zip(&parties) {
+        assert_eq!(share.index, party.index);
+        let encrypted = &transcript.encrypted_shares[party.index as usize - 1];
+        assert!(pvss_verify_share(&party.pk, encrypted, share));
+    }
+    let expected = Affine::generator().mul(secret).into_affine();
+    for subset in [[0, 1, 2], [0, 2, 4], [1, 3, 4], [2, 3, 4]] {
+        let subset: Vec<DecryptedShare> = subset.iter().map(|i| shares[*i]).collect();
+        assert_eq!(pvss_reconstruct(&subset), expected);
+    }
+    assert_eq!(pvss_reconstruct(&shares), expected);
+    assert_ne!(pvss_reconstruct(&shares[..threshold - 1]), expected);
+    // party 1 publishes a wrong share: the proof gives it away, and the others reconstruct without it
+    let mut published = shares.clone();
+    published[0].share = (published[0].share + Affine::generator()).into_affine();
+    let valid: Vec<DecryptedShare> = published
+        .iter()
+        .zip(&parties)
+        .filter(|(share, party)| {
+            let encrypted = &transcript.encrypted_shares[party.index as usize - 1];
+            pvss_verify_share(&party.pk, encrypted, share)
+        })
+        .map(|(share, _)| *share)
+        .collect();
+    assert_eq!(valid.len(), n as usize - 1);
+    assert_eq!(pvss_reconstruct(&valid), expected);
+    assert_ne!(pvss_reconstruct(&published[..threshold]), expected);
+    // Q27: compared with Feldman VSS, who can check the dealing here? Why would revealing s itself
+    // (instead of sG) break the secrecy of the encrypted shares?
+    runner.question("Q27");
+
+    // The range proofs of the solvency capstone grow linearly with the number of bits
+    // Take a look at module `bulletproofs`: the inner-product argument brings them down to a logarithmic size
+    // Q28: write `ipa_prove` which proves P = <a, G> + <b, H> + <a, b> U, folding the statement in log2(n) rounds
+    // Q29: write `ipa_verify` which folds the generators and P with the challenges, then checks the last round
+    // The hash of the challenges is the backend B of module `transcript`: `B::challenge(&P)`, `ipa_challenge::<B>`
+    runner.questions(&["Q28", "Q29"]);
+    let gens = BulletproofsGens::new(2 * RANGE_BITS);
+    for n in [1, 4, 16, 2 * RANGE_BITS] {
+        let a: Vec<Fr> = (0..n).map(|_| Fr::rand(&mut rng)).collect();
+        let b: Vec<Fr> = (0..n).map(|_| Fr::rand(&mut rng)).collect();
+        let (g, h) = (&gens.g[..n], &gens.h[..n]);
+        let p = (msm(g, &a) + msm(h, &b) + gens.u.mul(inner_product(&a, &b))).into_affine();
+        let proof = ipa_prove::<Sha256Backend>(g, h, &gens.u, &p, &a, &b);
+        // logarithmic size: one pair (L, R) per round
+        assert_eq!(proof.l.len(), n.trailing_zeros() as usize);
+        assert_eq!(proof.r.len(), proof.l.len());
+        assert!(ipa_verify::<Sha256Backend>(g, h, &gens.u, &p, &proof));
+        // another statement, or a wrong inner product, is rejected
+        let wrong = (p + gens.u).into_affine();
+        assert!(!ipa_verify::<Sha256Backend>(g, h, &gens.u, &wrong, &proof));
+        assert!(!ipa_verify::<Sha256Backend>(
+            &gens.h[..n],
+            &gens.g[..n],
+            &gens.u,
+            &p,
+            &proof
+        ));
+        let mut tampered = proof.clone();
+        tampered.a += Fr::from(1);
+        assert!(!ipa_verify::<Sha256Backend>(g, h, &gens.u, &p, &tampered));
+        if n > 1 {
+            // the rounds must come in order
+            let mut swapped = proof.clone();
+            swapped.l.swap(0, 1);
+            swapped.r.swap(0, 1);
+            assert!(!ipa_verify::<Sha256Backend>(g, h, &gens.u, &p, &swapped));
+            assert!(!ipa_verify::<Sha256Backend>(
+                &gens.g[..n / 2],
+                &gens.h[..n / 2],
+                &gens.u,
+                &p,
+                &proof
+            ));
+        }
+    }
+
+    // Q30: write `bulletproof_prove` which proves that V = vG + gamma H commits to v in [0, 2^32),
+    // or returns None if v is out of range
+    // Q31: write `bulletproof_verify`
+    runner.questions(&["Q30", "Q31"]);
+    let gens = BulletproofsGens::new(RANGE_BITS);
+    for value in [0, 1, 42, u32::MAX as u64] {
+        let blinding = Fr::rand(&mut rng);
+        let commitment = params.commit(Fr::from(value), blinding);
+        let proof = bulletproof_prove::<Sha256Backend, _>(
+            &params,
+            &gens,
+            Fr::from(value),
+            blinding,
+            &mut rng,
+        )
+        .unwrap();
+        assert!(bulletproof_verify::<Sha256Backend>(
+            &params,
+            &gens,
+            &commitment,
+            &proof
+        ));
+        assert_eq!(proof.ipp.l.len(), RANGE_BITS.trailing_zeros() as usize);
+        // the proof is bound to its commitment, values shifted out of range included
+        let shifted = (commitment + params.g.mul(Fr::from(1u64 << RANGE_BITS))).into_affine();
+        assert!(!bulletproof_verify::<Sha256Backend>(
+            &params, &gens, &shifted, &proof
+        ));
+        let mut tampered = proof.clone();
+        tampered.t += Fr::from(1);
+        assert!(!bulletproof_verify::<Sha256Backend>(
+            &params,
+            &gens,
+            &commitment,
+            &tampered
+        ));
+        let mut tampered = proof;
+        tampered.tau_x += Fr::from(1);
+        assert!(!bulletproof_verify::<Sha256Backend>(
+            &params,
+            &gens,
+            &commitment,
+            &tampered
+        ));
+    }
+    // out-of-range values cannot be proven
+    for value in [
+        -Fr::from(1),
+        Fr::from(1u64 << RANGE_BITS),
+        Fr::rand(&mut rng),
+    ] {
+        let blinding = Fr::rand(&mut rng);
+        assert!(
+            bulletproof_prove::<Sha256Backend, _>(&params, &gens, value, blinding, &mut rng)
+                .is_none()
+        );
+    }
+    let value = Fr::from(rng.gen::<u32>());
+    let blinding = Fr::rand(&mut rng);
+    let bulletproof =
+        bulletproof_prove::<Sha256Backend, _>(&params, &gens, value, blinding, &mut rng).unwrap();
+    let bit_by_bit = range_prove(&params, value, blinding, RANGE_BITS, &mut rng).unwrap();
+    println!(
+        "{}-bit range proofs: {} bytes bit by bit, {} bytes with Bulletproofs\n",
+        RANGE_BITS,
+        bit_by_bit.compressed_size(),
+        bulletproof.compressed_size()
+    );
+
+    // Q32: the verifier of Q29 folds the generators round by round: how can it compute the final G and H
+    // with a single multi-scalar multiplication of size 2n?
+    // Is the verification still linear in n? How do Bulletproofs aggregate the range proofs of m values?
+    runner.question("Q32");
+
+    // Take a look at module `transcript`: the challenges of Bulletproofs are hashed with SHA-256, BLAKE3 or
+    // Poseidon, chosen by the protocol
+    // Q33: write `prover_time` which returns the average time of `bulletproof_prove` with the backend B
+    runner.question("Q33");
+    // the challenges of each backend are pinned by test vectors, BLAKE3 by the ones of its specification
+    assert_eq!(
+        to_hex_bytes(&blake3::blake3(b"")),
+        "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+    );
+    assert_eq!(
+        to_hex_bytes(&blake3::blake3(b"abc")),
+        "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+    );
+    // the inputs 0, 1, ..., 250, 0, 1, ... of the specification: one chunk, two chunks, the first of them full
+    for (len, expected) in [
+        (
+            1024,
+            "42214739f095a406f3fc83deb889744ac00df831c10daa55189b5d121c855af7",
+        ),
+        (
+            1025,
+            "d00278ae47eb27b34faecf67b4fe263f82d5412916c1ffd97c8cb7fb814b8444",
+        ),
+        (
+            2048,
+            "e776b6028c7cd22a4d0ba182a8bf62205d2ef576467e838ed6f2529b85fba24a",
+        ),
+    ] {
+        let input: Vec<u8> = (0..len).map(|i| (i % 251) as u8).collect();
+        assert_eq!(to_hex_bytes(&blake3::blake3(&input)), expected);
+    }
+    let transcript = vector_transcript();
+    let challenges = [
+        Sha256Backend::challenge(&transcript),
+        Blake3Backend::challenge(&transcript),
+        PoseidonBackend::challenge(&transcript),
+    ];
+    for ((name, expected), challenge) in CHALLENGE_VECTORS.iter().zip(challenges) {
+        assert_eq!(to_hex(challenge), *expected, "{}", name);
+    }
+    assert_eq!(challenges[0], hash_to_scalar_field(&transcript));
+    // a proof only verifies with the backend it was made with
+    let value = Fr::from(rng.gen::<u32>());
+    let blinding = Fr::rand(&mut rng);
+    let commitment = params.commit(value, blinding);
+    let proof =
+        bulletproof_prove::<Blake3Backend, _>(&params, &gens, value, blinding, &mut rng).unwrap();
+    assert!(bulletproof_verify::<Blake3Backend>(
+        &params,
+        &gens,
+        &commitment,
+        &proof
+    ));
+    assert!(!bulletproof_verify::<Sha256Backend>(
+        &params,
+        &gens,
+        &commitment,
+        &proof
+    ));
+    let proof =
+        bulletproof_prove::<PoseidonBackend, _>(&params, &gens, value, blinding, &mut rng).unwrap();
+    assert!(bulletproof_verify::<PoseidonBackend>(
+        &params,
+        &gens,
+        &commitment,
+        &proof
+    ));
+    assert!(!bulletproof_verify::<Blake3Backend>(
+        &params,
+        &gens,
+        &commitment,
+        &proof
+    ));
+    let timings = [
+        (
+            Sha256Backend::NAME,
+            prover_time::<Sha256Backend, _>(&params, &gens, PROVER_RUNS, &mut rng),
+        ),
+        (
+            Blake3Backend::NAME,
+            prover_time::<Blake3Backend, _>(&params, &gens, PROVER_RUNS, &mut rng),
+        ),
+        (
+            PoseidonBackend::NAME,
+            prover_time::<PoseidonBackend, _>(&params, &gens, PROVER_RUNS, &mut rng),
+        ),
+    ];
+    assert!(timings.iter().all(|(_, time)| !time.is_zero()));
+    let hashings = [
+        challenge_time::<Sha256Backend>(&proof),
+        challenge_time::<Blake3Backend>(&proof),
+        challenge_time::<PoseidonBackend>(&proof),
+    ];
+    for ((name, time), hashing) in timings.iter().zip(hashings) {
+        println!(
+            "{:>8}: {:?} per {}-bit range proof, {:?} per challenge",
+            name, time, RANGE_BITS, hashing
+        );
+    }
+    println!();
+
+    // Q34: how much of the prover time goes to hashing? Why is BLAKE3 not faster than SHA-256 on such short
+    // transcripts? Poseidon is by far the slowest backend here: why would a protocol still choose it?
+    runner.question("Q34");
+
+    // Take a look at module `flawed_commitment`
+    // Q35: its setup derives H from a public seed, but through a scalar. Write `forge_opening` which opens a
+    // commitment of the flawed setup to any other value
+    runner.question("Q35");
+    let flawed = flawed_setup();
+    let opening = Opening {
+        value: Fr::from(rng.gen::<u32>()),
+        blinding: Fr::rand(&mut rng),
+    };
+    let commitment = flawed.commit(opening.value, opening.blinding);
+    assert!(verify_opening(&flawed, &commitment, &opening));
+    for _ in 0..10 {
+        let value = Fr::rand(&mut rng);
+        let forged = forge_opening(&opening, value);
+        assert_eq!(forged.value, value);
+        assert!(verify_opening(&flawed, &commitment, &forged));
+    }
+
+    // Q36: fix the setup: write `pedersen_setup` which derives H from a domain with `hash_to_curve`
+    runner.question("Q36");
+    let fixed = pedersen_setup(FLAWED_SEED);
+    assert_eq!(fixed.g, Affine::generator());
+    assert_eq!(fixed.h, hash_to_curve(FLAWED_SEED));
+    assert!(fixed.h.is_on_curve() && fixed.h.is_in_correct_subgroup_assuming_on_curve());
+    assert_ne!(fixed.h, flawed.h);
+    assert_ne!(pedersen_setup(b"another domain").h, fixed.h);
+    // the honest openings still verify, the forged ones do not
+    let commitment = fixed.commit(opening.value, opening.blinding);
+    assert!(verify_opening(&fixed, &commitment, &opening));
+    let forged = forge_opening(&opening, Fr::rand(&mut rng));
+    assert!(!verify_opening(&fixed, &commitment, &forged));
+
+    // Q37: could the designer of `hash_to_curve` know log_G(H)? What should a verifier check before trusting
+    // a setup which publishes H and its seed? The flawed setup is still hiding: why?
+    runner.question("Q37");
+
+    // Back to the identification protocol of Q1, now over a link an adversary controls
+    // Q38: write `run_over_channel`, which plays the three moves with the prover and the verifier talking through
+    // their own ends of a channel, and returns the verdict of the verifier: a missing or unexpected message
+    // is a rejection
+    runner.question("Q38");
+    let (sk, pk) = keygen(&mut rng);
+    let (mut prover_end, mut verifier_end) = duplex(0, 1);
+    let accepted = run_over_channel(
+        &mut Prover::new(sk),
+        &mut Verifier::new(pk),
+        &mut prover_end,
+        &mut verifier_end,
+        &mut rng,
+    );
+    let transcript = verifier_end.transcript();
+    assert!(accepted);
+    assert_eq!(transcript.len(), 3);
+    let recorded: Vec<IdentificationMessage> = transcript.sent_by(0).copied().collect();
+    let old_response = recorded[1];
+    // an adversary on the link of the prover, then on the link of the verifier
+    let attacks: [(&str, bool); 5] = [
+        ("the response dropped", {
+            let (prover_end, mut verifier_end) = duplex(0, 1);
+            let mut prover_end = Dropping::new(prover_end, vec![1]);
+            run_over_channel(
+                &mut Prover::new(sk),
+                &mut Verifier::new(pk),
+                &mut prover_end,
+                &mut verifier_end,
+                &mut rng,
+            )
+        }),
+        ("the response of an earlier session replayed", {
+            let (prover_end, mut verifier_end) = duplex(0, 1);
+            let mut prover_end = Replaying::new(prover_end, 1, old_response);
+            run_over_channel(
+                &mut Prover::new(sk),
+                &mut Verifier::new(pk),
+                &mut prover_end,
+                &mut verifier_end,
+                &mut rng,
+            )
+        }),
+        ("a whole earlier session replayed", {
+            let (prover_end, mut verifier_end) = duplex(0, 1);
+            let prover_end = Replaying::new(prover_end, 0, recorded[0]);
+            let mut prover_end = Replaying::new(prover_end, 1, old_response);
+            run_over_channel(
+                &mut Prover::new(sk),
+                &mut Verifier::new(pk),
+                &mut prover_end,
+                &mut verifier_end,
+                &mut rng,
+            )
+        }),
+        ("the challenge set to zero", {
+            let (mut prover_end, verifier_end) = duplex(0, 1);
+            let mut verifier_end = Tampering::new(verifier_end, |_, message| match message {
+                IdentificationMessage::Challenge(_) => {
+                    IdentificationMessage::Challenge(Fr::from(0))
+                }
+                message => message,
+            });
+            run_over_channel(
+                &mut Prover::new(sk),
+                &mut Verifier::new(pk),
+                &mut prover_end,
+                &mut verifier_end,
+                &mut rng,
+            )
+        }),
+        ("the messages relayed untouched by a man in the middle", {
+            let (prover_end, mut verifier_end) = duplex(0, 1);
+            let mut prover_end = Tampering::new(prover_end, |_, message| message);
+            run_over_channel(
+                &mut Prover::new(sk),
+                &mut Verifier::new(pk),
+                &mut prover_end,
+                &mut verifier_end,
+                &mut rng,
+            )
+        }),
+    ];
+    for (attack, accepted) in attacks {
+        println!(
+            "identification with {}: {}",
+            attack,
+            if accepted { "accepted" } else { "rejected" }
+        );
+    }
+    assert_eq!(
+        attacks.map(|(_, accepted)| accepted),
+        [false, false, false, false, true]
+    );
+    // Q39: why does the replay of a whole session fail, and what would make it succeed? The verifier accepts the
+    // relay: the man in the middle proved nothing, but a door which opens for whoever relays the badge of its
+    // owner from a distance is still open. What do distance bounding protocols measure against this relay attack?
+    runner.question("Q39");
+
+    println!("Good job!");
+    runner.finish();
+}
+
+fn run_over_channel<R: Rng>(
+    prover: &mut Prover,
+    verifier: &mut Verifier,
+    prover_end: &mut impl Channel<IdentificationMessage>,
+    verifier_end: &mut impl Channel<IdentificationMessage>,
+    rng: &mut R,
+) -> bool {
+    // SOLUTION-BEGIN
+    use IdentificationMessage::*;
+    prover_end.send(Commitment(prover.commit(rng)));
+    let Some(Commitment(commitment)) = verifier_end.receive() else {
+        return false;
+    };
+    verifier_end.send(Challenge(verifier.challenge(commitment, rng)));
+    let Some(Challenge(challenge)) = prover_end.receive() else {
+        return false;
+    };
+    prover_end.send(Response(prover.respond(challenge)));
+    let Some(Response(response)) = verifier_end.receive() else {
+        return false;
+    };
+    verifier.verify(response)
+    // SOLUTION-END
+}
+
+impl Prover {
+    pub fn commit<R: Rng>(&mut self, rng: &mut R) -> Affine {
+        // SOLUTION-BEGIN
+        let r = Fr::rand(rng);
+        self.nonce = Some(r);
+        Affine::generator().mul(r).into_affine()
+        // SOLUTION-END
+    }
+
+    pub fn respond(&mut self, challenge: Fr) -> Fr {
+        // SOLUTION-BEGIN
+        let r = self
+            .nonce
+            .take()
+            .expect("commit must be called before respond");
+        r + challenge * self.sk
+        // SOLUTION-END
+    }
+}
+
+impl Verifier {
+    pub fn challenge<R: Rng>(&mut self, commitment: Affine, rng: &mut R) -> Fr {
+        // SOLUTION-BEGIN
+        let c = Fr::rand(rng);
+        self.commitment = Some(commitment);
+        self.challenge = Some(c);
+        c
+        // SOLUTION-END
+    }
+
+    pub fn verify(&self, response: Fr) -> bool {
+        // SOLUTION-BEGIN
+        match (self.commitment, self.challenge) {
+            (Some(commitment), Some(challenge)) => is_accepting(
+                &self.pk,
+                &Transcript {
+                    commitment,
+                    challenge,
+                    response,
+                },
+            ),
+            _ => false,
+        }
+        // SOLUTION-END
+    }
+}
+
+fn extract(t1: &Transcript, t2: &Transcript) -> Fr {
+    // SOLUTION-BEGIN
+    // s1 - s2 = (c1 - c2) x
+    (t1.response - t2.response) * (t1.challenge - t2.challenge).inverse().unwrap()
+    // SOLUTION-END
+}
+
+fn simulate<R: Rng>(pk: &Affine, rng: &mut R) -> Transcript {
+    // SOLUTION-BEGIN
+    let challenge = Fr::rand(rng);
+    let response = Fr::rand(rng);
+    // R := sG - cX
+    let commitment = (Affine::generator().mul(response) - pk.mul(challenge)).into_affine();
+    Transcript {
+        commitment,
+        challenge,
+        response,
+    }
+    // SOLUTION-END
+}
+
+fn fs_prove<R: Rng>(sk: Fr, rng: &mut R) -> SchnorrProof {
+    // SOLUTION-BEGIN
+    let pk = Affine::generator().mul(sk).into_affine();
+    let r = Fr::rand(rng);
+    let commitment = Affine::generator().mul(r).into_affine();
+    let challenge = hash_to_scalar_field(&(Affine::generator(), pk, commitment));
+    SchnorrProof {
+        commitment,
+        response: r + challenge * sk,
+    }
+    // SOLUTION-END
+}
+
+fn fs_verify(pk: &Affine, proof: &SchnorrProof) -> bool {
+    // SOLUTION-BEGIN
+    let challenge = hash_to_scalar_field(&(Affine::generator(), *pk, proof.commitment));
+    is_accepting(
+        pk,
+        &Transcript {
+            commitment: proof.commitment,
+            challenge,
+            response: proof.response,
+        },
+    )
+    // SOLUTION-END
+}
+
+impl DleqProver {
+    pub fn commit<R: Rng>(&mut self, rng: &mut R) -> (Affine, Affine) {
+        // SOLUTION-BEGIN
+        let r = Fr::rand(rng);
+        self.nonce = Some(r);
+        (
+            self.statement.g.mul(r).into_affine(),
+            self.statement.h.mul(r).into_affine(),
+        )
+        // SOLUTION-END
+    }
+
+    pub fn respond(&mut self, challenge: Fr) -> Fr {
+        // SOLUTION-BEGIN
+        let r = self
+            .nonce
+            .take()
+            .expect("commit must be called before respond");
+        r + challenge * self.x
+        // SOLUTION-END
+    }
+}
+
+impl DleqVerifier {
+    pub fn challenge<R: Rng>(&mut self, commitments: (Affine, Affine), rng: &mut R) -> Fr {
+        // SOLUTION-BEGIN
+        let c = Fr::rand(rng);
+        self.commitments = Some(commitments);
+        self.challenge = Some(c);
+        c
+        // SOLUTION-END
+    }
+
+    pub fn verify(&self, response: Fr) -> bool {
+        // SOLUTION-BEGIN
+        let (Some((r1, r2)), Some(c)) = (self.commitments, self.challenge) else {
+            return false;
+        };
+        let st = &self.statement;
+        st.g.mul(response) == r1 + st.a.mul(c) && st.h.mul(response) == r2 + st.b.mul(c)
+        // SOLUTION-END
+    }
+}
+
+fn dleq_prove<R: Rng>(statement: &DleqStatement, x: Fr, rng: &mut R) -> DleqProof {
+    // SOLUTION-BEGIN
+    let r = Fr::rand(rng);
+    let r1 = statement.g.mul(r).into_affine();
+    let r2 = statement.h.mul(r).into_affine();
+    let st = statement;
+    let challenge = hash_to_scalar_field(&((st.g, st.a), (st.h, st.b), (r1, r2)));
+    DleqProof {
+        challenge,
+        response: r + challenge * x,
+    }
+    // SOLUTION-END
+}
+
+fn dleq_verify(statement: &DleqStatement, proof: &DleqProof) -> bool {
+    // SOLUTION-BEGIN
+    let st = statement;
+    // R1 = sG - cA, R2 = sH - cB
+    let r1 = (st.g.mul(proof.response) - st.a.mul(proof.challenge)).into_affine();
+    let r2 = (st.h.mul(proof.response) - st.b.mul(proof.challenge)).into_affine();
+    proof.challenge == hash_to_scalar_field(&((st.g, st.a), (st.h, st.b), (r1, r2)))
+    // SOLUTION-END
+}
+
+fn vrf_evaluate<R: Rng>(sk: Fr, m: &[u8], rng: &mut R) -> VrfOutput {
+    // SOLUTION-BEGIN
+    let h = hash_to_curve(m);
+    let statement = DleqStatement::new(Affine::generator(), h, sk);
+    VrfOutput {
+        beta: proof_to_hash(&statement.b),
+        gamma: statement.b,
+        proof: dleq_prove(&statement, sk, rng),
+    }
+    // SOLUTION-END
+}
+
+fn vrf_verify(pk: &Affine, m: &[u8], output: &VrfOutput) -> Option<[u8; 32]> {
+    // SOLUTION-BEGIN
+    let statement = DleqStatement {
+        g: Affine::generator(),
+        a: *pk,
+        h: hash_to_curve(m),
+        b: output.gamma,
+    };
+    let beta = proof_to_hash(&output.gamma);
+    (dleq_verify(&statement, &output.proof) && beta == output.beta).then_some(beta)
+    // SOLUTION-END
+}
+
+impl ColoringProver {
+    pub fn commit<R: Rng>(&mut self, rng: &mut R) -> Vec<Commitment> {
+        // SOLUTION-BEGIN
+        let mut permutation = [0u8, 1, 2];
+        permutation.shuffle(rng);
+        let openings: Vec<ColorOpening> = self
+            .coloring
+            .iter()
+            .map(|color| ColorOpening {
+                color: permutation[*color as usize],
+                blinding: rng.gen(),
+            })
+            .collect();
+        let commitments = openings.iter().map(commit_color).collect();
+        self.round = Some(openings);
+        commitments
+        // SOLUTION-END
+    }
+
+    pub fn respond(&mut self, edge: (usize, usize)) -> (ColorOpening, ColorOpening) {
+        // SOLUTION-BEGIN
+        let openings = self
+            .round
+            .take()
+            .expect("commit must be called before respond");
+        (openings[edge.0], openings[edge.1])
+        // SOLUTION-END
+    }
+}
+
+impl ColoringVerifier {
+    pub fn challenge<R: Rng>(
+        &mut self,
+        commitments: Vec<Commitment>,
+        rng: &mut R,
+    ) -> (usize, usize) {
+        // SOLUTION-BEGIN
+        let edge = *self.graph.edges.choose(rng).unwrap();
+        self.commitments = Some(commitments);
+        self.edge = Some(edge);
+        edge
+        // SOLUTION-END
+    }
+
+    pub fn verify(&self, openings: (ColorOpening, ColorOpening)) -> bool {
+        // SOLUTION-BEGIN
+        let (Some(commitments), Some((u, v))) = (&self.commitments, self.edge) else {
+            return false;
+        };
+        commitments.len() == self.graph.vertex_count
+            && commit_color(&openings.0) == commitments[u]
+            && commit_color(&openings.1) == commitments[v]
+            && openings.0.color < 3
+            && openings.1.color < 3
+            && openings.0.color != openings.1.color
+        // SOLUTION-END
+    }
+}
+
+fn ring_sign<R: Rng>(
+    ring: &[Affine],
+    signer: usize,
+    sk: Fr,
+    m: &[u8],
+    rng: &mut R,
+) -> RingSignature {
+    // SOLUTION-BEGIN
+    let n = ring.len();
+    let mut challenges = vec![Fr::from(0); n];
+    let mut responses = vec![Fr::from(0); n];
+    let nonce = Fr::rand(rng);
+    let commitment = Affine::generator().mul(nonce).into_affine();
+    challenges[(signer + 1) % n] = ring_challenge(ring, m, &commitment);
+    for k in 1..n {
+        let i = (signer + k) % n;
+        responses[i] = Fr::rand(rng);
+        let commitment =
+            (Affine::generator().mul(responses[i]) - ring[i].mul(challenges[i])).into_affine();
+        challenges[(i + 1) % n] = ring_challenge(ring, m, &commitment);
+    }
+    responses[signer] = nonce + challenges[signer] * sk;
+    RingSignature {
+        challenge: challenges[0],
+        responses,
+    }
+    // SOLUTION-END
+}
+
+fn ring_verify(ring: &[Affine], m: &[u8], signature: &RingSignature) -> bool {
+    // SOLUTION-BEGIN
+    if signature.responses.len() != ring.len() {
+        return false;
+    }
+    let mut challenge = signature.challenge;
+    for (pk, response) in ring.iter().zip(&signature.responses) {
+        let commitment = (Affine::generator().mul(*response) - pk.mul(challenge)).into_affine();
+        challenge = ring_challenge(ring, m, &commitment);
+    }
+    challenge == signature.challenge
+    // SOLUTION-END
+}
+
+impl Bank {
+    pub fn commit<R: Rng>(&mut self, rng: &mut R) -> Affine {
+        // SOLUTION-BEGIN
+        let k = Fr::rand(rng);
+        self.nonce = Some(k);
+        Affine::generator().mul(k).into_affine()
+        // SOLUTION-END
+    }
+
+    pub fn respond(&mut self, challenge: Fr) -> Fr {
+        // SOLUTION-BEGIN
+        let k = self
+            .nonce
+            .take()
+            .expect("commit must be called before respond");
+        k + challenge * self.sk
+        // SOLUTION-END
+    }
+}
+
+impl BlindUser {
+    pub fn challenge<R: Rng>(&mut self, commitment: Affine, rng: &mut R) -> Fr {
+        // SOLUTION-BEGIN
+        let alpha = Fr::rand(rng);
+        let beta = Fr::rand(rng);
+        let blinded =
+            (commitment + Affine::generator().mul(alpha) + self.pk.mul(beta)).into_affine();
+        let blinded_challenge = hash_to_scalar_field(&(self.pk, blinded, self.message.as_slice()));
+        self.blinding = Some((alpha, beta, blinded));
+        blinded_challenge + beta
+        // SOLUTION-END
+    }
+
+    pub fn unblind(&mut self, response: Fr) -> SchnorrSignature {
+        // SOLUTION-BEGIN
+        let (alpha, _, blinded) = self
+            .blinding
+            .take()
+            .expect("challenge must be called before unblind");
+        let signature = SchnorrSignature {
+            commitment: blinded,
+            response: response + alpha,
+        };
+        assert!(
+            schnorr_verify(&self.pk, &self.message, &signature),
+            "the bank cheated"
+        );
+        signature
+        // SOLUTION-END
+    }
+}
+
+fn is_consistent(pk: &Affine, m: &[u8], view: &BankView, signature: &SchnorrSignature) -> bool {
+    // SOLUTION-BEGIN
+    // alpha = s' - s and beta = c - c' are forced, check that they map R to R'
+    let alpha = signature.response - view.response;
+    let beta = view.challenge - hash_to_scalar_field(&(*pk, signature.commitment, m));
+    view.commitment + Affine::generator().mul(alpha) + pk.mul(beta) == signature.commitment
+    // SOLUTION-END
+}
+
+fn prove_asset<R: Rng>(
+    params: &PedersenParams,
+    address: &Address,
+    sk: Option<Fr>,
+    rng: &mut R,
+) -> (Affine, Fr, OrProof) {
+    // SOLUTION-BEGIN
+    let blinding = Fr::rand(rng);
+    let (value, known, witnesses) = match sk {
+        Some(x) => (Fr::from(address.balance), 1, vec![x, blinding]),
+        None => (Fr::from(0), 0, vec![blinding]),
+    };
+    let commitment = params.commit(value, blinding);
+    let branches = asset_branches(params, address, &commitment);
+    let proof = or_prove(&branches, known, &witnesses, b"asset", rng);
+    (commitment, blinding, proof)
+    // SOLUTION-END
+}
+
+fn prove_solvency<R: Rng>(
+    params: &PedersenParams,
+    addresses: &[Address],
+    keys: &[Option<Fr>],
+    customers: &[u64],
+    rng: &mut R,
+) -> Option<(SolvencyProof, Vec<Fr>)> {
+    // SOLUTION-BEGIN
+    let mut surplus = Fr::from(0);
+    let mut surplus_blinding = Fr::from(0);
+    let mut asset_commitments = vec![];
+    let mut asset_proofs = vec![];
+    for (address, key) in addresses.iter().zip(keys) {
+        let (commitment, blinding, proof) = prove_asset(params, address, *key, rng);
+        asset_commitments.push(commitment);
+        asset_proofs.push(proof);
+        if key.is_some() {
+            surplus += Fr::from(address.balance);
+        }
+        surplus_blinding += blinding;
+    }
+    let mut liability_commitments = vec![];
+    let mut liability_proofs = vec![];
+    let mut blindings = vec![];
+    for balance in customers {
+        let blinding = Fr::rand(rng);
+        let value = Fr::from(*balance);
+        liability_commitments.push(params.commit(value, blinding));
+        liability_proofs.push(range_prove(params, value, blinding, BITS, rng)?);
+        blindings.push(blinding);
+        surplus -= value;
+        surplus_blinding -= blinding;
+    }
+    let surplus_proof = range_prove(params, surplus, surplus_blinding, BITS, rng)?;
+    Some((
+        SolvencyProof {
+            asset_commitments,
+            asset_proofs,
+            liability_commitments,
+            liability_proofs,
+            surplus_proof,
+        },
+        blindings,
+    ))
+    // SOLUTION-END
+}
+
+fn verify_solvency(params: &PedersenParams, addresses: &[Address], proof: &SolvencyProof) -> bool {
+    // SOLUTION-BEGIN
+    if proof.asset_commitments.len() != addresses.len()
+        || proof.asset_proofs.len() != addresses.len()
+        || proof.liability_proofs.len() != proof.liability_commitments.len()
+    {
+        return false;
+    }
+    let assets_ok = addresses
+        .iter()
+        .zip(&proof.asset_commitments)
+        .zip(&proof.asset_proofs)
+        .all(|((address, commitment), asset_proof)| {
+            or_verify(
+                &asset_branches(params, address, commitment),
+                asset_proof,
+                b"asset",
+            )
+        });
+    let liabilities_ok = proof
+        .liability_commitments
+        .iter()
+        .zip(&proof.liability_proofs)
+        .all(|(commitment, range)| range_verify(params, commitment, range, BITS));
+    let assets: Projective = proof.asset_commitments.iter().map(|c| c.into_group()).sum();
+    let liabilities: Projective = proof
+        .liability_commitments
+        .iter()
+        .map(|c| c.into_group())
+        .sum();
+    let surplus = (assets - liabilities).into_affine();
+    assets_ok && liabilities_ok && range_verify(params, &surplus, &proof.surplus_proof, BITS)
+    // SOLUTION-END
+}
+
+fn ecvrf_prove(sk: &Fr, alpha: &[u8]) -> EcvrfProof {
+    // SOLUTION-BEGIN
+    let pk = Affine::generator().mul(sk).into_affine();
+    let h = ecvrf_hash_to_curve(&pk, alpha);
+    let gamma = h.mul(sk).into_affine();
+    let k = ecvrf_nonce(sk, &h);
+    let u = Affine::generator().mul(k).into_affine();
+    let v = h.mul(k).into_affine();
+    let challenge = ecvrf_challenge([&pk, &h, &gamma, &u, &v]);
+    EcvrfProof {
+        gamma,
+        challenge,
+        response: k + challenge * sk,
+    }
+    // SOLUTION-END
+}
+
+fn ecvrf_verify(pk: &Affine, alpha: &[u8], proof: &EcvrfProof) -> Option<[u8; 32]> {
+    // SOLUTION-BEGIN
+    let h = ecvrf_hash_to_curve(pk, alpha);
+    // U = sG - cY, V = sH - c Gamma
+    let u = (Affine::generator().mul(proof.response) - pk.mul(proof.challenge)).into_affine();
+    let v = (h.mul(proof.response) - proof.gamma.mul(proof.challenge)).into_affine();
+    let challenge = ecvrf_challenge([pk, &h, &proof.gamma, &u, &v]);
+    (challenge == proof.challenge).then(|| ecvrf_proof_to_hash(proof))
+    // SOLUTION-END
+}
+
+fn grind_gamma(sk: &Fr, alpha: &[u8]) -> EcvrfProof {
+    // SOLUTION-BEGIN
+    let honest = ecvrf_prove(sk, alpha);
+    let mut gamma = honest.gamma;
+    loop {
+        gamma = (gamma + Affine::generator()).into_affine();
+        let cheat = EcvrfProof { gamma, ..honest };
+        if is_elected(&ecvrf_proof_to_hash(&cheat)) {
+            return cheat;
+        }
+    }
+    // SOLUTION-END
+}
+
+fn pvss_deal<R: Rng>(
+    secret: Fr,
+    threshold: usize,
+    public_keys: &[Affine],
+    rng: &mut R,
+) -> PvssTranscript {
+    // SOLUTION-BEGIN
+    let mut coefficients = vec![secret];
+    coefficients.extend((1..threshold).map(|_| Fr::rand(rng)));
+    let h = pvss_base();
+    let commitments = coefficients
+        .iter()
+        .map(|a| h.mul(a).into_affine())
+        .collect();
+    let (encrypted_shares, proofs) = public_keys
+        .iter()
+        .zip(1u64..)
+        .map(|(pk, index)| {
+            let x = Fr::from(index);
+            let share = coefficients
+                .iter()
+                .rev()
+                .fold(Fr::from(0), |acc, a| acc * x + a);
+            let statement = DleqStatement::new(h, *pk, share);
+            (statement.b, dleq_prove(&statement, share, rng))
+        })
+        .unzip();
+    PvssTranscript {
+        commitments,
+        encrypted_shares,
+        proofs,
+    }
+    // SOLUTION-END
+}
+
+fn pvss_verify_transcript(transcript: &PvssTranscript, public_keys: &[Affine]) -> bool {
+    // SOLUTION-BEGIN
+    if transcript.commitments.is_empty()
+        || transcript.encrypted_shares.len() != public_keys.len()
+        || transcript.proofs.len() != public_keys.len()
+    {
+        return false;
+    }
+    public_keys
+        .iter()
+        .zip(&transcript.encrypted_shares)
+        .zip(&transcript.proofs)
+        .zip(1u64..)
+        .all(|(((pk, encrypted), proof), index)| {
+            let statement = DleqStatement {
+                g: pvss_base(),
+                a: evaluate_commitments(&transcript.commitments, index),
+                h: *pk,
+                b: *encrypted,
+            };
+            dleq_verify(&statement, proof)
+        })
+    // SOLUTION-END
+}
+
+fn pvss_decrypt_share<R: Rng>(
+    party: &PvssParty,
+    transcript: &PvssTranscript,
+    rng: &mut R,
+) -> DecryptedShare {
+    // SOLUTION-BEGIN
+    let encrypted = transcript.encrypted_shares[party.index as usize - 1];
+    let share = encrypted.mul(party.sk.inverse().unwrap()).into_affine();
+    // Y_i = x_i G and E_i = x_i S_i
+    let statement = DleqStatement::new(Affine::generator(), share, party.sk);
+    DecryptedShare {
+        index: party.index,
+        share,
+        proof: dleq_prove(&statement, party.sk, rng),
+    }
+    // SOLUTION-END
+}
+
+fn pvss_verify_share(
+    public_key: &Affine,
+    encrypted_share: &Affine,
+    share: &DecryptedShare,
+) -> bool {
+    // SOLUTION-BEGIN
+    let statement = DleqStatement {
+        g: Affine::generator(),
+        a: *public_key,
+        h: share.share,
+        b: *encrypted_share,
+    };
+    dleq_verify(&statement, &share.proof)
+    // SOLUTION-END
+}
+
+fn pvss_reconstruct(shares: &[DecryptedShare]) -> Affine {
+    // SOLUTION-BEGIN
+    let indices: Vec<u64> = shares.iter().map(|share| share.index).collect();
+    shares
+        .iter()
+        .map(|share| share.share.mul(lagrange_coefficient(&indices, share.index)))
+        .sum::<Projective>()
+        .into_affine()
+    // SOLUTION-END
+}
+
+fn ipa_prove<B: HashBackend>(
+    g: &[Affine],
+    h: &[Affine],
+    u: &Affine,
+    p: &Affine,
+    a: &[Fr],
+    b: &[Fr],
+) -> InnerProductProof {
+    // SOLUTION-BEGIN
+    let (mut g, mut h, mut a, mut b) = (g.to_vec(), h.to_vec(), a.to_vec(), b.to_vec());
+    let mut challenge = B::challenge(p);
+    let (mut ls, mut rs) = (vec![], vec![]);
+    while a.len() > 1 {
+        let half = a.len() / 2;
+        let (a_lo, a_hi) = a.split_at(half);
+        let (b_lo, b_hi) = b.split_at(half);
+        let (g_lo, g_hi) = g.split_at(half);
+        let (h_lo, h_hi) = h.split_at(half);
+        let l = msm(g_hi, a_lo) + msm(h_lo, b_hi) + u.mul(inner_product(a_lo, b_hi));
+        let r = msm(g_lo, a_hi) + msm(h_hi, b_lo) + u.mul(inner_product(a_hi, b_lo));
+        let (l, r) = (l.into_affine(), r.into_affine());
+        challenge = ipa_challenge::<B>(challenge, &l, &r);
+        let (x, x_inv) = (challenge, challenge.inverse().unwrap());
+        let fold_points = |lo: &[Affine], hi: &[Affine], x_lo: Fr, x_hi: Fr| -> Vec<Affine> {
+            let folded: Vec<Projective> = lo
+                .iter()
+                .zip(hi)
+                .map(|(lo, hi)| lo.mul(x_lo) + hi.mul(x_hi))
+                .collect();
+            Projective::normalize_batch(&folded)
+        };
+        let fold_scalars = |lo: &[Fr], hi: &[Fr], x_lo: Fr, x_hi: Fr| -> Vec<Fr> {
+            lo.iter()
+                .zip(hi)
+                .map(|(lo, hi)| *lo * x_lo + *hi * x_hi)
+                .collect()
+        };
+        let next_g = fold_points(g_lo, g_hi, x_inv, x);
+        let next_h = fold_points(h_lo, h_hi, x, x_inv);
+        let next_a = fold_scalars(a_lo, a_hi, x, x_inv);
+        let next_b = fold_scalars(b_lo, b_hi, x_inv, x);
+        (g, h, a, b) = (next_g, next_h, next_a, next_b);
+        ls.push(l);
+        rs.push(r);
+    }
+    InnerProductProof {
+        l: ls,
+        r: rs,
+        a: a[0],
+        b: b[0],
+    }
+    // SOLUTION-END
+}
+
+fn ipa_verify<B: HashBackend>(
+    g: &[Affine],
+    h: &[Affine],
+    u: &Affine,
+    p: &Affine,
+    proof: &InnerProductProof,
+) -> bool {
+    // SOLUTION-BEGIN
+    if proof.l.len() != proof.r.len() || g.len() != 1 << proof.l.len() || h.len() != g.len() {
+        return false;
+    }
+    let (mut g, mut h) = (g.to_vec(), h.to_vec());
+    let mut p = p.into_group();
+    let mut challenge = B::challenge(&p.into_affine());
+    for (l, r) in proof.l.iter().zip(&proof.r) {
+        challenge = ipa_challenge::<B>(challenge, l, r);
+        let (x, x_inv) = (challenge, challenge.inverse().unwrap());
+        let half = g.len() / 2;
+        let next_g: Vec<Projective> = (0..half)
+            .map(|i| g[i].mul(x_inv) + g[half + i].mul(x))
+            .collect();
+        let next_h: Vec<Projective> = (0..half)
+            .map(|i| h[i].mul(x) + h[half + i].mul(x_inv))
+            .collect();
+        g = Projective::normalize_batch(&next_g);
+        h = Projective::normalize_batch(&next_h);
+        p += l.mul(x * x) + r.mul(x_inv * x_inv);
+    }
+    p == g[0].mul(proof.a) + h[0].mul(proof.b) + u.mul(proof.a * proof.b)
+    // SOLUTION-END
+}
+
+fn bulletproof_prove<B: HashBackend, R: Rng>(
+    params: &PedersenParams,
+    gens: &BulletproofsGens,
+    value: Fr,
+    blinding: Fr,
+    rng: &mut R,
+) -> Option<Bulletproof> {
+    // SOLUTION-BEGIN
+    let n = RANGE_BITS;
+    let limbs = value.into_bigint();
+    if (n..256).any(|i| limbs.get_bit(i)) {
+        return None;
+    }
+    let one = Fr::from(1);
+    let a_l: Vec<Fr> = (0..n).map(|i| Fr::from(limbs.get_bit(i) as u64)).collect();
+    let a_r: Vec<Fr> = a_l.iter().map(|bit| *bit - one).collect();
+    let s_l: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+    let s_r: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+    let (alpha, rho) = (Fr::rand(rng), Fr::rand(rng));
+    let commitment = params.commit(value, blinding);
+    let a = (params.h.mul(alpha) + msm(&gens.g, &a_l) + msm(&gens.h, &a_r)).into_affine();
+    let s = (params.h.mul(rho) + msm(&gens.g, &s_l) + msm(&gens.h, &s_r)).into_affine();
+    let (y, z) = range_challenges::<B>(&commitment, &a, &s);
+
+    // l(X) = l_0 + l_1 X and r(X) = r_0 + r_1 X
+    let y_n = powers(y, n);
+    let two_n = powers(Fr::from(2), n);
+    let l_0: Vec<Fr> = a_l.iter().map(|bit| *bit - z).collect();
+    let r_0: Vec<Fr> = (0..n)
+        .map(|i| y_n[i] * (a_r[i] + z) + z * z * two_n[i])
+        .collect();
+    let r_1: Vec<Fr> = (0..n).map(|i| y_n[i] * s_r[i]).collect();
+    let t_1 = inner_product(&l_0, &r_1) + inner_product(&s_l, &r_0);
+    let t_2 = inner_product(&s_l, &r_1);
+    let (tau_1, tau_2) = (Fr::rand(rng), Fr::rand(rng));
+    let t1 = params.commit(t_1, tau_1);
+    let t2 = params.commit(t_2, tau_2);
+    let x = range_challenge_x::<B>(z, &t1, &t2);
+
+    let l: Vec<Fr> = (0..n).map(|i| l_0[i] + s_l[i] * x).collect();
+    let r: Vec<Fr> = (0..n).map(|i| r_0[i] + r_1[i] * x).collect();
+    let t = inner_product(&l, &r);
+    let tau_x = tau_2 * x * x + tau_1 * x + z * z * blinding;
+    let mu = alpha + rho * x;
+    let u = gens
+        .u
+        .mul(range_challenge_w::<B>(x, t, tau_x, mu))
+        .into_affine();
+    let h_prime = scaled_h(gens, y);
+    let p = (msm(&gens.g, &l) + msm(&h_prime, &r) + u.mul(t)).into_affine();
+    Some(Bulletproof {
+        a,
+        s,
+        t1,
+        t2,
+        t,
+        tau_x,
+        mu,
+        ipp: ipa_prove::<B>(&gens.g, &h_prime, &u, &p, &l, &r),
+    })
+    // SOLUTION-END
+}
+
+fn bulletproof_verify<B: HashBackend>(
+    params: &PedersenParams,
+    gens: &BulletproofsGens,
+    commitment: &Affine,
+    proof: &Bulletproof,
+) -> bool {
+    // SOLUTION-BEGIN
+    let n = RANGE_BITS;
+    let (y, z) = range_challenges::<B>(commitment, &proof.a, &proof.s);
+    let x = range_challenge_x::<B>(z, &proof.t1, &proof.t2);
+    let lhs = params.commit(proof.t, proof.tau_x);
+    let rhs = commitment.mul(z * z)
+        + params.g.mul(delta(y, z, n))
+        + proof.t1.mul(x)
+        + proof.t2.mul(x * x);
+    if lhs != rhs {
+        return false;
+    }
+    let u = gens
+        .u
+        .mul(range_challenge_w::<B>(x, proof.t, proof.tau_x, proof.mu));
+    let h_prime = scaled_h(gens, y);
+    let y_n = powers(y, n);
+    let two_n = powers(Fr::from(2), n);
+    let h_exponents: Vec<Fr> = (0..n).map(|i| z * y_n[i] + z * z * two_n[i]).collect();
+    let g_sum: Projective = gens.g.iter().map(|g| g.into_group()).sum();
+    let p = proof.a + proof.s.mul(x) - g_sum.mul(z) + msm(&h_prime, &h_exponents)
+        - params.h.mul(proof.mu)
+        + u.mul(proof.t);
+    ipa_verify::<B>(
+        &gens.g,
+        &h_prime,
+        &u.into_affine(),
+        &p.into_affine(),
+        &proof.ipp,
+    )
+    // SOLUTION-END
+}
+
+fn prover_time<B: HashBackend, R: Rng>(
+    params: &PedersenParams,
+    gens: &BulletproofsGens,
+    runs: u32,
+    rng: &mut R,
+) -> Duration {
+    // SOLUTION-BEGIN
+    let start = Instant::now();
+    for _ in 0..runs {
+        let value = Fr::from(rng.gen::<u32>());
+        let blinding = Fr::rand(rng);
+        black_box(bulletproof_prove::<B, R>(params, gens, value, blinding, rng).unwrap());
+    }
+    start.elapsed() / runs
+    // SOLUTION-END
+}
+
+fn forge_opening(opening: &Opening, value: Fr) -> Opening {
+    // SOLUTION-BEGIN
+    // v G + r sG = v' G + r' sG with r' = r + (v - v') / s
+    let s = hash_to_scalar_field(&FLAWED_SEED.to_vec());
+    Opening {
+        value,
+        blinding: opening.blinding + (opening.value - value) * s.inverse().unwrap(),
+    }
+    // SOLUTION-END
+}
+
+fn pedersen_setup(domain: &[u8]) -> PedersenParams {
+    // SOLUTION-BEGIN
+    PedersenParams {
+        g: Affine::generator(),
+        h: hash_to_curve(domain),
+    }
+    // SOLUTION-END
+}
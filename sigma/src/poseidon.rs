@@ -0,0 +1,140 @@
+use crate::*;
+use ark_ff::BigInteger256;
+use std::sync::OnceLock;
+
+// Poseidon over the scalar field of secp256k1, as a backend of the Fiat-Shamir transcripts
+// (chapter hashes builds it step by step over the scalar field of BLS12-381)
+// - the permutation: t = 3 elements, x^5 S-box (gcd(5, n - 1) = 1), R_F = 8 full rounds around R_P = 57
+//   partial rounds, the round numbers of the 255-bit field of BLS12-381 with t = 3
+// - the constants come from the Grain LFSR of the reference implementation, seeded with these parameters,
+//   and the MDS matrix is a Cauchy matrix from the same stream
+// - the sponge absorbs the bytes by chunks of 31 bytes, two field elements per permutation, with the length
+//   of the input in the capacity element, and squeezes one field element
+// These parameters have not been reviewed: a toy instance for comparing speeds, not for production
+
+const WIDTH: usize = 3;
+const RATE: usize = 2;
+const FULL_ROUNDS: usize = 8;
+const PARTIAL_ROUNDS: usize = 57;
+const CHUNK: usize = 31;
+
+struct Parameters {
+    round_constants: Vec<[Fr; WIDTH]>,
+    mds: [[Fr; WIDTH]; WIDTH],
+}
+
+struct GrainLfsr {
+    state: [bool; 80],
+}
+
+impl GrainLfsr {
+    fn new() -> GrainLfsr {
+        let mut state = [true; 80];
+        // prime field, S-box x^alpha, n, t, R_F, R_P, then 30 bits set to 1
+        let fields = [
+            (1, 2),
+            (0, 4),
+            (Fr::MODULUS_BIT_SIZE as u64, 12),
+            (WIDTH as u64, 12),
+            (FULL_ROUNDS as u64, 10),
+            (PARTIAL_ROUNDS as u64, 10),
+        ];
+        let mut position = 0;
+        for (value, width) in fields {
+            for i in (0..width).rev() {
+                state[position] = (value >> i) & 1 == 1;
+                position += 1;
+            }
+        }
+        let mut lfsr = GrainLfsr { state };
+        for _ in 0..160 {
+            lfsr.update();
+        }
+        lfsr
+    }
+
+    fn update(&mut self) -> bool {
+        let s = &self.state;
+        let bit = s[62] ^ s[51] ^ s[38] ^ s[23] ^ s[13] ^ s[0];
+        self.state.copy_within(1.., 0);
+        self.state[79] = bit;
+        bit
+    }
+
+    fn next_bits(&mut self, count: usize) -> Vec<bool> {
+        let mut bits = Vec::with_capacity(count);
+        while bits.len() < count {
+            let keep = self.update();
+            let bit = self.update();
+            if keep {
+                bits.push(bit);
+            }
+        }
+        bits
+    }
+
+    fn next_field_element(&mut self) -> Fr {
+        loop {
+            let bits = self.next_bits(Fr::MODULUS_BIT_SIZE as usize);
+            if let Some(x) = Fr::from_bigint(BigInteger256::from_bits_be(&bits)) {
+                return x;
+            }
+        }
+    }
+}
+
+// generated once, on the first hash
+fn parameters() -> &'static Parameters {
+    static PARAMETERS: OnceLock<Parameters> = OnceLock::new();
+    PARAMETERS.get_or_init(|| {
+        let mut lfsr = GrainLfsr::new();
+        let round_constants = (0..FULL_ROUNDS + PARTIAL_ROUNDS)
+            .map(|_| [(); WIDTH].map(|_| lfsr.next_field_element()))
+            .collect();
+        let xs = [(); WIDTH].map(|_| lfsr.next_field_element());
+        let ys = [(); WIDTH].map(|_| lfsr.next_field_element());
+        let mds = xs.map(|x| ys.map(|y| (x + y).inverse().unwrap()));
+        Parameters {
+            round_constants,
+            mds,
+        }
+    })
+}
+
+fn permute(state: &mut [Fr; WIDTH]) {
+    let parameters = parameters();
+    let sbox = |x: Fr| x.square().square() * x;
+    for (round, constants) in parameters.round_constants.iter().enumerate() {
+        for (x, c) in state.iter_mut().zip(constants) {
+            *x += c;
+        }
+        let partial = (FULL_ROUNDS / 2..FULL_ROUNDS / 2 + PARTIAL_ROUNDS).contains(&round);
+        if !partial {
+            *state = state.map(sbox);
+        } else {
+            state[0] = sbox(state[0]);
+        }
+        *state = parameters
+            .mds
+            .map(|row| row.iter().zip(state.iter()).map(|(m, x)| *m * x).sum());
+    }
+}
+
+pub fn poseidon(input: &[u8]) -> Fr {
+    let mut state = [Fr::from(input.len() as u64), Fr::from(0), Fr::from(0)];
+    let elements: Vec<Fr> = input
+        .chunks(CHUNK)
+        .map(Fr::from_le_bytes_mod_order)
+        .collect();
+    // the empty input still goes through one permutation
+    for block in elements
+        .chunks(RATE)
+        .chain(elements.is_empty().then_some(&[][..]))
+    {
+        for (x, element) in state[1..].iter_mut().zip(block) {
+            *x += element;
+        }
+        permute(&mut state);
+    }
+    state[1]
+}
@@ -0,0 +1,70 @@
+use crate::*;
+
+// Publicly verifiable secret sharing (PVSS), Schoenmakers' scheme (Crypto '99)
+// The shares are encrypted under the public keys of the parties and published, with proofs that anyone
+// can check: the dealer cannot hand out inconsistent shares, and the parties cannot lie about their shares
+// Two independent generators: G, and H = hash_to_curve("pvss") (nobody knows log_G H)
+// - party i = 1..n has a key pair (x_i, Y_i = x_i G)
+// - distribution: the dealer picks a random polynomial p of degree t - 1 and publishes
+//   - the commitments C_j = a_j H to the coefficients of p
+//   - the encrypted shares E_i = p(i) Y_i
+//   - a DLEQ proof that log_H X_i = log_(Y_i) E_i, where X_i = sum_j i^j C_j = p(i) H can be computed by anyone
+// - decryption: party i publishes S_i = x_i^-1 E_i = p(i) G, with a DLEQ proof that log_G Y_i = log_(S_i) E_i
+// - reconstruction: Lagrange interpolation "in the exponent", sum lambda_i S_i = p(0) G
+// The secret is the point sG, not s: it is meant to be hashed into a key (or a random beacon output)
+
+pub struct PvssParty {
+    pub index: u64, // i, the share is p(i)
+    pub sk: Fr,     // x_i
+    pub pk: Affine, // Y_i
+}
+
+// everything the dealer publishes, the shares being ordered by party index
+#[derive(Clone, Debug)]
+pub struct PvssTranscript {
+    pub commitments: Vec<Affine>,      // C_j
+    pub encrypted_shares: Vec<Affine>, // E_i
+    pub proofs: Vec<DleqProof>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct DecryptedShare {
+    pub index: u64,
+    pub share: Affine, // S_i
+    pub proof: DleqProof,
+}
+
+impl PvssParty {
+    pub fn new<R: Rng>(index: u64, rng: &mut R) -> PvssParty {
+        let sk = Fr::rand(rng);
+        PvssParty {
+            index,
+            sk,
+            pk: Affine::generator().mul(sk).into_affine(),
+        }
+    }
+}
+
+pub fn pvss_base() -> Affine {
+    hash_to_curve(b"pvss")
+}
+
+// X_i = sum_j i^j C_j
+pub fn evaluate_commitments(commitments: &[Affine], index: u64) -> Affine {
+    commitments
+        .iter()
+        .rev()
+        .fold(Projective::default(), |acc, commitment| {
+            acc * Fr::from(index) + commitment
+        })
+        .into_affine()
+}
+
+// lambda_i = prod_{j != i} j / (j - i), for the interpolation at 0 from the parties `indices`
+pub fn lagrange_coefficient(indices: &[u64], i: u64) -> Fr {
+    indices
+        .iter()
+        .filter(|j| **j != i)
+        .map(|j| Fr::from(*j) / (Fr::from(*j) - Fr::from(i)))
+        .product()
+}
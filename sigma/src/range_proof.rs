@@ -0,0 +1,209 @@
+use crate::*;
+use ark_ec::Group;
+use ark_ff::BigInteger;
+use ark_secp256k1::Projective;
+use ark_std::Zero;
+
+// Pedersen commitments over secp256k1: C = vG + rH, with H from `hash_to_curve` so that nobody knows log_G(H)
+pub struct PedersenParams {
+    pub g: Affine,
+    pub h: Affine,
+}
+
+impl PedersenParams {
+    pub fn new() -> PedersenParams {
+        PedersenParams {
+            g: Affine::generator(),
+            h: hash_to_curve(b"Pedersen commitment generator H"),
+        }
+    }
+
+    pub fn commit(&self, value: Fr, blinding: Fr) -> Affine {
+        (self.g.mul(value) + self.h.mul(blinding)).into_affine()
+    }
+}
+
+// A relation (base, target): "I know w such that target = w * base"
+pub type Relation = (Affine, Affine);
+
+// Proof of knowledge for *all* relations of one of the two branches (Cramer-Damgard-Schoenmakers OR-proof)
+// The prover simulates the branch she has no witness for, and the challenges of the two branches
+// must add up to the Fiat-Shamir challenge, so she can only choose one of them freely
+#[derive(Clone, Debug)]
+pub struct OrProof {
+    pub challenges: [Fr; 2],
+    pub responses: [Vec<Fr>; 2],
+}
+
+fn or_challenge(branches: &[Vec<Relation>; 2], commitments: &[Affine], context: &[u8]) -> Fr {
+    let points: Vec<Affine> = branches
+        .iter()
+        .flatten()
+        .flat_map(|(base, target)| [*base, *target])
+        .chain(commitments.iter().copied())
+        .collect();
+    hash_to_scalar_field(&(points, context))
+}
+
+pub fn or_prove<R: Rng>(
+    branches: &[Vec<Relation>; 2],
+    known: usize,
+    witnesses: &[Fr],
+    context: &[u8],
+    rng: &mut R,
+) -> OrProof {
+    let other = 1 - known;
+    let nonces: Vec<Fr> = witnesses.iter().map(|_| Fr::rand(rng)).collect();
+    let simulated_challenge = Fr::rand(rng);
+    let simulated_responses: Vec<Fr> = branches[other].iter().map(|_| Fr::rand(rng)).collect();
+    let mut commitments = [vec![], vec![]];
+    commitments[known] = branches[known]
+        .iter()
+        .zip(&nonces)
+        .map(|((base, _), r)| base.mul(r).into_affine())
+        .collect();
+    commitments[other] = branches[other]
+        .iter()
+        .zip(&simulated_responses)
+        .map(|((base, target), s)| (base.mul(s) - target.mul(simulated_challenge)).into_affine())
+        .collect();
+    let challenge = or_challenge(branches, &commitments.concat(), context);
+    let mut challenges = [Fr::zero(); 2];
+    challenges[other] = simulated_challenge;
+    challenges[known] = challenge - simulated_challenge;
+    let mut responses = [vec![], vec![]];
+    responses[known] = nonces
+        .iter()
+        .zip(witnesses)
+        .map(|(r, w)| *r + challenges[known] * w)
+        .collect();
+    responses[other] = simulated_responses;
+    OrProof {
+        challenges,
+        responses,
+    }
+}
+
+pub fn or_verify(branches: &[Vec<Relation>; 2], proof: &OrProof, context: &[u8]) -> bool {
+    let mut commitments = vec![];
+    for (b, branch) in branches.iter().enumerate() {
+        if branch.len() != proof.responses[b].len() {
+            return false;
+        }
+        for ((base, target), s) in branch.iter().zip(&proof.responses[b]) {
+            commitments.push((base.mul(s) - target.mul(proof.challenges[b])).into_affine());
+        }
+    }
+    proof.challenges[0] + proof.challenges[1] == or_challenge(branches, &commitments, context)
+}
+
+// Range proof by bit decomposition: C = vG + rH with 0 <= v < 2^bits
+// - the prover commits to every bit C_i = b_i G + r_i H and proves b_i in {0, 1} with an OR-proof
+//   (C_i = r_i H or C_i - G = r_i H)
+// - then she proves that C - sum 2^i C_i = (r - sum 2^i r_i) H, i.e. that the bits add up to v
+// The proof size is linear in the number of bits, see module `bulletproofs` for logarithmic ones
+#[derive(Clone, Debug)]
+pub struct RangeProof {
+    pub bit_commitments: Vec<Affine>,
+    pub bit_proofs: Vec<OrProof>,
+    pub consistency: SchnorrProof, // proof of knowledge of log_H(C - sum 2^i C_i)
+}
+
+impl RangeProof {
+    pub fn compressed_size(&self) -> usize {
+        let points = self.bit_commitments.len() + 1;
+        let scalars = self
+            .bit_proofs
+            .iter()
+            .map(|proof| 2 + proof.responses[0].len() + proof.responses[1].len())
+            .sum::<usize>()
+            + 1;
+        points * self.consistency.commitment.compressed_size()
+            + scalars * self.consistency.response.compressed_size()
+    }
+}
+
+fn bit_branches(params: &PedersenParams, bit_commitment: &Affine) -> [Vec<Relation>; 2] {
+    [
+        vec![(params.h, *bit_commitment)],
+        vec![(params.h, (*bit_commitment - params.g).into_affine())],
+    ]
+}
+
+fn consistency_target(commitment: &Affine, bits: &[Affine]) -> Affine {
+    let mut sum = Projective::zero();
+    for bit in bits.iter().rev() {
+        sum = sum.double() + bit;
+    }
+    (commitment.into_group() - sum).into_affine()
+}
+
+// `None` if the value does not fit in `bits` bits: there is no honest way to prove it then
+pub fn range_prove<R: Rng>(
+    params: &PedersenParams,
+    value: Fr,
+    blinding: Fr,
+    bits: usize,
+    rng: &mut R,
+) -> Option<RangeProof> {
+    let limbs = value.into_bigint();
+    if (bits..256).any(|i| limbs.get_bit(i)) {
+        return None;
+    }
+    let commitment = params.commit(value, blinding);
+    let mut bit_commitments = vec![];
+    let mut bit_proofs = vec![];
+    let mut blinding_sum = Fr::zero();
+    for i in 0..bits {
+        let bit = limbs.get_bit(i) as usize;
+        let r = Fr::rand(rng);
+        let bit_commitment = params.commit(Fr::from(bit as u64), r);
+        let branches = bit_branches(params, &bit_commitment);
+        bit_proofs.push(or_prove(&branches, bit, &[r], b"range proof bit", rng));
+        bit_commitments.push(bit_commitment);
+        blinding_sum += Fr::from(2u64).pow([i as u64]) * r;
+    }
+    let target = consistency_target(&commitment, &bit_commitments);
+    let consistency = prove_dlog(&params.h, &target, blinding - blinding_sum, rng);
+    Some(RangeProof {
+        bit_commitments,
+        bit_proofs,
+        consistency,
+    })
+}
+
+pub fn range_verify(
+    params: &PedersenParams,
+    commitment: &Affine,
+    proof: &RangeProof,
+    bits: usize,
+) -> bool {
+    proof.bit_commitments.len() == bits
+        && proof.bit_proofs.len() == bits
+        && proof
+            .bit_commitments
+            .iter()
+            .zip(&proof.bit_proofs)
+            .all(|(c, p)| or_verify(&bit_branches(params, c), p, b"range proof bit"))
+        && verify_dlog(
+            &params.h,
+            &consistency_target(commitment, &proof.bit_commitments),
+            &proof.consistency,
+        )
+}
+
+// Fiat-Shamir Schnorr proof of knowledge of w such that target = w * base
+pub fn prove_dlog<R: Rng>(base: &Affine, target: &Affine, w: Fr, rng: &mut R) -> SchnorrProof {
+    let r = Fr::rand(rng);
+    let commitment = base.mul(r).into_affine();
+    let challenge = hash_to_scalar_field(&(*base, *target, commitment));
+    SchnorrProof {
+        commitment,
+        response: r + challenge * w,
+    }
+}
+
+pub fn verify_dlog(base: &Affine, target: &Affine, proof: &SchnorrProof) -> bool {
+    let challenge = hash_to_scalar_field(&(*base, *target, proof.commitment));
+    base.mul(proof.response) == proof.commitment + target.mul(challenge)
+}
@@ -0,0 +1,18 @@
+use crate::*;
+
+// Abe-Ohkubo-Suzuki ring signatures: a member of a ring of public keys X_0, ..., X_{n-1} signs a message
+// without revealing which member she is
+// The signature is a "ring" of Schnorr challenges c_0, ..., c_{n-1} where each challenge is computed
+// from the previous one: c_{i+1} = H(ring, m, s_i G - c_i X_i) (indices mod n)
+// Only the actual signer j can close the ring, since s_j = r + c_j x_j requires the secret key x_j
+// The other responses are uniformly random, so nothing singles out the signer
+#[derive(Clone, Debug)]
+pub struct RingSignature {
+    pub challenge: Fr,      // c_0
+    pub responses: Vec<Fr>, // s_0, ..., s_{n-1}
+}
+
+// c = H(ring, m, R)
+pub fn ring_challenge(ring: &[Affine], m: &[u8], commitment: &Affine) -> Fr {
+    hash_to_scalar_field(&(ring.to_vec(), m, *commitment))
+}
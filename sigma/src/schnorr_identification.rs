@@ -0,0 +1,99 @@
+use crate::*;
+
+// The Schnorr identification protocol: the prover convinces the verifier that she knows x such that X = xG
+//
+//   Prover(x)                          Verifier(X)
+//   r unif. random, R := rG   --R-->
+//                             <--c--   c unif. random
+//   s := r + cx               --s-->
+//                                      sG = R + cX ?
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transcript {
+    pub commitment: Affine, // R
+    pub challenge: Fr,      // c
+    pub response: Fr,       // s
+}
+
+// the prover keeps her secret key and, between the two moves, the nonce behind the commitment
+// it derives `Clone` so that the harness can "rewind" it, which is what the soundness extractor needs
+#[derive(Clone)]
+pub struct Prover {
+    pub sk: Fr,
+    pub nonce: Option<Fr>,
+}
+
+pub struct Verifier {
+    pub pk: Affine,
+    pub commitment: Option<Affine>,
+    pub challenge: Option<Fr>,
+}
+
+// non-interactive proof of knowledge of the discrete log of the public key
+#[derive(Clone, Copy, Debug)]
+pub struct SchnorrProof {
+    pub commitment: Affine, // R
+    pub response: Fr,       // s
+}
+
+pub fn keygen<R: Rng>(rng: &mut R) -> (Fr, Affine) {
+    let sk = Fr::rand(rng);
+    let pk = Affine::generator().mul(sk).into_affine();
+    (sk, pk)
+}
+
+// hash the inputs with SHA-256 into the scalar field of secp256k1
+// used to derive the Fiat-Shamir challenge
+pub fn hash_to_scalar_field<C: CanonicalSerialize>(input: &C) -> Fr {
+    let bytes = input.hash::<sha2::Sha256>();
+    Fr::from_be_bytes_mod_order(&bytes)
+}
+
+// the verification equation shared by the interactive and non-interactive variants
+pub fn is_accepting(pk: &Affine, transcript: &Transcript) -> bool {
+    let lhs = Affine::generator().mul(transcript.response);
+    let rhs = transcript.commitment + pk.mul(transcript.challenge);
+    lhs == rhs
+}
+
+impl Prover {
+    pub fn new(sk: Fr) -> Prover {
+        Prover { sk, nonce: None }
+    }
+}
+
+impl Verifier {
+    pub fn new(pk: Affine) -> Verifier {
+        Verifier {
+            pk,
+            commitment: None,
+            challenge: None,
+        }
+    }
+}
+
+// run the three moves between an honest prover and an honest verifier
+pub fn run_protocol<R: Rng>(
+    prover: &mut Prover,
+    verifier: &mut Verifier,
+    rng: &mut R,
+) -> (Transcript, bool) {
+    let commitment = prover.commit(rng);
+    let challenge = verifier.challenge(commitment, rng);
+    let response = prover.respond(challenge);
+    let transcript = Transcript {
+        commitment,
+        challenge,
+        response,
+    };
+    (transcript, verifier.verify(response))
+}
+
+// The same three moves over a `Channel` of the training-common crate (module `protocol_sim`), each party only
+// reading and writing its own end of the link: an adversary on the link can then drop, replay or rewrite them
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IdentificationMessage {
+    Commitment(Affine),
+    Challenge(Fr),
+    Response(Fr),
+}
@@ -0,0 +1,46 @@
+use crate::*;
+
+// Provisions-style proof of solvency
+// The exchange proves that the bitcoins it controls add up to at least what it owes its customers, without
+// revealing which addresses it owns, how much it holds, how many customers it has nor their balances
+//
+// - Assets: the blockchain gives a public list of addresses (public keys) with their balances. The exchange
+//   hides the ones it owns in a larger anonymity set: for each address i it publishes P_i = s_i bal_i G + v_i H
+//   with s_i = 1 if it owns the address, 0 otherwise, and proves that either P_i commits to 0
+//   or it knows the secret key of the address and P_i commits to bal_i
+// - Liabilities: for each customer j it publishes L_j = c_j G + r_j H with a range proof 0 <= c_j < 2^BITS,
+//   so that negative balances cannot be used to shrink the total; each customer checks her own commitment
+// - Solvency: sum P_i - sum L_j commits to assets - liabilities, and the exchange proves that it lies in [0, 2^BITS)
+
+pub const BITS: usize = 32;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Address {
+    pub pk: Affine,
+    pub balance: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct SolvencyProof {
+    pub asset_commitments: Vec<Affine>,
+    pub asset_proofs: Vec<OrProof>,
+    pub liability_commitments: Vec<Affine>,
+    pub liability_proofs: Vec<RangeProof>,
+    pub surplus_proof: RangeProof,
+}
+
+// the two branches proven for address i: "P_i = v H" or "Y_i = x G and P_i - bal_i G = v H"
+pub fn asset_branches(
+    params: &PedersenParams,
+    address: &Address,
+    commitment: &Affine,
+) -> [Vec<Relation>; 2] {
+    let balance = params.g.mul(Fr::from(address.balance));
+    [
+        vec![(params.h, *commitment)],
+        vec![
+            (params.g, address.pk),
+            (params.h, (commitment.into_group() - balance).into_affine()),
+        ],
+    ]
+}
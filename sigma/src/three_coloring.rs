@@ -0,0 +1,106 @@
+use crate::*;
+use sha2::{Digest, Sha256};
+
+// Zero-knowledge proof that a graph is 3-colorable (Goldreich-Micali-Wigderson)
+// Graph 3-coloring is NP-complete: any NP statement (a Sudoku solution, a circuit witness...)
+// can be reduced to it, so this protocol is a zero-knowledge proof for all of NP
+//
+// One round:
+//   Prover(coloring)                                        Verifier(graph)
+//   permute the 3 colors at random,
+//   commit to the color of every vertex   --commitments-->
+//                                         <--edge (u,v)--   pick a random edge
+//   open the commitments of u and v       --openings-->
+//                                                           openings are valid and colors differ ?
+// A cheating prover is caught with probability at least 1/|E| per round, so many rounds are needed
+
+pub type Commitment = [u8; 32];
+
+pub struct Graph {
+    pub vertex_count: usize,
+    pub edges: Vec<(usize, usize)>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ColorOpening {
+    pub color: u8,
+    pub blinding: [u8; 32],
+}
+
+pub struct ColoringProver {
+    pub coloring: Vec<u8>,
+    // permuted colors and blindings of the current round, between commitment and response
+    pub round: Option<Vec<ColorOpening>>,
+}
+
+pub struct ColoringVerifier {
+    pub graph: Graph,
+    pub commitments: Option<Vec<Commitment>>,
+    pub edge: Option<(usize, usize)>,
+}
+
+// hash-based commitment to a color
+// hiding thanks to the random blinding, binding thanks to the collision resistance of SHA-256
+pub fn commit_color(opening: &ColorOpening) -> Commitment {
+    Sha256::new()
+        .chain_update([opening.color])
+        .chain_update(opening.blinding)
+        .finalize()
+        .into()
+}
+
+// a random graph together with one of its proper 3-colorings
+pub fn random_colorable_graph<R: Rng>(
+    vertex_count: usize,
+    edge_count: usize,
+    rng: &mut R,
+) -> (Graph, Vec<u8>) {
+    let coloring: Vec<u8> = (0..vertex_count).map(|_| rng.gen_range(0..3)).collect();
+    let mut edges = Vec::new();
+    while edges.len() < edge_count {
+        let u = rng.gen_range(0..vertex_count);
+        let v = rng.gen_range(0..vertex_count);
+        if coloring[u] != coloring[v] && !edges.contains(&(u, v)) && !edges.contains(&(v, u)) {
+            edges.push((u, v));
+        }
+    }
+    (
+        Graph {
+            vertex_count,
+            edges,
+        },
+        coloring,
+    )
+}
+
+impl ColoringProver {
+    pub fn new(coloring: Vec<u8>) -> ColoringProver {
+        ColoringProver {
+            coloring,
+            round: None,
+        }
+    }
+}
+
+impl ColoringVerifier {
+    pub fn new(graph: Graph) -> ColoringVerifier {
+        ColoringVerifier {
+            graph,
+            commitments: None,
+            edge: None,
+        }
+    }
+}
+
+// one round of the protocol, returns the opened colors and the verifier's decision
+pub fn run_coloring_round<R: Rng>(
+    prover: &mut ColoringProver,
+    verifier: &mut ColoringVerifier,
+    rng: &mut R,
+) -> ((u8, u8), bool) {
+    let commitments = prover.commit(rng);
+    let edge = verifier.challenge(commitments, rng);
+    let openings = prover.respond(edge);
+    let colors = (openings.0.color, openings.1.color);
+    (colors, verifier.verify(openings))
+}
@@ -0,0 +1,95 @@
+use crate::*;
+use sha2::{Digest, Sha256};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// The hash of the Fiat-Shamir transcripts is a parameter of the protocols which pick it: the prover and the
+// verifier must agree on it, a proof made with one backend is rejected by the verifier of another
+// - SHA-256, the default, what `hash_to_scalar_field` computes
+// - BLAKE3: a tree of compressions of 7 rounds, built for SIMD (see module `blake3`, portable and without
+//   SIMD, while SHA-256 may run on the SHA extensions of the CPU)
+// - Poseidon, slow in software but a few hundred constraints in a circuit (see module `poseidon`)
+// A challenge hashes the compressed serialization of its inputs, then maps the hash into the scalar field
+
+pub trait HashBackend {
+    const NAME: &'static str;
+
+    fn hash_to_field(bytes: &[u8]) -> Fr;
+
+    fn challenge<C: CanonicalSerialize>(input: &C) -> Fr {
+        let mut bytes = Vec::with_capacity(input.compressed_size());
+        input.serialize_compressed(&mut bytes).unwrap();
+        Self::hash_to_field(&bytes)
+    }
+}
+
+pub struct Sha256Backend;
+pub struct Blake3Backend;
+pub struct PoseidonBackend;
+
+impl HashBackend for Sha256Backend {
+    const NAME: &'static str = "SHA-256";
+
+    fn hash_to_field(bytes: &[u8]) -> Fr {
+        Fr::from_be_bytes_mod_order(&Sha256::digest(bytes))
+    }
+}
+
+impl HashBackend for Blake3Backend {
+    const NAME: &'static str = "BLAKE3";
+
+    fn hash_to_field(bytes: &[u8]) -> Fr {
+        Fr::from_be_bytes_mod_order(&blake3::blake3(bytes))
+    }
+}
+
+impl HashBackend for PoseidonBackend {
+    const NAME: &'static str = "Poseidon";
+
+    fn hash_to_field(bytes: &[u8]) -> Fr {
+        poseidon::poseidon(bytes)
+    }
+}
+
+// the challenge of each backend on the transcript (G, 42), big-endian in hexadecimal, which pins the
+// serialization, the hash and the reduction into the field
+pub const CHALLENGE_VECTORS: [(&str, &str); 3] = [
+    (
+        "SHA-256",
+        "8f49f5bdc25fb4c9faa31c722e1612bd9f1bc219e20eda381439afe5f7c1ddc8",
+    ),
+    (
+        "BLAKE3",
+        "b542bcc56922ba974376ae3fc88f4c981339fbefe2529e501c5713e0c1200cfd",
+    ),
+    (
+        "Poseidon",
+        "cac0f9357a610f437dae8aad67e3ce96d27af036ba2966714a0bb47a2a1a8faf",
+    ),
+];
+
+pub fn vector_transcript() -> (Affine, Fr) {
+    (Affine::generator(), Fr::from(42))
+}
+
+// the proofs timed per backend in Q33
+pub const PROVER_RUNS: u32 = 20;
+const CHALLENGE_RUNS: u32 = 1000;
+
+// the average time of one challenge on three points of a range proof, the size of most of its transcripts
+pub fn challenge_time<B: HashBackend>(proof: &Bulletproof) -> Duration {
+    let transcript = (proof.a, proof.s, proof.t1);
+    let start = Instant::now();
+    for _ in 0..CHALLENGE_RUNS {
+        black_box(B::challenge(black_box(&transcript)));
+    }
+    start.elapsed() / CHALLENGE_RUNS
+}
+
+pub fn to_hex_bytes(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+pub fn to_hex(x: Fr) -> String {
+    to_hex_bytes(&x.into_bigint().to_bytes_be())
+}
@@ -0,0 +1,19 @@
+use runner::lang::{Catalog, Lang, Statements};
+
+pub const CATALOG: Catalog = Catalog {
+    lang: Lang::Fr,
+    statements: Statements::Table(&[
+        ("Q1", "écrire `typed_sign` et `typed_verify` d'ECDSA avec seulement `Scalar` et `Coordinate`, en partant du\ncorps de `untyped_sign`, et laisser le compilateur montrer ses erreurs"),
+        ("Q2", "la réduction manquante de r dans `untyped_sign` a passé 100 tests aléatoires : pour quels nonces se\nvoit-elle, et à quelle fréquence ? Où ailleurs les deux corps se rencontrent-ils dans les exercices, du défi de BIP340\naux clés x-only ? Les types n'empêchent pas toutes les confusions : quelles conversions passent encore par des entiers ?"),
+    ]),
+    hints: &[
+        (
+            "Q1",
+            &[
+                "`k * Affine::generator()` est kG, et `Coordinate::x` donne son x, ou `None` à l'infini",
+                "r * x ne compile pas tant que r est une `Coordinate` : le passage est `reduce_mod_n`, une fois",
+                "Vérification : w = s^-1, puis x(zw G + rw Q) réduit mod n doit valoir r, avec r non nul",
+            ],
+        ),
+    ],
+};
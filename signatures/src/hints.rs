@@ -0,0 +1,16 @@
+use runner::hints::Hints;
+use runner::lang::Catalog;
+
+pub const CATALOGS: &[Catalog] = &[
+    Catalog::english(HINTS, include_str!("main.rs")),
+    crate::fr::CATALOG,
+];
+
+pub const HINTS: Hints = &[(
+    "Q1",
+    &[
+        "`k * Affine::generator()` is kG, and `Coordinate::x` gives its x, or `None` at infinity",
+        "r * x does not compile while r is a `Coordinate`: the crossing is `reduce_mod_n`, once",
+        "Verifying: w = s^-1, then x(zw G + rw Q) reduced mod n must be r, with r not zero",
+    ],
+)];
@@ -0,0 +1,78 @@
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_secp256k1::{Affine, Fq, Fr};
+use ark_std::{ops::Mul, One, UniformRand, Zero};
+use num_bigint::BigUint;
+use runner::Runner;
+use typed::*;
+
+mod fr;
+mod hints;
+mod typed;
+
+fn main() {
+    let runner = Runner::start(env!("CARGO_PKG_NAME"), hints::CATALOGS);
+    let mut rng = seed::rng();
+
+    // The signatures of the other chapters, ECDSA first, from the side of their implementation: the types
+    // which keep their formulas in the right field
+
+    // Fr or Fq? Take a look at module `typed`
+    // Q1: write ECDSA's `typed_sign` and `typed_verify` with `Scalar` and `Coordinate` only, starting from the
+    // body of `untyped_sign`, and let the compiler point at its mistakes
+    runner.question("Q1");
+    let bytes = |x: &Fr| x.into_bigint().to_bytes_be();
+    for _ in 0..100 {
+        let (x, z, k) = (Fr::rand(&mut rng), Fr::rand(&mut rng), Fr::rand(&mut rng));
+        let public_key = Affine::generator().mul(x).into_affine();
+        let (r, s) = typed_sign(Scalar::new(x), Scalar::new(z), Scalar::new(k)).unwrap();
+        // checked without the wrappers: x(z/s G + r/s Q) = r mod n
+        let w = s.value().inverse().unwrap();
+        let point = (Affine::generator().mul(z * w) + public_key.mul(r.value() * w)).into_affine();
+        assert_eq!(
+            Fr::from_le_bytes_mod_order(&point.x.into_bigint().to_bytes_le()),
+            r.value()
+        );
+        assert!(typed_verify(&public_key, Scalar::new(z), (r, s)));
+        assert!(!typed_verify(
+            &public_key,
+            Scalar::new(z + Fr::one()),
+            (r, s)
+        ));
+        assert!(!typed_verify(&public_key, Scalar::new(z), (r, -s - s)));
+        // s computed mod p does not verify
+        let to_biguint = |x: &Fr| BigUint::from_bytes_be(&bytes(x));
+        let (untyped_r, untyped_s) =
+            untyped_sign(&to_biguint(&x), &to_biguint(&z), &to_biguint(&k));
+        let untyped = (
+            Scalar::from_be_bytes(&untyped_r.to_bytes_be()),
+            Scalar::from_be_bytes(&untyped_s.to_bytes_be()),
+        );
+        assert!(!typed_verify(&public_key, Scalar::new(z), untyped));
+    }
+    // Q2: the missing reduction of r in `untyped_sign` passed 100 random tests: for which nonces does it
+    // show, and how often? Where else do the two fields meet in the exercises, from BIP340's challenge to
+    // the x-only keys? Types do not stop every confusion: which conversions still go through integers?
+    runner.question("Q2");
+
+    println!("Good job! 🏴‍☠️");
+    runner.finish();
+}
+
+fn typed_sign(x: Scalar, z: Scalar, k: Scalar) -> Option<(Scalar, Scalar)> {
+    // SOLUTION-BEGIN
+    let r = Coordinate::x(&(k * Affine::generator()))?.reduce_mod_n();
+    let s = k.inverse()? * (z + r * x);
+    (!r.is_zero() && !s.is_zero()).then_some((r, s))
+    // SOLUTION-END
+}
+
+fn typed_verify(public_key: &Affine, z: Scalar, (r, s): (Scalar, Scalar)) -> bool {
+    // SOLUTION-BEGIN
+    let Some(w) = s.inverse() else {
+        return false;
+    };
+    let point = ((z * w) * Affine::generator() + (r * w) * *public_key).into_affine();
+    !r.is_zero() && Coordinate::x(&point).is_some_and(|x| x.reduce_mod_n() == r)
+    // SOLUTION-END
+}
@@ -0,0 +1,138 @@
+use crate::*;
+use std::ops::{Add, Mul, Neg, Sub};
+
+// Two fields of 256 bits live side by side on secp256k1: Fq, where the coordinates of the points are, and
+// Fr, the integers mod the order n of the group, where the private keys, the nonces and the scalars are.
+// p and n are so close (n < p < n + 2^129) that a value of one field almost always fits in the other:
+// code which moves numbers between them through integers or bytes compiles, and its tests pass, until the
+// rare value on which the two reductions differ, or the next formula computed in the wrong field
+// ECDSA crosses on purpose once, r = x(kG) mod n: a coordinate becomes a scalar
+//
+// The newtypes `Scalar` and `Coordinate` below let the compiler tell them apart:
+// - a scalar adds to and multiplies scalars, and multiplies points
+// - a coordinate adds to and multiplies coordinates, and comes out of a point
+// - `Coordinate::reduce_mod_n` is the single way from one to the other, visible at the place of the crossing
+// Nothing else: no `From` between them, no way into a coordinate but out of a point
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Scalar(Fr);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Coordinate(Fq);
+
+impl Scalar {
+    pub fn new(value: Fr) -> Scalar {
+        Scalar(value)
+    }
+
+    // a hash or any other integer, reduced mod n
+    pub fn from_be_bytes(bytes: &[u8]) -> Scalar {
+        Scalar(Fr::from_be_bytes_mod_order(bytes))
+    }
+
+    pub fn value(&self) -> Fr {
+        self.0
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn inverse(&self) -> Option<Scalar> {
+        self.0.inverse().map(Scalar)
+    }
+}
+
+impl Add for Scalar {
+    type Output = Scalar;
+
+    fn add(self, other: Scalar) -> Scalar {
+        Scalar(self.0 + other.0)
+    }
+}
+
+impl Sub for Scalar {
+    type Output = Scalar;
+
+    fn sub(self, other: Scalar) -> Scalar {
+        Scalar(self.0 - other.0)
+    }
+}
+
+impl Mul for Scalar {
+    type Output = Scalar;
+
+    fn mul(self, other: Scalar) -> Scalar {
+        Scalar(self.0 * other.0)
+    }
+}
+
+impl Neg for Scalar {
+    type Output = Scalar;
+
+    fn neg(self) -> Scalar {
+        Scalar(-self.0)
+    }
+}
+
+// kP
+impl Mul<Affine> for Scalar {
+    type Output = Affine;
+
+    fn mul(self, point: Affine) -> Affine {
+        point.mul(self.0).into_affine()
+    }
+}
+
+impl Coordinate {
+    // `None` for the point at infinity
+    pub fn x(point: &Affine) -> Option<Coordinate> {
+        point.xy().map(|(x, _)| Coordinate(*x))
+    }
+
+    // the integer in [0, p), reduced mod n: for x in [n, p) this is x - n, a case random tests never reach
+    pub fn reduce_mod_n(&self) -> Scalar {
+        Scalar(Fr::from_le_bytes_mod_order(
+            &self.0.into_bigint().to_bytes_le(),
+        ))
+    }
+}
+
+impl Add for Coordinate {
+    type Output = Coordinate;
+
+    fn add(self, other: Coordinate) -> Coordinate {
+        Coordinate(self.0 + other.0)
+    }
+}
+
+impl Sub for Coordinate {
+    type Output = Coordinate;
+
+    fn sub(self, other: Coordinate) -> Coordinate {
+        Coordinate(self.0 - other.0)
+    }
+}
+
+impl Mul for Coordinate {
+    type Output = Coordinate;
+
+    fn mul(self, other: Coordinate) -> Coordinate {
+        Coordinate(self.0 * other.0)
+    }
+}
+
+// ECDSA signing on integers: it compiles, and it is wrong twice. r is x(kG) without its reduction mod n, and
+// k^-1 and s are computed mod p, where the coordinates live
+// Rewritten with `Scalar` and `Coordinate`, both mistakes are type errors:
+//   let r = Coordinate::x(&(k * Affine::generator()))?;
+//   let s = k.inverse()? * (z + r * x);
+//                               ^^^^^ no implementation for `Coordinate * Scalar`
+pub fn untyped_sign(x: &BigUint, z: &BigUint, k: &BigUint) -> (BigUint, BigUint) {
+    let p = BigUint::from(Fq::MODULUS);
+    let point = Affine::generator().mul(Fr::from(k.clone())).into_affine();
+    let r = BigUint::from(point.x.into_bigint());
+    let k_inverse = k.modpow(&(&p - 2u32), &p);
+    let s = k_inverse * (z + &r * x) % &p;
+    (r, s)
+}
@@ -0,0 +1,132 @@
+// Spaced repetition over the templates of a spec
+// Every answer updates the statistics of its topic, kept in the progress report between sessions
+// The next question is drawn with a probability proportional to the error rate of its topic,
+// so weak topics come back more often, and a wrong answer is asked again (a fresh instance) a few questions later
+
+use crate::exercise::{Exercise, ExerciseSpec};
+use rand::Rng;
+use sage_fixtures::Fixtures;
+use std::collections::BTreeMap;
+use std::io;
+
+pub const PROGRESS_REPORT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../progress.report");
+
+// the number of questions before a missed template is asked again
+pub const RETRY_DELAY: usize = 3;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TopicStats {
+    pub asked: u64,
+    pub wrong: u64,
+}
+
+impl TopicStats {
+    // Laplace's rule of succession: an unseen topic starts at 1/2, and no topic ever gets a zero weight
+    pub fn error_rate(&self) -> f64 {
+        (self.wrong + 1) as f64 / (self.asked + 2) as f64
+    }
+}
+
+pub struct Drill<'a> {
+    specs: &'a [ExerciseSpec],
+    pub stats: BTreeMap<String, TopicStats>, // by topic
+    retries: Vec<(usize, usize)>,            // (question number, index of the template)
+    asked: usize,
+}
+
+impl<'a> Drill<'a> {
+    pub fn new(specs: &'a [ExerciseSpec], stats: BTreeMap<String, TopicStats>) -> Drill<'a> {
+        Drill {
+            specs,
+            stats,
+            retries: Vec::new(),
+            asked: 0,
+        }
+    }
+
+    pub fn next<R: Rng>(&mut self, rng: &mut R) -> Exercise {
+        self.asked += 1;
+        let due = self
+            .retries
+            .iter()
+            .position(|(question, _)| *question <= self.asked);
+        let index = match due {
+            Some(position) => self.retries.remove(position).1,
+            None => {
+                let weights: Vec<f64> = self
+                    .specs
+                    .iter()
+                    .map(|spec| self.topic(&spec.topic).error_rate())
+                    .collect();
+                let mut draw = rng.gen_range(0.0..weights.iter().sum::<f64>());
+                weights
+                    .iter()
+                    .position(|weight| {
+                        draw -= weight;
+                        draw < 0.0
+                    })
+                    .unwrap_or(weights.len() - 1)
+            }
+        };
+        self.specs[index].instantiate(rng)
+    }
+
+    pub fn record(&mut self, exercise: &Exercise, correct: bool) {
+        let stats = self.stats.entry(exercise.topic.clone()).or_default();
+        stats.asked += 1;
+        if !correct {
+            stats.wrong += 1;
+            let index = self
+                .specs
+                .iter()
+                .position(|spec| spec.id == exercise.id)
+                .unwrap();
+            self.retries.push((self.asked + RETRY_DELAY, index));
+        }
+    }
+
+    pub fn topic(&self, topic: &str) -> TopicStats {
+        self.stats.get(topic).copied().unwrap_or_default()
+    }
+}
+
+// the statistics are stored in the `drill.<topic>` sections, spaces replaced by dashes
+fn section(topic: &str) -> String {
+    format!("drill.{}", topic.replace(' ', "-"))
+}
+
+pub fn load_stats(report: &Fixtures, specs: &[ExerciseSpec]) -> BTreeMap<String, TopicStats> {
+    specs
+        .iter()
+        .filter(|spec| report.contains(&section(&spec.topic), "asked"))
+        .map(|spec| {
+            let section = section(&spec.topic);
+            let stats = TopicStats {
+                asked: report.get(&section, "asked"),
+                wrong: report.get(&section, "wrong"),
+            };
+            (spec.topic.clone(), stats)
+        })
+        .collect()
+}
+
+pub fn store_stats(report: &mut Fixtures, stats: &BTreeMap<String, TopicStats>) {
+    for (topic, stats) in stats {
+        report.set(&section(topic), "asked", stats.asked);
+        report.set(&section(topic), "wrong", stats.wrong);
+    }
+}
+
+pub fn read_progress_report() -> io::Result<Fixtures> {
+    match std::fs::read_to_string(PROGRESS_REPORT) {
+        Ok(text) => text
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Fixtures::new()),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn write_progress_report(report: &Fixtures) -> io::Result<()> {
+    std::fs::write(PROGRESS_REPORT, report.to_string())
+}
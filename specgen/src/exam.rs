@@ -0,0 +1,42 @@
+// A mini-exam for the start of a session: one fresh instance of every template of a spec
+// (`specs/exam.toml`: the generators of F_p^*, squares, an order and a small discrete logarithm)
+// Before the quiz, the instructor hands out the questions with a commitment to each answer,
+//   SHA-256("<salt>:<id>:<answer>")
+// where the salt is random and stays secret until the end of the quiz: the answers are small numbers,
+// the salt keeps them from being found by trying them all. Once the salt is revealed, every student checks
+// their own answers against the commitments, e.g.
+//   printf '%s' "<salt>:<id>:<answer>" | sha256sum
+// and sees that the answer key was not changed after the quiz
+
+use crate::exercise::{Exercise, ExerciseSpec};
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+pub const SALT_BYTES: usize = 16;
+
+pub struct Exam {
+    pub salt: String, // in hexadecimal
+    pub exercises: Vec<Exercise>,
+}
+
+impl Exam {
+    pub fn generate<R: Rng>(specs: &[ExerciseSpec], rng: &mut R) -> Exam {
+        let salt: [u8; SALT_BYTES] = rng.gen();
+        Exam {
+            salt: to_hex(&salt),
+            exercises: specs.iter().map(|spec| spec.instantiate(rng)).collect(),
+        }
+    }
+
+    pub fn commitment(&self, exercise: &Exercise) -> String {
+        commit(&self.salt, &exercise.id, exercise.answer)
+    }
+}
+
+pub fn commit(salt: &str, id: &str, answer: u64) -> String {
+    to_hex(&Sha256::digest(format!("{}:{}:{}", salt, id, answer)))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
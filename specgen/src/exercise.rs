@@ -0,0 +1,278 @@
+use crate::toml::{Table, Value};
+use crate::SpecError;
+use rand::Rng;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    FieldAdd,            // a + b in F_p
+    FieldMul,            // a * b in F_p
+    FieldInverse,        // a^-1 in F_p
+    MultiplicativeOrder, // the order of g in F_p^*
+    DiscreteLog,         // x such that g^x = h, g generating F_p^*
+    GeneratorSum,        // the sum of the generators of F_p^*
+    SquareCount,         // how many of 1, ..., n are squares in F_p
+}
+
+impl Kind {
+    pub fn from_name(name: &str) -> Option<Kind> {
+        match name {
+            "field-add" => Some(Kind::FieldAdd),
+            "field-mul" => Some(Kind::FieldMul),
+            "field-inverse" => Some(Kind::FieldInverse),
+            "multiplicative-order" => Some(Kind::MultiplicativeOrder),
+            "discrete-log" => Some(Kind::DiscreteLog),
+            "generator-sum" => Some(Kind::GeneratorSum),
+            "square-count" => Some(Kind::SquareCount),
+            _ => None,
+        }
+    }
+
+    // the parameters a prompt may refer to, as {name}
+    pub fn placeholders(&self) -> &'static [&'static str] {
+        match self {
+            Kind::FieldAdd | Kind::FieldMul => &["p", "a", "b"],
+            Kind::FieldInverse => &["p", "a"],
+            Kind::MultiplicativeOrder => &["p", "g"],
+            Kind::DiscreteLog => &["p", "g", "h"],
+            Kind::GeneratorSum => &["p"],
+            Kind::SquareCount => &["p", "n"],
+        }
+    }
+
+    // the order and the discrete log factor p - 1 by trial division, the sum of the generators tries all
+    // the elements
+    fn max_prime(&self) -> u64 {
+        match self {
+            Kind::MultiplicativeOrder | Kind::DiscreteLog => 1 << 32,
+            Kind::GeneratorSum => 1 << 16,
+            _ => u64::MAX,
+        }
+    }
+}
+
+// the largest n of a `square-count` exercise
+pub const SQUARE_COUNT_MAX: u64 = 30;
+
+// a template, from one `[[exercise]]` table of a spec
+#[derive(Clone, Debug)]
+pub struct ExerciseSpec {
+    pub id: String,
+    pub topic: String,
+    pub kind: Kind,
+    pub primes: Vec<u64>, // one of them is picked for every instance
+    pub prompt: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Check {
+    Equals(u64),
+    // any x with g^x = h mod p is accepted, not only the one that was sampled
+    DiscreteLog { g: u64, h: u64, p: u64 },
+}
+
+// an instance of a spec, with its checker
+#[derive(Clone, Debug)]
+pub struct Exercise {
+    pub id: String,
+    pub topic: String,
+    pub question: String,
+    pub answer: u64, // a reference answer
+    pub check: Check,
+}
+
+impl Exercise {
+    pub fn check(&self, answer: &str) -> bool {
+        let Ok(answer) = answer.trim().parse::<u64>() else {
+            return false;
+        };
+        match self.check {
+            Check::Equals(expected) => answer == expected,
+            Check::DiscreteLog { g, h, p } => pow_mod(g, answer, p) == h,
+        }
+    }
+}
+
+impl ExerciseSpec {
+    pub fn from_table(table: &Table) -> Result<ExerciseSpec, SpecError> {
+        let error = |message: String| SpecError::new(table.line, &message);
+        let string = |key: &str| match table.get(key) {
+            Some(Value::String(s)) => Ok(s.clone()),
+            _ => Err(error(format!("`{}` must be a string", key))),
+        };
+        let id = string("id")?;
+        let topic = string("topic")?;
+        let prompt = string("prompt")?;
+        let kind = Kind::from_name(&string("kind")?)
+            .ok_or_else(|| error(format!("{}: unknown kind", id)))?;
+        let primes = match table.get("primes") {
+            Some(Value::Array(items)) if !items.is_empty() => items
+                .iter()
+                .map(|item| match item {
+                    Value::Integer(p) if is_prime(*p) && *p > 3 && *p < kind.max_prime() => Ok(*p),
+                    _ => Err(error(format!("{}: `primes` holds an invalid prime", id))),
+                })
+                .collect::<Result<Vec<u64>, SpecError>>()?,
+            _ => return Err(error(format!("{}: `primes` must be a non-empty array", id))),
+        };
+        for placeholder in placeholders(&prompt) {
+            if !kind.placeholders().contains(&placeholder) {
+                return Err(error(format!(
+                    "{}: unknown placeholder {{{}}}",
+                    id, placeholder
+                )));
+            }
+        }
+        Ok(ExerciseSpec {
+            id,
+            topic,
+            kind,
+            primes,
+            prompt,
+        })
+    }
+
+    pub fn instantiate<R: Rng>(&self, rng: &mut R) -> Exercise {
+        let p = self.primes[rng.gen_range(0..self.primes.len())];
+        let (parameters, answer, check) = match self.kind {
+            Kind::FieldAdd | Kind::FieldMul => {
+                let (a, b) = (rng.gen_range(0..p), rng.gen_range(0..p));
+                let answer = if self.kind == Kind::FieldAdd {
+                    ((a as u128 + b as u128) % p as u128) as u64
+                } else {
+                    mul_mod(a, b, p)
+                };
+                (vec![("a", a), ("b", b)], answer, Check::Equals(answer))
+            }
+            Kind::FieldInverse => {
+                let a = rng.gen_range(1..p);
+                let answer = pow_mod(a, p - 2, p);
+                (vec![("a", a)], answer, Check::Equals(answer))
+            }
+            Kind::MultiplicativeOrder => {
+                let g = rng.gen_range(2..p - 1);
+                let answer = multiplicative_order(g, p);
+                (vec![("g", g)], answer, Check::Equals(answer))
+            }
+            Kind::DiscreteLog => {
+                let g = primitive_root(p);
+                let x = rng.gen_range(1..p - 1);
+                let h = pow_mod(g, x, p);
+                (vec![("g", g), ("h", h)], x, Check::DiscreteLog { g, h, p })
+            }
+            Kind::GeneratorSum => {
+                let answer = (1..p)
+                    .filter(|g| multiplicative_order(*g, p) == p - 1)
+                    .sum();
+                (vec![], answer, Check::Equals(answer))
+            }
+            Kind::SquareCount => {
+                // few enough to be checked by hand
+                let max = (p - 1).min(SQUARE_COUNT_MAX);
+                let n = rng.gen_range(max / 2..=max);
+                let answer = (1..=n).filter(|a| is_square(*a, p)).count() as u64;
+                (vec![("n", n)], answer, Check::Equals(answer))
+            }
+        };
+        let question = parameters.iter().chain(&[("p", p)]).fold(
+            self.prompt.clone(),
+            |question, (name, value)| {
+                question.replace(&format!("{{{}}}", name), &value.to_string())
+            },
+        );
+        Exercise {
+            id: self.id.clone(),
+            topic: self.topic.clone(),
+            question,
+            answer,
+            check,
+        }
+    }
+}
+
+// the names between braces
+fn placeholders(prompt: &str) -> Vec<&str> {
+    prompt
+        .split('{')
+        .skip(1)
+        .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+        .collect()
+}
+
+pub fn mul_mod(a: u64, b: u64, p: u64) -> u64 {
+    (a as u128 * b as u128 % p as u128) as u64
+}
+
+pub fn pow_mod(base: u64, exponent: u64, p: u64) -> u64 {
+    (0..64).rev().fold(1, |acc, i| {
+        let acc = mul_mod(acc, acc, p);
+        if exponent >> i & 1 == 1 {
+            mul_mod(acc, base, p)
+        } else {
+            acc
+        }
+    })
+}
+
+// Miller-Rabin with the first 12 primes as bases, deterministic for 64-bit integers
+pub fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    if let Some(base) = BASES.iter().find(|base| n.is_multiple_of(**base)) {
+        return n == *base;
+    }
+    let (mut d, mut s) = (n - 1, 0);
+    while d.is_multiple_of(2) {
+        d /= 2;
+        s += 1;
+    }
+    BASES.iter().all(|base| {
+        let mut x = pow_mod(*base, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        (1..s).any(|_| {
+            x = mul_mod(x, x, n);
+            x == n - 1
+        })
+    })
+}
+
+fn prime_factors(mut n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+    let mut q = 2;
+    while q * q <= n {
+        if n.is_multiple_of(q) {
+            factors.push(q);
+            while n.is_multiple_of(q) {
+                n /= q;
+            }
+        }
+        q += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+pub fn multiplicative_order(g: u64, p: u64) -> u64 {
+    prime_factors(p - 1).iter().fold(p - 1, |mut order, q| {
+        while order.is_multiple_of(*q) && pow_mod(g, order / q, p) == 1 {
+            order /= q;
+        }
+        order
+    })
+}
+
+// Euler's criterion: a != 0 is a square iff a^((p - 1) / 2) = 1
+pub fn is_square(a: u64, p: u64) -> bool {
+    pow_mod(a, (p - 1) / 2, p) == 1
+}
+
+pub fn primitive_root(p: u64) -> u64 {
+    (2..p)
+        .find(|g| multiplicative_order(*g, p) == p - 1)
+        .unwrap()
+}
@@ -0,0 +1,85 @@
+//! Drill questions generated from a declarative spec
+//!
+//! Instructors describe families of small exercises in a TOML file instead of writing Rust for each
+//! of them. Every `[[exercise]]` table is a template: an `id`, a `topic`, a `kind` (which picks the
+//! parameters to sample and the checker), the `primes` to draw p from, and a `prompt` referring to
+//! the sampled parameters between braces:
+//!
+//! ```text
+//! [[exercise]]
+//! id = "dlog-small"
+//! topic = "discrete logarithms"
+//! kind = "discrete-log"
+//! primes = [1019, 2027]
+//! prompt = "Find x such that {g}^x = {h} mod {p}"
+//! ```
+//!
+//! The kinds are `field-add` and `field-mul` ({p}, {a}, {b}), `field-inverse` ({p}, {a}),
+//! `multiplicative-order` ({p}, {g}), `discrete-log` ({p}, {g}, {h}, g being a generator of F_p^*),
+//! `generator-sum` ({p}, the sum of the generators of F_p^*) and `square-count` ({p}, {n}, how many of
+//! 1, ..., n are squares). The order and the discrete log factor p - 1 by trial division, so their primes
+//! must be smaller than 2^32; the sum of the generators tries every element, its primes are below 2^16.
+//!
+//! Only a subset of TOML is understood: comments, arrays of tables, strings, integers and one-line arrays.
+//!
+//! `specgen drill` turns a spec into a practice session: questions are served on the standard input,
+//! the error rate of every topic is kept in the progress report, and weak topics are asked more often.
+//!
+//! `specgen exam` draws a quiz, one instance of every template of `specs/exam.toml`, and commits to its
+//! answers, see module `exam`.
+//!
+//! `specgen worksheet` prints a pen-and-paper worksheet as JSON: additions, products, inverses and square roots
+//! in small prime fields, and point additions on toy curves. The answer key is compiled in with the
+//! `answer-key` feature only, see module `worksheet`.
+
+use std::fmt;
+
+pub mod drill;
+pub mod exam;
+pub mod exercise;
+pub mod toml;
+pub mod worksheet;
+
+pub use drill::*;
+pub use exam::*;
+pub use exercise::*;
+pub use worksheet::*;
+
+#[derive(Debug)]
+pub struct SpecError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl SpecError {
+    pub fn new(line: usize, message: &str) -> SpecError {
+        SpecError {
+            line,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for SpecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for SpecError {}
+
+// the templates of a spec, all of them validated
+pub fn load_specs(text: &str) -> Result<Vec<ExerciseSpec>, SpecError> {
+    let mut specs: Vec<ExerciseSpec> = Vec::new();
+    for table in toml::parse(text)? {
+        if table.name != "exercise" {
+            return Err(SpecError::new(table.line, "expected `[[exercise]]`"));
+        }
+        let spec = ExerciseSpec::from_table(&table)?;
+        if specs.iter().any(|other| other.id == spec.id) {
+            return Err(SpecError::new(table.line, "duplicate id"));
+        }
+        specs.push(spec);
+    }
+    Ok(specs)
+}
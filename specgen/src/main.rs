@@ -0,0 +1,162 @@
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
+use specgen::*;
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::process;
+
+const USAGE: &str = "usage: specgen [spec file] [instances per exercise] [seed]
+       specgen drill [spec file] [questions]
+       specgen exam [spec file] [seed]
+       specgen worksheet [problems per kind] [seed]";
+const DEFAULT_SPEC: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/specs/drills.toml");
+const DEFAULT_EXAM: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/specs/exam.toml");
+const DEFAULT_QUESTIONS: usize = 10;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.split_first() {
+        Some((command, rest)) if command == "drill" => drill(rest),
+        Some((command, rest)) if command == "exam" => exam(rest),
+        Some((command, rest)) if command == "worksheet" => worksheet(rest),
+        _ => sheet(&args),
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(1);
+}
+
+fn load(path: &str) -> Vec<ExerciseSpec> {
+    let text = fs::read_to_string(path).unwrap_or_else(|error| {
+        eprintln!("cannot read {}: {}", path, error);
+        process::exit(1);
+    });
+    load_specs(&text).unwrap_or_else(|error| {
+        eprintln!("{}: {}", path, error);
+        process::exit(1);
+    })
+}
+
+// prints an instructor sheet: a few instances of every exercise of the spec, with their answers
+fn sheet(args: &[String]) {
+    let path = args.first().map_or(DEFAULT_SPEC, |path| path.as_str());
+    let (Ok(count), Ok(mut rng)) = (
+        args.get(1).map_or(Ok(3), |count| count.parse::<usize>()),
+        args.get(2).map_or_else(
+            || ChaChaRng::from_rng(rand::thread_rng()).map_err(|_| ()),
+            |seed| seed.parse().map(ChaChaRng::seed_from_u64).map_err(|_| ()),
+        ),
+    ) else {
+        usage();
+    };
+    let specs = load(path);
+
+    for spec in &specs {
+        println!("[{}] {}", spec.id, spec.topic);
+        for _ in 0..count {
+            let exercise = spec.instantiate(&mut rng);
+            // the generated checker must accept the reference answer
+            assert!(exercise.check(&exercise.answer.to_string()));
+            println!("  {}\n    answer: {}", exercise.question, exercise.answer);
+        }
+    }
+}
+
+// prints the questions of a quiz with the commitments to their answers, then the answer key
+fn exam(args: &[String]) {
+    let path = args.first().map_or(DEFAULT_EXAM, |path| path.as_str());
+    let Ok(mut rng) = args.get(1).map_or_else(
+        || ChaChaRng::from_rng(rand::thread_rng()).map_err(|_| ()),
+        |seed| seed.parse().map(ChaChaRng::seed_from_u64).map_err(|_| ()),
+    ) else {
+        usage();
+    };
+    let exam = Exam::generate(&load(path), &mut rng);
+
+    println!("Quiz: the commitments are SHA-256(\"<salt>:<id>:<answer>\"), the salt comes after the quiz\n");
+    for (number, exercise) in exam.exercises.iter().enumerate() {
+        assert!(exercise.check(&exercise.answer.to_string()));
+        println!("{}. [{}] {}", number + 1, exercise.id, exercise.question);
+        println!("   commitment: {}", exam.commitment(exercise));
+    }
+    println!("\nAnswer key, to keep until the end of the quiz");
+    println!("salt: {}", exam.salt);
+    for (number, exercise) in exam.exercises.iter().enumerate() {
+        println!("{}. [{}] {}", number + 1, exercise.id, exercise.answer);
+    }
+}
+
+// prints a worksheet as JSON, with its answers when built with the `answer-key` feature
+fn worksheet(args: &[String]) {
+    let (Ok(count), Ok(mut rng)) = (
+        args.first().map_or(Ok(4), |count| count.parse::<usize>()),
+        args.get(1).map_or_else(
+            || ChaChaRng::from_rng(rand::thread_rng()).map_err(|_| ()),
+            |seed| seed.parse().map(ChaChaRng::seed_from_u64).map_err(|_| ()),
+        ),
+    ) else {
+        usage();
+    };
+    let worksheet = Worksheet::generate(count, &mut rng);
+    for problem in &worksheet.problems {
+        // the checker must accept the reference answer
+        let answer = match problem.answer() {
+            Answer::Roots(r, _) => r.to_string(),
+            answer => answer.to_string(),
+        };
+        assert!(problem.check(&answer));
+    }
+    println!("{}", worksheet.to_json(cfg!(feature = "answer-key")));
+}
+
+// asks questions on the standard input, an empty line or the end of the input stops early
+fn drill(args: &[String]) {
+    let path = args.first().map_or(DEFAULT_SPEC, |path| path.as_str());
+    let Ok(questions) = args
+        .get(1)
+        .map_or(Ok(DEFAULT_QUESTIONS), |count| count.parse::<usize>())
+    else {
+        usage();
+    };
+    let specs = load(path);
+    let mut report = read_progress_report().expect("cannot read the progress report");
+    let mut drill = Drill::new(&specs, load_stats(&report, &specs));
+    let mut rng = ChaChaRng::from_entropy();
+    let mut lines = io::stdin().lock().lines();
+
+    let mut score = 0;
+    for number in 1..=questions {
+        let exercise = drill.next(&mut rng);
+        print!(
+            "{}/{} [{}] {}\n> ",
+            number, questions, exercise.topic, exercise.question
+        );
+        io::stdout().flush().unwrap();
+        let answer = match lines.next() {
+            Some(Ok(line)) if !line.trim().is_empty() => line,
+            _ => {
+                println!();
+                break;
+            }
+        };
+        let correct = exercise.check(&answer);
+        if correct {
+            score += 1;
+            println!("correct\n");
+        } else {
+            println!("wrong, a solution is {}\n", exercise.answer);
+        }
+        drill.record(&exercise, correct);
+    }
+
+    store_stats(&mut report, &drill.stats);
+    write_progress_report(&report).expect("cannot write the progress report");
+    println!("{} correct answers", score);
+    println!("error rates over all sessions:");
+    for (topic, stats) in &drill.stats {
+        println!("  {:<20} {}/{} wrong", topic, stats.wrong, stats.asked);
+    }
+}
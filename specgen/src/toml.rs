@@ -0,0 +1,108 @@
+// The subset of TOML used by the specs: comments, arrays of tables `[[name]]`, and `key = value`
+// where a value is a basic string "...", an integer, or a one-line array of them
+
+use crate::SpecError;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    String(String),
+    Integer(u64),
+    Array(Vec<Value>),
+}
+
+#[derive(Clone, Debug)]
+pub struct Table {
+    pub name: String,
+    pub line: usize,
+    pub entries: Vec<(String, Value)>,
+}
+
+impl Table {
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.entries.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+}
+
+pub fn parse(text: &str) -> Result<Vec<Table>, SpecError> {
+    let mut tables: Vec<Table> = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let error = |message: &str| SpecError::new(index + 1, message);
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix("[[").and_then(|l| l.strip_suffix("]]")) {
+            tables.push(Table {
+                name: name.trim().to_string(),
+                line: index + 1,
+                entries: Vec::new(),
+            });
+            continue;
+        }
+        if line.starts_with('[') {
+            return Err(error("only arrays of tables `[[name]]` are supported"));
+        }
+        let table = tables
+            .last_mut()
+            .ok_or_else(|| error("value outside of a table"))?;
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| error("expected `key = value`"))?;
+        let key = key.trim();
+        if table.get(key).is_some() {
+            return Err(error("duplicate key"));
+        }
+        let value = parse_value(value.trim()).ok_or_else(|| error("invalid value"))?;
+        table.entries.push((key.to_string(), value));
+    }
+    Ok(tables)
+}
+
+// a '#' outside of a string starts a comment
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn parse_value(raw: &str) -> Option<Value> {
+    if let Some(inner) = raw.strip_prefix('"').and_then(|r| r.strip_suffix('"')) {
+        return (!inner.contains('"')).then(|| Value::String(inner.to_string()));
+    }
+    if let Some(inner) = raw.strip_prefix('[').and_then(|r| r.strip_suffix(']')) {
+        return split_items(inner)?
+            .iter()
+            .map(|item| parse_value(item))
+            .collect::<Option<Vec<Value>>>()
+            .map(Value::Array);
+    }
+    raw.replace('_', "").parse().ok().map(Value::Integer)
+}
+
+// the items of an array, allowing a trailing comma and commas inside strings
+fn split_items(inner: &str) -> Option<Vec<&str>> {
+    let mut items = Vec::new();
+    let (mut start, mut in_string) = (0, false);
+    for (i, c) in inner.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            ',' if !in_string => {
+                items.push(inner[start..i].trim());
+                start = i + 1;
+            }
+            '[' if !in_string => return None, // no nested arrays
+            _ => {}
+        }
+    }
+    let last = inner[start..].trim();
+    if !last.is_empty() {
+        items.push(last);
+    }
+    (!in_string && items.iter().all(|item| !item.is_empty())).then_some(items)
+}
@@ -0,0 +1,271 @@
+// Pen-and-paper worksheets: randomized arithmetic in small prime fields and on toy curves, printed as JSON
+// - `add`, `mul`, `inverse` and `sqrt` in F_p, `point-add` on y^2 = x^3 + ax + b over F_p (a doubling
+//   when both points are the same)
+// - the primes are below 100, so that every answer can be worked out by hand
+// - the reference answers are computed here, and `Problem::check` accepts any correct answer: either square
+//   root, a point written "(x, y)" or "O" for the point at infinity
+// The JSON of the students carries no answer; the answer key is only compiled in with the `answer-key`
+// feature: `cargo run --release --features answer-key -- worksheet`
+
+use crate::exercise::{is_square, mul_mod, pow_mod};
+use rand::Rng;
+
+pub const WORKSHEET_PRIMES: [u64; 22] = [
+    11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97, 101,
+];
+pub const PROBLEM_KINDS: [&str; 5] = ["add", "mul", "inverse", "sqrt", "point-add"];
+
+pub type Point = Option<(u64, u64)>; // None is the point at infinity
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Problem {
+    Add {
+        p: u64,
+        a: u64,
+        b: u64,
+    },
+    Mul {
+        p: u64,
+        a: u64,
+        b: u64,
+    },
+    Inverse {
+        p: u64,
+        a: u64,
+    },
+    Sqrt {
+        p: u64,
+        a: u64,
+    }, // a is a non-zero square
+    PointAdd {
+        p: u64,
+        a: u64,
+        b: u64,
+        left: (u64, u64),
+        right: (u64, u64),
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Answer {
+    Number(u64),
+    Roots(u64, u64), // r and p - r
+    Point(Point),
+}
+
+impl std::fmt::Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Roots(r, s) => write!(f, "{} or {}", r, s),
+            Answer::Point(point) => write!(f, "{}", format_point(point)),
+        }
+    }
+}
+
+pub fn format_point(point: &Point) -> String {
+    match point {
+        Some((x, y)) => format!("({}, {})", x, y),
+        None => "O".to_string(),
+    }
+}
+
+pub fn parse_point(text: &str) -> Option<Point> {
+    let text = text.trim();
+    if text == "O" {
+        return Some(None);
+    }
+    let (x, y) = text.strip_prefix('(')?.strip_suffix(')')?.split_once(',')?;
+    Some(Some((x.trim().parse().ok()?, y.trim().parse().ok()?)))
+}
+
+fn inverse(a: u64, p: u64) -> u64 {
+    pow_mod(a, p - 2, p)
+}
+
+// the affine points of y^2 = x^3 + ax + b, by brute force
+fn curve_points(p: u64, a: u64, b: u64) -> Vec<(u64, u64)> {
+    (0..p)
+        .flat_map(|x| (0..p).map(move |y| (x, y)))
+        .filter(|(x, y)| on_curve(p, a, b, (*x, *y)))
+        .collect()
+}
+
+fn on_curve(p: u64, a: u64, b: u64, (x, y): (u64, u64)) -> bool {
+    mul_mod(y, y, p) == (mul_mod(mul_mod(x, x, p), x, p) + mul_mod(a, x, p) + b) % p
+}
+
+// the chord-and-tangent law
+pub fn point_add(p: u64, a: u64, left: Point, right: Point) -> Point {
+    let (Some((x1, y1)), Some((x2, y2))) = (left, right) else {
+        return left.or(right);
+    };
+    if x1 == x2 && (y1 + y2) % p == 0 {
+        return None;
+    }
+    let lambda = if x1 == x2 {
+        mul_mod((3 * mul_mod(x1, x1, p) + a) % p, inverse(2 * y1 % p, p), p)
+    } else {
+        mul_mod((y2 + p - y1) % p, inverse((x2 + p - x1) % p, p), p)
+    };
+    let x3 = (mul_mod(lambda, lambda, p) + 2 * p - x1 - x2) % p;
+    let y3 = (mul_mod(lambda, (x1 + p - x3) % p, p) + p - y1) % p;
+    Some((x3, y3))
+}
+
+impl Problem {
+    pub fn generate<R: Rng>(kind: &str, rng: &mut R) -> Problem {
+        let p = WORKSHEET_PRIMES[rng.gen_range(0..WORKSHEET_PRIMES.len())];
+        match kind {
+            "add" => Problem::Add {
+                p,
+                a: rng.gen_range(0..p),
+                b: rng.gen_range(0..p),
+            },
+            "mul" => Problem::Mul {
+                p,
+                a: rng.gen_range(0..p),
+                b: rng.gen_range(0..p),
+            },
+            "inverse" => Problem::Inverse {
+                p,
+                a: rng.gen_range(1..p),
+            },
+            "sqrt" => Problem::Sqrt {
+                p,
+                a: loop {
+                    let a = rng.gen_range(1..p);
+                    if is_square(a, p) {
+                        break a;
+                    }
+                },
+            },
+            "point-add" => loop {
+                let (a, b) = (rng.gen_range(0..p), rng.gen_range(0..p));
+                // non-singular: 4a^3 + 27b^2 != 0
+                if (4 * mul_mod(mul_mod(a, a, p), a, p) + 27 * mul_mod(b, b, p)).is_multiple_of(p) {
+                    continue;
+                }
+                let points = curve_points(p, a, b);
+                if points.len() < 2 {
+                    continue;
+                }
+                let left = points[rng.gen_range(0..points.len())];
+                // a doubling one time in four
+                let right = match rng.gen_range(0..4) {
+                    0 => left,
+                    _ => points[rng.gen_range(0..points.len())],
+                };
+                break Problem::PointAdd {
+                    p,
+                    a,
+                    b,
+                    left,
+                    right,
+                };
+            },
+            _ => panic!("unknown kind of problem: {}", kind),
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Problem::Add { .. } => "add",
+            Problem::Mul { .. } => "mul",
+            Problem::Inverse { .. } => "inverse",
+            Problem::Sqrt { .. } => "sqrt",
+            Problem::PointAdd { .. } => "point-add",
+        }
+    }
+
+    pub fn prompt(&self) -> String {
+        match self {
+            Problem::Add { p, a, b } => format!("In F_{}, compute {} + {}", p, a, b),
+            Problem::Mul { p, a, b } => format!("In F_{}, compute {} * {}", p, a, b),
+            Problem::Inverse { p, a } => format!("In F_{}, compute the inverse of {}", p, a),
+            Problem::Sqrt { p, a } => format!("In F_{}, find a square root of {}", p, a),
+            Problem::PointAdd {
+                p,
+                a,
+                b,
+                left,
+                right,
+            } => format!(
+                "On y^2 = x^3 + {}x + {} over F_{}, compute {} + {}",
+                a,
+                b,
+                p,
+                format_point(&Some(*left)),
+                format_point(&Some(*right))
+            ),
+        }
+    }
+
+    pub fn answer(&self) -> Answer {
+        match *self {
+            Problem::Add { p, a, b } => Answer::Number((a + b) % p),
+            Problem::Mul { p, a, b } => Answer::Number(mul_mod(a, b, p)),
+            Problem::Inverse { p, a } => Answer::Number(inverse(a, p)),
+            Problem::Sqrt { p, a } => {
+                let r = (1..p).find(|r| mul_mod(*r, *r, p) == a).unwrap();
+                Answer::Roots(r.min(p - r), r.max(p - r))
+            }
+            Problem::PointAdd {
+                p, a, left, right, ..
+            } => Answer::Point(point_add(p, a, Some(left), Some(right))),
+        }
+    }
+
+    // whether the text is a correct answer
+    pub fn check(&self, text: &str) -> bool {
+        match (self, self.answer()) {
+            (Problem::Sqrt { p, a }, _) => text
+                .trim()
+                .parse::<u64>()
+                .is_ok_and(|r| r < *p && mul_mod(r, r, *p) == *a),
+            (_, Answer::Point(expected)) => parse_point(text) == Some(expected),
+            (_, expected) => text.trim().parse().map(Answer::Number) == Ok(expected),
+        }
+    }
+}
+
+pub struct Worksheet {
+    pub problems: Vec<Problem>,
+}
+
+impl Worksheet {
+    // `count` problems of every kind
+    pub fn generate<R: Rng>(count: usize, rng: &mut R) -> Worksheet {
+        let problems = PROBLEM_KINDS
+            .iter()
+            .flat_map(|kind| {
+                (0..count)
+                    .map(|_| Problem::generate(kind, rng))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        Worksheet { problems }
+    }
+
+    pub fn to_json(&self, with_answers: bool) -> String {
+        let problems: Vec<String> = self
+            .problems
+            .iter()
+            .enumerate()
+            .map(|(i, problem)| {
+                let answer = match with_answers {
+                    true => format!(", \"answer\": \"{}\"", problem.answer()),
+                    false => String::new(),
+                };
+                format!(
+                    "  {{\"number\": {}, \"kind\": \"{}\", \"prompt\": \"{}\"{}}}",
+                    i + 1,
+                    problem.kind(),
+                    problem.prompt(),
+                    answer
+                )
+            })
+            .collect();
+        format!("{{\"problems\": [\n{}\n]}}", problems.join(",\n"))
+    }
+}
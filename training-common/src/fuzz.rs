@@ -0,0 +1,148 @@
+// A small mutation fuzzer for the decoders of the exercises, which parse bytes an attacker chooses
+// The checks of a question try the mutants someone thought of; the fuzzer tries a hundred thousand nobody
+// did: bits flipped, bytes inserted, removed or set to 0x00, 0x80 or 0xff, truncations and repeated slices,
+// starting from valid encodings. A target returns whether it accepted the input, or an error for an input it
+// mishandled, and must never panic: a decoder which panics on a length byte is a denial of service
+//
+// The chapters run this fuzzer from a `fuzz` command, `cargo run --release -- fuzz` in ff-ec and schnorr, on a
+// stable toolchain. Without coverage feedback, the accepted mutants join the corpus, so that the fuzzer keeps
+// mutating inputs which get deep into the decoder. The same decoders, in the module `decoders` of the library
+// of each chapter, are cargo-fuzz targets as well: `cargo +nightly fuzz run parse_der` in schnorr, see the
+// crate `fuzz/` next to its sources, lets libFuzzer follow the branches the mutants take
+//
+// ```text
+// let report = training_common::fuzz::fuzz(&[valid_encoding], 100_000, &mut rng, |bytes| {
+//     match decode(bytes) {
+//         None => Ok(false),
+//         Some(value) if encode(&value) == bytes => Ok(true),
+//         Some(_) => Err("accepted a non-canonical encoding".to_string()),
+//     }
+// });
+// ```
+
+use rand::Rng;
+use std::panic::{self, AssertUnwindSafe};
+
+pub const DEFAULT_FUZZ_RUNS: usize = 100_000;
+// the accepted mutants kept for further mutations
+pub const MAX_CORPUS: usize = 1000;
+const INTERESTING_BYTES: [u8; 9] = [0x00, 0x01, 0x02, 0x03, 0x04, 0x30, 0x7f, 0x80, 0xff];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FuzzReport {
+    pub runs: usize,
+    pub accepted: usize,
+}
+
+// the first input which made the target fail or panic
+#[derive(Clone, Debug, PartialEq)]
+pub struct Finding {
+    pub input: Vec<u8>,
+    pub message: String,
+}
+
+// one to four random mutations of the input
+pub fn mutate<R: Rng>(input: &[u8], rng: &mut R) -> Vec<u8> {
+    let mut bytes = input.to_vec();
+    for _ in 0..rng.gen_range(1..=4) {
+        let len = bytes.len();
+        if len == 0 {
+            bytes.push(rng.gen());
+            continue;
+        }
+        let i = rng.gen_range(0..len);
+        match rng.gen_range(0..6) {
+            0 => bytes[i] ^= 1 << rng.gen_range(0..8),
+            1 => bytes[i] = INTERESTING_BYTES[rng.gen_range(0..INTERESTING_BYTES.len())],
+            2 => bytes.insert(rng.gen_range(0..=len), rng.gen()),
+            3 => {
+                bytes.remove(i);
+            }
+            4 => bytes.truncate(i),
+            _ => {
+                let end = rng.gen_range(i..=len);
+                let at = rng.gen_range(0..=len);
+                let slice = bytes[i..end].to_vec();
+                bytes.splice(at..at, slice);
+            }
+        }
+    }
+    bytes
+}
+
+// the seeds as they are, then `runs` mutants of the corpus, until the first finding
+pub fn fuzz<R: Rng>(
+    seeds: &[Vec<u8>],
+    runs: usize,
+    rng: &mut R,
+    target: impl Fn(&[u8]) -> Result<bool, String>,
+) -> Result<FuzzReport, Finding> {
+    let mut corpus = seeds.to_vec();
+    corpus.push(vec![]);
+    let mut report = FuzzReport {
+        runs: 0,
+        accepted: 0,
+    };
+    // the default hook would print every caught panic
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let mut finding = None;
+    for run in 0..seeds.len() + runs {
+        let input = match run < seeds.len() {
+            true => seeds[run].clone(),
+            false => mutate(&corpus[rng.gen_range(0..corpus.len())], rng),
+        };
+        report.runs += 1;
+        match panic::catch_unwind(AssertUnwindSafe(|| target(&input))) {
+            Ok(Ok(true)) => {
+                report.accepted += 1;
+                if corpus.len() < MAX_CORPUS {
+                    corpus.push(input);
+                }
+            }
+            Ok(Ok(false)) => {}
+            Ok(Err(message)) => {
+                finding = Some(Finding { input, message });
+                break;
+            }
+            Err(payload) => {
+                let message = match (
+                    payload.downcast_ref::<&str>(),
+                    payload.downcast_ref::<String>(),
+                ) {
+                    (Some(message), _) => message.to_string(),
+                    (_, Some(message)) => message.clone(),
+                    _ => "unknown panic".to_string(),
+                };
+                finding = Some(Finding {
+                    input,
+                    message: format!("panicked: {}", message),
+                });
+                break;
+            }
+        }
+    }
+    panic::set_hook(hook);
+    match finding {
+        Some(finding) => Err(finding),
+        None => Ok(report),
+    }
+}
+
+// prints the outcome of a campaign, and returns false on a finding
+pub fn print_report(name: &str, result: &Result<FuzzReport, Finding>) -> bool {
+    match result {
+        Ok(report) => {
+            println!(
+                "{}: {} inputs, {} accepted and re-encoded to the same bytes",
+                name, report.runs, report.accepted
+            );
+            true
+        }
+        Err(finding) => {
+            let hex: String = finding.input.iter().map(|b| format!("{:02x}", b)).collect();
+            println!("{}: {} on input {:?}", name, finding.message, hex);
+            false
+        }
+    }
+}
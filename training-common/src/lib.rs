@@ -0,0 +1,28 @@
+//! What a new exercise needs from the shared crates, in one dependency
+//!
+//! - the seeded random number generator of the chapters, `rng` (see the seed crate for `--seed` and
+//!   `TRAINING_SEED`)
+//! - the checks of answers by their digests, `assert_digest` and `assert_same` (see the canonical crate)
+//! - the toy curve of ff-ec, y^2 = x^3 + 7 over F_89, with its arkworks configuration, in module `toy`
+//! - the points of any curve over F_p for p < 200 on a terminal grid, with the orbit of a point, in module
+//!   `plot`
+//! - a mutation fuzzer for the decoders of the exercises, which must never panic, in module `fuzz`
+//! - message passing for the interactive exercises: two-party channels with adversarial wrappers which drop,
+//!   replay or modify messages, a simulated network of parties, and their transcripts, in module `protocol_sim`
+//!
+//! ```text
+//! use training_common::toy::{ToyAffine, F};
+//! let mut rng = training_common::rng();
+//! training_common::assert_same("my-chapter/Q1", &answer, &expected);
+//! ```
+//!
+//! Chapters are crates of the root workspace, each with its own `main`: a new topic gets a chapter of its own
+//! (or a module of an existing one) rather than more questions at the end of a long `main.rs`.
+
+pub mod fuzz;
+pub mod plot;
+pub mod protocol_sim;
+pub mod toy;
+
+pub use canonical::{assert_digest, assert_same, check, digest, digest_hex, Canonical};
+pub use seed::rng;
@@ -0,0 +1,126 @@
+// The points of a small curve y^2 = x^3 + ax + b over F_p on a terminal grid, x to the right and y upwards,
+// for the live demos of the F_89 sections (`cargo run --release -- plot` in ff-ec)
+//   ·  a point of the curve
+//   G  the highlighted point
+//   •  a point of its orbit {2G, 3G, ...}, or the multiple k of G when `plot` is asked to number them
+// The points look randomly scattered, symmetric around y = p / 2 (P and -P), and the orbit of G jumps all
+// over the grid: nothing in the picture of kG tells k, which is the discrete log problem in miniature
+//
+// ```text
+// let curve = training_common::plot::SmallCurve::new(89, 0, 7);
+// print!("{}", training_common::plot::plot(&curve, Some((60, 2)), false));
+// ```
+
+// wider grids do not fit in a terminal
+pub const MAX_P: u64 = 200;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SmallCurve {
+    pub p: u64,
+    pub a: u64,
+    pub b: u64,
+}
+
+// None is the point at infinity
+pub type SmallPoint = Option<(u64, u64)>;
+
+impl SmallCurve {
+    pub fn new(p: u64, a: u64, b: u64) -> SmallCurve {
+        assert!(p > 3 && p < MAX_P, "p must be an odd prime below {}", MAX_P);
+        assert!(
+            (2..p)
+                .take_while(|d| d * d <= p)
+                .all(|d| !p.is_multiple_of(d)),
+            "{} is not prime",
+            p
+        );
+        SmallCurve {
+            p,
+            a: a % p,
+            b: b % p,
+        }
+    }
+
+    pub fn is_on_curve(&self, (x, y): (u64, u64)) -> bool {
+        let p = self.p;
+        x < p && y < p && y * y % p == ((x * x % p) * x + self.a * x + self.b) % p
+    }
+
+    // all the affine points, by brute force
+    pub fn points(&self) -> Vec<(u64, u64)> {
+        (0..self.p)
+            .flat_map(|x| (0..self.p).map(move |y| (x, y)))
+            .filter(|point| self.is_on_curve(*point))
+            .collect()
+    }
+
+    fn inverse(&self, x: u64) -> u64 {
+        // Fermat: x^(p - 2)
+        (0..self.p - 2).fold(1, |acc, _| acc * x % self.p)
+    }
+
+    pub fn add(&self, left: SmallPoint, right: SmallPoint) -> SmallPoint {
+        let p = self.p;
+        let ((x1, y1), (x2, y2)) = match (left, right) {
+            (None, other) | (other, None) => return other,
+            (Some(l), Some(r)) => (l, r),
+        };
+        if x1 == x2 && (y1 + y2) % p == 0 {
+            return None;
+        }
+        let lambda = if x1 == x2 {
+            (3 * x1 * x1 + self.a) % p * self.inverse(2 * y1 % p) % p
+        } else {
+            (y2 + p - y1) * self.inverse((x2 + p - x1) % p) % p
+        };
+        let x3 = (lambda * lambda + 2 * p - x1 - x2) % p;
+        let y3 = (lambda * ((x1 + p - x3) % p) + p - y1) % p;
+        Some((x3, y3))
+    }
+
+    // G, 2G, 3G, ... up to the last multiple before the point at infinity
+    pub fn orbit(&self, g: (u64, u64)) -> Vec<(u64, u64)> {
+        assert!(self.is_on_curve(g), "{:?} is not on the curve", g);
+        let mut orbit = vec![g];
+        while let Some(next) = self.add(Some(*orbit.last().unwrap()), Some(g)) {
+            orbit.push(next);
+        }
+        orbit
+    }
+}
+
+// the grid of the points of `curve`, with the orbit of `highlight` if any; `numbered` writes the last digit
+// of k at kG instead of a dot, to follow the orbit step by step
+pub fn plot(curve: &SmallCurve, highlight: Option<(u64, u64)>, numbered: bool) -> String {
+    let p = curve.p as usize;
+    let mut grid = vec![vec![' '; p]; p];
+    for (x, y) in curve.points() {
+        grid[y as usize][x as usize] = '·';
+    }
+    let orbit = highlight.map(|g| curve.orbit(g)).unwrap_or_default();
+    for (k, (x, y)) in orbit.iter().enumerate().skip(1) {
+        grid[*y as usize][*x as usize] = match numbered {
+            true => char::from_digit((k + 1) as u32 % 10, 10).unwrap(),
+            false => '•',
+        };
+    }
+    if let Some((x, y)) = highlight {
+        grid[y as usize][x as usize] = 'G';
+    }
+    let mut frame = String::new();
+    for (y, row) in grid.iter().enumerate().rev() {
+        frame += &format!("{:>3} |{}\n", y, row.iter().collect::<String>());
+    }
+    frame += &format!("    +{}\n", "-".repeat(p));
+    frame += &format!(
+        "y^2 = x^3 + {}x + {} over F_{}: {} points and the point at infinity",
+        curve.a,
+        curve.b,
+        curve.p,
+        curve.points().len()
+    );
+    if let Some(g) = highlight {
+        frame += &format!(", G = {:?} of order {}", g, orbit.len() + 1);
+    }
+    frame + "\n"
+}
@@ -0,0 +1,378 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::rc::Rc;
+
+// In-process message passing for the interactive exercises, so that a protocol only writes its parties
+// - `Transcript`: the messages delivered, with their sender and recipient, in order
+// - `Channel`: a two-party link, for the protocols run move by move (identification, ECDH, OT...).
+//   `duplex` builds both ends of a reliable in-memory link, and the wrappers `Dropping`, `Replaying` and
+//   `Tampering` put an active adversary on the sending side of an end
+// - `Simnet`: a network of `Party` state machines, for the protocols of many parties (MuSig2, DKG, AVSS...).
+//   It delivers the messages as an asynchronous network would:
+//   - every message takes a random latency (in ticks) to reach its recipient, so messages get reordered
+//   - links are fair-lossy: a message is dropped with probability `drop_rate`, the link layer then
+//     retransmits it after another latency, so that every message is eventually delivered
+//   - a message is duplicated with probability `duplicate_rate`, a duplicate is not duplicated again
+//   - the messages from or to a crashed party are silently lost
+//   - the messages sent by a Byzantine party go through a hook, which can rewrite them or drop them
+//   The simulation is deterministic given the seed, which makes failures reproducible
+//
+// let (mut prover, mut verifier) = duplex::<Message>(0, 1);
+// let mut prover = Tampering::new(prover, |index, message| if index == 1 { forge(message) } else { message });
+
+pub type PartyId = usize;
+
+#[derive(Clone, Debug)]
+pub struct Transcript<M> {
+    pub messages: Vec<(PartyId, PartyId, M)>,
+}
+
+impl<M> Default for Transcript<M> {
+    fn default() -> Transcript<M> {
+        Transcript { messages: vec![] }
+    }
+}
+
+impl<M> Transcript<M> {
+    pub fn record(&mut self, from: PartyId, to: PartyId, message: M) {
+        self.messages.push((from, to, message));
+    }
+
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    // the messages alone, in order
+    pub fn into_messages(self) -> Vec<M> {
+        self.messages
+            .into_iter()
+            .map(|(_, _, message)| message)
+            .collect()
+    }
+
+    pub fn sent_by(&self, party: PartyId) -> impl Iterator<Item = &M> {
+        self.messages
+            .iter()
+            .filter(move |(from, _, _)| *from == party)
+            .map(|(_, _, message)| message)
+    }
+}
+
+pub trait Channel<M> {
+    fn send(&mut self, message: M);
+
+    // the next message from the other end, None if there is none (lost, or never sent)
+    fn receive(&mut self) -> Option<M>;
+}
+
+// one end of an in-memory duplex link: what it sends lands in the queue of the other end
+pub struct Endpoint<M> {
+    pub id: PartyId,
+    pub peer: PartyId,
+    inbox: Rc<RefCell<VecDeque<M>>>,
+    outbox: Rc<RefCell<VecDeque<M>>>,
+    transcript: Rc<RefCell<Transcript<M>>>,
+}
+
+// both ends of a reliable link between the parties `a` and `b`, sharing the transcript of the messages received
+pub fn duplex<M>(a: PartyId, b: PartyId) -> (Endpoint<M>, Endpoint<M>) {
+    let (to_a, to_b) = Default::default();
+    let transcript: Rc<RefCell<Transcript<M>>> = Default::default();
+    let end_a = Endpoint {
+        id: a,
+        peer: b,
+        inbox: Rc::clone(&to_a),
+        outbox: Rc::clone(&to_b),
+        transcript: Rc::clone(&transcript),
+    };
+    let end_b = Endpoint {
+        id: b,
+        peer: a,
+        inbox: to_b,
+        outbox: to_a,
+        transcript,
+    };
+    (end_a, end_b)
+}
+
+impl<M: Clone> Endpoint<M> {
+    pub fn transcript(&self) -> Transcript<M> {
+        self.transcript.borrow().clone()
+    }
+}
+
+impl<M: Clone> Channel<M> for Endpoint<M> {
+    fn send(&mut self, message: M) {
+        self.outbox.borrow_mut().push_back(message);
+    }
+
+    fn receive(&mut self) -> Option<M> {
+        let message = self.inbox.borrow_mut().pop_front()?;
+        self.transcript
+            .borrow_mut()
+            .record(self.peer, self.id, message.clone());
+        Some(message)
+    }
+}
+
+// drops the messages sent whose index (from 0) is in `indices`
+pub struct Dropping<C> {
+    pub inner: C,
+    pub indices: Vec<usize>,
+    sent: usize,
+}
+
+impl<C> Dropping<C> {
+    pub fn new(inner: C, indices: Vec<usize>) -> Dropping<C> {
+        Dropping {
+            inner,
+            indices,
+            sent: 0,
+        }
+    }
+}
+
+impl<M, C: Channel<M>> Channel<M> for Dropping<C> {
+    fn send(&mut self, message: M) {
+        if !self.indices.contains(&self.sent) {
+            self.inner.send(message);
+        }
+        self.sent += 1;
+    }
+
+    fn receive(&mut self) -> Option<M> {
+        self.inner.receive()
+    }
+}
+
+// sends `replayed` in place of the message of index `index`, a message recorded from an earlier session
+pub struct Replaying<C, M> {
+    pub inner: C,
+    pub index: usize,
+    pub replayed: M,
+    sent: usize,
+}
+
+impl<C, M> Replaying<C, M> {
+    pub fn new(inner: C, index: usize, replayed: M) -> Replaying<C, M> {
+        Replaying {
+            inner,
+            index,
+            replayed,
+            sent: 0,
+        }
+    }
+}
+
+impl<M: Clone, C: Channel<M>> Channel<M> for Replaying<C, M> {
+    fn send(&mut self, message: M) {
+        let message = match self.sent == self.index {
+            true => self.replayed.clone(),
+            false => message,
+        };
+        self.inner.send(message);
+        self.sent += 1;
+    }
+
+    fn receive(&mut self) -> Option<M> {
+        self.inner.receive()
+    }
+}
+
+// rewrites every message sent with `modify(index, message)`
+pub struct Tampering<C, F> {
+    pub inner: C,
+    modify: F,
+    sent: usize,
+}
+
+impl<C, F> Tampering<C, F> {
+    pub fn new(inner: C, modify: F) -> Tampering<C, F> {
+        Tampering {
+            inner,
+            modify,
+            sent: 0,
+        }
+    }
+}
+
+impl<M, C: Channel<M>, F: FnMut(usize, M) -> M> Channel<M> for Tampering<C, F> {
+    fn send(&mut self, message: M) {
+        let message = (self.modify)(self.sent, message);
+        self.inner.send(message);
+        self.sent += 1;
+    }
+
+    fn receive(&mut self) -> Option<M> {
+        self.inner.receive()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Outgoing<M> {
+    To(PartyId, M),
+    Broadcast(M), // to every party, including the sender itself
+}
+
+// a party of a `Simnet`: a state machine reacting to the messages it receives with messages to send
+pub trait Party<M> {
+    // the first messages sent, before anything is received
+    fn start(&mut self, _rng: &mut ChaChaRng) -> Vec<Outgoing<M>> {
+        vec![]
+    }
+
+    fn receive(&mut self, from: PartyId, message: M, rng: &mut ChaChaRng) -> Vec<Outgoing<M>>;
+}
+
+// (from, to, message) -> the message actually sent, or None to drop it
+pub type ByzantineHook<M> = Box<dyn FnMut(PartyId, PartyId, M) -> Option<M>>;
+
+#[derive(Clone, Copy, Debug)]
+pub struct SimnetConfig {
+    pub latency: (u64, u64), // min and max number of ticks
+    pub drop_rate: f64,      // below 1, or no message ever gets through
+    pub duplicate_rate: f64,
+}
+
+impl Default for SimnetConfig {
+    // a reliable network delivering the messages in order
+    fn default() -> SimnetConfig {
+        SimnetConfig {
+            latency: (1, 1),
+            drop_rate: 0.0,
+            duplicate_rate: 0.0,
+        }
+    }
+}
+
+struct Envelope<M> {
+    deliver_at: u64,
+    sequence: u64, // ties are broken by sending order
+    from: PartyId,
+    to: PartyId,
+    message: M,
+    duplicate: bool,
+}
+
+pub struct Simnet<M> {
+    pub config: SimnetConfig,
+    pub crashed: Vec<PartyId>,
+    pub byzantine: HashMap<PartyId, ByzantineHook<M>>,
+    pub clock: u64,
+    pub delivered: usize,
+    pub dropped: usize,
+    pub log: Transcript<M>, // the delivered messages, in order
+    in_flight: Vec<Envelope<M>>,
+    sequence: u64,
+    rng: ChaChaRng,
+}
+
+impl<M: Clone> Simnet<M> {
+    pub fn new(config: SimnetConfig, seed: u64) -> Simnet<M> {
+        assert!(
+            (0.0..1.0).contains(&config.drop_rate),
+            "the drop rate must be in [0, 1)"
+        );
+        Simnet {
+            config,
+            crashed: vec![],
+            byzantine: HashMap::new(),
+            clock: 0,
+            delivered: 0,
+            dropped: 0,
+            log: Transcript::default(),
+            in_flight: vec![],
+            sequence: 0,
+            rng: ChaChaRng::seed_from_u64(seed),
+        }
+    }
+
+    fn schedule(&mut self, from: PartyId, to: PartyId, message: M, duplicate: bool) {
+        let (min, max) = self.config.latency;
+        let deliver_at = self.clock + self.rng.gen_range(min..=max);
+        self.sequence += 1;
+        self.in_flight.push(Envelope {
+            deliver_at,
+            sequence: self.sequence,
+            from,
+            to,
+            message,
+            duplicate,
+        });
+    }
+
+    pub fn send(&mut self, from: PartyId, to: PartyId, message: M) {
+        if self.crashed.contains(&from) || self.crashed.contains(&to) {
+            return;
+        }
+        let message = match self.byzantine.get_mut(&from) {
+            Some(hook) => match hook(from, to, message) {
+                Some(message) => message,
+                None => return,
+            },
+            None => message,
+        };
+        self.schedule(from, to, message, false);
+    }
+
+    fn route(&mut self, from: PartyId, party_count: usize, outgoing: Vec<Outgoing<M>>) {
+        for out in outgoing {
+            match out {
+                Outgoing::To(to, message) => self.send(from, to, message),
+                Outgoing::Broadcast(message) => {
+                    for to in 0..party_count {
+                        self.send(from, to, message.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    // the next message to deliver, None once the network is quiet
+    pub fn deliver_next(&mut self) -> Option<(PartyId, PartyId, M)> {
+        loop {
+            let index = (0..self.in_flight.len())
+                .min_by_key(|&i| (self.in_flight[i].deliver_at, self.in_flight[i].sequence))?;
+            let envelope = self.in_flight.swap_remove(index);
+            self.clock = envelope.deliver_at;
+            if self.rng.gen_bool(self.config.drop_rate) {
+                // lost on the wire, the link layer will try again
+                self.dropped += 1;
+                self.schedule(
+                    envelope.from,
+                    envelope.to,
+                    envelope.message,
+                    envelope.duplicate,
+                );
+                continue;
+            }
+            if !envelope.duplicate && self.rng.gen_bool(self.config.duplicate_rate) {
+                self.schedule(envelope.from, envelope.to, envelope.message.clone(), true);
+            }
+            self.delivered += 1;
+            self.log
+                .record(envelope.from, envelope.to, envelope.message.clone());
+            return Some((envelope.from, envelope.to, envelope.message));
+        }
+    }
+
+    // starts the parties (party i has the id i) and runs until the network is quiet
+    pub fn run(&mut self, parties: &mut [&mut dyn Party<M>]) {
+        for id in 0..parties.len() {
+            if !self.crashed.contains(&id) {
+                let outgoing = parties[id].start(&mut self.rng);
+                self.route(id, parties.len(), outgoing);
+            }
+        }
+        while let Some((from, to, message)) = self.deliver_next() {
+            let outgoing = parties[to].receive(from, message, &mut self.rng);
+            self.route(to, parties.len(), outgoing);
+        }
+    }
+}
@@ -0,0 +1,52 @@
+// the derive macro `MontConfig` of this version of arkworks implements its traits inside an anonymous const
+#![allow(non_local_definitions)]
+
+use ark_ec::short_weierstrass::{self, SWCurveConfig};
+use ark_ec::CurveConfig;
+use ark_ff::{Fp64, MontBackend, MontConfig, MontFp, PrimeField};
+
+// The small field of the first questions of ff-ec, F_89, also the base field of the toy curve
+#[derive(MontConfig)]
+#[modulus = "89"]
+#[generator = "3"] // a generator of F*, the multiplicative group of the field
+pub struct FqConfig;
+pub type F = Fp64<MontBackend<FqConfig, 1>>;
+
+// y^2 = x^3 + 7, the equation of secp256k1, over F_89
+// Small enough to list every point, and not of prime order, unlike secp256k1:
+// arkworks works in a subgroup of prime order r, and wants to know it along with the cofactor h = #E / r
+// - `ScalarField` is F_r, the field of the scalars of the subgroup
+// - `GENERATOR` is a point of order r, and `COFACTOR_INV` is h^-1 mod r
+
+#[derive(MontConfig)]
+#[modulus = "5"]
+#[generator = "2"]
+pub struct FrConfig;
+pub type ToyFr = Fp64<MontBackend<FrConfig, 1>>;
+
+pub struct ToyConfig;
+
+impl CurveConfig for ToyConfig {
+    type BaseField = F;
+    type ScalarField = ToyFr;
+
+    const COFACTOR: &'static [u64] = &[18];
+    const COFACTOR_INV: ToyFr = MontFp!("2");
+}
+
+impl SWCurveConfig for ToyConfig {
+    const COEFF_A: F = MontFp!("0");
+    const COEFF_B: F = MontFp!("7");
+    const GENERATOR: ToyAffine = ToyAffine::new_unchecked(MontFp!("60"), MontFp!("2"));
+}
+
+pub type ToyAffine = short_weierstrass::Affine<ToyConfig>;
+
+pub fn configured_cofactor() -> u64 {
+    ToyConfig::COFACTOR[0]
+}
+
+// the order of the whole group, from the configuration
+pub fn configured_order() -> u64 {
+    configured_cofactor() * ToyFr::MODULUS.0[0]
+}
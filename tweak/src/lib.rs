@@ -0,0 +1,152 @@
+//! Tweaking secp256k1 keys: the one operation behind BIP32 public derivation, Taproot output keys and
+//! stealth addresses
+//!
+//! A tweak t moves a key pair (x, xG) to (x + t, xG + tG), or (tx, t xG) for a multiplicative tweak, so that
+//! whoever knows only the public key and t derives the new public key, and only the owner of x the new
+//! private key. The chapters share these functions instead of redoing the point arithmetic with raw
+//! arkworks calls, and with it the rejection of the invalid cases:
+//!
+//! - a tweak given as 32 bytes must be a canonical scalar, t < n (`parse_tweak`)
+//! - an additive tweak must not land on the point at infinity, x + t != 0 (BIP32 then skips the index)
+//! - a multiplicative tweak must not be zero
+//!
+//! x-only keys (BIP340, Taproot) are 32-byte x-coordinates standing for the point with an even y: tweaking
+//! one lifts it, adds tG and returns the x-coordinate of the result along with the parity of its y, which
+//! the spender needs to prove the tweak (BIP341 `taproot_tweak_pubkey`). On the private side, the key is
+//! negated first when its point has an odd y (`taproot_tweak_seckey`).
+//!
+//! ```text
+//! let (output_key, parity) = tweak::x_only_tweak_add(&internal_key, &t)?;
+//! let child = tweak::tweak_add(&parent, &tweak::parse_tweak(&il)?)?;
+//! ```
+//!
+//! Without its default `std` feature the crate is `no_std` (with `alloc`, for the byte encodings of
+//! arkworks): `TweakError` then only implements `Display`, not `std::error::Error`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, Field, PrimeField, Zero};
+use ark_secp256k1::{Affine, Fq, Fr};
+use core::fmt;
+use core::ops::Mul;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TweakError {
+    // the 32 bytes of the tweak are not a scalar smaller than n
+    Overflow,
+    // a multiplicative tweak of zero
+    Zero,
+    // the tweaked key is zero, or the point at infinity
+    Infinity,
+    // the x-only key is not the x-coordinate of a point of the curve
+    InvalidXOnly,
+}
+
+impl fmt::Display for TweakError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            TweakError::Overflow => "the tweak is not smaller than the order of the curve",
+            TweakError::Zero => "the multiplicative tweak is zero",
+            TweakError::Infinity => "the tweaked key is the point at infinity",
+            TweakError::InvalidXOnly => "the x-only key is not on the curve",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TweakError {}
+
+// big-endian, rejecting t >= n instead of reducing it
+pub fn parse_tweak(bytes: &[u8; 32]) -> Result<Fr, TweakError> {
+    let t = Fr::from_be_bytes_mod_order(bytes);
+    match t.into_bigint().to_bytes_be() == bytes {
+        true => Ok(t),
+        false => Err(TweakError::Overflow),
+    }
+}
+
+// P + tG
+pub fn tweak_add(pubkey: &Affine, t: &Fr) -> Result<Affine, TweakError> {
+    let tweaked = (Affine::generator().mul(t) + pubkey).into_affine();
+    match tweaked.is_zero() {
+        true => Err(TweakError::Infinity),
+        false => Ok(tweaked),
+    }
+}
+
+// x + t
+pub fn tweak_add_private(sk: &Fr, t: &Fr) -> Result<Fr, TweakError> {
+    let tweaked = *sk + t;
+    match tweaked.is_zero() {
+        true => Err(TweakError::Infinity),
+        false => Ok(tweaked),
+    }
+}
+
+// tP
+pub fn tweak_mul(pubkey: &Affine, t: &Fr) -> Result<Affine, TweakError> {
+    if t.is_zero() {
+        return Err(TweakError::Zero);
+    }
+    let tweaked = pubkey.mul(t).into_affine();
+    match tweaked.is_zero() {
+        true => Err(TweakError::Infinity),
+        false => Ok(tweaked),
+    }
+}
+
+// tx
+pub fn tweak_mul_private(sk: &Fr, t: &Fr) -> Result<Fr, TweakError> {
+    match (t.is_zero(), sk.is_zero()) {
+        (true, _) => Err(TweakError::Zero),
+        (_, true) => Err(TweakError::Infinity),
+        _ => Ok(*sk * t),
+    }
+}
+
+pub fn has_even_y(point: &Affine) -> bool {
+    point.y.into_bigint().is_even()
+}
+
+// the x-coordinate of a point, and whether its y is odd
+pub fn x_only(point: &Affine) -> ([u8; 32], bool) {
+    let x = point.x.into_bigint().to_bytes_be().try_into().unwrap();
+    (x, !has_even_y(point))
+}
+
+// the point with x-coordinate `x` and an even y-coordinate
+pub fn lift_x(x: &[u8; 32]) -> Result<Affine, TweakError> {
+    let x_field = Fq::from_be_bytes_mod_order(x);
+    if x_field.into_bigint().to_bytes_be() != x {
+        return Err(TweakError::InvalidXOnly); // x >= p
+    }
+    let y = (x_field.square() * x_field + Fq::from(7))
+        .sqrt()
+        .ok_or(TweakError::InvalidXOnly)?;
+    let point = Affine::new_unchecked(x_field, y);
+    Ok(if has_even_y(&point) { point } else { -point })
+}
+
+// lift_x(x) + tG, as an x-only key and the parity of its y
+pub fn x_only_tweak_add(x: &[u8; 32], t: &Fr) -> Result<([u8; 32], bool), TweakError> {
+    Ok(x_only(&tweak_add(&lift_x(x)?, t)?))
+}
+
+// the private key of `x_only_tweak_add` from the private key of x, negated if xG has an odd y
+pub fn x_only_tweak_add_private(sk: &Fr, t: &Fr) -> Result<Fr, TweakError> {
+    let point = Affine::generator().mul(sk).into_affine();
+    let sk = if has_even_y(&point) { *sk } else { -*sk };
+    tweak_add_private(&sk, t)
+}
+
+// whether `tweaked` with this parity is the x-only tweak of `internal` by t (BIP341 script path check)
+pub fn x_only_tweak_add_check(
+    internal: &[u8; 32],
+    tweaked: &[u8; 32],
+    parity: bool,
+    t: &Fr,
+) -> bool {
+    x_only_tweak_add(internal, t) == Ok((*tweaked, parity))
+}
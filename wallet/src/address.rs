@@ -0,0 +1,171 @@
+use sha2::{Digest, Sha256};
+
+// A native segwit (P2WPKH) address commits to HASH160(serP(K)) = RIPEMD160(SHA256(serP(K))), 20 bytes
+// BIP173 encodes it in Bech32: hrp || "1" || data || checksum, where
+// - hrp is "bc" on mainnet, "tb" on testnet
+// - data is the witness version (0) as one 5-bit value, then the 20 bytes regrouped in 5-bit values (160 bits,
+//   32 values, the last one padded with zeros when the bits do not divide)
+// - the checksum is 6 more 5-bit values: polymod(hrp_expand(hrp) || data || [0; 6]) ^ 1, split from the most
+//   significant 5 bits, where `polymod` is the remainder of a BCH code over GF(32) which detects any 4 errors
+// - every 5-bit value is a character of `CHARSET`
+// The script of the output is 0x00 0x14 || HASH160: OP_0, then a push of the 20 bytes
+
+pub const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+pub fn bech32_polymod(values: &[u8]) -> u32 {
+    const GENERATORS: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+    let mut checksum = 1u32;
+    for value in values {
+        let top = checksum >> 25;
+        checksum = (checksum & 0x1ffffff) << 5 ^ *value as u32;
+        for (i, generator) in GENERATORS.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+// the high bits of every character of the hrp, a zero, then their low bits
+pub fn hrp_expand(hrp: &str) -> Vec<u8> {
+    let bytes = hrp.as_bytes();
+    let mut expanded: Vec<u8> = bytes.iter().map(|c| c >> 5).collect();
+    expanded.push(0);
+    expanded.extend(bytes.iter().map(|c| c & 31));
+    expanded
+}
+
+// regroups `from`-bit values into `to`-bit values, padding the last one with zeros if `pad`, otherwise None
+// when the leftover bits are not zero padding
+pub fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Option<Vec<u8>> {
+    let (mut accumulator, mut bits) = (0u32, 0u32);
+    let mut converted = vec![];
+    for value in data {
+        accumulator = accumulator << from | *value as u32;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            converted.push((accumulator >> bits & ((1 << to) - 1)) as u8);
+        }
+    }
+    if pad && bits > 0 {
+        converted.push((accumulator << (to - bits) & ((1 << to) - 1)) as u8);
+    } else if !pad && (bits >= from || accumulator & ((1 << bits) - 1) != 0) {
+        return None;
+    }
+    Some(converted)
+}
+
+// the witness version and program of a segwit address of this hrp, for the recipients of a transaction
+pub fn decode_segwit_address(hrp: &str, address: &str) -> Option<(u8, Vec<u8>)> {
+    let (address_hrp, data) = address.rsplit_once('1')?;
+    if address_hrp != hrp || data.len() < 6 {
+        return None;
+    }
+    let values = data
+        .bytes()
+        .map(|c| CHARSET.iter().position(|&x| x == c).map(|i| i as u8))
+        .collect::<Option<Vec<u8>>>()?;
+    if bech32_polymod(&[hrp_expand(hrp), values.clone()].concat()) != 1 {
+        return None;
+    }
+    let (version, program) = values[..values.len() - 6].split_first()?;
+    Some((*version, convert_bits(program, 5, 8, false)?))
+}
+
+// OP_n then a push of the program
+pub fn segwit_script_pubkey(version: u8, program: &[u8]) -> Vec<u8> {
+    let op = if version == 0 { 0 } else { 0x50 + version };
+    [vec![op, program.len() as u8], program.to_vec()].concat()
+}
+
+pub fn hash160(bytes: &[u8]) -> [u8; 20] {
+    ripemd160(&Sha256::digest(bytes))
+}
+
+// RIPEMD-160: two parallel lines of 80 steps on a state of 5 words, little-endian
+pub fn ripemd160(message: &[u8]) -> [u8; 20] {
+    const R: [[usize; 16]; 5] = [
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+        [7, 4, 13, 1, 10, 6, 15, 3, 12, 0, 9, 5, 2, 14, 11, 8],
+        [3, 10, 14, 4, 9, 15, 8, 1, 2, 7, 0, 6, 13, 11, 5, 12],
+        [1, 9, 11, 10, 0, 8, 12, 4, 13, 3, 7, 15, 14, 5, 6, 2],
+        [4, 0, 5, 9, 7, 12, 2, 10, 14, 1, 3, 8, 11, 6, 15, 13],
+    ];
+    const R_PRIME: [[usize; 16]; 5] = [
+        [5, 14, 7, 0, 9, 2, 11, 4, 13, 6, 15, 8, 1, 10, 3, 12],
+        [6, 11, 3, 7, 0, 13, 5, 10, 14, 15, 8, 12, 4, 9, 1, 2],
+        [15, 5, 1, 3, 7, 14, 6, 9, 11, 8, 12, 2, 10, 0, 4, 13],
+        [8, 6, 4, 1, 3, 11, 15, 0, 5, 12, 2, 13, 9, 7, 10, 14],
+        [12, 15, 10, 4, 1, 5, 8, 7, 6, 2, 13, 14, 0, 3, 9, 11],
+    ];
+    const S: [[u32; 16]; 5] = [
+        [11, 14, 15, 12, 5, 8, 7, 9, 11, 13, 14, 15, 6, 7, 9, 8],
+        [7, 6, 8, 13, 11, 9, 7, 15, 7, 12, 15, 9, 11, 7, 13, 12],
+        [11, 13, 6, 7, 14, 9, 13, 15, 14, 8, 13, 6, 5, 12, 7, 5],
+        [11, 12, 14, 15, 14, 15, 9, 8, 9, 14, 5, 6, 8, 6, 5, 12],
+        [9, 15, 5, 11, 6, 8, 13, 12, 5, 12, 13, 14, 11, 8, 5, 6],
+    ];
+    const S_PRIME: [[u32; 16]; 5] = [
+        [8, 9, 9, 11, 13, 15, 15, 5, 7, 7, 8, 11, 14, 14, 12, 6],
+        [9, 13, 15, 7, 12, 8, 9, 11, 7, 7, 12, 7, 6, 15, 13, 11],
+        [9, 7, 15, 11, 8, 6, 6, 14, 12, 13, 5, 14, 13, 13, 7, 5],
+        [15, 5, 8, 11, 14, 14, 6, 14, 6, 9, 12, 9, 12, 5, 15, 8],
+        [8, 5, 12, 9, 12, 5, 14, 6, 8, 13, 6, 5, 15, 13, 11, 11],
+    ];
+    const K: [u32; 5] = [0, 0x5a827999, 0x6ed9eba1, 0x8f1bbcdc, 0xa953fd4e];
+    const K_PRIME: [u32; 5] = [0x50a28be6, 0x5c4dd124, 0x6d703ef3, 0x7a6d76e9, 0];
+    let f = |round: usize, x: u32, y: u32, z: u32| match round {
+        0 => x ^ y ^ z,
+        1 => (x & y) | (!x & z),
+        2 => (x | !y) ^ z,
+        3 => (x & z) | (y & !z),
+        _ => x ^ (y | !z),
+    };
+
+    let mut padded = message.to_vec();
+    padded.push(0x80);
+    while padded.len() % 64 != 56 {
+        padded.push(0);
+    }
+    padded.extend((message.len() as u64 * 8).to_le_bytes());
+    let mut h: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+    for block in padded.chunks(64) {
+        let x: Vec<u32> = block
+            .chunks(4)
+            .map(|word| u32::from_le_bytes(word.try_into().unwrap()))
+            .collect();
+        let [mut a, mut b, mut c, mut d, mut e] = h;
+        let [mut a2, mut b2, mut c2, mut d2, mut e2] = h;
+        for round in 0..5 {
+            for i in 0..16 {
+                let t = a
+                    .wrapping_add(f(round, b, c, d))
+                    .wrapping_add(x[R[round][i]])
+                    .wrapping_add(K[round])
+                    .rotate_left(S[round][i])
+                    .wrapping_add(e);
+                (a, e, d, c, b) = (e, d, c.rotate_left(10), b, t);
+                let t = a2
+                    .wrapping_add(f(4 - round, b2, c2, d2))
+                    .wrapping_add(x[R_PRIME[round][i]])
+                    .wrapping_add(K_PRIME[round])
+                    .rotate_left(S_PRIME[round][i])
+                    .wrapping_add(e2);
+                (a2, e2, d2, c2, b2) = (e2, d2, c2.rotate_left(10), b2, t);
+            }
+        }
+        let t = h[1].wrapping_add(c).wrapping_add(d2);
+        h[1] = h[2].wrapping_add(d).wrapping_add(e2);
+        h[2] = h[3].wrapping_add(e).wrapping_add(a2);
+        h[3] = h[4].wrapping_add(a).wrapping_add(b2);
+        h[4] = h[0].wrapping_add(b).wrapping_add(c2);
+        h[0] = t;
+    }
+    let mut digest = [0u8; 20];
+    for (chunk, word) in digest.chunks_mut(4).zip(h) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    digest
+}
@@ -0,0 +1,72 @@
+use crate::*;
+
+// BIP32 derivation, as in the schnorr chapter: the master key (k, c) = HMAC-SHA512("Bitcoin seed", seed), and
+// child i of (k, c) is (k + IL, IR) with IL || IR = HMAC-SHA512(c, data) where
+// - data = 0x00 || ser256(k) || ser32(i) for a hardened child, i >= 2^31, written i' in a path
+// - data = serP(kG) || ser32(i) otherwise
+// BIP84 fixes the path of the keys of native segwit (P2WPKH) addresses: m / 84' / coin' / account' / change / i,
+// with coin 0 for Bitcoin, change 0 for receiving addresses and 1 for change addresses
+
+pub const HARDENED: u32 = 1 << 31;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExtendedPrivateKey {
+    pub depth: u8,
+    pub child_number: u32,
+    pub chain_code: [u8; 32],
+    pub key: Fr,
+}
+
+// The first receiving and change keys of the BIP84 test vectors, from `TEST_MNEMONIC` without passphrase: path,
+// compressed public key and address
+pub const BIP84_VECTORS: [(&str, &str, &str); 3] = [
+    (
+        "m/84'/0'/0'/0/0",
+        "0330d54fd0dd420a6e5f8d3624f5f3482cae350f79d5f0753bf5beef9c2d91af3c",
+        "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu",
+    ),
+    (
+        "m/84'/0'/0'/0/1",
+        "03e775fd51f0dfb8cd865d9ff1cca2a158cf651fe997fdc9fee9c1d3b5e995ea77",
+        "bc1qnjg0jd8228aq7egyzacy8cys3knf9xvrerkf9g",
+    ),
+    (
+        "m/84'/0'/0'/1/0",
+        "03025324888e429ab8e3dbaf1f7802648b9cd01e9b418485c5fa4c1b9b5700e1a6",
+        "bc1q8c6fshw2dlwun7ekn9qwf37cu2rn755upcp6el",
+    ),
+];
+
+// "m/84'/0'/0'/0/5" into [84 + 2^31, 2^31, 2^31, 0, 5]
+pub fn parse_path(path: &str) -> Vec<u32> {
+    let mut parts = path.split('/');
+    assert_eq!(parts.next(), Some("m"), "a path starts from the master key");
+    parts
+        .map(|part| match part.strip_suffix('\'') {
+            Some(index) => index.parse::<u32>().expect("invalid index") + HARDENED,
+            None => part.parse().expect("invalid index"),
+        })
+        .collect()
+}
+
+// big-endian, None if not smaller than n
+pub fn parse256(bytes: &[u8]) -> Option<Fr> {
+    tweak::parse_tweak(bytes.try_into().ok()?).ok()
+}
+
+pub fn ser256(scalar: &Fr) -> [u8; 32] {
+    scalar.into_bigint().to_bytes_be().try_into().unwrap()
+}
+
+// SEC1 compressed encoding: 0x02 or 0x03 depending on the parity of y, then x
+pub fn ser_p(point: &Affine) -> [u8; 33] {
+    let (x, odd) = tweak::x_only(point);
+    let mut bytes = [0u8; 33];
+    bytes[0] = if odd { 0x03 } else { 0x02 };
+    bytes[1..].copy_from_slice(&x);
+    bytes
+}
+
+pub fn public_key(key: &ExtendedPrivateKey) -> Affine {
+    Affine::generator().mul(key.key).into_affine()
+}
@@ -0,0 +1,40 @@
+use sha2::{Digest, Sha512};
+
+// BIP39: the seed of a wallet from the words its owner writes down
+// - the mnemonic encodes some entropy and a checksum, 11 bits per word of a list of 2048 words
+// - the seed is PBKDF2-HMAC-SHA512 of the mnemonic (the password) with the salt "mnemonic" || passphrase,
+//   2048 iterations, 64 bytes: the words are hashed as a sentence, the word list is not needed to derive it
+// PBKDF2 with one block of output: U_1 = HMAC(P, S || INT(1)), U_j = HMAC(P, U_{j-1}), and the block is
+// U_1 ^ U_2 ^ ... ^ U_c, where INT(1) is 1 on 4 bytes, big-endian
+// The iterations slow down the search for a forgotten passphrase, or of a stolen one
+
+pub const PBKDF2_ITERATIONS: u32 = 2048;
+
+// The mnemonic of the BIP39 and BIP84 test vectors: 128 bits of zeros
+pub const TEST_MNEMONIC: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+// its seed with the passphrase "TREZOR", first vector of BIP39
+pub const TREZOR_SEED: &str = "\
+    c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c\
+    81b2f001698e7463b04";
+
+pub fn hmac_sha512(key: &[u8], data: &[u8]) -> [u8; 64] {
+    const BLOCK_SIZE: usize = 128;
+    let mut padded_key = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        padded_key[..64].copy_from_slice(&Sha512::digest(key));
+    } else {
+        padded_key[..key.len()].copy_from_slice(key);
+    }
+    let inner_pad: Vec<u8> = padded_key.iter().map(|b| b ^ 0x36).collect();
+    let outer_pad: Vec<u8> = padded_key.iter().map(|b| b ^ 0x5c).collect();
+    let inner = Sha512::new()
+        .chain_update(inner_pad)
+        .chain_update(data)
+        .finalize();
+    Sha512::new()
+        .chain_update(outer_pad)
+        .chain_update(inner)
+        .finalize()
+        .into()
+}
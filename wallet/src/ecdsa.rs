@@ -0,0 +1,80 @@
+use crate::*;
+
+// ECDSA over secp256k1 as Bitcoin Core signs: the nonce of RFC 6979, then "low s"
+// - k = the first output of HMAC_DRBG seeded with the private key x and the sighash z, both on 32 bytes
+//   (z reduced mod n), see the schnorr chapter for the steps of RFC 6979 section 3.2
+// - r = (kG).x mod n and s = (z + r x) / k, replaced by n - s when s > n / 2 (BIP146 "low s", a standard rule
+//   since Bitcoin Core 0.11.1: (r, n - s) is also valid and would change the txid of legacy transactions)
+// - DER: 0x30, length, then 0x02, length, r and 0x02, length, s as minimal big-endian signed integers
+
+pub fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    const BLOCK_SIZE: usize = 64;
+    let mut padded_key = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        padded_key[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        padded_key[..key.len()].copy_from_slice(key);
+    }
+    let inner_pad: Vec<u8> = padded_key.iter().map(|b| b ^ 0x36).collect();
+    let outer_pad: Vec<u8> = padded_key.iter().map(|b| b ^ 0x5c).collect();
+    let inner = Sha256::new()
+        .chain_update(inner_pad)
+        .chain_update(data)
+        .finalize();
+    Sha256::new()
+        .chain_update(outer_pad)
+        .chain_update(inner)
+        .finalize()
+        .into()
+}
+
+pub fn scalar_from_be(bytes: &[u8]) -> Fr {
+    Fr::from_be_bytes_mod_order(bytes)
+}
+
+// the x-coordinate of a point, reduced mod n
+pub fn x_mod_n(point: &Affine) -> Fr {
+    scalar_from_be(&point.x.into_bigint().to_bytes_be())
+}
+
+pub fn is_low_s(s: &Fr) -> bool {
+    s.into_bigint() <= Fr::MODULUS_MINUS_ONE_DIV_TWO
+}
+
+// for the harness, which checks the signatures of the transaction it parses
+pub fn ecdsa_verify(z: &Fr, (r, s): &(Fr, Fr), public_key: &Affine) -> bool {
+    let Some(w) = s.inverse() else {
+        return false;
+    };
+    let point = (Affine::generator().mul(*z * w) + public_key.mul(*r * w)).into_affine();
+    !r.is_zero() && !point.is_zero() && x_mod_n(&point) == *r
+}
+
+// the two integers of a DER signature, without checking that the encoding is strict
+pub fn der_decode(der: &[u8]) -> Option<(Fr, Fr)> {
+    let (&[0x30, length], body) = der.split_first_chunk::<2>()? else {
+        return None;
+    };
+    if body.len() != length as usize {
+        return None;
+    }
+    let (&[0x02, r_length], rest) = body.split_first_chunk::<2>()? else {
+        return None;
+    };
+    let (r, rest) = rest.split_at_checked(r_length as usize)?;
+    let (&[0x02, s_length], s) = rest.split_first_chunk::<2>()? else {
+        return None;
+    };
+    (s.len() == s_length as usize).then(|| (scalar_from_be(r), scalar_from_be(s)))
+}
+
+// SEC1 compressed point
+pub fn decompress(bytes: &[u8]) -> Option<Affine> {
+    let (&prefix, x) = bytes.split_first()?;
+    let even = tweak::lift_x(x.try_into().ok()?).ok()?;
+    match prefix {
+        2 => Some(even),
+        3 => Some(-even),
+        _ => None,
+    }
+}
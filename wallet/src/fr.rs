@@ -0,0 +1,81 @@
+use runner::lang::{Catalog, Lang, Statements};
+
+pub const CATALOG: Catalog = Catalog {
+    lang: Lang::Fr,
+    statements: Statements::Table(&[
+        ("Q1", "écrire `mnemonic_to_seed`, PBKDF2-HMAC-SHA512 de la phrase mnémonique et de la phrase de passe"),
+        ("Q2", "écrire `master_key`, `derive_child` et `derive_path`"),
+        ("Q3", "écrire `segwit_address`, l'encodage Bech32 d'une version de témoin et d'un programme"),
+        ("Q4", "écrire `bip143_sighash`, le haché que signe une entrée d'une transaction segwit avec SIGHASH_ALL"),
+        ("Q5", "écrire `rfc6979_nonce`, `ecdsa_sign` avec un s bas, et `der_encode`"),
+        ("Q6", "écrire `sign_p2wpkh_input`, qui pose le témoin d'une entrée, et `serialize`, la sérialisation\nsegwit de BIP144, puis payer le destinataire depuis le portefeuille"),
+        ("Q7", "les frais sont implicites, les entrées moins les sorties : que pouvait faire payer un ordinateur compromis à un\nsignataire hors ligne avant que BIP143 ne signe le montant ? Lesquelles de ces étapes un portefeuille matériel doit-il exécuter lui-même, et\nlesquelles l'ordinateur peut-il faire ? Pourquoi la monnaie rendue va-t-elle sur une autre branche de l'arbre que les adresses\nde réception ?"),
+        ("Q8", "les compiler : à quelles parties de `std` ont-elles renoncé, et qu'est-ce qui y remplace `println!`, un `HashSet` ou\n`std::error::Error` ? Quelles fonctions de ce chapitre ne compileraient pas, et comment l'appareil obtiendrait-il\nson aléa, son budget de temps pour PBKDF2 et la transaction à signer ?"),
+    ]),
+    hints: &[
+        (
+            "Q1",
+            &[
+                "PBKDF2 avec la phrase mnémonique pour clé : HMAC-SHA512 avec la phrase mnémonique comme clé",
+                "Le sel est « mnemonic » suivi de la phrase de passe ; U_1 = HMAC(mnemonic, sel || 00 00 00 01)",
+                "U_j = HMAC(mnemonic, U_{j-1}) pour 2048 itérations en tout, et la graine est le XOR de tous les U_j",
+            ],
+        ),
+        (
+            "Q2",
+            &[
+                "La clé maîtresse est la première moitié de HMAC-SHA512(« Bitcoin seed », seed), le code de chaîne la seconde",
+                "Un enfant hache 0x00 || ser256(k) || ser32(i) s'il est durci, serP(kG) || ser32(i) sinon, avec le code de chaîne pour clé",
+                "La clé enfant est k + IL avec `tweak::tweak_add_private` ; replier `derive_child` sur `parse_path`",
+            ],
+        ),
+        (
+            "Q3",
+            &[
+                "La partie données est la version, puis le programme regroupé en valeurs de 5 bits avec `convert_bits`",
+                "La somme de contrôle est bech32_polymod(hrp_expand(hrp) || data || six zéros) XOR 1",
+                "Découper les 30 bits de la somme de contrôle en six valeurs de 5 bits, la plus significative d'abord, et passer chaque valeur par `CHARSET`",
+            ],
+        ),
+        (
+            "Q4",
+            &[
+                "Suivre champ par champ la préimage du module `transaction`, tous les entiers en petit-boutiste",
+                "hashPrevouts, hashSequence et hashOutputs couvrent toutes les entrées ou sorties, avec SHA256d",
+                "Le scriptCode est `p2wpkh_script_code`, puis le montant sur 8 octets et la séquence de l'entrée",
+            ],
+        ),
+        (
+            "Q5",
+            &[
+                "Le nonce est celui de la RFC 6979 avec q = n : x et z mod n sur 32 octets chacun, V et K sur 32 octets",
+                "Un V candidat est le nonce s'il est un scalaire (`parse256`) et non nul",
+                "Remplacer s par -s quand il dépasse n / 2 (`is_low_s`) ; les entiers DER sont minimaux et reçoivent un 0x00 devant un bit de poids fort",
+            ],
+        ),
+        (
+            "Q6",
+            &[
+                "Le témoin d'une entrée P2WPKH est [signature DER || 0x01, clé publique compressée]",
+                "Le sighash de l'entrée s'engage sur le HASH160 de la clé publique et sur le montant qu'elle dépense",
+                "La sérialisation est la version, 0x00 0x01, le corps, les éléments de témoin de chaque entrée avec leurs longueurs, puis le lock time",
+            ],
+        ),
+        (
+            "Q7",
+            &[
+                "Avant segwit, le signataire devait croire le montant de l'entrée qu'on lui annonçait",
+                "Dériver les clés et signer demandent les secrets ; construire la transaction et encoder les adresses non",
+                "Les portefeuilles en lecture seule dérivent les deux branches depuis la xpub, et une monnaie rendue sur une adresse de réception relie les paiements",
+            ],
+        ),
+        (
+            "Q8",
+            &[
+                "`core` n'a ni tas, ni fichiers, ni horloge, ni threads ; `alloc` ramène `Vec` et `String` pour peu qu'il y ait un allocateur",
+                "`HashSet` a besoin d'une graine aléatoire du système d'exploitation, pas `BTreeSet` ; un appareil journalise sur un port série, s'il le fait",
+                "`seed::rng`, `Runner` et les `println!` du harnais ont besoin de std ; un appareil tire de son générateur matériel et reçoit la transaction par USB",
+            ],
+        ),
+    ],
+};
@@ -0,0 +1,74 @@
+use runner::hints::Hints;
+use runner::lang::Catalog;
+
+pub const CATALOGS: &[Catalog] = &[
+    Catalog::english(HINTS, include_str!("main.rs")),
+    crate::fr::CATALOG,
+];
+
+pub const HINTS: Hints = &[
+    (
+        "Q1",
+        &[
+            "PBKDF2 keyed by the mnemonic: HMAC-SHA512 with the mnemonic as the key",
+            "The salt is \"mnemonic\" followed by the passphrase; U_1 = HMAC(mnemonic, salt || 00 00 00 01)",
+            "U_j = HMAC(mnemonic, U_{j-1}) for 2048 iterations in all, and the seed is the XOR of every U_j",
+        ],
+    ),
+    (
+        "Q2",
+        &[
+            "The master key is the first half of HMAC-SHA512(\"Bitcoin seed\", seed), the chain code the second",
+            "A child hashes 0x00 || ser256(k) || ser32(i) when hardened, serP(kG) || ser32(i) otherwise, keyed by the chain code",
+            "The child key is k + IL with `tweak::tweak_add_private`; fold `derive_child` over `parse_path`",
+        ],
+    ),
+    (
+        "Q3",
+        &[
+            "The data part is the version, then the program regrouped in 5-bit values with `convert_bits`",
+            "The checksum is bech32_polymod(hrp_expand(hrp) || data || six zeros) XOR 1",
+            "Split the 30 bits of the checksum into six 5-bit values, the most significant first, and map every value through `CHARSET`",
+        ],
+    ),
+    (
+        "Q4",
+        &[
+            "Follow the preimage of module `transaction` field by field, all integers little-endian",
+            "hashPrevouts, hashSequence and hashOutputs cover every input or output, with SHA256d",
+            "The scriptCode is `p2wpkh_script_code`, then the amount on 8 bytes and the sequence of the input",
+        ],
+    ),
+    (
+        "Q5",
+        &[
+            "The nonce is the one of RFC 6979 with q = n: x and z mod n on 32 bytes each, V and K on 32 bytes",
+            "A candidate V is the nonce if it is a scalar (`parse256`) and not zero",
+            "Replace s by -s when it is above n / 2 (`is_low_s`); DER integers are minimal and get a 0x00 before a high bit",
+        ],
+    ),
+    (
+        "Q6",
+        &[
+            "The witness of a P2WPKH input is [DER signature || 0x01, compressed public key]",
+            "The sighash of the input commits to the HASH160 of the public key and to the amount it spends",
+            "The serialization is the version, 0x00 0x01, the body, the witness items of every input with their lengths, then the lock time",
+        ],
+    ),
+    (
+        "Q7",
+        &[
+            "Before segwit, the signer had to trust the amount of the input it was told",
+            "Deriving keys and signing need the secrets; building the transaction and encoding addresses do not",
+            "Watch-only wallets derive both branches from the xpub, and a change output on a receiving address links payments",
+        ],
+    ),
+    (
+        "Q8",
+        &[
+            "`core` has no heap, no files, no clock and no threads; `alloc` brings back `Vec` and `String` given an allocator",
+            "`HashSet` needs a random seed from the operating system, `BTreeSet` does not; a device logs over a serial port, if at all",
+            "`seed::rng`, `Runner` and the `println!` of the harness need std; a device draws from its hardware RNG and gets the transaction over USB",
+        ],
+    ),
+];
@@ -0,0 +1,377 @@
+use address::*;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_secp256k1::{Affine, Fr};
+use ark_std::{ops::Mul, Zero};
+use bip32::*;
+use bip39::*;
+use ecdsa::*;
+use runner::Runner;
+use sha2::{Digest, Sha256};
+use transaction::*;
+
+mod address;
+mod bip32;
+mod bip39;
+mod ecdsa;
+mod fr;
+mod hints;
+mod transaction;
+
+// The capstone: a wallet from its mnemonic to a signed transaction, every step written again by hand
+// The previous chapters did each piece on its own (BIP32 and ECDSA in schnorr, HMAC in hashes), here they must
+// fit together byte for byte: one wrong endianness and the transaction is not the expected one
+fn main() {
+    let runner = Runner::start(env!("CARGO_PKG_NAME"), hints::CATALOGS);
+
+    // Take a look at module `bip39`
+    // Q1: write `mnemonic_to_seed`, PBKDF2-HMAC-SHA512 of the mnemonic and the passphrase
+    runner.question("Q1");
+    assert_eq!(hex(&mnemonic_to_seed(TEST_MNEMONIC, "TREZOR")), TREZOR_SEED);
+    let seed = mnemonic_to_seed(TEST_MNEMONIC, "");
+    assert_ne!(seed, mnemonic_to_seed(TEST_MNEMONIC, "TREZOR"));
+
+    // Take a look at module `bip32`
+    // Q2: write `master_key`, `derive_child` and `derive_path`
+    runner.question("Q2");
+    for (path, public_key_hex, _) in BIP84_VECTORS {
+        let key = derive_path(&seed, path);
+        assert_eq!(hex(&ser_p(&public_key(&key))), public_key_hex, "{}", path);
+        assert_eq!(key.depth, 5);
+        assert_eq!(key.child_number, *parse_path(path).last().unwrap());
+    }
+    let master = master_key(&seed);
+    assert_eq!(derive_path(&seed, "m"), master);
+    assert_eq!(
+        derive_path(&seed, "m/84'/0'"),
+        derive_child(&derive_child(&master, 84 + HARDENED), HARDENED)
+    );
+
+    // Take a look at module `address`
+    // Q3: write `segwit_address`, the Bech32 encoding of a witness version and program
+    runner.question("Q3");
+    assert_eq!(
+        hex(&ripemd160(b"")),
+        "9c1185a5c5e9fc54612808977ee8f548b2258d31"
+    );
+    assert_eq!(
+        hex(&ripemd160(b"abc")),
+        "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"
+    );
+    for (path, public_key_hex, address) in BIP84_VECTORS {
+        let program = hash160(&unhex(public_key_hex));
+        assert_eq!(segwit_address("bc", 0, &program), address, "{}", path);
+        assert_eq!(
+            decode_segwit_address("bc", address),
+            Some((0, program.to_vec()))
+        );
+    }
+    // the example of BIP173, and a witness program of 32 bytes on testnet
+    let (version, program) = decode_segwit_address("bc", RECIPIENT).unwrap();
+    assert_eq!(segwit_address("bc", version, &program), RECIPIENT);
+    let program: Vec<u8> = (0..32).collect();
+    let address = segwit_address("tb", 0, &program);
+    assert_eq!(decode_segwit_address("tb", &address), Some((0, program)));
+    assert_eq!(decode_segwit_address("bc", &address), None);
+
+    // Take a look at module `transaction`
+    // Q4: write `bip143_sighash`, the hash an input of a segwit transaction signs with SIGHASH_ALL
+    runner.question("Q4");
+    let bip143 = Transaction::parse(&unhex(BIP143_UNSIGNED)).unwrap();
+    assert_eq!(hex(&bip143.serialize_without_witness()), BIP143_UNSIGNED);
+    let bip143_hash = hash160(&unhex(BIP143_PUBLIC_KEY));
+    let sighash = bip143_sighash(&bip143, 1, &bip143_hash, BIP143_AMOUNT);
+    assert_eq!(hex(&sighash), BIP143_SIGHASH);
+    assert_ne!(
+        bip143_sighash(&bip143, 1, &bip143_hash, BIP143_AMOUNT + 1),
+        sighash
+    );
+
+    // Take a look at module `ecdsa`
+    // Q5: write `rfc6979_nonce`, `ecdsa_sign` with a low s, and `der_encode`
+    runner.question("Q5");
+    // Bitcoin Core signed the example of BIP143: the same key and sighash give the same signature
+    let bip143_key = scalar_from_be(&unhex(BIP143_SECRET_KEY));
+    let (r, s) = ecdsa_sign(&bip143_key, &sighash);
+    let mut witness_signature = der_encode(&r, &s);
+    witness_signature.push(SIGHASH_ALL as u8);
+    assert_eq!(hex(&witness_signature), BIP143_SIGNATURE);
+    let bip143_public_key = decompress(&unhex(BIP143_PUBLIC_KEY)).unwrap();
+    for i in 0..100u32 {
+        let z = sha256d(&i.to_le_bytes());
+        let signature = ecdsa_sign(&bip143_key, &z);
+        assert!(is_low_s(&signature.1));
+        assert!(ecdsa_verify(
+            &scalar_from_be(&z),
+            &signature,
+            &bip143_public_key
+        ));
+        assert_eq!(
+            der_decode(&der_encode(&signature.0, &signature.1)),
+            Some(signature)
+        );
+        assert_eq!(ecdsa_sign(&bip143_key, &z), signature);
+    }
+
+    // Q6: write `sign_p2wpkh_input`, which sets the witness of an input, and `serialize`, the segwit
+    // serialization of BIP144, then pay the recipient from the wallet
+    runner.question("Q6");
+    let funding_key = derive_path(&seed, BIP84_VECTORS[0].0);
+    let change_key = derive_path(&seed, BIP84_VECTORS[2].0);
+    let (version, program) = decode_segwit_address("bc", RECIPIENT).unwrap();
+    let mut tx = Transaction {
+        version: 2,
+        inputs: vec![TxIn {
+            txid: parse_txid(FUNDING_TXID),
+            vout: FUNDING_VOUT,
+            script_sig: vec![],
+            sequence: 0xfffffffd, // replaceable by fee, BIP125
+            witness: vec![],
+        }],
+        outputs: vec![
+            TxOut {
+                value: PAYMENT,
+                script_pubkey: segwit_script_pubkey(version, &program),
+            },
+            TxOut {
+                value: FUNDING_AMOUNT - PAYMENT - FEE,
+                script_pubkey: segwit_script_pubkey(0, &hash160(&ser_p(&public_key(&change_key)))),
+            },
+        ],
+        lock_time: 0,
+    };
+    sign_p2wpkh_input(&mut tx, 0, &funding_key, FUNDING_AMOUNT);
+    let signed = serialize(&tx);
+    assert_eq!(hex(&signed), EXPECTED_TX);
+    assert_eq!(tx.txid(), EXPECTED_TXID);
+    assert_eq!(display_hash(&sha256d(&signed)), EXPECTED_WTXID);
+    // what a node checks, from the bytes alone: the witness key matches the spent output and signs the sighash
+    let parsed = Transaction::parse(&signed).unwrap();
+    assert_eq!(parsed, tx);
+    let [signature, witness_key] = &parsed.inputs[0].witness[..] else {
+        panic!("a P2WPKH witness holds a signature and a public key");
+    };
+    let funding_hash = hash160(witness_key);
+    let funding_address = segwit_address("bc", 0, &funding_hash);
+    assert_eq!(funding_address, BIP84_VECTORS[0].2);
+    let (der, sighash_type) = signature.split_at(signature.len() - 1);
+    assert_eq!(sighash_type, [SIGHASH_ALL as u8]);
+    let z = bip143_sighash(&parsed, 0, &funding_hash, FUNDING_AMOUNT);
+    assert!(ecdsa_verify(
+        &scalar_from_be(&z),
+        &der_decode(der).unwrap(),
+        &decompress(witness_key).unwrap()
+    ));
+    println!(
+        "Paid {} sat to {} from {}: txid {}",
+        PAYMENT, RECIPIENT, funding_address, EXPECTED_TXID
+    );
+
+    // Q7: the fee is implicit, the inputs minus the outputs: what could a compromised computer make an offline
+    // signer pay before BIP143 signed the amount? Which of these steps must a hardware wallet run itself, and
+    // which can the computer do? Why does the change go to another branch of the tree than the receiving
+    // addresses?
+    runner.question("Q7");
+
+    // A hardware wallet runs the signing steps on a microcontroller, without an operating system: the `tweak`,
+    // `secret` and `canonical` crates build there without their default `std` feature
+    //   rustup target add thumbv7em-none-eabihf
+    //   cargo build --no-default-features --target thumbv7em-none-eabihf   (from the directory of each crate)
+    // Q8: build them: which parts of `std` did they give up, and what replaces `println!`, a `HashSet` or
+    // `std::error::Error` there? Which functions of this chapter would not build, and how would the device get
+    // its randomness, its PBKDF2 time budget and the transaction to sign?
+    runner.question("Q8");
+
+    println!("Good job!");
+    runner.finish();
+}
+
+fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> [u8; 64] {
+    // SOLUTION-BEGIN
+    let salt = format!("mnemonic{}", passphrase);
+    let mut u = hmac_sha512(
+        mnemonic.as_bytes(),
+        &[salt.as_bytes(), &1u32.to_be_bytes()].concat(),
+    );
+    let mut seed = u;
+    for _ in 1..PBKDF2_ITERATIONS {
+        u = hmac_sha512(mnemonic.as_bytes(), &u);
+        for (byte, u_byte) in seed.iter_mut().zip(u) {
+            *byte ^= u_byte;
+        }
+    }
+    seed
+    // SOLUTION-END
+}
+
+fn master_key(seed: &[u8]) -> ExtendedPrivateKey {
+    // SOLUTION-BEGIN
+    let i = hmac_sha512(b"Bitcoin seed", seed);
+    ExtendedPrivateKey {
+        depth: 0,
+        child_number: 0,
+        chain_code: i[32..].try_into().unwrap(),
+        key: parse256(&i[..32])
+            .filter(|k| !k.is_zero())
+            .expect("invalid master key"),
+    }
+    // SOLUTION-END
+}
+
+fn derive_child(parent: &ExtendedPrivateKey, index: u32) -> ExtendedPrivateKey {
+    // SOLUTION-BEGIN
+    let mut data = Vec::new();
+    if index >= HARDENED {
+        data.push(0);
+        data.extend(ser256(&parent.key));
+    } else {
+        data.extend(ser_p(&public_key(parent)));
+    }
+    data.extend(index.to_be_bytes());
+    let i = hmac_sha512(&parent.chain_code, &data);
+    let key =
+        tweak::tweak_add_private(&parent.key, &parse256(&i[..32]).expect("invalid child key"))
+            .expect("invalid child key");
+    ExtendedPrivateKey {
+        depth: parent.depth + 1,
+        child_number: index,
+        chain_code: i[32..].try_into().unwrap(),
+        key,
+    }
+    // SOLUTION-END
+}
+
+fn derive_path(seed: &[u8], path: &str) -> ExtendedPrivateKey {
+    // SOLUTION-BEGIN
+    parse_path(path)
+        .into_iter()
+        .fold(master_key(seed), |key, index| derive_child(&key, index))
+    // SOLUTION-END
+}
+
+fn segwit_address(hrp: &str, version: u8, program: &[u8]) -> String {
+    // SOLUTION-BEGIN
+    let mut data = vec![version];
+    data.extend(convert_bits(program, 8, 5, true).unwrap());
+    let polymod = bech32_polymod(&[hrp_expand(hrp), data.clone(), vec![0; 6]].concat()) ^ 1;
+    data.extend((0..6).map(|i| (polymod >> (5 * (5 - i)) & 31) as u8));
+    let characters: String = data.iter().map(|&v| CHARSET[v as usize] as char).collect();
+    format!("{}1{}", hrp, characters)
+    // SOLUTION-END
+}
+
+fn bip143_sighash(
+    tx: &Transaction,
+    index: usize,
+    public_key_hash: &[u8; 20],
+    amount: u64,
+) -> [u8; 32] {
+    // SOLUTION-BEGIN
+    let hash_prevouts = sha256d(
+        &tx.inputs
+            .iter()
+            .flat_map(TxIn::outpoint)
+            .collect::<Vec<u8>>(),
+    );
+    let hash_sequence = sha256d(
+        &tx.inputs
+            .iter()
+            .flat_map(|input| input.sequence.to_le_bytes())
+            .collect::<Vec<u8>>(),
+    );
+    let hash_outputs = sha256d(
+        &tx.outputs
+            .iter()
+            .flat_map(TxOut::serialize)
+            .collect::<Vec<u8>>(),
+    );
+    let input = &tx.inputs[index];
+    let preimage = [
+        &tx.version.to_le_bytes()[..],
+        &hash_prevouts,
+        &hash_sequence,
+        &input.outpoint(),
+        &p2wpkh_script_code(public_key_hash),
+        &amount.to_le_bytes(),
+        &input.sequence.to_le_bytes(),
+        &hash_outputs,
+        &tx.lock_time.to_le_bytes(),
+        &SIGHASH_ALL.to_le_bytes(),
+    ]
+    .concat();
+    sha256d(&preimage)
+    // SOLUTION-END
+}
+
+fn rfc6979_nonce(secret_key: &Fr, z: &[u8; 32]) -> Fr {
+    // SOLUTION-BEGIN
+    // q = n has 256 bits like the hash: bits2int is the identity and bits2octets a reduction mod n
+    let seed = [ser256(secret_key), ser256(&scalar_from_be(z))].concat();
+    let mut v = [1u8; 32];
+    let mut k = [0u8; 32];
+    for separator in [0, 1] {
+        k = hmac_sha256(&k, &[&v[..], &[separator], &seed].concat());
+        v = hmac_sha256(&k, &v);
+    }
+    loop {
+        v = hmac_sha256(&k, &v);
+        if let Some(nonce) = parse256(&v).filter(|nonce| !nonce.is_zero()) {
+            return nonce;
+        }
+        k = hmac_sha256(&k, &[&v[..], &[0]].concat());
+        v = hmac_sha256(&k, &v);
+    }
+    // SOLUTION-END
+}
+
+fn ecdsa_sign(secret_key: &Fr, z: &[u8; 32]) -> (Fr, Fr) {
+    // SOLUTION-BEGIN
+    let k = rfc6979_nonce(secret_key, z);
+    let r = x_mod_n(&Affine::generator().mul(k).into_affine());
+    let s = (scalar_from_be(z) + r * secret_key) * k.inverse().unwrap();
+    (r, if is_low_s(&s) { s } else { -s })
+    // SOLUTION-END
+}
+
+fn der_encode(r: &Fr, s: &Fr) -> Vec<u8> {
+    // SOLUTION-BEGIN
+    let integer = |x: &Fr| {
+        let bytes = x.into_bigint().to_bytes_be();
+        let start = bytes.iter().position(|byte| *byte != 0).unwrap_or(31);
+        let mut minimal = bytes[start..].to_vec();
+        if minimal[0] >= 0x80 {
+            minimal.insert(0, 0);
+        }
+        [vec![0x02, minimal.len() as u8], minimal].concat()
+    };
+    let body = [integer(r), integer(s)].concat();
+    [vec![0x30, body.len() as u8], body].concat()
+    // SOLUTION-END
+}
+
+fn sign_p2wpkh_input(tx: &mut Transaction, index: usize, key: &ExtendedPrivateKey, amount: u64) {
+    // SOLUTION-BEGIN
+    let public_key = ser_p(&public_key(key));
+    let z = bip143_sighash(tx, index, &hash160(&public_key), amount);
+    let (r, s) = ecdsa_sign(&key.key, &z);
+    let mut signature = der_encode(&r, &s);
+    signature.push(SIGHASH_ALL as u8);
+    tx.inputs[index].witness = vec![signature, public_key.to_vec()];
+    // SOLUTION-END
+}
+
+fn serialize(tx: &Transaction) -> Vec<u8> {
+    // SOLUTION-BEGIN
+    let mut bytes = tx.version.to_le_bytes().to_vec();
+    bytes.extend([0x00, 0x01]); // marker and flag
+    bytes.extend(tx.serialize_body());
+    for input in &tx.inputs {
+        bytes.extend(compact_size(input.witness.len()));
+        for item in &input.witness {
+            bytes.extend(with_length(item));
+        }
+    }
+    bytes.extend(tx.lock_time.to_le_bytes());
+    bytes
+    // SOLUTION-END
+}
@@ -0,0 +1,246 @@
+use crate::*;
+
+// A Bitcoin transaction, serialized with little-endian integers and "compact size" lengths
+// (one byte below 0xfd, then 0xfd || 2 bytes, 0xfe || 4 bytes, 0xff || 8 bytes):
+//   version (4) || #inputs || inputs || #outputs || outputs || lock_time (4)
+// - an input: the txid of the previous transaction (32, in the byte order of the hash, the reverse of how it is
+//   displayed) || the index of its output (4) || the script (empty for segwit) || sequence (4)
+// - an output: the amount in satoshis (8) || the script_pubkey
+// A segwit transaction (BIP144) inserts the marker 0x00 and the flag 0x01 after the version, and the witness of
+// every input before the lock time: #items || (length || item)... For P2WPKH the witness is the DER signature
+// followed by the sighash type, then the compressed public key
+// The txid is the double SHA-256 of the serialization without the witness: a signature does not change it
+// The wtxid hashes everything
+//
+// BIP143 signs, for input i spending `amount` from HASH160 h (double SHA-256 of the preimage):
+//   version || hashPrevouts || hashSequence || outpoint_i || scriptCode || amount (8) || sequence_i ||
+//   hashOutputs || lock_time || sighash type (4)
+// where hashPrevouts = SHA256d(all the outpoints), hashSequence = SHA256d(all the sequences), hashOutputs =
+// SHA256d(all the serialized outputs) for SIGHASH_ALL, and scriptCode is the P2PKH script of h with its length:
+// 0x19 0x76 0xa9 0x14 || h || 0x88 0xac
+
+pub const SIGHASH_ALL: u32 = 1;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TxIn {
+    pub txid: [u8; 32],
+    pub vout: u32,
+    pub script_sig: Vec<u8>,
+    pub sequence: u32,
+    pub witness: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TxOut {
+    pub value: u64,
+    pub script_pubkey: Vec<u8>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Transaction {
+    pub version: u32,
+    pub inputs: Vec<TxIn>,
+    pub outputs: Vec<TxOut>,
+    pub lock_time: u32,
+}
+
+// The native P2WPKH example of BIP143: the unsigned transaction, its second input spends 6 BTC from the HASH160
+// of `BIP143_PUBLIC_KEY` with `BIP143_SECRET_KEY`, and the sighash and witness signature of this input
+pub const BIP143_UNSIGNED: &str = "\
+    0100000002fff7f7881a8099afa6940d42d1e7f6362bec38171ea3edf433541db4e4ad969f0000000000eeffffffef51e1b804cc89d1\
+    82d279655c3aa89e815b1b309fe287d9b2b55d57b90ec68a0100000000ffffffff02202cb206000000001976a9148280b37df378db99\
+    f66f85c95a783a76ac7a6d5988ac9093510d000000001976a9143bde42dbee7e4dbe6a21b2d50ce2f0167faa815988ac11000000";
+pub const BIP143_AMOUNT: u64 = 600_000_000;
+pub const BIP143_SECRET_KEY: &str =
+    "619c335025c7f4012e556c2a58b2506e30b8511b53ade95ea316fd8c3286feb9";
+pub const BIP143_PUBLIC_KEY: &str =
+    "025476c2e83188368da1ff3e292e7acafcdb3566bb0ad253f62fc70f07aeee6357";
+pub const BIP143_SIGHASH: &str = "c37af31116d1b27caf68aae9e3ac82f1477929014d5b917657d0eb49478cb670";
+pub const BIP143_SIGNATURE: &str = "\
+    304402203609e17b84f6a7d30c80bfa610b5b4542f32a8a0d5447a12fb1366d7f01cc44a0220573a954c4518331561406f90300e8f33\
+    58f51928d43c212a8caed02de67eebee01";
+
+// The transaction of the exam: the wallet of `TEST_MNEMONIC` received `FUNDING_AMOUNT` on its first address
+// (output `FUNDING_VOUT` of `FUNDING_TXID`, as displayed), and pays `PAYMENT` to `RECIPIENT`, the rest minus
+// `FEE` going to its first change address
+// `EXPECTED_TX`, its txid and wtxid were computed by an independent implementation in Python
+pub const FUNDING_TXID: &str = "73b229103fe4e04b76cf9a032dbb116cf2bc338d5f7664bcb6aaaad8caf57404";
+pub const FUNDING_VOUT: u32 = 1;
+pub const FUNDING_AMOUNT: u64 = 100_000;
+pub const RECIPIENT: &str = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4";
+pub const PAYMENT: u64 = 60_000;
+pub const FEE: u64 = 1_000;
+pub const EXPECTED_TX: &str = "\
+    020000000001010474f5cad8aaaab6bc64765f8d33bcf26c11bb2d039acf764be0e43f1029b2730100000000fdffffff0260ea000000\
+    000000160014751e76e8199196d454941c45d1b3a323f1433bd658980000000000001600143e34985dca6fddc9fb369940e4c7d8e287\
+    3f529c02483045022100f79dec9d0b127161a945bb191e1df03d27f4f7f1e429868cae2e0b830c0d4c7e022060a8d4717e66e8c8e403\
+    4e1b7ec53c235ca6bf75b59437813d941a8a43afdf2801210330d54fd0dd420a6e5f8d3624f5f3482cae350f79d5f0753bf5beef9c2d\
+    91af3c00000000";
+pub const EXPECTED_TXID: &str = "77add6cfcf50371759117e953ffc18f90412821c85f89b0f097ed87d79881057";
+pub const EXPECTED_WTXID: &str = "f186c5fb65af8690ad7b6ffd73152d021d9a0c04319797add26c045962571365";
+
+pub fn sha256d(bytes: &[u8]) -> [u8; 32] {
+    Sha256::digest(Sha256::digest(bytes)).into()
+}
+
+pub fn compact_size(n: usize) -> Vec<u8> {
+    match n {
+        0..=0xfc => vec![n as u8],
+        0xfd..=0xffff => [&[0xfd], &(n as u16).to_le_bytes()[..]].concat(),
+        0x10000..=0xffffffff => [&[0xfe], &(n as u32).to_le_bytes()[..]].concat(),
+        _ => [&[0xff], &(n as u64).to_le_bytes()[..]].concat(),
+    }
+}
+
+pub fn with_length(bytes: &[u8]) -> Vec<u8> {
+    [compact_size(bytes.len()), bytes.to_vec()].concat()
+}
+
+impl TxIn {
+    pub fn outpoint(&self) -> Vec<u8> {
+        [&self.txid[..], &self.vout.to_le_bytes()].concat()
+    }
+}
+
+impl TxOut {
+    pub fn serialize(&self) -> Vec<u8> {
+        [
+            self.value.to_le_bytes().to_vec(),
+            with_length(&self.script_pubkey),
+        ]
+        .concat()
+    }
+}
+
+impl Transaction {
+    // #inputs || inputs || #outputs || outputs: what the two serializations share
+    pub fn serialize_body(&self) -> Vec<u8> {
+        let mut bytes = compact_size(self.inputs.len());
+        for input in &self.inputs {
+            bytes.extend(input.outpoint());
+            bytes.extend(with_length(&input.script_sig));
+            bytes.extend(input.sequence.to_le_bytes());
+        }
+        bytes.extend(compact_size(self.outputs.len()));
+        for output in &self.outputs {
+            bytes.extend(output.serialize());
+        }
+        bytes
+    }
+
+    pub fn serialize_without_witness(&self) -> Vec<u8> {
+        [
+            self.version.to_le_bytes().to_vec(),
+            self.serialize_body(),
+            self.lock_time.to_le_bytes().to_vec(),
+        ]
+        .concat()
+    }
+
+    // as displayed, in reverse byte order
+    pub fn txid(&self) -> String {
+        display_hash(&sha256d(&self.serialize_without_witness()))
+    }
+
+    // with or without witness, and nothing left over
+    pub fn parse(bytes: &[u8]) -> Option<Transaction> {
+        let mut reader = Reader(bytes);
+        let version = u32::from_le_bytes(reader.take(4)?.try_into().ok()?);
+        let segwit = reader.0.starts_with(&[0x00, 0x01]);
+        if segwit {
+            reader.take(2)?;
+        }
+        let mut inputs = vec![];
+        for _ in 0..reader.compact_size()? {
+            inputs.push(TxIn {
+                txid: reader.take(32)?.try_into().ok()?,
+                vout: u32::from_le_bytes(reader.take(4)?.try_into().ok()?),
+                script_sig: reader.with_length()?,
+                sequence: u32::from_le_bytes(reader.take(4)?.try_into().ok()?),
+                witness: vec![],
+            });
+        }
+        let mut outputs = vec![];
+        for _ in 0..reader.compact_size()? {
+            outputs.push(TxOut {
+                value: u64::from_le_bytes(reader.take(8)?.try_into().ok()?),
+                script_pubkey: reader.with_length()?,
+            });
+        }
+        if segwit {
+            for input in inputs.iter_mut() {
+                for _ in 0..reader.compact_size()? {
+                    input.witness.push(reader.with_length()?);
+                }
+            }
+        }
+        let lock_time = u32::from_le_bytes(reader.take(4)?.try_into().ok()?);
+        reader.0.is_empty().then_some(Transaction {
+            version,
+            inputs,
+            outputs,
+            lock_time,
+        })
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.0.len() < n {
+            return None;
+        }
+        let (taken, rest) = self.0.split_at(n);
+        self.0 = rest;
+        Some(taken)
+    }
+
+    fn compact_size(&mut self) -> Option<usize> {
+        let n = match self.take(1)?[0] {
+            0xfd => u16::from_le_bytes(self.take(2)?.try_into().ok()?) as usize,
+            0xfe => u32::from_le_bytes(self.take(4)?.try_into().ok()?) as usize,
+            0xff => u64::from_le_bytes(self.take(8)?.try_into().ok()?) as usize,
+            n => n as usize,
+        };
+        Some(n)
+    }
+
+    fn with_length(&mut self) -> Option<Vec<u8>> {
+        let length = self.compact_size()?;
+        Some(self.take(length)?.to_vec())
+    }
+}
+
+pub fn display_hash(hash: &[u8; 32]) -> String {
+    let mut reversed = *hash;
+    reversed.reverse();
+    hex(&reversed)
+}
+
+// the txid as displayed, into the byte order of an input
+pub fn parse_txid(displayed: &str) -> [u8; 32] {
+    let mut txid: [u8; 32] = unhex(displayed).try_into().unwrap();
+    txid.reverse();
+    txid
+}
+
+pub fn p2wpkh_script_code(public_key_hash: &[u8; 20]) -> Vec<u8> {
+    [
+        &[0x19, 0x76, 0xa9, 0x14],
+        &public_key_hash[..],
+        &[0x88, 0xac],
+    ]
+    .concat()
+}
+
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub fn unhex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
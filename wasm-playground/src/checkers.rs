@@ -0,0 +1,57 @@
+//! The checks of the ff-ec questions which fit in a few `u64`, for answers computed in the browser
+//!
+//! Each checker takes the inputs of the question and the student's answer, and tells whether the answer is
+//! right: the expected value is never returned, as with `canonical::assert_digest` in the native chapters.
+
+use crate::curve::*;
+use crate::field::*;
+
+// the toy curve of ff-ec: the equation of secp256k1 over F_89
+pub const TOY: Curve = Curve { p: 89, a: 0, b: 7 };
+
+// Q1: g generates F_89*
+pub fn q1(g: u64) -> bool {
+    g < TOY.p && is_generator(g, TOY.p)
+}
+
+// Q6: the number of points of y^2 = x^3 + 7 over F_p, the point at infinity included
+pub fn q6(p: u64, count: u64) -> bool {
+    is_prime(p) && Curve { p, a: 0, b: 7 }.count_points() == count
+}
+
+// Q10: the inverse of a mod p
+pub fn q10(a: u64, p: u64, inverse: u64) -> bool {
+    is_prime(p) && inverse < p && inverse_mod(a, p) == Some(inverse)
+}
+
+// Q19: the order of g in F_p*
+pub fn q19(g: u64, p: u64, order: u64) -> bool {
+    is_prime(p) && multiplicative_order(g, p) == Some(order)
+}
+
+// Q20: the number of elements of E(F_89)
+pub fn q20(group_order: u64) -> bool {
+    TOY.count_points() == group_order
+}
+
+// Q21: the order of the point (x, y) of E(F_89)
+pub fn q21(x: u64, y: u64, order: u64) -> bool {
+    let point = Some((x, y));
+    TOY.is_on_curve(point) && TOY.point_order(point) == order
+}
+
+// Q22: #E(F_89) = r h with r its largest prime factor
+pub fn q22(r: u64, h: u64) -> bool {
+    let group_order = TOY.count_points();
+    prime_factors(group_order).last() == Some(&r) && r * h == group_order
+}
+
+// Q25: the Legendre symbol of a mod p
+pub fn q25(a: u64, p: u64, symbol: i32) -> bool {
+    is_prime(p) && p > 2 && legendre_symbol(a, p) == symbol
+}
+
+// Q26: the trace of Frobenius of y^2 = x^3 + ax + b over F_p
+pub fn q26(a: u64, b: u64, p: u64, trace: i64) -> bool {
+    is_prime(p) && Curve { p, a, b }.trace_of_frobenius() == trace
+}
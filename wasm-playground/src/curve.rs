@@ -0,0 +1,94 @@
+//! Short Weierstrass curves y^2 = x^3 + ax + b over F_p, in affine coordinates with the point at infinity
+//! written `None`, as in ff-ec
+
+use crate::field::*;
+
+pub type Point = Option<(u64, u64)>;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Curve {
+    pub p: u64,
+    pub a: u64,
+    pub b: u64,
+}
+
+impl Curve {
+    // the right-hand side x^3 + ax + b
+    pub fn rhs(&self, x: u64) -> u64 {
+        let p = self.p;
+        add_mod(
+            add_mod(pow_mod(x, 3, p), mul_mod(self.a, x, p), p),
+            self.b,
+            p,
+        )
+    }
+
+    pub fn is_on_curve(&self, point: Point) -> bool {
+        match point {
+            None => true,
+            Some((x, y)) => x < self.p && y < self.p && mul_mod(y, y, self.p) == self.rhs(x),
+        }
+    }
+
+    pub fn neg(&self, point: Point) -> Point {
+        point.map(|(x, y)| (x, sub_mod(0, y, self.p)))
+    }
+
+    // chord and tangent
+    pub fn add(&self, p1: Point, p2: Point) -> Point {
+        let p = self.p;
+        let ((x1, y1), (x2, y2)) = match (p1, p2) {
+            (None, _) => return p2,
+            (_, None) => return p1,
+            (Some(p1), Some(p2)) => (p1, p2),
+        };
+        let slope = if x1 == x2 {
+            if add_mod(y1, y2, p) == 0 {
+                return None;
+            }
+            let numerator = add_mod(mul_mod(3, mul_mod(x1, x1, p), p), self.a, p);
+            mul_mod(numerator, inverse_mod(mul_mod(2, y1, p), p)?, p)
+        } else {
+            mul_mod(sub_mod(y2, y1, p), inverse_mod(sub_mod(x2, x1, p), p)?, p)
+        };
+        let x3 = sub_mod(sub_mod(mul_mod(slope, slope, p), x1, p), x2, p);
+        let y3 = sub_mod(mul_mod(slope, sub_mod(x1, x3, p), p), y1, p);
+        Some((x3, y3))
+    }
+
+    // double-and-add
+    pub fn mul(&self, mut k: u64, point: Point) -> Point {
+        let (mut result, mut addend) = (None, point);
+        while k > 0 {
+            if k & 1 == 1 {
+                result = self.add(result, addend);
+            }
+            addend = self.add(addend, addend);
+            k >>= 1;
+        }
+        result
+    }
+
+    // the point at infinity, then one or two points per x with the Legendre symbol of x^3 + ax + b
+    pub fn count_points(&self) -> u64 {
+        1 + (0..self.p)
+            .map(|x| (1 + legendre_symbol(self.rhs(x), self.p)) as u64)
+            .sum::<u64>()
+    }
+
+    // p + 1 - #E
+    pub fn trace_of_frobenius(&self) -> i64 {
+        (self.p + 1) as i64 - self.count_points() as i64
+    }
+
+    // the order divides #E: remove its prime factors as long as the multiple stays the point at infinity
+    pub fn point_order(&self, point: Point) -> u64 {
+        let mut order = self.count_points();
+        for q in prime_factors(order) {
+            while order.is_multiple_of(q) && self.mul(order / q, point).is_none() {
+                order /= q;
+            }
+        }
+        order
+    }
+}
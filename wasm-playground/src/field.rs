@@ -0,0 +1,149 @@
+//! Arithmetic in F_p for a prime p < 2^63, on plain `u64` so that every value crosses the wasm boundary as is
+
+pub fn add_mod(a: u64, b: u64, p: u64) -> u64 {
+    ((a as u128 + b as u128) % p as u128) as u64
+}
+
+pub fn sub_mod(a: u64, b: u64, p: u64) -> u64 {
+    add_mod(a % p, p - b % p, p)
+}
+
+pub fn mul_mod(a: u64, b: u64, p: u64) -> u64 {
+    (a as u128 * b as u128 % p as u128) as u64
+}
+
+pub fn pow_mod(mut base: u64, mut exponent: u64, p: u64) -> u64 {
+    let mut result = 1 % p;
+    base %= p;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, p);
+        }
+        base = mul_mod(base, base, p);
+        exponent >>= 1;
+    }
+    result
+}
+
+// with the extended Euclidean algorithm, None for a multiple of p
+pub fn inverse_mod(a: u64, p: u64) -> Option<u64> {
+    let (mut r0, mut r1) = (p as i128, (a % p) as i128);
+    let (mut u0, mut u1) = (0i128, 1i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (u0, u1) = (u1, u0 - q * u1);
+    }
+    (r0 == 1).then(|| u0.rem_euclid(p as i128) as u64)
+}
+
+// Euler's criterion: 1, 0 or -1 as a is a non-zero square, zero or not a square
+pub fn legendre_symbol(a: u64, p: u64) -> i32 {
+    match pow_mod(a, (p - 1) / 2, p) {
+        0 => 0,
+        1 => 1,
+        _ => -1,
+    }
+}
+
+// Tonelli-Shanks, the smaller of the two roots
+pub fn sqrt_mod(a: u64, p: u64) -> Option<u64> {
+    let a = a % p;
+    if a == 0 || p == 2 {
+        return Some(a);
+    }
+    if legendre_symbol(a, p) != 1 {
+        return None;
+    }
+    let (mut q, mut s) = (p - 1, 0);
+    while q % 2 == 0 {
+        q /= 2;
+        s += 1;
+    }
+    let z = (2..p).find(|&z| legendre_symbol(z, p) == -1).unwrap();
+    let (mut m, mut c, mut t, mut r) = (
+        s,
+        pow_mod(z, q, p),
+        pow_mod(a, q, p),
+        pow_mod(a, q.div_ceil(2), p),
+    );
+    while t != 1 {
+        let mut i = 1;
+        let mut t2i = mul_mod(t, t, p);
+        while t2i != 1 {
+            t2i = mul_mod(t2i, t2i, p);
+            i += 1;
+        }
+        let b = pow_mod(c, 1 << (m - i - 1), p);
+        (m, c) = (i, mul_mod(b, b, p));
+        t = mul_mod(t, c, p);
+        r = mul_mod(r, b, p);
+    }
+    Some(r.min(p - r))
+}
+
+// Miller-Rabin with the first 12 primes as bases, deterministic below 2^64
+pub fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    if let Some(&base) = BASES.iter().find(|&&base| n.is_multiple_of(base)) {
+        return n == base;
+    }
+    let (mut d, mut s) = (n - 1, 0);
+    while d % 2 == 0 {
+        d /= 2;
+        s += 1;
+    }
+    BASES.iter().all(|&base| {
+        let mut x = pow_mod(base, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+// trial division, the distinct prime factors: fine for the toy sizes of a workshop
+pub fn prime_factors(mut n: u64) -> Vec<u64> {
+    let mut factors = vec![];
+    let mut d = 2;
+    while d * d <= n {
+        if n.is_multiple_of(d) {
+            factors.push(d);
+            while n.is_multiple_of(d) {
+                n /= d;
+            }
+        }
+        d += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+// the order of g in F_p*: divide p - 1 by every prime factor while g^(order / q) stays 1
+pub fn multiplicative_order(g: u64, p: u64) -> Option<u64> {
+    if g.is_multiple_of(p) {
+        return None;
+    }
+    let mut order = p - 1;
+    for q in prime_factors(p - 1) {
+        while order.is_multiple_of(q) && pow_mod(g, order / q, p) == 1 {
+            order /= q;
+        }
+    }
+    Some(order)
+}
+
+pub fn is_generator(g: u64, p: u64) -> bool {
+    multiplicative_order(g, p) == Some(p - 1)
+}
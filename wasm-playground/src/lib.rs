@@ -0,0 +1,171 @@
+//! The field and curve operations of ff-ec, and the checkers of its questions, in a WebAssembly module for
+//! workshops where the students cannot install a Rust toolchain: they open `www/index.html` and answer in
+//! JavaScript
+//!
+//! ```text
+//! rustup target add wasm32-unknown-unknown
+//! cargo build --release --target wasm32-unknown-unknown
+//! cp ../target/wasm32-unknown-unknown/release/wasm_playground.wasm www/
+//! python3 -m http.server -d www
+//! ```
+//!
+//! The module has no dependency and needs no JavaScript glue: the exported functions take and return
+//! integers only, `u64` being a `BigInt` on the JavaScript side (`www/playground.js` wraps the conversions).
+//! Every value of F_p is an integer in [0, p), and `NONE` (2^64 - 1) stands for a missing value: the inverse
+//! of zero, the square root of a non-square, or the coordinates of the point at infinity.
+//!
+//! A curve operation returns the x-coordinate of its result, and `last_y` the y-coordinate, as a function
+//! cannot return two values through the C ABI without a pointer into the memory of the module.
+//!
+//! The chapters themselves do not build for `wasm32-unknown-unknown`: the runner reads and writes files and
+//! measures time, which this target has not, and arkworks would need `getrandom` with its `js` feature.
+
+use std::cell::Cell;
+
+pub mod checkers;
+pub mod curve;
+pub mod field;
+
+use curve::{Curve, Point};
+
+pub const NONE: u64 = u64::MAX;
+
+thread_local! {
+    static LAST_Y: Cell<u64> = const { Cell::new(NONE) };
+}
+
+fn to_point(x: u64, y: u64) -> Point {
+    (x != NONE).then_some((x, y))
+}
+
+// returns x, keeps y for `last_y`
+fn from_point(point: Point) -> u64 {
+    let (x, y) = point.unwrap_or((NONE, NONE));
+    LAST_Y.with(|last_y| last_y.set(y));
+    x
+}
+
+#[no_mangle]
+pub extern "C" fn last_y() -> u64 {
+    LAST_Y.with(Cell::get)
+}
+
+#[no_mangle]
+pub extern "C" fn fp_add(a: u64, b: u64, p: u64) -> u64 {
+    field::add_mod(a, b, p)
+}
+
+#[no_mangle]
+pub extern "C" fn fp_sub(a: u64, b: u64, p: u64) -> u64 {
+    field::sub_mod(a, b, p)
+}
+
+#[no_mangle]
+pub extern "C" fn fp_mul(a: u64, b: u64, p: u64) -> u64 {
+    field::mul_mod(a, b, p)
+}
+
+#[no_mangle]
+pub extern "C" fn fp_pow(a: u64, exponent: u64, p: u64) -> u64 {
+    field::pow_mod(a, exponent, p)
+}
+
+#[no_mangle]
+pub extern "C" fn fp_inv(a: u64, p: u64) -> u64 {
+    field::inverse_mod(a, p).unwrap_or(NONE)
+}
+
+#[no_mangle]
+pub extern "C" fn fp_sqrt(a: u64, p: u64) -> u64 {
+    field::sqrt_mod(a, p).unwrap_or(NONE)
+}
+
+#[no_mangle]
+pub extern "C" fn fp_legendre(a: u64, p: u64) -> i32 {
+    field::legendre_symbol(a, p)
+}
+
+#[no_mangle]
+pub extern "C" fn fp_order(g: u64, p: u64) -> u64 {
+    field::multiplicative_order(g, p).unwrap_or(NONE)
+}
+
+#[no_mangle]
+pub extern "C" fn is_prime(n: u64) -> bool {
+    field::is_prime(n)
+}
+
+#[no_mangle]
+pub extern "C" fn ec_is_on_curve(p: u64, a: u64, b: u64, x: u64, y: u64) -> bool {
+    Curve { p, a, b }.is_on_curve(to_point(x, y))
+}
+
+#[no_mangle]
+pub extern "C" fn ec_neg(p: u64, a: u64, b: u64, x: u64, y: u64) -> u64 {
+    from_point(Curve { p, a, b }.neg(to_point(x, y)))
+}
+
+#[no_mangle]
+pub extern "C" fn ec_add(p: u64, a: u64, b: u64, x1: u64, y1: u64, x2: u64, y2: u64) -> u64 {
+    from_point(Curve { p, a, b }.add(to_point(x1, y1), to_point(x2, y2)))
+}
+
+#[no_mangle]
+pub extern "C" fn ec_mul(p: u64, a: u64, b: u64, k: u64, x: u64, y: u64) -> u64 {
+    from_point(Curve { p, a, b }.mul(k, to_point(x, y)))
+}
+
+#[no_mangle]
+pub extern "C" fn ec_count_points(p: u64, a: u64, b: u64) -> u64 {
+    Curve { p, a, b }.count_points()
+}
+
+#[no_mangle]
+pub extern "C" fn ec_point_order(p: u64, a: u64, b: u64, x: u64, y: u64) -> u64 {
+    Curve { p, a, b }.point_order(to_point(x, y))
+}
+
+#[no_mangle]
+pub extern "C" fn check_q1(g: u64) -> bool {
+    checkers::q1(g)
+}
+
+#[no_mangle]
+pub extern "C" fn check_q6(p: u64, count: u64) -> bool {
+    checkers::q6(p, count)
+}
+
+#[no_mangle]
+pub extern "C" fn check_q10(a: u64, p: u64, inverse: u64) -> bool {
+    checkers::q10(a, p, inverse)
+}
+
+#[no_mangle]
+pub extern "C" fn check_q19(g: u64, p: u64, order: u64) -> bool {
+    checkers::q19(g, p, order)
+}
+
+#[no_mangle]
+pub extern "C" fn check_q20(group_order: u64) -> bool {
+    checkers::q20(group_order)
+}
+
+#[no_mangle]
+pub extern "C" fn check_q21(x: u64, y: u64, order: u64) -> bool {
+    checkers::q21(x, y, order)
+}
+
+#[no_mangle]
+pub extern "C" fn check_q22(r: u64, h: u64) -> bool {
+    checkers::q22(r, h)
+}
+
+#[no_mangle]
+pub extern "C" fn check_q25(a: u64, p: u64, symbol: i32) -> bool {
+    checkers::q25(a, p, symbol)
+}
+
+#[no_mangle]
+pub extern "C" fn check_q26(a: u64, b: u64, p: u64, trace: i64) -> bool {
+    checkers::q26(a, b, p, trace)
+}
@@ -0,0 +1,61 @@
+// Loads `wasm_playground.wasm` and wraps its exports: numbers go in as BigInt, field elements and coordinates
+// come back as BigInt, `null` standing for a missing value (the NONE of the module) and points being
+// [x, y] arrays or `null` for the point at infinity
+
+const NONE = 2n ** 64n - 1n;
+
+export async function load(url = "wasm_playground.wasm") {
+  const { instance } = await WebAssembly.instantiateStreaming(fetch(url));
+  const wasm = instance.exports;
+  const big = (values) => values.map((value) => BigInt(value));
+  const value = (result) => (result === NONE ? null : result);
+  const coordinates = (point) => (point === null ? [NONE, NONE] : big(point));
+  const point = (x) => (x === NONE ? null : [x, wasm.last_y()]);
+
+  const fp = {
+    add: (a, b, p) => wasm.fp_add(...big([a, b, p])),
+    sub: (a, b, p) => wasm.fp_sub(...big([a, b, p])),
+    mul: (a, b, p) => wasm.fp_mul(...big([a, b, p])),
+    pow: (a, e, p) => wasm.fp_pow(...big([a, e, p])),
+    inv: (a, p) => value(wasm.fp_inv(...big([a, p]))),
+    sqrt: (a, p) => value(wasm.fp_sqrt(...big([a, p]))),
+    legendre: (a, p) => wasm.fp_legendre(...big([a, p])),
+    order: (g, p) => value(wasm.fp_order(...big([g, p]))),
+    isPrime: (n) => wasm.is_prime(BigInt(n)) === 1,
+  };
+
+  // y^2 = x^3 + ax + b over F_p
+  const curve = (p, a, b) => {
+    const params = big([p, a, b]);
+    return {
+      isOnCurve: (P) => wasm.ec_is_on_curve(...params, ...coordinates(P)) === 1,
+      neg: (P) => point(wasm.ec_neg(...params, ...coordinates(P))),
+      add: (P, Q) => point(wasm.ec_add(...params, ...coordinates(P), ...coordinates(Q))),
+      mul: (k, P) => point(wasm.ec_mul(...params, BigInt(k), ...coordinates(P))),
+      countPoints: () => wasm.ec_count_points(...params),
+      pointOrder: (P) => wasm.ec_point_order(...params, ...coordinates(P)),
+    };
+  };
+
+  // the questions of ff-ec, and the arguments of their checkers: the inputs, then the answer
+  const checkers = {
+    Q1: (g) => wasm.check_q1(BigInt(g)),
+    Q6: (p, count) => wasm.check_q6(...big([p, count])),
+    Q10: (a, p, inverse) => wasm.check_q10(...big([a, p, inverse])),
+    Q19: (g, p, order) => wasm.check_q19(...big([g, p, order])),
+    Q20: (groupOrder) => wasm.check_q20(BigInt(groupOrder)),
+    Q21: (x, y, order) => wasm.check_q21(...big([x, y, order])),
+    Q22: (r, h) => wasm.check_q22(...big([r, h])),
+    Q25: (a, p, symbol) => wasm.check_q25(...big([a, p]), Number(symbol)),
+    Q26: (a, b, p, trace) => wasm.check_q26(...big([a, b, p, trace])),
+  };
+  const check = (question, ...args) => {
+    const checker = checkers[question.toUpperCase()];
+    if (!checker) {
+      throw new Error(`no checker for ${question}, try one of ${Object.keys(checkers).join(", ")}`);
+    }
+    return checker(...args) === 1;
+  };
+
+  return { fp, curve, check, questions: Object.keys(checkers) };
+}
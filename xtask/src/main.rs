@@ -0,0 +1,238 @@
+// Maintenance commands of the repository, `cargo xtask <command>` from its root (see .cargo/config.toml)
+//
+//   cargo xtask skeletons <directory>           writes the student version of the repository there
+//   cargo xtask skeletons --check               only checks the solution markers of every chapter
+//
+// The repository is the single source of truth: every exercise is written with its reference solution
+// between a `// SOLUTION-BEGIN` and a `// SOLUTION-END` line, and the student version is generated from it.
+// `skeletons` copies the files git tracks, and in the Rust sources
+// - replaces each marked block with `todo!()`, at the indentation of its `// SOLUTION-BEGIN`
+// - drops the items under `#[cfg(feature = "solutions")]`, and the files of the modules among them
+// then drops the `solutions` feature from the manifests, and leaves this crate and .cargo out
+// A marker alone on its line, in pairs and without nesting, is the only accepted form: anything else stops
+// the command before it writes a file, with the file and line to fix
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+
+const BEGIN: &str = "// SOLUTION-BEGIN";
+const END: &str = "// SOLUTION-END";
+const SOLUTIONS_ONLY: &str = "#[cfg(feature = \"solutions\")]";
+const SOLUTIONS_FEATURE: &str = "solutions = []";
+const PLACEHOLDER: &str = "todo!()";
+// the files which only make sense next to the solutions
+const LEFT_OUT: [&str; 2] = ["xtask/", ".cargo/"];
+
+// a Rust source with its solutions stripped
+struct Skeleton {
+    source: String,
+    solutions: usize,
+    // the files of the modules declared under `SOLUTIONS_ONLY`, relative to the directory of the source
+    solution_modules: Vec<String>,
+}
+
+fn indentation(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
+
+fn strip(source: &str) -> Result<Skeleton, String> {
+    let mut skeleton = Skeleton {
+        source: String::new(),
+        solutions: 0,
+        solution_modules: vec![],
+    };
+    let mut kept: Vec<String> = vec![];
+    let mut lines = source.lines().enumerate();
+    let mut open: Option<usize> = None;
+    while let Some((i, line)) = lines.next() {
+        let trimmed = line.trim();
+        let number = i + 1;
+        if trimmed == BEGIN {
+            if let Some(begin) = open {
+                return Err(format!(
+                    "line {}: nested in the block of line {}",
+                    number, begin
+                ));
+            }
+            open = Some(number);
+            skeleton.solutions += 1;
+            kept.push(format!("{}{}", indentation(line), PLACEHOLDER));
+        } else if trimmed == END {
+            if open.take().is_none() {
+                return Err(format!("line {}: {} without {}", number, END, BEGIN));
+            }
+        } else if line.contains(&BEGIN[3..]) || line.contains(&END[3..]) {
+            return Err(format!(
+                "line {}: a marker must be alone on its line",
+                number
+            ));
+        } else if open.is_some() {
+            // the solution
+        } else if trimmed == SOLUTIONS_ONLY {
+            // with the comments on the item
+            while kept
+                .last()
+                .is_some_and(|last| last.trim_start().starts_with("//"))
+            {
+                kept.pop();
+            }
+            let Some((_, item)) = lines.next() else {
+                return Err(format!("line {}: no item after the attribute", number));
+            };
+            if let Some(module) = item.trim().strip_prefix("mod ") {
+                let module = module.trim_end_matches(';');
+                skeleton.solution_modules.push(format!("{}.rs", module));
+            } else if item.trim_end().ends_with('{') {
+                let closing = format!("{}}}", indentation(item));
+                if !lines.any(|(_, line)| line.trim_end() == closing) {
+                    return Err(format!("line {}: the item never closes", number));
+                }
+            } else if !item.trim_end().ends_with(';') {
+                return Err(format!("line {}: cannot tell where the item ends", number));
+            }
+        } else {
+            kept.push(line.to_string());
+        }
+    }
+    match open {
+        Some(begin) => Err(format!("line {}: {} without {}", begin, BEGIN, END)),
+        None => {
+            skeleton.source = kept.join("\n") + "\n";
+            Ok(skeleton)
+        }
+    }
+}
+
+// the manifest without the `solutions` feature and its comment
+fn strip_manifest(manifest: &str) -> String {
+    let mut lines: Vec<&str> = vec![];
+    for line in manifest.lines() {
+        if line.trim() == SOLUTIONS_FEATURE {
+            while lines.last().is_some_and(|last| last.starts_with('#')) {
+                lines.pop();
+            }
+        } else {
+            lines.push(line);
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+fn tracked_files(root: &Path) -> Result<Vec<String>, String> {
+    let output = Command::new("git")
+        .args(["ls-files", "-z"])
+        .current_dir(root)
+        .output()
+        .map_err(|error| format!("cannot run git: {}", error))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).into_owned());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .split('\0')
+        .filter(|path| !path.is_empty())
+        .filter(|path| !LEFT_OUT.iter().any(|prefix| path.starts_with(prefix)))
+        .map(String::from)
+        .collect())
+}
+
+fn skeletons(root: &Path, output: Option<&Path>) -> Result<(), String> {
+    let files = tracked_files(root)?;
+    // every source is stripped before anything is written, so that a bad marker leaves no half-written tree
+    let mut stripped: BTreeMap<&str, Skeleton> = BTreeMap::new();
+    let mut errors = vec![];
+    for path in files.iter().filter(|path| path.ends_with(".rs")) {
+        let source = std::fs::read_to_string(root.join(path))
+            .map_err(|error| format!("{}: {}", path, error))?;
+        match strip(&source) {
+            Ok(skeleton) => {
+                stripped.insert(path, skeleton);
+            }
+            Err(error) => errors.push(format!("{}: {}", path, error)),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors.join("\n"));
+    }
+    let mut left_out: Vec<PathBuf> = vec![];
+    for (path, skeleton) in &stripped {
+        let directory = Path::new(path).parent().unwrap_or(Path::new(""));
+        left_out.extend(
+            skeleton
+                .solution_modules
+                .iter()
+                .map(|module| directory.join(module)),
+        );
+    }
+
+    let mut chapters: BTreeMap<&str, usize> = BTreeMap::new();
+    for (path, skeleton) in stripped
+        .iter()
+        .filter(|(_, skeleton)| skeleton.solutions > 0)
+    {
+        let chapter = path.split('/').next().unwrap_or(path);
+        *chapters.entry(chapter).or_default() += skeleton.solutions;
+    }
+    for (chapter, solutions) in &chapters {
+        println!("{}: {} solutions", chapter, solutions);
+    }
+    println!(
+        "{} solutions in {} chapters",
+        chapters.values().sum::<usize>(),
+        chapters.len()
+    );
+
+    let Some(output) = output else {
+        return Ok(());
+    };
+    if output
+        .read_dir()
+        .is_ok_and(|mut entries| entries.next().is_some())
+    {
+        return Err(format!(
+            "{} is not empty, remove it first",
+            output.display()
+        ));
+    }
+    for path in &files {
+        if left_out.iter().any(|left_out| Path::new(path) == left_out) {
+            continue;
+        }
+        let (source, destination) = (root.join(path), output.join(path));
+        if let Some(parent) = destination.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|error| format!("{}: {}", parent.display(), error))?;
+        }
+        let written = match stripped.get(path.as_str()) {
+            Some(skeleton) => std::fs::write(&destination, &skeleton.source),
+            None if path.ends_with("Cargo.toml") => std::fs::read_to_string(&source)
+                .and_then(|manifest| std::fs::write(&destination, strip_manifest(&manifest))),
+            None => std::fs::copy(&source, &destination).map(|_| ()),
+        };
+        written.map_err(|error| format!("{}: {}", destination.display(), error))?;
+    }
+    println!("student version written to {}", output.display());
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let result = match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        ["skeletons", "--check"] => skeletons(&root, None),
+        ["skeletons", output] => skeletons(&root, Some(Path::new(output))),
+        _ => Err("usage: cargo xtask skeletons <directory> | --check".to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use runner::lang::{Catalog, Lang, Statements};
+
+pub const CATALOG: Catalog = Catalog {
+    lang: Lang::Fr,
+    statements: Statements::Table(&[
+        ("Q1", "écrire `ConstraintSystem::alloc_input`, `ConstraintSystem::alloc_witness` et `LinearCombination::evaluate`"),
+        ("Q2", "écrire `ConstraintSystem::enforce` et `ConstraintSystem::which_is_unsatisfied`,\nqui renvoie l'indice de la première contrainte non satisfaite"),
+        ("Q3", "arithmétiser « je connais x tel que x^3 + x + 5 = out » dans `cube_equation`, avec out une entrée publique\nUtiliser aussi peu de contraintes que possible : rappelez-vous que les additions sont gratuites"),
+        ("Q4", "écrire `range_check`, qui prouve qu'une variable est inférieure à 2^bits avec sa décomposition binaire :\nune contrainte b (1 - b) = 0 par bit, puis une pour la recomposition"),
+        ("Q5", "combien de contraintes prend une comparaison a < b de deux valeurs de 64 bits ?\nPourquoi ne peut-on pas utiliser `range_check` avec bits = 255 pour prouver qu'une valeur est « petite » ?"),
+        ("Q6", "écrire `sbox_gadget`, qui contraint x^5 avec 3 contraintes"),
+        ("Q7", "écrire `poseidon_preimage_circuit` : « je connais (x_1, x_2) tel que Poseidon(x_1, x_2) = digest »,\nle haché étant la seule entrée publique"),
+        ("Q8", "que calcule le vérifieur, et comment son coût croît-il avec le nombre de contraintes ?\nAvec le nombre d'entrées publiques ? Pourquoi la mise en place doit-elle être refaite pour chaque circuit, et que\npourrait faire quelqu'un qui connaît tau ? Comparer avec une préimage SHA-256 (environ 25000 contraintes par bloc)"),
+        ("Q9", "implémenter `SumcheckProver` pour `HonestProver` : le polynôme du tour est donné par ses valeurs en 0 et 1\n(des sommes sur le reste de l'hypercube), et un défi fixe la première variable restante"),
+        ("Q10", "écrire `SumcheckVerifier::receive`, qui vérifie un polynôme de tour contre l'affirmation courante et\nrépond par un défi aléatoire (None pour rejeter), et `SumcheckVerifier::finish`, la requête finale à l'oracle"),
+        ("Q11", "le tricheur est attrapé ici avec probabilité 1 - 1/|F|, comment cela évolue-t-il avec le degré\nde f en chaque variable ? Le vérifieur a fait v + 1 opérations de corps par tour et une requête à f :\nqui évalue f en un point aléatoire dans un vrai système de preuve, et avec quoi ?"),
+    ]),
+    hints: &[
+        (
+            "Q3",
+            &[
+                "Une contrainte est A * B = C avec des combinaisons linéaires A, B et C : une multiplication chacune",
+                "x^3 + x + 5 demande deux multiplications : x * x = x^2, puis x^2 * x = x^3",
+                "Allouer x^2 et x^3 comme témoins, puis imposer (x^3 + x + 5) * 1 = out, les additions étant gratuites",
+            ],
+        ),
+        (
+            "Q4",
+            &[
+                "Un bit b vérifie b (1 - b) = 0, et une valeur inférieure à 2^bits est une somme de bits b_i 2^i",
+                "Allouer chaque bit de la valeur comme témoin et imposer b_i * (1 - b_i) = 0",
+                "Enfin imposer (sum b_i 2^i) * 1 = value, la somme étant une seule combinaison linéaire",
+            ],
+        ),
+        (
+            "Q5",
+            &[
+                "a < b si et seulement si b - a - 1 est dans [0, 2^64), une vérification d'intervalle de la différence",
+                "Les éléments du corps reviennent à zéro modulo p",
+                "p < 2^255 : chaque élément du corps a une décomposition sur 255 bits, donc la vérification ne prouve rien",
+            ],
+        ),
+        (
+            "Q9",
+            &[
+                "Le polynôme du tour est g(X) = somme de f(X, x_2, ..., x_v) sur le reste de l'hypercube",
+                "f est multilinéaire, donc g est de degré 1 et donné par g(0) et g(1)",
+                "g(0) et g(1) sont `sum_over_hypercube` de `fix_first_variable(0)` et de `fix_first_variable(1)`, et un défi r remplace le polynôme par `fix_first_variable(r)`",
+            ],
+        ),
+    ],
+};
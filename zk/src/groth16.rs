@@ -0,0 +1,202 @@
+use crate::*;
+use ark_bls12_381::{Bls12_381, G1Affine, G1Projective, G2Affine, G2Projective};
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup, Group, VariableBaseMSM};
+use ark_poly::{EvaluationDomain, Evaluations, GeneralEvaluationDomain};
+use ark_std::ops::Mul;
+
+// Groth16 (Eurocrypt 2016), the SNARK with the smallest proofs: 2 points of G1 and 1 of G2,
+// checked with 3 pairings whatever the size of the circuit
+// - R1CS to QAP: number the constraints j = 0..n over a domain {w^j} of size n, and interpolate for each
+//   variable k the polynomials u_k, v_k, w_k with u_k(w^j) = a_j[k] (resp. b, c). With
+//   A(X) = sum z_k u_k(X) (resp. B, C), z satisfies the constraints iff A B - C vanishes on the domain,
+//   i.e. A B - C = H Z with Z(X) = X^n - 1
+// - setup: the "toxic waste" alpha, beta, gamma, delta, tau gives the proving key (the powers of tau,
+//   the u_k(tau), v_k(tau) and combinations of them, in the groups) and the verifying key
+// - prove: A = alpha + A(tau) + r delta, B = beta + B(tau) + s delta, and
+//   C = (sum_witness z_k (beta u_k + alpha v_k + w_k)(tau) + H(tau) Z(tau)) / delta + s A + r B - r s delta
+// - verify: e(A, B) = e(alpha, beta) e(sum_input x_k (beta u_k + alpha v_k + w_k)(tau) / gamma, gamma) e(C, delta)
+// As in arkworks, the constraints z_k * 0 = 0 for the constant and the inputs are appended,
+// so that the polynomials of the public inputs are linearly independent
+// Here a single party samples the toxic waste: in practice, run a multi-party ceremony!
+
+pub struct ProvingKey {
+    pub alpha_g1: G1Affine,
+    pub beta_g1: G1Affine,
+    pub beta_g2: G2Affine,
+    pub delta_g1: G1Affine,
+    pub delta_g2: G2Affine,
+    pub a_query: Vec<G1Affine>,    // u_k(tau) for every variable
+    pub b_g1_query: Vec<G1Affine>, // v_k(tau)
+    pub b_g2_query: Vec<G2Affine>, // v_k(tau)
+    pub h_query: Vec<G1Affine>,    // tau^i Z(tau) / delta
+    pub l_query: Vec<G1Affine>,    // (beta u_k + alpha v_k + w_k)(tau) / delta for the witness
+}
+
+pub struct VerifyingKey {
+    pub alpha_g1: G1Affine,
+    pub beta_g2: G2Affine,
+    pub gamma_g2: G2Affine,
+    pub delta_g2: G2Affine,
+    pub gamma_abc_g1: Vec<G1Affine>, // (beta u_k + alpha v_k + w_k)(tau) / gamma for 1 and the inputs
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Proof {
+    pub a: G1Affine,
+    pub b: G2Affine,
+    pub c: G1Affine,
+}
+
+// the position of a variable in z = (1, inputs, witness)
+fn column(cs: &ConstraintSystem, variable: Variable) -> usize {
+    match variable {
+        Variable::One => 0,
+        Variable::Input(i) => 1 + i,
+        Variable::Witness(i) => 1 + cs.inputs.len() + i,
+    }
+}
+
+// the rows (a_j, b_j, c_j) of the QAP: the constraints, then z_k * 0 = 0 for 1 and the inputs
+fn rows(cs: &ConstraintSystem) -> Vec<Constraint> {
+    let input_rows = (0..=cs.inputs.len()).map(|k| Constraint {
+        a: if k == 0 {
+            Variable::One.into()
+        } else {
+            Variable::Input(k - 1).into()
+        },
+        b: LinearCombination::default(),
+        c: LinearCombination::default(),
+    });
+    cs.constraints.iter().cloned().chain(input_rows).collect()
+}
+
+fn domain(cs: &ConstraintSystem) -> GeneralEvaluationDomain<Fr> {
+    GeneralEvaluationDomain::new(cs.num_constraints() + cs.inputs.len() + 1).unwrap()
+}
+
+fn batch_mul_g1(base: G1Projective, scalars: &[Fr]) -> Vec<G1Affine> {
+    G1Projective::normalize_batch(&scalars.iter().map(|x| base * x).collect::<Vec<_>>())
+}
+
+pub fn setup<R: Rng>(cs: &ConstraintSystem, rng: &mut R) -> (ProvingKey, VerifyingKey) {
+    let [tau, alpha, beta, gamma, delta] = [(); 5].map(|_| Fr::rand(rng));
+    let domain = domain(cs);
+    let lagrange = domain.evaluate_all_lagrange_coefficients(tau);
+    let variables = 1 + cs.inputs.len() + cs.witnesses.len();
+    let (mut u, mut v, mut w) = (
+        vec![Fr::zero(); variables],
+        vec![Fr::zero(); variables],
+        vec![Fr::zero(); variables],
+    );
+    for (row, l_j) in rows(cs).iter().zip(&lagrange) {
+        for (polynomials, lc) in [(&mut u, &row.a), (&mut v, &row.b), (&mut w, &row.c)] {
+            for (coefficient, variable) in &lc.0 {
+                polynomials[column(cs, *variable)] += *coefficient * l_j;
+            }
+        }
+    }
+    let (g1, g2) = (G1Projective::generator(), G2Projective::generator());
+    let combined: Vec<Fr> = (0..variables)
+        .map(|k| beta * u[k] + alpha * v[k] + w[k])
+        .collect();
+    let public = 1 + cs.inputs.len();
+    let (gamma_inverse, delta_inverse) = (gamma.inverse().unwrap(), delta.inverse().unwrap());
+    let z_tau = domain.evaluate_vanishing_polynomial(tau);
+    let mut power = z_tau * delta_inverse;
+    let h_scalars: Vec<Fr> = (0..domain.size() - 1)
+        .map(|_| {
+            let current = power;
+            power *= tau;
+            current
+        })
+        .collect();
+    let proving_key = ProvingKey {
+        alpha_g1: (g1 * alpha).into_affine(),
+        beta_g1: (g1 * beta).into_affine(),
+        beta_g2: (g2 * beta).into_affine(),
+        delta_g1: (g1 * delta).into_affine(),
+        delta_g2: (g2 * delta).into_affine(),
+        a_query: batch_mul_g1(g1, &u),
+        b_g1_query: batch_mul_g1(g1, &v),
+        b_g2_query: G2Projective::normalize_batch(&v.iter().map(|x| g2 * x).collect::<Vec<_>>()),
+        h_query: batch_mul_g1(g1, &h_scalars),
+        l_query: batch_mul_g1(
+            g1,
+            &combined[public..]
+                .iter()
+                .map(|x| *x * delta_inverse)
+                .collect::<Vec<_>>(),
+        ),
+    };
+    let verifying_key = VerifyingKey {
+        alpha_g1: proving_key.alpha_g1,
+        beta_g2: proving_key.beta_g2,
+        gamma_g2: (g2 * gamma).into_affine(),
+        delta_g2: proving_key.delta_g2,
+        gamma_abc_g1: batch_mul_g1(
+            g1,
+            &combined[..public]
+                .iter()
+                .map(|x| *x * gamma_inverse)
+                .collect::<Vec<_>>(),
+        ),
+    };
+    (proving_key, verifying_key)
+}
+
+// the constraint system holds the assignment of the prover
+pub fn prove<R: Rng>(proving_key: &ProvingKey, cs: &ConstraintSystem, rng: &mut R) -> Proof {
+    let domain = domain(cs);
+    let evaluations = |side: fn(&Constraint) -> &LinearCombination| {
+        let mut values: Vec<Fr> = rows(cs).iter().map(|row| side(row).evaluate(cs)).collect();
+        values.resize(domain.size(), Fr::zero());
+        Evaluations::from_vec_and_domain(values, domain).interpolate()
+    };
+    let a = evaluations(|row| &row.a);
+    let b = evaluations(|row| &row.b);
+    let c = evaluations(|row| &row.c);
+    // H = (A B - C) / Z, the remainder is zero if the constraints hold
+    let (h, _) = (&(&a * &b) - &c).divide_by_vanishing_poly(domain).unwrap();
+
+    let z: Vec<Fr> = [Fr::one()]
+        .into_iter()
+        .chain(cs.inputs.iter().cloned())
+        .chain(cs.witnesses.iter().cloned())
+        .collect();
+    let witness = &z[1 + cs.inputs.len()..];
+    let (r, s) = (Fr::rand(rng), Fr::rand(rng));
+    let proof_a = G1Projective::msm(&proving_key.a_query, &z).unwrap()
+        + proving_key.alpha_g1
+        + proving_key.delta_g1 * r;
+    let proof_b = G2Projective::msm(&proving_key.b_g2_query, &z).unwrap()
+        + proving_key.beta_g2
+        + proving_key.delta_g2 * s;
+    let proof_b_g1 = G1Projective::msm(&proving_key.b_g1_query, &z).unwrap()
+        + proving_key.beta_g1
+        + proving_key.delta_g1 * s;
+    let h_coefficients = &h.coeffs[..h.coeffs.len().min(proving_key.h_query.len())];
+    let proof_c = G1Projective::msm(&proving_key.l_query, witness).unwrap()
+        + G1Projective::msm(&proving_key.h_query[..h_coefficients.len()], h_coefficients).unwrap()
+        + proof_a * s
+        + proof_b_g1 * r
+        - proving_key.delta_g1 * (r * s);
+    Proof {
+        a: proof_a.into_affine(),
+        b: proof_b.into_affine(),
+        c: proof_c.into_affine(),
+    }
+}
+
+pub fn verify(verifying_key: &VerifyingKey, inputs: &[Fr], proof: &Proof) -> bool {
+    if inputs.len() + 1 != verifying_key.gamma_abc_g1.len() {
+        return false;
+    }
+    let public = verifying_key.gamma_abc_g1[1..].iter().zip(inputs).fold(
+        verifying_key.gamma_abc_g1[0].into_group(),
+        |acc, (point, x)| acc + point.mul(x),
+    );
+    Bls12_381::pairing(proof.a, proof.b)
+        == Bls12_381::pairing(verifying_key.alpha_g1, verifying_key.beta_g2)
+            + Bls12_381::pairing(public, verifying_key.gamma_g2)
+            + Bls12_381::pairing(proof.c, verifying_key.delta_g2)
+}
@@ -0,0 +1,42 @@
+use runner::hints::Hints;
+use runner::lang::Catalog;
+
+pub const CATALOGS: &[Catalog] = &[
+    Catalog::english(HINTS, include_str!("main.rs")),
+    crate::fr::CATALOG,
+];
+
+pub const HINTS: Hints = &[
+    (
+        "Q3",
+        &[
+            "A constraint is A * B = C with linear combinations A, B and C: one multiplication each",
+            "x^3 + x + 5 needs two multiplications: x * x = x^2, then x^2 * x = x^3",
+            "Allocate x^2 and x^3 as witnesses, then enforce (x^3 + x + 5) * 1 = out, additions being free",
+        ],
+    ),
+    (
+        "Q4",
+        &[
+            "A bit b satisfies b (1 - b) = 0, and a value below 2^bits is a sum of bits b_i 2^i",
+            "Allocate each bit of the value as a witness and enforce b_i * (1 - b_i) = 0",
+            "Finally enforce (sum b_i 2^i) * 1 = value, the sum being a single linear combination",
+        ],
+    ),
+    (
+        "Q5",
+        &[
+            "a < b iff b - a - 1 is in [0, 2^64), a range check of the difference",
+            "Field elements wrap around modulo p",
+            "p < 2^255: every field element has a 255-bit decomposition, so the check proves nothing",
+        ],
+    ),
+    (
+        "Q9",
+        &[
+            "The round polynomial is g(X) = sum of f(X, x_2, ..., x_v) over the rest of the hypercube",
+            "f is multilinear, so g has degree 1 and is given by g(0) and g(1)",
+            "g(0) and g(1) are `sum_over_hypercube` of `fix_first_variable(0)` and `fix_first_variable(1)`, and a challenge r replaces the polynomial by `fix_first_variable(r)`",
+        ],
+    ),
+];
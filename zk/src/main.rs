@@ -0,0 +1,460 @@
+use ark_bls12_381::Fr;
+use ark_ff::{BigInteger, Field, One, PrimeField, Zero};
+use ark_serialize::CanonicalSerialize;
+use ark_std::UniformRand;
+use groth16::*;
+use poseidon::*;
+use r1cs::*;
+use rand::Rng;
+use runner::Runner;
+use std::str::FromStr;
+use std::time::Instant;
+use sumcheck::*;
+
+mod fr;
+mod groth16;
+mod hints;
+mod poseidon;
+mod r1cs;
+mod sumcheck;
+
+const SUMCHECK_VARIABLES: usize = 10;
+
+fn main() {
+    let runner = Runner::start(env!("CARGO_PKG_NAME"), hints::CATALOGS);
+    let mut rng = seed::rng();
+
+    // Take a look at module `r1cs`
+    // Q1: write `ConstraintSystem::alloc_input`, `ConstraintSystem::alloc_witness` and `LinearCombination::evaluate`
+    runner.question("Q1");
+    let mut cs = ConstraintSystem::new();
+    let a = cs.alloc_input(Fr::from(3));
+    let b = cs.alloc_witness(Fr::from(5));
+    let c = cs.alloc_witness(Fr::from(7));
+    assert_eq!(
+        (a, b, c),
+        (
+            Variable::Input(0),
+            Variable::Witness(0),
+            Variable::Witness(1)
+        )
+    );
+    assert_eq!(cs.value(c), Fr::from(7));
+    let lc = LinearCombination::from(a) * Fr::from(2) + b - Fr::from(1);
+    assert_eq!(lc.evaluate(&cs), Fr::from(10));
+    assert_eq!((lc.clone() - lc).evaluate(&cs), Fr::zero());
+
+    // Q2: write `ConstraintSystem::enforce` and `ConstraintSystem::which_is_unsatisfied`,
+    // which returns the index of the first constraint that does not hold
+    runner.question("Q2");
+    cs.enforce(a, b, Fr::from(15));
+    cs.enforce(LinearCombination::from(a) + b, Variable::One, Fr::from(8));
+    cs.enforce(
+        LinearCombination::from(c) - a,
+        c,
+        LinearCombination::from(b) * Fr::from(7) - c,
+    );
+    assert_eq!(cs.num_constraints(), 3);
+    assert!(cs.is_satisfied());
+    cs.enforce(a, a, b);
+    assert_eq!(cs.which_is_unsatisfied(), Some(3));
+    assert!(!cs.is_satisfied());
+
+    // Q3: arithmetize "I know x such that x^3 + x + 5 = out" in `cube_equation`, with out a public input
+    // Use as few constraints as you can: remember that additions are free
+    runner.question("Q3");
+    let mut cs = ConstraintSystem::new();
+    cube_equation(&mut cs, Fr::from(3), Fr::from(35));
+    assert_eq!(cs.num_constraints(), 3);
+    assert_eq!(cs.inputs, vec![Fr::from(35)]);
+    assert_eq!(cs.witnesses.len(), 3);
+    assert!(cs.is_satisfied());
+    // a wrong x, or a prover tampering with the intermediate values, is caught
+    let mut wrong = ConstraintSystem::new();
+    cube_equation(&mut wrong, Fr::from(4), Fr::from(35));
+    assert!(!wrong.is_satisfied());
+    for i in 0..cs.witnesses.len() {
+        let mut tampered = cs.clone();
+        tampered.witnesses[i] += Fr::one();
+        assert!(!tampered.is_satisfied());
+    }
+    let x = Fr::rand(&mut rng);
+    let mut cs = ConstraintSystem::new();
+    cube_equation(&mut cs, x, x * x * x + x + Fr::from(5));
+    assert!(cs.is_satisfied());
+
+    // Q4: write `range_check`, which proves that a variable is smaller than 2^bits with its binary decomposition:
+    // one constraint b (1 - b) = 0 per bit, then one for the recomposition
+    runner.question("Q4");
+    for value in [0u64, 1, 200, 255] {
+        let mut cs = ConstraintSystem::new();
+        let v = cs.alloc_witness(Fr::from(value));
+        let bits = range_check(&mut cs, v, 8);
+        assert_eq!(bits.len(), 8);
+        assert_eq!(cs.num_constraints(), 9);
+        assert!(cs.is_satisfied());
+        let recomposed = bits
+            .iter()
+            .rev()
+            .fold(0, |acc, bit| 2 * acc + (cs.value(*bit) == Fr::one()) as u64);
+        assert_eq!(recomposed, value);
+    }
+    let mut cs = ConstraintSystem::new();
+    let v = cs.alloc_witness(Fr::from(256));
+    let bits = range_check(&mut cs, v, 8);
+    assert_eq!(cs.which_is_unsatisfied(), Some(8));
+    // a cheating prover "decomposes" 256 as 256 * 2^0: the booleanity of the first bit catches her
+    let Variable::Witness(first) = bits[0] else {
+        panic!("the bits are private")
+    };
+    let mut cheating = cs.clone();
+    cheating.witnesses[first] = Fr::from(256);
+    assert_eq!(cheating.which_is_unsatisfied(), Some(0));
+
+    // Q5: how many constraints does a comparison a < b of two 64-bit values take?
+    // Why can't `range_check` be used with bits = 255 to prove that a value is "small"?
+    runner.question("Q5");
+
+    // A real SNARK now: take a look at modules `poseidon` and `groth16`
+    let parameters = PoseidonParameters::bls12_381_width_3();
+    assert_eq!(
+        poseidon_hash_two(&parameters, [Fr::from(1), Fr::from(2)]),
+        Fr::from_str(
+            "17237620914439447153424407850011932919400002140179467175894423994780641947098"
+        )
+        .unwrap()
+    );
+    // Q6: write `sbox_gadget`, which constrains x^5 with 3 constraints
+    runner.question("Q6");
+    let mut cs = ConstraintSystem::new();
+    let x = cs.alloc_witness(Fr::from(3));
+    let y = sbox_gadget(&mut cs, LinearCombination::from(x) + Fr::from(1));
+    assert_eq!(cs.value(y), Fr::from(1024));
+    assert_eq!(cs.num_constraints(), 3);
+    assert!(cs.is_satisfied());
+
+    // Q7: write `poseidon_preimage_circuit`: "I know (x_1, x_2) such that Poseidon(x_1, x_2) = digest",
+    // the digest being the only public input
+    runner.question("Q7");
+    let preimage = [Fr::rand(&mut rng), Fr::rand(&mut rng)];
+    let digest = poseidon_hash_two(&parameters, preimage);
+    let mut cs = ConstraintSystem::new();
+    poseidon_preimage_circuit(&mut cs, &parameters, preimage, digest);
+    assert_eq!(cs.inputs, vec![digest]);
+    // 3 constraints per S-box, 3 S-boxes per full round and 1 per partial round, then the output
+    assert_eq!(cs.num_constraints(), 3 * (3 * 8 + 57) + 1);
+    assert!(cs.is_satisfied());
+    let mut wrong = ConstraintSystem::new();
+    poseidon_preimage_circuit(&mut wrong, &parameters, [preimage[1], preimage[0]], digest);
+    assert!(!wrong.is_satisfied());
+
+    // setup, prove and verify
+    let start = Instant::now();
+    let (proving_key, verifying_key) = setup(&cs, &mut rng);
+    let setup_time = start.elapsed();
+    let start = Instant::now();
+    let proof = prove(&proving_key, &cs, &mut rng);
+    let proving_time = start.elapsed();
+    let start = Instant::now();
+    assert!(verify(&verifying_key, &[digest], &proof));
+    let verifying_time = start.elapsed();
+    assert!(!verify(&verifying_key, &[digest + Fr::from(1)], &proof));
+    assert!(!verify(&verifying_key, &[], &proof));
+    // the proof is randomized: two proofs of the same statement differ
+    assert_ne!(prove(&proving_key, &cs, &mut rng), proof);
+    // a prover with a wrong witness fails
+    let mut cheating = cs.clone();
+    let i = rng.gen_range(0..cheating.witnesses.len());
+    cheating.witnesses[i] += Fr::from(1);
+    assert!(!verify(
+        &verifying_key,
+        &[digest],
+        &prove(&proving_key, &cheating, &mut rng)
+    ));
+
+    let mut proof_bytes = Vec::new();
+    (proof.a, proof.b, proof.c)
+        .serialize_compressed(&mut proof_bytes)
+        .unwrap();
+    assert_eq!(proof_bytes.len(), 192);
+    let proving_key_points = proving_key.a_query.len()
+        + proving_key.b_g1_query.len()
+        + proving_key.h_query.len()
+        + proving_key.l_query.len();
+    println!(
+        "Groth16 for {} constraints: setup {:?}, proving {:?}, verifying {:?}",
+        cs.num_constraints(),
+        setup_time,
+        proving_time,
+        verifying_time
+    );
+    println!(
+        "proof: {} bytes, proving key: {} points of G1 and {} of G2, verifying key: {} points",
+        proof_bytes.len(),
+        proving_key_points,
+        proving_key.b_g2_query.len(),
+        verifying_key.gamma_abc_g1.len() + 4
+    );
+
+    // Q8: what does the verifier compute, and how does its cost grow with the number of constraints?
+    // With the number of public inputs? Why must the setup be run again for every circuit, and what
+    // could somebody knowing tau do? Compare with a SHA-256 preimage (about 25000 constraints per block)
+    runner.question("Q8");
+
+    // Proof systems such as Spartan replace the QAP and the pairings of Groth16 by the sumcheck protocol
+    // Take a look at module `sumcheck`
+    let f = MultilinearPolynomial::random(SUMCHECK_VARIABLES, &mut rng);
+    let sum = f.sum_over_hypercube();
+    let point: Vec<Fr> = (0..SUMCHECK_VARIABLES)
+        .map(|_| Fr::rand(&mut rng))
+        .collect();
+    assert_eq!(
+        f.evaluate(&point[..]),
+        f.fix_first_variable(point[0]).evaluate(&point[1..])
+    );
+
+    // Q9: implement `SumcheckProver` for `HonestProver`: the round polynomial is given by its values at 0 and 1
+    // (sums over the rest of the hypercube), and a challenge fixes the first variable left
+    runner.question("Q9");
+    let mut prover = HonestProver::new(&f);
+    assert_eq!(prover.claimed_sum(), sum);
+    let g = prover.round_polynomial();
+    assert_eq!(g.at_zero + g.at_one, sum);
+    prover.receive_challenge(point[0]);
+    assert_eq!(prover.polynomial, f.fix_first_variable(point[0]));
+    assert_eq!(
+        prover.round_polynomial().at_zero + prover.round_polynomial().at_one,
+        g.evaluate(point[0])
+    );
+
+    // Q10: write `SumcheckVerifier::receive`, which checks a round polynomial against the current claim and
+    // answers with a random challenge (None to reject), and `SumcheckVerifier::finish`, the final oracle query
+    runner.question("Q10");
+    for _ in 0..10 {
+        let f = MultilinearPolynomial::random(SUMCHECK_VARIABLES, &mut rng);
+        assert!(run_sumcheck(&mut HonestProver::new(&f), &f, &mut rng));
+    }
+    // a prover lying about the sum is caught, in the first round if it sends the right g_1...
+    let mut verifier = SumcheckVerifier::new(SUMCHECK_VARIABLES, sum + Fr::one());
+    assert!(verifier
+        .receive(&HonestProver::new(&f).round_polynomial(), &mut rng)
+        .is_none());
+    // ... and at the oracle query if it keeps lying consistently
+    let mut cheater = CheatingProver {
+        honest: HonestProver::new(&f),
+        claim: sum + Fr::one(),
+    };
+    assert!(!run_sumcheck(&mut cheater, &f, &mut rng));
+    // the verifier does not accept more rounds than variables, nor finishes early
+    let verifier = SumcheckVerifier::new(SUMCHECK_VARIABLES, sum);
+    assert!(!verifier.finish(&f));
+    let mut prover = HonestProver::new(&f);
+    let mut verifier = SumcheckVerifier::new(SUMCHECK_VARIABLES, sum);
+    for _ in 0..SUMCHECK_VARIABLES {
+        let r = verifier
+            .receive(&prover.round_polynomial(), &mut rng)
+            .unwrap();
+        prover.receive_challenge(r);
+    }
+    let extra = RoundPolynomial {
+        at_zero: verifier.claim,
+        at_one: Fr::zero(),
+    };
+    assert!(verifier.receive(&extra, &mut rng).is_none());
+    assert!(verifier.finish(&f));
+
+    // Q11: the cheater is caught with probability 1 - 1/|F| here, how does it grow with the degree
+    // of f in each variable? The verifier did v + 1 field operations per round and one query to f:
+    // who evaluates f at a random point in a real proof system, with what?
+    runner.question("Q11");
+
+    println!("Good job!");
+    runner.finish();
+}
+
+impl ConstraintSystem {
+    fn alloc_input(&mut self, value: Fr) -> Variable {
+        // SOLUTION-BEGIN
+        self.inputs.push(value);
+        Variable::Input(self.inputs.len() - 1)
+        // SOLUTION-END
+    }
+
+    fn alloc_witness(&mut self, value: Fr) -> Variable {
+        // SOLUTION-BEGIN
+        self.witnesses.push(value);
+        Variable::Witness(self.witnesses.len() - 1)
+        // SOLUTION-END
+    }
+
+    // a * b = c
+    fn enforce(
+        &mut self,
+        a: impl Into<LinearCombination>,
+        b: impl Into<LinearCombination>,
+        c: impl Into<LinearCombination>,
+    ) {
+        // SOLUTION-BEGIN
+        self.constraints.push(Constraint {
+            a: a.into(),
+            b: b.into(),
+            c: c.into(),
+        });
+        // SOLUTION-END
+    }
+
+    fn which_is_unsatisfied(&self) -> Option<usize> {
+        // SOLUTION-BEGIN
+        self.constraints.iter().position(|constraint| {
+            constraint.a.evaluate(self) * constraint.b.evaluate(self) != constraint.c.evaluate(self)
+        })
+        // SOLUTION-END
+    }
+}
+
+impl LinearCombination {
+    fn evaluate(&self, cs: &ConstraintSystem) -> Fr {
+        // SOLUTION-BEGIN
+        self.0
+            .iter()
+            .map(|(coefficient, variable)| *coefficient * cs.value(*variable))
+            .sum()
+        // SOLUTION-END
+    }
+}
+
+fn cube_equation(cs: &mut ConstraintSystem, x: Fr, out: Fr) {
+    // SOLUTION-BEGIN
+    let out = cs.alloc_input(out);
+    let x_value = x;
+    let x = cs.alloc_witness(x_value);
+    let x_squared = cs.alloc_witness(x_value.square());
+    let x_cubed = cs.alloc_witness(x_value.square() * x_value);
+    cs.enforce(x, x, x_squared);
+    cs.enforce(x_squared, x, x_cubed);
+    cs.enforce(
+        LinearCombination::from(x_cubed) + x + Fr::from(5),
+        Variable::One,
+        out,
+    );
+    // SOLUTION-END
+}
+
+fn range_check(cs: &mut ConstraintSystem, v: Variable, bits: usize) -> Vec<Variable> {
+    // SOLUTION-BEGIN
+    let value = cs.value(v).into_bigint();
+    let bits: Vec<Variable> = (0..bits)
+        .map(|i| cs.alloc_witness(Fr::from(value.get_bit(i) as u64)))
+        .collect();
+    for bit in &bits {
+        cs.enforce(*bit, LinearCombination::from(Fr::one()) - *bit, Fr::zero());
+    }
+    let mut power = Fr::one();
+    let mut recomposition = LinearCombination::default();
+    for bit in &bits {
+        recomposition = recomposition + LinearCombination::from(*bit) * power;
+        power.double_in_place();
+    }
+    cs.enforce(recomposition, Variable::One, v);
+    bits
+    // SOLUTION-END
+}
+
+fn sbox_gadget(cs: &mut ConstraintSystem, x: LinearCombination) -> Variable {
+    // SOLUTION-BEGIN
+    let value = x.evaluate(cs);
+    let x2 = cs.alloc_witness(value.square());
+    let x4 = cs.alloc_witness(value.square().square());
+    let x5 = cs.alloc_witness(value.pow([5]));
+    cs.enforce(x.clone(), x.clone(), x2);
+    cs.enforce(x2, x2, x4);
+    cs.enforce(x4, x, x5);
+    x5
+    // SOLUTION-END
+}
+
+fn poseidon_preimage_circuit(
+    cs: &mut ConstraintSystem,
+    parameters: &PoseidonParameters,
+    preimage: [Fr; 2],
+    digest: Fr,
+) {
+    // SOLUTION-BEGIN
+    let digest = cs.alloc_input(digest);
+    let mut state: [LinearCombination; WIDTH] = [
+        Fr::from(2).into(),
+        cs.alloc_witness(preimage[0]).into(),
+        cs.alloc_witness(preimage[1]).into(),
+    ];
+    for (round, constants) in parameters.round_constants.iter().enumerate() {
+        for (x, c) in state.iter_mut().zip(constants) {
+            *x = x.clone() + *c;
+        }
+        let sboxes = if parameters.is_full_round(round) {
+            WIDTH
+        } else {
+            1
+        };
+        for x in state[..sboxes].iter_mut() {
+            *x = sbox_gadget(cs, x.clone()).into();
+        }
+        // the MDS layer is linear: no constraint
+        state = parameters.mds.map(|row| {
+            row.iter()
+                .zip(&state)
+                .fold(LinearCombination::default(), |acc, (m, x)| {
+                    acc + x.clone() * *m
+                })
+                .simplify()
+        });
+    }
+    cs.enforce(state[1].clone(), Variable::One, digest);
+    // SOLUTION-END
+}
+
+impl SumcheckProver for HonestProver {
+    fn claimed_sum(&self) -> Fr {
+        // SOLUTION-BEGIN
+        self.polynomial.sum_over_hypercube()
+        // SOLUTION-END
+    }
+
+    fn round_polynomial(&self) -> RoundPolynomial {
+        // SOLUTION-BEGIN
+        let evaluations = &self.polynomial.evaluations;
+        let (low, high) = evaluations.split_at(evaluations.len() / 2);
+        RoundPolynomial {
+            at_zero: low.iter().sum(),
+            at_one: high.iter().sum(),
+        }
+        // SOLUTION-END
+    }
+
+    fn receive_challenge(&mut self, r: Fr) {
+        // SOLUTION-BEGIN
+        self.polynomial = self.polynomial.fix_first_variable(r);
+        // SOLUTION-END
+    }
+}
+
+impl SumcheckVerifier {
+    pub fn receive<R: Rng>(&mut self, g: &RoundPolynomial, rng: &mut R) -> Option<Fr> {
+        // SOLUTION-BEGIN
+        if self.challenges.len() == self.num_variables || g.at_zero + g.at_one != self.claim {
+            return None;
+        }
+        let r = Fr::rand(rng);
+        self.claim = g.evaluate(r);
+        self.challenges.push(r);
+        Some(r)
+        // SOLUTION-END
+    }
+
+    pub fn finish(&self, oracle: &MultilinearPolynomial) -> bool {
+        // SOLUTION-BEGIN
+        self.challenges.len() == self.num_variables
+            && oracle.num_variables() == self.num_variables
+            && oracle.evaluate(&self.challenges) == self.claim
+        // SOLUTION-END
+    }
+}
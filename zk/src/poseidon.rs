@@ -0,0 +1,137 @@
+use crate::*;
+use ark_ff::{BigInteger, BigInteger256};
+
+// The Poseidon hash of the hashes chapter, with the same parameters (t = 3, x^5, R_F = 8, R_P = 57)
+// You wrote the permutation there: here it is given, to compute the digests the circuit must match
+// - a round: add the round constants, apply the S-box x^5 (to the whole state for the R_F / 2 first
+//   and last full rounds, to the first element only for the R_P partial rounds), multiply by the MDS matrix
+// - the hash of 2 elements: the state (2, x_1, x_2) is permuted once, the digest is its second element
+
+pub const WIDTH: usize = 3;
+
+pub struct PoseidonParameters {
+    pub full_rounds: usize,
+    pub partial_rounds: usize,
+    pub round_constants: Vec<[Fr; WIDTH]>,
+    pub mds: [[Fr; WIDTH]; WIDTH],
+}
+
+// the LFSR of the Grain stream cipher, used as a source of "nothing-up-my-sleeve" numbers
+pub struct GrainLfsr {
+    state: [bool; 80],
+}
+
+impl GrainLfsr {
+    pub fn new(field_bits: u64, full_rounds: usize, partial_rounds: usize) -> GrainLfsr {
+        let mut state = [true; 80];
+        // the parameters: prime field (2 bits), S-box x^alpha (4 bits), n (12 bits), t (12 bits),
+        // R_F (10 bits), R_P (10 bits), then 30 bits set to 1
+        let fields = [
+            (1, 2),
+            (0, 4),
+            (field_bits, 12),
+            (WIDTH as u64, 12),
+            (full_rounds as u64, 10),
+            (partial_rounds as u64, 10),
+        ];
+        let mut position = 0;
+        for (value, width) in fields {
+            for i in (0..width).rev() {
+                state[position] = (value >> i) & 1 == 1;
+                position += 1;
+            }
+        }
+        let mut lfsr = GrainLfsr { state };
+        for _ in 0..160 {
+            lfsr.update();
+        }
+        lfsr
+    }
+
+    fn update(&mut self) -> bool {
+        let s = &self.state;
+        let bit = s[62] ^ s[51] ^ s[38] ^ s[23] ^ s[13] ^ s[0];
+        self.state.copy_within(1.., 0);
+        self.state[79] = bit;
+        bit
+    }
+
+    // the output is "self-shrunk": a pair of bits (1, b) outputs b, a pair (0, b) is discarded
+    pub fn next_bits(&mut self, count: usize) -> Vec<bool> {
+        let mut bits = Vec::with_capacity(count);
+        while bits.len() < count {
+            let keep = self.update();
+            let bit = self.update();
+            if keep {
+                bits.push(bit);
+            }
+        }
+        bits
+    }
+
+    // rejection sampling of a field element
+    pub fn next_field_element(&mut self) -> Fr {
+        loop {
+            let bits = self.next_bits(Fr::MODULUS_BIT_SIZE as usize);
+            if let Some(x) = Fr::from_bigint(BigInteger256::from_bits_be(&bits)) {
+                return x;
+            }
+        }
+    }
+
+    // a field element reduced modulo p
+    pub fn next_field_element_mod_p(&mut self) -> Fr {
+        let bits = self.next_bits(Fr::MODULUS_BIT_SIZE as usize);
+        Fr::from_be_bytes_mod_order(&BigInteger256::from_bits_be(&bits).to_bytes_be())
+    }
+}
+
+impl PoseidonParameters {
+    // the parameters for 128-bit security with t = 3 and x^5 on the BLS12-381 scalar field
+    pub fn bls12_381_width_3() -> PoseidonParameters {
+        let (full_rounds, partial_rounds) = (8, 57);
+        let mut lfsr = GrainLfsr::new(Fr::MODULUS_BIT_SIZE as u64, full_rounds, partial_rounds);
+        let round_constants = (0..full_rounds + partial_rounds)
+            .map(|_| [(); WIDTH].map(|_| lfsr.next_field_element()))
+            .collect();
+        // a Cauchy matrix M[i][j] = 1 / (x_i + y_j) is MDS
+        let xs = [(); WIDTH].map(|_| lfsr.next_field_element_mod_p());
+        let ys = [(); WIDTH].map(|_| lfsr.next_field_element_mod_p());
+        let mds = xs.map(|x| ys.map(|y| (x + y).inverse().unwrap()));
+        PoseidonParameters {
+            full_rounds,
+            partial_rounds,
+            round_constants,
+            mds,
+        }
+    }
+
+    pub fn is_full_round(&self, round: usize) -> bool {
+        round < self.full_rounds / 2 || round >= self.full_rounds / 2 + self.partial_rounds
+    }
+}
+
+pub fn poseidon_permutation(parameters: &PoseidonParameters, state: &mut [Fr; WIDTH]) {
+    for (round, constants) in parameters.round_constants.iter().enumerate() {
+        for (x, c) in state.iter_mut().zip(constants) {
+            *x += c;
+        }
+        let sboxes = if parameters.is_full_round(round) {
+            WIDTH
+        } else {
+            1
+        };
+        for x in state[..sboxes].iter_mut() {
+            *x = x.pow([5]);
+        }
+        *state = parameters
+            .mds
+            .map(|row| row.iter().zip(state.iter()).map(|(m, x)| *m * x).sum());
+    }
+}
+
+pub fn poseidon_hash_two(parameters: &PoseidonParameters, inputs: [Fr; 2]) -> Fr {
+    let mut state = [Fr::from(2), inputs[0], inputs[1]];
+    poseidon_permutation(parameters, &mut state);
+    state[1]
+}
@@ -0,0 +1,125 @@
+use crate::*;
+use std::ops::{Add, Mul, Neg, Sub};
+
+// Rank-1 constraint systems (R1CS), the language of most SNARKs (Groth16, Marlin, Spartan...)
+// A computation is arithmetized as a vector z = (1, x, w) of field elements, made of the constant 1,
+// the public inputs x (the instance) and the private witness w, and a list of constraints
+//   <a_k, z> * <b_k, z> = <c_k, z>
+// one per multiplication: each side is a linear combination of variables, and additions are free
+// The prover convinces the verifier that she knows w such that all the constraints hold for the public x
+// (the proof itself is the job of module `groth16`)
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Variable {
+    One,
+    Input(usize),   // public
+    Witness(usize), // private
+}
+
+// sum of c_i v_i, terms with the same variable are not merged until `simplify`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LinearCombination(pub Vec<(Fr, Variable)>);
+
+#[derive(Clone, Debug)]
+pub struct Constraint {
+    pub a: LinearCombination,
+    pub b: LinearCombination,
+    pub c: LinearCombination,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ConstraintSystem {
+    pub inputs: Vec<Fr>,    // the assignment of the public inputs
+    pub witnesses: Vec<Fr>, // the assignment of the private witness
+    pub constraints: Vec<Constraint>,
+}
+
+impl ConstraintSystem {
+    pub fn new() -> ConstraintSystem {
+        ConstraintSystem::default()
+    }
+
+    pub fn is_satisfied(&self) -> bool {
+        self.which_is_unsatisfied().is_none()
+    }
+
+    pub fn num_constraints(&self) -> usize {
+        self.constraints.len()
+    }
+
+    // the value of a variable in the current assignment
+    pub fn value(&self, variable: Variable) -> Fr {
+        match variable {
+            Variable::One => Fr::one(),
+            Variable::Input(i) => self.inputs[i],
+            Variable::Witness(i) => self.witnesses[i],
+        }
+    }
+}
+
+impl LinearCombination {
+    // merges the terms with the same variable and drops the zero ones, so that long chains of
+    // linear operations (the MDS layers of a hash) do not blow up the size of the combinations
+    pub fn simplify(mut self) -> LinearCombination {
+        self.0.sort_by_key(|(_, variable)| *variable);
+        let mut terms: Vec<(Fr, Variable)> = Vec::with_capacity(self.0.len());
+        for (coefficient, variable) in self.0 {
+            match terms.last_mut() {
+                Some((sum, last)) if *last == variable => *sum += coefficient,
+                _ => terms.push((coefficient, variable)),
+            }
+        }
+        terms.retain(|(coefficient, _)| !coefficient.is_zero());
+        LinearCombination(terms)
+    }
+}
+
+impl From<Variable> for LinearCombination {
+    fn from(variable: Variable) -> LinearCombination {
+        LinearCombination(vec![(Fr::one(), variable)])
+    }
+}
+
+impl From<Fr> for LinearCombination {
+    fn from(constant: Fr) -> LinearCombination {
+        LinearCombination(vec![(constant, Variable::One)])
+    }
+}
+
+impl<T: Into<LinearCombination>> Add<T> for LinearCombination {
+    type Output = LinearCombination;
+
+    fn add(mut self, other: T) -> LinearCombination {
+        self.0.extend(other.into().0);
+        self
+    }
+}
+
+impl<T: Into<LinearCombination>> Sub<T> for LinearCombination {
+    type Output = LinearCombination;
+
+    fn sub(self, other: T) -> LinearCombination {
+        self + -other.into()
+    }
+}
+
+impl Neg for LinearCombination {
+    type Output = LinearCombination;
+
+    fn neg(self) -> LinearCombination {
+        self * -Fr::one()
+    }
+}
+
+impl Mul<Fr> for LinearCombination {
+    type Output = LinearCombination;
+
+    fn mul(self, scalar: Fr) -> LinearCombination {
+        LinearCombination(
+            self.0
+                .into_iter()
+                .map(|(coefficient, variable)| (coefficient * scalar, variable))
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,152 @@
+use crate::*;
+
+// The sumcheck protocol (Lund, Fortnow, Karloff, Nisan), the engine of Spartan, GKR, HyperPlonk...
+// The prover claims that a v-variate polynomial f sums to H over the boolean hypercube {0, 1}^v:
+//   H = sum_{b in {0, 1}^v} f(b_1, ..., b_v)
+// Checking it directly costs 2^v evaluations; with sumcheck, the verifier does O(v) work plus one query to f
+// Round i = 1..v: the prover sends the univariate polynomial
+//   g_i(X) = sum_{b in {0, 1}^(v-i)} f(r_1, ..., r_(i-1), X, b)
+// the verifier checks g_i(0) + g_i(1) against the previous claim (H for the first round, g_(i-1)(r_(i-1)) after)
+// and replies with a random r_i: the new claim is g_i(r_i), about a polynomial with one variable less
+// At the end, the claim g_v(r_v) = f(r_1, ..., r_v) is checked with a single evaluation of f (the oracle query)
+// A lying prover must send at least one wrong g_i, which agrees with the right one on r_i with probability
+// at most deg(g_i) / |F| (Schwartz-Zippel)
+//
+// Here f is multilinear (degree 1 in each variable), given by its 2^v evaluations on the hypercube,
+// so each g_i has degree 1 and is sent as (g_i(0), g_i(1))
+
+// evaluations on {0, 1}^v, the first variable being the most significant bit of the index
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultilinearPolynomial {
+    pub evaluations: Vec<Fr>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RoundPolynomial {
+    pub at_zero: Fr,
+    pub at_one: Fr,
+}
+
+impl RoundPolynomial {
+    pub fn evaluate(&self, x: Fr) -> Fr {
+        self.at_zero + x * (self.at_one - self.at_zero)
+    }
+}
+
+impl MultilinearPolynomial {
+    pub fn random<R: Rng>(num_variables: usize, rng: &mut R) -> MultilinearPolynomial {
+        MultilinearPolynomial {
+            evaluations: (0..1 << num_variables).map(|_| Fr::rand(rng)).collect(),
+        }
+    }
+
+    pub fn num_variables(&self) -> usize {
+        self.evaluations.len().trailing_zeros() as usize
+    }
+
+    // f(r, X_2, ..., X_v): f = (1 - X_1) f(0, ...) + X_1 f(1, ...)
+    pub fn fix_first_variable(&self, r: Fr) -> MultilinearPolynomial {
+        let (low, high) = self.evaluations.split_at(self.evaluations.len() / 2);
+        MultilinearPolynomial {
+            evaluations: low
+                .iter()
+                .zip(high)
+                .map(|(low, high)| *low + r * (*high - low))
+                .collect(),
+        }
+    }
+
+    // the oracle query of the verifier, at any point of F^v (not only on the hypercube)
+    pub fn evaluate(&self, point: &[Fr]) -> Fr {
+        assert_eq!(point.len(), self.num_variables());
+        point
+            .iter()
+            .fold(self.clone(), |f, r| f.fix_first_variable(*r))
+            .evaluations[0]
+    }
+
+    pub fn sum_over_hypercube(&self) -> Fr {
+        self.evaluations.iter().sum()
+    }
+}
+
+pub trait SumcheckProver {
+    fn claimed_sum(&self) -> Fr;
+
+    // g_i, for the challenges received so far
+    fn round_polynomial(&self) -> RoundPolynomial;
+
+    fn receive_challenge(&mut self, r: Fr);
+}
+
+// the polynomial is f with the challenges received so far fixed: f(r_1, ..., r_(i-1), X_i, ..., X_v)
+pub struct HonestProver {
+    pub polynomial: MultilinearPolynomial,
+}
+
+impl HonestProver {
+    pub fn new(f: &MultilinearPolynomial) -> HonestProver {
+        HonestProver {
+            polynomial: f.clone(),
+        }
+    }
+}
+
+// `claim` is the value the next round polynomial must sum to on {0, 1}
+pub struct SumcheckVerifier {
+    pub num_variables: usize,
+    pub claim: Fr,
+    pub challenges: Vec<Fr>,
+}
+
+impl SumcheckVerifier {
+    pub fn new(num_variables: usize, claimed_sum: Fr) -> SumcheckVerifier {
+        SumcheckVerifier {
+            num_variables,
+            claim: claimed_sum,
+            challenges: vec![],
+        }
+    }
+}
+
+// follows the honest prover up to the claimed sum, then shifts every round polynomial to stay consistent
+// with its lie: g'_i(0) = claim - g_i(1), the verifier's checks on g'_i(0) + g'_i(1) all pass
+pub struct CheatingProver {
+    pub honest: HonestProver,
+    pub claim: Fr,
+}
+
+impl SumcheckProver for CheatingProver {
+    fn claimed_sum(&self) -> Fr {
+        self.claim
+    }
+
+    fn round_polynomial(&self) -> RoundPolynomial {
+        let g = self.honest.round_polynomial();
+        RoundPolynomial {
+            at_zero: self.claim - g.at_one,
+            at_one: g.at_one,
+        }
+    }
+
+    fn receive_challenge(&mut self, r: Fr) {
+        self.claim = self.round_polynomial().evaluate(r);
+        self.honest.receive_challenge(r);
+    }
+}
+
+// runs the protocol between a prover and the verifier, who only has oracle access to f
+pub fn run_sumcheck<R: Rng>(
+    prover: &mut dyn SumcheckProver,
+    oracle: &MultilinearPolynomial,
+    rng: &mut R,
+) -> bool {
+    let mut verifier = SumcheckVerifier::new(oracle.num_variables(), prover.claimed_sum());
+    for _ in 0..oracle.num_variables() {
+        let Some(r) = verifier.receive(&prover.round_polynomial(), rng) else {
+            return false;
+        };
+        prover.receive_challenge(r);
+    }
+    verifier.finish(oracle)
+}